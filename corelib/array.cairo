@@ -1,4 +1,6 @@
 extern type Array<T>;
+impl ArrayDrop<T: Drop> of Drop::<Array::<T>>;
+
 extern func array_new<T>() -> Array::<T> nopanic;
 extern func array_append<T>(ref arr: Array::<T>, value: T) nopanic;
 extern func array_at<T>(
@@ -6,3 +8,20 @@ extern func array_at<T>(
     index: uint128
 ) -> Option::<T> implicits(RangeCheck) nopanic;
 extern func array_len<T>(ref arr: Array::<T>) -> uint128 nopanic;
+
+trait ArrayTrait<T> {
+    func append(ref self: Array::<T>, value: T) nopanic;
+    func at(ref self: Array::<T>, index: uint128) -> Option::<T> implicits(RangeCheck) nopanic;
+    func len(ref self: Array::<T>) -> uint128 nopanic;
+}
+impl ArrayImpl<T> of ArrayTrait::<T> {
+    func append(ref self: Array::<T>, value: T) nopanic {
+        array_append::<T>(self, value)
+    }
+    func at(ref self: Array::<T>, index: uint128) -> Option::<T> implicits(RangeCheck) nopanic {
+        array_at::<T>(self, index)
+    }
+    func len(ref self: Array::<T>) -> uint128 nopanic {
+        array_len::<T>(self)
+    }
+}
@@ -1,6 +1,6 @@
 extern type Box<T>;
-impl BoxFeltCopy of Copy::<Box::<felt>>;
-impl BoxFeltDrop of Drop::<Box::<felt>>;
+impl BoxCopy<T: Copy> of Copy::<Box::<T>>;
+impl BoxDrop<T: Drop> of Drop::<Box::<T>>;
 
 extern func into_box<T>(value: T) -> Box::<T> nopanic;
 extern func unbox<T>(box: Box::<T>) -> T nopanic;
@@ -0,0 +1,17 @@
+// A sequence of bytes, e.g. the value of a string literal ("hello").
+// The bytes are packed into felts of 31 bytes each (big-endian). The last bytes, which do not fill
+// a complete word, are kept separately in `pending_word`.
+// Note: the order of the members is relied upon by the compiler, when lowering string literals.
+struct ByteArray {
+    // Full words of 31 bytes each.
+    data: Array::<felt>,
+    // The remaining bytes (less than 31).
+    pending_word: felt,
+    // The number of bytes in `pending_word`.
+    pending_word_len: felt,
+}
+
+// Returns an empty ByteArray.
+func byte_array_new() -> ByteArray nopanic {
+    ByteArray { data: array_new::<felt>(), pending_word: 0, pending_word_len: 0 }
+}
@@ -123,9 +123,6 @@ func uint128_checked_mul(
     }
 }
 
-impl NonZeroUint128Copy of Copy::<NonZero::<uint128>>;
-impl NonZeroUint128Drop of Drop::<NonZero::<uint128>>;
-
 #[panic_with(1, uint128_as_non_zero)]
 func uint128_checked_as_non_zero(a: uint128) -> Option::<NonZero::<uint128>> implicits() nopanic {
     match uint128_jump_nz(a) {
@@ -177,6 +174,54 @@ func uint128_ne(a: uint128, b: uint128) -> bool implicits() nopanic {
     !(a == b)
 }
 
+impl Uint128Add of Add::<uint128> {
+    func add(a: uint128, b: uint128) -> uint128 implicits(RangeCheck) {
+        uint128_add(a, b)
+    }
+}
+impl Uint128Sub of Sub::<uint128> {
+    func sub(a: uint128, b: uint128) -> uint128 implicits(RangeCheck) {
+        uint128_sub(a, b)
+    }
+}
+impl Uint128Mul of Mul::<uint128> {
+    func mul(a: uint128, b: uint128) -> uint128 implicits(RangeCheck) {
+        uint128_mul(a, b)
+    }
+}
+impl Uint128Div of Div::<uint128> {
+    func div(a: uint128, b: uint128) -> uint128 implicits(RangeCheck) {
+        uint128_div(a, b)
+    }
+}
+impl Uint128Rem of Rem::<uint128> {
+    func rem(a: uint128, b: uint128) -> uint128 implicits(RangeCheck) {
+        uint128_mod(a, b)
+    }
+}
+impl Uint128PartialEq of PartialEq::<uint128> {
+    func eq(a: uint128, b: uint128) -> bool implicits() nopanic {
+        uint128_eq(a, b)
+    }
+    func ne(a: uint128, b: uint128) -> bool implicits() nopanic {
+        uint128_ne(a, b)
+    }
+}
+impl Uint128PartialOrd of PartialOrd::<uint128> {
+    func le(a: uint128, b: uint128) -> bool implicits(RangeCheck) nopanic {
+        uint128_le(a, b)
+    }
+    func ge(a: uint128, b: uint128) -> bool implicits(RangeCheck) nopanic {
+        uint128_ge(a, b)
+    }
+    func lt(a: uint128, b: uint128) -> bool implicits(RangeCheck) nopanic {
+        uint128_lt(a, b)
+    }
+    func gt(a: uint128, b: uint128) -> bool implicits(RangeCheck) nopanic {
+        uint128_gt(a, b)
+    }
+}
+
 extern func uint128_jump_nz(a: uint128) -> JumpNzResult::<uint128> implicits() nopanic;
 
 #[derive(Copy, Drop)]
@@ -286,6 +331,66 @@ func uint256_ne(a: uint256, b: uint256) -> bool implicits() {
     !(a == b)
 }
 
+func uint256_lt(a: uint256, b: uint256) -> bool implicits(RangeCheck) {
+    if a.high < b.high {
+        true
+    } else if a.high == b.high {
+        a.low < b.low
+    } else {
+        false
+    }
+}
+
+func uint256_le(a: uint256, b: uint256) -> bool implicits(RangeCheck) {
+    !(b < a)
+}
+
+func uint256_gt(a: uint256, b: uint256) -> bool implicits(RangeCheck) {
+    b < a
+}
+
+func uint256_ge(a: uint256, b: uint256) -> bool implicits(RangeCheck) {
+    !(a < b)
+}
+
+impl Uint256Add of Add::<uint256> {
+    func add(a: uint256, b: uint256) -> uint256 implicits(RangeCheck) {
+        uint256_add(a, b)
+    }
+}
+impl Uint256Sub of Sub::<uint256> {
+    func sub(a: uint256, b: uint256) -> uint256 implicits(RangeCheck) {
+        uint256_sub(a, b)
+    }
+}
+impl Uint256Mul of Mul::<uint256> {
+    func mul(a: uint256, b: uint256) -> uint256 implicits(RangeCheck) {
+        uint256_mul(a, b)
+    }
+}
+impl Uint256PartialEq of PartialEq::<uint256> {
+    func eq(a: uint256, b: uint256) -> bool implicits() {
+        uint256_eq(a, b)
+    }
+    func ne(a: uint256, b: uint256) -> bool implicits() {
+        uint256_ne(a, b)
+    }
+}
+impl Uint256PartialOrd of PartialOrd::<uint256> {
+    func le(a: uint256, b: uint256) -> bool implicits(RangeCheck) {
+        uint256_le(a, b)
+    }
+    func ge(a: uint256, b: uint256) -> bool implicits(RangeCheck) {
+        uint256_ge(a, b)
+    }
+    func lt(a: uint256, b: uint256) -> bool implicits(RangeCheck) {
+        uint256_lt(a, b)
+    }
+    func gt(a: uint256, b: uint256) -> bool implicits(RangeCheck) {
+        uint256_gt(a, b)
+    }
+}
+
 func uint256_from_felt(a: felt) -> uint256 implicits(RangeCheck) nopanic {
     match uint128s_from_felt(a) {
         Uint128sFromFeltResult::Narrow(low) => uint256 { low, high: uint128_from_felt_low(0) },
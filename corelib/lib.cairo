@@ -1,6 +1,17 @@
 mod traits;
 use traits::Copy;
 use traits::Drop;
+use traits::Add;
+use traits::Sub;
+use traits::Mul;
+use traits::Div;
+use traits::Rem;
+use traits::PartialEq;
+use traits::PartialOrd;
+use traits::BitAnd;
+use traits::BitOr;
+use traits::Neg;
+use traits::Not;
 
 enum bool { False: (), True: (), }
 // TODO(spapini): Make unnamed.
@@ -47,7 +58,31 @@ func bool_eq(a: bool, b: bool) -> bool implicits() nopanic {
     }
 }
 func bool_ne(a: bool, b: bool) -> bool implicits() nopanic {
-    !(a == b)
+    bool_not(bool_eq(a, b))
+}
+
+impl BoolPartialEq of PartialEq::<bool> {
+    func eq(a: bool, b: bool) -> bool nopanic {
+        bool_eq(a, b)
+    }
+    func ne(a: bool, b: bool) -> bool nopanic {
+        bool_ne(a, b)
+    }
+}
+impl BoolBitAnd of BitAnd::<bool> {
+    func bitand(a: bool, b: bool) -> bool nopanic {
+        bool_and(a, b)
+    }
+}
+impl BoolBitOr of BitOr::<bool> {
+    func bitor(a: bool, b: bool) -> bool nopanic {
+        bool_or(a, b)
+    }
+}
+impl BoolNot of Not::<bool> {
+    func not(a: bool) -> bool nopanic {
+        bool_not(a)
+    }
 }
 
 extern type RangeCheck;
@@ -65,23 +100,30 @@ extern func felt_mul(a: felt, b: felt) -> felt nopanic;
 extern func felt_neg(a: felt) -> felt nopanic;
 
 extern type NonZero<T>;
-// TODO(spapini): Add generic impls for NonZero for Copy, Drop.
+impl NonZeroCopy<T: Copy> of Copy::<NonZero::<T>>;
+impl NonZeroDrop<T: Drop> of Drop::<NonZero::<T>>;
 enum JumpNzResult<T> { Zero: (), NonZero: NonZero::<T>, }
 extern func unwrap_nz<T>(a: NonZero::<T>) -> T nopanic;
 
-impl NonZeroFeltCopy of Copy::<NonZero::<felt>>;
-impl NonZeroFeltDrop of Drop::<NonZero::<felt>>;
 extern func felt_div(a: felt, b: NonZero::<felt>) -> felt nopanic;
 
+#[panic_with(1, felt_as_non_zero)]
+func felt_checked_as_non_zero(a: felt) -> Option::<NonZero::<felt>> nopanic {
+    match felt_jump_nz(a) {
+        JumpNzResult::Zero(()) => Option::<NonZero::<felt>>::None(()),
+        JumpNzResult::NonZero(x) => Option::<NonZero::<felt>>::Some(x),
+    }
+}
+
 // TODO(orizi): Change to extern when added.
 func felt_eq(a: felt, b: felt) -> bool nopanic {
-    match a - b {
+    match felt_sub(a, b) {
         0 => bool::True(()),
         _ => bool::False(()),
     }
 }
 func felt_ne(a: felt, b: felt) -> bool nopanic {
-    !(a == b)
+    bool_not(felt_eq(a, b))
 }
 
 // TODO(orizi): Change to extern when added.
@@ -102,6 +144,54 @@ func felt_ge(a: felt, b: felt) -> bool implicits(RangeCheck) {
     felt_le(b, a)
 }
 
+impl FeltAdd of Add::<felt> {
+    func add(a: felt, b: felt) -> felt nopanic {
+        felt_add(a, b)
+    }
+}
+impl FeltSub of Sub::<felt> {
+    func sub(a: felt, b: felt) -> felt nopanic {
+        felt_sub(a, b)
+    }
+}
+impl FeltMul of Mul::<felt> {
+    func mul(a: felt, b: felt) -> felt nopanic {
+        felt_mul(a, b)
+    }
+}
+impl FeltDiv of Div::<felt> {
+    func div(a: felt, b: felt) -> felt {
+        felt_div(a, felt_as_non_zero(b))
+    }
+}
+impl FeltNeg of Neg::<felt> {
+    func neg(a: felt) -> felt nopanic {
+        felt_neg(a)
+    }
+}
+impl FeltPartialEq of PartialEq::<felt> {
+    func eq(a: felt, b: felt) -> bool nopanic {
+        felt_eq(a, b)
+    }
+    func ne(a: felt, b: felt) -> bool nopanic {
+        felt_ne(a, b)
+    }
+}
+impl FeltPartialOrd of PartialOrd::<felt> {
+    func le(a: felt, b: felt) -> bool implicits(RangeCheck) {
+        felt_le(a, b)
+    }
+    func ge(a: felt, b: felt) -> bool implicits(RangeCheck) {
+        felt_ge(a, b)
+    }
+    func lt(a: felt, b: felt) -> bool implicits(RangeCheck) {
+        felt_lt(a, b)
+    }
+    func gt(a: felt, b: felt) -> bool implicits(RangeCheck) {
+        felt_gt(a, b)
+    }
+}
+
 extern func felt_jump_nz(a: felt) -> JumpNzResult::<felt> nopanic;
 
 // TODO(spapini): Constraint using Copy and Drop traits.
@@ -120,6 +210,12 @@ use array::Array;
 use array::array_new;
 use array::array_append;
 use array::array_at;
+use array::ArrayTrait;
+
+// Byte arrays.
+mod byte_array;
+use byte_array::ByteArray;
+use byte_array::byte_array_new;
 
 // Result.
 mod result;
@@ -128,6 +224,7 @@ use result::Result;
 // Option.
 mod option;
 use option::Option;
+use option::OptionTrait;
 
 // Integer.
 mod integer;
@@ -180,7 +277,15 @@ func assert(cond: bool, err_code: felt) {
 mod hash;
 use hash::pedersen;
 
+// Serialization.
+mod serde;
+use serde::Serde;
+
 // Syscall Ptr
 extern type SyscallPtr;
 
+// StarkNet.
+mod starknet;
+use starknet::StorageAddress;
+
 mod test;
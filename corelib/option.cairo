@@ -1 +1,36 @@
 enum Option<T> { Some: T, None: (), }
+impl OptionCopy<T: Copy> of Copy::<Option::<T>>;
+impl OptionDrop<T: Drop> of Drop::<Option::<T>>;
+
+trait OptionTrait<T> {
+    func unwrap(self: Option::<T>) -> T;
+    func is_some(self: Option::<T>) -> bool nopanic;
+    func is_none(self: Option::<T>) -> bool nopanic;
+}
+// TODO(spapini): Replace with `impl OptionImpl<T> of OptionTrait::<T>` once generic functions are
+// specialized. Until then, calling a generic impl function is only supported if it forwards its
+// params to an extern function (see L0005), and these functions match on `self`.
+impl OptionFeltImpl of OptionTrait::<felt> {
+    func unwrap(self: Option::<felt>) -> felt {
+        match self {
+            Option::Some(x) => x,
+            Option::None(_) => {
+                let mut data = array_new::<felt>();
+                array_append::<felt>(data, 'Option::unwrap failed.');
+                panic(data)
+            },
+        }
+    }
+    func is_some(self: Option::<felt>) -> bool nopanic {
+        match self {
+            Option::Some(_) => true,
+            Option::None(_) => false,
+        }
+    }
+    func is_none(self: Option::<felt>) -> bool nopanic {
+        match self {
+            Option::Some(_) => false,
+            Option::None(_) => true,
+        }
+    }
+}
@@ -1 +1,3 @@
 enum Result<T, E> { Ok: T, Err: E, }
+impl ResultCopy<T: Copy, E: Copy> of Copy::<Result::<T, E>>;
+impl ResultDrop<T: Drop, E: Drop> of Drop::<Result::<T, E>>;
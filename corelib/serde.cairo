@@ -0,0 +1,151 @@
+use array::ArrayTrait;
+
+// Serialization of values into an array of felts, e.g. for the calldata and the return data of
+// StarkNet entry points.
+trait Serde<T> {
+    func serialize(ref serialized: Array::<felt>, input: T);
+    // Deserializes a value from `serialized`, starting at `offset`, and advances `offset` past it.
+    // Returns `None` if `serialized` does not hold a valid value at `offset`.
+    func deserialize(ref serialized: Array::<felt>, ref offset: uint128) -> Option::<T>;
+}
+
+impl FeltSerde of Serde::<felt> {
+    func serialize(ref serialized: Array::<felt>, input: felt) {
+        serialized.append(input);
+    }
+    func deserialize(ref serialized: Array::<felt>, ref offset: uint128) -> Option::<felt> {
+        match serialized.at(offset) {
+            Option::Some(value) => {
+                offset = offset + uint128_from_felt(1);
+                Option::<felt>::Some(value)
+            },
+            Option::None(_) => Option::<felt>::None(()),
+        }
+    }
+}
+
+impl UnitSerde of Serde::<()> {
+    func serialize(ref serialized: Array::<felt>, input: ()) {}
+    func deserialize(ref serialized: Array::<felt>, ref offset: uint128) -> Option::<()> {
+        Option::<()>::Some(())
+    }
+}
+
+impl BoolSerde of Serde::<bool> {
+    func serialize(ref serialized: Array::<felt>, input: bool) {
+        FeltSerde::serialize(serialized, if input { 1 } else { 0 });
+    }
+    func deserialize(ref serialized: Array::<felt>, ref offset: uint128) -> Option::<bool> {
+        match FeltSerde::deserialize(serialized, offset) {
+            Option::Some(value) => match value {
+                0 => Option::<bool>::Some(false),
+                _ => if value == 1 {
+                    Option::<bool>::Some(true)
+                } else {
+                    Option::<bool>::None(())
+                },
+            },
+            Option::None(_) => Option::<bool>::None(()),
+        }
+    }
+}
+
+impl Uint128Serde of Serde::<uint128> {
+    func serialize(ref serialized: Array::<felt>, input: uint128) {
+        FeltSerde::serialize(serialized, uint128_to_felt(input));
+    }
+    func deserialize(ref serialized: Array::<felt>, ref offset: uint128) -> Option::<uint128> {
+        match FeltSerde::deserialize(serialized, offset) {
+            Option::Some(value) => integer::uint128_try_from_felt(value),
+            Option::None(_) => Option::<uint128>::None(()),
+        }
+    }
+}
+
+impl Uint256Serde of Serde::<uint256> {
+    func serialize(ref serialized: Array::<felt>, input: uint256) {
+        Uint128Serde::serialize(serialized, input.low);
+        Uint128Serde::serialize(serialized, input.high);
+    }
+    func deserialize(ref serialized: Array::<felt>, ref offset: uint128) -> Option::<uint256> {
+        match Uint128Serde::deserialize(serialized, offset) {
+            Option::Some(low) => match Uint128Serde::deserialize(serialized, offset) {
+                Option::Some(high) => Option::<uint256>::Some(uint256 { low, high }),
+                Option::None(_) => Option::<uint256>::None(()),
+            },
+            Option::None(_) => Option::<uint256>::None(()),
+        }
+    }
+}
+
+// An array is serialized as its length, followed by its elements.
+// TODO(spapini): Replace with a generic impl once generic functions can be lowered.
+impl ArrayFeltSerde of Serde::<Array::<felt>> {
+    func serialize(ref serialized: Array::<felt>, input: Array::<felt>) {
+        let mut input = input;
+        FeltSerde::serialize(serialized, uint128_to_felt(input.len()));
+        serialize_array_felt_helper(serialized, input, uint128_from_felt(0));
+    }
+    func deserialize(
+        ref serialized: Array::<felt>,
+        ref offset: uint128
+    ) -> Option::<Array::<felt>> {
+        match FeltSerde::deserialize(serialized, offset) {
+            Option::Some(length) => deserialize_array_felt_helper(
+                serialized,
+                offset,
+                array_new::<felt>(),
+                length
+            ),
+            Option::None(_) => Option::<Array::<felt>>::None(()),
+        }
+    }
+}
+
+func serialize_array_felt_helper(
+    ref serialized: Array::<felt>,
+    ref input: Array::<felt>,
+    index: uint128
+) {
+    match get_gas() {
+        Option::Some(_) => {},
+        Option::None(_) => {
+            let mut data = array_new::<felt>();
+            array_append::<felt>(data, 'Out of gas');
+            panic(data);
+        },
+    }
+    match input.at(index) {
+        Option::Some(value) => {
+            FeltSerde::serialize(serialized, value);
+            serialize_array_felt_helper(serialized, input, index + uint128_from_felt(1));
+        },
+        Option::None(_) => {},
+    }
+}
+
+func deserialize_array_felt_helper(
+    ref serialized: Array::<felt>,
+    ref offset: uint128,
+    mut curr_output: Array::<felt>,
+    remaining: felt
+) -> Option::<Array::<felt>> {
+    match get_gas() {
+        Option::Some(_) => {},
+        Option::None(_) => {
+            let mut data = array_new::<felt>();
+            array_append::<felt>(data, 'Out of gas');
+            panic(data);
+        },
+    }
+    match remaining {
+        0 => Option::<Array::<felt>>::Some(curr_output),
+        _ => match FeltSerde::deserialize(serialized, offset) {
+            Option::Some(value) => {
+                curr_output.append(value);
+                deserialize_array_felt_helper(serialized, offset, curr_output, remaining - 1)
+            },
+            Option::None(_) => Option::<Array::<felt>>::None(()),
+        },
+    }
+}
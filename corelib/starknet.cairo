@@ -0,0 +1,86 @@
+// Storage.
+extern type StorageAddress;
+impl StorageAddressCopy of Copy::<StorageAddress>;
+impl StorageAddressDrop of Drop::<StorageAddress>;
+
+extern func storage_address_const<address>() -> StorageAddress nopanic;
+
+extern func storage_read(
+    ref syscall_ptr: SyscallPtr, address: StorageAddress
+) -> felt nopanic;
+extern func storage_write(
+    ref syscall_ptr: SyscallPtr, address: StorageAddress, value: felt
+) nopanic;
+// Returns the address `offset` cells after `address`, for values stored in several cells.
+extern func storage_address_offset<offset>(
+    address: StorageAddress
+) -> StorageAddress nopanic;
+
+// Reading and writing values of a type in the storage, e.g. for the storage variables of
+// contracts. A value of several felts is stored in consecutive addresses, starting at `address`.
+trait StorageAccess<T> {
+    func read(ref syscall_ptr: SyscallPtr, address: StorageAddress) -> T;
+    func write(ref syscall_ptr: SyscallPtr, address: StorageAddress, value: T);
+}
+
+impl StorageAccessFelt of StorageAccess::<felt> {
+    func read(ref syscall_ptr: SyscallPtr, address: StorageAddress) -> felt {
+        storage_read(syscall_ptr, address)
+    }
+    func write(ref syscall_ptr: SyscallPtr, address: StorageAddress, value: felt) {
+        storage_write(syscall_ptr, address, value)
+    }
+}
+
+impl StorageAccessUint128 of StorageAccess::<uint128> {
+    func read(ref syscall_ptr: SyscallPtr, address: StorageAddress) -> uint128 {
+        uint128_from_felt(StorageAccessFelt::read(syscall_ptr, address))
+    }
+    func write(ref syscall_ptr: SyscallPtr, address: StorageAddress, value: uint128) {
+        StorageAccessFelt::write(syscall_ptr, address, uint128_to_felt(value))
+    }
+}
+
+impl StorageAccessUint256 of StorageAccess::<uint256> {
+    func read(ref syscall_ptr: SyscallPtr, address: StorageAddress) -> uint256 {
+        let low = StorageAccessUint128::read(syscall_ptr, address);
+        let high = StorageAccessUint128::read(
+            syscall_ptr, storage_address_offset::<1>(address)
+        );
+        uint256 { low, high }
+    }
+    func write(ref syscall_ptr: SyscallPtr, address: StorageAddress, value: uint256) {
+        StorageAccessUint128::write(syscall_ptr, address, value.low);
+        StorageAccessUint128::write(
+            syscall_ptr, storage_address_offset::<1>(address), value.high
+        )
+    }
+}
+
+// Contract calls.
+extern func call_contract(
+    ref syscall_ptr: SyscallPtr,
+    address: felt,
+    entry_point_selector: felt,
+    calldata: Array::<felt>
+) -> Array::<felt> nopanic;
+extern func deploy(
+    ref syscall_ptr: SyscallPtr,
+    class_hash: felt,
+    contract_address_salt: felt,
+    calldata: Array::<felt>
+) -> felt nopanic;
+
+// Execution info.
+extern func get_caller_address(ref syscall_ptr: SyscallPtr) -> felt nopanic;
+extern func get_contract_address(ref syscall_ptr: SyscallPtr) -> felt nopanic;
+extern func get_block_number(ref syscall_ptr: SyscallPtr) -> felt nopanic;
+extern func get_block_timestamp(ref syscall_ptr: SyscallPtr) -> felt nopanic;
+
+// Events and messages.
+extern func emit_event(
+    ref syscall_ptr: SyscallPtr, keys: Array::<felt>, data: Array::<felt>
+) nopanic;
+extern func send_message_to_l1(
+    ref syscall_ptr: SyscallPtr, to_address: felt, payload: Array::<felt>
+) nopanic;
@@ -4,6 +4,15 @@ func test_assert_false() {
     assert(false, 1);
 }
 
+#[test]
+#[should_panic(expected: ('error_code', 42))]
+func test_panic_with_expected_data() {
+    let mut data = array_new::<felt>();
+    array_append::<felt>(data, 'error_code');
+    array_append::<felt>(data, 42);
+    panic(data);
+}
+
 #[test]
 func test_assert_true() {
     assert(true, 1);
@@ -244,3 +253,77 @@ func test_array_out_of_bound_1() {
 func test_array_out_of_bound_2() {
     test_array_helper(11);
 }
+
+#[test]
+#[available_gas(100000)]
+func test_loop_with_gas() implicits(RangeCheck, GasBuiltin) {
+    let mut i = 0;
+    let mut sum = 0;
+    loop {
+        if i == 10 {
+            break;
+        }
+        sum = sum + i;
+        i = i + 1;
+    }
+    assert(sum == 45, 1);
+}
+
+#[test]
+#[available_gas(100000)]
+func test_serde() {
+    let mut serialized = array_new::<felt>();
+    Serde::<felt>::serialize(serialized, 5);
+    Serde::<bool>::serialize(serialized, true);
+    Serde::<uint256>::serialize(
+        serialized, uint256 { low: uint128_from_felt(1), high: uint128_from_felt(2) }
+    );
+    let mut arr = array_new::<felt>();
+    arr.append(7);
+    arr.append(8);
+    Serde::<Array::<felt>>::serialize(serialized, arr);
+    assert(serialized.len() == uint128_from_felt(7), 1);
+
+    let mut offset = uint128_from_felt(0);
+    assert(Serde::<felt>::deserialize(serialized, offset).unwrap() == 5, 2);
+    let value = match Serde::<bool>::deserialize(serialized, offset) {
+        Option::Some(value) => value,
+        Option::None(_) => false,
+    };
+    assert(value, 3);
+    let value = match Serde::<uint256>::deserialize(serialized, offset) {
+        Option::Some(value) => value,
+        Option::None(_) => uint256 { low: uint128_from_felt(0), high: uint128_from_felt(0) },
+    };
+    assert(value == uint256 { low: uint128_from_felt(1), high: uint128_from_felt(2) }, 4);
+    let mut value = match Serde::<Array::<felt>>::deserialize(serialized, offset) {
+        Option::Some(value) => value,
+        Option::None(_) => array_new::<felt>(),
+    };
+    assert(value.len() == uint128_from_felt(2), 5);
+    assert(value.at(uint128_from_felt(0)).unwrap() == 7, 5);
+    assert(value.at(uint128_from_felt(1)).unwrap() == 8, 5);
+    assert(offset == uint128_from_felt(7), 6);
+    assert(Serde::<felt>::deserialize(serialized, offset).is_none(), 7);
+}
+
+#[test]
+#[available_gas(100000)]
+func test_serde_invalid_input() {
+    let mut serialized = array_new::<felt>();
+    serialized.append(2);
+    serialized.append(3);
+    let mut offset = uint128_from_felt(0);
+    let is_none = match Serde::<bool>::deserialize(serialized, offset) {
+        Option::Some(_) => false,
+        Option::None(_) => true,
+    };
+    assert(is_none, 1);
+    // An array of length 3, with only a single element.
+    let mut offset = uint128_from_felt(1);
+    let is_none = match Serde::<Array::<felt>>::deserialize(serialized, offset) {
+        Option::Some(_) => false,
+        Option::None(_) => true,
+    };
+    assert(is_none, 2);
+}
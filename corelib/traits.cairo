@@ -1,2 +1,42 @@
 trait Copy<T>;
 trait Drop<T>;
+
+// Traits of the operators. Each operator is a call to the function of the corresponding trait,
+// implemented for the type of the operands.
+trait Add<T> {
+    func add(a: T, b: T) -> T;
+}
+trait Sub<T> {
+    func sub(a: T, b: T) -> T;
+}
+trait Mul<T> {
+    func mul(a: T, b: T) -> T;
+}
+trait Div<T> {
+    func div(a: T, b: T) -> T;
+}
+trait Rem<T> {
+    func rem(a: T, b: T) -> T;
+}
+trait PartialEq<T> {
+    func eq(a: T, b: T) -> bool;
+    func ne(a: T, b: T) -> bool;
+}
+trait PartialOrd<T> {
+    func le(a: T, b: T) -> bool;
+    func ge(a: T, b: T) -> bool;
+    func lt(a: T, b: T) -> bool;
+    func gt(a: T, b: T) -> bool;
+}
+trait BitAnd<T> {
+    func bitand(a: T, b: T) -> T;
+}
+trait BitOr<T> {
+    func bitor(a: T, b: T) -> T;
+}
+trait Neg<T> {
+    func neg(a: T) -> T;
+}
+trait Not<T> {
+    func not(a: T) -> T;
+}
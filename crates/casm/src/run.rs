@@ -1,4 +1,5 @@
 use std::any::Any;
+use std::cell::RefCell;
 use std::collections::HashMap;
 
 use cairo_rs::hint_processor::hint_processor_definition::{HintProcessor, HintReference};
@@ -15,7 +16,7 @@ use num_bigint::BigInt;
 
 use crate::hints::Hint;
 use crate::instructions::Instruction;
-use crate::operand::{CellRef, DerefOrImmediate, Register};
+use crate::operand::{BinOpOperand, CellRef, DerefOrImmediate, Operation, Register, ResOperand};
 
 #[cfg(test)]
 #[path = "run_test.rs"]
@@ -38,16 +39,73 @@ fn hint_to_hint_params(hint: &Hint) -> HintParams {
     }
 }
 
+/// The memory of a system call, as given to a [SyscallHandler]. Offsets are relative to the system
+/// call pointer, where the request starts with the selector of the system call.
+pub struct SyscallMemory<'a> {
+    vm: &'a mut VirtualMachine,
+    syscall_ptr: Relocatable,
+}
+impl SyscallMemory<'_> {
+    /// Returns the felt at the given offset.
+    pub fn read(&self, offset: usize) -> Result<BigInt, VirtualMachineError> {
+        Ok(self.vm.get_integer(&(self.syscall_ptr + offset))?.as_ref().clone())
+    }
+
+    /// Returns the elements of the array whose start and end pointers are at the given offset.
+    pub fn read_array(&self, offset: usize) -> Result<Vec<BigInt>, VirtualMachineError> {
+        let start = self.vm.get_relocatable(&(self.syscall_ptr + offset))?.into_owned();
+        let end = self.vm.get_relocatable(&(self.syscall_ptr + offset + 1))?.into_owned();
+        (0..end.offset - start.offset)
+            .map(|index| Ok(self.vm.get_integer(&(start + index))?.as_ref().clone()))
+            .collect()
+    }
+
+    /// Writes a felt at the given offset.
+    pub fn write(&mut self, offset: usize, value: BigInt) -> Result<(), VirtualMachineError> {
+        self.vm.insert_value(&(self.syscall_ptr + offset), value)?;
+        Ok(())
+    }
+
+    /// Writes the elements into a new memory segment, and the start and end pointers of the
+    /// resulting array at the given offset.
+    pub fn write_array(
+        &mut self,
+        offset: usize,
+        elements: Vec<BigInt>,
+    ) -> Result<(), VirtualMachineError> {
+        let start = self.vm.add_memory_segment();
+        let len = elements.len();
+        for (index, element) in elements.into_iter().enumerate() {
+            self.vm.insert_value(&(start + index), element)?;
+        }
+        self.vm.insert_value(&(self.syscall_ptr + offset), start)?;
+        self.vm.insert_value(&(self.syscall_ptr + offset + 1), start + len)?;
+        Ok(())
+    }
+}
+
+/// Handler of the system calls triggered by `Hint::SystemCall` during a run.
+pub trait SyscallHandler {
+    /// Executes the system call whose request is at the start of `memory`, and writes its response
+    /// right after the request.
+    fn execute(&mut self, memory: &mut SyscallMemory<'_>) -> Result<(), VirtualMachineError>;
+}
+
 /// HintProcessor for Cairo compiler hints.
-struct CairoHintProcessor {
+struct CairoHintProcessor<'a> {
     // A dict from instruction offset to hint vector.
     pub hints_dict: HashMap<usize, Vec<HintParams>>,
     // A mapping from a string that represents a hint to the hint object.
     pub string_to_hint: HashMap<String, Hint>,
+    // The handler of the system calls, if the run makes any.
+    pub syscall_handler: Option<RefCell<&'a mut dyn SyscallHandler>>,
 }
 
-impl CairoHintProcessor {
-    pub fn new(program: Vec<Instruction>) -> Self {
+impl<'a> CairoHintProcessor<'a> {
+    pub fn new(
+        program: Vec<Instruction>,
+        syscall_handler: Option<&'a mut dyn SyscallHandler>,
+    ) -> Self {
         let mut hints_dict: HashMap<usize, Vec<HintParams>> = HashMap::new();
         let mut string_to_hint: HashMap<String, Hint> = HashMap::new();
 
@@ -67,7 +125,11 @@ impl CairoHintProcessor {
             }
             hint_offset += instruction.body.op_size();
         }
-        CairoHintProcessor { hints_dict, string_to_hint }
+        CairoHintProcessor {
+            hints_dict,
+            string_to_hint,
+            syscall_handler: syscall_handler.map(RefCell::new),
+        }
     }
 }
 
@@ -79,7 +141,24 @@ fn cell_ref_to_relocatable(cell_ref: CellRef, vm: &VirtualMachine) -> Relocatabl
     base + (cell_ref.offset as i32)
 }
 
-impl HintProcessor for CairoHintProcessor {
+/// Returns the pointer an operand of the form `[cell]` or `[cell] + imm` evaluates to.
+fn res_operand_to_relocatable(
+    operand: &ResOperand,
+    vm: &VirtualMachine,
+) -> Result<Relocatable, VirtualMachineError> {
+    let (cell, offset) = match operand {
+        ResOperand::Deref(cell) => (cell, 0),
+        ResOperand::BinOp(BinOpOperand {
+            op: Operation::Add,
+            a,
+            b: DerefOrImmediate::Immediate(offset),
+        }) => (a, i32::try_from(offset).unwrap()),
+        _ => panic!("Unexpected pointer operand: {operand}."),
+    };
+    Ok(vm.get_relocatable(&cell_ref_to_relocatable(*cell, vm))?.into_owned() + offset)
+}
+
+impl HintProcessor for CairoHintProcessor<'_> {
     /// Trait function to execute a given hint in the hint processor.
     fn execute_hint(
         &self,
@@ -134,7 +213,15 @@ impl HintProcessor for CairoHintProcessor {
             Hint::EnterScope => todo!(),
             Hint::ExitScope => todo!(),
             Hint::DictSquashHints { .. } => todo!(),
-            Hint::SystemCall { .. } => todo!(),
+            Hint::SystemCall { syscall_ptr } => {
+                let Some(syscall_handler) = &self.syscall_handler else {
+                    return Err(VirtualMachineError::UnknownHint(
+                        "Running system calls requires a system call handler.".into(),
+                    ));
+                };
+                let syscall_ptr = res_operand_to_relocatable(syscall_ptr, vm)?;
+                syscall_handler.borrow_mut().execute(&mut SyscallMemory { vm, syscall_ptr })?;
+            }
         };
         Ok(())
     }
@@ -151,17 +238,71 @@ impl HintProcessor for CairoHintProcessor {
     }
 }
 
+/// An entry of the execution trace of a run.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TraceEntry {
+    /// The offset of the executed instruction in the code.
+    pub pc: usize,
+    /// The value of ap, as an index into the relocated memory.
+    pub ap: usize,
+    /// The value of fp, as an index into the relocated memory.
+    pub fp: usize,
+}
+
+/// A successful run, with its memory and execution trace.
+#[derive(Debug)]
+pub struct TracedRun {
+    /// The relocated memory of the run.
+    pub memory: Vec<Option<BigInt>>,
+    /// The execution trace of the run.
+    pub trace: Vec<TraceEntry>,
+}
+
+/// A failure of a run, together with where in the code it occurred.
+#[derive(Debug)]
+pub struct RunFailure {
+    pub error: VirtualMachineError,
+    /// The offset in the code of the instruction that failed.
+    pub pc: usize,
+    /// The offsets in the code of the instructions executed before the failing one. The rest of
+    /// the execution trace is not available, as the memory of a failed run can't be relocated.
+    pub pc_trace: Vec<usize>,
+}
+
 /// Runs `program` on layout with prime, and returns the memory layout and ap value.
 pub fn run_function(
     function: Vec<Instruction>,
 ) -> Result<(Vec<Option<BigInt>>, usize), Box<VirtualMachineError>> {
+    let TracedRun { memory, trace } =
+        run_function_with_trace(function).map_err(|failure| Box::new(failure.error))?;
+    Ok((memory, trace.last().unwrap().ap))
+}
+
+/// Runs `program` on layout with prime, and returns the memory layout and the execution trace.
+pub fn run_function_with_trace(function: Vec<Instruction>) -> Result<TracedRun, Box<RunFailure>> {
+    run_function_with_syscall_handler_impl(function, None)
+}
+
+/// Same as [run_function_with_trace], handling the system calls of the run with
+/// `syscall_handler`.
+pub fn run_function_with_syscall_handler(
+    function: Vec<Instruction>,
+    syscall_handler: &mut dyn SyscallHandler,
+) -> Result<TracedRun, Box<RunFailure>> {
+    run_function_with_syscall_handler_impl(function, Some(syscall_handler))
+}
+
+fn run_function_with_syscall_handler_impl(
+    function: Vec<Instruction>,
+    syscall_handler: Option<&mut dyn SyscallHandler>,
+) -> Result<TracedRun, Box<RunFailure>> {
     let data: Vec<MaybeRelocatable> = function
         .iter()
         .flat_map(|inst| inst.assemble().encode())
         .map(MaybeRelocatable::from)
         .collect();
 
-    let hint_processor = CairoHintProcessor::new(function);
+    let hint_processor = CairoHintProcessor::new(function, syscall_handler);
 
     let program = Program {
         builtins: Vec::new(),
@@ -175,19 +316,45 @@ pub fn run_function(
         reference_manager: ReferenceManager { references: Vec::new() },
         identifiers: HashMap::new(),
     };
+    let setup_failure = |error| Box::new(RunFailure { error, pc: 0, pc_trace: vec![] });
     let mut runner = CairoRunner::new(&program, "plain", false)
         .map_err(VirtualMachineError::from)
-        .map_err(Box::new)?;
+        .map_err(setup_failure)?;
     let mut vm = VirtualMachine::new(get_prime(), true);
 
-    let end = runner.initialize(&mut vm).map_err(VirtualMachineError::from).map_err(Box::new)?;
+    let end =
+        runner.initialize(&mut vm).map_err(VirtualMachineError::from).map_err(setup_failure)?;
 
-    runner.run_until_pc(end, &mut vm, &hint_processor)?;
-    // TODO(alont) Remove this hack once the VM no longer squashes Nones at the end of segments.
-    vm.insert_value(vm.get_ap() + 1, MaybeRelocatable::Int(BigInt::from(0)))?;
-    runner.end_run(true, false, &mut vm, &hint_processor).map_err(Box::new)?;
-    runner.relocate(&mut vm).map_err(VirtualMachineError::from).map_err(Box::new)?;
-    Ok((runner.relocated_memory, runner.relocated_trace.unwrap().last().unwrap().ap))
+    let run_result = (|| -> Result<(), VirtualMachineError> {
+        runner.run_until_pc(end, &mut vm, &hint_processor)?;
+        // TODO(alont) Remove this hack once the VM no longer squashes Nones at the end of segments.
+        vm.insert_value(vm.get_ap() + 1, MaybeRelocatable::Int(BigInt::from(0)))?;
+        runner.end_run(true, false, &mut vm, &hint_processor)?;
+        runner.relocate(&mut vm).map_err(VirtualMachineError::from)
+    })();
+    if let Err(error) = run_result {
+        let pc = vm.get_pc().offset;
+        // The program segment is the first segment, so the offsets of the unrelocated pcs are the
+        // offsets in the code.
+        let pc_trace = vm
+            .get_trace()
+            .map(|trace| trace.iter().map(|entry| entry.pc.offset).collect())
+            .unwrap_or_default();
+        return Err(Box::new(RunFailure { error, pc, pc_trace }));
+    }
+    let trace = relocated_trace(&runner);
+    Ok(TracedRun { memory: runner.relocated_memory, trace })
+}
+
+/// Returns the relocated execution trace of a run.
+fn relocated_trace(runner: &CairoRunner) -> Vec<TraceEntry> {
+    runner
+        .relocated_trace
+        .iter()
+        .flatten()
+        // The program segment is relocated to start at address 1.
+        .map(|entry| TraceEntry { pc: entry.pc - 1, ap: entry.ap, fp: entry.fp })
+        .collect()
 }
 
 /// Runs `function` and returns `n_returns` return values.
@@ -2,8 +2,10 @@ use itertools::Itertools;
 use num_bigint::{BigInt, Sign};
 use test_case::test_case;
 
+use crate::hints::Hint;
 use crate::inline::CasmContext;
-use crate::run::{run_function, run_function_return_values};
+use crate::operand::ResOperand;
+use crate::run::{run_function, run_function_return_values, run_function_with_trace, TracedRun};
 use crate::{casm, deref};
 
 #[test_case(
@@ -111,3 +113,63 @@ fn test_allocate_segment() {
     let [ptr] = &digits[..] else {panic!("Number not in index range.");};
     assert_eq!(memory[*ptr as usize], Some(BigInt::from(1337)));
 }
+
+#[test]
+fn test_trace() {
+    let TracedRun { trace, .. } = run_function_with_trace(
+        casm! {
+            [ap] = 5, ap++;
+            [ap] = [ap - 1] + 2, ap++;
+            ret;
+        }
+        .instructions,
+    )
+    .expect("Running code failed.");
+    assert_eq!(trace.iter().map(|entry| entry.pc).collect_vec(), vec![0, 2, 4]);
+    assert_eq!(trace[1].ap, trace[0].ap + 1);
+}
+
+#[test]
+fn test_failure_pc() {
+    let failure = run_function_with_trace(
+        casm! {
+            [ap] = 5, ap++;
+            [ap - 1] = 6;
+            ret;
+        }
+        .instructions,
+    )
+    .expect_err("Running code should fail.");
+    assert_eq!(failure.pc, 2);
+}
+
+#[test]
+fn test_failure_pc_trace() {
+    let failure = run_function_with_trace(
+        casm! {
+            [ap] = 5, ap++;
+            call rel 3;
+            ret;
+            [fp - 3] = 6;
+            ret;
+        }
+        .instructions,
+    )
+    .expect_err("Running code should fail.");
+    assert_eq!(failure.pc, 5);
+    assert_eq!(failure.pc_trace, vec![0, 2]);
+}
+
+#[test]
+fn test_failure_syscall_without_handler() {
+    let mut function = casm! {
+        [ap] = 5, ap++;
+        ret;
+    };
+    function.instructions[1]
+        .hints
+        .push(Hint::SystemCall { syscall_ptr: ResOperand::Deref(deref!([ap - 1])) });
+    let failure =
+        run_function_with_trace(function.instructions).expect_err("Running code should fail.");
+    assert_eq!(failure.pc, 2);
+}
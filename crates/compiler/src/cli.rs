@@ -3,6 +3,7 @@ use std::path::PathBuf;
 
 use anyhow::Context;
 use clap::Parser;
+use compiler::diagnostics::MessageFormat;
 use compiler::{compile_cairo_project_at_path, CompilerConfig};
 use utils::logging::init_logging;
 
@@ -18,6 +19,12 @@ struct Args {
     /// Replaces sierra ids with human-readable ones.
     #[arg(short, long, default_value_t = false)]
     replace_ids: bool,
+    /// The format of the reported diagnostics.
+    #[arg(long, value_enum, default_value_t = MessageFormat::Human)]
+    message_format: MessageFormat,
+    /// Fails the compilation on warnings, and not only on errors.
+    #[arg(long, default_value_t = false)]
+    deny_warnings: bool,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -28,7 +35,12 @@ fn main() -> anyhow::Result<()> {
 
     let sierra_program = compile_cairo_project_at_path(
         &args.path,
-        CompilerConfig { replace_ids: args.replace_ids, ..CompilerConfig::default() },
+        CompilerConfig {
+            replace_ids: args.replace_ids,
+            message_format: args.message_format,
+            deny_warnings: args.deny_warnings,
+            ..CompilerConfig::default()
+        },
     )?;
 
     match args.output {
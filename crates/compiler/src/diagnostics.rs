@@ -1,35 +1,67 @@
+use clap::ValueEnum;
 use defs::db::DefsGroup;
 use defs::ids::ModuleId;
+use diagnostics::{
+    DiagnosticEntry, Diagnostics, Severity, StructuredDiagnostic, StructuredLocation,
+};
 use filesystem::db::FilesGroup;
-use filesystem::ids::FileLongId;
+use filesystem::ids::{CrateLongId, FileLongId};
 use lowering::db::LoweringGroup;
 use parser::db::ParserGroup;
 use semantic::db::SemanticGroup;
+use semantic::diagnostic::SemanticDiagnosticKind;
 use sierra_generator::db::SierraGenGroup;
 
 use crate::db::RootDatabase;
 
-/// Checks if there are diagnostics and reports them to the provided callback as strings.
+/// The format in which diagnostics are reported.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MessageFormat {
+    /// Human readable messages, with the relevant source lines.
+    #[default]
+    Human,
+    /// A JSON object per line for each diagnostic.
+    Json,
+}
+
+/// Checks if there are diagnostics and reports them to the provided callback as strings, in the
+/// given format. Lint warnings are reported for all the crates except the core library.
 ///
 /// # Returns
 ///
-/// Returns `true` if diagnostics were found.
+/// Returns `true` if errors were found, or if warnings were found and `deny_warnings` is set.
 pub fn check_diagnostics(
     db: &mut RootDatabase,
     on_diagnostic: Option<Box<dyn FnMut(String)>>,
+    message_format: MessageFormat,
+    deny_warnings: bool,
 ) -> bool {
     let mut on_diagnostic = on_diagnostic.unwrap_or_else(|| Box::new(|_| ()));
 
     let mut found_diagnostics = false;
+    let mut found_warnings = false;
+    let core_crate = db.intern_crate(CrateLongId("core".into()));
     for crate_id in db.crates() {
         for module_id in &*db.crate_modules(crate_id) {
             for file_id in db.module_files(*module_id).unwrap_or_default() {
                 if db.file_content(file_id).is_none() {
                     if let ModuleId::CrateRoot(_) = *module_id {
                         match db.lookup_intern_file(file_id) {
-                            FileLongId::OnDisk(path) => {
-                                on_diagnostic(format!("{} not found", path.display()))
-                            }
+                            FileLongId::OnDisk(path) => on_diagnostic(match message_format {
+                                MessageFormat::Human => format!("{} not found", path.display()),
+                                MessageFormat::Json => json_line(&StructuredDiagnostic {
+                                    severity: Severity::Error,
+                                    code: SemanticDiagnosticKind::FileNotFound.code(),
+                                    message: "File not found.".into(),
+                                    location: StructuredLocation {
+                                        file: path.display().to_string(),
+                                        start: None,
+                                        end: None,
+                                    },
+                                    related: vec![],
+                                    help: None,
+                                }),
+                            }),
                             FileLongId::Virtual(_) => panic!("Missing virtual file."),
                         }
                         found_diagnostics = true;
@@ -38,7 +70,7 @@ pub fn check_diagnostics(
                     let diag = db.file_syntax_diagnostics(file_id);
                     if !diag.get_all().is_empty() {
                         found_diagnostics = true;
-                        on_diagnostic(diag.format(db));
+                        on_diagnostic(format_diagnostics(&diag, db, message_format));
                     }
                 }
             }
@@ -46,29 +78,64 @@ pub fn check_diagnostics(
             if let Some(diag) = db.module_semantic_diagnostics(*module_id) {
                 if !diag.get_all().is_empty() {
                     found_diagnostics = true;
-                    on_diagnostic(diag.format(db));
+                    on_diagnostic(format_diagnostics(&diag, db, message_format));
                 }
             }
 
             if let Some(diag) = db.module_lowering_diagnostics(*module_id) {
                 if !diag.get_all().is_empty() {
                     found_diagnostics = true;
-                    on_diagnostic(diag.format(db));
+                    on_diagnostic(format_diagnostics(&diag, db, message_format));
                 }
             }
 
             let diag = db.module_sierra_diagnostics(*module_id);
             if !diag.get_all().is_empty() {
                 found_diagnostics = true;
-                on_diagnostic(diag.format(db));
+                on_diagnostic(format_diagnostics(&diag, db, message_format));
+            }
+
+            if crate_id == core_crate {
+                continue;
+            }
+            if let Some(diag) = db.module_lint_diagnostics(*module_id) {
+                if !diag.get_all().is_empty() {
+                    found_warnings = true;
+                    on_diagnostic(format_diagnostics(&diag, db, message_format));
+                }
             }
         }
     }
-    found_diagnostics
+    found_diagnostics || (deny_warnings && found_warnings)
 }
 
 pub fn check_and_eprint_diagnostics(db: &mut RootDatabase) -> bool {
-    check_diagnostics(db, Some(Box::new(eprint_diagnostic)))
+    check_and_eprint_diagnostics_with_format(db, MessageFormat::Human)
+}
+
+pub fn check_and_eprint_diagnostics_with_format(
+    db: &mut RootDatabase,
+    message_format: MessageFormat,
+) -> bool {
+    check_diagnostics(db, Some(Box::new(eprint_diagnostic)), message_format, false)
+}
+
+/// Formats the diagnostics in the given format.
+fn format_diagnostics<TEntry: DiagnosticEntry>(
+    diagnostics: &Diagnostics<TEntry>,
+    db: &TEntry::DbType,
+    message_format: MessageFormat,
+) -> String {
+    match message_format {
+        MessageFormat::Human => diagnostics.format(db),
+        MessageFormat::Json => diagnostics.structured(db).iter().map(json_line).collect(),
+    }
+}
+
+/// Returns the diagnostic as a single line of JSON.
+fn json_line(diagnostic: &StructuredDiagnostic) -> String {
+    let json = serde_json::to_string(diagnostic).expect("Diagnostics are always serializable.");
+    format!("{json}\n")
 }
 
 pub fn eprint_diagnostic(diag: String) {
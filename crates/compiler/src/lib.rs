@@ -2,7 +2,7 @@ use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::{bail, Context, Result};
-use diagnostics::{check_diagnostics, eprint_diagnostic};
+use diagnostics::{check_diagnostics, eprint_diagnostic, MessageFormat};
 use filesystem::db::FilesGroupEx;
 use filesystem::ids::CrateId;
 use sierra::program::Program;
@@ -19,13 +19,24 @@ pub mod project;
 pub struct CompilerConfig {
     pub on_diagnostic: Option<Box<dyn FnMut(String)>>,
 
+    /// The format in which diagnostics are passed to `on_diagnostic`.
+    pub message_format: MessageFormat,
+
+    /// Fails the compilation on warnings, and not only on errors.
+    pub deny_warnings: bool,
+
     /// Replaces sierra ids with human-readable ones.
     pub replace_ids: bool,
 }
 
 impl Default for CompilerConfig {
     fn default() -> Self {
-        CompilerConfig { on_diagnostic: Some(Box::new(eprint_diagnostic)), replace_ids: false }
+        CompilerConfig {
+            on_diagnostic: Some(Box::new(eprint_diagnostic)),
+            message_format: MessageFormat::Human,
+            deny_warnings: false,
+            replace_ids: false,
+        }
     }
 }
 
@@ -56,7 +67,12 @@ fn compile_prepared_db(
     main_crate_ids: Vec<CrateId>,
     compiler_config: CompilerConfig,
 ) -> Result<SierraProgram> {
-    if check_diagnostics(&mut db, compiler_config.on_diagnostic) {
+    if check_diagnostics(
+        &mut db,
+        compiler_config.on_diagnostic,
+        compiler_config.message_format,
+        compiler_config.deny_warnings,
+    ) {
         bail!("Compilation failed.");
     }
 
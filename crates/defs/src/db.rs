@@ -32,6 +32,10 @@ pub trait DefsGroup:
     #[salsa::interned]
     fn intern_impl_function(&self, id: ImplFunctionLongId) -> ImplFunctionId;
     #[salsa::interned]
+    fn intern_loop_function(&self, id: LoopFunctionLongId) -> LoopFunctionId;
+    #[salsa::interned]
+    fn intern_constant(&self, id: ConstantLongId) -> ConstantId;
+    #[salsa::interned]
     fn intern_struct(&self, id: StructLongId) -> StructId;
     #[salsa::interned]
     fn intern_enum(&self, id: EnumLongId) -> EnumId;
@@ -90,6 +94,8 @@ pub struct PluginResult {
     pub code: Option<(SmolStr, String)>,
     /// Diagnostics.
     pub diagnostics: Vec<PluginDiagnostic>,
+    /// If true - the original item should be removed, and only the generated code should be used.
+    pub remove_original_item: bool,
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -189,6 +195,7 @@ pub struct ModuleData {
     pub submodules: OrderedHashMap<SubmoduleId, ast::ItemModule>,
     pub uses: OrderedHashMap<UseId, ast::ItemUse>,
     pub free_functions: OrderedHashMap<FreeFunctionId, ast::ItemFreeFunction>,
+    pub constants: OrderedHashMap<ConstantId, ast::ItemConstant>,
     pub structs: OrderedHashMap<StructId, ast::ItemStruct>,
     pub enums: OrderedHashMap<EnumId, ast::ItemEnum>,
     pub traits: OrderedHashMap<TraitId, ast::ItemTrait>,
@@ -217,11 +224,13 @@ fn module_data(db: &dyn DefsGroup, module_id: ModuleId) -> Option<ModuleData> {
         res.files.push(file);
         let syntax_file = db.file_syntax(file)?;
         for item in syntax_file.items(syntax_db).elements(syntax_db) {
+            let mut remove_original_item = false;
             for plugin in db.macro_plugins() {
                 let result = plugin.generate_code(db.upcast(), item.clone());
                 for plugin_diag in result.diagnostics {
                     res.plugin_diagnostics.push((module_file_id, plugin_diag));
                 }
+                remove_original_item |= result.remove_original_item;
 
                 let Some((name, content)) = result.code else { continue };
                 let new_file = db.intern_file(FileLongId::Virtual(VirtualFile {
@@ -231,6 +240,9 @@ fn module_data(db: &dyn DefsGroup, module_id: ModuleId) -> Option<ModuleData> {
                 }));
                 file_queue.push_back(new_file);
             }
+            if remove_original_item {
+                continue;
+            }
             match item {
                 ast::Item::Module(module) => {
                     let item_id =
@@ -248,6 +260,11 @@ fn module_data(db: &dyn DefsGroup, module_id: ModuleId) -> Option<ModuleData> {
                     ));
                     res.free_functions.insert(item_id, function);
                 }
+                ast::Item::Constant(constant) => {
+                    let item_id =
+                        db.intern_constant(ConstantLongId(module_file_id, constant.stable_ptr()));
+                    res.constants.insert(item_id, constant);
+                }
                 ast::Item::ExternFunction(extern_function) => {
                     let item_id = db.intern_extern_function(ExternFunctionLongId(
                         module_file_id,
@@ -309,6 +326,10 @@ fn module_items(db: &dyn DefsGroup, module_id: ModuleId) -> Option<ModuleItems>
                 syntax.name(syntax_db).text(syntax_db),
                 ModuleItemId::FreeFunction(*free_function_id),
             )),
+            module_data.constants.iter().map(|(constant_id, syntax)| (
+                syntax.name(syntax_db).text(syntax_db),
+                ModuleItemId::Constant(*constant_id),
+            )),
             module_data.extern_functions.iter().map(|(extern_function_id, syntax)| (
                 syntax.name(syntax_db).text(syntax_db),
                 ModuleItemId::ExternFunction(*extern_function_id),
@@ -20,6 +20,11 @@ impl StableLocation {
         Self { module_file_id, stable_ptr: node.as_syntax_node().stable_ptr() }
     }
 
+    /// Returns the stable pointer of the node at the location.
+    pub fn stable_ptr(&self) -> SyntaxStablePtrId {
+        self.stable_ptr
+    }
+
     /// Returns the [DiagnosticLocation] that corresponds to the [StableLocation].
     pub fn diagnostic_location(&self, db: &(dyn DefsGroup + 'static)) -> DiagnosticLocation {
         let file_id =
@@ -242,6 +242,17 @@ impl ModuleId {
             }
         }
     }
+
+    /// Returns the crate the module belongs to.
+    pub fn owning_crate(&self, db: &dyn DefsGroup) -> CrateId {
+        match self {
+            ModuleId::CrateRoot(crate_id) => *crate_id,
+            ModuleId::Submodule(id) => id.module(db).owning_crate(db),
+            ModuleId::VirtualSubmodule(virtual_submodule_id) => {
+                db.lookup_intern_virtual_submodule(*virtual_submodule_id).parent.owning_crate(db)
+            }
+        }
+    }
 }
 impl DebugWithDb<dyn DefsGroup> for ModuleId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>, db: &dyn DefsGroup) -> std::fmt::Result {
@@ -270,6 +281,7 @@ define_language_element_id_as_enum! {
         Submodule(SubmoduleId),
         Use(UseId),
         FreeFunction(FreeFunctionId),
+        Constant(ConstantId),
         Struct(StructId),
         Enum(EnumId),
         Trait(TraitId),
@@ -311,6 +323,16 @@ define_language_element_id!(
     lookup_intern_impl_function,
     name
 );
+impl PartialOrd for ImplFunctionId {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+impl Ord for ImplFunctionId {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
 impl ImplFunctionId {
     pub fn impl_id(&self, db: &dyn DefsGroup) -> ImplId {
         let ImplFunctionLongId(module_file_id, ptr) = db.lookup_intern_impl_function(*self);
@@ -329,6 +351,42 @@ impl ImplFunctionId {
     }
 }
 
+/// A loop inside a function. Loops are compiled into separate recursive functions.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct LoopFunctionLongId {
+    /// The function in which the loop is defined.
+    pub parent: FunctionWithBodyId,
+    /// The index of the loop expression in the expression arena of the parent function.
+    pub expr_index: usize,
+    pub stable_ptr: ast::ExprPtr,
+}
+define_short_id!(LoopFunctionId, LoopFunctionLongId, DefsGroup, lookup_intern_loop_function);
+impl LoopFunctionId {
+    pub fn parent(&self, db: &dyn DefsGroup) -> FunctionWithBodyId {
+        db.lookup_intern_loop_function(*self).parent
+    }
+    pub fn stable_ptr(&self, db: &dyn DefsGroup) -> ast::ExprPtr {
+        db.lookup_intern_loop_function(*self).stable_ptr
+    }
+}
+impl LanguageElementId for LoopFunctionId {
+    fn module(&self, db: &dyn DefsGroup) -> ModuleId {
+        self.parent(db).module(db)
+    }
+    fn file_index(&self, db: &dyn DefsGroup) -> FileIndex {
+        self.parent(db).file_index(db)
+    }
+    fn untyped_stable_ptr(&self, db: &(dyn DefsGroup + 'static)) -> SyntaxStablePtrId {
+        self.stable_ptr(db).untyped()
+    }
+}
+impl TopLevelLanguageElementId for LoopFunctionId {
+    fn name(&self, db: &dyn DefsGroup) -> SmolStr {
+        let LoopFunctionLongId { parent, expr_index, .. } = db.lookup_intern_loop_function(*self);
+        format!("{}_loop{}", parent.name(db), expr_index).into()
+    }
+}
+
 define_language_element_id!(
     ExternFunctionId,
     ExternFunctionLongId,
@@ -336,6 +394,13 @@ define_language_element_id!(
     lookup_intern_extern_function,
     name
 );
+define_language_element_id!(
+    ConstantId,
+    ConstantLongId,
+    ast::ItemConstant,
+    lookup_intern_constant,
+    name
+);
 define_language_element_id!(StructId, StructLongId, ast::ItemStruct, lookup_intern_struct, name);
 define_language_element_id!(EnumId, EnumLongId, ast::ItemEnum, lookup_intern_enum, name);
 define_language_element_id!(
@@ -424,11 +489,69 @@ define_language_element_id_as_enum! {
         Extern(ExternFunctionId),
         TraitFunction(TraitFunctionId),
         ImplFunction(ImplFunctionId),
+        Loop(LoopFunctionId),
     }
 }
 impl GenericFunctionId {
     pub fn format(&self, db: &(dyn DefsGroup + 'static)) -> String {
-        format!("{}::{}", self.module(db).full_path(db), self.name(db))
+        match self {
+            GenericFunctionId::ImplFunction(impl_function_id) => format!(
+                "{}::{}::{}",
+                self.module(db).full_path(db),
+                impl_function_id.impl_id(db).name(db),
+                self.name(db)
+            ),
+            _ => format!("{}::{}", self.module(db).full_path(db), self.name(db)),
+        }
+    }
+
+    /// Returns the function with a body that this generic function refers to, if any.
+    pub fn function_with_body(&self) -> Option<FunctionWithBodyId> {
+        match self {
+            GenericFunctionId::Free(free_function_id) => {
+                Some(FunctionWithBodyId::Free(*free_function_id))
+            }
+            GenericFunctionId::ImplFunction(impl_function_id) => {
+                Some(FunctionWithBodyId::Impl(*impl_function_id))
+            }
+            GenericFunctionId::Extern(_)
+            | GenericFunctionId::TraitFunction(_)
+            | GenericFunctionId::Loop(_) => None,
+        }
+    }
+}
+
+define_language_element_id_as_enum! {
+    #[toplevel]
+    /// Id for functions that have a body - free functions and impl functions.
+    pub enum FunctionWithBodyId {
+        Free(FreeFunctionId),
+        Impl(ImplFunctionId),
+    }
+}
+impl PartialOrd for FunctionWithBodyId {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for FunctionWithBodyId {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (FunctionWithBodyId::Free(id), FunctionWithBodyId::Free(other_id)) => id.cmp(other_id),
+            (FunctionWithBodyId::Impl(id), FunctionWithBodyId::Impl(other_id)) => id.cmp(other_id),
+            (FunctionWithBodyId::Free(_), FunctionWithBodyId::Impl(_)) => std::cmp::Ordering::Less,
+            (FunctionWithBodyId::Impl(_), FunctionWithBodyId::Free(_)) => {
+                std::cmp::Ordering::Greater
+            }
+        }
+    }
+}
+impl From<FunctionWithBodyId> for GenericFunctionId {
+    fn from(function_with_body_id: FunctionWithBodyId) -> Self {
+        match function_with_body_id {
+            FunctionWithBodyId::Free(id) => GenericFunctionId::Free(id),
+            FunctionWithBodyId::Impl(id) => GenericFunctionId::ImplFunction(id),
+        }
     }
 }
 
@@ -456,6 +579,7 @@ impl OptionFrom<ModuleItemId> for GenericFunctionId {
             ModuleItemId::ExternFunction(id) => Some(GenericFunctionId::Extern(id)),
             ModuleItemId::Submodule(_)
             | ModuleItemId::Use(_)
+            | ModuleItemId::Constant(_)
             | ModuleItemId::Trait(_)
             | ModuleItemId::Impl(_)
             | ModuleItemId::Struct(_)
@@ -475,6 +599,7 @@ impl OptionFrom<ModuleItemId> for GenericTypeId {
             ModuleItemId::Submodule(_)
             | ModuleItemId::Use(_)
             | ModuleItemId::FreeFunction(_)
+            | ModuleItemId::Constant(_)
             | ModuleItemId::Trait(_)
             | ModuleItemId::Impl(_)
             | ModuleItemId::ExternFunction(_) => None,
@@ -171,6 +171,7 @@ impl MacroPlugin for DummyPlugin {
                     format!("func foo(x:{}){{}}", struct_ast.name(db).text(db)),
                 )),
                 diagnostics: vec![],
+                ..Default::default()
             },
             ast::Item::FreeFunction(item) => PluginResult {
                 code: Some(("virt2".into(), "extern type B;".into())),
@@ -178,8 +179,9 @@ impl MacroPlugin for DummyPlugin {
                     stable_ptr: item.stable_ptr().untyped(),
                     message: "bla".into(),
                 }],
+                ..Default::default()
             },
-            _ => PluginResult { code: None, diagnostics: vec![] },
+            _ => PluginResult { code: None, diagnostics: vec![], ..Default::default() },
         }
     }
 }
@@ -2,14 +2,15 @@
 #[path = "diagnostics_test.rs"]
 mod test;
 
-use std::fmt::Write;
+use std::fmt::{Display, Write};
 use std::sync::Arc;
 
 use db_utils::Upcast;
 use filesystem::db::FilesGroup;
-use filesystem::ids::FileId;
-use filesystem::span::TextSpan;
+use filesystem::ids::{FileId, FileLongId};
+use filesystem::span::{TextOffset, TextSpan};
 use itertools::Itertools;
+use serde::Serialize;
 
 use crate::location_marks::get_location_marks;
 
@@ -19,12 +20,113 @@ pub trait DiagnosticEntry: Clone + std::fmt::Debug + Eq + std::hash::Hash {
     type DbType: Upcast<dyn FilesGroup> + ?Sized;
     fn format(&self, db: &Self::DbType) -> String;
     fn location(&self, db: &Self::DbType) -> DiagnosticLocation;
-    // TODO(spapini): Add a way to inspect the diagnostic programmatically, e.g, downcast.
+    /// Returns a stable code identifying the kind of the diagnostic, e.g. `S0003`. A code is never
+    /// reused for another kind, even if its kind is removed.
+    fn code(&self) -> &'static str;
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+    /// Returns secondary locations relevant to the diagnostic, each with a label.
+    fn related(&self, _db: &Self::DbType) -> Vec<RelatedLocation> {
+        vec![]
+    }
+    /// Returns a suggestion of how to resolve the diagnostic, if there is one.
+    fn help(&self, _db: &Self::DbType) -> Option<String> {
+        None
+    }
+}
+
+/// The severity of a diagnostic.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+}
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Note => write!(f, "note"),
+        }
+    }
 }
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct DiagnosticLocation {
     pub file_id: FileId,
     pub span: TextSpan,
 }
+impl DiagnosticLocation {
+    /// Returns the start of the location formatted as `file:line:col`, with 1-based line and column
+    /// numbers.
+    pub fn fmt_location(&self, db: &dyn FilesGroup) -> String {
+        let filename = self.file_id.file_name(db);
+        match self.span.start.position_in_file(db, self.file_id) {
+            Some(pos) => format!("{filename}:{}:{}", pos.line + 1, pos.col + 1),
+            None => format!("{filename}:?"),
+        }
+    }
+
+    /// Returns the location in a form that doesn't require the db to be inspected.
+    pub fn structured(&self, db: &dyn FilesGroup) -> StructuredLocation {
+        let file = match db.lookup_intern_file(self.file_id) {
+            FileLongId::OnDisk(path) => path.display().to_string(),
+            FileLongId::Virtual(_) => self.file_id.file_name(db),
+        };
+        let position = |offset: TextOffset| {
+            offset
+                .position_in_file(db, self.file_id)
+                .map(|pos| StructuredPosition { line: pos.line + 1, col: pos.col + 1 })
+        };
+        StructuredLocation { file, start: position(self.span.start), end: position(self.span.end) }
+    }
+}
+
+/// A secondary location of a diagnostic, with a label explaining its relevance.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RelatedLocation {
+    pub location: DiagnosticLocation,
+    pub label: String,
+}
+
+/// A diagnostic with all of its data resolved, meant to be inspected programmatically or consumed
+/// by external tools.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct StructuredDiagnostic {
+    pub severity: Severity,
+    pub code: &'static str,
+    pub message: String,
+    pub location: StructuredLocation,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub related: Vec<StructuredRelatedLocation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+}
+
+/// A [DiagnosticLocation] with a file path and 1-based line and column numbers.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct StructuredLocation {
+    pub file: String,
+    pub start: Option<StructuredPosition>,
+    pub end: Option<StructuredPosition>,
+}
+
+/// A position in a file, with 1-based line and column numbers.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct StructuredPosition {
+    pub line: usize,
+    pub col: usize,
+}
+
+/// A [RelatedLocation] with a resolved location.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct StructuredRelatedLocation {
+    pub location: StructuredLocation,
+    pub label: String,
+}
 
 /// A builder for Diagnostics, accumulating multiple diagnostic entries.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -65,21 +167,46 @@ impl<TEntry: DiagnosticEntry> Diagnostics<TEntry> {
         let mut res = String::new();
         // Format leaves.
         for entry in &self.0.leaves {
-            let location = entry.location(db);
-            let filename = location.file_id.file_name(db.upcast());
-            let marks = get_location_marks(db.upcast(), &location);
-            let pos = match location.span.start.position_in_file(db.upcast(), location.file_id) {
-                Some(pos) => format!("{}:{}", pos.line + 1, pos.col + 1),
-                None => "?".into(),
-            };
+            let severity = entry.severity();
             let message = entry.format(db);
-            writeln!(res, "error: {message}\n --> {filename}:{pos}\n{marks}\n").unwrap();
+            writeln!(res, "{severity}: {message}\n{}", format_location(db, &entry.location(db)))
+                .unwrap();
+            for RelatedLocation { location, label } in entry.related(db) {
+                writeln!(res, "note: {label}\n{}", format_location(db, &location)).unwrap();
+            }
+            if let Some(help) = entry.help(db) {
+                writeln!(res, "help: {help}").unwrap();
+            }
+            res.push('\n');
         }
         // Format subtrees.
         res += &self.0.subtrees.iter().map(|subtree| subtree.format(db)).join("");
         res
     }
 
+    /// Returns all the diagnostics, including the ones of the subtrees, in a structured form.
+    pub fn structured(&self, db: &TEntry::DbType) -> Vec<StructuredDiagnostic> {
+        let files_db = db.upcast();
+        self.get_all()
+            .into_iter()
+            .map(|entry| StructuredDiagnostic {
+                severity: entry.severity(),
+                code: entry.code(),
+                message: entry.format(db),
+                location: entry.location(db).structured(files_db),
+                related: entry
+                    .related(db)
+                    .into_iter()
+                    .map(|RelatedLocation { location, label }| StructuredRelatedLocation {
+                        location: location.structured(files_db),
+                        label,
+                    })
+                    .collect(),
+                help: entry.help(db),
+            })
+            .collect()
+    }
+
     /// Asserts that no diagnostic has occurred, panicking with an error message on failure.
     pub fn expect(&self, error_message: &str) {
         assert!(self.0.leaves.is_empty(), "{}\n{:?}", error_message, self);
@@ -110,3 +237,13 @@ impl<TEntry: DiagnosticEntry> Default for Diagnostics<TEntry> {
         Self::new()
     }
 }
+
+/// Formats a location as its position followed by the marked source line.
+fn format_location<TDb: Upcast<dyn FilesGroup> + ?Sized>(
+    db: &TDb,
+    location: &DiagnosticLocation,
+) -> String {
+    let pos = location.fmt_location(db.upcast());
+    let marks = get_location_marks(db.upcast(), location);
+    format!(" --> {pos}\n{marks}")
+}
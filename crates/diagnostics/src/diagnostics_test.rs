@@ -7,7 +7,10 @@ use filesystem::test_utils::FilesDatabaseForTesting;
 use indoc::indoc;
 use test_log::test;
 
-use super::{DiagnosticEntry, DiagnosticLocation, DiagnosticsBuilder};
+use super::{
+    DiagnosticEntry, DiagnosticLocation, DiagnosticsBuilder, RelatedLocation, Severity,
+    StructuredDiagnostic, StructuredLocation, StructuredPosition, StructuredRelatedLocation,
+};
 
 // Test diagnostic.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -27,6 +30,52 @@ impl DiagnosticEntry for SimpleDiag {
             span: TextSpan { start: TextOffset(0), end: TextOffset(6) },
         }
     }
+
+    fn code(&self) -> &'static str {
+        "T0001"
+    }
+}
+
+// Test diagnostic with all the optional data.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct DetailedDiag {
+    file_id: FileId,
+}
+impl DiagnosticEntry for DetailedDiag {
+    type DbType = dyn FilesGroup;
+
+    fn format(&self, _db: &dyn filesystem::db::FilesGroup) -> String {
+        "Detailed diagnostic.".into()
+    }
+
+    fn location(&self, _db: &dyn filesystem::db::FilesGroup) -> DiagnosticLocation {
+        DiagnosticLocation {
+            file_id: self.file_id,
+            span: TextSpan { start: TextOffset(5), end: TextOffset(8) },
+        }
+    }
+
+    fn code(&self) -> &'static str {
+        "T0002"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn related(&self, _db: &dyn filesystem::db::FilesGroup) -> Vec<RelatedLocation> {
+        vec![RelatedLocation {
+            location: DiagnosticLocation {
+                file_id: self.file_id,
+                span: TextSpan { start: TextOffset(1), end: TextOffset(3) },
+            },
+            label: "Related to this.".into(),
+        }]
+    }
+
+    fn help(&self, _db: &dyn filesystem::db::FilesGroup) -> Option<String> {
+        Some("Do something else.".into())
+    }
 }
 
 fn setup() -> (FilesDatabaseForTesting, FileId) {
@@ -58,3 +107,47 @@ fn test_diagnostics() {
         " }
     );
 }
+
+#[test]
+fn test_detailed_diagnostics() {
+    let (db_val, file_id) = setup();
+
+    let mut diagnostics: DiagnosticsBuilder<DetailedDiag> = DiagnosticsBuilder::default();
+    diagnostics.add(DetailedDiag { file_id });
+    let diagnostics = diagnostics.build();
+
+    assert_eq!(
+        diagnostics.format(&db_val),
+        indoc! { "
+            warning: Detailed diagnostic.
+             --> dummy_file.sierra:2:1
+            efg.
+            ^*^
+            note: Related to this.
+             --> dummy_file.sierra:1:2
+            abcd
+             ^^
+            help: Do something else.
+
+        " }
+    );
+    let location = |start: (usize, usize), end: (usize, usize)| StructuredLocation {
+        file: "dummy_file.sierra".into(),
+        start: Some(StructuredPosition { line: start.0, col: start.1 }),
+        end: Some(StructuredPosition { line: end.0, col: end.1 }),
+    };
+    assert_eq!(
+        diagnostics.structured(&db_val),
+        vec![StructuredDiagnostic {
+            severity: Severity::Warning,
+            code: "T0002",
+            message: "Detailed diagnostic.".into(),
+            location: location((2, 1), (2, 4)),
+            related: vec![StructuredRelatedLocation {
+                location: location((1, 2), (1, 4)),
+                label: "Related to this.".into(),
+            }],
+            help: Some("Do something else.".into()),
+        }]
+    );
+}
@@ -4,4 +4,8 @@
 mod diagnostics;
 mod location_marks;
 
-pub use self::diagnostics::{DiagnosticEntry, DiagnosticLocation, Diagnostics, DiagnosticsBuilder};
+pub use self::diagnostics::{
+    DiagnosticEntry, DiagnosticLocation, Diagnostics, DiagnosticsBuilder, RelatedLocation,
+    Severity, StructuredDiagnostic, StructuredLocation, StructuredPosition,
+    StructuredRelatedLocation,
+};
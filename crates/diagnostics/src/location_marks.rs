@@ -1,5 +1,3 @@
-use filesystem::span::TextOffset;
-
 use crate::DiagnosticLocation;
 
 #[cfg(test)]
@@ -12,27 +10,26 @@ pub fn get_location_marks(
 ) -> String {
     // TODO(ilya, 10/10/2023): Handle locations which spread over a few lines.
     let content = db.file_content(location.file_id).expect("File missing from DB.");
-    let summary = db.file_summary(location.file_id).expect("File missing from DB.");
 
     let span = &location.span;
 
-    let first_line_idx =
-        span.start.get_line_number(db, location.file_id).expect("Failed to find location in file.");
-    let first_line_start = summary.line_offsets[first_line_idx].0;
-    let first_line_end = match summary.line_offsets.get(first_line_idx + 1) {
-        Some(TextOffset(offset)) => offset - 1,
-        None => summary.total_length,
-    };
+    // The span offsets are in bytes, so the line boundaries are found in the content itself.
+    let span_start = std::cmp::min(span.start.0, content.len());
+    let first_line_start = content[..span_start].rfind('\n').map_or(0, |idx| idx + 1);
+    let first_line_end =
+        content[span_start..].find('\n').map_or(content.len(), |idx| span_start + idx);
 
     let first_line = &content[first_line_start..first_line_end];
     let mut res = first_line.to_string();
     res.push('\n');
-    for _ in first_line_start..span.start.0 {
+    // The marks are aligned by characters, while the offsets are in bytes.
+    for _ in content[first_line_start..span_start].chars() {
         res.push(' ');
     }
     res.push('^');
 
-    let marker_length = std::cmp::min(first_line_end, span.end.0) - span.start.0;
+    let marked_end = std::cmp::min(first_line_end, span.end.0).max(span_start);
+    let marker_length = content[span_start..marked_end].chars().count();
     if marker_length > 1 {
         for _ in 0..marker_length - 2 {
             res.push('*');
@@ -130,3 +130,58 @@ fn test_location_marks() {
         "}
     );
 }
+
+#[test]
+fn test_location_marks_non_ascii() {
+    let content = indoc! {"
+        let a = 'é';
+        let b = 'é';"};
+
+    let db = FilesDatabaseForTesting::default();
+    let file = db.intern_file(FileLongId::Virtual(VirtualFile {
+        parent: None,
+        name: "name".into(),
+        content: Arc::new(content.into()),
+    }));
+    // Span of the literal, containing a 2 bytes character.
+    let location = DiagnosticLocation {
+        file_id: file,
+        span: TextSpan { start: TextOffset(8), end: TextOffset(12) },
+    };
+
+    assert_eq!(
+        get_location_marks(&db, &location) + "\n",
+        indoc! {"
+            let a = 'é';
+                    ^*^
+        "}
+    );
+
+    // Span following the character.
+    let location = DiagnosticLocation {
+        file_id: file,
+        span: TextSpan { start: TextOffset(12), end: TextOffset(13) },
+    };
+
+    assert_eq!(
+        get_location_marks(&db, &location) + "\n",
+        indoc! {"
+            let a = 'é';
+                       ^
+        "}
+    );
+
+    // Span in a line following the character.
+    let location = DiagnosticLocation {
+        file_id: file,
+        span: TextSpan { start: TextOffset(22), end: TextOffset(26) },
+    };
+
+    assert_eq!(
+        get_location_marks(&db, &location) + "\n",
+        indoc! {"
+            let b = 'é';
+                    ^*^
+        "}
+    );
+}
@@ -10,8 +10,10 @@ use colored::Colorize;
 use diffy::{create_patch, PatchFormatter};
 use filesystem::db::FilesGroup;
 use filesystem::ids::{FileId, FileLongId, VirtualFile};
-use formatter::{get_formatted_file, FormatterConfig};
+use filesystem::span::{TextOffset, TextSpan};
+use formatter::{get_comments, get_formatted_file, get_formatted_range, FormatterConfig};
 use parser::utils::{get_syntax_root_and_diagnostics, SimpleParserDatabase};
+use syntax::node::SyntaxNode;
 use utils::logging::init_logging;
 
 #[derive(Debug)]
@@ -24,6 +26,8 @@ enum Input<'a> {
 enum FormatResult {
     Identical,
     DiffFound,
+    /// Formatting the formatted content changes it.
+    NotIdempotent,
 }
 
 impl<'a> Input<'a> {
@@ -64,8 +68,37 @@ impl<'a> std::fmt::Display for Input<'a> {
     }
 }
 
-/// Formats an input from stdin or file
-fn format_input(input: &Input<'_>, config: &FormatterConfig, check: bool) -> Result<FormatResult> {
+/// Formats the text of `syntax_root`, or only the statements and items in `range` if given.
+/// Returns the formatted text, and the span of the formatted range in it.
+fn format_text(
+    db: &SimpleParserDatabase,
+    syntax_root: &SyntaxNode,
+    text: &str,
+    range: Option<TextSpan>,
+    config: &FormatterConfig,
+) -> (String, Option<TextSpan>) {
+    let Some(range) = range else {
+        return (get_formatted_file(db, syntax_root, config.clone()), None);
+    };
+    let (formatted_span, formatted_range_text) =
+        get_formatted_range(db, syntax_root, range, config.clone());
+    let mut formatted_text = text.to_string();
+    formatted_text
+        .replace_range(formatted_span.start.0..formatted_span.end.0, &formatted_range_text);
+    let formatted_range = TextSpan {
+        start: formatted_span.start,
+        end: TextOffset(formatted_span.start.0 + formatted_range_text.len()),
+    };
+    (formatted_text, Some(formatted_range))
+}
+
+/// Formats an input from stdin or file, or only the given range of it.
+fn format_input(
+    input: &Input<'_>,
+    config: &FormatterConfig,
+    args: &FormatterArgs,
+) -> Result<FormatResult> {
+    let check = args.check;
     let db = SimpleParserDatabase::default();
     let file_id = match input.to_file_id(&db) {
         Ok(value) => value,
@@ -93,7 +126,45 @@ fn format_input(input: &Input<'_>, config: &FormatterConfig, check: bool) -> Res
         bail!("Unable to parse input");
     }
 
-    let formatted_text = get_formatted_file(&db, &syntax_root, config.clone());
+    if matches!(args.range, Some(range) if range.end.0 > original_text.len()) {
+        eprintln!("{}", format!("The range is out of the bounds of {input}.").red());
+        bail!("Range out of bounds");
+    }
+    let (formatted_text, formatted_range) =
+        format_text(&db, &syntax_root, &original_text, args.range, config);
+
+    // Formatting must keep the comments, and their order, unless `use` items are sorted, which
+    // moves their comments with them.
+    let Some(formatted_root) = parse_text(&db, &formatted_text) else {
+        eprintln!("{}", format!("Formatting {input} results in invalid code.").red());
+        bail!("Unable to parse the formatted content");
+    };
+    let comments = |root| {
+        let mut comments = get_comments(&db, root);
+        if config.sort_imports {
+            comments.sort();
+        }
+        comments
+    };
+    if comments(&formatted_root) != comments(&syntax_root) {
+        eprintln!(
+            "{}",
+            format!("Formatting {input} changes its comments. The content was not formatted.")
+                .red()
+        );
+        bail!("Comments changed by formatting");
+    }
+    if args.check_idempotent {
+        // The formatted range is reformatted, as the rest of the text is not formatted.
+        let (reformatted_text, _) =
+            format_text(&db, &formatted_root, &formatted_text, formatted_range, config);
+        if reformatted_text == formatted_text {
+            return Ok(FormatResult::Identical);
+        }
+        println!("Formatting is not idempotent for {input}.");
+        print_diff(input, &formatted_text, &reformatted_text);
+        return Ok(FormatResult::NotIdempotent);
+    }
 
     if &formatted_text == original_text.as_ref() {
         // Always print if input is stdin, unless --check is used
@@ -117,14 +188,20 @@ fn format_input(input: &Input<'_>, config: &FormatterConfig, check: bool) -> Res
     }
 }
 
+/// Parses a text, and returns its syntax root, or None if it has parsing errors.
+fn parse_text(db: &SimpleParserDatabase, text: &str) -> Option<SyntaxNode> {
+    let file_id = db.intern_file(FileLongId::Virtual(VirtualFile {
+        parent: None,
+        name: "<formatted>".into(),
+        content: Arc::new(text.to_string()),
+    }));
+    let (syntax_root, diagnostics) = get_syntax_root_and_diagnostics(db, file_id, text);
+    diagnostics.0.leaves.is_empty().then_some(syntax_root)
+}
+
 /// Formats all files in a directory and sub directories (if specified), and return true if all
 /// files were formatted correctly.
-fn format_directory(
-    path: &str,
-    args: &FormatterArgs,
-    recursion_depth: usize,
-    config: &FormatterConfig,
-) -> bool {
+fn format_directory(path: &str, args: &FormatterArgs, recursion_depth: usize) -> bool {
     if !args.recursive && recursion_depth > 0 {
         return true;
     }
@@ -136,24 +213,15 @@ fn format_directory(
     }
     let mut all_correct = true;
     for sub_path in fs::read_dir(path).unwrap() {
-        all_correct &= format_path(
-            sub_path.unwrap().path().to_str().unwrap(),
-            args,
-            recursion_depth + 1,
-            config,
-        );
+        all_correct &=
+            format_path(sub_path.unwrap().path().to_str().unwrap(), args, recursion_depth + 1);
     }
     all_correct
 }
 
 /// Gets a path to a file or directory and, if exists, calls the respective formatting function,
 /// and returns if it was formatted correctly.
-fn format_path(
-    path: &str,
-    args: &FormatterArgs,
-    recursion_depth: usize,
-    config: &FormatterConfig,
-) -> bool {
+fn format_path(path: &str, args: &FormatterArgs, recursion_depth: usize) -> bool {
     match fs::metadata(path) {
         // File exists
         Ok(metadata) => {
@@ -166,14 +234,15 @@ fn format_path(
                     true
                 } else {
                     eprintln_if_verbose(&format!("Formatting file: {}.", path), args.verbose);
+                    let Some(config) = get_config(Path::new(path), args) else { return false };
                     matches!(
-                        (format_input(&Input::File { path }, config, args.check), args.check),
+                        (format_input(&Input::File { path }, &config, args), args.check),
                         (Ok(FormatResult::Identical), _) | (Ok(FormatResult::DiffFound), false)
                     )
                 }
             } else if metadata.is_dir() {
                 eprintln_if_verbose(&format!("Formatting directory: {}.", path), args.verbose);
-                format_directory(path, args, recursion_depth, config)
+                format_directory(path, args, recursion_depth)
             } else {
                 // A symlink.
                 eprintln!("{}", format!("The file {} is a symlink. It was ignored.", path).red());
@@ -187,6 +256,29 @@ fn format_path(
     }
 }
 
+/// Returns the config to format a path with: the config file given in the arguments, or else the
+/// config file of the nearest directory containing the path, overridden by the other arguments.
+fn get_config(path: &Path, args: &FormatterArgs) -> Option<FormatterConfig> {
+    let config = match &args.config {
+        Some(config_file) => FormatterConfig::from_file(config_file),
+        None => FormatterConfig::discover(path),
+    };
+    let mut config = match config {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("{}", format!("Failed to load the formatter config: {err}").red());
+            return None;
+        }
+    };
+    if let Some(tab_size) = args.tab_size {
+        config.tab_size = tab_size;
+    }
+    if let Some(max_line_length) = args.max_line_length {
+        config.max_line_length = max_line_length;
+    }
+    Some(config)
+}
+
 /// Checks if the file extension is "cairo".
 /// Should only be called with a file path.
 fn is_cairo_file(file_path: &str) -> bool {
@@ -223,30 +315,67 @@ struct FormatterArgs {
     /// just output the diff between the original and the formatted file.
     #[arg(short, long, default_value_t = false)]
     check: bool,
+    /// Idempotency check mode, don't write the formatted files,
+    /// just verify that formatting them again does not change them.
+    #[arg(long, default_value_t = false)]
+    check_idempotent: bool,
     /// Format directories content recursively.
     #[arg(short, long, default_value_t = false)]
     recursive: bool,
     /// Print verbose output.
     #[arg(short, long, default_value_t = false)]
     verbose: bool,
+    /// The config file to use. By default, the `cairofmt.toml` file of the nearest directory
+    /// containing each formatted file is used.
+    #[arg(long)]
+    config: Option<PathBuf>,
+    /// The number of spaces of an indentation level, overriding the config file.
+    #[arg(long)]
+    tab_size: Option<usize>,
+    /// The maximal length of a line, overriding the config file.
+    #[arg(long)]
+    max_line_length: Option<usize>,
+    /// Format only the statements and items in a range of byte offsets, given as `START:END`.
+    /// Requires a single file to format.
+    #[arg(long, value_parser = parse_range)]
+    range: Option<TextSpan>,
     /// A list of files and directories to format. Use "-" for stdin.
     files: Vec<String>,
 }
 
+/// Parses a range of byte offsets, given as `START:END`.
+fn parse_range(range: &str) -> Result<TextSpan, String> {
+    let invalid_range = || format!("Invalid range `{range}`, expected `START:END`.");
+    let (start, end) = range.split_once(':').ok_or_else(invalid_range)?;
+    let start = start.parse().map_err(|_| invalid_range())?;
+    let end = end.parse().map_err(|_| invalid_range())?;
+    if start > end {
+        return Err(invalid_range());
+    }
+    Ok(TextSpan { start: TextOffset(start), end: TextOffset(end) })
+}
+
 fn main() -> ExitCode {
     init_logging(log::LevelFilter::Off);
     log::info!("Starting formatting.");
 
     let args = FormatterArgs::parse();
-    let config = FormatterConfig::default();
     eprintln_if_verbose(
-        &format!("Start formatting. Check: {}, Recursive: {}.", args.check, args.recursive),
+        &format!(
+            "Start formatting. Check: {}, Check idempotent: {}, Recursive: {}.",
+            args.check, args.check_idempotent, args.recursive
+        ),
         args.verbose,
     );
 
+    if args.range.is_some() && (args.files.len() != 1 || Path::new(&args.files[0]).is_dir()) {
+        eprintln!("{}", "A range can only be formatted in a single file.".red());
+        return ExitCode::FAILURE;
+    }
     if args.files.len() == 1 && args.files[0] == "-" {
         // Input comes from stdin
-        match (format_input(&Input::Stdin, &config, args.check), args.check) {
+        let Some(config) = get_config(Path::new("."), &args) else { return ExitCode::FAILURE };
+        match (format_input(&Input::Stdin, &config, &args), args.check) {
             (Ok(FormatResult::Identical), _) => ExitCode::SUCCESS,
             (Ok(FormatResult::DiffFound), false) => ExitCode::SUCCESS,
             _ => ExitCode::FAILURE,
@@ -254,12 +383,16 @@ fn main() -> ExitCode {
     } else {
         let mut all_correct = true;
         if args.files.is_empty() {
-            all_correct = format_path(".", &args, 0, &config);
+            all_correct = format_path(".", &args, 0);
         } else {
             for file in args.files.iter() {
-                all_correct &= format_path(file, &args, 0, &config);
+                all_correct &= format_path(file, &args, 0);
             }
         }
-        if !all_correct && args.check { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+        if !all_correct && (args.check || args.check_idempotent) {
+            ExitCode::FAILURE
+        } else {
+            ExitCode::SUCCESS
+        }
     }
 }
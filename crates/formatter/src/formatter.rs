@@ -3,9 +3,11 @@ use std::fmt;
 use itertools::Itertools;
 use smol_str::SmolStr;
 use syntax::node::db::SyntaxGroup;
+use syntax::node::kind::SyntaxKind;
 use syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use utils::unordered_hash_map::UnorderedHashMap;
 
-use crate::FormatterConfig;
+use crate::{get_comments, FormatterConfig};
 
 #[derive(Clone)]
 /// Defines the break point behaviour.
@@ -76,6 +78,10 @@ enum LineComponent {
     Indent(usize),
     /// An optional break line point, that will be used if the line is too long.
     BreakLinePoint(BreakLinePointProperties),
+    /// A comment within a line, which the line must be broken after. A leading or a dangling
+    /// comment (see [CommentAttachment]) is on its own line, and is also preceded by a line break.
+    /// Comments are not counted in the width of the line, so that they don't cause code to break.
+    Comment { text: String, own_line: bool },
 }
 impl LineComponent {
     pub fn width(&self) -> usize {
@@ -85,6 +91,7 @@ impl LineComponent {
             Self::Space => 1,
             Self::Indent(n) => *n,
             Self::BreakLinePoint(_) => 0,
+            Self::Comment { .. } => 0,
         }
     }
 }
@@ -96,10 +103,25 @@ impl fmt::Display for LineComponent {
             Self::Space => write!(f, " "),
             Self::Indent(n) => write!(f, "{}", " ".repeat(*n)),
             Self::BreakLinePoint(_) => write!(f, ""),
+            Self::Comment { text, .. } => write!(f, "{text}"),
         }
     }
 }
 
+/// The code a comment is attached to, which determines where the comment is placed.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CommentAttachment {
+    /// A comment at the end of a line of code (in the trailing trivia of a token). It stays at the
+    /// end of the line.
+    Trailing,
+    /// A comment on its own line, before code (in the leading trivia of a token). It is placed on
+    /// its own line, indented as the code.
+    Leading,
+    /// A comment on its own line, after the elements of a block or a list and before its closing
+    /// delimiter. It is placed on its own line, indented as the elements.
+    Dangling,
+}
+
 /// Represents a line in the code, separated by optional break line points.
 /// Used to break the line if too long.
 #[derive(Clone)]
@@ -208,46 +230,63 @@ impl LineBuilder {
             vec![]
         }
     }
+    /// Returns the type of the break line points which have the minimum precedence from within all
+    /// the break line point children, or None if there are no such break line points.
+    fn get_preceding_break_type(&self) -> Option<BreakLinePointType> {
+        let position = *self.get_preceding_break_points_indices().first()?;
+        match &self.children[position] {
+            LineComponent::BreakLinePoint(properties) => Some(properties.break_type.clone()),
+            _ => unreachable!("Index is taken from a break line points positions vector."),
+        }
+    }
     /// Returns a vec of strings, representing the code in the LineTree,
     /// each one with len < max_Width (if possible).
-    fn to_broken_string_by_width(&self, max_line_width: usize, tab_size: usize) -> Vec<String> {
+    fn to_broken_string_by_width(
+        &self,
+        max_line_width: usize,
+        config: &FormatterConfig,
+    ) -> Vec<String> {
         // TODO(gil): consider using a write buffer similar to 'write!()' to reduce string
         // allocations.
-        if self.width() < max_line_width {
+        let fits_width = self.width() < max_line_width;
+        if fits_width && !self.has_inner_comment() {
             return vec![self.to_string()];
         }
-        let mut sub_builders = self.to_broken_tree_by_width(max_line_width, tab_size);
-        // While the line is not broken into several lines, try to flatten it and then break it.
-        while sub_builders.len() == 1 {
-            if !sub_builders[0].is_flat() {
-                sub_builders =
-                    sub_builders[0].flatten().to_broken_tree_by_width(max_line_width, tab_size);
-            } else {
+        // While the line has no break line points, try to flatten it.
+        let mut builder = self.clone();
+        while builder.get_preceding_break_type().is_none() {
+            if builder.is_flat() {
                 // Can't break tree to fit within width
                 // TODO(Gil): Propagate error to user.
-                return vec![self.to_string()];
+                return builder.break_at_comments(config.tab_size);
             }
+            builder = builder.flatten();
+        }
+        // A line that fits is only broken for its comments. It is broken into the elements of a
+        // list, which keeps each comment with the element it is attached to, and otherwise only
+        // after its comments.
+        if fits_width && !builder.get_preceding_break_type().unwrap().is_list_break() {
+            return builder.break_at_comments(config.tab_size);
         }
         // Keep breaking recursively the new lines if they are still too long
-        sub_builders
+        builder
+            .to_broken_tree_by_width(max_line_width, config)
             .iter()
-            .flat_map(|tree| tree.to_broken_string_by_width(max_line_width, tab_size))
+            .flat_map(|tree| tree.to_broken_string_by_width(max_line_width, config))
             .collect()
     }
     /// Breaks the LineTree into a vector of LineTrees
     /// according to the lowest precedence break line point found in the LineTree.
-    fn to_broken_tree_by_width(&self, max_line_width: usize, tab_size: usize) -> Vec<LineBuilder> {
-        let mut breaking_positions = self.get_preceding_break_points_indices();
-        if breaking_positions.is_empty() {
+    fn to_broken_tree_by_width(
+        &self,
+        max_line_width: usize,
+        config: &FormatterConfig,
+    ) -> Vec<LineBuilder> {
+        let tab_size = config.tab_size;
+        let Some(mut break_line_point_type) = self.get_preceding_break_type() else {
             return vec![self.clone()];
-        }
-        let mut break_line_point_type = if let LineComponent::BreakLinePoint(properties) =
-            &self.children[breaking_positions[0]]
-        {
-            properties.break_type.clone()
-        } else {
-            unreachable!("Index is taken from a break line points positions vector.");
         };
+        let mut breaking_positions = self.get_preceding_break_points_indices();
         let mut trees: Vec<LineBuilder> = vec![LineBuilder::new()];
         let mut added_indent = 0;
         let mut prev_position = 0;
@@ -273,6 +312,17 @@ impl LineBuilder {
                             trees.last_mut().unwrap().push_space();
                         }
                     }
+                    // In a breakable list, comments before the trailing tokens are inside the
+                    // list, and are indented as its elements.
+                    LineComponent::Comment { own_line: true, .. }
+                        if break_line_point_type.is_list_break()
+                            && i == breaking_positions.len() - 1
+                            && trees.len() > 1
+                            && trees.last().unwrap().is_only_indents() =>
+                    {
+                        let n_trees = trees.len();
+                        trees[n_trees - 2].push_child(self.children[j].clone());
+                    }
                     _ => trees.last_mut().unwrap().push_child(self.children[j].clone()),
                 }
             }
@@ -289,6 +339,9 @@ impl LineBuilder {
                     // (e.g. before "};" to unindent the closing brace)
                     if i == breaking_positions.len() - 2 {
                         added_indent -= tab_size;
+                        if config.trailing_comma {
+                            trees.last_mut().unwrap().push_trailing_comma();
+                        }
                     }
                 }
                 trees.push(LineBuilder::new());
@@ -322,15 +375,19 @@ impl LineBuilder {
     /// Creates a string of the code represented in the builder. The string may represent
     /// several lines (separated by '\n'), where each line length is
     /// less than max_line_width (if possible).
-    /// Each line is prepended by the leading
-    pub fn build(&self, max_line_width: usize, tab_size: usize, leading_indent: &str) -> String {
-        self.to_broken_string_by_width(max_line_width, tab_size)
+    /// Each line is prepended by the leading indentation, and stripped of its trailing spaces.
+    pub fn build(
+        &self,
+        max_line_width: usize,
+        config: &FormatterConfig,
+        leading_indent: &str,
+    ) -> String {
+        self.to_broken_string_by_width(max_line_width, config)
             .iter()
-            .map(
-                |line| {
-                    if line.is_empty() { "".to_string() } else { leading_indent.to_string() + line }
-                },
-            )
+            .map(|line| {
+                let line = line.trim_end();
+                if line.is_empty() { "".to_string() } else { leading_indent.to_string() + line }
+            })
             .join("\n")
     }
     /// Creates a new LineBuilder where the first subchild which is a LineBuilder, is replaced by
@@ -355,6 +412,100 @@ impl LineBuilder {
     fn is_flat(&self) -> bool {
         !self.children.iter().any(|child| matches!(child, LineComponent::Internal(_)))
     }
+    /// Appends a comma to a line holding the last element of a list, unless it already ends with
+    /// one. The comma is added before trailing comments.
+    fn push_trailing_comma(&mut self) {
+        let Some(last_position) = self.children.iter().rposition(|child| {
+            matches!(child, LineComponent::Token(_) | LineComponent::Internal(_))
+        }) else {
+            return;
+        };
+        if !matches!(&self.children[last_position], LineComponent::Token(s) if s == ",") {
+            self.children.insert(last_position + 1, LineComponent::Token(",".to_string()));
+        }
+    }
+    /// Returns the components of the line, with the internal LineBuilders replaced by their
+    /// components, recursively.
+    fn leaves(&self) -> Vec<&LineComponent> {
+        self.children
+            .iter()
+            .flat_map(|child| match child {
+                LineComponent::Internal(sub_builder) => sub_builder.leaves(),
+                _ => vec![child],
+            })
+            .collect()
+    }
+    /// Returns whether the line contains a comment that must be followed by a line break, or a
+    /// comment on its own line that must be preceded by one.
+    fn has_inner_comment(&self) -> bool {
+        let leaves = self.leaves();
+        let is_content = |leaf: &&LineComponent| {
+            matches!(leaf, LineComponent::Token(_) | LineComponent::Comment { .. })
+        };
+        leaves.iter().enumerate().any(|(i, leaf)| match leaf {
+            LineComponent::Comment { own_line, .. } => {
+                leaves[i + 1..].iter().any(is_content)
+                    || (*own_line && leaves[..i].iter().any(is_content))
+            }
+            _ => false,
+        })
+    }
+    /// Breaks the line right after each comment, and right before each comment on its own line.
+    /// The new lines are indented as the first line, and the lines in the middle of code that
+    /// started in a previous line are further indented by a tab.
+    fn break_at_comments(&self, tab_size: usize) -> Vec<String> {
+        let leaves = self.leaves();
+        let indents: Vec<_> = leaves
+            .iter()
+            .take_while(|leaf| matches!(leaf, LineComponent::Indent(_)))
+            .map(|leaf| (*leaf).clone())
+            .collect();
+        let mut continuation_indents = indents.clone();
+        continuation_indents.push(LineComponent::Indent(tab_size));
+        let mut lines = vec![];
+        let mut line = LineBuilder { children: indents.clone(), is_open: true };
+        let last_code_position =
+            leaves.iter().rposition(|leaf| matches!(leaf, LineComponent::Token(_)));
+        // Whether code was already placed in a previous line.
+        let mut is_after_code = false;
+        for (i, leaf) in leaves.into_iter().enumerate().skip(indents.len()) {
+            let breaks_before = matches!(leaf, LineComponent::Comment { own_line: true, .. })
+                && !line.is_only_indents();
+            if breaks_before {
+                while matches!(line.children.last(), Some(LineComponent::Space)) {
+                    line.children.pop();
+                }
+            }
+            if breaks_before || matches!(line.children.last(), Some(LineComponent::Comment { .. }))
+            {
+                is_after_code |= line.has_code();
+                // Lines in the middle of the code continue it, unlike the comments after it.
+                let is_continuation = is_after_code && Some(i) <= last_code_position;
+                let new_line = LineBuilder {
+                    children: if is_continuation {
+                        continuation_indents.clone()
+                    } else {
+                        indents.clone()
+                    },
+                    is_open: true,
+                };
+                lines.push(std::mem::replace(&mut line, new_line));
+            }
+            // Ignore spaces at the start of a line.
+            if matches!(leaf, LineComponent::Space) && line.is_only_indents() {
+                continue;
+            }
+            line.children.push(leaf.clone());
+        }
+        if !line.is_only_indents() || lines.is_empty() {
+            lines.push(line);
+        }
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+    /// Returns whether the line contains code, and not only comments.
+    fn has_code(&self) -> bool {
+        self.leaves().iter().any(|leaf| matches!(leaf, LineComponent::Token(_)))
+    }
     /// Returns whether the line contains only indents.
     fn is_only_indents(&self) -> bool {
         !self.children.iter().any(|child| !matches!(child, LineComponent::Indent(_)))
@@ -434,6 +585,11 @@ pub struct Formatter<'a> {
     current_indent: usize,
     /// The number of empty lines allowed after the current node.
     empty_lines_allowance: usize,
+    /// The number of empty lines required before the next node.
+    empty_lines_requirement: usize,
+    /// The trivia to format instead of the leading trivia of terminals, for the `use` items whose
+    /// comments are separated from them by sorting.
+    leading_trivia_overrides: UnorderedHashMap<SyntaxNode, Vec<ast::Trivium>>,
 }
 
 impl<'a> Formatter<'a> {
@@ -447,6 +603,8 @@ impl<'a> Formatter<'a> {
             indents_list,
             current_indent: 0,
             empty_lines_allowance: 0,
+            empty_lines_requirement: 0,
+            leading_trivia_overrides: UnorderedHashMap::default(),
         }
     }
     /// Returns the result of the formatter after format_node was called.
@@ -472,7 +630,8 @@ impl<'a> Formatter<'a> {
     /// Formats an internal node and appends the formatted string to the result.
     fn format_internal(&mut self, syntax_node: &SyntaxNode, no_space_after: bool) {
         let indent_change = usize::from(syntax_node.should_change_indent(self.db));
-        let allowed_empty_between = syntax_node.allowed_empty_between(self.db);
+        let is_item_list = syntax_node.kind(self.db) == SyntaxKind::ItemList;
+        let allowed_empty_between = self.allowed_empty_between(syntax_node);
         let no_space_after = no_space_after || syntax_node.force_no_space_after(self.db);
 
         if syntax_node.is_protected_breaking_node(self.db) {
@@ -481,18 +640,24 @@ impl<'a> Formatter<'a> {
         if syntax_node.is_breakable_list(self.db) {
             self.append_break_line_point(syntax_node.get_break_line_point_properties(self.db));
         }
-        let children = syntax_node.children(self.db);
+        let mut children: Vec<_> = syntax_node.children(self.db).collect();
+        if is_item_list {
+            children = self.arrange_uses(children);
+        }
         let n_children = children.len();
-        for (i, child) in children.enumerate() {
+        for (i, child) in children.iter().enumerate() {
             if child.width(self.db) == 0 {
                 continue;
             }
+            if i > 0 {
+                self.require_empty_lines_between(&children[i - 1], child);
+            }
 
             self.current_indent += indent_change;
             if self.line_state.is_empty() {
                 self.line_state.reset(self.get_indentation())
             }
-            self.format_node(&child, no_space_after && i == n_children - 1);
+            self.format_node(child, no_space_after && i == n_children - 1);
 
             self.empty_lines_allowance = allowed_empty_between;
             self.current_indent -= indent_change;
@@ -508,6 +673,156 @@ impl<'a> Formatter<'a> {
             self.line_state.line_buffer.close_sub_builder();
         }
     }
+    /// Appends the formatted string of consecutive siblings, which are not necessarily children of
+    /// the root, to the result. The siblings are indented according to their ancestors.
+    pub fn format_siblings(&mut self, nodes: &[SyntaxNode]) {
+        let Some(parent) = nodes.first().and_then(|node| node.parent()) else { return };
+        let mut ancestor = Some(parent.clone());
+        while let Some(node) = ancestor {
+            self.current_indent += usize::from(node.should_change_indent(self.db));
+            ancestor = node.parent();
+        }
+        let allowed_empty_between = self.allowed_empty_between(&parent);
+        let mut previous_sibling =
+            parent.children(self.db).take_while(|child| child.offset() < nodes[0].offset()).last();
+        // Empty lines are kept before a node, unless it is the first child.
+        if previous_sibling.is_some() {
+            self.empty_lines_allowance = allowed_empty_between;
+        }
+        for node in nodes {
+            if let Some(previous_sibling) = &previous_sibling {
+                self.require_empty_lines_between(previous_sibling, node);
+            }
+            if self.line_state.is_empty() {
+                self.line_state.reset(self.get_indentation())
+            }
+            self.format_node(node, false);
+            self.empty_lines_allowance = allowed_empty_between;
+            previous_sibling = Some(node.clone());
+        }
+        if !self.line_state.is_empty() {
+            self.finalize_line();
+        }
+    }
+    /// Returns the number of allowed empty lines between two consecutive children of a node.
+    fn allowed_empty_between(&self, syntax_node: &SyntaxNode) -> usize {
+        if syntax_node.kind(self.db) == SyntaxKind::ItemList {
+            self.config.max_empty_lines_between_items
+        } else {
+            syntax_node.allowed_empty_between(self.db)
+        }
+    }
+    /// Requires an empty line between two consecutive items, if the config requires it.
+    fn require_empty_lines_between(&mut self, previous: &SyntaxNode, next: &SyntaxNode) {
+        if !self.config.empty_line_between_items
+            || next.parent().map(|parent| parent.kind(self.db)) != Some(SyntaxKind::ItemList)
+        {
+            return;
+        }
+        let is_use = |node: &SyntaxNode| node.kind(self.db) == SyntaxKind::ItemUse;
+        if !(is_use(previous) && is_use(next)) {
+            self.empty_lines_requirement = 1;
+        }
+    }
+    /// Sorts and deduplicates the groups of consecutive `use` items among the children of an item
+    /// list, according to the config. Groups are separated by empty lines.
+    /// The comments on the lines right above a `use` item, and the comment at the end of its line,
+    /// are attached to it and move with it. The comments above the empty line that starts a group
+    /// stay at the start of the group.
+    fn arrange_uses(&mut self, children: Vec<SyntaxNode>) -> Vec<SyntaxNode> {
+        if !self.config.sort_imports && !self.config.dedup_imports {
+            return children;
+        }
+        let is_use = |node: &SyntaxNode| node.kind(self.db) == SyntaxKind::ItemUse;
+        // The text of a node, ignoring whitespaces and the comments around it.
+        let key = |node: &SyntaxNode| {
+            node.clone().get_text_without_trivia(self.db).split_whitespace().join("")
+        };
+        let mut groups: Vec<Vec<SyntaxNode>> = vec![];
+        for child in children {
+            match groups.last_mut() {
+                Some(group)
+                    if is_use(&group[0]) == is_use(&child)
+                        && self.detached_leading_trivia_len(&child) == 0 =>
+                {
+                    group.push(child)
+                }
+                _ => groups.push(vec![child]),
+            }
+        }
+        let mut arranged = vec![];
+        for mut group in groups {
+            if is_use(&group[0]) {
+                let first = group[0].clone();
+                if self.config.sort_imports {
+                    group.sort_by_cached_key(|node| {
+                        key(&ast::ItemUse::from_syntax_node(self.db, node.clone())
+                            .name(self.db)
+                            .as_syntax_node())
+                    });
+                }
+                if self.config.dedup_imports {
+                    // Comments are never dropped, so duplicates with comments are kept.
+                    let mut seen = vec![];
+                    group.retain(|node| {
+                        let node_key = key(node);
+                        let is_new = !seen.contains(&node_key);
+                        seen.push(node_key);
+                        is_new || !get_comments(self.db, node).is_empty()
+                    });
+                }
+                // The first item is never removed, as it precedes its duplicates.
+                if group[0] != first {
+                    self.move_detached_leading_trivia(&first, &group[0]);
+                }
+            }
+            arranged.extend(group);
+        }
+        arranged
+    }
+    /// Returns the number of leading trivia elements of a node which are not attached to it, i.e.
+    /// the elements up to its last preceding empty line.
+    fn detached_leading_trivia_len(&self, node: &SyntaxNode) -> usize {
+        let mut detached_len = 0;
+        let mut is_empty_line = true;
+        for (i, trivium) in self.leading_trivia(node).iter().enumerate() {
+            match trivium {
+                ast::Trivium::Newline(_) => {
+                    if is_empty_line {
+                        detached_len = i + 1;
+                    }
+                    is_empty_line = true;
+                }
+                ast::Trivium::Whitespace(_) => {}
+                _ => is_empty_line = false,
+            }
+        }
+        detached_len
+    }
+    /// Moves the detached leading trivia of a node, which was the first of its group, to the node
+    /// that is now the first of the group.
+    fn move_detached_leading_trivia(&mut self, from: &SyntaxNode, to: &SyntaxNode) {
+        let detached_len = self.detached_leading_trivia_len(from);
+        if detached_len == 0 {
+            return;
+        }
+        let mut from_trivia = self.leading_trivia(from);
+        let mut to_trivia: Vec<_> = from_trivia.drain(..detached_len).collect();
+        to_trivia.extend(self.leading_trivia(to));
+        self.leading_trivia_overrides.insert(first_terminal(self.db, from), from_trivia);
+        self.leading_trivia_overrides.insert(first_terminal(self.db, to), to_trivia);
+    }
+    /// Returns the leading trivia of the first terminal of a node.
+    fn leading_trivia(&self, node: &SyntaxNode) -> Vec<ast::Trivium> {
+        let terminal = first_terminal(self.db, node);
+        match self.leading_trivia_overrides.get(&terminal) {
+            Some(trivia) => trivia.clone(),
+            None => {
+                ast::Trivia::from_syntax_node(self.db, terminal.children(self.db).next().unwrap())
+                    .elements(self.db)
+            }
+        }
+    }
     /// Formats a terminal node and appends the formatted string to the result.
     fn format_terminal(&mut self, syntax_node: &SyntaxNode, no_space_after: bool) {
         // TODO(spapini): Introduce a Terminal and a Token enum in ast.rs to make this cleaner.
@@ -516,20 +831,71 @@ impl<'a> Formatter<'a> {
         let token = children.next().unwrap();
         let trailing_trivia = ast::Trivia::from_syntax_node(self.db, children.next().unwrap());
 
+        let leading_trivia = self
+            .leading_trivia_overrides
+            .remove(syntax_node)
+            .unwrap_or_else(|| leading_trivia.elements(self.db));
         // The first newlines is the leading trivia correspond exactly to empty lines.
-        self.format_trivia(leading_trivia, self.empty_lines_allowance);
+        self.add_required_empty_lines(&leading_trivia);
+        // Comments before a closing delimiter are after the elements of the delimited code.
+        let leading_attachment = if matches!(
+            syntax_node.kind(self.db),
+            SyntaxKind::TerminalRBrace | SyntaxKind::TerminalRParen | SyntaxKind::TerminalRBrack
+        ) {
+            CommentAttachment::Dangling
+        } else {
+            CommentAttachment::Leading
+        };
+        self.format_trivia(leading_trivia, self.empty_lines_allowance, leading_attachment);
         self.empty_lines_allowance = 0;
         self.format_token(&token, no_space_after || syntax_node.force_no_space_after(self.db));
         let allowed_newlines = usize::from(syntax_node.allow_newline_after(self.db));
-        self.format_trivia(trailing_trivia, allowed_newlines);
-    }
-    /// Appends a trivia node (if needed) to the result.
-    fn format_trivia(&mut self, trivia: syntax::node::ast::Trivia, mut allowed_newlines: usize) {
-        for trivium in trivia.elements(self.db) {
+        self.format_trivia(
+            trailing_trivia.elements(self.db),
+            allowed_newlines,
+            CommentAttachment::Trailing,
+        );
+    }
+    /// Appends the empty lines that are required before a node, and are missing from its leading
+    /// trivia.
+    fn add_required_empty_lines(&mut self, leading_trivia: &[ast::Trivium]) {
+        let existing_empty_lines = leading_trivia
+            .iter()
+            .take_while(|trivium| {
+                matches!(trivium, ast::Trivium::Whitespace(_) | ast::Trivium::Newline(_))
+            })
+            .filter(|trivium| matches!(trivium, ast::Trivium::Newline(_)))
+            .count()
+            .min(self.empty_lines_allowance);
+        for _ in existing_empty_lines..self.empty_lines_requirement {
+            self.finalize_line();
+        }
+        self.empty_lines_requirement = 0;
+    }
+    /// Appends trivia elements (if needed) to the result. Their comments are placed according to
+    /// the code they are attached to.
+    fn format_trivia(
+        &mut self,
+        trivia: Vec<ast::Trivium>,
+        mut allowed_newlines: usize,
+        attachment: CommentAttachment,
+    ) {
+        for trivium in trivia {
             match trivium {
                 ast::Trivium::SingleLineComment(_) => {
-                    allowed_newlines = 2;
-                    self.format_token(&trivium.as_syntax_node(), true);
+                    if self.line_state.is_empty() {
+                        // A comment on its own line, between statements or items.
+                        if attachment == CommentAttachment::Dangling {
+                            self.line_state.reset(self.indents(self.current_indent + 1));
+                        }
+                        allowed_newlines = 2;
+                        self.format_token(&trivium.as_syntax_node(), true);
+                    } else {
+                        // A comment within a line is kept in it, and the line is broken after it
+                        // when it is built.
+                        allowed_newlines = 0;
+                        self.append_comment(&trivium.as_syntax_node(), attachment);
+                    }
                 }
                 ast::Trivium::Whitespace(_) => {}
                 ast::Trivium::Newline(_) => {
@@ -544,6 +910,15 @@ impl<'a> Formatter<'a> {
             }
         }
     }
+    /// Appends a comment to the pending line.
+    fn append_comment(&mut self, syntax_node: &SyntaxNode, attachment: CommentAttachment) {
+        let text = syntax_node.text(self.db).unwrap();
+        self.line_state.line_buffer.push_space();
+        self.line_state.line_buffer.push_child(LineComponent::Comment {
+            text: text.trim_end().to_string(),
+            own_line: attachment != CommentAttachment::Trailing,
+        });
+    }
     /// Formats a token node and it to the result.
     fn format_token(&mut self, syntax_node: &SyntaxNode, no_space_after: bool) {
         let no_space_after = no_space_after || syntax_node.force_no_space_after(self.db);
@@ -569,10 +944,14 @@ impl<'a> Formatter<'a> {
     }
     /// Returns the leading indentation according to the current indent and the tab size.
     fn get_indentation(&self) -> String {
-        if self.current_indent < self.indents_list.len() {
-            self.indents_list[self.current_indent].clone()
+        self.indents(self.current_indent)
+    }
+    /// Returns the leading indentation of the given number of tabs.
+    fn indents(&self, n_tabs: usize) -> String {
+        if n_tabs < self.indents_list.len() {
+            self.indents_list[n_tabs].clone()
         } else {
-            " ".repeat(self.config.tab_size * self.current_indent)
+            " ".repeat(self.config.tab_size * n_tabs)
         }
     }
     fn append_newline(&mut self) {
@@ -580,16 +959,30 @@ impl<'a> Formatter<'a> {
     }
     /// Builds the pending line states into a string, and append it to the result.
     fn finalize_line(&mut self) {
+        // The line is indented as it was when it started.
+        let indentation = &self.line_state.indentation;
         self.result.push_str(&self.line_state.line_buffer.build(
-            self.config.max_line_length - self.current_indent * self.config.tab_size,
-            self.config.tab_size,
-            &self.get_indentation(),
+            self.config.max_line_length.saturating_sub(indentation.len()),
+            &self.config,
+            indentation,
         ));
         self.append_newline();
         self.line_state.reset(self.get_indentation());
     }
 }
 
+/// Returns the first terminal of a node, whose leading trivia precedes the code of the node.
+fn first_terminal(db: &dyn SyntaxGroup, node: &SyntaxNode) -> SyntaxNode {
+    // Nodes without terminals (e.g. empty lists) are skipped.
+    fn find(db: &dyn SyntaxGroup, node: &SyntaxNode) -> Option<SyntaxNode> {
+        if node.kind(db).is_terminal() {
+            return Some(node.clone());
+        }
+        node.children(db).find_map(|child| find(db, &child))
+    }
+    find(db, node).expect("A node with code must have a terminal.")
+}
+
 /// Generates the leading indents for reasonable indent sizes
 fn generate_indents_list(config: &FormatterConfig) -> Vec<String> {
     let mut indent_list: Vec<String> = vec![];
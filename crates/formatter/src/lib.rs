@@ -3,12 +3,16 @@
 pub mod formatter;
 pub mod node_properties;
 
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use diagnostics::DiagnosticsBuilder;
 use filesystem::ids::{FileLongId, VirtualFile};
+use filesystem::span::{TextOffset, TextSpan};
 use parser::parser::Parser;
+use serde::{Deserialize, Serialize};
 use syntax::node::db::SyntaxGroup;
+use syntax::node::kind::SyntaxKind;
 use syntax::node::{SyntaxNode, TypedSyntaxNode};
 
 use crate::formatter::Formatter;
@@ -26,6 +30,104 @@ pub fn get_formatted_file(
     formatter.get_result()
 }
 
+/// Formats the statements or items of a file that intersect `span`, leaving the rest of the file
+/// untouched. The span is expanded to whole statements or items, and further to their enclosing
+/// statement or item if they share their lines with other code.
+/// Returns the span of the text that was formatted, with its formatted text.
+pub fn get_formatted_range(
+    db: &dyn SyntaxGroup,
+    syntax_root: &SyntaxNode,
+    span: TextSpan,
+    config: FormatterConfig,
+) -> (TextSpan, String) {
+    match get_range_nodes(db, syntax_root, span) {
+        Some(nodes) => {
+            let formatted_span = TextSpan {
+                start: nodes[0].span(db).start,
+                end: nodes[nodes.len() - 1].span(db).end,
+            };
+            let mut formatter = Formatter::new(db, config);
+            formatter.format_siblings(&nodes);
+            (formatted_span, formatter.get_result())
+        }
+        None => (syntax_root.span(db), get_formatted_file(db, syntax_root, config)),
+    }
+}
+
+/// Returns the consecutive statements or items to format for a span: the children, intersecting
+/// the span, of the innermost list containing it, that span whole lines. Returns None if the whole
+/// file should be formatted.
+fn get_range_nodes(
+    db: &dyn SyntaxGroup,
+    syntax_root: &SyntaxNode,
+    mut span: TextSpan,
+) -> Option<Vec<SyntaxNode>> {
+    let contains = |node: &SyntaxNode, span: TextSpan| {
+        let node_span = node.span(db);
+        node_span.start <= span.start && span.end <= node_span.end
+    };
+    let mut lists = vec![];
+    let mut node = syntax_root.clone();
+    loop {
+        if matches!(node.kind(db), SyntaxKind::StatementList | SyntaxKind::ItemList) {
+            lists.push(node.clone());
+        }
+        let Some(child) = node.children(db).find(|child| contains(child, span)) else { break };
+        node = child;
+    }
+
+    let text = syntax_root.clone().get_text(db);
+    let starts_line = |offset: TextOffset| offset.0 == 0 || text.as_bytes()[offset.0 - 1] == b'\n';
+    let ends_line = |offset: TextOffset| offset.0 == text.len() || starts_line(offset);
+    for list in lists.into_iter().rev() {
+        let children: Vec<_> = list.children(db).collect();
+        let intersects = |child: &SyntaxNode| {
+            let child_span = child.span(db);
+            if span.start == span.end {
+                child_span.start <= span.start && span.start < child_span.end
+            } else {
+                child_span.start < span.end && span.start < child_span.end
+            }
+        };
+        let (Some(mut first), Some(mut last)) =
+            (children.iter().position(intersects), children.iter().rposition(intersects))
+        else {
+            span = list.span(db);
+            continue;
+        };
+        // Nodes that share lines with the range are formatted too.
+        while first > 0 && !starts_line(children[first].span(db).start) {
+            first -= 1;
+        }
+        while last + 1 < children.len() && !ends_line(children[last].span(db).end) {
+            last += 1;
+        }
+        if starts_line(children[first].span(db).start) && ends_line(children[last].span(db).end) {
+            return Some(children[first..=last].to_vec());
+        }
+        span = list.span(db);
+    }
+    None
+}
+
+/// Returns the comments of a syntax tree, in order, without their trailing whitespaces.
+/// Formatting must keep them as they are.
+pub fn get_comments(db: &dyn SyntaxGroup, syntax_root: &SyntaxNode) -> Vec<String> {
+    let mut comments = vec![];
+    collect_comments(db, syntax_root, &mut comments);
+    comments
+}
+
+fn collect_comments(db: &dyn SyntaxGroup, node: &SyntaxNode, comments: &mut Vec<String>) {
+    if node.kind(db) == SyntaxKind::TokenSingleLineComment {
+        comments.push(node.text(db).unwrap().trim_end().to_string());
+        return;
+    }
+    for child in node.children(db) {
+        collect_comments(db, &child, comments);
+    }
+}
+
 /// formats Cairo code given as a string.
 pub fn format_string(db: &dyn SyntaxGroup, content: String) -> String {
     let virtual_file = db.upcast().intern_file(FileLongId::Virtual(VirtualFile {
@@ -39,24 +141,85 @@ pub fn format_string(db: &dyn SyntaxGroup, content: String) -> String {
     get_formatted_file(db, &syntax_root, FormatterConfig::default())
 }
 
-#[derive(Clone)]
+#[derive(thiserror::Error, Debug)]
+pub enum FormatterConfigError {
+    #[error(transparent)]
+    TomlError(#[from] toml::de::Error),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+}
+
+/// The name of the formatter config file, which applies to the files of its directory and its sub
+/// directories.
+pub const CONFIG_FILE_NAME: &str = "cairofmt.toml";
+
+/// The formatter config. Missing fields of a config file take their default values.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct FormatterConfig {
-    tab_size: usize,
-    max_line_length: usize,
+    /// The number of spaces of an indentation level.
+    pub tab_size: usize,
+    /// The maximal length of a line, above which the line is broken (if possible).
+    pub max_line_length: usize,
+    /// Sorts groups of consecutive `use` items by their paths.
+    pub sort_imports: bool,
+    /// Removes `use` items that are identical to a previous one in their group.
+    /// `use` items are not merged into one (e.g. `use a::b; use a::c;` into `use a::{b, c};`),
+    /// since a `use` item can only import a single path.
+    // TODO(Gil): Add a `merge_imports` option once `use` items support multiple paths.
+    pub dedup_imports: bool,
+    /// Adds a trailing comma after the last element of lists that are broken into several lines.
+    pub trailing_comma: bool,
+    /// The maximal number of empty lines kept between items.
+    pub max_empty_lines_between_items: usize,
+    /// Separates consecutive items by an empty line, unless both are `use` items.
+    pub empty_line_between_items: bool,
 }
 
 // Config params
-// TODO(Gil): export to file and load from file
 const TAB_SIZE: usize = 4;
 const MAX_LINE_LENGTH: usize = 100;
+const MAX_EMPTY_LINES_BETWEEN_ITEMS: usize = 2;
 
 impl FormatterConfig {
     pub fn new(tab_size: usize, max_line_length: usize) -> Self {
-        Self { tab_size, max_line_length }
+        Self { tab_size, max_line_length, ..Self::default() }
+    }
+    /// Loads the config from a config file.
+    pub fn from_file(filename: &Path) -> Result<Self, FormatterConfigError> {
+        Ok(toml::from_str(&std::fs::read_to_string(filename)?)?)
+    }
+    /// Loads the config of a file or a directory, from the config file of the nearest directory
+    /// containing it. Returns the default config if there is no such file.
+    pub fn discover(path: &Path) -> Result<Self, FormatterConfigError> {
+        match find_config_file(path) {
+            Some(config_file) => Self::from_file(&config_file),
+            None => Ok(Self::default()),
+        }
     }
 }
 impl Default for FormatterConfig {
     fn default() -> Self {
-        Self::new(TAB_SIZE, MAX_LINE_LENGTH)
+        Self {
+            tab_size: TAB_SIZE,
+            max_line_length: MAX_LINE_LENGTH,
+            sort_imports: false,
+            dedup_imports: false,
+            trailing_comma: false,
+            max_empty_lines_between_items: MAX_EMPTY_LINES_BETWEEN_ITEMS,
+            empty_line_between_items: false,
+        }
     }
 }
+
+/// Returns the config file of the nearest directory containing `path` (a directory is considered
+/// as containing itself), if any.
+pub fn find_config_file(path: &Path) -> Option<PathBuf> {
+    // Relative paths are resolved, so that the parents of the current directory are searched too.
+    let path = path.canonicalize().ok()?;
+    let directory = if path.is_dir() { path.as_path() } else { path.parent()? };
+    directory
+        .ancestors()
+        .map(|ancestor| ancestor.join(CONFIG_FILE_NAME))
+        .find(|config_file| config_file.is_file())
+}
@@ -103,6 +103,7 @@ impl SyntaxNodeFormat for SyntaxNode {
                 | SyntaxKind::ExprList
                 | SyntaxKind::StructArgList
                 | SyntaxKind::ParamList
+                | SyntaxKind::MemberList
                 | SyntaxKind::GenericParamList
                 | SyntaxKind::GenericArgList
         )
@@ -113,6 +114,8 @@ impl SyntaxNodeFormat for SyntaxNode {
             SyntaxKind::StatementLet
             | SyntaxKind::StatementExpr
             | SyntaxKind::StatementReturn
+            | SyntaxKind::StatementBreak
+            | SyntaxKind::StatementContinue
             | SyntaxKind::ItemFreeFunction
             | SyntaxKind::ItemExternFunction
             | SyntaxKind::ItemExternType
@@ -122,6 +125,7 @@ impl SyntaxNodeFormat for SyntaxNode {
             | SyntaxKind::Attribute
             | SyntaxKind::ItemEnum
             | SyntaxKind::ItemModule
+            | SyntaxKind::ItemConstant
             | SyntaxKind::ItemUse => true,
             SyntaxKind::TerminalComma
                 if matches!(parent_kind(db, self), Some(SyntaxKind::MatchArms)) =>
@@ -163,7 +167,10 @@ impl SyntaxNodeFormat for SyntaxNode {
     fn is_breakable_list(&self, db: &dyn SyntaxGroup) -> bool {
         matches!(
             self.kind(db),
-            SyntaxKind::StructArgList | SyntaxKind::ParamList | SyntaxKind::ExprList
+            SyntaxKind::StructArgList
+                | SyntaxKind::ParamList
+                | SyntaxKind::ExprList
+                | SyntaxKind::MemberList
         )
     }
     fn is_protected_breaking_node(&self, db: &dyn SyntaxGroup) -> bool {
@@ -173,6 +180,7 @@ impl SyntaxNodeFormat for SyntaxNode {
                 | SyntaxKind::StructArgList
                 | SyntaxKind::ParamList
                 | SyntaxKind::ExprList
+                | SyntaxKind::MemberList
         )
     }
     fn get_break_line_point_properties(&self, db: &dyn SyntaxGroup) -> BreakLinePointProperties {
@@ -189,6 +197,10 @@ impl SyntaxNodeFormat for SyntaxNode {
                 precedence: 12,
                 break_type: BreakLinePointType::ListBreak,
             },
+            SyntaxKind::MemberList => BreakLinePointProperties {
+                precedence: 13,
+                break_type: BreakLinePointType::ListBreak,
+            },
             SyntaxKind::TokenPlus | SyntaxKind::TokenMinus => BreakLinePointProperties {
                 precedence: 100,
                 break_type: BreakLinePointType::Dangling,
@@ -1,13 +1,20 @@
 use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use db_utils::Upcast;
 use filesystem::db::{FilesDatabase, FilesGroup};
-use parser::utils::{get_syntax_root_and_diagnostics_from_file, SimpleParserDatabase};
+use filesystem::ids::{FileLongId, VirtualFile};
+use filesystem::span::{TextOffset, TextSpan};
+use parser::utils::{
+    get_syntax_root_and_diagnostics, get_syntax_root_and_diagnostics_from_file,
+    SimpleParserDatabase,
+};
 use pretty_assertions::assert_eq;
 use syntax::node::db::SyntaxDatabase;
 use test_case::test_case;
 
-use crate::{get_formatted_file, FormatterConfig};
+use crate::{get_comments, get_formatted_file, get_formatted_range, FormatterConfig};
 
 #[salsa::database(SyntaxDatabase, FilesDatabase)]
 #[derive(Default)]
@@ -27,6 +34,7 @@ impl Upcast<dyn FilesGroup> for DatabaseImpl {
     "test_data/cairo_files/linebreaking.cairo",
     "test_data/expected_results/linebreaking.cairo"
 )]
+#[test_case("test_data/cairo_files/comments.cairo", "test_data/expected_results/comments.cairo")]
 fn format_and_compare_file(unformatted_filename: &str, expected_filename: &str) {
     let db_val = SimpleParserDatabase::default();
     let db = &db_val;
@@ -40,3 +48,101 @@ fn format_and_compare_file(unformatted_filename: &str, expected_filename: &str)
         fs::read_to_string(expected_filename).expect("Expected file does not exists.");
     assert_eq!(formatted_file, expected_file);
 }
+
+#[test]
+fn format_and_compare_file_with_config() {
+    let db_val = SimpleParserDatabase::default();
+    let db = &db_val;
+
+    let unformatted_filename = "test_data/cairo_files/with_config/options.cairo";
+    let (syntax_root, diagnostics) =
+        get_syntax_root_and_diagnostics_from_file(db, unformatted_filename);
+    diagnostics.expect("A parsing error occurred while trying to format the code.");
+    // The config is taken from the `cairofmt.toml` file next to the formatted file.
+    let config = FormatterConfig::discover(Path::new(unformatted_filename))
+        .expect("Failed to load the formatter config.");
+    let formatted_file = get_formatted_file(db, &syntax_root, config);
+    let expected_file = fs::read_to_string("test_data/expected_results/with_config.cairo")
+        .expect("Expected file does not exists.");
+    assert_eq!(formatted_file, expected_file);
+}
+
+#[test]
+fn config_rejects_unknown_options() {
+    // `use` items can't be merged, so there is no such option.
+    let error = toml::from_str::<FormatterConfig>("merge_imports = true").unwrap_err();
+    assert!(error.to_string().contains("unknown field `merge_imports`"), "{error}");
+}
+
+#[test_case(45, 46, "test_data/expected_results/range_shared_line.cairo"; "shared line")]
+#[test_case(95, 96, "test_data/expected_results/range_nested.cairo"; "nested")]
+fn format_and_compare_range(start: usize, end: usize, expected_filename: &str) {
+    let db_val = SimpleParserDatabase::default();
+    let db = &db_val;
+
+    let unformatted_filename = "test_data/cairo_files/range.cairo";
+    let (syntax_root, diagnostics) =
+        get_syntax_root_and_diagnostics_from_file(db, unformatted_filename);
+    diagnostics.expect("A parsing error occurred while trying to format the code.");
+    let span = TextSpan { start: TextOffset(start), end: TextOffset(end) };
+    let (formatted_span, formatted_range) =
+        get_formatted_range(db, &syntax_root, span, FormatterConfig::default());
+    let mut formatted_file =
+        fs::read_to_string(unformatted_filename).expect("Unformatted file does not exists.");
+    formatted_file.replace_range(formatted_span.start.0..formatted_span.end.0, &formatted_range);
+    let expected_file =
+        fs::read_to_string(expected_filename).expect("Expected file does not exists.");
+    assert_eq!(formatted_file, expected_file);
+}
+
+/// Checks, for all the Cairo files of a directory, that formatting keeps their comments, and that
+/// formatting the formatted code does not change it.
+#[test_case("test_data/cairo_files")]
+#[test_case("test_data/expected_results")]
+#[test_case("../../corelib")]
+#[test_case("../../examples")]
+fn format_round_trip(directory: &str) {
+    let db_val = SimpleParserDatabase::default();
+    let db = &db_val;
+
+    for filename in cairo_files(Path::new(directory)) {
+        let filename = filename.to_str().unwrap();
+        let (syntax_root, diagnostics) = get_syntax_root_and_diagnostics_from_file(db, filename);
+        diagnostics.expect("A parsing error occurred while trying to format the code.");
+        let formatted_file = get_formatted_file(db, &syntax_root, FormatterConfig::default());
+
+        let formatted_file_id = db.intern_file(FileLongId::Virtual(VirtualFile {
+            parent: None,
+            name: "formatted".into(),
+            content: Arc::new(formatted_file.clone()),
+        }));
+        let (formatted_root, diagnostics) =
+            get_syntax_root_and_diagnostics(db, formatted_file_id, &formatted_file);
+        diagnostics.expect("A parsing error occurred in the formatted code.");
+        assert_eq!(
+            get_comments(db, &formatted_root),
+            get_comments(db, &syntax_root),
+            "Formatting changes the comments of {filename}."
+        );
+        assert_eq!(
+            get_formatted_file(db, &formatted_root, FormatterConfig::default()),
+            formatted_file,
+            "Formatting is not idempotent for {filename}."
+        );
+    }
+}
+
+/// Returns the paths of the Cairo files in a directory and its sub directories.
+fn cairo_files(directory: &Path) -> Vec<PathBuf> {
+    let mut files = vec![];
+    for entry in fs::read_dir(directory).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            files.extend(cairo_files(&path));
+        } else if matches!(path.extension(), Some(ext) if ext == "cairo") {
+            files.push(path);
+        }
+    }
+    files.sort();
+    files
+}
@@ -0,0 +1,21 @@
+// Imports.
+
+use c::d; // trailing d
+// leading b
+use a::b;
+use c::d;
+use a::a;
+
+use b::b;
+use a::c; // trailing c
+struct A { first_member: felt, second_member: felt }
+func foo(first_arg: felt, second_arg: felt, third_arg: felt) -> felt {
+let x = A{first_member: first_arg, second_member: second_arg + third_arg};
+bar(first_arg, second_arg)
+}
+
+
+
+func bar(a: felt, b: felt) -> felt {
+    a
+}
@@ -0,0 +1,54 @@
+// Header comment.
+
+use a::b; // trailing use
+// leading use
+use a::c;
+
+// Leading comment of foo.
+func foo(
+    a: felt, // trailing comment of a
+    // leading comment of b
+    b: felt,
+) -> felt { // after brace
+    let x = 1; // trailing comment of x
+    // leading comment of y
+    let y = bar(
+        a, // first arg
+        // second arg
+        b
+    );
+    match x {
+        0 => 1, // zero arm
+        // other arms
+        _ => 2,
+    }
+    // dangling at end of block
+}
+
+struct A {
+    // leading member
+    a: felt, // trailing member
+}
+
+#[derive(Copy, Drop)] // trailing attribute
+enum E {
+    A: felt, // first variant
+    // dangling in enum
+}
+
+func g() {
+    let x = 1 + // after plus
+        2;
+    let s = S {
+        a: 1, // first member
+        b: 2
+    };
+    foo::<felt>( // after paren
+        1
+    );
+    return (); // after return
+}
+func empty() {
+    // only a comment
+}
+// Trailing comment at end of file.
@@ -1,107 +1,107 @@
 func foo(x: T) -> S {
     // Cascaded dangling break
-    let x1 = 1 + 2 
-               + 3 
-               + 4 
-               + 5 
-               + 6 
-               + 7 
-               + 8 
-               + 9 
-               + 1 * 2 
-                   * 3 
-                   * 4 
-                   * 5 
-                   * 6 
-                   * 7 
-                   * 8 
-                   * 9 
-                   * 1 
-                   * 2 
-                   * 3 
-                   * 4 
-                   * 5 
-                   * 6 
-                   * 7 
-                   * 8 
-                   * 9 
-                   * 1 
-                   * 2 
-                   * 3 
-                   * 4 
-                   * 5 
-                   * 6 
-                   * 7 
-                   * 8 
-                   * 9 
-               + 1 
-               + 2 
-               + 3 
-               + 4 
-               + 5 
-               + 6 
-               + 7 
-               + 8 
-               + 9 
-               + 1 
-               + 2 
-               + 3 
-               + 4 
-               + 5 
-               + 6 
-               + 7 
-               + 8 
+    let x1 = 1 + 2
+               + 3
+               + 4
+               + 5
+               + 6
+               + 7
+               + 8
+               + 9
+               + 1 * 2
+                   * 3
+                   * 4
+                   * 5
+                   * 6
+                   * 7
+                   * 8
+                   * 9
+                   * 1
+                   * 2
+                   * 3
+                   * 4
+                   * 5
+                   * 6
+                   * 7
+                   * 8
+                   * 9
+                   * 1
+                   * 2
+                   * 3
+                   * 4
+                   * 5
+                   * 6
+                   * 7
+                   * 8
+                   * 9
+               + 1
+               + 2
+               + 3
+               + 4
+               + 5
+               + 6
+               + 7
+               + 8
+               + 9
+               + 1
+               + 2
+               + 3
+               + 4
+               + 5
+               + 6
+               + 7
+               + 8
                + 9;
     // Non-dangling break (overridden)
-    let x2 = a_very_very_very_very_very_very_very_long_name() 
+    let x2 = a_very_very_very_very_very_very_very_long_name()
         + a_very_very_very_very_very_very_very_long_name();
-    let x3 = (1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9) + (1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9) 
-                                                 + (1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9) 
+    let x3 = (1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9) + (1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9)
+                                                 + (1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9)
                                                  + (1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9);
-    let x4 = (1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9 + 1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9) 
-        + (1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9 + 1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9) 
-        + (1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9) 
+    let x4 = (1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9 + 1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9)
+        + (1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9 + 1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9)
+        + (1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9)
         + (1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9);
-    let x5 = (1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9 + 1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9) 
-        + (1 + 2 
-             + 3 
-             + 4 
-             + 5 
-             + 6 
-             + 7 
-             + 8 
-             + 9 
-             + 1 
-             + 2 
-             + 3 
-             + 4 
-             + 5 
-             + 6 
-             + 7 
-             + 8 
-             + 9 
-             + 1 
-             + 2 
-             + 3 
-             + 4 
-             + 5 
-             + 6 
-             + 7 
-             + 8 
-             + 9) 
-        + (1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9) 
+    let x5 = (1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9 + 1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9)
+        + (1 + 2
+             + 3
+             + 4
+             + 5
+             + 6
+             + 7
+             + 8
+             + 9
+             + 1
+             + 2
+             + 3
+             + 4
+             + 5
+             + 6
+             + 7
+             + 8
+             + 9
+             + 1
+             + 2
+             + 3
+             + 4
+             + 5
+             + 6
+             + 7
+             + 8
+             + 9)
+        + (1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9)
         + (1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9);
-    let x6 = (1 + 0 
-                + (2 + 0 
-                     + (3 + 0 
-                          + (4 + 0 
-                               + (5 + 0 
-                                    + (6 + 0 
-                                         + (7 + 0 
-                                              + (8 + 0 
-                                                   + (9 + 0 
-                                                        + (1 + 0 
-                                                             + (2 + 0 
+    let x6 = (1 + 0
+                + (2 + 0
+                     + (3 + 0
+                          + (4 + 0
+                               + (5 + 0
+                                    + (6 + 0
+                                         + (7 + 0
+                                              + (8 + 0
+                                                   + (9 + 0
+                                                        + (1 + 0
+                                                             + (2 + 0
                                                                   + (3 + 0 + (4 + 0)))))))))))));
 }
 
@@ -0,0 +1,9 @@
+func foo(a: felt)->felt{
+    let x=1+2;
+  let y  =   3; let z=4;
+    if a==0 {
+        let w = 5;
+    }
+    a
+}
+func   bar()  {   }
@@ -48,3 +48,16 @@ func fib(a: Box::<felt>, b: Box::<felt>, n: Box::<felt>) -> Box::<felt> {
         },
     }
 }
+
+func loops(mut n: felt) {
+    while n != 0 {
+        n = n - 1;
+        if n == 5 {
+            break;
+        }
+        continue;
+    }
+    loop {
+        break;
+    }
+}
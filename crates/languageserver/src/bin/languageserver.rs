@@ -1,4 +1,4 @@
-use languageserver::{Backend, RootDatabase, State};
+use languageserver::{Backend, RootDatabase};
 use tower_lsp::{LspService, Server};
 
 #[tokio::main]
@@ -11,10 +11,6 @@ async fn main() {
     let (stdin, stdout) = (stdin.compat(), stdout.compat_write());
 
     let db = RootDatabase::default();
-    let (service, socket) = LspService::new(|client| Backend {
-        client,
-        db_mutex: db.into(),
-        state_mutex: State::default().into(),
-    });
+    let (service, socket) = LspService::new(|client| Backend::new(client, db));
     Server::new(stdin, stdout, socket).serve(service).await;
 }
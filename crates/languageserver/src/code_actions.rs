@@ -0,0 +1,483 @@
+//! Code actions that fix semantic and lowering diagnostics.
+
+#[cfg(test)]
+#[path = "code_actions_test.rs"]
+mod test;
+
+use std::collections::HashMap;
+
+use defs::ids::{
+    FunctionWithBodyId, GenericTypeId, LanguageElementId, LookupItemId, ModuleFileId, ModuleItemId,
+    TopLevelLanguageElementId, VarId,
+};
+use diagnostics::DiagnosticEntry;
+use filesystem::ids::FileId;
+use filesystem::span::{TextOffset, TextSpan};
+use lowering::db::LoweringGroup;
+use lowering::diagnostic::LoweringDiagnosticKind;
+use semantic::db::SemanticGroup;
+use semantic::diagnostic::SemanticDiagnosticKind;
+use semantic::items::function_with_body::SemanticExprLookup;
+use semantic::resolve_path::ResolvedGenericItem;
+use semantic::{Expr, TypeId, TypeLongId};
+use syntax::node::helpers::PathSegmentEx;
+use syntax::node::kind::SyntaxKind;
+use syntax::node::{ast, SyntaxNode, Terminal, TypedSyntaxNode};
+use tower_lsp::lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, Range, TextEdit, Url, WorkspaceEdit,
+};
+
+use crate::completion::function_with_body;
+use crate::{file_uri, lookup_item_from_ast, span_range};
+
+/// Returns the quick fixes for the diagnostics of a module file that intersect `range`.
+pub fn code_actions(
+    lowering_db: &(dyn LoweringGroup + 'static),
+    module_file_id: ModuleFileId,
+    range: Range,
+) -> Option<Vec<CodeActionOrCommand>> {
+    let db: &(dyn SemanticGroup + 'static) = lowering_db.upcast();
+    let file = db.module_file(module_file_id)?;
+    let root = db.file_syntax(file)?.as_syntax_node();
+    let ctx = CodeActionContext { db, module_file_id, file, root };
+    let intersects = |location: diagnostics::DiagnosticLocation| {
+        matches!(
+            span_range(db.upcast(), location.file_id, location.span),
+            Some(span) if span.start <= range.end && range.start <= span.end
+        )
+    };
+
+    let mut actions = vec![];
+    for diagnostic in db.file_semantic_diagnostics(file).unwrap_or_default().get_all() {
+        if !intersects(diagnostic.location(db)) {
+            continue;
+        }
+        let node = ctx.root.lookup_ptr(db.upcast(), diagnostic.stable_location.stable_ptr());
+        match diagnostic.kind {
+            SemanticDiagnosticKind::PathNotFound => actions.extend(ctx.import_actions(node, false)),
+            SemanticDiagnosticKind::VariableNotFound { .. } => {
+                actions.extend(ctx.import_actions(node, true))
+            }
+            SemanticDiagnosticKind::MissingMember { .. } => {
+                actions.extend(ctx.missing_members_action(node))
+            }
+            SemanticDiagnosticKind::MissingMatchArms { enum_id, missing_variants } => {
+                let missing_variants: Vec<_> =
+                    missing_variants.iter().map(|variant| variant.to_string()).collect();
+                actions.extend(ctx.missing_arms_action(
+                    node,
+                    &enum_id.name(db.upcast()),
+                    &missing_variants,
+                ))
+            }
+            SemanticDiagnosticKind::RefArgNotMutable => {
+                actions.extend(ctx.ref_arg_var(node).and_then(|var| ctx.make_mutable_action(var)))
+            }
+            SemanticDiagnosticKind::AssignmentToImmutableVar => {
+                actions.extend(ctx.assigned_var(node).and_then(|var| ctx.make_mutable_action(var)))
+            }
+            _ => {}
+        }
+    }
+    for diagnostic in lowering_db.file_lowering_diagnostics(file).unwrap_or_default().get_all() {
+        if !intersects(diagnostic.location(db)) {
+            continue;
+        }
+        let node = ctx.root.lookup_ptr(db.upcast(), diagnostic.stable_location.stable_ptr());
+        match diagnostic.kind {
+            LoweringDiagnosticKind::VariableMoved => {
+                actions.extend(ctx.expr_ty(node).and_then(|ty| ctx.derive_action(ty, "Copy")))
+            }
+            LoweringDiagnosticKind::VariableNotDropped { ty } => {
+                actions.extend(ctx.derive_action(ty, "Drop"))
+            }
+            _ => {}
+        }
+    }
+
+    // A few diagnostics may have the same fix (e.g. a constructor missing a few members).
+    let mut titles = vec![];
+    actions.retain(|action| {
+        let is_new = !titles.contains(&action.title);
+        titles.push(action.title.clone());
+        is_new
+    });
+    Some(actions.into_iter().map(CodeActionOrCommand::CodeAction).collect())
+}
+
+struct CodeActionContext<'a> {
+    db: &'a (dyn SemanticGroup + 'static),
+    module_file_id: ModuleFileId,
+    file: FileId,
+    root: SyntaxNode,
+}
+impl<'a> CodeActionContext<'a> {
+    /// Returns the actions that add a `use` of the items named as the unresolved first segment of
+    /// a path, from the other modules of the crates.
+    fn import_actions(&self, node: SyntaxNode, only_constants: bool) -> Vec<CodeAction> {
+        let db = self.db;
+        let syntax_db = db.upcast();
+        if node.kind(syntax_db) != SyntaxKind::TerminalIdentifier {
+            return vec![];
+        }
+        // Only the first segment of a path may be imported.
+        let Some(path) = node.parent().and_then(|segment| segment.parent()) else { return vec![] };
+        if path.kind(syntax_db) != SyntaxKind::ExprPath
+            || path.offset() != node.parent().unwrap().offset()
+        {
+            return vec![];
+        }
+        let name = ast::TerminalIdentifier::from_syntax_node(syntax_db, node).text(syntax_db);
+
+        let mut actions = vec![];
+        for crate_id in db.crates() {
+            for module_id in db.crate_modules(crate_id).iter() {
+                if *module_id == self.module_file_id.0 {
+                    continue;
+                }
+                let Some(item) =
+                    db.module_items(*module_id).and_then(|items| items.items.get(&name).copied())
+                else {
+                    continue;
+                };
+                if matches!(item, ModuleItemId::Use(_))
+                    || (only_constants && !matches!(item, ModuleItemId::Constant(_)))
+                {
+                    continue;
+                }
+                let item_path = format!("{}::{name}", module_id.full_path(db.upcast()));
+                let edit = self.use_insertion(&item_path);
+                actions.extend(self.action(format!("Import `{item_path}`"), self.file, vec![edit]));
+            }
+        }
+        actions
+    }
+
+    /// Returns the edit that adds a `use` of `path`, after the last `use` of the file.
+    fn use_insertion(&self, path: &str) -> Option<TextEdit> {
+        let syntax_db = self.db.upcast();
+        let items =
+            ast::SyntaxFile::from_syntax_node(syntax_db, self.root.clone()).items(syntax_db);
+        let last_use = items
+            .elements(syntax_db)
+            .into_iter()
+            .filter(|item| matches!(item, ast::Item::Use(_)))
+            .last();
+        match last_use {
+            Some(last_use) => self.insertion(
+                last_use.as_syntax_node().span_end_without_trivia(syntax_db),
+                format!("\nuse {path};"),
+            ),
+            None => self.insertion(TextOffset(0), format!("use {path};\n")),
+        }
+    }
+
+    /// Returns the action that adds the missing members of a struct constructor, as shorthands.
+    fn missing_members_action(&self, node: SyntaxNode) -> Option<CodeAction> {
+        let db = self.db;
+        let syntax_db = db.upcast();
+        if node.kind(syntax_db) != SyntaxKind::ExprStructCtorCall {
+            return None;
+        }
+        let ctor = ast::ExprStructCtorCall::from_syntax_node(syntax_db, node.clone());
+        let identifier = ctor.path(syntax_db).elements(syntax_db).last()?.identifier_ast(syntax_db);
+        let struct_id = self.lookup_items(&node).into_iter().find_map(|lookup_item_id| match db
+            .lookup_resolved_generic_item_by_ptr(lookup_item_id, identifier.stable_ptr())
+        {
+            Some(ResolvedGenericItem::GenericType(GenericTypeId::Struct(struct_id))) => {
+                Some(struct_id)
+            }
+            _ => None,
+        })?;
+        let arguments = ctor.arguments(syntax_db).arguments(syntax_db);
+        let written: Vec<_> = arguments
+            .elements(syntax_db)
+            .into_iter()
+            .filter_map(|arg| match arg {
+                ast::StructArg::StructArgSingle(arg) => {
+                    Some(arg.identifier(syntax_db).text(syntax_db))
+                }
+                ast::StructArg::StructArgTail(_) => None,
+            })
+            .collect();
+        let missing: Vec<_> = db
+            .struct_members(struct_id)?
+            .into_iter()
+            .map(|(name, _)| name)
+            .filter(|name| !written.contains(name))
+            .collect();
+        if missing.is_empty() {
+            return None;
+        }
+        let missing = missing.join(", ");
+        let arguments_node = arguments.as_syntax_node();
+        let edit = match arguments_node.children(syntax_db).last() {
+            None => self.insertion(
+                ctor.arguments(syntax_db)
+                    .lbrace(syntax_db)
+                    .as_syntax_node()
+                    .span_end_without_trivia(syntax_db),
+                format!(" {missing} "),
+            ),
+            Some(last) if last.kind(syntax_db) == SyntaxKind::TerminalComma => {
+                self.insertion(last.span_end_without_trivia(syntax_db), format!(" {missing},"))
+            }
+            Some(last) => {
+                self.insertion(last.span_end_without_trivia(syntax_db), format!(", {missing}"))
+            }
+        };
+        self.action("Add missing members".into(), self.file, vec![edit])
+    }
+
+    /// Returns the action that adds arms for the missing variants of a match on an enum. `node` is
+    /// the matched expression.
+    fn missing_arms_action(
+        &self,
+        node: SyntaxNode,
+        enum_name: &str,
+        missing_variants: &[String],
+    ) -> Option<CodeAction> {
+        let syntax_db = self.db.upcast();
+        let expr_match = ast::ExprMatch::from_syntax_node(syntax_db, node.parent()?);
+        let arms = expr_match.arms(syntax_db);
+        let arm_elements = arms.elements(syntax_db);
+        // The variants are written as in the existing arms, e.g. `Option::Some`.
+        let enum_path = arm_elements
+            .iter()
+            .find_map(|arm| match arm.pattern(syntax_db) {
+                ast::Pattern::Enum(pattern) => {
+                    let segments = pattern.path(syntax_db).as_syntax_node();
+                    let text = self.text(&segments)?;
+                    Some(text.rsplit_once("::")?.0.to_string())
+                }
+                _ => None,
+            })
+            .unwrap_or_else(|| enum_name.to_string());
+        let match_column =
+            self.column(expr_match.as_syntax_node().span_start_without_trivia(syntax_db))?;
+        let indent = match arm_elements.first() {
+            Some(arm) => self.column(arm.as_syntax_node().span_start_without_trivia(syntax_db))?,
+            None => match_column + 4,
+        };
+        let new_arms: String = missing_variants
+            .iter()
+            .map(|variant| format!("\n{}{enum_path}::{variant}(_) => {{}},", " ".repeat(indent)))
+            .collect();
+        let arms_node = arms.as_syntax_node();
+        let edit = match arms_node.children(syntax_db).last() {
+            None => self.insertion(
+                expr_match.lbrace(syntax_db).as_syntax_node().span_end_without_trivia(syntax_db),
+                new_arms,
+            ),
+            Some(last) if last.kind(syntax_db) == SyntaxKind::TerminalComma => {
+                self.insertion(last.span_end_without_trivia(syntax_db), new_arms)
+            }
+            Some(last) => {
+                self.insertion(last.span_end_without_trivia(syntax_db), format!(",{new_arms}"))
+            }
+        };
+        self.action("Add missing match arms".into(), self.file, vec![edit])
+    }
+
+    /// Returns the variable assigned by an assignment expression.
+    fn assigned_var(&self, node: SyntaxNode) -> Option<VarId> {
+        let expr = ast::Expr::from_syntax_node(self.db.upcast(), node.clone());
+        let function_id = self.function_with_body(&node)?;
+        let expr_id = self.db.lookup_expr_by_ptr(function_id, expr.stable_ptr())?;
+        match self.db.expr_semantic(function_id, expr_id) {
+            Expr::Assignment(expr_assignment) => Some(expr_assignment.var),
+            _ => None,
+        }
+    }
+
+    /// Returns the variable passed as the `ref` argument `node` of the enclosing function call.
+    fn ref_arg_var(&self, node: SyntaxNode) -> Option<VarId> {
+        let db = self.db;
+        let syntax_db = db.upcast();
+        let name = self.text(&node)?;
+        let function_id = self.function_with_body(&node)?;
+        // Variables passed by reference are kept in the call, and not as expressions of their own.
+        // A method call is represented by the `.` expression.
+        let mut current = node.parent();
+        while let Some(ancestor) = current {
+            if matches!(
+                ancestor.kind(syntax_db),
+                SyntaxKind::ExprFunctionCall | SyntaxKind::ExprBinary
+            ) {
+                let expr = ast::Expr::from_syntax_node(syntax_db, ancestor.clone());
+                if let Some(Expr::FunctionCall(expr_call)) = db
+                    .lookup_expr_by_ptr(function_id, expr.stable_ptr())
+                    .map(|expr_id| db.expr_semantic(function_id, expr_id))
+                {
+                    return expr_call.ref_args.into_iter().find(|var| {
+                        self.var_name(*var).map(|(var_name, _)| var_name) == Some(name.clone())
+                    });
+                }
+            }
+            current = ancestor.parent();
+        }
+        None
+    }
+
+    /// Returns the name of a variable, and the node of the name in its definition.
+    fn var_name(&self, var: VarId) -> Option<(String, SyntaxNode)> {
+        let db = self.db;
+        let syntax_db = db.upcast();
+        match var {
+            VarId::Local(local_var_id) => {
+                let name_node =
+                    self.root.lookup_ptr(syntax_db, local_var_id.stable_ptr(db.upcast()).untyped());
+                // Only variables bound by name may have modifiers. A name without modifiers is
+                // parsed as a path.
+                if !matches!(
+                    name_node.parent()?.kind(syntax_db),
+                    SyntaxKind::PatternIdentifier | SyntaxKind::PathSegmentSimple
+                ) {
+                    return None;
+                }
+                let name = ast::TerminalIdentifier::from_syntax_node(syntax_db, name_node.clone())
+                    .text(syntax_db);
+                Some((name.to_string(), name_node))
+            }
+            VarId::Param(param_id) => {
+                let param_ptr = param_id.stable_ptr(db.upcast());
+                let param = ast::Param::from_syntax_node(
+                    syntax_db,
+                    self.root.lookup_ptr(syntax_db, param_ptr.untyped()),
+                );
+                Some((
+                    param_id.name(db.upcast()).to_string(),
+                    param.name(syntax_db).as_syntax_node(),
+                ))
+            }
+        }
+    }
+
+    /// Returns the action that adds `mut` to the definition of a variable.
+    fn make_mutable_action(&self, var: VarId) -> Option<CodeAction> {
+        let (name, name_node) = self.var_name(var)?;
+        let edit =
+            self.insertion(name_node.span_start_without_trivia(self.db.upcast()), "mut ".into());
+        self.action(format!("Make `{name}` mutable"), self.file, vec![edit])
+    }
+
+    /// Returns the type of an expression.
+    fn expr_ty(&self, node: SyntaxNode) -> Option<TypeId> {
+        let expr = ast::Expr::from_syntax_node(self.db.upcast(), node.clone());
+        let function_id = self.function_with_body(&node)?;
+        let expr_id = self.db.lookup_expr_by_ptr(function_id, expr.stable_ptr())?;
+        Some(self.db.expr_semantic(function_id, expr_id).ty())
+    }
+
+    /// Returns the action that adds a derive of `trait_name` to a struct or enum type.
+    fn derive_action(&self, ty: TypeId, trait_name: &str) -> Option<CodeAction> {
+        let db = self.db;
+        let syntax_db = db.upcast();
+        let TypeLongId::Concrete(concrete_type) = db.lookup_intern_type(ty) else {
+            return None;
+        };
+        let generic_type = concrete_type.generic_type(db);
+        let type_file = db.module_file(generic_type.module_file(db.upcast()))?;
+        let type_node = db
+            .file_syntax(type_file)?
+            .as_syntax_node()
+            .lookup_ptr(syntax_db, generic_type.untyped_stable_ptr(db.upcast()));
+        let attributes = match generic_type {
+            GenericTypeId::Struct(_) => {
+                ast::ItemStruct::from_syntax_node(syntax_db, type_node.clone())
+                    .attributes(syntax_db)
+            }
+            GenericTypeId::Enum(_) => {
+                ast::ItemEnum::from_syntax_node(syntax_db, type_node.clone()).attributes(syntax_db)
+            }
+            GenericTypeId::Extern(_) => return None,
+        };
+        let derive_args = attributes.elements(syntax_db).into_iter().find_map(|attribute| {
+            if attribute.attr(syntax_db).text(syntax_db) != "derive" {
+                return None;
+            }
+            match attribute.args(syntax_db) {
+                ast::OptionAttributeArgs::AttributeArgs(args) => Some(args),
+                ast::OptionAttributeArgs::Empty(_) => None,
+            }
+        });
+        let (offset, text) = match derive_args {
+            Some(args) => {
+                let separator =
+                    if args.arg_list(syntax_db).elements(syntax_db).is_empty() { "" } else { ", " };
+                (
+                    args.rangle(syntax_db).as_syntax_node().span_start_without_trivia(syntax_db),
+                    format!("{separator}{trait_name}"),
+                )
+            }
+            None => {
+                let start = type_node.span_start_without_trivia(syntax_db);
+                let column = start.position_in_file(db.upcast(), type_file)?.col;
+                (start, format!("#[derive({trait_name})]\n{}", " ".repeat(column)))
+            }
+        };
+        let edit = span_range(db.upcast(), type_file, TextSpan { start: offset, end: offset })
+            .map(|range| TextEdit { range, new_text: text });
+        let type_name = generic_type.name(db.upcast());
+        self.action(
+            format!("Add `#[derive({trait_name})]` to `{type_name}`"),
+            type_file,
+            vec![edit],
+        )
+    }
+
+    /// Returns a quick fix that applies edits to a file, if they could all be computed.
+    fn action(
+        &self,
+        title: String,
+        file: FileId,
+        edits: Vec<Option<TextEdit>>,
+    ) -> Option<CodeAction> {
+        let uri: Url = file_uri(self.db.upcast(), file)?;
+        let edits = edits.into_iter().collect::<Option<Vec<_>>>()?;
+        Some(CodeAction {
+            title,
+            kind: Some(CodeActionKind::QUICKFIX),
+            edit: Some(WorkspaceEdit {
+                changes: Some(HashMap::from([(uri, edits)])),
+                ..WorkspaceEdit::default()
+            }),
+            ..CodeAction::default()
+        })
+    }
+
+    /// Returns the edit that inserts `text` at `offset` of the file.
+    fn insertion(&self, offset: TextOffset, text: String) -> Option<TextEdit> {
+        let range =
+            span_range(self.db.upcast(), self.file, TextSpan { start: offset, end: offset })?;
+        Some(TextEdit { range, new_text: text })
+    }
+
+    /// Returns the column of an offset in the file.
+    fn column(&self, offset: TextOffset) -> Option<usize> {
+        Some(offset.position_in_file(self.db.upcast(), self.file)?.col)
+    }
+
+    /// Returns the text of a node without its trivia.
+    fn text(&self, node: &SyntaxNode) -> Option<String> {
+        let content = self.db.file_content(self.file)?;
+        let span = node.span_without_trivia(self.db.upcast());
+        Some(content.get(span.start.0..span.end.0)?.to_string())
+    }
+
+    /// Returns the lookup items containing a node, from the innermost.
+    fn lookup_items(&self, node: &SyntaxNode) -> Vec<LookupItemId> {
+        let mut lookup_items = vec![];
+        let mut current = Some(node.clone());
+        while let Some(node) = current {
+            lookup_items.extend(lookup_item_from_ast(self.db, self.module_file_id, node.clone()));
+            current = node.parent();
+        }
+        lookup_items
+    }
+
+    /// Returns the function with a body containing a node.
+    fn function_with_body(&self, node: &SyntaxNode) -> Option<FunctionWithBodyId> {
+        self.lookup_items(node).into_iter().find_map(function_with_body)
+    }
+}
@@ -0,0 +1,190 @@
+use std::sync::Arc;
+
+use defs::db::DefsGroup;
+use defs::ids::ModuleId;
+use filesystem::db::{FilesGroup, FilesGroupEx};
+use filesystem::ids::{CrateLongId, Directory, FileLongId};
+use indoc::indoc;
+use test_case::test_case;
+use tower_lsp::lsp_types::{CodeActionOrCommand, Position, Range, Url};
+
+use super::code_actions;
+use crate::db::RootDatabase;
+use crate::{find_module_file_id, position_to_offset};
+
+/// The directory of the test crate. Code actions only edit files on disk, which must have absolute
+/// paths.
+const CRATE_DIR: &str = "/test/src";
+
+/// Sets up a crate whose root module has `root_code` and a submodule `submodule` with
+/// `submodule_code`. Applies the code action with the given title, offered for the whole root
+/// module, and returns the edited root module code, or None if there is no such action.
+fn apply_code_action(root_code: &str, submodule_code: &str, title: &str) -> Option<String> {
+    let mut db = RootDatabase::default();
+    let crate_id = db.intern_crate(CrateLongId("test".into()));
+    db.set_crate_root(crate_id, Some(Directory(CRATE_DIR.into())));
+    let root_file = db.module_main_file(ModuleId::CrateRoot(crate_id)).unwrap();
+    let root_code = format!("mod submodule;\n{root_code}");
+    db.override_file_content(root_file, Some(Arc::new(root_code.clone())));
+    let submodule_file =
+        db.intern_file(FileLongId::OnDisk(format!("{CRATE_DIR}/submodule.cairo").into()));
+    db.override_file_content(submodule_file, Some(Arc::new(submodule_code.into())));
+
+    let module_file_id = find_module_file_id(&db, root_file).unwrap();
+    let range = Range {
+        start: Position { line: 0, character: 0 },
+        end: Position { line: root_code.lines().count() as u32, character: 0 },
+    };
+    let action =
+        code_actions(&db, module_file_id, range).unwrap().into_iter().find_map(|action| {
+            match action {
+                CodeActionOrCommand::CodeAction(action) if action.title == title => Some(action),
+                _ => None,
+            }
+        })?;
+
+    let root_uri = Url::from_file_path(format!("{CRATE_DIR}/lib.cairo")).unwrap();
+    let mut edits = action.edit.unwrap().changes.unwrap().remove(&root_uri).unwrap();
+    // Apply the edits from the last, so the positions of the rest are not changed.
+    edits.sort_by_key(|edit| (edit.range.start.line, edit.range.start.character));
+    let mut text = root_code;
+    for edit in edits.into_iter().rev() {
+        let start = position_to_offset(&text, edit.range.start);
+        let end = position_to_offset(&text, edit.range.end);
+        text.replace_range(start..end, &edit.new_text);
+    }
+    Some(text)
+}
+
+#[test_case(
+    indoc! {"
+        use submodule::bar;
+        func foo() -> felt {
+            baz()
+        }
+    "},
+    "func bar() {}\nfunc baz() -> felt { 1 }",
+    "Import `test::submodule::baz`",
+    indoc! {"
+        mod submodule;
+        use submodule::bar;
+        use test::submodule::baz;
+        func foo() -> felt {
+            baz()
+        }
+    "};
+    "import"
+)]
+#[test_case(
+    indoc! {"
+        struct A { x: felt, y: felt, z: felt }
+        func foo() -> A {
+            A { x: 1, }
+        }
+    "},
+    "",
+    "Add missing members",
+    indoc! {"
+        mod submodule;
+        struct A { x: felt, y: felt, z: felt }
+        func foo() -> A {
+            A { x: 1, y, z, }
+        }
+    "};
+    "missing members"
+)]
+#[test_case(
+    indoc! {"
+        enum E { A: felt, B: felt, C: () }
+        func foo(e: E) -> felt {
+            match e {
+                E::A(x) => x,
+            }
+        }
+    "},
+    "",
+    "Add missing match arms",
+    indoc! {"
+        mod submodule;
+        enum E { A: felt, B: felt, C: () }
+        func foo(e: E) -> felt {
+            match e {
+                E::A(x) => x,
+                E::B(_) => {},
+                E::C(_) => {},
+            }
+        }
+    "};
+    "missing match arms"
+)]
+#[test_case(
+    indoc! {"
+        func foo() {
+            let x = 1;
+            x = 2;
+        }
+    "},
+    "",
+    "Make `x` mutable",
+    indoc! {"
+        mod submodule;
+        func foo() {
+            let mut x = 1;
+            x = 2;
+        }
+    "};
+    "make mutable"
+)]
+#[test_case(
+    indoc! {"
+        #[derive(Drop)]
+        struct A { x: felt }
+        func foo(a: A) -> (A, A) {
+            (a, a)
+        }
+    "},
+    "",
+    "Add `#[derive(Copy)]` to `A`",
+    indoc! {"
+        mod submodule;
+        #[derive(Drop, Copy)]
+        struct A { x: felt }
+        func foo(a: A) -> (A, A) {
+            (a, a)
+        }
+    "};
+    "derive copy"
+)]
+#[test_case(
+    indoc! {"
+        enum E { A: felt, B: felt }
+        func foo(e: E) -> felt {
+            let e2 = E::A(1);
+            1
+        }
+    "},
+    "",
+    "Add `#[derive(Drop)]` to `E`",
+    indoc! {"
+        mod submodule;
+        #[derive(Drop)]
+        enum E { A: felt, B: felt }
+        func foo(e: E) -> felt {
+            let e2 = E::A(1);
+            1
+        }
+    "};
+    "derive drop"
+)]
+fn test_code_action(root_code: &str, submodule_code: &str, title: &str, expected: &str) {
+    assert_eq!(apply_code_action(root_code, submodule_code, title).as_deref(), Some(expected));
+}
+
+#[test]
+fn test_no_derive_action_for_extern_types() {
+    let root_code = indoc! {"
+        extern type A;
+        func foo(a: A) {}
+    "};
+    assert_eq!(apply_code_action(root_code, "", "Add `#[derive(Drop)]` to `A`"), None);
+}
@@ -0,0 +1,430 @@
+//! Code completion, computed from the semantic model.
+
+#[cfg(test)]
+#[path = "completion_test.rs"]
+mod test;
+
+use defs::ids::{
+    EnumId, FunctionWithBodyId, GenericFunctionId, GenericTypeId, LookupItemId, ModuleFileId,
+    ModuleId, ModuleItemId,
+};
+use semantic::corelib::{core_module, unit_ty};
+use semantic::db::SemanticGroup;
+use semantic::expr::compute::find_method_candidates;
+use semantic::items::function_with_body::SemanticExprLookup;
+use semantic::items::imp::ImplLookupContext;
+use semantic::items::strct::SemanticStructEx;
+use semantic::resolve_path::ResolvedGenericItem;
+use semantic::{
+    ConcreteFunction, ConcreteTypeId, Expr, FunctionLongId, Signature, Statement, TypeLongId,
+};
+use syntax::node::helpers::PathSegmentEx;
+use syntax::node::kind::SyntaxKind;
+use syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use tower_lsp::lsp_types::{CompletionItem, CompletionItemKind, InsertTextFormat};
+use utils::ordered_hash_set::OrderedHashSet;
+
+use crate::signature_help::param_label;
+
+/// Returns the completions at the token `node`, which is the token right before the cursor.
+pub fn completions_at(
+    db: &(dyn SemanticGroup + 'static),
+    module_file_id: ModuleFileId,
+    lookup_items: &[LookupItemId],
+    node: SyntaxNode,
+) -> Vec<CompletionItem> {
+    let syntax_db = db.upcast();
+    let parent_kind = |node: &SyntaxNode| node.parent().map(|parent| parent.kind(syntax_db));
+    // Tokens are wrapped by terminals, so the node of interest is the grandparent of the token.
+    let Some(grandparent) = node.parent().and_then(|terminal| terminal.parent()) else {
+        return generic_completions(db, module_file_id, lookup_items, &node);
+    };
+    match (node.kind(syntax_db), grandparent.kind(syntax_db)) {
+        // `expr.`
+        (SyntaxKind::TokenDot, SyntaxKind::ExprBinary) => {
+            let binary = ast::ExprBinary::from_syntax_node(syntax_db, grandparent);
+            dot_completions(db, module_file_id, lookup_items, binary.lhs(syntax_db))
+                .unwrap_or_default()
+        }
+        // `path::`
+        (SyntaxKind::TokenColonColon, SyntaxKind::ExprPath) => {
+            previous_segment_completions(db, lookup_items, &grandparent, &node.parent().unwrap())
+        }
+        // `expr.member` or `path::name`, while typing the last identifier.
+        (SyntaxKind::TokenIdentifier, SyntaxKind::PathSegmentSimple)
+            if parent_kind(&grandparent) == Some(SyntaxKind::ExprPath) =>
+        {
+            let path = grandparent.parent().unwrap();
+            if let Some(binary) = path.parent().filter(|binary| {
+                binary.kind(syntax_db) == SyntaxKind::ExprBinary
+                    && is_dot_rhs(db, &ast::ExprBinary::from_syntax_node(syntax_db, binary.clone()))
+            }) {
+                let binary = ast::ExprBinary::from_syntax_node(syntax_db, binary);
+                return dot_completions(db, module_file_id, lookup_items, binary.lhs(syntax_db))
+                    .unwrap_or_default();
+            }
+            if path.children(syntax_db).next().as_ref() == Some(&grandparent) {
+                generic_completions(db, module_file_id, lookup_items, &node)
+            } else {
+                previous_segment_completions(db, lookup_items, &path, &grandparent)
+            }
+        }
+        _ => generic_completions(db, module_file_id, lookup_items, &node),
+    }
+}
+
+/// Returns true if the binary expression is a member access (`a.b`), and not a method call.
+fn is_dot_rhs(db: &dyn SemanticGroup, binary: &ast::ExprBinary) -> bool {
+    let syntax_db = db.upcast();
+    matches!(binary.op(syntax_db), ast::BinaryOperator::Dot(_))
+        && matches!(binary.rhs(syntax_db), ast::Expr::Path(_))
+}
+
+/// Returns the completions of the items that are visible without a path: the items of the current
+/// module and of the core crate, the crates, and the local variables in scope at `node`.
+pub fn generic_completions(
+    db: &(dyn SemanticGroup + 'static),
+    module_file_id: ModuleFileId,
+    lookup_items: &[LookupItemId],
+    node: &SyntaxNode,
+) -> Vec<CompletionItem> {
+    let mut completions = vec![];
+    if let Some(function_id) = lookup_items.first().and_then(|item| function_with_body(*item)) {
+        completions.extend(local_completions(db, function_id, node));
+    }
+    completions.extend(module_completions(db, module_file_id.0));
+    completions.extend(db.crates().into_iter().map(|crate_id| CompletionItem {
+        label: db.lookup_intern_crate(crate_id).0.into(),
+        kind: Some(CompletionItemKind::MODULE),
+        ..CompletionItem::default()
+    }));
+    if module_file_id.0 != core_module(db) {
+        completions.extend(module_completions(db, core_module(db)));
+    }
+    completions
+}
+
+/// Returns the completions after `::`, given the path segment before it.
+fn previous_segment_completions(
+    db: &(dyn SemanticGroup + 'static),
+    lookup_items: &[LookupItemId],
+    path: &SyntaxNode,
+    segment_or_separator: &SyntaxNode,
+) -> Vec<CompletionItem> {
+    let syntax_db = db.upcast();
+    // The children of a path alternate between segments and separators.
+    let children = path.children(syntax_db).collect::<Vec<_>>();
+    let Some(index) = children.iter().position(|child| child == segment_or_separator) else {
+        return vec![];
+    };
+    let previous_segment = if segment_or_separator.kind(syntax_db) == SyntaxKind::TerminalColonColon
+    {
+        index.checked_sub(1)
+    } else {
+        index.checked_sub(2)
+    };
+    let Some(previous_segment) = previous_segment.map(|index| children[index].clone()) else {
+        return vec![];
+    };
+    let segment = ast::PathSegment::from_syntax_node(syntax_db, previous_segment);
+    colon_colon_completions(db, lookup_items, segment).unwrap_or_default()
+}
+
+/// Returns the completions after `segment::`, according to the item `segment` was resolved to:
+/// the items of a module, or the variants of an enum.
+pub fn colon_colon_completions(
+    db: &(dyn SemanticGroup + 'static),
+    lookup_items: &[LookupItemId],
+    segment: ast::PathSegment,
+) -> Option<Vec<CompletionItem>> {
+    let identifier = segment.identifier_ast(db.upcast());
+    let item = lookup_items
+        .iter()
+        .find_map(|item| db.lookup_resolved_generic_item_by_ptr(*item, identifier.stable_ptr()))?;
+    Some(match item {
+        ResolvedGenericItem::Module(module_id) => module_completions(db, module_id),
+        ResolvedGenericItem::GenericType(GenericTypeId::Enum(enum_id)) => {
+            variant_completions(db, enum_id)?
+        }
+        _ => vec![],
+    })
+}
+
+/// Returns the completions after `expr.`: the members of the struct type of `expr`, and the methods
+/// that may be called on it.
+pub fn dot_completions(
+    db: &(dyn SemanticGroup + 'static),
+    module_file_id: ModuleFileId,
+    lookup_items: &[LookupItemId],
+    expr: ast::Expr,
+) -> Option<Vec<CompletionItem>> {
+    let function_id = function_with_body(*lookup_items.first()?)?;
+    let expr_id = db.lookup_expr_by_ptr(function_id, expr.stable_ptr())?;
+    let ty = db.expr_semantic(function_id, expr_id).ty();
+    let mut completions = vec![];
+    if let TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) =
+        db.lookup_intern_type(ty)
+    {
+        let members = db.concrete_struct_members(concrete_struct_id)?;
+        completions.extend(members.iter().map(|(name, member)| CompletionItem {
+            label: name.to_string(),
+            kind: Some(CompletionItemKind::FIELD),
+            detail: Some(member.ty.format(db)),
+            ..CompletionItem::default()
+        }));
+    }
+    let lookup_context = ImplLookupContext {
+        module_id: module_file_id.0,
+        extra_modules: vec![],
+        generic_params: db.function_with_body_generic_params(function_id)?,
+    };
+    // A method may be implemented by several impls, in which case calling it is ambiguous, but it
+    // is still completed once.
+    let mut method_names = OrderedHashSet::default();
+    for (concrete_impl_id, impl_function_id) in
+        find_method_candidates(db, &lookup_context, ty, None)
+    {
+        let name = impl_function_id.name(db.upcast());
+        if !method_names.insert(name.clone()) {
+            continue;
+        }
+        // The signature is specialized for the impl, unless the method has generic params of its
+        // own.
+        let function_id = db.intern_function(FunctionLongId {
+            function: ConcreteFunction {
+                generic_function: GenericFunctionId::ImplFunction(impl_function_id),
+                generic_args: db.lookup_intern_concrete_impl(concrete_impl_id).generic_args,
+            },
+        });
+        let signature = db
+            .concrete_function_signature(function_id)
+            .or_else(|| db.impl_function_signature(impl_function_id));
+        if let Some(signature) = signature {
+            completions.push(function_completion(db, name.into(), &signature, true));
+        }
+    }
+    Some(completions)
+}
+
+/// Returns the completions of the items of a module.
+fn module_completions(
+    db: &(dyn SemanticGroup + 'static),
+    module_id: ModuleId,
+) -> Vec<CompletionItem> {
+    let Some(module_items) = db.module_items(module_id) else { return vec![] };
+    module_items
+        .items
+        .iter()
+        .filter_map(|(name, item)| {
+            let resolved_item = match *item {
+                ModuleItemId::Submodule(id) => ResolvedGenericItem::Module(ModuleId::Submodule(id)),
+                ModuleItemId::Use(id) => db.use_resolved_item(id)?,
+                ModuleItemId::FreeFunction(id) => {
+                    ResolvedGenericItem::GenericFunction(GenericFunctionId::Free(id))
+                }
+                ModuleItemId::Constant(id) => ResolvedGenericItem::Constant(id),
+                ModuleItemId::ExternFunction(id) => {
+                    ResolvedGenericItem::GenericFunction(GenericFunctionId::Extern(id))
+                }
+                ModuleItemId::Struct(id) => {
+                    ResolvedGenericItem::GenericType(GenericTypeId::Struct(id))
+                }
+                ModuleItemId::Enum(id) => ResolvedGenericItem::GenericType(GenericTypeId::Enum(id)),
+                ModuleItemId::ExternType(id) => {
+                    ResolvedGenericItem::GenericType(GenericTypeId::Extern(id))
+                }
+                ModuleItemId::Trait(id) => ResolvedGenericItem::Trait(id),
+                // Impls are not referred to by name.
+                ModuleItemId::Impl(_) => return None,
+            };
+            Some(item_completion(db, name.to_string(), resolved_item))
+        })
+        .collect()
+}
+
+/// Returns the completion of an item named `name`. Functions are completed with a snippet of their
+/// parameters.
+fn item_completion(
+    db: &(dyn SemanticGroup + 'static),
+    name: String,
+    item: ResolvedGenericItem,
+) -> CompletionItem {
+    let kind = match item {
+        ResolvedGenericItem::Module(_) => CompletionItemKind::MODULE,
+        ResolvedGenericItem::Constant(_) => CompletionItemKind::CONSTANT,
+        ResolvedGenericItem::GenericFunction(function_id) => {
+            if let Some(signature) = db.generic_function_signature(function_id) {
+                return function_completion(db, name, &signature, false);
+            }
+            CompletionItemKind::FUNCTION
+        }
+        ResolvedGenericItem::GenericType(GenericTypeId::Struct(_)) => CompletionItemKind::STRUCT,
+        ResolvedGenericItem::GenericType(GenericTypeId::Enum(_)) => CompletionItemKind::ENUM,
+        ResolvedGenericItem::GenericType(GenericTypeId::Extern(_)) => CompletionItemKind::CLASS,
+        ResolvedGenericItem::Variant(_) => CompletionItemKind::ENUM_MEMBER,
+        ResolvedGenericItem::Trait(_) => CompletionItemKind::INTERFACE,
+        ResolvedGenericItem::Impl(_) => CompletionItemKind::CLASS,
+    };
+    CompletionItem { label: name, kind: Some(kind), ..CompletionItem::default() }
+}
+
+/// Returns the completion of a function, with its signature as detail, and a snippet of its
+/// parameters as the inserted text. The `self` parameter of a method is not part of the snippet.
+fn function_completion(
+    db: &(dyn SemanticGroup + 'static),
+    name: String,
+    signature: &Signature,
+    is_method: bool,
+) -> CompletionItem {
+    let defs_db = db.upcast();
+    let params =
+        signature.params.iter().map(|param| param_label(db, param)).collect::<Vec<_>>().join(", ");
+    let return_type = if signature.return_type == unit_ty(db) {
+        String::new()
+    } else {
+        format!(" -> {}", signature.return_type.format(db))
+    };
+    let snippet_params = signature
+        .params
+        .iter()
+        .skip(usize::from(is_method))
+        .enumerate()
+        .map(|(index, param)| format!("${{{}:{}}}", index + 1, param.id.name(defs_db)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    CompletionItem {
+        detail: Some(format!("func {name}({params}){return_type}")),
+        insert_text: Some(format!("{name}({snippet_params})")),
+        insert_text_format: Some(InsertTextFormat::SNIPPET),
+        label: name,
+        kind: Some(if is_method {
+            CompletionItemKind::METHOD
+        } else {
+            CompletionItemKind::FUNCTION
+        }),
+        ..CompletionItem::default()
+    }
+}
+
+/// Returns the completions of the variants of an enum.
+fn variant_completions(
+    db: &(dyn SemanticGroup + 'static),
+    enum_id: EnumId,
+) -> Option<Vec<CompletionItem>> {
+    let variants = db.enum_variants(enum_id)?;
+    Some(
+        variants
+            .iter()
+            .map(|(name, variant_id)| CompletionItem {
+                label: name.to_string(),
+                kind: Some(CompletionItemKind::ENUM_MEMBER),
+                detail: db
+                    .variant_semantic(enum_id, *variant_id)
+                    .map(|variant| variant.ty.format(db)),
+                ..CompletionItem::default()
+            })
+            .collect(),
+    )
+}
+
+/// Returns the completions of the parameters of a function, and of its local variables that are
+/// in scope at `node`.
+fn local_completions(
+    db: &(dyn SemanticGroup + 'static),
+    function_id: FunctionWithBodyId,
+    node: &SyntaxNode,
+) -> Vec<CompletionItem> {
+    let defs_db = db.upcast();
+    let syntax_db = db.upcast();
+    let mut completions = vec![];
+    let mut names = OrderedHashSet::default();
+    let mut add = |completions: &mut Vec<CompletionItem>, name: String, detail: String| {
+        // Inner variables shadow outer ones, and are added first.
+        if names.insert(name.clone()) {
+            completions.push(CompletionItem {
+                label: name,
+                kind: Some(CompletionItemKind::VARIABLE),
+                detail: Some(detail),
+                ..CompletionItem::default()
+            });
+        }
+    };
+
+    let Some(definition) = db.function_with_body_definition(function_id) else { return vec![] };
+    let offset = node.offset();
+    // The scopes containing the node, from the innermost.
+    let mut scopes = vec![];
+    let mut root = node.clone();
+    while let Some(parent) = root.parent() {
+        root = parent;
+        if matches!(root.kind(syntax_db), SyntaxKind::ExprBlock | SyntaxKind::ExprMatch) {
+            scopes.push(ast::Expr::from_syntax_node(syntax_db, root.clone()));
+        }
+    }
+    for scope in scopes {
+        let Some(expr_id) = db.lookup_expr_by_ptr(function_id, scope.stable_ptr()) else {
+            continue;
+        };
+        match &definition.exprs[expr_id] {
+            Expr::Block(block) => {
+                // Only variables defined by statements that end before the offset are in scope.
+                for statement_id in block.statements.iter().rev() {
+                    let Statement::Let(statement) = &definition.statements[*statement_id] else {
+                        continue;
+                    };
+                    let statement_node = root.lookup_ptr(syntax_db, statement.stable_ptr.untyped());
+                    if statement_node.span_end_without_trivia(syntax_db) > offset {
+                        continue;
+                    }
+                    for variable in statement.pattern.variables().into_iter().rev() {
+                        add(
+                            &mut completions,
+                            variable.name.to_string(),
+                            variable.var.ty.format(db),
+                        );
+                    }
+                }
+            }
+            Expr::Match(expr_match) => {
+                // Only the variables of the arm containing the offset are in scope.
+                for arm in &expr_match.arms {
+                    let arm_node = root.lookup_ptr(
+                        syntax_db,
+                        definition.exprs[arm.expression].stable_ptr().untyped(),
+                    );
+                    let span = arm_node.span(syntax_db);
+                    if span.start > offset || span.end < offset {
+                        continue;
+                    }
+                    for variable in arm.pattern.variables() {
+                        add(
+                            &mut completions,
+                            variable.name.to_string(),
+                            variable.var.ty.format(db),
+                        );
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    if let Some(signature) = db.function_with_body_signature(function_id) {
+        for param in signature.params {
+            add(&mut completions, param.id.name(defs_db).to_string(), param.ty.format(db));
+        }
+    }
+    completions
+}
+
+/// Returns the function with a body of a lookup item, if it is such a function.
+pub fn function_with_body(lookup_item_id: LookupItemId) -> Option<FunctionWithBodyId> {
+    match lookup_item_id {
+        LookupItemId::ModuleItem(ModuleItemId::FreeFunction(free_function_id)) => {
+            Some(FunctionWithBodyId::Free(free_function_id))
+        }
+        LookupItemId::ImplFunction(impl_function_id) => {
+            Some(FunctionWithBodyId::Impl(impl_function_id))
+        }
+        _ => None,
+    }
+}
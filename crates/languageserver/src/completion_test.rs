@@ -0,0 +1,209 @@
+use std::sync::Arc;
+
+use filesystem::db::{FilesGroup, FilesGroupEx};
+use filesystem::ids::FileLongId;
+use indoc::indoc;
+use semantic::test_utils::setup_test_crate;
+use test_case::test_case;
+use tower_lsp::lsp_types::{CompletionItem, CompletionItemKind, Position};
+
+use super::completions_at;
+use crate::db::RootDatabase;
+use crate::{find_module_file_id, get_node_and_lookup_items};
+
+/// The marker of the position of the cursor in the test code.
+const CARET: &str = "<caret>";
+
+/// Sets up a crate whose root module has `root_code`, with a [CARET], and a submodule `submodule`
+/// with `submodule_code`. Returns the completions at the caret.
+fn completions_at_caret(root_code: &str, submodule_code: &str) -> Vec<CompletionItem> {
+    let mut db = RootDatabase::default();
+    let root_code = format!("mod submodule;\n{root_code}");
+    setup_test_crate(&mut db, &root_code.replace(CARET, ""));
+    let submodule_file = db.intern_file(FileLongId::OnDisk("src/submodule.cairo".into()));
+    db.override_file_content(submodule_file, Some(Arc::new(submodule_code.into())));
+    let root_file = db.intern_file(FileLongId::OnDisk("src/lib.cairo".into()));
+
+    let (line, line_text) =
+        root_code.lines().enumerate().find(|(_, line_text)| line_text.contains(CARET)).unwrap();
+    // Completions are according to the token right before the cursor.
+    let position =
+        Position { line: line as u32, character: line_text.find(CARET).unwrap() as u32 - 1 };
+    let (node, lookup_items) = get_node_and_lookup_items(&db, root_file, position).unwrap();
+    let module_file_id = find_module_file_id(&db, root_file).unwrap();
+    completions_at(&db, module_file_id, &lookup_items, node)
+}
+
+#[test_case(
+    indoc! {"
+        func foo() -> felt {
+            submodule::<caret>
+        }
+    "},
+    &["bar", "A", "B"];
+    "items of a module"
+)]
+#[test_case(
+    indoc! {"
+        func foo() -> felt {
+            submodule::ba<caret>
+        }
+    "},
+    &["bar", "A", "B"];
+    "items of a module while typing"
+)]
+#[test_case(
+    indoc! {"
+        use submodule::B;
+        func foo() -> B {
+            B::<caret>
+        }
+    "},
+    &["First", "Second"];
+    "variants of an enum"
+)]
+#[test_case(
+    indoc! {"
+        func foo(a: submodule::A) -> felt {
+            a.<caret>
+        }
+    "},
+    &["x", "y"];
+    "members of a struct"
+)]
+#[test_case(
+    indoc! {"
+        func foo(a: submodule::A) -> felt {
+            a.y<caret>
+        }
+    "},
+    &["x", "y"];
+    "members of a struct while typing"
+)]
+#[test_case(
+    indoc! {"
+        func foo(ref a: Array::<felt>) {
+            a.<caret>
+        }
+    "},
+    &["append", "at", "len"];
+    "methods"
+)]
+#[test_case(
+    indoc! {"
+        func foo() -> felt {
+            1.<caret>
+        }
+    "},
+    &[];
+    "no members"
+)]
+fn test_completions(root_code: &str, expected_labels: &[&str]) {
+    let submodule_code = indoc! {"
+        struct A { x: felt, y: felt }
+        enum B { First: (), Second: felt }
+        func bar(a: felt, ref b: A) -> felt { a }
+    "};
+    let mut labels: Vec<_> = completions_at_caret(root_code, submodule_code)
+        .into_iter()
+        .map(|completion| completion.label)
+        .collect();
+    labels.sort();
+    let mut expected_labels = expected_labels.to_vec();
+    expected_labels.sort();
+    assert_eq!(labels, expected_labels);
+}
+
+#[test_case(
+    indoc! {"
+        func foo(a: felt) {
+            let b = 1;
+            {
+                let (c, d) = (2, 3);
+                <caret>
+                let e = 4;
+            }
+            let f = 5;
+        }
+    "},
+    &["d", "c", "b", "a"];
+    "block scopes"
+)]
+#[test_case(
+    indoc! {"
+        func foo(a: Option::<felt>) -> felt {
+            let b = 1;
+            match a {
+                Option::Some(c) => {
+                    <caret>
+                    c
+                },
+                Option::None(d) => 0,
+            }
+        }
+    "},
+    &["c", "b", "a"];
+    "match arm"
+)]
+#[test_case(
+    indoc! {"
+        func foo(a: felt) -> felt {
+            let a = 1;
+            <caret>
+        }
+    "},
+    &["a"];
+    "shadowed variable"
+)]
+fn test_local_completions(root_code: &str, expected_labels: &[&str]) {
+    let labels: Vec<_> = completions_at_caret(root_code, "")
+        .into_iter()
+        .filter(|completion| completion.kind == Some(CompletionItemKind::VARIABLE))
+        .map(|completion| completion.label)
+        .collect();
+    assert_eq!(labels, expected_labels);
+}
+
+#[test_case(
+    indoc! {"
+        func foo() -> felt {
+            submodule::<caret>
+        }
+    "},
+    "bar",
+    CompletionItemKind::FUNCTION,
+    "func bar(a: core::felt, ref b: test::submodule::A) -> core::felt",
+    "bar(${1:a}, ${2:b})";
+    "function"
+)]
+#[test_case(
+    indoc! {"
+        func foo(ref a: Array::<felt>) {
+            a.<caret>
+        }
+    "},
+    "append",
+    CompletionItemKind::METHOD,
+    "func append(ref self: core::array::Array::<core::felt>, value: core::felt)",
+    "append(${1:value})";
+    "method"
+)]
+fn test_function_completion(
+    root_code: &str,
+    label: &str,
+    kind: CompletionItemKind,
+    detail: &str,
+    insert_text: &str,
+) {
+    let submodule_code = indoc! {"
+        struct A { x: felt, y: felt }
+        func bar(a: felt, ref b: A) -> felt { a }
+    "};
+    let completion = completions_at_caret(root_code, submodule_code)
+        .into_iter()
+        .find(|completion| completion.label == label)
+        .unwrap();
+    assert_eq!(completion.kind, Some(kind));
+    assert_eq!(completion.detail.as_deref(), Some(detail));
+    assert_eq!(completion.insert_text.as_deref(), Some(insert_text));
+}
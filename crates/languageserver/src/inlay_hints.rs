@@ -0,0 +1,111 @@
+//! Inlay hints of the inferred types of variables, computed from the semantic model.
+
+#[cfg(test)]
+#[path = "inlay_hints_test.rs"]
+mod test;
+
+use defs::ids::{FunctionWithBodyId, ModuleFileId};
+use filesystem::ids::FileId;
+use semantic::db::SemanticGroup;
+use semantic::{Expr, LocalVariable, Statement, TypeId};
+use syntax::node::kind::SyntaxKind;
+use syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use tower_lsp::lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, Range};
+
+use crate::completion::function_with_body;
+use crate::{from_pos, lookup_item_from_ast};
+
+/// Returns the type hints of the variables defined in `range` of a module file: the variables of
+/// `let` statements without a type clause, and the variables of match arms.
+pub fn inlay_hints(
+    db: &(dyn SemanticGroup + 'static),
+    module_file_id: ModuleFileId,
+    range: Range,
+) -> Option<Vec<InlayHint>> {
+    let file = db.module_file(module_file_id)?;
+    let root = db.file_syntax(file)?.as_syntax_node();
+    let mut hints = vec![];
+    collect_inlay_hints(db, module_file_id, file, &root, root.clone(), &mut hints);
+    hints.retain(|hint| range.start <= hint.position && hint.position <= range.end);
+    hints.sort_by_key(|hint| hint.position);
+    Some(hints)
+}
+
+/// Adds the hints of the functions in the subtree of `node`.
+fn collect_inlay_hints(
+    db: &(dyn SemanticGroup + 'static),
+    module_file_id: ModuleFileId,
+    file: FileId,
+    root: &SyntaxNode,
+    node: SyntaxNode,
+    hints: &mut Vec<InlayHint>,
+) {
+    let syntax_db = db.upcast();
+    if node.kind(syntax_db) == SyntaxKind::ItemFreeFunction {
+        let function_id =
+            lookup_item_from_ast(db, module_file_id, node).and_then(function_with_body);
+        if let Some(function_id) = function_id {
+            function_inlay_hints(db, file, root, function_id, hints);
+        }
+        return;
+    }
+    for child in node.children(syntax_db) {
+        collect_inlay_hints(db, module_file_id, file, root, child, hints);
+    }
+}
+
+/// Adds the hints of the variables of a function.
+fn function_inlay_hints(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    root: &SyntaxNode,
+    function_id: FunctionWithBodyId,
+    hints: &mut Vec<InlayHint>,
+) {
+    let syntax_db = db.upcast();
+    let Some(definition) = db.function_with_body_definition(function_id) else { return };
+    let mut variables = vec![];
+    for (_, statement) in definition.statements.iter() {
+        let Statement::Let(statement) = statement else { continue };
+        let statement_node = root.lookup_ptr(syntax_db, statement.stable_ptr.untyped());
+        let statement_syntax = ast::StatementLet::from_syntax_node(syntax_db, statement_node);
+        // The type is already written.
+        if let ast::OptionTypeClause::TypeClause(_) = statement_syntax.type_clause(syntax_db) {
+            continue;
+        }
+        variables.extend(statement.pattern.variables().into_iter().map(|variable| &variable.var));
+    }
+    for (_, expr) in definition.exprs.iter() {
+        let Expr::Match(expr_match) = expr else { continue };
+        for arm in &expr_match.arms {
+            variables.extend(arm.pattern.variables().into_iter().map(|variable| &variable.var));
+        }
+    }
+    hints.extend(variables.into_iter().filter_map(|variable| type_hint(db, file, root, variable)));
+}
+
+/// Returns the hint of the type of a variable, right after its name.
+fn type_hint(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    root: &SyntaxNode,
+    variable: &LocalVariable,
+) -> Option<InlayHint> {
+    if variable.ty == TypeId::missing(db) {
+        return None;
+    }
+    let syntax_db = db.upcast();
+    let name = root.lookup_ptr(syntax_db, variable.stable_ptr(db.upcast()).untyped());
+    let position =
+        from_pos(name.span_end_without_trivia(syntax_db).position_in_file(db.upcast(), file)?);
+    Some(InlayHint {
+        position,
+        label: InlayHintLabel::String(format!(": {}", variable.ty.format(db))),
+        kind: Some(InlayHintKind::TYPE),
+        text_edits: None,
+        tooltip: None,
+        padding_left: None,
+        padding_right: None,
+        data: None,
+    })
+}
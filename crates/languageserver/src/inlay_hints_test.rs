@@ -0,0 +1,66 @@
+use defs::ids::{FileIndex, ModuleFileId, ModuleId};
+use indoc::indoc;
+use semantic::test_utils::setup_test_crate;
+use tower_lsp::lsp_types::{InlayHintLabel, Position, Range};
+
+use super::inlay_hints;
+use crate::db::RootDatabase;
+
+/// Sets up a crate with `code`, and returns its inlay hints in `range`, formatted as
+/// `line:character label`.
+fn inlay_hints_in_range(code: &str, range: Range) -> Vec<String> {
+    let mut db = RootDatabase::default();
+    let crate_id = setup_test_crate(&mut db, code);
+    let module_file_id = ModuleFileId(ModuleId::CrateRoot(crate_id), FileIndex(0));
+    inlay_hints(&db, module_file_id, range)
+        .unwrap()
+        .into_iter()
+        .map(|hint| {
+            let InlayHintLabel::String(label) = hint.label else { panic!("Unexpected label.") };
+            format!("{}:{} {label}", hint.position.line, hint.position.character)
+        })
+        .collect()
+}
+
+const CODE: &str = indoc! {"
+    struct A { x: felt, y: bool }
+    func foo(a: Option::<felt>) -> felt {
+        let b = 1;
+        let c: felt = 2;
+        let (d, _) = (true, c);
+        let _ = 3;
+        let A { x, y: e } = A { x: b, y: d };
+        match a {
+            Option::Some(f) => f,
+            Option::None(_) => x,
+        }
+    }
+"};
+
+#[test]
+fn test_inlay_hints() {
+    let range = Range {
+        start: Position { line: 0, character: 0 },
+        end: Position { line: CODE.lines().count() as u32, character: 0 },
+    };
+    // Explicitly typed variables and `_` patterns get no hints.
+    assert_eq!(
+        inlay_hints_in_range(CODE, range),
+        [
+            "2:9 : core::felt",
+            "4:10 : core::bool",
+            "6:13 : core::felt",
+            "6:19 : core::bool",
+            "8:22 : core::felt",
+        ]
+    );
+}
+
+#[test]
+fn test_inlay_hints_in_range() {
+    let range = Range {
+        start: Position { line: 3, character: 0 },
+        end: Position { line: 5, character: 0 },
+    };
+    assert_eq!(inlay_hints_in_range(CODE, range), ["4:10 : core::bool"]);
+}
@@ -1,42 +1,59 @@
 //! Cairo language server. Implements the LSP protocol over stdin/out.
 
+mod code_actions;
+mod completion;
 mod db;
+mod inlay_hints;
+mod references;
 mod semantic_highlighting;
+mod signature_help;
+mod symbols;
+#[cfg(test)]
+mod test;
 
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
+use code_actions::code_actions;
+use completion::completions_at;
 pub use db::RootDatabase;
 use db_utils::Upcast;
 use debug::DebugWithDb;
 use defs::db::DefsGroup;
 use defs::ids::{
-    EnumLongId, ExternFunctionLongId, ExternTypeLongId, FileIndex, FreeFunctionId,
-    FreeFunctionLongId, ImplLongId, LanguageElementId, LookupItemId, ModuleFileId, ModuleItemId,
-    StructLongId, TraitLongId, UseLongId,
+    ConstantLongId, EnumLongId, ExternFunctionLongId, ExternTypeLongId, FileIndex,
+    FreeFunctionLongId, FunctionWithBodyId, ImplFunctionLongId, ImplLongId, LanguageElementId,
+    LookupItemId, ModuleFileId, ModuleId, ModuleItemId, StructLongId, TraitLongId, UseLongId,
 };
-use diagnostics::{DiagnosticEntry, Diagnostics};
+use diagnostics::{DiagnosticEntry, Diagnostics, RelatedLocation, Severity};
 use filesystem::db::{AsFilesGroupMut, FilesGroup, FilesGroupEx, PrivRawFileContentQuery};
 use filesystem::ids::{FileId, FileLongId};
-use filesystem::span::TextPosition;
-use formatter::{get_formatted_file, FormatterConfig};
+use filesystem::span::{TextOffset, TextPosition, TextSpan};
+use formatter::{get_formatted_file, get_formatted_range, FormatterConfig};
+use inlay_hints::inlay_hints;
 use lowering::db::LoweringGroup;
 use lowering::diagnostic::LoweringDiagnostic;
 use parser::db::ParserGroup;
+use parser::lexer::Lexer;
 use parser::ParserDiagnostic;
 use project::ProjectConfig;
+use references::{find_references, symbol_at, Symbol};
 use semantic::db::SemanticGroup;
-use semantic::items::free_function::SemanticExprLookup;
+use semantic::items::function_with_body::SemanticExprLookup;
 use semantic::resolve_path::ResolvedGenericItem;
 use semantic::SemanticDiagnostic;
 use semantic_highlighting::token_kind::SemanticTokenKind;
 use semantic_highlighting::SemanticTokensTraverser;
 use serde_json::Value;
+use signature_help::signature_help_at;
+use symbols::{document_symbols, folding_ranges, workspace_symbols};
 use syntax::node::db::SyntaxGroup;
 use syntax::node::kind::SyntaxKind;
 use syntax::node::stable_ptr::SyntaxStablePtr;
-use syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use syntax::node::{ast, SyntaxNode, Terminal, TypedSyntaxNode};
 use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer};
@@ -44,28 +61,47 @@ use utils::ordered_hash_set::OrderedHashSet;
 use utils::OptionHelper;
 
 const MAX_CRATE_DETECTION_DEPTH: usize = 20;
+/// The time to wait after a change before refreshing the diagnostics, so that they are not
+/// recomputed on every keystroke.
+const DIAGNOSTICS_DEBOUNCE: Duration = Duration::from_millis(200);
 
 #[derive(Default, PartialEq, Eq)]
 pub struct FileDiagnostics {
     pub parser: Diagnostics<ParserDiagnostic>,
     pub semantic: Diagnostics<SemanticDiagnostic>,
     pub lowering: Diagnostics<LoweringDiagnostic>,
+    pub lints: Diagnostics<SemanticDiagnostic>,
 }
 #[derive(Default)]
 pub struct State {
     pub file_diagnostics: HashMap<FileId, FileDiagnostics>,
     pub open_files: HashSet<FileId>,
 }
+/// The language server. Clones share the same database and state, so that work can be moved to
+/// background tasks.
+#[derive(Clone)]
 pub struct Backend {
     pub client: Client,
     // TODO(spapini): Remove this once we support ParallelDatabase.
-    pub db_mutex: tokio::sync::Mutex<RootDatabase>,
-    pub state_mutex: tokio::sync::Mutex<State>,
+    pub db_mutex: Arc<tokio::sync::Mutex<RootDatabase>>,
+    pub state_mutex: Arc<tokio::sync::Mutex<State>>,
+    /// The number of diagnostics refreshes scheduled so far. A refresh is stale once a newer one
+    /// is scheduled.
+    pub diagnostics_generation: Arc<AtomicU64>,
 }
 fn from_pos(pos: TextPosition) -> Position {
     Position { line: pos.line as u32, character: pos.col as u32 }
 }
 impl Backend {
+    /// Creates a language server, communicating with `client`, over the given database.
+    pub fn new(client: Client, db: RootDatabase) -> Self {
+        Self {
+            client,
+            db_mutex: Arc::new(db.into()),
+            state_mutex: Default::default(),
+            diagnostics_generation: Default::default(),
+        }
+    }
     /// Locks and gets a database instance.
     async fn db(&self) -> tokio::sync::MutexGuard<'_, RootDatabase> {
         self.db_mutex.lock().await
@@ -75,23 +111,51 @@ impl Backend {
         let path = uri.to_file_path().expect("Only file URIs are supported.");
         FileId::new(db, path)
     }
+    /// Schedules a refresh of the diagnostics after a short delay, in a background task, so that
+    /// the notification that caused it is not held. The refresh is skipped, or stopped, if another
+    /// one is scheduled in the meantime.
+    fn schedule_diagnostics_refresh(&self) {
+        let generation = self.diagnostics_generation.fetch_add(1, Ordering::SeqCst) + 1;
+        let backend = self.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(DIAGNOSTICS_DEBOUNCE).await;
+            backend.refresh_diagnostics(generation).await;
+        });
+    }
+    /// Returns true if a refresh of the diagnostics newer than `generation` was scheduled.
+    fn is_stale(&self, generation: u64) -> bool {
+        self.diagnostics_generation.load(Ordering::SeqCst) != generation
+    }
     // Refresh diagnostics and send diffs to client.
-    async fn refresh_diagnostics(&self) {
-        let db = self.db().await;
-        let mut state = self.state_mutex.lock().await;
-
+    async fn refresh_diagnostics(&self, generation: u64) {
+        if self.is_stale(generation) {
+            return;
+        }
         // Get all files. Try to go over open files first.
-        let mut files_set: OrderedHashSet<_> = state.open_files.iter().copied().collect();
-        for crate_id in db.crates() {
-            for module_id in db.crate_modules(crate_id).iter() {
-                for file_id in db.module_files(*module_id).unwrap_or_default() {
-                    files_set.insert(file_id);
+        let files_set: OrderedHashSet<_> = {
+            let db = self.db().await;
+            let state = self.state_mutex.lock().await;
+            let mut files_set: OrderedHashSet<_> = state.open_files.iter().copied().collect();
+            for crate_id in db.crates() {
+                for module_id in db.crate_modules(crate_id).iter() {
+                    for file_id in db.module_files(*module_id).unwrap_or_default() {
+                        files_set.insert(file_id);
+                    }
                 }
             }
-        }
+            files_set
+        };
 
-        // Get all diagnostics.
+        // Get all diagnostics. The database is locked for one file at a time, so that changes are
+        // not blocked by a whole refresh, and a stale refresh stops at the next file.
+        // Note that the computation for a single file is not cancelled: salsa can only cancel
+        // queries running on database snapshots, which requires a ParallelDatabase (see the TODO
+        // on `db_mutex`). Until then, a change waits for the diagnostics of the current file.
         for file_id in files_set.iter().copied() {
+            if self.is_stale(generation) {
+                return;
+            }
+            let db = self.db().await;
             let uri = if let FileLongId::OnDisk(path) = db.lookup_intern_file(file_id) {
                 Url::from_file_path(path).unwrap()
             } else {
@@ -101,7 +165,9 @@ impl Backend {
                 parser: db.file_syntax_diagnostics(file_id),
                 semantic: db.file_semantic_diagnostics(file_id).unwrap_or_default(),
                 lowering: db.file_lowering_diagnostics(file_id).unwrap_or_default(),
+                lints: db.file_lint_diagnostics(file_id).unwrap_or_default(),
             };
+            let mut state = self.state_mutex.lock().await;
             // Since we are using Arcs, this comparison should be efficient.
             if let Some(old_file_diagnostics) = state.file_diagnostics.get(&file_id) {
                 if old_file_diagnostics == &new_file_diagnostics {
@@ -112,12 +178,17 @@ impl Backend {
             self.get_diagnostics((*db).upcast(), &mut diags, &new_file_diagnostics.parser);
             self.get_diagnostics((*db).upcast(), &mut diags, &new_file_diagnostics.semantic);
             self.get_diagnostics((*db).upcast(), &mut diags, &new_file_diagnostics.lowering);
+            self.get_diagnostics((*db).upcast(), &mut diags, &new_file_diagnostics.lints);
             state.file_diagnostics.insert(file_id, new_file_diagnostics);
+            drop(state);
+            drop(db);
 
             self.client.publish_diagnostics(uri, diags, None).await
         }
 
         // Clear old diagnostics.
+        let db = self.db().await;
+        let mut state = self.state_mutex.lock().await;
         let old_files: Vec<_> = state.file_diagnostics.keys().copied().collect();
         for file_id in old_files {
             if files_set.contains(&file_id) {
@@ -149,9 +220,48 @@ impl Backend {
             let end = from_pos(
                 location.span.start.position_in_file(db.upcast(), location.file_id).unwrap(),
             );
+            let severity = match diagnostic.severity() {
+                Severity::Error => DiagnosticSeverity::ERROR,
+                Severity::Warning => DiagnosticSeverity::WARNING,
+                Severity::Note => DiagnosticSeverity::INFORMATION,
+            };
+            let message = match diagnostic.help(db) {
+                Some(help) => format!("{message}\nhelp: {help}"),
+                None => message,
+            };
+            let related_information = diagnostic
+                .related(db)
+                .into_iter()
+                .filter_map(|RelatedLocation { location, label }| {
+                    let files_db = db.upcast();
+                    Some(DiagnosticRelatedInformation {
+                        location: Location {
+                            uri: file_uri(files_db, location.file_id)?,
+                            range: Range {
+                                start: from_pos(
+                                    location
+                                        .span
+                                        .start
+                                        .position_in_file(files_db, location.file_id)?,
+                                ),
+                                end: from_pos(
+                                    location
+                                        .span
+                                        .end
+                                        .position_in_file(files_db, location.file_id)?,
+                                ),
+                            },
+                        },
+                        message: label,
+                    })
+                })
+                .collect();
             diags.push(Diagnostic {
                 range: Range { start, end },
+                severity: Some(severity),
+                code: Some(NumberOrString::String(diagnostic.code().into())),
                 message,
+                related_information: Some(related_information),
                 ..Diagnostic::default()
             });
         }
@@ -165,11 +275,11 @@ impl LanguageServer for Backend {
             server_info: None,
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                    TextDocumentSyncKind::FULL,
+                    TextDocumentSyncKind::INCREMENTAL,
                 )),
                 completion_provider: Some(CompletionOptions {
                     resolve_provider: Some(false),
-                    trigger_characters: Some(vec![".".to_string()]),
+                    trigger_characters: Some(vec![".".to_string(), ":".to_string()]),
                     work_done_progress_options: Default::default(),
                     all_commit_characters: None,
                 }),
@@ -196,8 +306,33 @@ impl LanguageServer for Backend {
                     .into(),
                 ),
                 document_formatting_provider: Some(OneOf::Left(true)),
+                document_range_formatting_provider: Some(OneOf::Left(true)),
+                document_on_type_formatting_provider: Some(DocumentOnTypeFormattingOptions {
+                    first_trigger_character: ";".into(),
+                    more_trigger_character: Some(vec!["}".into()]),
+                }),
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
                 definition_provider: Some(OneOf::Left(true)),
+                references_provider: Some(OneOf::Left(true)),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: Default::default(),
+                })),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                workspace_symbol_provider: Some(OneOf::Left(true)),
+                folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+                signature_help_provider: Some(SignatureHelpOptions {
+                    trigger_characters: Some(vec!["(".to_string(), ",".to_string()]),
+                    retrigger_characters: None,
+                    work_done_progress_options: Default::default(),
+                }),
+                inlay_hint_provider: Some(OneOf::Left(true)),
+                code_action_provider: Some(CodeActionProviderCapability::Options(
+                    CodeActionOptions {
+                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+                        ..CodeActionOptions::default()
+                    },
+                )),
                 ..ServerCapabilities::default()
             },
         })
@@ -237,26 +372,25 @@ impl LanguageServer for Backend {
         let path = uri.path();
         detect_crate_for(&mut db, path);
 
+        // The opened text may differ from the file on disk, e.g. if the file is not saved.
         let file = self.file(&db, uri.clone());
+        db.override_file_content(file, Some(Arc::new(params.text_document.text)));
         self.state_mutex.lock().await.open_files.insert(file);
         drop(db);
-        self.refresh_diagnostics().await;
+        self.schedule_diagnostics_refresh();
     }
 
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
-        let text =
-            if let [TextDocumentContentChangeEvent { text, .. }] = &params.content_changes[..] {
-                text
-            } else {
-                eprintln!("Unexpected format of document change.");
-                return;
-            };
         let mut db = self.db().await;
         let uri = params.text_document.uri;
         let file = self.file(&db, uri.clone());
-        db.override_file_content(file, Some(Arc::new(text.into())));
+        let mut text = db.file_content(file).map(|text| text.to_string()).unwrap_or_default();
+        for change in params.content_changes {
+            apply_content_change(&mut text, change);
+        }
+        db.override_file_content(file, Some(Arc::new(text)));
         drop(db);
-        self.refresh_diagnostics().await;
+        self.schedule_diagnostics_refresh();
     }
 
     async fn did_save(&self, params: DidSaveTextDocumentParams) {
@@ -272,14 +406,20 @@ impl LanguageServer for Backend {
         self.state_mutex.lock().await.open_files.remove(&file);
         db.override_file_content(file, None);
         drop(db);
-        self.refresh_diagnostics().await;
+        self.schedule_diagnostics_refresh();
     }
 
-    async fn completion(&self, _: CompletionParams) -> Result<Option<CompletionResponse>> {
-        Ok(Some(CompletionResponse::Array(vec![
-            CompletionItem::new_simple("Hello".to_string(), "Some detail".to_string()),
-            CompletionItem::new_simple("Bye".to_string(), "More detail".to_string()),
-        ])))
+    async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
+        let db = self.db().await;
+        let file_uri = params.text_document_position.text_document.uri;
+        let file = self.file(&db, file_uri);
+        let mut position = params.text_document_position.position;
+        // Complete according to the token right before the cursor.
+        position.character = position.character.saturating_sub(1);
+        let Some((node, lookup_items)) = get_node_and_lookup_items(&*db, file, position) else {return Ok(None)};
+        let Some(module_file_id) = find_module_file_id(&*db, file) else {return Ok(None)};
+        let completions = completions_at(&*db, module_file_id, &lookup_items, node);
+        Ok(Some(CompletionResponse::Array(completions)))
     }
 
     async fn semantic_tokens_full(
@@ -312,11 +452,8 @@ impl LanguageServer for Backend {
             eprintln!("Formatting failed. File '{file_uri}' does not exist.");
             return Ok(None);
         };
-        let new_text = get_formatted_file(
-            (*db).upcast(),
-            &syntax.as_syntax_node(),
-            FormatterConfig::default(),
-        );
+        let config = formatter_config(&file_uri);
+        let new_text = get_formatted_file((*db).upcast(), &syntax.as_syntax_node(), config);
 
         let file_summary = if let Some(summary) = db.file_summary(file) {
             summary
@@ -340,22 +477,67 @@ impl LanguageServer for Backend {
         }]))
     }
 
+    async fn range_formatting(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        let db = self.db().await;
+        let file_uri = params.text_document.uri;
+        let file = self.file(&db, file_uri.clone());
+        let Some(content) = db.file_content(file) else {
+            eprintln!("Formatting failed. File '{file_uri}' does not exist.");
+            return Ok(None);
+        };
+        let start = position_to_offset(&content, params.range.start);
+        let end = position_to_offset(&content, params.range.end).max(start);
+        let span = TextSpan { start: TextOffset(start), end: TextOffset(end) };
+        Ok(format_range(&db, &file_uri, file, span))
+    }
+
+    async fn on_type_formatting(
+        &self,
+        params: DocumentOnTypeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        let db = self.db().await;
+        let file_uri = params.text_document_position.text_document.uri;
+        let file = self.file(&db, file_uri.clone());
+        let Some(content) = db.file_content(file) else { return Ok(None) };
+        // The code is usually incomplete while it is typed, and should not be formatted then.
+        if !db.file_syntax_diagnostics(file).get_all().is_empty() {
+            return Ok(None);
+        }
+        // Formats the statement or item ended by the typed character.
+        let offset = position_to_offset(&content, params.text_document_position.position);
+        if !content[..offset].ends_with(params.ch.as_str()) {
+            return Ok(None);
+        }
+        let offset = TextOffset(offset - params.ch.len());
+        Ok(format_range(&db, &file_uri, file, TextSpan { start: offset, end: offset }))
+    }
+
     async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
         let db = self.db().await;
         let file_uri = params.text_document_position_params.text_document.uri;
         let file = self.file(&db, file_uri);
         let position = params.text_document_position_params.position;
         let Some((node, lookup_items)) = get_node_and_lookup_items(&*db, file, position) else {return Ok(None)};
-        let Some(
-            LookupItemId::ModuleItem(ModuleItemId::FreeFunction(free_function_id))
-        ) = lookup_items.into_iter().next() else  {return Ok(None)};
+        let Some(lookup_item_id) = lookup_items.into_iter().next() else {return Ok(None)};
+        let function_id = match lookup_item_id {
+            LookupItemId::ModuleItem(ModuleItemId::FreeFunction(free_function_id)) => {
+                FunctionWithBodyId::Free(free_function_id)
+            }
+            LookupItemId::ImplFunction(impl_function_id) => {
+                FunctionWithBodyId::Impl(impl_function_id)
+            }
+            _ => return Ok(None),
+        };
 
         // Build texts.
         let mut hints = Vec::new();
-        if let Some(hint) = get_expr_hint(&*db, free_function_id, node.clone()) {
+        if let Some(hint) = get_expr_hint(&*db, function_id, node.clone()) {
             hints.push(MarkedString::String(hint));
         };
-        if let Some(hint) = get_identifier_hint(&*db, free_function_id, node) {
+        if let Some(hint) = get_identifier_hint(&*db, lookup_item_id, node) {
             hints.push(MarkedString::String(hint));
         };
 
@@ -386,6 +568,11 @@ impl LanguageServer for Backend {
                 ResolvedGenericItem::Module(item) => {
                     (item, FileIndex(0), db.intern_stable_ptr(SyntaxStablePtr::Root))
                 }
+                ResolvedGenericItem::Constant(item) => (
+                    item.module(defs_db),
+                    item.file_index(defs_db),
+                    item.stable_ptr(defs_db).untyped(),
+                ),
                 ResolvedGenericItem::GenericFunction(item) => (
                     item.module(defs_db),
                     item.file_index(defs_db),
@@ -443,6 +630,206 @@ impl LanguageServer for Backend {
         }
         return Ok(None);
     }
+
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        let db = self.db().await;
+        let file = self.file(&db, params.text_document_position.text_document.uri);
+        let position = params.text_document_position.position;
+        let Some((node, lookup_items)) = get_node_and_lookup_items(&*db, file, position) else {return Ok(None)};
+        let Some(module_file_id) = find_module_file_id(&*db, file) else {return Ok(None)};
+        let Some((symbol, identifier)) = symbol_at(&*db, module_file_id, &lookup_items, node)
+        else {
+            return Ok(None);
+        };
+        let name = identifier.text((*db).upcast());
+        let locations = find_references(&*db, symbol, &name)
+            .into_iter()
+            .filter(|reference| params.context.include_declaration || !reference.is_definition)
+            .filter_map(|reference| {
+                Some(Location {
+                    uri: file_uri((*db).upcast(), reference.file_id)?,
+                    range: span_range((*db).upcast(), reference.file_id, reference.span)?,
+                })
+            })
+            .collect();
+        Ok(Some(locations))
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>> {
+        let db = self.db().await;
+        let file = self.file(&db, params.text_document.uri);
+        let Some((node, lookup_items)) = get_node_and_lookup_items(&*db, file, params.position) else {return Ok(None)};
+        let Some(module_file_id) = find_module_file_id(&*db, file) else {return Ok(None)};
+        let Some((symbol, identifier)) = symbol_at(&*db, module_file_id, &lookup_items, node)
+        else {
+            return Ok(None);
+        };
+        check_symbol_in_workspace(&db, symbol)?;
+        let span = identifier.as_syntax_node().span_without_trivia((*db).upcast());
+        Ok(span_range((*db).upcast(), file, span).map(PrepareRenameResponse::Range))
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let db = self.db().await;
+        let file = self.file(&db, params.text_document_position.text_document.uri);
+        let position = params.text_document_position.position;
+        if !is_identifier(&db, file, &params.new_name) {
+            return Err(tower_lsp::jsonrpc::Error::invalid_params(format!(
+                "`{}` is not a valid identifier.",
+                params.new_name
+            )));
+        }
+        let Some((node, lookup_items)) = get_node_and_lookup_items(&*db, file, position) else {return Ok(None)};
+        let Some(module_file_id) = find_module_file_id(&*db, file) else {return Ok(None)};
+        let Some((symbol, identifier)) = symbol_at(&*db, module_file_id, &lookup_items, node)
+        else {
+            return Ok(None);
+        };
+        check_symbol_in_workspace(&db, symbol)?;
+        let name = identifier.text((*db).upcast());
+        let new_name = &params.new_name;
+        let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+        for reference in find_references(&*db, symbol, &name) {
+            let (Some(uri), Some(range)) =
+                (file_uri((*db).upcast(), reference.file_id), span_range((*db).upcast(), reference.file_id, reference.span))
+            else {
+                // Code that is not on disk, e.g. generated by plugins, is not edited.
+                continue;
+            };
+            // A shorthand is expanded, to keep referring to both the member and the variable.
+            let new_text = match (reference.is_shorthand, symbol) {
+                (false, _) => new_name.clone(),
+                (true, Symbol::Member(_)) => format!("{new_name}: {name}"),
+                (true, _) => format!("{name}: {new_name}"),
+            };
+            changes.entry(uri).or_default().push(TextEdit { range, new_text });
+        }
+        Ok(Some(WorkspaceEdit { changes: Some(changes), ..WorkspaceEdit::default() }))
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> Result<Option<DocumentSymbolResponse>> {
+        let db = self.db().await;
+        let file = self.file(&db, params.text_document.uri);
+        let Some(module_file_id) = find_module_file_id(&*db, file) else {return Ok(None)};
+        Ok(document_symbols(&*db, module_file_id).map(DocumentSymbolResponse::Nested))
+    }
+
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> Result<Option<Vec<SymbolInformation>>> {
+        let db = self.db().await;
+        Ok(Some(workspace_symbols(&*db, &params.query)))
+    }
+
+    async fn folding_range(&self, params: FoldingRangeParams) -> Result<Option<Vec<FoldingRange>>> {
+        let db = self.db().await;
+        let file = self.file(&db, params.text_document.uri);
+        Ok(folding_ranges(&*db, file))
+    }
+
+    async fn signature_help(&self, params: SignatureHelpParams) -> Result<Option<SignatureHelp>> {
+        let db = self.db().await;
+        let file = self.file(&db, params.text_document_position_params.text_document.uri);
+        let mut position = params.text_document_position_params.position;
+        // Help according to the token right before the cursor.
+        position.character = position.character.saturating_sub(1);
+        let Some((node, lookup_items)) = get_node_and_lookup_items(&*db, file, position) else {return Ok(None)};
+        Ok(signature_help_at(&*db, &lookup_items, node))
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let db = self.db().await;
+        let file = self.file(&db, params.text_document.uri);
+        let Some(module_file_id) = find_module_file_id(&*db, file) else {return Ok(None)};
+        Ok(code_actions(&*db, module_file_id, params.range))
+    }
+
+    async fn inlay_hint(&self, params: InlayHintParams) -> Result<Option<Vec<InlayHint>>> {
+        let db = self.db().await;
+        let file = self.file(&db, params.text_document.uri);
+        let Some(module_file_id) = find_module_file_id(&*db, file) else {return Ok(None)};
+        Ok(inlay_hints(&*db, module_file_id, params.range))
+    }
+}
+
+/// Returns an error if `symbol` is defined outside the crates of the workspace, that is in the core
+/// library, whose files must not be edited by a rename.
+fn check_symbol_in_workspace(db: &RootDatabase, symbol: Symbol) -> Result<()> {
+    let crate_id = symbol.module((*db).upcast()).owning_crate((*db).upcast());
+    if ModuleId::CrateRoot(crate_id) == db.core_module() {
+        return Err(tower_lsp::jsonrpc::Error::invalid_params(
+            "Symbols defined in the core library can't be renamed.",
+        ));
+    }
+    Ok(())
+}
+
+/// Applies a change of a document to its text.
+fn apply_content_change(text: &mut String, change: TextDocumentContentChangeEvent) {
+    match change.range {
+        Some(range) => {
+            let start = position_to_offset(text, range.start);
+            let end = position_to_offset(text, range.end).max(start);
+            text.replace_range(start..end, &change.text);
+        }
+        None => *text = change.text,
+    }
+}
+
+/// Returns the offset in a text of a position, whose character is counted in UTF-16 code units as
+/// in the LSP. Positions past the end of a line, or of the text, are clamped to it.
+fn position_to_offset(text: &str, position: Position) -> usize {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        match text[line_start..].find('\n') {
+            Some(line_length) => line_start += line_length + 1,
+            None => return text.len(),
+        }
+    }
+    let line = &text[line_start..];
+    let line = &line[..line.find('\n').unwrap_or(line.len())];
+    let line = line.strip_suffix('\r').unwrap_or(line);
+    let mut character = 0;
+    for (offset, c) in line.char_indices() {
+        if character >= position.character as usize {
+            return line_start + offset;
+        }
+        character += c.len_utf16();
+    }
+    line_start + line.len()
+}
+
+/// Returns the URI of a file, if it is on disk.
+fn file_uri(db: &dyn FilesGroup, file: FileId) -> Option<Url> {
+    match db.lookup_intern_file(file) {
+        FileLongId::OnDisk(path) => Url::from_file_path(path).ok(),
+        FileLongId::Virtual(_) => None,
+    }
+}
+
+/// Returns the range of a span in a file.
+fn span_range(db: &dyn FilesGroup, file: FileId, span: TextSpan) -> Option<Range> {
+    let start = span.start.position_in_file(db, file)?;
+    let end = span.end.position_in_file(db, file)?;
+    Some(Range { start: from_pos(start), end: from_pos(end) })
+}
+
+/// Returns true if the text is a single identifier, which is not a keyword.
+fn is_identifier(db: &RootDatabase, file: FileId, text: &str) -> bool {
+    let terminals: Vec<_> = Lexer::from_text(db.upcast(), file, text).collect();
+    matches!(
+        terminals.as_slice(),
+        [identifier, eof] if identifier.kind == SyntaxKind::TerminalIdentifier
+            && identifier.text == text
+            && eof.kind == SyntaxKind::TerminalEndOfFile
+    )
 }
 
 /// If the ast node is a lookup item, return the corresponding id. Otherwise, return None.
@@ -455,12 +842,24 @@ fn lookup_item_from_ast(
     let syntax_db = db.upcast();
     // TODO(spapini): Handle trait items.
     match node.kind(syntax_db) {
+        SyntaxKind::ItemFreeFunction if is_impl_item(syntax_db, &node) => {
+            Some(LookupItemId::ImplFunction(db.intern_impl_function(ImplFunctionLongId(
+                module_file_id,
+                ast::ItemFreeFunction::from_syntax_node(syntax_db, node).stable_ptr(),
+            ))))
+        }
         SyntaxKind::ItemFreeFunction => Some(LookupItemId::ModuleItem(ModuleItemId::FreeFunction(
             db.intern_free_function(FreeFunctionLongId(
                 module_file_id,
                 ast::ItemFreeFunction::from_syntax_node(syntax_db, node).stable_ptr(),
             )),
         ))),
+        SyntaxKind::ItemConstant => Some(LookupItemId::ModuleItem(ModuleItemId::Constant(
+            db.intern_constant(ConstantLongId(
+                module_file_id,
+                ast::ItemConstant::from_syntax_node(syntax_db, node).stable_ptr(),
+            )),
+        ))),
         SyntaxKind::ItemExternFunction => Some(LookupItemId::ModuleItem(
             ModuleItemId::ExternFunction(db.intern_extern_function(ExternFunctionLongId(
                 module_file_id,
@@ -504,6 +903,15 @@ fn lookup_item_from_ast(
     }
 }
 
+/// Returns true if the item node is defined inside the body of an impl.
+fn is_impl_item(syntax_db: &dyn SyntaxGroup, node: &SyntaxNode) -> bool {
+    // The parent of the item is an ItemList, whose parent is the body of the impl.
+    matches!(
+        node.parent().and_then(|item_list| item_list.parent()).map(|body| body.kind(syntax_db)),
+        Some(SyntaxKind::ImplBody)
+    )
+}
+
 /// Given a position in a file, return the syntax node for the token at that position, and all the
 /// lookup items above this node.
 fn get_node_and_lookup_items(
@@ -534,7 +942,25 @@ fn get_node_and_lookup_items(
         .as_syntax_node()
         .lookup_offset(syntax_db, line_offset.add(position.character as usize));
 
-    // Find module.
+    let module_file_id = find_module_file_id(db, file)?;
+
+    // Find containing function.
+    let mut item_node = node.clone();
+    loop {
+        if let Some(item) = lookup_item_from_ast(db, module_file_id, item_node.clone()) {
+            res.push(item);
+        }
+        match item_node.parent() {
+            Some(next_node) => {
+                item_node = next_node;
+            }
+            None => return Some((node, res)),
+        }
+    }
+}
+
+/// Returns the module file of a file.
+fn find_module_file_id(db: &(dyn SemanticGroup + 'static), file: FileId) -> Option<ModuleFileId> {
     let modules: Vec<_> = db.file_modules(file).into_iter().flatten().collect();
     if modules.len() != 1 {
         eprintln!("Hover failed. Expected a single module for this file.");
@@ -550,27 +976,13 @@ fn get_node_and_lookup_items(
             .unwrap()
             .0,
     );
-    let module_file_id = ModuleFileId(module_id, file_index);
-
-    // Find containing function.
-    let mut item_node = node.clone();
-    loop {
-        if let Some(item) = lookup_item_from_ast(db, module_file_id, item_node.clone()) {
-            res.push(item);
-        }
-        match item_node.parent() {
-            Some(next_node) => {
-                item_node = next_node;
-            }
-            None => return Some((node, res)),
-        }
-    }
+    Some(ModuleFileId(module_id, file_index))
 }
 
 /// If the node is an identifier, retrieves a hover hint for it.
 fn get_identifier_hint(
     db: &(dyn SemanticGroup + 'static),
-    free_function_id: FreeFunctionId,
+    lookup_item_id: LookupItemId,
     node: SyntaxNode,
 ) -> Option<String> {
     let syntax_db = db.upcast();
@@ -578,10 +990,7 @@ fn get_identifier_hint(
         return None;
     }
     let identifier = ast::TerminalIdentifier::from_syntax_node(syntax_db, node.parent().unwrap());
-    let item = db.lookup_resolved_generic_item_by_ptr(
-        LookupItemId::ModuleItem(ModuleItemId::FreeFunction(free_function_id)),
-        identifier.stable_ptr(),
-    )?;
+    let item = db.lookup_resolved_generic_item_by_ptr(lookup_item_id, identifier.stable_ptr())?;
 
     // TODO(spapini): Also include concrete item hints.
     // TODO(spapini): Format this better.
@@ -591,7 +1000,7 @@ fn get_identifier_hint(
 /// If the node is an expression, retrieves a hover hint for it.
 fn get_expr_hint(
     db: &(dyn SemanticGroup + 'static),
-    free_function_id: FreeFunctionId,
+    function_id: FunctionWithBodyId,
     mut node: SyntaxNode,
 ) -> Option<String> {
     let syntax_db = db.upcast();
@@ -601,11 +1010,10 @@ fn get_expr_hint(
     }
     let expr_node = ast::Expr::from_syntax_node(syntax_db, node);
     // Lookup semantic expression.
-    let expr_id =
-        db.lookup_expr_by_ptr(free_function_id, expr_node.stable_ptr()).on_none(|| {
-            eprintln!("Hover failed. Semantic model not found for expression.");
-        })?;
-    let semantic_expr = db.expr_semantic(free_function_id, expr_id);
+    let expr_id = db.lookup_expr_by_ptr(function_id, expr_node.stable_ptr()).on_none(|| {
+        eprintln!("Hover failed. Semantic model not found for expression.");
+    })?;
+    let semantic_expr = db.expr_semantic(function_id, expr_id);
     // Format the hover text.
     Some(format!("Type: `{}`", semantic_expr.ty().format(db)))
 }
@@ -619,6 +1027,8 @@ fn is_expr(kind: SyntaxKind) -> bool {
             | SyntaxKind::ExprParenthesized
             | SyntaxKind::ExprFunctionCall
             | SyntaxKind::ExprIf
+            | SyntaxKind::ExprLoop
+            | SyntaxKind::ExprWhile
             | SyntaxKind::ExprMatch
             | SyntaxKind::ExprMissing
             | SyntaxKind::ExprStructCtorCall
@@ -628,6 +1038,39 @@ fn is_expr(kind: SyntaxKind) -> bool {
     )
 }
 
+/// Returns the edit that formats the statements or items of a file intersecting a span, if it
+/// changes the file.
+fn format_range(
+    db: &RootDatabase,
+    file_uri: &Url,
+    file: FileId,
+    span: TextSpan,
+) -> Option<Vec<TextEdit>> {
+    let syntax = db.file_syntax(file)?;
+    let content = db.file_content(file)?;
+    let (formatted_span, new_text) = get_formatted_range(
+        db.upcast(),
+        &syntax.as_syntax_node(),
+        span,
+        formatter_config(file_uri),
+    );
+    if content.get(formatted_span.start.0..formatted_span.end.0) == Some(new_text.as_str()) {
+        return Some(vec![]);
+    }
+    let range = span_range(db.upcast(), file, formatted_span)?;
+    Some(vec![TextEdit { range, new_text }])
+}
+
+/// Returns the formatter config of a file, from the nearest formatter config file, or the default
+/// config if there is none.
+fn formatter_config(uri: &Url) -> FormatterConfig {
+    let Ok(path) = uri.to_file_path() else { return FormatterConfig::default() };
+    FormatterConfig::discover(&path).unwrap_or_else(|err| {
+        eprintln!("Failed to load the formatter config of '{uri}': {err}");
+        FormatterConfig::default()
+    })
+}
+
 /// Tries to detect the crate root the config that contains a cairo file, and add it to the system.
 fn detect_crate_for(db: &mut tokio::sync::MutexGuard<'_, RootDatabase>, path: &str) {
     let mut path = PathBuf::from(path);
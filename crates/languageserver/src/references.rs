@@ -0,0 +1,426 @@
+//! Find-references and rename, computed from the semantic model.
+
+#[cfg(test)]
+#[path = "references_test.rs"]
+mod test;
+
+use defs::db::DefsGroup;
+use defs::ids::{
+    ConstantId, FileIndex, FunctionWithBodyId, GenericFunctionId, GenericTypeId, ImplId,
+    LanguageElementId, LocalVarLongId, LookupItemId, MemberId, MemberLongId, ModuleFileId,
+    ModuleId, ModuleItemId, ParamLongId, StructId, TraitId, VarId, VariantId, VariantLongId,
+};
+use filesystem::ids::FileId;
+use filesystem::span::TextSpan;
+use semantic::db::SemanticGroup;
+use semantic::items::function_with_body::SemanticExprLookup;
+use semantic::resolve_path::ResolvedGenericItem;
+use semantic::Expr;
+use syntax::node::helpers::PathSegmentEx;
+use syntax::node::kind::SyntaxKind;
+use syntax::node::{ast, SyntaxNode, Terminal, TypedSyntaxNode};
+
+use crate::completion::function_with_body;
+use crate::lookup_item_from_ast;
+
+/// A symbol that can be referenced and renamed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Symbol {
+    Function(GenericFunctionId),
+    Type(GenericTypeId),
+    Constant(ConstantId),
+    Trait(TraitId),
+    Impl(ImplId),
+    Variant(VariantId),
+    Member(MemberId),
+    Var(FunctionWithBodyId, VarId),
+}
+impl Symbol {
+    /// Returns the module in which the symbol is defined.
+    pub fn module(&self, db: &dyn DefsGroup) -> ModuleId {
+        match self {
+            Symbol::Function(id) => id.module(db),
+            Symbol::Type(id) => id.module(db),
+            Symbol::Constant(id) => id.module(db),
+            Symbol::Trait(id) => id.module(db),
+            Symbol::Impl(id) => id.module(db),
+            Symbol::Variant(id) => id.module(db),
+            Symbol::Member(id) => id.module(db),
+            Symbol::Var(function_id, _) => function_id.module(db),
+        }
+    }
+}
+
+/// An occurrence of a symbol at an identifier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Occurrence {
+    symbol: Symbol,
+    is_definition: bool,
+}
+
+/// A reference to a symbol.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reference {
+    pub file_id: FileId,
+    /// The span of the identifier.
+    pub span: TextSpan,
+    /// Whether the identifier is the definition of the symbol.
+    pub is_definition: bool,
+    /// Whether the identifier stands for both a member and a variable, as `x` in `A { x }`.
+    pub is_shorthand: bool,
+}
+
+/// Returns the symbol at the token `node`, if it is an identifier of a symbol, together with the
+/// identifier. In a shorthand (`x` in `A { x }`), the variable is preferred over the member.
+pub fn symbol_at(
+    db: &(dyn SemanticGroup + 'static),
+    module_file_id: ModuleFileId,
+    lookup_items: &[LookupItemId],
+    node: SyntaxNode,
+) -> Option<(Symbol, ast::TerminalIdentifier)> {
+    let syntax_db = db.upcast();
+    if node.kind(syntax_db) != SyntaxKind::TokenIdentifier {
+        return None;
+    }
+    let terminal = node.parent()?;
+    let occurrences = identifier_occurrences(db, module_file_id, lookup_items, terminal.clone());
+    let occurrence = occurrences
+        .iter()
+        .find(|occurrence| matches!(occurrence.symbol, Symbol::Var(..)))
+        .or_else(|| occurrences.first())?;
+    Some((occurrence.symbol, ast::TerminalIdentifier::from_syntax_node(syntax_db, terminal)))
+}
+
+/// Returns all the references to `symbol`, named `name`, in all the crates, including its
+/// definition.
+pub fn find_references(
+    db: &(dyn SemanticGroup + 'static),
+    symbol: Symbol,
+    name: &str,
+) -> Vec<Reference> {
+    let mut references = vec![];
+    for crate_id in db.crates() {
+        for module_id in db.crate_modules(crate_id).iter() {
+            let files = db.module_files(*module_id).unwrap_or_default();
+            for (file_index, file_id) in files.into_iter().enumerate() {
+                let Some(syntax) = db.file_syntax(file_id) else { continue };
+                let mut ctx = ReferencesContext {
+                    db,
+                    module_file_id: ModuleFileId(*module_id, FileIndex(file_index)),
+                    file_id,
+                    symbol,
+                    name,
+                    lookup_items: vec![],
+                    references: &mut references,
+                };
+                ctx.collect(syntax.as_syntax_node());
+            }
+        }
+    }
+    references
+}
+
+/// The state of collecting the references in a file.
+struct ReferencesContext<'a> {
+    db: &'a (dyn SemanticGroup + 'static),
+    module_file_id: ModuleFileId,
+    file_id: FileId,
+    symbol: Symbol,
+    name: &'a str,
+    /// The lookup items containing the current node, from the innermost.
+    lookup_items: Vec<LookupItemId>,
+    references: &'a mut Vec<Reference>,
+}
+impl ReferencesContext<'_> {
+    /// Collects the references under `node`.
+    fn collect(&mut self, node: SyntaxNode) {
+        let syntax_db = self.db.upcast();
+        if node.kind(syntax_db) == SyntaxKind::TerminalIdentifier {
+            let identifier = ast::TerminalIdentifier::from_syntax_node(syntax_db, node.clone());
+            // Only identifiers with the name of the symbol may refer to it.
+            if identifier.text(syntax_db) != self.name {
+                return;
+            }
+            let occurrences =
+                identifier_occurrences(self.db, self.module_file_id, &self.lookup_items, node);
+            if let Some(occurrence) =
+                occurrences.iter().find(|occurrence| occurrence.symbol == self.symbol)
+            {
+                self.references.push(Reference {
+                    file_id: self.file_id,
+                    span: identifier.as_syntax_node().span_without_trivia(syntax_db),
+                    is_definition: occurrence.is_definition,
+                    is_shorthand: occurrences.len() > 1,
+                });
+            }
+            return;
+        }
+        let lookup_item = lookup_item_from_ast(self.db, self.module_file_id, node.clone());
+        if let Some(lookup_item) = lookup_item {
+            self.lookup_items.insert(0, lookup_item);
+        }
+        for child in node.children(syntax_db) {
+            self.collect(child);
+        }
+        if lookup_item.is_some() {
+            self.lookup_items.remove(0);
+        }
+    }
+}
+
+/// Returns the symbols a terminal identifier refers to. This is usually a single symbol, except
+/// for shorthands (`x` in `A { x }`), which refer to both a member and a variable.
+fn identifier_occurrences(
+    db: &(dyn SemanticGroup + 'static),
+    module_file_id: ModuleFileId,
+    lookup_items: &[LookupItemId],
+    terminal: SyntaxNode,
+) -> Vec<Occurrence> {
+    let syntax_db = db.upcast();
+    let identifier = ast::TerminalIdentifier::from_syntax_node(syntax_db, terminal.clone());
+    if let Some(item) = lookup_items
+        .iter()
+        .find_map(|item| db.lookup_resolved_generic_item_by_ptr(*item, identifier.stable_ptr()))
+    {
+        return resolved_item_symbol(item).map(reference).into_iter().collect();
+    }
+    let Some(parent) = terminal.parent() else { return vec![] };
+    let function_id = lookup_items.first().and_then(|item| function_with_body(*item));
+    let local_var = |function_id| {
+        Symbol::Var(
+            function_id,
+            VarId::Local(
+                db.intern_local_var(LocalVarLongId(module_file_id, identifier.stable_ptr())),
+            ),
+        )
+    };
+    match parent.kind(syntax_db) {
+        // A member of a struct or a variant of an enum.
+        SyntaxKind::Member => {
+            let member_ptr = ast::Member::from_syntax_node(syntax_db, parent.clone()).stable_ptr();
+            // The parent of a member is a member list, whose parent is the struct or the enum.
+            match parent.parent().and_then(|list| list.parent()).map(|item| item.kind(syntax_db)) {
+                Some(SyntaxKind::ItemStruct) => vec![definition(Symbol::Member(
+                    db.intern_member(MemberLongId(module_file_id, member_ptr)),
+                ))],
+                Some(SyntaxKind::ItemEnum) => vec![definition(Symbol::Variant(
+                    db.intern_variant(VariantLongId(module_file_id, member_ptr)),
+                ))],
+                _ => vec![],
+            }
+        }
+        SyntaxKind::Param => {
+            let Some(function_id) = function_id else { return vec![] };
+            let param_ptr = ast::Param::from_syntax_node(syntax_db, parent).stable_ptr();
+            let param_id = db.intern_param(ParamLongId(module_file_id, param_ptr));
+            vec![definition(Symbol::Var(function_id, VarId::Param(param_id)))]
+        }
+        SyntaxKind::PatternIdentifier => {
+            function_id.map(local_var).map(definition).into_iter().collect()
+        }
+        SyntaxKind::PathSegmentSimple => {
+            let (Some(function_id), Some(path)) = (function_id, parent.parent()) else {
+                return vec![];
+            };
+            path_occurrences(db, function_id, path).unwrap_or_else(|| {
+                if is_pattern_path(db, &parent.parent().unwrap()) {
+                    vec![definition(local_var(function_id))]
+                } else {
+                    vec![]
+                }
+            })
+        }
+        // `A { x: expr }` or `A { x }`.
+        SyntaxKind::StructArgSingle => {
+            // The parents are the argument list, then the braces, then the constructor.
+            let Some(ctor) = ancestor(&parent, 3) else { return vec![] };
+            let ctor = ast::ExprStructCtorCall::from_syntax_node(syntax_db, ctor);
+            let Some(member_id) = path_member(db, lookup_items, ctor.path(syntax_db), &identifier)
+            else {
+                return vec![];
+            };
+            let mut occurrences = vec![reference(Symbol::Member(member_id))];
+            let arg = ast::StructArgSingle::from_syntax_node(syntax_db, parent);
+            if let (ast::OptionStructArgExpr::Empty(_), Some(function_id)) =
+                (arg.arg_expr(syntax_db), function_id)
+            {
+                let var = expr_at(db, function_id, ctor.as_syntax_node()).and_then(|ctor| {
+                    let Expr::StructCtor(ctor) = ctor else { return None };
+                    let (_, expr_id) = ctor.members.iter().find(|(id, _)| *id == member_id)?;
+                    match db.expr_semantic(function_id, *expr_id) {
+                        Expr::Var(var) => Some(var.var),
+                        _ => None,
+                    }
+                });
+                occurrences.extend(var.map(|var| reference(Symbol::Var(function_id, var))));
+            }
+            occurrences
+        }
+        // `A { x: pattern }`.
+        SyntaxKind::PatternStructParamWithExpr => {
+            // The parents are the parameter list, then the struct pattern.
+            let Some(pattern) = ancestor(&parent, 2) else { return vec![] };
+            let pattern = ast::PatternStruct::from_syntax_node(syntax_db, pattern);
+            path_member(db, lookup_items, pattern.path(syntax_db), &identifier)
+                .map(|member_id| reference(Symbol::Member(member_id)))
+                .into_iter()
+                .collect()
+        }
+        // `A { x }` in a pattern.
+        SyntaxKind::PatternStructParamList => {
+            let Some(pattern) = parent.parent() else { return vec![] };
+            let pattern = ast::PatternStruct::from_syntax_node(syntax_db, pattern);
+            let mut occurrences: Vec<_> =
+                path_member(db, lookup_items, pattern.path(syntax_db), &identifier)
+                    .map(|member_id| reference(Symbol::Member(member_id)))
+                    .into_iter()
+                    .collect();
+            occurrences.extend(function_id.map(local_var).map(definition));
+            occurrences
+        }
+        // The name of an item.
+        _ => match lookup_item_from_ast(db, module_file_id, parent) {
+            Some(LookupItemId::ModuleItem(item)) => {
+                module_item_symbol(item).map(definition).into_iter().collect()
+            }
+            _ => vec![],
+        },
+    }
+}
+
+/// Returns the symbols a single identifier path refers to inside a function, if it is an
+/// expression: a variable, or a member in `a.x`.
+fn path_occurrences(
+    db: &(dyn SemanticGroup + 'static),
+    function_id: FunctionWithBodyId,
+    path: SyntaxNode,
+) -> Option<Vec<Occurrence>> {
+    let syntax_db = db.upcast();
+    let parent = path.parent()?;
+    if parent.kind(syntax_db) == SyntaxKind::ExprBinary {
+        let binary = ast::ExprBinary::from_syntax_node(syntax_db, parent.clone());
+        match expr_at(db, function_id, parent) {
+            Some(Expr::MemberAccess(member_access))
+                if binary.rhs(syntax_db).as_syntax_node() == path =>
+            {
+                return Some(vec![reference(Symbol::Member(member_access.member))]);
+            }
+            Some(Expr::Assignment(assignment))
+                if binary.lhs(syntax_db).as_syntax_node() == path =>
+            {
+                return Some(vec![reference(Symbol::Var(function_id, assignment.var))]);
+            }
+            _ => {}
+        }
+    }
+    match expr_at(db, function_id, path)? {
+        Expr::Var(var) => Some(vec![reference(Symbol::Var(function_id, var.var))]),
+        _ => Some(vec![]),
+    }
+}
+
+/// Returns true if the path is a pattern, which defines a variable.
+fn is_pattern_path(db: &dyn SemanticGroup, path: &SyntaxNode) -> bool {
+    let syntax_db = db.upcast();
+    let Some(parent) = path.parent() else { return false };
+    match parent.kind(syntax_db) {
+        SyntaxKind::StatementLet => {
+            ast::StatementLet::from_syntax_node(syntax_db, parent)
+                .pattern(syntax_db)
+                .as_syntax_node()
+                == *path
+        }
+        SyntaxKind::MatchArm => {
+            ast::MatchArm::from_syntax_node(syntax_db, parent).pattern(syntax_db).as_syntax_node()
+                == *path
+        }
+        SyntaxKind::PatternList
+        | SyntaxKind::PatternStructParamWithExpr
+        | SyntaxKind::PatternEnum => true,
+        _ => false,
+    }
+}
+
+/// Returns the member named as `identifier` of the struct `path` resolves to.
+fn path_member(
+    db: &dyn SemanticGroup,
+    lookup_items: &[LookupItemId],
+    path: ast::ExprPath,
+    identifier: &ast::TerminalIdentifier,
+) -> Option<MemberId> {
+    let syntax_db = db.upcast();
+    let struct_id = path_struct(db, lookup_items, path)?;
+    Some(db.struct_members(struct_id)?.get(&identifier.text(syntax_db))?.id)
+}
+
+/// Returns the struct a path resolves to.
+fn path_struct(
+    db: &dyn SemanticGroup,
+    lookup_items: &[LookupItemId],
+    path: ast::ExprPath,
+) -> Option<StructId> {
+    let identifier = path.elements(db.upcast()).last()?.identifier_ast(db.upcast());
+    match lookup_items
+        .iter()
+        .find_map(|item| db.lookup_resolved_generic_item_by_ptr(*item, identifier.stable_ptr()))?
+    {
+        ResolvedGenericItem::GenericType(GenericTypeId::Struct(struct_id)) => Some(struct_id),
+        _ => None,
+    }
+}
+
+/// Returns the semantic expression of an expression node in a function.
+fn expr_at(
+    db: &(dyn SemanticGroup + 'static),
+    function_id: FunctionWithBodyId,
+    node: SyntaxNode,
+) -> Option<Expr> {
+    let expr = ast::Expr::from_syntax_node(db.upcast(), node);
+    let expr_id = db.lookup_expr_by_ptr(function_id, expr.stable_ptr())?;
+    Some(db.expr_semantic(function_id, expr_id))
+}
+
+/// Returns the `n`-th ancestor of a node.
+fn ancestor(node: &SyntaxNode, n: usize) -> Option<SyntaxNode> {
+    (0..n).try_fold(node.clone(), |node, _| node.parent())
+}
+
+/// Returns the symbol of a resolved item, if it can be renamed.
+fn resolved_item_symbol(item: ResolvedGenericItem) -> Option<Symbol> {
+    Some(match item {
+        ResolvedGenericItem::GenericFunction(
+            function_id @ (GenericFunctionId::Free(_) | GenericFunctionId::Extern(_)),
+        ) => Symbol::Function(function_id),
+        ResolvedGenericItem::GenericType(type_id) => Symbol::Type(type_id),
+        ResolvedGenericItem::Constant(constant_id) => Symbol::Constant(constant_id),
+        ResolvedGenericItem::Trait(trait_id) => Symbol::Trait(trait_id),
+        ResolvedGenericItem::Impl(impl_id) => Symbol::Impl(impl_id),
+        ResolvedGenericItem::Variant(variant) => Symbol::Variant(variant.id),
+        // Renaming modules requires renaming files, and functions of impls must match their
+        // traits.
+        ResolvedGenericItem::Module(_) | ResolvedGenericItem::GenericFunction(_) => return None,
+    })
+}
+
+/// Returns the symbol of a module item, if it can be renamed.
+fn module_item_symbol(item: ModuleItemId) -> Option<Symbol> {
+    Some(match item {
+        ModuleItemId::FreeFunction(id) => Symbol::Function(GenericFunctionId::Free(id)),
+        ModuleItemId::ExternFunction(id) => Symbol::Function(GenericFunctionId::Extern(id)),
+        ModuleItemId::Struct(id) => Symbol::Type(GenericTypeId::Struct(id)),
+        ModuleItemId::Enum(id) => Symbol::Type(GenericTypeId::Enum(id)),
+        ModuleItemId::ExternType(id) => Symbol::Type(GenericTypeId::Extern(id)),
+        ModuleItemId::Constant(id) => Symbol::Constant(id),
+        ModuleItemId::Trait(id) => Symbol::Trait(id),
+        ModuleItemId::Impl(id) => Symbol::Impl(id),
+        ModuleItemId::Submodule(_) | ModuleItemId::Use(_) => return None,
+    })
+}
+
+fn reference(symbol: Symbol) -> Occurrence {
+    Occurrence { symbol, is_definition: false }
+}
+
+fn definition(symbol: Symbol) -> Occurrence {
+    Occurrence { symbol, is_definition: true }
+}
@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use filesystem::db::{FilesGroup, FilesGroupEx};
+use filesystem::ids::FileLongId;
+use indoc::indoc;
+use semantic::test_utils::setup_test_crate;
+use syntax::node::Terminal;
+use test_case::test_case;
+use tower_lsp::lsp_types::Position;
+
+use super::{find_references, symbol_at};
+use crate::db::RootDatabase;
+use crate::{find_module_file_id, get_node_and_lookup_items};
+
+/// The marker of the position of the cursor in the test code.
+const CARET: &str = "<caret>";
+
+/// Sets up a crate whose root module has `root_code` and a submodule `submodule` with
+/// `submodule_code`. One of them has a [CARET]. Returns the references to the symbol at the caret,
+/// formatted as `file:line:col`, and marked if they are the definition.
+fn references_at_caret(root_code: &str, submodule_code: &str) -> Vec<String> {
+    let mut db = RootDatabase::default();
+    let root_code = format!("mod submodule;\n{root_code}");
+    setup_test_crate(&mut db, &root_code.replace(CARET, ""));
+    let submodule_file = db.intern_file(FileLongId::OnDisk("src/submodule.cairo".into()));
+    db.override_file_content(submodule_file, Some(Arc::new(submodule_code.replace(CARET, ""))));
+    let root_file = db.intern_file(FileLongId::OnDisk("src/lib.cairo".into()));
+
+    let (file, code) = if root_code.contains(CARET) {
+        (root_file, root_code.as_str())
+    } else {
+        (submodule_file, submodule_code)
+    };
+    let (line, line_text) =
+        code.lines().enumerate().find(|(_, line_text)| line_text.contains(CARET)).unwrap();
+    let position = Position { line: line as u32, character: line_text.find(CARET).unwrap() as u32 };
+    let (node, lookup_items) = get_node_and_lookup_items(&db, file, position).unwrap();
+    let module_file_id = find_module_file_id(&db, file).unwrap();
+    let (symbol, identifier) = symbol_at(&db, module_file_id, &lookup_items, node).unwrap();
+
+    find_references(&db, symbol, &identifier.text(&db))
+        .into_iter()
+        .map(|reference| {
+            let position = reference.span.start.position_in_file(&db, reference.file_id).unwrap();
+            format!(
+                "{}:{}:{}{}",
+                reference.file_id.file_name(&db),
+                position.line + 1,
+                position.col + 1,
+                if reference.is_definition { " (definition)" } else { "" }
+            )
+        })
+        .collect()
+}
+
+#[test_case(
+    indoc! {"
+        use submodule::foo;
+        func bar() -> felt {
+            foo() + submodule::foo()
+        }
+    "},
+    "func <caret>foo() -> felt { 1 }",
+    &["lib.cairo:2:16", "lib.cairo:4:5", "lib.cairo:4:24", "submodule.cairo:1:6 (definition)"];
+    "function in another module"
+)]
+#[test_case(
+    indoc! {"
+        use submodule::<caret>foo;
+        func bar() -> felt {
+            foo()
+        }
+    "},
+    "func foo() -> felt { 1 }",
+    &["lib.cairo:2:16", "lib.cairo:4:5", "submodule.cairo:1:6 (definition)"];
+    "use of a function"
+)]
+#[test_case(
+    indoc! {"
+        use submodule::A;
+        func bar(a: <caret>A) -> submodule::A {
+            a
+        }
+    "},
+    "struct A { x: felt }",
+    &["lib.cairo:2:16", "lib.cairo:3:13", "lib.cairo:3:30", "submodule.cairo:1:8 (definition)"];
+    "type in another module"
+)]
+#[test_case(
+    indoc! {"
+        func bar() -> felt {
+            let <caret>x = 1;
+            let y = x + x;
+            let x = y;
+            x
+        }
+    "},
+    "",
+    &["lib.cairo:3:9 (definition)", "lib.cairo:4:13", "lib.cairo:4:17"];
+    "shadowed local variable"
+)]
+#[test_case(
+    indoc! {"
+        func bar(a: felt) -> felt {
+            let b = <caret>a;
+            a + b
+        }
+    "},
+    "",
+    &["lib.cairo:2:10 (definition)", "lib.cairo:3:13", "lib.cairo:4:5"];
+    "param"
+)]
+fn test_find_references(root_code: &str, submodule_code: &str, expected: &[&str]) {
+    assert_eq!(references_at_caret(root_code, submodule_code), expected);
+}
+
+#[test]
+fn test_find_references_of_core_library_symbol() {
+    let references = references_at_caret(
+        indoc! {"
+            func bar(ref arr: Array::<felt>) nopanic {
+                <caret>array_append::<felt>(arr, 1)
+            }
+        "},
+        "",
+    );
+    assert!(references.contains(&"lib.cairo:3:5".to_string()));
+    assert!(references.contains(&"array.cairo:5:13 (definition)".to_string()));
+    assert!(references.contains(&"array.cairo:19:9".to_string()));
+}
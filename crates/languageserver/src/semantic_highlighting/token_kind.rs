@@ -19,6 +19,7 @@ pub enum SemanticTokenKind {
     Keyword,
     Operator,
     Number,
+    String,
 }
 impl SemanticTokenKind {
     pub fn from_syntax_kind(kind: SyntaxKind) -> Option<Self> {
@@ -26,6 +27,7 @@ impl SemanticTokenKind {
             _ if kind.is_keyword_token() => SemanticTokenKind::Keyword,
             SyntaxKind::TokenIdentifier => SemanticTokenKind::Variable,
             SyntaxKind::TokenLiteralNumber => SemanticTokenKind::Number,
+            SyntaxKind::TokenShortString | SyntaxKind::TokenString => SemanticTokenKind::String,
             SyntaxKind::TokenAnd
             | SyntaxKind::TokenAndAnd
             | SyntaxKind::TokenOr
@@ -64,6 +66,7 @@ impl SemanticTokenKind {
             SemanticTokenKind::Keyword => 13,
             SemanticTokenKind::Operator => 14,
             SemanticTokenKind::Number => 15,
+            SemanticTokenKind::String => 16,
         }
     }
     pub fn legend() -> Vec<SemanticTokenType> {
@@ -84,6 +87,7 @@ impl SemanticTokenKind {
             SemanticTokenType::KEYWORD,
             SemanticTokenType::OPERATOR,
             SemanticTokenType::NUMBER,
+            SemanticTokenType::STRING,
         ]
     }
 }
@@ -0,0 +1,146 @@
+//! Signature help for function calls, computed from the semantic model.
+
+#[cfg(test)]
+#[path = "signature_help_test.rs"]
+mod test;
+
+use defs::ids::{GenericFunctionId, LookupItemId, TopLevelLanguageElementId};
+use semantic::corelib::unit_ty;
+use semantic::db::SemanticGroup;
+use semantic::resolve_path::{ResolvedConcreteItem, ResolvedGenericItem};
+use semantic::{Mutability, Parameter, Signature};
+use syntax::node::ast::BinaryOperator;
+use syntax::node::helpers::PathSegmentEx;
+use syntax::node::kind::SyntaxKind;
+use syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use tower_lsp::lsp_types::{
+    ParameterInformation, ParameterLabel, SignatureHelp, SignatureInformation,
+};
+
+/// Returns the signature help for the innermost function call whose arguments contain the token
+/// `node`, which is the token right before the cursor.
+pub fn signature_help_at(
+    db: &(dyn SemanticGroup + 'static),
+    lookup_items: &[LookupItemId],
+    node: SyntaxNode,
+) -> Option<SignatureHelp> {
+    let syntax_db = db.upcast();
+    let offset = node.offset();
+    let mut call_node = node.parent();
+    let call = loop {
+        let current = call_node?;
+        if current.kind(syntax_db) == SyntaxKind::ExprFunctionCall {
+            let call = ast::ExprFunctionCall::from_syntax_node(syntax_db, current.clone());
+            let arguments = call.arguments(syntax_db);
+            // The cursor is between the parentheses, or after the opening one if the closing one
+            // is not written yet.
+            if arguments.lparen(syntax_db).as_syntax_node().offset() <= offset
+                && offset < arguments.rparen(syntax_db).as_syntax_node().offset()
+            {
+                break call;
+            }
+        }
+        call_node = current.parent();
+    };
+    let mut active_parameter = call
+        .arguments(syntax_db)
+        .expressions(syntax_db)
+        .as_syntax_node()
+        .children(syntax_db)
+        .filter(|child| {
+            child.kind(syntax_db) == SyntaxKind::TerminalComma && child.offset() <= offset
+        })
+        .count();
+
+    let segment = call.path(syntax_db).elements(syntax_db).pop()?;
+    let identifier = segment.identifier_ast(syntax_db);
+    // The `self` argument of a method call is written before the method.
+    if is_method_call(db, &call) {
+        active_parameter += 1;
+    }
+    let (generic_function, signature) = lookup_items.iter().find_map(|lookup_item_id| {
+        match db.lookup_resolved_concrete_item_by_ptr(*lookup_item_id, identifier.stable_ptr()) {
+            Some(ResolvedConcreteItem::Function(function_id)) => Some((
+                db.lookup_intern_function(function_id).function.generic_function,
+                db.concrete_function_signature(function_id)?,
+            )),
+            // The generic arguments may not be resolved yet.
+            _ => match db
+                .lookup_resolved_generic_item_by_ptr(*lookup_item_id, identifier.stable_ptr())?
+            {
+                ResolvedGenericItem::GenericFunction(generic_function) => {
+                    Some((generic_function, db.generic_function_signature(generic_function)?))
+                }
+                _ => None,
+            },
+        }
+    })?;
+    Some(SignatureHelp {
+        signatures: vec![signature_information(db, generic_function, &signature)],
+        active_signature: Some(0),
+        active_parameter: Some(active_parameter as u32),
+    })
+}
+
+/// Returns true if the call is the right hand side of a `.` operator.
+fn is_method_call(db: &(dyn SemanticGroup + 'static), call: &ast::ExprFunctionCall) -> bool {
+    let syntax_db = db.upcast();
+    let Some(parent) = call.as_syntax_node().parent() else { return false };
+    if parent.kind(syntax_db) != SyntaxKind::ExprBinary {
+        return false;
+    }
+    let binary = ast::ExprBinary::from_syntax_node(syntax_db, parent);
+    matches!(binary.op(syntax_db), BinaryOperator::Dot(_))
+        && binary.rhs(syntax_db).as_syntax_node().offset() == call.as_syntax_node().offset()
+}
+
+/// Returns the signature information of a function, with the offsets of its parameters in the
+/// label.
+fn signature_information(
+    db: &(dyn SemanticGroup + 'static),
+    generic_function: GenericFunctionId,
+    signature: &Signature,
+) -> SignatureInformation {
+    let mut label = format!("func {}(", generic_function.name(db.upcast()));
+    let mut parameters = vec![];
+    for (index, param) in signature.params.iter().enumerate() {
+        if index > 0 {
+            label.push_str(", ");
+        }
+        let start = label.encode_utf16().count() as u32;
+        label.push_str(&param_label(db, param));
+        let end = label.encode_utf16().count() as u32;
+        parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start, end]),
+            documentation: None,
+        });
+    }
+    label.push(')');
+    if signature.return_type != unit_ty(db) {
+        label.push_str(&format!(" -> {}", signature.return_type.format(db)));
+    }
+    if !signature.implicits.is_empty() {
+        let implicits =
+            signature.implicits.iter().map(|ty| ty.format(db)).collect::<Vec<_>>().join(", ");
+        label.push_str(&format!(" implicits({implicits})"));
+    }
+    if !signature.panicable {
+        label.push_str(" nopanic");
+    }
+    SignatureInformation {
+        label,
+        documentation: None,
+        parameters: Some(parameters),
+        active_parameter: None,
+    }
+}
+
+/// Returns the text of a parameter as written in a signature, e.g. `ref self: Array::<felt>`.
+pub fn param_label(db: &(dyn SemanticGroup + 'static), param: &Parameter) -> String {
+    let prefix = match param.mutability {
+        Mutability::Immutable => "",
+        Mutability::Mutable => "mut ",
+        Mutability::Reference => "ref ",
+    };
+    format!("{prefix}{}: {}", param.id.name(db.upcast()), param.ty.format(db))
+}
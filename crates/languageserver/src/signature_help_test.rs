@@ -0,0 +1,85 @@
+use filesystem::db::FilesGroup;
+use filesystem::ids::FileLongId;
+use indoc::indoc;
+use semantic::test_utils::setup_test_crate;
+use test_case::test_case;
+use tower_lsp::lsp_types::Position;
+
+use super::signature_help_at;
+use crate::db::RootDatabase;
+use crate::get_node_and_lookup_items;
+
+/// The marker of the position of the cursor in the test code.
+const CARET: &str = "<caret>";
+
+/// The functions called in the test code.
+const FUNCTIONS: &str = indoc! {"
+    func bar(a: felt, b: felt) -> felt { a }
+    func baz(a: felt) -> felt { a }
+"};
+
+/// Sets up a crate with `code`, which has a [CARET], after [FUNCTIONS]. Returns the label of the
+/// signature at the caret and the index of its active parameter, if there is a signature.
+fn signature_help_at_caret(code: &str) -> Option<(String, u32)> {
+    let mut db = RootDatabase::default();
+    let code = format!("{FUNCTIONS}{code}");
+    setup_test_crate(&mut db, &code.replace(CARET, ""));
+    let file = db.intern_file(FileLongId::OnDisk("src/lib.cairo".into()));
+
+    let (line, line_text) =
+        code.lines().enumerate().find(|(_, line_text)| line_text.contains(CARET)).unwrap();
+    // Signature help is according to the token right before the cursor.
+    let position =
+        Position { line: line as u32, character: line_text.find(CARET).unwrap() as u32 - 1 };
+    let (node, lookup_items) = get_node_and_lookup_items(&db, file, position).unwrap();
+    let mut signature_help = signature_help_at(&db, &lookup_items, node)?;
+    Some((signature_help.signatures.remove(0).label, signature_help.active_parameter.unwrap()))
+}
+
+#[test_case(
+    "func foo() -> felt { bar(<caret>1, 2) }",
+    Some(("func bar(a: core::felt, b: core::felt) -> core::felt", 0));
+    "first argument"
+)]
+#[test_case(
+    "func foo() -> felt { bar(1,<caret> 2) }",
+    Some(("func bar(a: core::felt, b: core::felt) -> core::felt", 1));
+    "second argument"
+)]
+#[test_case(
+    "func foo() -> felt { bar(1, 2<caret>) }",
+    Some(("func bar(a: core::felt, b: core::felt) -> core::felt", 1));
+    "end of the last argument"
+)]
+#[test_case(
+    "func foo() -> felt { bar(1, baz(<caret>2)) }",
+    Some(("func baz(a: core::felt) -> core::felt", 0));
+    "nested call"
+)]
+#[test_case(
+    "func foo() -> felt { bar(baz(1),<caret> 2) }",
+    Some(("func bar(a: core::felt, b: core::felt) -> core::felt", 1));
+    "after a nested call"
+)]
+#[test_case(
+    "func foo(ref a: Array::<felt>) { a.append(<caret>1) }",
+    Some((
+        "func append(ref self: core::array::Array::<core::felt>, value: core::felt) nopanic",
+        1,
+    ));
+    "method call"
+)]
+#[test_case(
+    "func foo() -> felt { bar(1, 2) +<caret> 1 }",
+    None;
+    "outside of a call"
+)]
+fn test_signature_help(code: &str, expected: Option<(&str, u32)>) {
+    let signature_help = signature_help_at_caret(code);
+    assert_eq!(
+        signature_help
+            .as_ref()
+            .map(|(label, active_parameter)| (label.as_str(), *active_parameter)),
+        expected
+    );
+}
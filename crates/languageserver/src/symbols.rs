@@ -0,0 +1,345 @@
+//! Document symbols, workspace symbols and folding ranges, computed from the module items and the
+//! syntax tree.
+
+#[cfg(test)]
+#[path = "symbols_test.rs"]
+mod test;
+
+use defs::ids::{FileIndex, LanguageElementId, ModuleFileId, ModuleItemId};
+use filesystem::ids::FileId;
+use filesystem::span::TextOffset;
+use semantic::db::SemanticGroup;
+use syntax::node::kind::SyntaxKind;
+use syntax::node::{ast, SyntaxNode, Terminal, TypedSyntaxNode};
+use tower_lsp::lsp_types::{
+    DocumentSymbol, FoldingRange, FoldingRangeKind, Location, SymbolInformation, SymbolKind,
+};
+
+use crate::{file_uri, span_range};
+
+/// Returns the outline of a module file: its items, with the members of structs, the variants of
+/// enums and the functions of traits and impls as children.
+pub fn document_symbols(
+    db: &(dyn SemanticGroup + 'static),
+    module_file_id: ModuleFileId,
+) -> Option<Vec<DocumentSymbol>> {
+    let syntax_db = db.upcast();
+    let file = db.module_file(module_file_id)?;
+    let root = db.file_syntax(file)?.as_syntax_node();
+    let module_items = db.module_items(module_file_id.0)?;
+    let mut symbols = vec![];
+    for item in module_items.items.values() {
+        if item.module_file(db.upcast()) != module_file_id {
+            continue;
+        }
+        let node = root.lookup_ptr(syntax_db, item.untyped_stable_ptr(db.upcast()));
+        let (kind, detail, children) = match item {
+            ModuleItemId::Submodule(_) => (SymbolKind::MODULE, None, vec![]),
+            // Uses do not define new symbols.
+            ModuleItemId::Use(_) => continue,
+            ModuleItemId::FreeFunction(_) => {
+                let function = ast::ItemFreeFunction::from_syntax_node(syntax_db, node.clone());
+                let signature = function.signature(syntax_db).as_syntax_node();
+                (SymbolKind::FUNCTION, text_without_trivia(db, file, &signature), vec![])
+            }
+            ModuleItemId::ExternFunction(_) => {
+                let function = ast::ItemExternFunction::from_syntax_node(syntax_db, node.clone());
+                let signature = function.signature(syntax_db).as_syntax_node();
+                (SymbolKind::FUNCTION, text_without_trivia(db, file, &signature), vec![])
+            }
+            ModuleItemId::Constant(_) => (SymbolKind::CONSTANT, None, vec![]),
+            ModuleItemId::ExternType(_) => (SymbolKind::TYPE_PARAMETER, None, vec![]),
+            ModuleItemId::Struct(_) => {
+                let members = ast::ItemStruct::from_syntax_node(syntax_db, node.clone())
+                    .members(syntax_db)
+                    .elements(syntax_db);
+                (SymbolKind::STRUCT, None, member_symbols(db, file, members, SymbolKind::FIELD))
+            }
+            ModuleItemId::Enum(_) => {
+                let variants = ast::ItemEnum::from_syntax_node(syntax_db, node.clone())
+                    .variants(syntax_db)
+                    .elements(syntax_db);
+                (
+                    SymbolKind::ENUM,
+                    None,
+                    member_symbols(db, file, variants, SymbolKind::ENUM_MEMBER),
+                )
+            }
+            ModuleItemId::Trait(_) => {
+                let trait_body = ast::ItemTrait::from_syntax_node(syntax_db, node.clone());
+                let functions = match trait_body.body(syntax_db) {
+                    ast::MaybeTraitBody::Some(body) => body
+                        .items(syntax_db)
+                        .elements(syntax_db)
+                        .into_iter()
+                        .filter_map(|item| match item {
+                            ast::TraitItem::Function(function) => symbol(
+                                db,
+                                file,
+                                function.as_syntax_node(),
+                                SymbolKind::METHOD,
+                                text_without_trivia(
+                                    db,
+                                    file,
+                                    &function.signature(syntax_db).as_syntax_node(),
+                                ),
+                                vec![],
+                            ),
+                        })
+                        .collect(),
+                    ast::MaybeTraitBody::None(_) => vec![],
+                };
+                (SymbolKind::INTERFACE, None, functions)
+            }
+            ModuleItemId::Impl(_) => {
+                let imp = ast::ItemImpl::from_syntax_node(syntax_db, node.clone());
+                let detail =
+                    text_without_trivia(db, file, &imp.trait_path(syntax_db).as_syntax_node())
+                        .map(|trait_path| format!("of {trait_path}"));
+                let functions = match imp.body(syntax_db) {
+                    ast::MaybeImplBody::Some(body) => body
+                        .items(syntax_db)
+                        .elements(syntax_db)
+                        .into_iter()
+                        .filter_map(|item| match item {
+                            ast::Item::FreeFunction(function) => symbol(
+                                db,
+                                file,
+                                function.as_syntax_node(),
+                                SymbolKind::METHOD,
+                                text_without_trivia(
+                                    db,
+                                    file,
+                                    &function.signature(syntax_db).as_syntax_node(),
+                                ),
+                                vec![],
+                            ),
+                            _ => None,
+                        })
+                        .collect(),
+                    ast::MaybeImplBody::None(_) => vec![],
+                };
+                (SymbolKind::OBJECT, detail, functions)
+            }
+        };
+        symbols.extend(symbol(db, file, node, kind, detail, children));
+    }
+    symbols.sort_by_key(|symbol| (symbol.range.start.line, symbol.range.start.character));
+    Some(symbols)
+}
+
+/// Returns the symbols of the members of a struct, or of the variants of an enum.
+fn member_symbols(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    members: Vec<ast::Member>,
+    kind: SymbolKind,
+) -> Vec<DocumentSymbol> {
+    let syntax_db = db.upcast();
+    members
+        .into_iter()
+        .filter_map(|member| {
+            let ty = member.type_clause(syntax_db).ty(syntax_db).as_syntax_node();
+            symbol(
+                db,
+                file,
+                member.as_syntax_node(),
+                kind,
+                text_without_trivia(db, file, &ty),
+                vec![],
+            )
+        })
+        .collect()
+}
+
+/// Returns the symbol of a node, named by its identifier child.
+#[allow(deprecated)]
+fn symbol(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: SyntaxNode,
+    kind: SymbolKind,
+    detail: Option<String>,
+    children: Vec<DocumentSymbol>,
+) -> Option<DocumentSymbol> {
+    let syntax_db = db.upcast();
+    let name = node
+        .children(syntax_db)
+        .find(|child| child.kind(syntax_db) == SyntaxKind::TerminalIdentifier)?;
+    let name_span = name.span_without_trivia(syntax_db);
+    Some(DocumentSymbol {
+        name: ast::TerminalIdentifier::from_syntax_node(syntax_db, name).text(syntax_db).into(),
+        detail,
+        kind,
+        tags: None,
+        deprecated: None,
+        range: span_range(db.upcast(), file, node.span_without_trivia(syntax_db))?,
+        selection_range: span_range(db.upcast(), file, name_span)?,
+        children: if children.is_empty() { None } else { Some(children) },
+    })
+}
+
+/// Returns the text of a node without its trivia, with its whitespace collapsed.
+fn text_without_trivia(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: &SyntaxNode,
+) -> Option<String> {
+    let content = db.file_content(file)?;
+    let span = node.span_without_trivia(db.upcast());
+    let text = content.get(span.start.0..span.end.0)?;
+    Some(text.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// Returns the symbols of all the crates whose names match `query`, as a subsequence ignoring case.
+/// The best matches come first, see [fuzzy_match].
+pub fn workspace_symbols(
+    db: &(dyn SemanticGroup + 'static),
+    query: &str,
+) -> Vec<SymbolInformation> {
+    let mut symbols = vec![];
+    for crate_id in db.crates() {
+        for module_id in db.crate_modules(crate_id).iter() {
+            let files = db.module_files(*module_id).unwrap_or_default();
+            for (file_index, file) in files.into_iter().enumerate() {
+                let Some(uri) = file_uri(db.upcast(), file) else { continue };
+                let module_file_id = ModuleFileId(*module_id, FileIndex(file_index));
+                let Some(document_symbols) = document_symbols(db, module_file_id) else {
+                    continue;
+                };
+                flatten_symbols(
+                    &mut symbols,
+                    document_symbols,
+                    &module_id.full_path(db.upcast()),
+                    &|symbol| {
+                        fuzzy_match(query, &symbol.name)
+                            .map(|_| Location { uri: uri.clone(), range: symbol.selection_range })
+                    },
+                );
+            }
+        }
+    }
+    // The sort is stable, so symbols that match equally well stay in the order of their files.
+    symbols.sort_by_key(|symbol| fuzzy_match(query, &symbol.name));
+    symbols
+}
+
+/// Adds the symbols, and their children, for which `location` returns a location.
+#[allow(deprecated)]
+fn flatten_symbols(
+    symbols: &mut Vec<SymbolInformation>,
+    document_symbols: Vec<DocumentSymbol>,
+    container_name: &str,
+    location: &dyn Fn(&DocumentSymbol) -> Option<Location>,
+) {
+    for document_symbol in document_symbols {
+        if let Some(location) = location(&document_symbol) {
+            symbols.push(SymbolInformation {
+                name: document_symbol.name.clone(),
+                kind: document_symbol.kind,
+                tags: None,
+                deprecated: None,
+                location,
+                container_name: Some(container_name.to_string()),
+            });
+        }
+        if let Some(children) = document_symbol.children {
+            let container_name = format!("{container_name}::{}", document_symbol.name);
+            flatten_symbols(symbols, children, &container_name, location);
+        }
+    }
+}
+
+/// How well a name matches a query, from the best match to the worst.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum FuzzyMatch {
+    Exact,
+    Prefix,
+    Substring,
+    Subsequence,
+}
+
+/// Returns how well `name` matches `query`, ignoring case, or None if the characters of `query` do
+/// not appear in order in `name`.
+fn fuzzy_match(query: &str, name: &str) -> Option<FuzzyMatch> {
+    let query = query.to_lowercase();
+    let name = name.to_lowercase();
+    let mut name_chars = name.chars();
+    if !query.chars().all(|c| name_chars.any(|name_c| name_c == c)) {
+        return None;
+    }
+    Some(if name == query {
+        FuzzyMatch::Exact
+    } else if name.starts_with(&query) {
+        FuzzyMatch::Prefix
+    } else if name.contains(&query) {
+        FuzzyMatch::Substring
+    } else {
+        FuzzyMatch::Subsequence
+    })
+}
+
+/// Returns the folding ranges of a file: its braced nodes that span several lines (e.g. blocks,
+/// struct members and trait bodies), and its groups of consecutive `use` items.
+pub fn folding_ranges(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+) -> Option<Vec<FoldingRange>> {
+    let root = db.file_syntax(file)?.as_syntax_node();
+    let mut ranges = vec![];
+    collect_folding_ranges(db, file, root, &mut ranges);
+    Some(ranges)
+}
+
+fn collect_folding_ranges(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: SyntaxNode,
+    ranges: &mut Vec<FoldingRange>,
+) {
+    let syntax_db = db.upcast();
+    let line = |offset: TextOffset| offset.position_in_file(db.upcast(), file).map(|pos| pos.line);
+    let children: Vec<_> = node.children(syntax_db).collect();
+    let lbrace = children.iter().find(|child| child.kind(syntax_db) == SyntaxKind::TerminalLBrace);
+    let rbrace =
+        children.iter().rev().find(|child| child.kind(syntax_db) == SyntaxKind::TerminalRBrace);
+    if let (Some(lbrace), Some(rbrace)) = (lbrace, rbrace) {
+        if let (Some(start_line), Some(end_line)) = (
+            line(lbrace.span_start_without_trivia(syntax_db)),
+            line(rbrace.span_start_without_trivia(syntax_db)),
+        ) {
+            // The line of the closing brace is kept visible.
+            if end_line > start_line + 1 {
+                ranges.push(FoldingRange {
+                    start_line: start_line as u32,
+                    end_line: end_line as u32 - 1,
+                    ..FoldingRange::default()
+                });
+            }
+        }
+    }
+    if node.kind(syntax_db) == SyntaxKind::ItemList {
+        let is_use = |child: &SyntaxNode| child.kind(syntax_db) == SyntaxKind::ItemUse;
+        let mut remaining = &children[..];
+        while let Some(start) = remaining.iter().position(is_use) {
+            let uses = remaining[start..].iter().take_while(|child| is_use(child)).count();
+            if let (Some(start_line), Some(end_line)) = (
+                line(remaining[start].span_start_without_trivia(syntax_db)),
+                line(remaining[start + uses - 1].span_end_without_trivia(syntax_db)),
+            ) {
+                if end_line > start_line {
+                    ranges.push(FoldingRange {
+                        start_line: start_line as u32,
+                        end_line: end_line as u32,
+                        kind: Some(FoldingRangeKind::Imports),
+                        ..FoldingRange::default()
+                    });
+                }
+            }
+            remaining = &remaining[start + uses..];
+        }
+    }
+    for child in children {
+        collect_folding_ranges(db, file, child, ranges);
+    }
+}
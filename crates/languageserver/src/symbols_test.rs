@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use defs::ids::{FileIndex, ModuleFileId, ModuleId};
+use filesystem::db::{FilesGroup, FilesGroupEx};
+use filesystem::ids::{CrateLongId, Directory, FileLongId};
+use indoc::indoc;
+use semantic::test_utils::setup_test_crate;
+use test_case::test_case;
+use tower_lsp::lsp_types::DocumentSymbol;
+
+use super::{document_symbols, folding_ranges, workspace_symbols};
+use crate::db::RootDatabase;
+
+/// The directory of the test crate of the workspace symbols. Workspace symbols are only of files on
+/// disk, which must have absolute paths.
+const CRATE_DIR: &str = "/test/src";
+
+/// Formats the symbols, one per line, with their children indented under them.
+fn format_symbols(symbols: &[DocumentSymbol], indent: usize, lines: &mut Vec<String>) {
+    for symbol in symbols {
+        let detail = symbol.detail.as_ref().map(|detail| format!(": {detail}")).unwrap_or_default();
+        lines.push(format!("{}{} {:?}{detail}", "    ".repeat(indent), symbol.name, symbol.kind));
+        format_symbols(symbol.children.as_deref().unwrap_or_default(), indent + 1, lines);
+    }
+}
+
+#[test]
+fn test_document_symbols() {
+    let mut db = RootDatabase::default();
+    let crate_id = setup_test_crate(
+        &mut db,
+        indoc! {"
+            mod submodule;
+            use submodule::bar;
+            const C: felt = 1;
+            extern type T;
+            extern func ext(a: felt) -> felt nopanic;
+            struct A { x: felt, y: T }
+            enum E { First: (), Second: felt }
+            trait MyTrait {
+                func f(a: felt) -> felt;
+            }
+            impl MyImpl of MyTrait {
+                func f(a: felt) -> felt {
+                    a
+                }
+            }
+            func foo(a: A) -> felt {
+                a.x
+            }
+        "},
+    );
+    let module_file_id = ModuleFileId(ModuleId::CrateRoot(crate_id), FileIndex(0));
+    let mut lines = vec![];
+    format_symbols(&document_symbols(&db, module_file_id).unwrap(), 0, &mut lines);
+    assert_eq!(
+        lines,
+        [
+            "submodule Module",
+            "C Constant",
+            "T TypeParameter",
+            "ext Function: (a: felt) -> felt nopanic",
+            "A Struct",
+            "    x Field: felt",
+            "    y Field: T",
+            "E Enum",
+            "    First EnumMember: ()",
+            "    Second EnumMember: felt",
+            "MyTrait Interface",
+            "    f Method: (a: felt) -> felt",
+            "MyImpl Object: of MyTrait",
+            "    f Method: (a: felt) -> felt",
+            "foo Function: (a: A) -> felt",
+        ]
+    );
+}
+
+#[test_case(
+    "point",
+    &[
+        "test::Point",
+        "test::point_x",
+        "test::Line::start_point",
+        "test::Line::end_point",
+        "test::pop_int",
+    ];
+    "exact matches, then prefixes, then substrings, then subsequences"
+)]
+#[test_case(
+    "POINT_",
+    &["test::point_x"];
+    "ignoring case"
+)]
+#[test_case(
+    "pnt",
+    &[
+        "test::Point",
+        "test::Line::start_point",
+        "test::Line::end_point",
+        "test::pop_int",
+        "test::point_x",
+    ];
+    "equal matches in the order of the file"
+)]
+#[test_case(
+    "xyz",
+    &[];
+    "no matches"
+)]
+fn test_workspace_symbols(query: &str, expected_symbols: &[&str]) {
+    let mut db = RootDatabase::default();
+    let crate_id = db.intern_crate(CrateLongId("test".into()));
+    db.set_crate_root(crate_id, Some(Directory(CRATE_DIR.into())));
+    let file = db.intern_file(FileLongId::OnDisk(format!("{CRATE_DIR}/lib.cairo").into()));
+    let code = indoc! {"
+        struct Point { x: felt, y: felt }
+        struct Line { start_point: Point, end_point: Point }
+        func pop_int() {}
+        func point_x(p: Point) -> felt {
+            p.x
+        }
+        func foo() {}
+    "};
+    db.override_file_content(file, Some(Arc::new(code.into())));
+
+    // The symbols of the core crate are skipped.
+    let symbols: Vec<_> = workspace_symbols(&db, query)
+        .into_iter()
+        .filter_map(|symbol| {
+            let container_name = symbol.container_name.unwrap();
+            (container_name == "test" || container_name.starts_with("test::"))
+                .then(|| format!("{container_name}::{}", symbol.name))
+        })
+        .collect();
+    assert_eq!(symbols, expected_symbols);
+}
+
+#[test]
+fn test_folding_ranges() {
+    let mut db = RootDatabase::default();
+    setup_test_crate(
+        &mut db,
+        indoc! {"
+            use a::b;
+            use a::c;
+            struct A {
+                x: felt,
+                y: felt,
+            }
+            func foo() -> felt {
+                let x = {
+                    1
+                };
+                x
+            }
+            func bar() -> felt { 1 }
+            use a::d;
+        "},
+    );
+    let file = db.intern_file(FileLongId::OnDisk("src/lib.cairo".into()));
+    let ranges: Vec<_> = folding_ranges(&db, file)
+        .unwrap()
+        .into_iter()
+        .map(|range| {
+            let kind = range.kind.map(|kind| format!(" {kind:?}")).unwrap_or_default();
+            format!("{}-{}{kind}", range.start_line, range.end_line)
+        })
+        .collect();
+    // A single `use` is not folded, and neither is a block on a single line.
+    assert_eq!(ranges, ["0-1 Imports", "2-4", "6-10", "7-8"]);
+}
@@ -0,0 +1,85 @@
+use filesystem::db::FilesGroup;
+use test_case::test_case;
+use tower_lsp::lsp_types::{
+    DidChangeTextDocumentParams, DidOpenTextDocumentParams, Position, Range,
+    TextDocumentContentChangeEvent, TextDocumentItem, Url, VersionedTextDocumentIdentifier,
+};
+use tower_lsp::{LanguageServer, LspService};
+
+use crate::{apply_content_change, position_to_offset, Backend, RootDatabase};
+
+/// A text with multi-byte characters, a character outside the BMP (a surrogate pair in UTF-16) and
+/// a CRLF line ending.
+const TEXT: &str = "aé😀b\r\nxy\n";
+
+#[test_case(0, 0, 0; "line start")]
+#[test_case(0, 2, 3; "after a two byte character")]
+#[test_case(0, 4, 7; "after a surrogate pair")]
+#[test_case(0, 3, 7; "inside a surrogate pair")]
+#[test_case(0, 5, 8; "line end before CRLF")]
+#[test_case(0, 100, 8; "past line end")]
+#[test_case(1, 1, 11; "second line")]
+#[test_case(2, 0, 13; "file end")]
+#[test_case(5, 3, 13; "past file end")]
+fn test_position_to_offset(line: u32, character: u32, expected: usize) {
+    assert_eq!(position_to_offset(TEXT, Position { line, character }), expected);
+}
+
+/// Returns a change of the text between two positions, given as `(line, character)`.
+fn change(start: (u32, u32), end: (u32, u32), text: &str) -> TextDocumentContentChangeEvent {
+    TextDocumentContentChangeEvent {
+        range: Some(Range {
+            start: Position { line: start.0, character: start.1 },
+            end: Position { line: end.0, character: end.1 },
+        }),
+        range_length: None,
+        text: text.into(),
+    }
+}
+
+#[test_case(change((0, 2), (0, 4), "c"), "aécb\r\nxy\n"; "replace surrogate pair")]
+#[test_case(change((0, 100), (1, 0), " "), "aé😀b xy\n"; "join lines across CRLF")]
+#[test_case(change((1, 2), (1, 2), "\r\nz"), "aé😀b\r\nxy\r\nz\n"; "insert a line")]
+#[test_case(change((1, 1), (0, 1), "z"), "aé😀b\r\nxzy\n"; "end before start")]
+#[test_case(change((7, 0), (8, 0), "z"), "aé😀b\r\nxy\nz"; "past file end")]
+#[test_case(
+    TextDocumentContentChangeEvent { range: None, range_length: None, text: "z".into() },
+    "z";
+    "whole text"
+)]
+fn test_apply_content_change(change: TextDocumentContentChangeEvent, expected: &str) {
+    let mut text = TEXT.to_string();
+    apply_content_change(&mut text, change);
+    assert_eq!(text, expected);
+}
+
+#[tokio::test]
+async fn test_did_open_with_unsaved_text() {
+    let path = std::env::temp_dir().join(format!("did_open_{}.cairo", std::process::id()));
+    std::fs::write(&path, "// Saved.\n").unwrap();
+    let uri = Url::from_file_path(&path).unwrap();
+    let (service, _) = LspService::new(|client| Backend::new(client, RootDatabase::default()));
+    let backend = service.inner();
+
+    backend
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "cairo".into(),
+                version: 0,
+                text: "func foo() {}\n".into(),
+            },
+        })
+        .await;
+    backend
+        .did_change(DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier { uri: uri.clone(), version: 1 },
+            content_changes: vec![change((0, 5), (0, 8), "bar")],
+        })
+        .await;
+
+    let db = backend.db().await;
+    let file = backend.file(&db, uri);
+    assert_eq!(db.file_content(file).unwrap().as_str(), "func bar() {}\n");
+    std::fs::remove_file(path).unwrap();
+}
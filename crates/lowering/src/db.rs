@@ -2,20 +2,24 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 use db_utils::Upcast;
-use defs::ids::{FreeFunctionId, ModuleId, ModuleItemId};
+use defs::ids::{FunctionWithBodyId, LoopFunctionId, ModuleId, ModuleItemId};
 use diagnostics::{Diagnostics, DiagnosticsBuilder};
 use filesystem::ids::FileId;
 use semantic::db::SemanticGroup;
 use semantic::TypeId;
 
 use crate::diagnostic::LoweringDiagnostic;
-use crate::lower::{lower, Lowered};
+use crate::lower::{lower, Lowered, LoweredLoop};
 
 // Salsa database interface.
 #[salsa::query_group(LoweringDatabase)]
 pub trait LoweringGroup: SemanticGroup + Upcast<dyn SemanticGroup> {
-    /// Computed the lowered representation of a free function.
-    fn free_function_lowered(&self, free_function: FreeFunctionId) -> Option<Arc<Lowered>>;
+    /// Computed the lowered representation of a function with a body.
+    fn function_with_body_lowered(&self, function_id: FunctionWithBodyId) -> Option<Arc<Lowered>>;
+
+    /// Returns the lowered representation of a loop function. Computed as part of the lowering of
+    /// the function containing the loop.
+    fn loop_function_lowered(&self, loop_function: LoopFunctionId) -> Option<Arc<LoweredLoop>>;
 
     /// Aggregates module level semantic diagnostics.
     fn module_lowering_diagnostics(
@@ -32,7 +36,7 @@ pub trait LoweringGroup: SemanticGroup + Upcast<dyn SemanticGroup> {
     /// Returns the representative of the function's strongly connected component. The
     /// representative is consistently chosen for all the functions in the same SCC.
     #[salsa::invoke(crate::lower::implicits::function_scc_representative)]
-    fn function_scc_representative(&self, function: FreeFunctionId) -> SCCRepresentative;
+    fn function_scc_representative(&self, function: FunctionWithBodyId) -> SCCRepresentative;
 
     /// Returns the explicit implicits required by all the functions in the SCC of this function.
     /// These are all the implicit parameters that are explicitly declared in the functions of
@@ -52,39 +56,55 @@ pub trait LoweringGroup: SemanticGroup + Upcast<dyn SemanticGroup> {
     #[salsa::invoke(crate::lower::implicits::function_all_implicits)]
     fn function_all_implicits(&self, function: semantic::FunctionId) -> Option<Vec<TypeId>>;
 
-    /// Returns all the implicit parameters that the free function requires (according to both its
-    /// signature and the functions it calls).
-    #[salsa::invoke(crate::lower::implicits::free_function_all_implicits)]
-    fn free_function_all_implicits(&self, function: FreeFunctionId) -> Option<HashSet<TypeId>>;
+    /// Returns all the implicit parameters that the function with body requires (according to both
+    /// its signature and the functions it calls).
+    #[salsa::invoke(crate::lower::implicits::function_with_body_all_implicits)]
+    fn function_with_body_all_implicits(
+        &self,
+        function: FunctionWithBodyId,
+    ) -> Option<HashSet<TypeId>>;
 
-    /// Returns all the implicit parameters that the free function requires (according to both its
-    /// signature and the functions it calls). The items in the returned vector are unique and the
-    /// order is consistent, but not necessarily related to the order of the explicit implicits in
-    /// the signature of the function.
-    #[salsa::invoke(crate::lower::implicits::free_function_all_implicits_vec)]
-    fn free_function_all_implicits_vec(&self, function: FreeFunctionId) -> Option<Vec<TypeId>>;
+    /// Returns all the implicit parameters that the function with body requires (according to both
+    /// its signature and the functions it calls). The items in the returned vector are unique and
+    /// the order is consistent, but not necessarily related to the order of the explicit implicits
+    /// in the signature of the function.
+    #[salsa::invoke(crate::lower::implicits::function_with_body_all_implicits_vec)]
+    fn function_with_body_all_implicits_vec(
+        &self,
+        function: FunctionWithBodyId,
+    ) -> Option<Vec<TypeId>>;
 
     /// Returns whether the function may panic.
     #[salsa::invoke(crate::lower::implicits::function_may_panic)]
     fn function_may_panic(&self, function: semantic::FunctionId) -> Option<bool>;
 
     /// Returns whether the function may panic.
-    #[salsa::invoke(crate::lower::implicits::free_function_may_panic)]
-    fn free_function_may_panic(&self, free_function: FreeFunctionId) -> Option<bool>;
+    #[salsa::invoke(crate::lower::implicits::function_with_body_may_panic)]
+    fn function_with_body_may_panic(&self, function: FunctionWithBodyId) -> Option<bool>;
 
     /// Returns all the functions in the same strongly connected component as the given function.
     #[salsa::invoke(crate::lower::implicits::function_scc)]
-    fn function_scc(&self, function_id: FreeFunctionId) -> Vec<FreeFunctionId>;
+    fn function_scc(&self, function_id: FunctionWithBodyId) -> Vec<FunctionWithBodyId>;
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
-pub struct SCCRepresentative(pub FreeFunctionId);
+pub struct SCCRepresentative(pub FunctionWithBodyId);
 
-fn free_function_lowered(
+fn function_with_body_lowered(
     db: &dyn LoweringGroup,
-    free_function_id: FreeFunctionId,
+    function_id: FunctionWithBodyId,
 ) -> Option<Arc<Lowered>> {
-    Some(Arc::new(lower(db.upcast(), free_function_id)?))
+    Some(Arc::new(lower(db.upcast(), function_id)?))
+}
+
+fn loop_function_lowered(
+    db: &dyn LoweringGroup,
+    loop_function: LoopFunctionId,
+) -> Option<Arc<LoweredLoop>> {
+    db.function_with_body_lowered(loop_function.parent(db.upcast()))?
+        .loops
+        .get(&loop_function)
+        .cloned()
 }
 
 fn module_lowering_diagnostics(
@@ -96,17 +116,26 @@ fn module_lowering_diagnostics(
         match item {
             ModuleItemId::FreeFunction(free_function) => {
                 diagnostics.extend(
-                    db.free_function_lowered(*free_function)
+                    db.function_with_body_lowered(FunctionWithBodyId::Free(*free_function))
                         .map(|lowered| lowered.diagnostics.clone())
                         .unwrap_or_default(),
                 );
             }
+            ModuleItemId::Impl(impl_id) => {
+                for impl_function in db.impl_functions(*impl_id).unwrap_or_default() {
+                    diagnostics.extend(
+                        db.function_with_body_lowered(FunctionWithBodyId::Impl(impl_function))
+                            .map(|lowered| lowered.diagnostics.clone())
+                            .unwrap_or_default(),
+                    );
+                }
+            }
             ModuleItemId::Submodule(_) => {}
             ModuleItemId::Use(_) => {}
+            ModuleItemId::Constant(_) => {}
             ModuleItemId::Struct(_) => {}
             ModuleItemId::Enum(_) => {}
             ModuleItemId::Trait(_) => {}
-            ModuleItemId::Impl(_) => {}
             ModuleItemId::ExternType(_) => {}
             ModuleItemId::ExternFunction(_) => {}
         }
@@ -31,7 +31,7 @@ pub struct LoweringDiagnostic {
 impl DiagnosticEntry for LoweringDiagnostic {
     type DbType = dyn SemanticGroup;
 
-    fn format(&self, _db: &Self::DbType) -> String {
+    fn format(&self, db: &Self::DbType) -> String {
         match &self.kind {
             LoweringDiagnosticKind::Unreachable { .. } => "Unreachable code".into(),
             LoweringDiagnosticKind::NonZeroValueInMatch => {
@@ -41,6 +41,19 @@ impl DiagnosticEntry for LoweringDiagnostic {
                 "Only match zero (match ... { 0 => ..., _ => ... }) is currently supported.".into()
             }
             LoweringDiagnosticKind::VariableMoved => "Variable was previously moved.".into(),
+            LoweringDiagnosticKind::VariableNotDropped { ty } => {
+                format!(r#"Variable of type "{}" is not dropped."#, ty.format(db))
+            }
+            LoweringDiagnosticKind::UnsupportedGenericFunctionCall => {
+                "Calling a generic function is not supported yet, unless it is an impl function \
+                 that only forwards its params to an extern function."
+                    .into()
+            }
+            LoweringDiagnosticKind::UnsupportedTraitFunctionCall => {
+                "Calling a trait function through a trait bound of a generic param is not \
+                 supported yet."
+                    .into()
+            }
         }
     }
 
@@ -56,8 +69,30 @@ impl DiagnosticEntry for LoweringDiagnostic {
         }
         self.stable_location.diagnostic_location(db.upcast())
     }
+
+    fn code(&self) -> &'static str {
+        self.kind.code()
+    }
+
+    fn help(&self, _db: &Self::DbType) -> Option<String> {
+        match &self.kind {
+            LoweringDiagnosticKind::VariableMoved => Some(
+                "Derive `Copy` for the type of the variable, or don't use the variable after it \
+                 is moved."
+                    .into(),
+            ),
+            LoweringDiagnosticKind::VariableNotDropped { .. } => Some(
+                "Derive `Drop` for the type of the variable, or move the variable before it goes \
+                 out of scope."
+                    .into(),
+            ),
+            _ => None,
+        }
+    }
 }
 
+/// The kind of a lowering diagnostic. Each kind has a stable code, see
+/// [LoweringDiagnosticKind::code].
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum LoweringDiagnosticKind {
     Unreachable { last_statement_ptr: SyntaxStablePtrId },
@@ -66,4 +101,23 @@ pub enum LoweringDiagnosticKind {
     // TODO(lior): Remove once supported.
     OnlyMatchZeroIsSupported,
     VariableMoved,
+    VariableNotDropped { ty: semantic::TypeId },
+    // TODO(spapini): Remove once generic functions are specialized.
+    UnsupportedGenericFunctionCall,
+    // TODO(spapini): Remove once generic functions are specialized.
+    UnsupportedTraitFunctionCall,
+}
+impl LoweringDiagnosticKind {
+    /// Returns the stable code of the kind. Codes of removed kinds must not be reused.
+    pub fn code(&self) -> &'static str {
+        match self {
+            LoweringDiagnosticKind::Unreachable { .. } => "L0001",
+            LoweringDiagnosticKind::NonZeroValueInMatch => "L0002",
+            LoweringDiagnosticKind::OnlyMatchZeroIsSupported => "L0003",
+            LoweringDiagnosticKind::VariableMoved => "L0004",
+            LoweringDiagnosticKind::UnsupportedGenericFunctionCall => "L0005",
+            LoweringDiagnosticKind::UnsupportedTraitFunctionCall => "L0006",
+            LoweringDiagnosticKind::VariableNotDropped { .. } => "L0007",
+        }
+    }
 }
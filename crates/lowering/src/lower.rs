@@ -1,20 +1,27 @@
+use std::sync::Arc;
+
 use debug::DebugWithDb;
-use defs::ids::{FreeFunctionId, LanguageElementId};
+use defs::diagnostic_utils::StableLocation;
+use defs::ids::{FunctionWithBodyId, GenericFunctionId, LanguageElementId, LoopFunctionId};
 use diagnostics::Diagnostics;
 use id_arena::Arena;
 use itertools::{chain, zip_eq, Itertools};
+use num_bigint::{BigInt, Sign};
 use num_traits::Zero;
 use scope::{BlockScope, BlockScopeEnd};
 use semantic::corelib::{
-    core_felt_ty, core_jump_nz_func, core_nonzero_ty, get_core_function_id,
+    core_felt_ty, core_jump_nz_func, core_nonzero_ty, get_core_function_id, get_core_ty_by_name,
     get_enum_concrete_variant, get_panic_ty, jump_nz_nonzero_variant, jump_nz_zero_variant,
 };
 use semantic::expr::fmt::ExprFormatter;
 use semantic::items::enm::SemanticEnumEx;
 use semantic::items::imp::ImplLookupContext;
+use semantic::literals::SHORT_STRING_MAX_LEN;
 use semantic::{ConcreteTypeId, GenericArgumentId, Mutability, TypeLongId, VarId};
 use syntax::node::ids::SyntaxStablePtrId;
+use utils::ordered_hash_map::OrderedHashMap;
 use utils::unordered_hash_map::UnorderedHashMap;
+use utils::unordered_hash_set::UnorderedHashSet;
 use utils::{extract_matches, try_extract_matches};
 
 use self::context::{
@@ -23,6 +30,7 @@ use self::context::{
 };
 use self::external::{extern_facade_expr, extern_facade_return_tys};
 use self::lower_if::lower_expr_if;
+use self::lower_loop::{lower_continue, lower_expr_loop};
 use self::scope::{generators, BlockFlowMerger, BlockMergerFinalized};
 use self::variables::LivingVar;
 use crate::db::LoweringGroup;
@@ -34,6 +42,7 @@ mod context;
 mod external;
 pub mod implicits;
 mod lower_if;
+mod lower_loop;
 mod scope;
 mod semantic_map;
 mod variables;
@@ -49,16 +58,34 @@ pub struct Lowered {
     pub variables: Arena<Variable>,
     /// Arena of allocated lowered blocks.
     pub blocks: Arena<Block>,
+    /// The lowered loop functions of the loops in the function, including nested ones. Empty for
+    /// the lowering of a loop function itself.
+    pub loops: OrderedHashMap<LoopFunctionId, Arc<LoweredLoop>>,
+}
+
+/// A lowered loop function. A loop is lowered into a recursive function, that performs a single
+/// iteration of the loop, and calls itself for the next iteration.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LoweredLoop {
+    /// The lowered code of the loop function.
+    pub lowered: Lowered,
+    /// The types of the captured variables that are passed by reference (the variables changed by
+    /// the loop). These come after the implicits in the parameters of the function.
+    pub ref_param_tys: Vec<semantic::TypeId>,
+    /// The types of the rest of the captured variables, passed after the reference parameters.
+    pub param_tys: Vec<semantic::TypeId>,
+    /// The type of the loop expression.
+    pub return_type: semantic::TypeId,
 }
 
-/// Lowers a semantic free function.
-pub fn lower(db: &dyn LoweringGroup, free_function_id: FreeFunctionId) -> Option<Lowered> {
-    log::trace!("Lowering a free function.");
-    let function_def = db.free_function_definition(free_function_id)?;
-    let generic_params = db.free_function_declaration_generic_params(free_function_id)?;
-    let signature = db.free_function_declaration_signature(free_function_id)?;
+/// Lowers a semantic function with a body (a free function or an impl function).
+pub fn lower(db: &dyn LoweringGroup, function_id: FunctionWithBodyId) -> Option<Lowered> {
+    log::trace!("Lowering a function.");
+    let function_def = db.function_with_body_definition(function_id)?;
+    let generic_params = db.function_with_body_generic_params(function_id)?;
+    let signature = db.function_with_body_signature(function_id)?;
 
-    let implicits = db.free_function_all_implicits_vec(free_function_id)?;
+    let implicits = db.function_with_body_all_implicits_vec(function_id)?;
     // Params.
     let ref_params = signature
         .params
@@ -68,30 +95,34 @@ pub fn lower(db: &dyn LoweringGroup, free_function_id: FreeFunctionId) -> Option
         .collect_vec();
     let input_semantic_vars: Vec<semantic::Variable> =
         signature.params.iter().cloned().map(semantic::Variable::Param).collect();
-    let (input_semantic_var_ids, input_var_tys): (Vec<_>, Vec<_>) = input_semantic_vars
-        .iter()
-        .map(|semantic_var| (semantic_var.id(), semantic_var.ty()))
-        .unzip();
-    let input_var_tys = chain!(implicits.clone(), input_var_tys).collect();
+    let input_var_tys =
+        chain!(implicits.clone(), input_semantic_vars.iter().map(|semantic_var| semantic_var.ty()))
+            .collect();
 
     let implicits_ref = &implicits;
     let mut ctx = LoweringContext {
         db,
+        function_id,
         function_def: &function_def,
-        signature,
-        may_panic: db.free_function_may_panic(free_function_id)?,
-        diagnostics: LoweringDiagnostics::new(free_function_id.module_file(db.upcast())),
+        return_type: signature.return_type,
+        may_panic: db.function_with_body_may_panic(function_id)?,
+        diagnostics: LoweringDiagnostics::new(function_id.module_file(db.upcast())),
         variables: Arena::default(),
         blocks: Arena::default(),
         semantic_defs: UnorderedHashMap::default(),
         ref_params: &ref_params,
         implicits: implicits_ref,
         lookup_context: ImplLookupContext {
-            module_id: free_function_id.module(db.upcast()),
+            module_id: function_id.module(db.upcast()),
             extra_modules: vec![],
             generic_params,
         },
-        expr_formatter: ExprFormatter { db: db.upcast(), free_function_id },
+        expr_formatter: ExprFormatter { db: db.upcast(), function_id },
+        current_loop: None,
+        loops: OrderedHashMap::default(),
+        current_location: None,
+        variable_locations: UnorderedHashMap::default(),
+        not_dropped_reported: UnorderedHashSet::default(),
     };
 
     // TODO(spapini): Build semantic_defs in semantic model.
@@ -113,8 +144,13 @@ pub fn lower(db: &dyn LoweringGroup, free_function_id: FreeFunctionId) -> Option
                 }
 
                 // Initialize implicits and params.
-                for (semantic_var_id, var) in zip_eq(input_semantic_var_ids, variables_iter) {
-                    scope.put_semantic_variable(semantic_var_id, var);
+                for (param, var) in zip_eq(&signature.params, variables_iter) {
+                    let location = StableLocation::new(
+                        ctx.diagnostics.module_file_id,
+                        param.id.stable_ptr(ctx.db.upcast()).untyped(),
+                    );
+                    ctx.variable_locations.insert(var.var_id(), location);
+                    scope.put_semantic_variable(VarId::Param(param.id), var);
                 }
                 lower_block(ctx, scope, semantic_block, true)
             })
@@ -126,6 +162,7 @@ pub fn lower(db: &dyn LoweringGroup, free_function_id: FreeFunctionId) -> Option
         root,
         variables: ctx.variables,
         blocks: ctx.blocks,
+        loops: ctx.loops,
     })
 }
 
@@ -229,6 +266,24 @@ pub fn lower_statement(
             let return_vars = get_full_return_vars(ctx, scope, lowered_expr)?;
             return Err(StatementLoweringFlowError::End(BlockScopeEnd::Return(return_vars)));
         }
+        semantic::Statement::Break(semantic::StatementBreak { expr, stable_ptr: _ }) => {
+            log::trace!("Lowering a break statement.");
+            if ctx.current_loop.is_none() {
+                return Err(StatementLoweringFlowError::Failed);
+            }
+            let lowered_expr = match expr {
+                Some(expr) => lower_expr(ctx, scope, *expr)?,
+                None => LoweredExpr::Tuple(vec![]),
+            };
+            // Breaking returns from the loop function.
+            let return_vars = get_full_return_vars(ctx, scope, lowered_expr)?;
+            return Err(StatementLoweringFlowError::End(BlockScopeEnd::Return(return_vars)));
+        }
+        semantic::Statement::Continue(semantic::StatementContinue { stable_ptr }) => {
+            log::trace!("Lowering a continue statement.");
+            let return_vars = lower_continue(ctx, scope, stable_ptr.untyped())?;
+            return Err(StatementLoweringFlowError::End(BlockScopeEnd::Return(return_vars)));
+        }
     }
     Ok(())
 }
@@ -366,6 +421,23 @@ fn lower_expr(
     ctx: &mut LoweringContext<'_>,
     scope: &mut BlockScope,
     expr_id: semantic::ExprId,
+) -> Result<LoweredExpr, LoweringFlowError> {
+    // The statements generated for the expression are attributed to its location.
+    let location = StableLocation::new(
+        ctx.diagnostics.module_file_id,
+        ctx.function_def.exprs[expr_id].stable_ptr().untyped(),
+    );
+    let parent_location = ctx.current_location.replace(location);
+    let res = lower_expr_inner(ctx, scope, expr_id);
+    ctx.current_location = parent_location;
+    res
+}
+
+/// Lowers a semantic expression, without updating the current location.
+fn lower_expr_inner(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    expr_id: semantic::ExprId,
 ) -> Result<LoweredExpr, LoweringFlowError> {
     let expr = &ctx.function_def.exprs[expr_id];
     match expr {
@@ -375,6 +447,7 @@ fn lower_expr(
         semantic::Expr::FunctionCall(expr) => lower_expr_function_call(ctx, expr, scope),
         semantic::Expr::Match(expr) => lower_expr_match(ctx, expr, scope),
         semantic::Expr::If(expr) => lower_expr_if(ctx, scope, expr),
+        semantic::Expr::Loop(_) | semantic::Expr::While(_) => lower_expr_loop(ctx, scope, expr_id),
         semantic::Expr::Var(expr) => {
             log::trace!("Lowering a variable: {:?}", expr.debug(&ctx.expr_formatter));
             Ok(LoweredExpr::AtVariable(use_semantic_var(
@@ -390,6 +463,7 @@ fn lower_expr(
                 generators::Literal { value: expr.value.clone(), ty: expr.ty }.add(ctx, scope),
             ))
         }
+        semantic::Expr::StringLiteral(expr) => lower_expr_string_literal(ctx, expr, scope),
         semantic::Expr::MemberAccess(expr) => lower_expr_member_access(ctx, expr, scope),
         semantic::Expr::StructCtor(expr) => lower_expr_struct_ctor(ctx, expr, scope),
         semantic::Expr::EnumVariantCtor(expr) => lower_expr_enum_ctor(ctx, expr, scope),
@@ -445,6 +519,20 @@ fn lower_expr_function_call(
     scope: &mut BlockScope,
 ) -> Result<LoweredExpr, LoweringFlowError> {
     log::trace!("Lowering a function call expression: {:?}", expr.debug(&ctx.expr_formatter));
+    let function = forwarded_function(ctx.db, expr.function).unwrap_or(expr.function);
+    let concrete_function = ctx.db.lookup_intern_function(function).function;
+    if concrete_function.generic_function.function_with_body().is_some()
+        && !concrete_function.generic_args.is_empty()
+    {
+        ctx.diagnostics.report(expr.stable_ptr.untyped(), UnsupportedGenericFunctionCall);
+        return Err(LoweringFlowError::Failed);
+    }
+    // Trait functions are called through trait bounds of generic params, and can't be resolved to
+    // an impl function before the generic function is specialized.
+    if matches!(concrete_function.generic_function, GenericFunctionId::TraitFunction(_)) {
+        ctx.diagnostics.report(expr.stable_ptr.untyped(), UnsupportedTraitFunctionCall);
+        return Err(LoweringFlowError::Failed);
+    }
 
     // TODO(spapini): Use the correct stable pointer.
     let arg_inputs = lower_exprs_as_vars(ctx, &expr.args, scope)?;
@@ -461,7 +549,7 @@ fn lower_expr_function_call(
         .into_iter()
         .unzip();
     let callee_implicit_types =
-        ctx.db.function_all_implicits(expr.function).ok_or(LoweringFlowError::Failed)?;
+        ctx.db.function_all_implicits(function).ok_or(LoweringFlowError::Failed)?;
     let implicits = callee_implicit_types
         .iter()
         .map(|ty| scope.take_implicit(*ty))
@@ -471,13 +559,13 @@ fn lower_expr_function_call(
     let inputs = chain!(implicits, ref_inputs, arg_inputs.into_iter()).collect();
 
     // If the function is panic(), do something special.
-    if expr.function == get_core_function_id(ctx.db.upcast(), "panic".into(), vec![]) {
+    if function == get_core_function_id(ctx.db.upcast(), "panic".into(), vec![]) {
         let [input] = <[_; 1]>::try_from(inputs).ok().unwrap();
         return lower_panic(ctx, scope, input);
     }
 
     // The following is relevant only to extern functions.
-    if expr.function.try_get_extern_function_id(ctx.db.upcast()).is_some() {
+    if function.try_get_extern_function_id(ctx.db.upcast()).is_some() {
         if let semantic::TypeLongId::Concrete(semantic::ConcreteTypeId::Enum(concrete_enum_id)) =
             ctx.db.lookup_intern_type(expr.ty)
         {
@@ -487,7 +575,7 @@ fn lower_expr_function_call(
             // 1. [lower_optimized_extern_match]
             // 2. [context::LoweredExprExternEnum::var]
             return Ok(LoweredExpr::ExternEnum(LoweredExprExternEnum {
-                function: expr.function,
+                function,
                 concrete_enum_id,
                 inputs,
                 ref_args: expr.ref_args.clone(),
@@ -496,11 +584,11 @@ fn lower_expr_function_call(
         }
     }
 
-    let may_panic = ctx.db.function_may_panic(expr.function).ok_or(LoweringFlowError::Failed)?;
+    let may_panic = ctx.db.function_may_panic(function).ok_or(LoweringFlowError::Failed)?;
     let expr_ty = if may_panic { get_panic_ty(ctx.db.upcast(), expr.ty) } else { expr.ty };
 
     let (implicit_outputs, ref_outputs, res) =
-        perform_function_call(ctx, scope, expr.function, inputs, ref_tys, expr_ty)?;
+        perform_function_call(ctx, scope, function, inputs, ref_tys, expr_ty)?;
 
     // Rebind the implicits.
     for (implicit_type, implicit_output) in zip_eq(callee_implicit_types, implicit_outputs) {
@@ -517,6 +605,82 @@ fn lower_expr_function_call(
     Ok(res)
 }
 
+/// If `function` is an impl function whose body only passes its params, in order, to an extern
+/// function, or to a non-generic free function, returns that function, specialized for the generic
+/// args of `function`.
+/// Calls to such functions are lowered to direct calls to the function they forward to. This
+/// spares a function call for the operator impls wrapping a libfunc, such as `FeltAdd::add`, and
+/// is the only way to call impl functions of generic impls, as generic functions are not
+/// specialized yet.
+fn forwarded_function(
+    db: &dyn LoweringGroup,
+    function: semantic::FunctionId,
+) -> Option<semantic::FunctionId> {
+    let semantic::ConcreteFunction { generic_function, generic_args } =
+        db.lookup_intern_function(function).function;
+    let function_with_body = FunctionWithBodyId::Impl(try_extract_matches!(
+        generic_function,
+        GenericFunctionId::ImplFunction
+    )?);
+    let definition = db.function_with_body_definition(function_with_body)?;
+    let block = try_extract_matches!(&definition.exprs[definition.body], semantic::Expr::Block)?;
+    if !block.statements.is_empty() {
+        return None;
+    }
+    let call = try_extract_matches!(&definition.exprs[block.tail?], semantic::Expr::FunctionCall)?;
+    let callee = db.lookup_intern_function(call.function).function;
+    let forwards_to_supported_function = match callee.generic_function {
+        GenericFunctionId::Extern(_) => true,
+        GenericFunctionId::Free(_) => callee.generic_args.is_empty(),
+        _ => false,
+    };
+    if !forwards_to_supported_function {
+        return None;
+    }
+
+    let (ref_params, params): (Vec<_>, Vec<_>) = db
+        .function_with_body_signature(function_with_body)?
+        .params
+        .into_iter()
+        .partition(|param| param.mutability == Mutability::Reference);
+    let forwards_ref_params = call.ref_args.len() == ref_params.len()
+        && zip_eq(&call.ref_args, &ref_params)
+            .all(|(ref_arg, param)| *ref_arg == VarId::Param(param.id));
+    let forwards_params = call.args.len() == params.len()
+        && zip_eq(&call.args, &params).all(|(arg, param)| {
+            matches!(
+                &definition.exprs[*arg],
+                semantic::Expr::Var(semantic::ExprVar { var: VarId::Param(param_id), .. })
+                    if *param_id == param.id
+            )
+        });
+    if !forwards_ref_params || !forwards_params {
+        return None;
+    }
+
+    let generic_params = db.function_with_body_generic_params(function_with_body)?;
+    if generic_params.len() != generic_args.len() {
+        return None;
+    }
+    let substitution = zip_eq(generic_params, generic_args).collect();
+    let generic_args = callee
+        .generic_args
+        .into_iter()
+        .map(|generic_arg| match generic_arg {
+            GenericArgumentId::Type(ty) => GenericArgumentId::Type(
+                semantic::types::substitute_generics(db.upcast(), &substitution, ty),
+            ),
+            GenericArgumentId::Literal(_) => generic_arg,
+        })
+        .collect();
+    Some(db.intern_function(semantic::FunctionLongId {
+        function: semantic::ConcreteFunction {
+            generic_function: callee.generic_function,
+            generic_args,
+        },
+    }))
+}
+
 /// Creates a LoweredExpr for a function call, taking into consideration external function facades:
 /// For external functions, sometimes the high level signature doesn't exactly correspond to the
 /// external function returned variables / branches.
@@ -555,7 +719,7 @@ fn lower_panic(
     let func_err_variant = get_enum_concrete_variant(
         ctx.db.upcast(),
         "PanicResult",
-        vec![GenericArgumentId::Type(ctx.signature.return_type)],
+        vec![GenericArgumentId::Type(ctx.return_type)],
         "Err",
     );
     let value_var =
@@ -879,7 +1043,12 @@ fn lower_expr_struct_ctor(
         generators::StructConstruct {
             inputs: members
                 .into_iter()
-                .map(|(_, member)| lower_expr(ctx, scope, member_expr[member.id])?.var(ctx, scope))
+                .map(|(_, member)| {
+                    // A missing member was already reported by the semantic model.
+                    let expr_id =
+                        member_expr.get(&member.id).copied().ok_or(LoweringFlowError::Failed)?;
+                    lower_expr(ctx, scope, expr_id)?.var(ctx, scope)
+                })
                 .collect::<Result<Vec<_>, _>>()?,
             ty: expr.ty,
         }
@@ -887,6 +1056,68 @@ fn lower_expr_struct_ctor(
     ))
 }
 
+/// Lowers an expression of type [semantic::ExprStringLiteral].
+/// The string is split into words of [SHORT_STRING_MAX_LEN] bytes, which are appended to the data
+/// array of the resulting `ByteArray`. The remaining bytes form its pending word.
+fn lower_expr_string_literal(
+    ctx: &mut LoweringContext<'_>,
+    expr: &semantic::ExprStringLiteral,
+    scope: &mut BlockScope,
+) -> Result<LoweredExpr, LoweringFlowError> {
+    log::trace!("Lowering a string literal: {:?}", expr.debug(&ctx.expr_formatter));
+    let semantic_db = ctx.db.upcast();
+    let felt_ty = core_felt_ty(semantic_db);
+    let generic_args = vec![GenericArgumentId::Type(felt_ty)];
+    let array_ty = get_core_ty_by_name(semantic_db, "Array".into(), generic_args.clone());
+
+    let array_new = get_core_function_id(semantic_db, "array_new".into(), generic_args.clone());
+    let mut data = generators::Call {
+        function: array_new,
+        inputs: vec![],
+        ref_tys: vec![],
+        ret_tys: vec![array_ty],
+    }
+    .add(ctx, scope)
+    .returns
+    .remove(0);
+
+    let array_append = get_core_function_id(semantic_db, "array_append".into(), generic_args);
+    let mut words = expr.value.as_bytes().chunks(SHORT_STRING_MAX_LEN).collect_vec();
+    // The last word is the pending word, unless all the words are full.
+    let pending_word = match words.last() {
+        Some(word) if word.len() < SHORT_STRING_MAX_LEN => words.pop().unwrap(),
+        _ => &[],
+    };
+    for word in words {
+        let word =
+            generators::Literal { value: BigInt::from_bytes_be(Sign::Plus, word), ty: felt_ty }
+                .add(ctx, scope);
+        data = generators::Call {
+            function: array_append,
+            inputs: vec![data, word],
+            ref_tys: vec![array_ty],
+            ret_tys: vec![],
+        }
+        .add(ctx, scope)
+        .ref_outputs
+        .remove(0);
+    }
+    let pending_word_len =
+        generators::Literal { value: pending_word.len().into(), ty: felt_ty }.add(ctx, scope);
+    let pending_word =
+        generators::Literal { value: BigInt::from_bytes_be(Sign::Plus, pending_word), ty: felt_ty }
+            .add(ctx, scope);
+
+    // The members are given in the order of their definition in `ByteArray`.
+    Ok(LoweredExpr::AtVariable(
+        generators::StructConstruct {
+            inputs: vec![data, pending_word, pending_word_len],
+            ty: expr.ty,
+        }
+        .add(ctx, scope),
+    ))
+}
+
 /// Lowers an expression of type [semantic::ExprPropagateError].
 fn lower_panic_error_propagate(
     ctx: &mut LoweringContext<'_>,
@@ -909,7 +1140,7 @@ fn lower_panic_error_propagate(
     let func_err_variant = get_enum_concrete_variant(
         ctx.db.upcast(),
         "PanicResult",
-        vec![GenericArgumentId::Type(ctx.signature.return_type)],
+        vec![GenericArgumentId::Type(ctx.return_type)],
         "Err",
     );
     lower_error_propagate(
@@ -1,26 +1,36 @@
+use std::sync::Arc;
+
+use defs::diagnostic_utils::StableLocation;
+use defs::ids::{FunctionWithBodyId, LoopFunctionId};
 use id_arena::Arena;
 use itertools::{chain, zip_eq};
 use semantic::expr::fmt::ExprFormatter;
 use semantic::items::enm::SemanticEnumEx;
 use semantic::items::imp::ImplLookupContext;
+use utils::ordered_hash_map::OrderedHashMap;
 use utils::unordered_hash_map::UnorderedHashMap;
+use utils::unordered_hash_set::UnorderedHashSet;
 
 use super::lowered_expr_from_block_result;
 use super::scope::{generators, BlockScope, BlockScopeEnd};
 use super::variables::LivingVar;
 use crate::db::LoweringGroup;
+use crate::diagnostic::LoweringDiagnosticKind::VariableNotDropped;
 use crate::diagnostic::LoweringDiagnostics;
 use crate::lower::external::{extern_facade_expr, extern_facade_return_tys};
 use crate::lower::scope::BlockFlowMerger;
-use crate::objects::{Block, Variable};
+use crate::lower::LoweredLoop;
+use crate::objects::{Block, Variable, VariableId};
 
-/// Context for the lowering phase of a free function.
+/// Context for the lowering phase of a function.
 pub struct LoweringContext<'db> {
     pub db: &'db dyn LoweringGroup,
+    /// The function being lowered. For loop functions, this is the function containing the loop.
+    pub function_id: FunctionWithBodyId,
     /// Semantic model for current function definition.
-    pub function_def: &'db semantic::FreeFunctionDefinition,
-    // Semantic signature for current function.
-    pub signature: semantic::Signature,
+    pub function_def: &'db semantic::FunctionDefinition,
+    /// The return type of the lowered function (excluding the panic wrapping).
+    pub return_type: semantic::TypeId,
     /// Whether the current function may panic.
     pub may_panic: bool,
     /// Current emitted diagnostics.
@@ -40,6 +50,42 @@ pub struct LoweringContext<'db> {
     pub lookup_context: ImplLookupContext,
     // Expression formatter of the free function.
     pub expr_formatter: ExprFormatter<'db>,
+    /// The loop currently being lowered, if any.
+    pub current_loop: Option<LoopLoweringInfo>,
+    /// The lowered loop functions encountered so far, including nested ones.
+    pub loops: OrderedHashMap<LoopFunctionId, Arc<LoweredLoop>>,
+    /// The location of the expression currently being lowered, attached to the generated
+    /// statements.
+    pub current_location: Option<StableLocation>,
+    /// The locations of the expressions (or params) that introduced the lowered variables.
+    pub variable_locations: UnorderedHashMap<VariableId, StableLocation>,
+    /// The variables that were already reported as not dropped.
+    pub not_dropped_reported: UnorderedHashSet<VariableId>,
+}
+impl<'db> LoweringContext<'db> {
+    /// Reports the variables of `drops` whose types can't be dropped. Each variable is reported
+    /// once, even if it is dropped in several parallel blocks.
+    pub fn report_not_dropped(&mut self, drops: &[VariableId]) {
+        for var_id in drops {
+            let var = &self.variables[*var_id];
+            if var.droppable || !self.not_dropped_reported.insert(*var_id) {
+                continue;
+            }
+            if let Some(location) = self.variable_locations.get(var_id) {
+                self.diagnostics.report(location.stable_ptr(), VariableNotDropped { ty: var.ty });
+            }
+        }
+    }
+}
+
+/// Information about the loop function currently being lowered, used for lowering `continue`.
+#[derive(Clone)]
+pub struct LoopLoweringInfo {
+    /// The concrete function of the loop.
+    pub function: semantic::FunctionId,
+    /// The captured variables passed to the loop function by value. The variables passed by
+    /// reference are the `ref_params` of the context.
+    pub params: Vec<semantic::VarId>,
 }
 
 /// Representation of the value of a computed expression.
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use defs::ids::{FreeFunctionId, GenericFunctionId};
+use defs::ids::{FunctionWithBodyId, GenericFunctionId};
 use itertools::Itertools;
 use semantic::TypeId;
 use utils::strongly_connected_components::{compute_scc, GraphNode};
@@ -10,7 +10,7 @@ use crate::db::{LoweringGroup, SCCRepresentative};
 /// Query implementation of [crate::db::LoweringGroup::function_scc_representative].
 pub fn function_scc_representative(
     db: &dyn LoweringGroup,
-    function: FreeFunctionId,
+    function: FunctionWithBodyId,
 ) -> SCCRepresentative {
     SCCRepresentative(db.function_scc(function).into_iter().min().unwrap_or(function))
 }
@@ -24,7 +24,7 @@ pub fn function_scc_explicit_implicits(
     let mut explicit_implicits = HashSet::new();
     for func in scc {
         let current_implicits: HashSet<TypeId> =
-            db.free_function_declaration_implicits(func)?.into_iter().collect();
+            db.function_with_body_signature(func)?.implicits.into_iter().collect();
         explicit_implicits.extend(current_implicits);
     }
     Some(explicit_implicits)
@@ -36,18 +36,31 @@ pub fn function_all_implicits(
     function: semantic::FunctionId,
 ) -> Option<Vec<TypeId>> {
     match db.lookup_intern_function(function).function.generic_function {
-        GenericFunctionId::Free(free_function) => db.free_function_all_implicits_vec(free_function),
+        GenericFunctionId::Free(free_function) => {
+            db.function_with_body_all_implicits_vec(FunctionWithBodyId::Free(free_function))
+        }
+        GenericFunctionId::ImplFunction(impl_function) => {
+            db.function_with_body_all_implicits_vec(FunctionWithBodyId::Impl(impl_function))
+        }
         GenericFunctionId::Extern(extern_function) => {
             db.extern_function_declaration_implicits(extern_function)
         }
-        GenericFunctionId::TraitFunction(_) | GenericFunctionId::ImplFunction(_) => todo!(),
+        // A loop function shares the implicits of the function it is defined in.
+        GenericFunctionId::Loop(loop_function) => {
+            db.function_with_body_all_implicits_vec(loop_function.parent(db.upcast()))
+        }
+        // A trait function is called through a trait bound, and its impl is not known. Its impls
+        // must have the declared implicits.
+        GenericFunctionId::TraitFunction(trait_function) => {
+            Some(db.trait_function_signature(trait_function)?.implicits)
+        }
     }
 }
 
-/// Query implementation of [crate::db::LoweringGroup::free_function_all_implicits].
-pub fn free_function_all_implicits(
+/// Query implementation of [crate::db::LoweringGroup::function_with_body_all_implicits].
+pub fn function_with_body_all_implicits(
     db: &dyn LoweringGroup,
-    function: FreeFunctionId,
+    function: FunctionWithBodyId,
 ) -> Option<HashSet<TypeId>> {
     // Find the SCC representative.
     let scc_representative = db.function_scc_representative(function);
@@ -56,114 +69,139 @@ pub fn free_function_all_implicits(
     let mut all_implicits = db.function_scc_explicit_implicits(scc_representative.clone())?;
 
     // For each direct callee, add its implicits.
-    for direct_callee in db.free_function_definition_direct_callees(function)? {
-        let current_implicits =
-            match db.lookup_intern_function(direct_callee).function.generic_function {
-                GenericFunctionId::Free(free_function) => {
-                    // For a free function, call this method recursively. To avoid cycles, first
-                    // check that the callee is not in this function's SCC.
-                    let direct_callee_representative =
-                        db.function_scc_representative(free_function);
-                    if direct_callee_representative == scc_representative {
-                        // We already have the implicits of this SCC - do nothing.
-                        continue;
-                    }
-                    db.free_function_all_implicits(direct_callee_representative.0)?
-                }
+    for direct_callee in db.function_with_body_direct_callees(function)? {
+        let generic_function = db.lookup_intern_function(direct_callee).function.generic_function;
+        let current_implicits = if let Some(function_with_body) =
+            generic_function.function_with_body()
+        {
+            // For a function with a body, call this method recursively. To avoid cycles, first
+            // check that the callee is not in this function's SCC.
+            let direct_callee_representative = db.function_scc_representative(function_with_body);
+            if direct_callee_representative == scc_representative {
+                // We already have the implicits of this SCC - do nothing.
+                continue;
+            }
+            db.function_with_body_all_implicits(direct_callee_representative.0)?
+        } else {
+            match generic_function {
                 GenericFunctionId::Extern(extern_function) => {
                     // All implicits of a libfunc are explicit implicits.
                     db.extern_function_declaration_implicits(extern_function)?.into_iter().collect()
                 }
-                GenericFunctionId::TraitFunction(_) | GenericFunctionId::ImplFunction(_) => todo!(),
-            };
+                // Loop functions are created during lowering, and are never direct callees.
+                GenericFunctionId::Loop(_) => unreachable!(),
+                GenericFunctionId::TraitFunction(trait_function) => {
+                    db.trait_function_signature(trait_function)?.implicits.into_iter().collect()
+                }
+                GenericFunctionId::Free(_) | GenericFunctionId::ImplFunction(_) => unreachable!(),
+            }
+        };
         all_implicits.extend(&current_implicits);
     }
     Some(all_implicits)
 }
 
-/// Query implementation of [crate::db::LoweringGroup::free_function_all_implicits_vec].
-pub fn free_function_all_implicits_vec(
+/// Query implementation of [crate::db::LoweringGroup::function_with_body_all_implicits_vec].
+pub fn function_with_body_all_implicits_vec(
     db: &dyn LoweringGroup,
-    function: FreeFunctionId,
+    function: FunctionWithBodyId,
 ) -> Option<Vec<TypeId>> {
-    let implicits_set = db.free_function_all_implicits(function)?;
+    let implicits_set = db.function_with_body_all_implicits(function)?;
     let mut implicits_vec = implicits_set.into_iter().collect_vec();
     implicits_vec.sort();
     Some(implicits_vec)
 }
 
 /// Query implementation of [crate::db::LoweringGroup::function_scc].
-pub fn function_scc(db: &dyn LoweringGroup, function_id: FreeFunctionId) -> Vec<FreeFunctionId> {
-    compute_scc::<FreeFunctionNode<'_>>(FreeFunctionNode {
-        free_function_id: function_id,
-        db: db.upcast(),
-    })
+pub fn function_scc(
+    db: &dyn LoweringGroup,
+    function_id: FunctionWithBodyId,
+) -> Vec<FunctionWithBodyId> {
+    compute_scc::<FunctionWithBodyNode<'_>>(FunctionWithBodyNode { function_id, db: db.upcast() })
 }
 
 /// A node to use in the SCC computation.
 #[derive(Clone)]
-struct FreeFunctionNode<'a> {
-    free_function_id: FreeFunctionId,
+struct FunctionWithBodyNode<'a> {
+    function_id: FunctionWithBodyId,
     db: &'a dyn LoweringGroup,
 }
-impl<'a> GraphNode for FreeFunctionNode<'a> {
-    type NodeId = FreeFunctionId;
+impl<'a> GraphNode for FunctionWithBodyNode<'a> {
+    type NodeId = FunctionWithBodyId;
 
     fn get_neighbors(&self) -> Vec<Self> {
         self.db
-            .free_function_definition_direct_free_function_callees(self.free_function_id)
+            .function_with_body_direct_function_with_body_callees(self.function_id)
             .unwrap()
             .into_iter()
-            .map(|free_function_id| FreeFunctionNode { free_function_id, db: self.db })
+            .map(|function_id| FunctionWithBodyNode { function_id, db: self.db })
             .collect()
     }
 
     fn get_id(&self) -> Self::NodeId {
-        self.free_function_id
+        self.function_id
     }
 }
 
 /// Query implementation of [crate::db::LoweringGroup::function_may_panic].
 pub fn function_may_panic(db: &dyn LoweringGroup, function: semantic::FunctionId) -> Option<bool> {
     match db.lookup_intern_function(function).function.generic_function {
-        GenericFunctionId::Free(free_function) => db.free_function_may_panic(free_function),
+        GenericFunctionId::Free(free_function) => {
+            db.function_with_body_may_panic(FunctionWithBodyId::Free(free_function))
+        }
+        GenericFunctionId::ImplFunction(impl_function) => {
+            db.function_with_body_may_panic(FunctionWithBodyId::Impl(impl_function))
+        }
         GenericFunctionId::Extern(extern_function) => {
             Some(db.extern_function_declaration_signature(extern_function)?.panicable)
         }
-        GenericFunctionId::TraitFunction(_) | GenericFunctionId::ImplFunction(_) => todo!(),
+        // Loop functions always check for gas, and may thus panic.
+        GenericFunctionId::Loop(_) => Some(true),
+        GenericFunctionId::TraitFunction(trait_function) => {
+            Some(db.trait_function_signature(trait_function)?.panicable)
+        }
     }
 }
 
-/// Query implementation of [crate::db::LoweringGroup::free_function_may_panic].
-pub fn free_function_may_panic(
+/// Query implementation of [crate::db::LoweringGroup::function_with_body_may_panic].
+pub fn function_with_body_may_panic(
     db: &dyn LoweringGroup,
-    free_function: FreeFunctionId,
+    function: FunctionWithBodyId,
 ) -> Option<bool> {
     // Find the SCC representative.
-    let scc_representative = db.function_scc_representative(free_function);
+    let scc_representative = db.function_scc_representative(function);
 
     // TODO(spapini): Add something that actually panics.
     // For each direct callee, find if it may panic.
-    for direct_callee in db.free_function_definition_direct_callees(free_function)? {
-        match db.lookup_intern_function(direct_callee).function.generic_function {
-            GenericFunctionId::Free(free_function) => {
-                // For a free function, call this method recursively. To avoid cycles, first
-                // check that the callee is not in this function's SCC.
-                let direct_callee_representative = function_scc_representative(db, free_function);
-                if direct_callee_representative == scc_representative {
-                    // We already have the implicits of this SCC - do nothing.
-                    continue;
-                }
-                if db.free_function_may_panic(direct_callee_representative.0)? {
-                    return Some(true);
-                }
+    for direct_callee in db.function_with_body_direct_callees(function)? {
+        let generic_function = db.lookup_intern_function(direct_callee).function.generic_function;
+        if let Some(function_with_body) = generic_function.function_with_body() {
+            // For a function with a body, call this method recursively. To avoid cycles, first
+            // check that the callee is not in this function's SCC.
+            let direct_callee_representative = function_scc_representative(db, function_with_body);
+            if direct_callee_representative == scc_representative {
+                // We already have the implicits of this SCC - do nothing.
+                continue;
             }
+            if db.function_with_body_may_panic(direct_callee_representative.0)? {
+                return Some(true);
+            }
+            continue;
+        }
+        match generic_function {
             GenericFunctionId::Extern(extern_function) => {
                 if db.extern_function_declaration_signature(extern_function)?.panicable {
                     return Some(true);
                 }
             }
-            GenericFunctionId::TraitFunction(_) | GenericFunctionId::ImplFunction(_) => todo!(),
+            // Loop functions are created during lowering, and are never direct callees.
+            GenericFunctionId::Loop(_) => unreachable!(),
+            GenericFunctionId::TraitFunction(trait_function) => {
+                if db.trait_function_signature(trait_function)?.panicable {
+                    return Some(true);
+                }
+            }
+            GenericFunctionId::Free(_) | GenericFunctionId::ImplFunction(_) => unreachable!(),
         };
     }
     Some(false)
@@ -0,0 +1,494 @@
+//! Lowering of loops. Each loop is lowered into a separate recursive function (a loop function),
+//! performing a single iteration of the loop and calling itself for the next one. The variables of
+//! the enclosing function used by the loop are passed to the loop function as parameters, where
+//! the variables changed by the loop are passed by reference.
+
+use std::sync::Arc;
+
+use debug::DebugWithDb;
+use defs::ids::{GenericFunctionId, LoopFunctionLongId};
+use id_arena::Arena;
+use itertools::{chain, zip_eq, Itertools};
+use num_bigint::{BigInt, Sign};
+use semantic::corelib::{
+    core_bool_enum, core_felt_ty, false_variant, get_core_function_id, get_core_ty_by_name,
+    get_panic_ty, true_variant, unit_ty,
+};
+use semantic::expr::fmt::ExprFormatter;
+use semantic::items::enm::SemanticEnumEx;
+use semantic::{ConcreteTypeId, GenericArgumentId, TypeLongId};
+use syntax::node::ids::SyntaxStablePtrId;
+use utils::extract_matches;
+use utils::ordered_hash_set::OrderedHashSet;
+use utils::unordered_hash_map::UnorderedHashMap;
+use utils::unordered_hash_set::UnorderedHashSet;
+
+use super::context::{
+    lowering_flow_error_to_block_scope_end, LoopLoweringInfo, LoweredExpr, LoweredExprExternEnum,
+    LoweringContext, LoweringFlowError,
+};
+use super::scope::{generators, BlockFlowMerger, BlockScope, BlockScopeEnd};
+use super::variables::LivingVar;
+use super::{
+    get_full_return_vars, get_plain_full_return_vars, lower_block, lower_expr, lower_panic,
+    lower_panic_error_propagate, lowered_expr_from_block_result, lowered_expr_to_block_scope_end,
+    match_extern_arm_ref_args_bind, match_extern_variant_arm_input_types, take_semantic_var,
+    use_semantic_var, Lowered, LoweredLoop,
+};
+use crate::diagnostic::LoweringDiagnostics;
+
+/// Lowers an expression of type [semantic::ExprLoop] or [semantic::ExprWhile]: lowers the loop
+/// function, and calls it.
+pub fn lower_expr_loop(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    expr_id: semantic::ExprId,
+) -> Result<LoweredExpr, LoweringFlowError> {
+    let expr = &ctx.function_def.exprs[expr_id];
+    log::trace!("Lowering a loop expression: {:?}", expr.debug(&ctx.expr_formatter));
+    let ty = expr.ty();
+    let stable_ptr = expr.stable_ptr();
+
+    // Find the variables of the enclosing scope used by the loop.
+    let mut collector = CapturedVarsCollector {
+        function_def: ctx.function_def,
+        used: Default::default(),
+        changed: Default::default(),
+    };
+    collector.visit_expr(expr_id);
+    let CapturedVarsCollector { used, changed, .. } = collector;
+    let (ref_vars, param_vars): (Vec<_>, Vec<_>) = used
+        .into_iter()
+        .filter(|semantic_var_id| ctx.semantic_defs.contains_key(semantic_var_id))
+        .partition(|semantic_var_id| changed.contains(semantic_var_id));
+
+    let loop_function_id = ctx.db.intern_loop_function(LoopFunctionLongId {
+        parent: ctx.function_id,
+        expr_index: expr_id.index(),
+        stable_ptr,
+    });
+    let function = ctx.db.intern_function(semantic::FunctionLongId {
+        function: semantic::ConcreteFunction {
+            generic_function: GenericFunctionId::Loop(loop_function_id),
+            generic_args: vec![],
+        },
+    });
+    let lowered_loop = lower_loop_function(ctx, expr_id, function, ty, &ref_vars, &param_vars);
+    ctx.loops.insert(loop_function_id, Arc::new(lowered_loop));
+
+    let res =
+        call_loop_function(ctx, scope, function, ty, &ref_vars, &param_vars, stable_ptr.untyped())?;
+    lower_panic_error_propagate(ctx, scope, LoweredExpr::AtVariable(res), ty)
+}
+
+/// Lowers a `continue` statement, by calling the current loop function for the next iteration.
+/// Returns the return variables of the current loop function.
+pub fn lower_continue(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    stable_ptr: SyntaxStablePtrId,
+) -> Result<Vec<LivingVar>, LoweringFlowError> {
+    let LoopLoweringInfo { function, params } =
+        ctx.current_loop.clone().ok_or(LoweringFlowError::Failed)?;
+    let ref_params = ctx.ref_params;
+    let res =
+        call_loop_function(ctx, scope, function, ctx.return_type, ref_params, &params, stable_ptr)?;
+    get_plain_full_return_vars(ctx, scope, vec![res])
+}
+
+/// Lowers the loop function of the loop expression `expr_id`.
+fn lower_loop_function(
+    ctx: &mut LoweringContext<'_>,
+    expr_id: semantic::ExprId,
+    function: semantic::FunctionId,
+    return_type: semantic::TypeId,
+    ref_vars: &[semantic::VarId],
+    param_vars: &[semantic::VarId],
+) -> LoweredLoop {
+    let db = ctx.db;
+    let ref_param_tys = ref_vars.iter().map(|var| ctx.semantic_defs[*var].ty()).collect_vec();
+    let param_tys = param_vars.iter().map(|var| ctx.semantic_defs[*var].ty()).collect_vec();
+    let input_var_tys =
+        chain!(ctx.implicits.iter().cloned(), ref_param_tys.clone(), param_tys.clone()).collect();
+
+    let mut loop_ctx = LoweringContext {
+        db,
+        function_id: ctx.function_id,
+        function_def: ctx.function_def,
+        return_type,
+        // Running out of gas panics.
+        may_panic: true,
+        diagnostics: LoweringDiagnostics::new(ctx.diagnostics.module_file_id),
+        variables: Arena::default(),
+        blocks: Arena::default(),
+        semantic_defs: ctx.semantic_defs.clone(),
+        ref_params: ref_vars,
+        implicits: ctx.implicits,
+        lookup_context: ctx.lookup_context.clone(),
+        expr_formatter: ExprFormatter { db: db.upcast(), function_id: ctx.function_id },
+        current_loop: Some(LoopLoweringInfo { function, params: param_vars.to_vec() }),
+        // Nested loops are collected to the same map.
+        loops: std::mem::take(&mut ctx.loops),
+        current_location: ctx.current_location.clone(),
+        variable_locations: UnorderedHashMap::default(),
+        not_dropped_reported: UnorderedHashSet::default(),
+    };
+
+    let implicits = ctx.implicits;
+    let (block_sealed_opt, mut merger_finalized) =
+        BlockFlowMerger::with_root(&mut loop_ctx, ref_vars, |ctx, merger| {
+            merger.run_in_subscope(ctx, input_var_tys, |ctx, scope, variables| {
+                let mut variables_iter = variables.into_iter();
+                for ty in implicits {
+                    let var = variables_iter.next()?;
+                    scope.put_implicit(*ty, var);
+                }
+                for (semantic_var_id, var) in zip_eq(chain!(ref_vars, param_vars), variables_iter) {
+                    scope.put_semantic_variable(*semantic_var_id, var);
+                }
+                lower_loop_function_body(ctx, scope, expr_id)
+            })
+        });
+    let root = block_sealed_opt
+        .map(|block_sealed| merger_finalized.finalize_block(&mut loop_ctx, block_sealed).block);
+
+    ctx.loops = std::mem::take(&mut loop_ctx.loops);
+    let diagnostics = loop_ctx.diagnostics.build();
+    ctx.diagnostics.diagnostics.extend(diagnostics.clone());
+    LoweredLoop {
+        lowered: Lowered {
+            diagnostics,
+            root,
+            variables: loop_ctx.variables,
+            blocks: loop_ctx.blocks,
+            loops: Default::default(),
+        },
+        ref_param_tys,
+        param_tys,
+        return_type,
+    }
+}
+
+/// Lowers the body of a loop function: a gas check, followed by a single iteration of the loop.
+fn lower_loop_function_body(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    expr_id: semantic::ExprId,
+) -> Option<BlockScopeEnd> {
+    if let Err(err) = lower_gas_check(ctx, scope) {
+        return lowering_flow_error_to_block_scope_end(err);
+    }
+    match &ctx.function_def.exprs[expr_id] {
+        semantic::Expr::Loop(expr) => lower_loop_iteration(ctx, scope, expr.body),
+        semantic::Expr::While(expr) => lower_while_iteration(ctx, scope, expr),
+        _ => unreachable!("Only loop expressions have loop functions."),
+    }
+}
+
+/// Lowers a single iteration of the loop body. Unless the flow leaves the loop, the iteration ends
+/// with a call for the next one.
+fn lower_loop_iteration(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    body: semantic::ExprId,
+) -> Option<BlockScopeEnd> {
+    let expr_block = extract_matches!(&ctx.function_def.exprs[body], semantic::Expr::Block);
+    match lower_block(ctx, scope, expr_block, false)? {
+        BlockScopeEnd::Callsite(_) => {
+            match lower_continue(ctx, scope, expr_block.stable_ptr.untyped()) {
+                Ok(return_vars) => Some(BlockScopeEnd::Return(return_vars)),
+                Err(err) => lowering_flow_error_to_block_scope_end(err),
+            }
+        }
+        end => Some(end),
+    }
+}
+
+/// Lowers a single iteration of a while loop: returns if the condition does not hold, and
+/// otherwise runs the loop body.
+fn lower_while_iteration(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    expr: &semantic::ExprWhile,
+) -> Option<BlockScopeEnd> {
+    let condition_var =
+        match lower_expr(ctx, scope, expr.condition).and_then(|cond| cond.var(ctx, scope)) {
+            Ok(var) => var,
+            Err(err) => return lowering_flow_error_to_block_scope_end(err),
+        };
+
+    let semantic_db = ctx.db.upcast();
+    let unit_ty = unit_ty(semantic_db);
+    let (res, mut finalized_merger) = BlockFlowMerger::with(ctx, scope, &[], |ctx, merger| {
+        let main_block_scope = merger.run_in_subscope(ctx, vec![unit_ty], |ctx, subscope, _| {
+            lower_loop_iteration(ctx, subscope, expr.body)
+        });
+        let exit_block_scope = merger.run_in_subscope(ctx, vec![unit_ty], |ctx, subscope, _| {
+            let return_vars =
+                get_full_return_vars(ctx, subscope, LoweredExpr::Tuple(vec![])).ok()?;
+            Some(BlockScopeEnd::Return(return_vars))
+        });
+        Some((main_block_scope?, exit_block_scope?))
+    });
+    let (main_block_sealed, exit_block_sealed) = res?;
+    let main_finalized = finalized_merger.finalize_block(ctx, main_block_sealed);
+    let exit_finalized = finalized_merger.finalize_block(ctx, exit_block_sealed);
+
+    let block_result = (generators::MatchEnum {
+        input: condition_var,
+        concrete_enum_id: core_bool_enum(semantic_db),
+        arms: vec![
+            (false_variant(semantic_db), exit_finalized.block),
+            (true_variant(semantic_db), main_finalized.block),
+        ],
+        end_info: finalized_merger.end_info.clone(),
+    })
+    .add(ctx, scope);
+    let lowered_expr = lowered_expr_from_block_result(scope, block_result, finalized_merger);
+    lowered_expr_to_block_scope_end(ctx, scope, lowered_expr, false)
+}
+
+/// Lowers a check that enough gas is left for another iteration, and panics otherwise.
+fn lower_gas_check(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+) -> Result<(), LoweringFlowError> {
+    let semantic_db = ctx.db.upcast();
+    let get_gas = get_core_function_id(semantic_db, "get_gas".into(), vec![]);
+    let implicits = ctx.db.function_all_implicits(get_gas).ok_or(LoweringFlowError::Failed)?;
+    let inputs = implicits
+        .iter()
+        .map(|ty| scope.take_implicit(*ty))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(LoweringFlowError::Failed)?;
+    let signature = ctx.db.concrete_function_signature(get_gas).ok_or(LoweringFlowError::Failed)?;
+    let concrete_enum_id = extract_matches!(
+        extract_matches!(ctx.db.lookup_intern_type(signature.return_type), TypeLongId::Concrete),
+        ConcreteTypeId::Enum
+    );
+    let [some_variant, none_variant] =
+        <[_; 2]>::try_from(ctx.db.concrete_enum_variants(concrete_enum_id).unwrap()).ok().unwrap();
+    let extern_enum = LoweredExprExternEnum {
+        function: get_gas,
+        concrete_enum_id,
+        inputs,
+        ref_args: vec![],
+        implicits,
+    };
+
+    let (blocks, mut finalized_merger) =
+        BlockFlowMerger::with(ctx, scope, &[], |ctx, merger| -> Result<_, LoweringFlowError> {
+            Ok([
+                {
+                    let input_tys =
+                        match_extern_variant_arm_input_types(ctx, some_variant.ty, &extern_enum);
+                    merger
+                        .run_in_subscope(ctx, input_tys, |_ctx, subscope, mut arm_inputs| {
+                            match_extern_arm_ref_args_bind(&mut arm_inputs, &extern_enum, subscope);
+                            Some(BlockScopeEnd::Callsite(None))
+                        })
+                        .ok_or(LoweringFlowError::Failed)?
+                },
+                {
+                    let input_tys =
+                        match_extern_variant_arm_input_types(ctx, none_variant.ty, &extern_enum);
+                    merger
+                        .run_in_subscope(ctx, input_tys, |ctx, subscope, mut arm_inputs| {
+                            match_extern_arm_ref_args_bind(&mut arm_inputs, &extern_enum, subscope);
+                            lowering_flow_error_to_block_scope_end(lower_out_of_gas_panic(
+                                ctx, subscope,
+                            ))
+                        })
+                        .ok_or(LoweringFlowError::Failed)?
+                },
+            ])
+        });
+    let finalized_blocks =
+        blocks?.map(|sealed| finalized_merger.finalize_block(ctx, sealed).block).to_vec();
+    let arms = zip_eq(vec![some_variant, none_variant], finalized_blocks).collect();
+
+    let block_result = generators::MatchExtern {
+        function: extern_enum.function,
+        inputs: extern_enum.inputs,
+        arms,
+        end_info: finalized_merger.end_info.clone(),
+    }
+    .add(ctx, scope);
+    lowered_expr_from_block_result(scope, block_result, finalized_merger)?;
+    Ok(())
+}
+
+/// Lowers a panic with the 'Out of gas' error code.
+fn lower_out_of_gas_panic(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+) -> LoweringFlowError {
+    let semantic_db = ctx.db.upcast();
+    let felt_ty = core_felt_ty(semantic_db);
+    let generic_args = vec![GenericArgumentId::Type(felt_ty)];
+    let array_ty = get_core_ty_by_name(semantic_db, "Array".into(), generic_args.clone());
+
+    let array_new = get_core_function_id(semantic_db, "array_new".into(), generic_args.clone());
+    let data = generators::Call {
+        function: array_new,
+        inputs: vec![],
+        ref_tys: vec![],
+        ret_tys: vec![array_ty],
+    }
+    .add(ctx, scope)
+    .returns
+    .remove(0);
+    let err_code = generators::Literal {
+        value: BigInt::from_bytes_be(Sign::Plus, b"Out of gas"),
+        ty: felt_ty,
+    }
+    .add(ctx, scope);
+    let array_append = get_core_function_id(semantic_db, "array_append".into(), generic_args);
+    let data = generators::Call {
+        function: array_append,
+        inputs: vec![data, err_code],
+        ref_tys: vec![array_ty],
+        ret_tys: vec![],
+    }
+    .add(ctx, scope)
+    .ref_outputs
+    .remove(0);
+    match lower_panic(ctx, scope, data) {
+        Ok(_) => unreachable!("Panic never returns."),
+        Err(err) => err,
+    }
+}
+
+/// Calls a loop function with the given captured variables, and rebinds the variables passed by
+/// reference. Returns the `PanicResult` returned by the loop function.
+fn call_loop_function(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    function: semantic::FunctionId,
+    ty: semantic::TypeId,
+    ref_vars: &[semantic::VarId],
+    param_vars: &[semantic::VarId],
+    stable_ptr: SyntaxStablePtrId,
+) -> Result<LivingVar, LoweringFlowError> {
+    let implicits = ctx
+        .implicits
+        .iter()
+        .map(|ty| scope.take_implicit(*ty))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(LoweringFlowError::Failed)?;
+    let ref_inputs = ref_vars
+        .iter()
+        .map(|semantic_var_id| take_semantic_var(ctx, scope, *semantic_var_id, stable_ptr))
+        .collect::<Result<Vec<_>, _>>()?;
+    let param_inputs = param_vars
+        .iter()
+        .map(|semantic_var_id| use_semantic_var(ctx, scope, *semantic_var_id, stable_ptr))
+        .collect::<Result<Vec<_>, _>>()?;
+    let ref_tys =
+        ref_vars.iter().map(|semantic_var_id| ctx.semantic_defs[*semantic_var_id].ty()).collect();
+
+    let call_result = generators::Call {
+        function,
+        inputs: chain!(implicits, ref_inputs, param_inputs).collect(),
+        ref_tys,
+        ret_tys: vec![get_panic_ty(ctx.db.upcast(), ty)],
+    }
+    .add(ctx, scope);
+
+    // Rebind the implicits.
+    for (implicit_type, implicit_output) in zip_eq(ctx.implicits, call_result.implicit_outputs) {
+        scope.put_implicit(*implicit_type, implicit_output);
+    }
+    // Rebind the ref variables.
+    for (semantic_var_id, output_var) in zip_eq(ref_vars, call_result.ref_outputs) {
+        scope.put_semantic_variable(*semantic_var_id, output_var);
+    }
+    Ok(call_result.returns.into_iter().next().unwrap())
+}
+
+/// Collects the semantic variables used by an expression, in order of appearance, and the
+/// variables it changes.
+struct CapturedVarsCollector<'a> {
+    function_def: &'a semantic::FunctionDefinition,
+    used: OrderedHashSet<semantic::VarId>,
+    changed: OrderedHashSet<semantic::VarId>,
+}
+impl<'a> CapturedVarsCollector<'a> {
+    fn visit_expr(&mut self, expr_id: semantic::ExprId) {
+        match &self.function_def.exprs[expr_id] {
+            semantic::Expr::Tuple(expr) => self.visit_exprs(&expr.items),
+            semantic::Expr::Assignment(expr) => {
+                self.visit_expr(expr.rhs);
+                self.used.insert(expr.var);
+                self.changed.insert(expr.var);
+            }
+            semantic::Expr::Block(expr) => {
+                for stmt_id in &expr.statements {
+                    self.visit_statement(*stmt_id);
+                }
+                if let Some(tail) = expr.tail {
+                    self.visit_expr(tail);
+                }
+            }
+            semantic::Expr::FunctionCall(expr) => {
+                for semantic_var_id in &expr.ref_args {
+                    self.used.insert(*semantic_var_id);
+                    self.changed.insert(*semantic_var_id);
+                }
+                self.visit_exprs(&expr.args);
+            }
+            semantic::Expr::Match(expr) => {
+                self.visit_expr(expr.matched_expr);
+                for arm in &expr.arms {
+                    self.visit_expr(arm.expression);
+                }
+            }
+            semantic::Expr::If(expr) => {
+                self.visit_expr(expr.condition);
+                self.visit_expr(expr.if_block);
+                if let Some(else_block) = expr.else_block {
+                    self.visit_expr(else_block);
+                }
+            }
+            semantic::Expr::Loop(expr) => self.visit_expr(expr.body),
+            semantic::Expr::While(expr) => {
+                self.visit_expr(expr.condition);
+                self.visit_expr(expr.body);
+            }
+            semantic::Expr::Var(expr) => {
+                self.used.insert(expr.var);
+            }
+            semantic::Expr::MemberAccess(expr) => self.visit_expr(expr.expr),
+            semantic::Expr::StructCtor(expr) => {
+                for (_, member_expr) in &expr.members {
+                    self.visit_expr(*member_expr);
+                }
+            }
+            semantic::Expr::EnumVariantCtor(expr) => self.visit_expr(expr.value_expr),
+            semantic::Expr::PropagateError(expr) => self.visit_expr(expr.inner),
+            semantic::Expr::Literal(_)
+            | semantic::Expr::StringLiteral(_)
+            | semantic::Expr::Missing(_) => {}
+        }
+    }
+
+    fn visit_exprs(&mut self, expr_ids: &[semantic::ExprId]) {
+        for expr_id in expr_ids {
+            self.visit_expr(*expr_id);
+        }
+    }
+
+    fn visit_statement(&mut self, stmt_id: semantic::StatementId) {
+        match &self.function_def.statements[stmt_id] {
+            semantic::Statement::Expr(stmt) => self.visit_expr(stmt.expr),
+            semantic::Statement::Let(stmt) => self.visit_expr(stmt.expr),
+            semantic::Statement::Return(stmt) => self.visit_expr(stmt.expr),
+            semantic::Statement::Break(stmt) => {
+                if let Some(expr) = stmt.expr {
+                    self.visit_expr(expr);
+                }
+            }
+            semantic::Statement::Continue(_) => {}
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-use itertools::chain;
+use itertools::{chain, Itertools};
 use utils::ordered_hash_map::OrderedHashMap;
 use utils::{borrow_as_box, try_extract_matches};
 
@@ -148,7 +148,8 @@ impl BlockScope {
 
     /// Pull the living implicit variables into the given merger.
     fn pull_implicits(&mut self, merger: &mut BlockFlowMerger) {
-        for (ty, var) in self.implicits.drain() {
+        // Pull in a consistent order, to keep the order of the remaining living variables stable.
+        for (ty, var) in self.implicits.drain().sorted_by_key(|(ty, _)| *ty) {
             let usable_var = self.living_variables.take_var(var);
             let living_var = merger.splitter.add(usable_var);
             merger.implicit_pulls.insert(ty, living_var);
@@ -273,6 +274,9 @@ impl BlockSealed {
                 let outputs = chain!(implicit_pushes, pushes, maybe_output.into_iter()).collect();
 
                 let drops = living_variables.get_all();
+                // TODO(spapini): Check the drops of returning blocks too, once they don't include
+                // the variables of the outer scopes that are consumed after the block.
+                ctx.report_not_dropped(&drops);
                 (
                     BlockEnd::Callsite(outputs),
                     BlockEndInfo::Callsite { maybe_output_ty, push_tys },
@@ -77,6 +77,7 @@ impl Call {
             function: self.function,
             inputs,
             outputs,
+            location: ctx.current_location.clone(),
         }));
 
         CallResult { returns, ref_outputs, implicit_outputs }
@@ -167,6 +168,7 @@ impl MatchExtern {
             inputs,
             arms: self.arms,
             outputs,
+            location: ctx.current_location.clone(),
         }));
         res
     }
@@ -215,6 +217,7 @@ impl EnumConstruct {
             variant: self.variant,
             input,
             output: output.var_id(),
+            location: ctx.current_location.clone(),
         }));
         output
     }
@@ -63,6 +63,9 @@ impl LivingVariables {
             droppable: ty_info.droppable,
             ty,
         });
+        if let Some(location) = ctx.current_location.clone() {
+            ctx.variable_locations.insert(var_id, location);
+        }
         self.introduce_var(UsableVariable(var_id))
     }
 
@@ -3,6 +3,7 @@
 //! assigned once. It is also normal form: each function argument is a variable, rather than a
 //! compound expression.
 
+use defs::diagnostic_utils::StableLocation;
 use id_arena::Id;
 use num_bigint::BigInt;
 use semantic::{ConcreteEnumId, ConcreteVariant};
@@ -60,7 +61,6 @@ pub struct Variable {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Statement {
     // Values.
-    // TODO(spapini): Consts.
     Literal(StatementLiteral),
 
     // Flow control.
@@ -101,6 +101,19 @@ impl Statement {
             Statement::MatchEnum(stmt) => stmt.outputs.clone(),
         }
     }
+    /// Returns the location of the statement in the source code, if known.
+    pub fn location(&self) -> Option<&StableLocation> {
+        match &self {
+            Statement::Call(stmt) => stmt.location.as_ref(),
+            Statement::MatchExtern(stmt) => stmt.location.as_ref(),
+            Statement::EnumConstruct(stmt) => stmt.location.as_ref(),
+            Statement::Literal(_)
+            | Statement::CallBlock(_)
+            | Statement::StructConstruct(_)
+            | Statement::StructDestructure(_)
+            | Statement::MatchEnum(_) => None,
+        }
+    }
 }
 
 /// A statement that binds a literal value to a variable.
@@ -121,6 +134,8 @@ pub struct StatementCall {
     pub inputs: Vec<VariableId>,
     /// New variables to be introduced into the current scope from the function outputs.
     pub outputs: Vec<VariableId>,
+    /// The location of the call in the source code, if known.
+    pub location: Option<StableLocation>,
 }
 
 /// A statement that jumps to another block. If that block ends with a BlockEnd::CallSite, the flow
@@ -147,6 +162,8 @@ pub struct StatementMatchExtern {
     pub arms: Vec<(ConcreteVariant, BlockId)>,
     /// New variables to be introduced into the current scope from the arm outputs.
     pub outputs: Vec<VariableId>,
+    /// The location of the call in the source code, if known.
+    pub location: Option<StableLocation>,
 }
 
 /// A statement that construct a variant of an enum with a single argument, and binds it to a
@@ -158,6 +175,8 @@ pub struct StatementEnumConstruct {
     pub input: VariableId,
     /// The variable to bind the value to.
     pub output: VariableId,
+    /// The location of the expression constructing the variant in the source code, if known.
+    pub location: Option<StableLocation>,
 }
 
 /// A statement that matches an enum, and "calls" a possibly different block for each branch.
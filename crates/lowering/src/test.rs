@@ -1,4 +1,6 @@
 use debug::DebugWithDb;
+use defs::ids::TopLevelLanguageElementId;
+use itertools::Itertools;
 use semantic::test_utils::setup_test_function;
 use utils::ordered_hash_map::OrderedHashMap;
 
@@ -14,10 +16,13 @@ test_utils::test_file_test!(
         "src/test_data/enums",
         "src/test_data/error_propagate",
         "src/test_data/extern",
+        "src/test_data/generics",
         "src/test_data/arm_pattern_destructure",
         "src/test_data/if",
         "src/test_data/match",
+        "src/test_data/operators",
         "src/test_data/panic",
+        "src/test_data/string",
         "src/test_data/struct",
         "src/test_data/tests",
         "src/test_data/tuple",
@@ -26,6 +31,13 @@ test_utils::test_file_test!(
     test_function_lowering
 );
 
+test_utils::test_file_test!(
+    loop_lowering_test,
+    ["src/test_data/loop",],
+    LoweringDatabaseForTesting,
+    test_loop_lowering
+);
+
 fn test_function_lowering(
     db: &mut LoweringDatabaseForTesting,
     inputs: &OrderedHashMap<String, String>,
@@ -46,3 +58,37 @@ fn test_function_lowering(
         ("lowering_format".into(), format!("{:?}", lowered.debug(&lowered_formatter))),
     ])
 }
+
+fn test_loop_lowering(
+    db: &mut LoweringDatabaseForTesting,
+    inputs: &OrderedHashMap<String, String>,
+) -> OrderedHashMap<String, String> {
+    let (test_function, semantic_diagnostics) = setup_test_function(
+        db,
+        inputs["function"].as_str(),
+        inputs["function_name"].as_str(),
+        inputs["module_code"].as_str(),
+    )
+    .split();
+    let lowered = lower(db, test_function.function_id).unwrap();
+
+    let lowered_formatter = LoweredFormatter { db, lowered: &lowered };
+    let loops_format = lowered
+        .loops
+        .iter()
+        .map(|(loop_function_id, lowered_loop)| {
+            let loop_formatter = LoweredFormatter { db, lowered: &lowered_loop.lowered };
+            format!(
+                "{}:\n{:?}",
+                loop_function_id.full_path(db),
+                lowered_loop.lowered.debug(&loop_formatter)
+            )
+        })
+        .join("\n");
+    OrderedHashMap::from([
+        ("semantic_diagnostics".into(), semantic_diagnostics),
+        ("lowering_diagnostics".into(), lowered.diagnostics.format(db)),
+        ("lowering_format".into(), format!("{:?}", lowered.debug(&lowered_formatter))),
+        ("loops_lowering_format".into(), loops_format),
+    ])
+}
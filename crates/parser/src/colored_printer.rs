@@ -64,9 +64,11 @@ fn set_color(text: SmolStr, kind: SyntaxKind) -> ColoredString {
         | SyntaxKind::TokenDiv
         | SyntaxKind::TokenMod
         | SyntaxKind::TokenDot => text.bright_magenta(),
-        SyntaxKind::TokenLiteralNumber | SyntaxKind::TokenFalse | SyntaxKind::TokenTrue => {
-            text.bright_cyan()
-        }
+        SyntaxKind::TokenLiteralNumber
+        | SyntaxKind::TokenShortString
+        | SyntaxKind::TokenString
+        | SyntaxKind::TokenFalse
+        | SyntaxKind::TokenTrue => text.bright_cyan(),
         SyntaxKind::TokenExtern
         | SyntaxKind::TokenType
         | SyntaxKind::TokenFunction
@@ -77,7 +79,12 @@ fn set_color(text: SmolStr, kind: SyntaxKind) -> ColoredString {
         | SyntaxKind::TokenReturn
         | SyntaxKind::TokenMatch
         | SyntaxKind::TokenIf
-        | SyntaxKind::TokenElse => text.bright_blue(),
+        | SyntaxKind::TokenElse
+        | SyntaxKind::TokenLoop
+        | SyntaxKind::TokenWhile
+        | SyntaxKind::TokenBreak
+        | SyntaxKind::TokenContinue
+        | SyntaxKind::TokenConst => text.bright_blue(),
         SyntaxKind::TokenArrow
         | SyntaxKind::TokenMatchArrow
         | SyntaxKind::TokenColon
@@ -11,6 +11,7 @@ pub struct ParserDiagnostic {
     pub span: TextSpan,
     pub kind: ParserDiagnosticKind,
 }
+/// The kind of a parser diagnostic. Each kind has a stable code, see [ParserDiagnosticKind::code].
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum ParserDiagnosticKind {
     // TODO(spapini): Add tokens from the recovery set to the message.
@@ -23,6 +24,21 @@ pub enum ParserDiagnosticKind {
     ReservedIdentifier { identifier: SmolStr },
     UnderscoreNotAllowedAsIdentifier,
 }
+impl ParserDiagnosticKind {
+    /// Returns the stable code of the kind. Codes of removed kinds must not be reused.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ParserDiagnosticKind::SkippedElement { .. } => "P0001",
+            ParserDiagnosticKind::MissingToken(_) => "P0002",
+            ParserDiagnosticKind::MissingExpression => "P0003",
+            ParserDiagnosticKind::MissingPathSegment => "P0004",
+            ParserDiagnosticKind::MissingTypeClause => "P0005",
+            ParserDiagnosticKind::MissingTypeExpression => "P0006",
+            ParserDiagnosticKind::ReservedIdentifier { .. } => "P0007",
+            ParserDiagnosticKind::UnderscoreNotAllowedAsIdentifier => "P0008",
+        }
+    }
+}
 impl DiagnosticEntry for ParserDiagnostic {
     type DbType = dyn FilesGroup;
 
@@ -58,4 +74,8 @@ impl DiagnosticEntry for ParserDiagnostic {
     fn location(&self, _db: &dyn FilesGroup) -> diagnostics::DiagnosticLocation {
         diagnostics::DiagnosticLocation { file_id: self.file_id, span: self.span }
     }
+
+    fn code(&self) -> &'static str {
+        self.kind.code()
+    }
 }
@@ -45,7 +45,7 @@ impl<'a> Lexer<'a> {
 
     fn take(&mut self) -> Option<char> {
         let res = self.peek()?;
-        self.current_position.inc();
+        self.current_position = self.current_position.add(res.len_utf8());
         Some(res)
     }
 
@@ -129,6 +129,32 @@ impl<'a> Lexer<'a> {
         TokenKind::LiteralNumber
     }
 
+    /// Takes a quoted literal: a short string (e.g. 'hello') or a string (e.g. "hello").
+    /// Escaped characters (e.g. '\'') are taken as is, and are interpreted by the semantic model.
+    /// If the literal is not terminated on the same line, returns [TokenKind::BadCharacters].
+    /// Assumes the next character is `quote`.
+    fn take_token_quoted(&mut self, quote: char, kind: TokenKind) -> TokenKind {
+        self.take();
+        loop {
+            match self.peek() {
+                Some(c) if c == quote => {
+                    self.take();
+                    return kind;
+                }
+                Some('\\') => {
+                    self.take();
+                    if self.peek() != Some('\n') {
+                        self.take();
+                    }
+                }
+                Some('\n') | None => return TokenKind::BadCharacters,
+                Some(_) => {
+                    self.take();
+                }
+            }
+        }
+    }
+
     /// Assumes the next character is [a-zA-Z_].
     fn take_token_identifier(&mut self) -> TokenKind {
         // TODO(spapini): Support or explicitly report general unicode characters.
@@ -151,6 +177,11 @@ impl<'a> Lexer<'a> {
             "match" => TokenKind::Match,
             "if" => TokenKind::If,
             "else" => TokenKind::Else,
+            "loop" => TokenKind::Loop,
+            "while" => TokenKind::While,
+            "break" => TokenKind::Break,
+            "continue" => TokenKind::Continue,
+            "const" => TokenKind::Const,
             "use" => TokenKind::Use,
             "implicits" => TokenKind::Implicits,
             "ref" => TokenKind::Ref,
@@ -189,6 +220,8 @@ impl<'a> Lexer<'a> {
         let kind = if let Some(current) = self.peek() {
             match current {
                 '0'..='9' => self.take_token_literal_number(),
+                '\'' => self.take_token_quoted('\'', TokenKind::ShortString),
+                '"' => self.take_token_quoted('"', TokenKind::String),
                 ',' => self.take_token_of_kind(TokenKind::Comma),
                 ';' => self.take_token_of_kind(TokenKind::Semicolon),
                 '?' => self.take_token_of_kind(TokenKind::QuestionMark),
@@ -275,6 +308,8 @@ enum TokenKind {
 
     // Literals.
     LiteralNumber,
+    ShortString,
+    String,
 
     // Keywords.
     False,
@@ -293,6 +328,11 @@ enum TokenKind {
     Match,
     If,
     Else,
+    Loop,
+    While,
+    Break,
+    Continue,
+    Const,
     Use,
     Implicits,
     NoPanic,
@@ -347,6 +387,8 @@ fn token_kind_to_terminal_syntax_kind(kind: TokenKind) -> SyntaxKind {
     match kind {
         TokenKind::Identifier => SyntaxKind::TerminalIdentifier,
         TokenKind::LiteralNumber => SyntaxKind::TerminalLiteralNumber,
+        TokenKind::ShortString => SyntaxKind::TerminalShortString,
+        TokenKind::String => SyntaxKind::TerminalString,
         TokenKind::False => SyntaxKind::TerminalFalse,
         TokenKind::True => SyntaxKind::TerminalTrue,
         TokenKind::Extern => SyntaxKind::TerminalExtern,
@@ -363,6 +405,11 @@ fn token_kind_to_terminal_syntax_kind(kind: TokenKind) -> SyntaxKind {
         TokenKind::Match => SyntaxKind::TerminalMatch,
         TokenKind::If => SyntaxKind::TerminalIf,
         TokenKind::Else => SyntaxKind::TerminalElse,
+        TokenKind::Loop => SyntaxKind::TerminalLoop,
+        TokenKind::While => SyntaxKind::TerminalWhile,
+        TokenKind::Break => SyntaxKind::TerminalBreak,
+        TokenKind::Continue => SyntaxKind::TerminalContinue,
+        TokenKind::Const => SyntaxKind::TerminalConst,
         TokenKind::Use => SyntaxKind::TerminalUse,
         TokenKind::Implicits => SyntaxKind::TerminalImplicits,
         TokenKind::NoPanic => SyntaxKind::TerminalNoPanic,
@@ -25,6 +25,8 @@ fn terminal_kind_to_text(kind: SyntaxKind) -> Vec<&'static str> {
                 "0xA2_u128",
             ]
         }
+        SyntaxKind::TerminalShortString => vec!["'hello'", "''", "'it\\'s'", "'\\\\'"],
+        SyntaxKind::TerminalString => vec!["\"hello\"", "\"\"", "\"say \\\"hi\\\"\"", "\"'\""],
         SyntaxKind::TerminalFalse => vec!["false"],
         SyntaxKind::TerminalExtern => vec!["extern"],
         SyntaxKind::TerminalType => vec!["type"],
@@ -44,6 +46,11 @@ fn terminal_kind_to_text(kind: SyntaxKind) -> Vec<&'static str> {
         SyntaxKind::TerminalMatch => vec!["match"],
         SyntaxKind::TerminalIf => vec!["if"],
         SyntaxKind::TerminalElse => vec!["else"],
+        SyntaxKind::TerminalLoop => vec!["loop"],
+        SyntaxKind::TerminalWhile => vec!["while"],
+        SyntaxKind::TerminalBreak => vec!["break"],
+        SyntaxKind::TerminalContinue => vec!["continue"],
+        SyntaxKind::TerminalConst => vec!["const"],
         SyntaxKind::TerminalUse => vec!["use"],
         SyntaxKind::TerminalAnd => vec!["&"],
         SyntaxKind::TerminalAndAnd => vec!["&&"],
@@ -90,6 +97,8 @@ fn terminal_kinds() -> Vec<SyntaxKind> {
     vec![
         SyntaxKind::TerminalIdentifier,
         SyntaxKind::TerminalLiteralNumber,
+        SyntaxKind::TerminalShortString,
+        SyntaxKind::TerminalString,
         SyntaxKind::TerminalFalse,
         SyntaxKind::TerminalTrue,
         SyntaxKind::TerminalExtern,
@@ -109,6 +118,11 @@ fn terminal_kinds() -> Vec<SyntaxKind> {
         SyntaxKind::TerminalMatch,
         SyntaxKind::TerminalIf,
         SyntaxKind::TerminalElse,
+        SyntaxKind::TerminalLoop,
+        SyntaxKind::TerminalWhile,
+        SyntaxKind::TerminalBreak,
+        SyntaxKind::TerminalContinue,
+        SyntaxKind::TerminalConst,
         SyntaxKind::TerminalUse,
         SyntaxKind::TerminalAnd,
         SyntaxKind::TerminalAndAnd,
@@ -422,3 +436,29 @@ fn test_bad_character() {
     );
     assert!(lexer.next().is_none(), "Expected end of lexer stream.");
 }
+
+#[test]
+fn test_unterminated_string() {
+    let db_val = SimpleParserDatabase::default();
+    let db = &db_val;
+
+    for text in ["'abc", "\"abc", "'abc\\'", "\"abc\\\""] {
+        let mut lexer = Lexer::from_text(db, test_source(), text);
+        let terminal = lexer.next().unwrap();
+        assert_eq!(
+            terminal.kind,
+            SyntaxKind::TerminalBadCharacters,
+            "Wrong token kind, with text: \"{}\".",
+            text
+        );
+        assert_eq!(terminal.text, text, "Wrong token text.");
+
+        assert_eq!(
+            lexer.next().unwrap().kind,
+            SyntaxKind::TerminalEndOfFile,
+            "Wrong eof token, with text: \"{}\".",
+            text
+        );
+        assert!(lexer.next().is_none(), "Expected end of lexer stream.");
+    }
+}
@@ -135,6 +135,7 @@ impl<'a> Parser<'a> {
             SyntaxKind::TerminalExtern => Some(self.expect_extern_item(attributes)),
             SyntaxKind::TerminalFunction => Some(self.expect_free_function(attributes).into()),
             SyntaxKind::TerminalUse => Some(self.expect_use(attributes).into()),
+            SyntaxKind::TerminalConst => Some(self.expect_const(attributes).into()),
             SyntaxKind::TerminalTrait => Some(self.expect_trait(attributes).into()),
             SyntaxKind::TerminalImpl => Some(self.expect_impl(attributes).into()),
             _ => None,
@@ -270,6 +271,27 @@ impl<'a> Parser<'a> {
         ItemUse::new_green(self.db, attributes, use_kw, path, semicolon)
     }
 
+    /// Assumes the current token is Const.
+    /// Expected pattern: `const<Identifier>:<Type>=<Expr>;`
+    fn expect_const(&mut self, attributes: AttributeListGreen) -> ItemConstantGreen {
+        let const_kw = self.take::<TerminalConst>();
+        let name = self.parse_identifier();
+        let type_clause = self.parse_type_clause();
+        let eq = self.parse_token::<TerminalEq>();
+        let value = self.parse_expr();
+        let semicolon = self.parse_token::<TerminalSemicolon>();
+        ItemConstant::new_green(
+            self.db,
+            attributes,
+            const_kw,
+            name,
+            type_clause,
+            eq,
+            value,
+            semicolon,
+        )
+    }
+
     /// Returns a GreenId of a node with an identifier kind or None if an identifier can't be
     /// parsed.
     /// Note that if the terminal is a keyword or an underscore, it is skipped, and
@@ -307,23 +329,63 @@ impl<'a> Parser<'a> {
     }
 
     /// Parses the arguments of an attributes if exists.
-    /// Expected pattern: `\(<ExprList>\)`
+    /// Expected pattern: `\(<AttributeArgList>\)`
     fn try_attribute_arg_list_parenthesized(&mut self) -> OptionAttributeArgsGreen {
         if self.peek().kind != SyntaxKind::TerminalLParen {
             return OptionAttributeArgsEmpty::new_green(self.db).into();
         }
         let lparen = self.take::<TerminalLParen>();
-        let args = self
-            .parse_separated_list::<Expr, TerminalComma, AttributeArgListElementOrSeparatorGreen>(
-                Self::try_parse_expr,
-                is_of_kind!(rparen, block, rbrace, top_level),
-                "expression",
-            );
+        let args = self.parse_separated_list::<
+            AttributeArg,
+            TerminalComma,
+            AttributeArgListElementOrSeparatorGreen,
+        >(
+            Self::try_parse_attribute_arg,
+            is_of_kind!(rparen, block, rbrace, top_level),
+            "expression",
+        );
         let arg_list = AttributeArgList::new_green(self.db, args);
         let rparen = self.parse_token::<TerminalRParen>();
         AttributeArgs::new_green(self.db, lparen, arg_list, rparen).into()
     }
 
+    /// Returns a GreenId of a node with an AttributeArg kind or None if an attribute argument can't
+    /// be parsed.
+    /// Expected pattern: `<Expr>` or `<Identifier>: <Expr>`.
+    fn try_parse_attribute_arg(&mut self) -> Option<AttributeArgGreen> {
+        let expr = self.try_parse_expr()?;
+        if self.peek().kind == SyntaxKind::TerminalColon {
+            if let Some(name) = self.try_extract_identifier(expr) {
+                let colon = self.take::<TerminalColon>();
+                let name = AttributeArgName::new_green(self.db, name, colon).into();
+                let value = self.parse_expr();
+                return Some(AttributeArg::new_green(self.db, name, value));
+            }
+        }
+        let name = OptionAttributeArgNameEmpty::new_green(self.db).into();
+        Some(AttributeArg::new_green(self.db, name, expr))
+    }
+
+    /// Returns the identifier of an expression that is a path with a single simple segment (e.g.
+    /// `a`), or None if the expression is not such a path.
+    fn try_extract_identifier(&self, expr: ExprGreen) -> Option<TerminalIdentifierGreen> {
+        let path = self.db.lookup_intern_green(expr.0);
+        if path.kind != SyntaxKind::ExprPath {
+            return None;
+        }
+        let [segment] = path.children()[..] else {
+            return None;
+        };
+        let segment = self.db.lookup_intern_green(segment);
+        if segment.kind != SyntaxKind::PathSegmentSimple {
+            return None;
+        }
+        let [ident] = segment.children()[..] else {
+            return None;
+        };
+        Some(TerminalIdentifierGreen(ident))
+    }
+
     /// Returns a GreenId of a node with an attribute kind or None if an attribute can't be parsed.
     fn try_parse_attribute(&mut self) -> Option<AttributeGreen> {
         match self.peek().kind {
@@ -343,8 +405,8 @@ impl<'a> Parser<'a> {
 
     /// Parses an attribute list.
     fn parse_attribute_list(&mut self) -> AttributeListGreen {
-        let expected_elements =
-            "Module/Use/FreeFunction/ExternFunction/ExternType/Trait/Impl/Struct/Enum/Attribute";
+        let expected_elements = "Module/Use/Const/FreeFunction/ExternFunction/ExternType/Trait/\
+                                 Impl/Struct/Enum/Attribute";
         AttributeList::new_green(
             self.db,
             self.parse_list(
@@ -583,6 +645,8 @@ impl<'a> Parser<'a> {
             SyntaxKind::TerminalFalse => Some(self.take::<TerminalFalse>().into()),
             SyntaxKind::TerminalTrue => Some(self.take::<TerminalTrue>().into()),
             SyntaxKind::TerminalLiteralNumber => Some(self.take::<TerminalLiteralNumber>().into()),
+            SyntaxKind::TerminalShortString => Some(self.take::<TerminalShortString>().into()),
+            SyntaxKind::TerminalString => Some(self.take::<TerminalString>().into()),
             SyntaxKind::TerminalLParen => {
                 // Note that LBrace is allowed inside parenthesis, even if `lbrace_allowed` is
                 // [LbraceAllowed::Forbid].
@@ -597,6 +661,12 @@ impl<'a> Parser<'a> {
             SyntaxKind::TerminalIf if lbrace_allowed == LbraceAllowed::Allow => {
                 Some(self.expect_if_expr().into())
             }
+            SyntaxKind::TerminalLoop if lbrace_allowed == LbraceAllowed::Allow => {
+                Some(self.expect_loop_expr().into())
+            }
+            SyntaxKind::TerminalWhile if lbrace_allowed == LbraceAllowed::Allow => {
+                Some(self.expect_while_expr().into())
+            }
             _ => {
                 // TODO(yuval): report to diagnostics.
                 None
@@ -779,6 +849,23 @@ impl<'a> Parser<'a> {
         ExprIf::new_green(self.db, if_kw, condition, if_block, else_clause)
     }
 
+    /// Assumes the current token is `Loop`.
+    /// Expected pattern: `loop <block>`.
+    fn expect_loop_expr(&mut self) -> ExprLoopGreen {
+        let loop_kw = self.take::<TerminalLoop>();
+        let body = self.parse_block();
+        ExprLoop::new_green(self.db, loop_kw, body)
+    }
+
+    /// Assumes the current token is `While`.
+    /// Expected pattern: `while <expr> <block>`.
+    fn expect_while_expr(&mut self) -> ExprWhileGreen {
+        let while_kw = self.take::<TerminalWhile>();
+        let condition = self.parse_expr_limited(MAX_PRECEDENCE, LbraceAllowed::Forbid);
+        let body = self.parse_block();
+        ExprWhile::new_green(self.db, while_kw, condition, body)
+    }
+
     /// Returns a GreenId of a node with a MatchArm kind or None if a match arm can't be parsed.
     pub fn try_parse_match_arm(&mut self) -> Option<MatchArmGreen> {
         let pattern = self.try_parse_pattern()?;
@@ -908,6 +995,20 @@ impl<'a> Parser<'a> {
                 let semicolon = self.parse_token::<TerminalSemicolon>();
                 Some(StatementReturn::new_green(self.db, return_kw, expr, semicolon).into())
             }
+            SyntaxKind::TerminalBreak => {
+                let break_kw = self.take::<TerminalBreak>();
+                let expr_clause: OptionExprClauseGreen = match self.try_parse_expr() {
+                    Some(expr) => ExprClause::new_green(self.db, expr).into(),
+                    None => OptionExprClauseEmpty::new_green(self.db).into(),
+                };
+                let semicolon = self.parse_token::<TerminalSemicolon>();
+                Some(StatementBreak::new_green(self.db, break_kw, expr_clause, semicolon).into())
+            }
+            SyntaxKind::TerminalContinue => {
+                let continue_kw = self.take::<TerminalContinue>();
+                let semicolon = self.parse_token::<TerminalSemicolon>();
+                Some(StatementContinue::new_green(self.db, continue_kw, semicolon).into())
+            }
             _ => match self.try_parse_expr() {
                 None => None,
                 Some(expr) => {
@@ -1168,8 +1269,32 @@ impl<'a> Parser<'a> {
         self.expect_generic_params().into()
     }
 
+    /// Returns a GreenId of a node with kind GenericParam or None if a generic param can't be
+    /// parsed.
+    /// Expected pattern: `<Identifier>(: <ExprPath>(+ <ExprPath>)*)?`
     fn try_parse_generic_param(&mut self) -> Option<GenericParamGreen> {
-        self.try_parse_identifier().map(|name| GenericParam::new_green(self.db, name))
+        let name = self.try_parse_identifier()?;
+        let bounds = self.parse_optional_generic_param_bounds();
+        Some(GenericParam::new_green(self.db, name, bounds))
+    }
+
+    fn parse_optional_generic_param_bounds(&mut self) -> OptionGenericParamBoundsGreen {
+        let Some(colon) = self.try_parse_token::<TerminalColon>() else {
+            return OptionGenericParamBoundsEmpty::new_green(self.db).into();
+        };
+        let traits = GenericParamBoundList::new_green(
+            self.db,
+            self.parse_separated_list::<
+                ExprPath,
+                TerminalPlus,
+                GenericParamBoundListElementOrSeparatorGreen,
+            >(
+                Self::try_parse_path,
+                is_of_kind!(comma, rangle, rparen, block, lbrace, rbrace, top_level),
+                "trait bound",
+            ),
+        );
+        GenericParamBounds::new_green(self.db, colon, traits).into()
     }
 
     // ------------------------------- Helpers -------------------------------
@@ -302,8 +302,10 @@ parser_test!(
     ["src/parser_test_data/not_isnt_a_binary_operator"],
     test_partial_parser_tree
 );
+parser_test!(item_constant, ["src/parser_test_data/item_constant"], test_partial_parser_tree);
 parser_test!(item_trait, ["src/parser_test_data/item_trait"], test_partial_parser_tree);
 parser_test!(let_statement, ["src/parser_test_data/let_statement"], test_partial_parser_tree);
 parser_test!(if_else, ["src/parser_test_data/if_else"], test_partial_parser_tree);
+parser_test!(loop_, ["src/parser_test_data/loop"], test_partial_parser_tree);
 
 parser_test!(literal, ["src/parser_test_data/literal"], test_partial_parser_tree);
@@ -41,6 +41,13 @@ macro_rules! rangle {
 }
 pub(crate) use rangle;
 
+macro_rules! comma {
+    () => {
+        SyntaxKind::TerminalComma
+    };
+}
+pub(crate) use comma;
+
 macro_rules! top_level {
     () => {
         SyntaxKind::TerminalExtern
@@ -52,13 +59,20 @@ macro_rules! top_level {
             | SyntaxKind::TerminalModule
             | SyntaxKind::TerminalStruct
             | SyntaxKind::TerminalUse
+            | SyntaxKind::TerminalConst
     };
 }
 pub(crate) use top_level;
 
 macro_rules! block {
     () => {
-        SyntaxKind::TerminalLet | SyntaxKind::TerminalMatch | SyntaxKind::TerminalReturn
+        SyntaxKind::TerminalLet
+            | SyntaxKind::TerminalMatch
+            | SyntaxKind::TerminalReturn
+            | SyntaxKind::TerminalLoop
+            | SyntaxKind::TerminalWhile
+            | SyntaxKind::TerminalBreak
+            | SyntaxKind::TerminalContinue
     };
 }
 pub(crate) use block;
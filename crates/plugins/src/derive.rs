@@ -1,4 +1,7 @@
 use defs::db::{MacroPlugin, PluginDiagnostic, PluginResult};
+use indoc::formatdoc;
+use itertools::Itertools;
+use smol_str::SmolStr;
 use syntax::node::ast::AttributeList;
 use syntax::node::db::SyntaxGroup;
 use syntax::node::{ast, Terminal, TypedSyntaxNode};
@@ -9,22 +12,47 @@ pub struct DerivePlugin {}
 impl MacroPlugin for DerivePlugin {
     fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
         match item_ast {
-            ast::Item::Struct(struct_ast) => {
-                generate_derive_code_for_type(db, struct_ast.name(db), struct_ast.attributes(db))
-            }
-            ast::Item::Enum(enum_ast) => {
-                generate_derive_code_for_type(db, enum_ast.name(db), enum_ast.attributes(db))
-            }
-            _ => PluginResult { code: None, diagnostics: vec![] },
+            ast::Item::Struct(struct_ast) => generate_derive_code_for_type(
+                db,
+                struct_ast.name(db),
+                struct_ast.attributes(db),
+                TypeMembers::Struct(extract_members(db, struct_ast.members(db))),
+            ),
+            ast::Item::Enum(enum_ast) => generate_derive_code_for_type(
+                db,
+                enum_ast.name(db),
+                enum_ast.attributes(db),
+                TypeMembers::Enum(extract_members(db, enum_ast.variants(db))),
+            ),
+            _ => PluginResult { code: None, diagnostics: vec![], ..Default::default() },
         }
     }
 }
 
+/// The members of a struct, or the variants of an enum, as pairs of a name and a type.
+enum TypeMembers {
+    Struct(Vec<(SmolStr, String)>),
+    Enum(Vec<(SmolStr, String)>),
+}
+
+/// Returns the names and the types of the members of a struct or of the variants of an enum.
+fn extract_members(db: &dyn SyntaxGroup, members: ast::MemberList) -> Vec<(SmolStr, String)> {
+    members
+        .elements(db)
+        .into_iter()
+        .map(|member| {
+            let ty = member.type_clause(db).ty(db).as_syntax_node().get_text_without_trivia(db);
+            (member.name(db).text(db), ty)
+        })
+        .collect()
+}
+
 /// Adds an implementation for all requested derives for the type.
 fn generate_derive_code_for_type(
     db: &dyn SyntaxGroup,
     ident: ast::TerminalIdentifier,
     attributes: AttributeList,
+    members: TypeMembers,
 ) -> PluginResult {
     let mut impls = vec![];
     for attr in attributes.elements(db) {
@@ -32,11 +60,17 @@ fn generate_derive_code_for_type(
             // TODO(orizi): Add diagnostics for all the unexpected cases.
             if let ast::OptionAttributeArgs::AttributeArgs(args) = attr.args(db) {
                 for arg in args.arg_list(db).elements(db) {
-                    if let ast::Expr::Path(expr) = arg {
+                    if let ast::Expr::Path(expr) = arg.value(db) {
                         if let [ast::PathSegment::Simple(segment)] = &expr.elements(db)[..] {
                             let name = ident.text(db);
                             let derived = segment.ident(db).text(db);
-                            impls.push(format!("impl {name}{derived} of {derived}::<{name}>;\n"));
+                            if derived == "Serde" {
+                                impls.push(generate_serde_impl(&name, &members));
+                            } else {
+                                impls.push(format!(
+                                    "impl {name}{derived} of {derived}::<{name}>;\n"
+                                ));
+                            }
                         } else {
                             return PluginResult {
                                 code: None,
@@ -44,6 +78,7 @@ fn generate_derive_code_for_type(
                                     stable_ptr: expr.stable_ptr().untyped(),
                                     message: "Expected a single segment.".into(),
                                 }],
+                                ..Default::default()
                             };
                         }
                     } else {
@@ -53,6 +88,7 @@ fn generate_derive_code_for_type(
                                 stable_ptr: arg.stable_ptr().untyped(),
                                 message: "Expected path.".into(),
                             }],
+                            ..Default::default()
                         };
                     }
                 }
@@ -63,13 +99,112 @@ fn generate_derive_code_for_type(
                         stable_ptr: attr.args(db).stable_ptr().untyped(),
                         message: "Expected args.".into(),
                     }],
+                    ..Default::default()
                 };
             }
         }
     }
     if impls.is_empty() {
-        PluginResult { code: None, diagnostics: vec![] }
+        PluginResult { code: None, diagnostics: vec![], ..Default::default() }
     } else {
-        PluginResult { code: Some(("impls".into(), impls.join(""))), diagnostics: vec![] }
+        PluginResult {
+            code: Some(("impls".into(), impls.join(""))),
+            diagnostics: vec![],
+            ..Default::default()
+        }
     }
 }
+
+/// Returns an implementation of `Serde` for a type.
+/// A struct is serialized as its members, in order. An enum is serialized as the index of its
+/// variant, followed by the value of the variant.
+fn generate_serde_impl(name: &str, members: &TypeMembers) -> String {
+    // The variables of the members are prefixed, so they don't shadow the parameters.
+    let (serialize_body, deserialize_body) = match members {
+        TypeMembers::Struct(members) => {
+            let member_vars =
+                members.iter().map(|(member, _)| format!("{member}: __{member}")).join(", ");
+            let serialize_members = members
+                .iter()
+                .map(|(member, ty)| {
+                    format!("serde::Serde::<{ty}>::serialize(serialized, __{member});")
+                })
+                .join("\n");
+            let mut deserialize = format!("Option::<{name}>::Some({name} {{ {member_vars} }})");
+            for (member, ty) in members.iter().rev() {
+                deserialize = formatdoc! {"
+                    match serde::Serde::<{ty}>::deserialize(serialized, offset) {{
+                        Option::Some(__{member}) => {},
+                        Option::None(_) => Option::<{name}>::None(()),
+                    }}",
+                    indent_inner_lines(&deserialize, 1)
+                };
+            }
+            (format!("let {name} {{ {member_vars} }} = input;\n{serialize_members}"), deserialize)
+        }
+        TypeMembers::Enum(variants) => {
+            let serialize_arms = variants
+                .iter()
+                .enumerate()
+                .map(|(index, (variant, ty))| {
+                    formatdoc! {"
+                        {name}::{variant}(__value) => {{
+                            serde::Serde::<felt>::serialize(serialized, {index});
+                            serde::Serde::<{ty}>::serialize(serialized, __value);
+                        }},"
+                    }
+                })
+                .join("\n");
+            // The variants are matched one at a time, as a felt is only matched against 0.
+            let mut deserialize_variant = format!("Option::<{name}>::None(())");
+            for (index, (variant, ty)) in variants.iter().enumerate().rev() {
+                let selector =
+                    if index == 0 { "__index".into() } else { format!("__index - {index}") };
+                deserialize_variant = formatdoc! {"
+                    match {selector} {{
+                        0 => match serde::Serde::<{ty}>::deserialize(serialized, offset) {{
+                            Option::Some(__value) => Option::<{name}>::Some({name}::{variant}(__value)),
+                            Option::None(_) => Option::<{name}>::None(()),
+                        }},
+                        _ => {},
+                    }}",
+                    indent_inner_lines(&deserialize_variant, 1)
+                };
+            }
+            (
+                formatdoc! {"
+                    match input {{
+                        {}
+                    }}",
+                    indent_inner_lines(&serialize_arms, 1)
+                },
+                formatdoc! {"
+                    match serde::Serde::<felt>::deserialize(serialized, offset) {{
+                        Option::Some(__index) => {},
+                        Option::None(_) => Option::<{name}>::None(()),
+                    }}",
+                    indent_inner_lines(&deserialize_variant, 1)
+                },
+            )
+        }
+    };
+    formatdoc! {"
+        impl {name}Serde of serde::Serde::<{name}> {{
+            func serialize(ref serialized: Array::<felt>, input: {name}) {{
+                {}
+            }}
+            func deserialize(ref serialized: Array::<felt>, ref offset: uint128) -> Option::<{name}> {{
+                {}
+            }}
+        }}
+        ",
+        indent_inner_lines(&serialize_body, 2),
+        indent_inner_lines(&deserialize_body, 2),
+    }
+}
+
+/// Indents all the lines of a code snippet but the first by the given number of levels, so it can
+/// be placed in an already indented line.
+fn indent_inner_lines(code: &str, levels: usize) -> String {
+    code.lines().join(&format!("\n{}", "    ".repeat(levels)))
+}
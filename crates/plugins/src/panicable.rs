@@ -23,7 +23,7 @@ impl MacroPlugin for PanicablePlugin {
                 free_func_ast.signature(db),
                 free_func_ast.attributes(db),
             ),
-            _ => PluginResult { code: None, diagnostics: vec![] },
+            _ => PluginResult { code: None, diagnostics: vec![], ..Default::default() },
         }
     }
 }
@@ -51,6 +51,7 @@ fn generate_panicable_code(
                     stable_ptr: signature.stable_ptr().untyped(),
                     message: "Only nonpanic functions can be wrapped".into(),
                 }],
+                ..Default::default()
             };
         }
 
@@ -64,12 +65,14 @@ fn generate_panicable_code(
                                 Result<T, E>"
                         .into(),
                 }],
+                ..Default::default()
             };
         };
 
         let Some((err_value, panicable_name)) = try_extract_matches!(attr.args(db), ast::OptionAttributeArgs::AttributeArgs).and_then(
             |args| {
-            if let [ast::Expr::Literal(err_value), ast::Expr::Path(name)] = &args.arg_list(db).elements(db)[..] {
+            let args = args.arg_list(db).elements(db).into_iter().map(|arg| arg.value(db)).collect_vec();
+            if let [ast::Expr::Literal(err_value), ast::Expr::Path(name)] = &args[..] {
                 // TODO(orizi): Once generic user functions are supported, support generic params, e.g. for `array_at<T>`.
                 if let [ast::PathSegment::Simple(segment)] = &name.elements(db)[..] {
                     Some((err_value.text(db), segment.ident(db).text(db)))
@@ -86,6 +89,7 @@ fn generate_panicable_code(
                     stable_ptr: signature.stable_ptr().untyped(),
                     message: "Failed to extract panic data attribute".into(),
                 }],
+                ..Default::default()
             };
         };
 
@@ -118,9 +122,10 @@ fn generate_panicable_code(
                 ),
             )),
             diagnostics: vec![],
+            ..Default::default()
         };
     }
-    PluginResult { code: None, diagnostics: vec![] }
+    PluginResult { code: None, diagnostics: vec![], ..Default::default() }
 }
 
 /// Given a function signature, if it returns `Option::<T>` or `Result::<T, E>`, returns T and the
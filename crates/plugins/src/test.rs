@@ -75,6 +75,73 @@ fn set_file_content(db: &mut DatabaseForTesting, path: &str, content: &str) {
     ];
     "derive"
 )]
+#[test_case(
+    vec![Arc::new(DerivePlugin{})],
+    indoc! {"
+        #[derive(Drop, Serde)]
+        struct A { x: felt, y: Array::<felt> }
+
+        #[derive(Drop, Serde)]
+        enum B { First: A, Second: () }
+    "},
+    &[
+        indoc! {"
+            impl ADrop of Drop::<A>;
+            impl ASerde of serde::Serde::<A> {
+                func serialize(ref serialized: Array::<felt>, input: A) {
+                    let A { x: __x, y: __y } = input;
+                    serde::Serde::<felt>::serialize(serialized, __x);
+                    serde::Serde::<Array::<felt>>::serialize(serialized, __y);
+                }
+                func deserialize(ref serialized: Array::<felt>, ref offset: uint128) -> Option::<A> {
+                    match serde::Serde::<felt>::deserialize(serialized, offset) {
+                        Option::Some(__x) => match serde::Serde::<Array::<felt>>::deserialize(serialized, offset) {
+                            Option::Some(__y) => Option::<A>::Some(A { x: __x, y: __y }),
+                            Option::None(_) => Option::<A>::None(()),
+                        },
+                        Option::None(_) => Option::<A>::None(()),
+                    }
+                }
+            }
+        "},
+        indoc! {"
+            impl BDrop of Drop::<B>;
+            impl BSerde of serde::Serde::<B> {
+                func serialize(ref serialized: Array::<felt>, input: B) {
+                    match input {
+                        B::First(__value) => {
+                            serde::Serde::<felt>::serialize(serialized, 0);
+                            serde::Serde::<A>::serialize(serialized, __value);
+                        },
+                        B::Second(__value) => {
+                            serde::Serde::<felt>::serialize(serialized, 1);
+                            serde::Serde::<()>::serialize(serialized, __value);
+                        },
+                    }
+                }
+                func deserialize(ref serialized: Array::<felt>, ref offset: uint128) -> Option::<B> {
+                    match serde::Serde::<felt>::deserialize(serialized, offset) {
+                        Option::Some(__index) => match __index {
+                            0 => match serde::Serde::<A>::deserialize(serialized, offset) {
+                                Option::Some(__value) => Option::<B>::Some(B::First(__value)),
+                                Option::None(_) => Option::<B>::None(()),
+                            },
+                            _ => match __index - 1 {
+                                0 => match serde::Serde::<()>::deserialize(serialized, offset) {
+                                    Option::Some(__value) => Option::<B>::Some(B::Second(__value)),
+                                    Option::None(_) => Option::<B>::None(()),
+                                },
+                                _ => Option::<B>::None(()),
+                            },
+                        },
+                        Option::None(_) => Option::<B>::None(()),
+                    }
+                }
+            }
+        "},
+    ];
+    "derive_serde"
+)]
 #[test_case(
     vec![Arc::new(PanicablePlugin{})],
     indoc! {"
@@ -5,7 +5,7 @@ use std::path::Path;
 use anyhow::{Context, Ok};
 use clap::Parser;
 use compiler::db::RootDatabase;
-use compiler::diagnostics::check_and_eprint_diagnostics;
+use compiler::diagnostics::{check_and_eprint_diagnostics_with_format, MessageFormat};
 use compiler::project::setup_project;
 use runner::SierraCasmRunner;
 use sierra_generator::db::SierraGenGroup;
@@ -19,12 +19,21 @@ struct Args {
     /// The file to compile and run.
     #[arg(short, long)]
     path: String,
+    /// The suffix of the path of the function to run.
+    #[arg(long, default_value = "::main")]
+    function: String,
+    /// The arguments of the function, as a JSON array with an element per non-implicit parameter.
+    #[arg(long, default_value = "[]")]
+    args: String,
     /// In cases where gas is available, the amount of provided gas.
     #[arg(long)]
     available_gas: Option<usize>,
     /// Whether to print the memory.
     #[arg(long, default_value_t = false)]
     print_full_memory: bool,
+    /// The format of the reported diagnostics.
+    #[arg(long, value_enum, default_value_t = MessageFormat::Human)]
+    message_format: MessageFormat,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -35,27 +44,37 @@ fn main() -> anyhow::Result<()> {
 
     let main_crate_ids = setup_project(db, Path::new(&args.path))?;
 
-    if check_and_eprint_diagnostics(db) {
+    if check_and_eprint_diagnostics_with_format(db, args.message_format) {
         anyhow::bail!("failed to compile: {}", args.path);
     }
 
+    let function_args: Vec<serde_json::Value> = serde_json::from_str(&args.args)
+        .with_context(|| "Failed parsing the arguments, expected a JSON array.")?;
+
     let sierra_program = db
-        .get_sierra_program(main_crate_ids)
+        .get_sierra_program_with_debug(main_crate_ids)
         .with_context(|| "Compilation failed without any diagnostics.")?;
     let runner = SierraCasmRunner::new(
-        replace_sierra_ids_in_program(db, &sierra_program),
+        replace_sierra_ids_in_program(db, &sierra_program.program),
         args.available_gas.is_some(),
     )
-    .with_context(|| "Failed setting up runner.")?;
+    .with_context(|| "Failed setting up runner.")?
+    .with_statements_locations(sierra_program.debug_info.statements_source_locations(db));
+    let function_args = runner
+        .serialize_args(&args.function, &function_args)
+        .with_context(|| "Failed serializing the arguments.")?;
     let result = runner
-        .run_function("::main", &[], &args.available_gas)
+        .run_function(&args.function, &function_args, &args.available_gas)
         .with_context(|| "Failed to run the function.")?;
     match result.value {
-        runner::RunResultValue::Success(values) => {
-            println!("Run completed successfully, returning {values:?}")
+        runner::RunResultValue::Success(_) => {
+            println!("Run completed successfully, returning {}", result.typed_value.unwrap())
         }
         runner::RunResultValue::Panic(values) => {
-            println!("Run panicked with err values: {values:?}")
+            println!(
+                "Run panicked with err values: {values:?}{}",
+                result.panic_stack_trace.unwrap_or_default()
+            )
         }
     }
     if let Some(gas) = result.gas_counter {
@@ -1,9 +1,11 @@
 use std::collections::HashMap;
 
 use cairo_rs::vm::errors::vm_errors::VirtualMachineError;
+use casm::inline::CasmContext;
 use casm::instructions::Instruction;
+use casm::run::TracedRun;
 use casm::{casm, casm_extend};
-use itertools::chain;
+use itertools::{chain, zip_eq};
 use num_bigint::BigInt;
 use sierra::extensions::builtin_cost::CostTokenType;
 use sierra::extensions::core::{CoreLibFunc, CoreType};
@@ -17,6 +19,15 @@ use sierra_to_casm::metadata::Metadata;
 use thiserror::Error;
 use utils::extract_matches;
 
+use crate::stack_trace::call_stack_effects;
+
+mod stack_trace;
+mod syscalls;
+mod values;
+
+pub use stack_trace::{StackFrame, StackTrace};
+pub use syscalls::{ContractCall, Deployment, Event, L1Message, MockSyscallHandler};
+
 #[derive(Debug, Error)]
 pub enum RunnerError {
     #[error("Not enough gas to call function.")]
@@ -29,12 +40,34 @@ pub enum RunnerError {
     MissingFunction { suffix: String },
     #[error("Function expects arguments of size {expected} and received {actual} instead.")]
     ArgumentsSizeMismatch { expected: usize, actual: usize },
+    #[error("Function expects {expected} arguments and received {actual} instead.")]
+    ArgumentsCountMismatch { expected: usize, actual: usize },
+    #[error("Invalid value for the argument at index {index}: {reason}")]
+    InvalidArgument { index: usize, reason: String },
     #[error(transparent)]
     ProgramRegistryError(#[from] Box<ProgramRegistryError>),
     #[error(transparent)]
     SierraCompilationError(#[from] CompilationError),
-    #[error(transparent)]
-    VirtualMachineError(#[from] Box<VirtualMachineError>),
+    #[error("{error}{stack_trace}")]
+    VirtualMachineError { error: Box<VirtualMachineError>, stack_trace: StackTrace },
+}
+
+/// An argument of a run function, as written into the memory before the call.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Arg {
+    /// A value taking a single cell.
+    Value(BigInt),
+    /// An array, written into a new memory segment, and passed as pointers to its start and end.
+    Array(Vec<Arg>),
+}
+impl Arg {
+    /// Returns the number of cells the argument takes as a parameter.
+    fn size(&self) -> usize {
+        match self {
+            Arg::Value(_) => 1,
+            Arg::Array(_) => 2,
+        }
+    }
 }
 
 /// The full result of a run.
@@ -42,6 +75,13 @@ pub struct RunResult {
     pub gas_counter: Option<BigInt>,
     pub memory: Vec<Option<BigInt>>,
     pub value: RunResultValue,
+    /// The returned value in JSON form, according to the return type of the function, or None if
+    /// the run panicked. See [SierraCasmRunner::serialize_args] for the format.
+    pub typed_value: Option<serde_json::Value>,
+    /// The stack trace at the origin of the panic, if the run panicked.
+    pub panic_stack_trace: Option<StackTrace>,
+    /// The system call handler of the run, with the state left by the run.
+    pub syscall_handler: MockSyscallHandler,
 }
 
 /// The ran function return value.
@@ -63,6 +103,10 @@ pub struct SierraCasmRunner {
     sierra_program_registry: ProgramRegistry<CoreType, CoreLibFunc>,
     /// The casm program matching the Sierra code.
     casm_program: CairoProgram,
+    /// The location in the Cairo code of each Sierra statement, if known, for stack traces.
+    statements_locations: Vec<Option<String>>,
+    /// The handler of the StarkNet system calls made by the run.
+    syscall_handler: MockSyscallHandler,
 }
 impl SierraCasmRunner {
     pub fn new(
@@ -73,23 +117,62 @@ impl SierraCasmRunner {
         let sierra_program_registry =
             ProgramRegistry::<CoreType, CoreLibFunc>::new(&sierra_program)?;
         let casm_program = sierra_to_casm::compiler::compile(&sierra_program, &metadata, calc_gas)?;
-        Ok(Self { sierra_program, metadata, sierra_program_registry, casm_program })
+        Ok(Self {
+            sierra_program,
+            metadata,
+            sierra_program_registry,
+            casm_program,
+            statements_locations: vec![],
+            syscall_handler: MockSyscallHandler::default(),
+        })
+    }
+
+    /// Sets the location in the Cairo code of each Sierra statement, as `file:line:col`, to be
+    /// shown in stack traces.
+    pub fn with_statements_locations(mut self, statements_locations: Vec<Option<String>>) -> Self {
+        self.statements_locations = statements_locations;
+        self
+    }
+
+    /// Sets the handler of the StarkNet system calls made by the run, holding the initial state of
+    /// the contract.
+    pub fn with_syscall_handler(mut self, syscall_handler: MockSyscallHandler) -> Self {
+        self.syscall_handler = syscall_handler;
+        self
     }
 
     /// Runs the vm starting from a function. Function may have implicits, but no other ref params.
+    /// A `SyscallPtr` param is supported, with its system calls handled by the syscall handler.
     /// The cost of the function is deducted from available_gas before the execution begins.
     pub fn run_function(
         mut self,
         name_suffix: &str,
-        args: &[BigInt],
+        args: &[Arg],
         available_gas: &Option<usize>,
     ) -> Result<RunResult, RunnerError> {
-        // Extracting instructions before since `self` becomes borrowed later.
+        // Extracting instructions and the syscall handler before since `self` becomes borrowed
+        // later.
         let instructions = self.casm_program.instructions;
         self.casm_program.instructions = vec![];
+        let mut syscall_handler = std::mem::take(&mut self.syscall_handler);
         let func = self.find_function(name_suffix)?;
         let entry_code = self.create_entry_code(func, args, available_gas)?;
-        let (cells, ap) = casm::run::run_function(chain!(entry_code, instructions).collect())?;
+        let code_start = entry_code.iter().map(|instruction| instruction.body.op_size()).sum();
+        let program: Vec<Instruction> = chain!(entry_code, instructions).collect();
+        let effects = call_stack_effects(&program);
+        let TracedRun { memory: cells, trace } =
+            casm::run::run_function_with_syscall_handler(program, &mut syscall_handler).map_err(
+                |failure| RunnerError::VirtualMachineError {
+                    stack_trace: self.failure_stack_trace(
+                        &effects,
+                        code_start,
+                        &failure.pc_trace,
+                        failure.pc,
+                    ),
+                    error: Box::new(failure.error),
+                },
+            )?;
+        let ap = trace.last().unwrap().ap;
         let mut results_data = self.get_results_data(func, &cells, ap)?;
         // Handling implicits.
         let mut gas_counter = None;
@@ -100,61 +183,90 @@ impl SierraCasmRunner {
                 false
             } else {
                 // TODO(orizi): Actually return the range check data.
-                *ty != "RangeCheck".into()
+                *ty != "RangeCheck".into() && *ty != "SyscallPtr".into()
             }
         });
         assert!(results_data.len() <= 1);
-        let value = if results_data.is_empty() {
+        let (value, typed_value) = if results_data.is_empty() {
             // No result type - no panic.
-            RunResultValue::Success(vec![])
+            (RunResultValue::Success(vec![]), Some(serde_json::Value::Array(vec![])))
         } else {
             let [(ty, values)] = <[_; 1]>::try_from(results_data).ok().unwrap();
-            self.handle_main_return_value(ty, values, &cells)?
+            let value = self.handle_main_return_value(&ty, values, &cells)?;
+            let typed_value = match &value {
+                RunResultValue::Success(values) => {
+                    let value_ty = self.panic_result_inner_type(&ty)?.unwrap_or(ty);
+                    Some(self.deserialize_value(&value_ty, values, &cells)?)
+                }
+                RunResultValue::Panic(_) => None,
+            };
+            (value, typed_value)
         };
-        Ok(RunResult { gas_counter, memory: cells, value })
+        let panic_stack_trace = match value {
+            RunResultValue::Success(_) => None,
+            RunResultValue::Panic(_) => {
+                self.panic_stack_trace(&effects, code_start, &trace, &cells)?
+            }
+        };
+        Ok(RunResult {
+            gas_counter,
+            memory: cells,
+            value,
+            typed_value,
+            panic_stack_trace,
+            syscall_handler,
+        })
     }
 
-    /// Handling the main return value to create a `RunResultValue`.
-    fn handle_main_return_value(
+    /// Returns the type of the value wrapped by `ty`, if it is the `PanicResult` enum returned by
+    /// functions including a panic wrapper.
+    fn panic_result_inner_type(
         &self,
-        ty: sierra::ids::ConcreteTypeId,
-        values: Vec<BigInt>,
-        cells: &[Option<BigInt>],
-    ) -> Result<RunResultValue, RunnerError> {
-        let info = self.sierra_program_registry.get_type(&ty)?.info();
-        let long_id = &info.long_id;
+        ty: &sierra::ids::ConcreteTypeId,
+    ) -> Result<Option<sierra::ids::ConcreteTypeId>, RunnerError> {
+        let long_id = &self.sierra_program_registry.get_type(ty)?.info().long_id;
         Ok(
             if long_id.generic_id == "Enum".into()
                 && matches!(&long_id.generic_args[0], GenericArg::UserType(ut) if ut.debug_name.as_ref().unwrap().starts_with("core::PanicResult::"))
             {
-                // The function includes a panic wrapper.
-                if values[0] != BigInt::from(0) {
-                    // The run resulted in a panic, returning the error data.
-                    let err_data_start = usize::try_from(&values[1]).unwrap();
-                    let err_data_end = usize::try_from(&values[2]).unwrap();
-                    RunResultValue::Panic(
-                        cells[err_data_start..err_data_end]
-                            .iter()
-                            .cloned()
-                            .map(|cell| cell.unwrap())
-                            .collect(),
-                    )
-                } else {
-                    // The run resulted successfully, returning the inner value.
-                    let inner_ty = extract_matches!(&long_id.generic_args[1], GenericArg::Type);
-                    let inner_ty_size =
-                        self.sierra_program_registry.get_type(inner_ty)?.info().size;
-                    RunResultValue::Success(
-                        values.into_iter().skip(1).take(inner_ty_size).collect(),
-                    )
-                }
+                Some(extract_matches!(&long_id.generic_args[1], GenericArg::Type).clone())
             } else {
-                // No panic wrap - so always successful.
-                RunResultValue::Success(values)
+                None
             },
         )
     }
 
+    /// Handling the main return value to create a `RunResultValue`.
+    fn handle_main_return_value(
+        &self,
+        ty: &sierra::ids::ConcreteTypeId,
+        values: Vec<BigInt>,
+        cells: &[Option<BigInt>],
+    ) -> Result<RunResultValue, RunnerError> {
+        Ok(if let Some(inner_ty) = self.panic_result_inner_type(ty)? {
+            // The function includes a panic wrapper.
+            if values[0] != BigInt::from(0) {
+                // The run resulted in a panic, returning the error data.
+                let err_data_start = usize::try_from(&values[1]).unwrap();
+                let err_data_end = usize::try_from(&values[2]).unwrap();
+                RunResultValue::Panic(
+                    cells[err_data_start..err_data_end]
+                        .iter()
+                        .cloned()
+                        .map(|cell| cell.unwrap())
+                        .collect(),
+                )
+            } else {
+                // The run resulted successfully, returning the inner value.
+                let inner_ty_size = self.sierra_program_registry.get_type(&inner_ty)?.info().size;
+                RunResultValue::Success(values.into_iter().skip(1).take(inner_ty_size).collect())
+            }
+        } else {
+            // No panic wrap - so always successful.
+            RunResultValue::Success(values)
+        })
+    }
+
     /// Returns the final values and type of all if `func`s returning variables.
     fn get_results_data(
         &self,
@@ -189,19 +301,36 @@ impl SierraCasmRunner {
     fn create_entry_code(
         &self,
         func: &Function,
-        args: &[BigInt],
+        args: &[Arg],
         available_gas: &Option<usize>,
     ) -> Result<Vec<Instruction>, RunnerError> {
-        let mut arg_iter = args.iter();
-        let mut expected_arguments_size = 0;
         let mut ctx = casm! {};
+        // The number of cells pushed so far by the entry code.
+        let mut ap_offset = 0;
+        // The parameters of the function must be the last cells pushed before the call, so the
+        // contents of the arrays are written beforehand.
+        let mut array_ptrs = vec![];
+        for arg in args {
+            array_ptrs.push(match arg {
+                Arg::Value(_) => None,
+                Arg::Array(elements) => {
+                    let array_ptr;
+                    (ctx, array_ptr) = write_array(ctx, elements, &mut ap_offset);
+                    Some(array_ptr)
+                }
+            });
+        }
+        let mut arg_iter = zip_eq(args, array_ptrs);
+        let mut expected_arguments_size = 0;
+        let mut actual_arguments_size = 0;
         for ty in func.signature.param_types.iter() {
-            if ty == &"RangeCheck".into() {
+            if ty == &"RangeCheck".into() || ty == &"SyscallPtr".into() {
                 // TODO(orizi): Use the vm's range check segment.
                 casm_extend! {ctx,
                     %{ memory[ap + 0] = segments.add() %}
                     ap += 1;
                 }
+                ap_offset += 1;
             } else if ty == &"GasBuiltin".into() {
                 if let Some(available_gas) = available_gas {
                     // TODO(lior): Handle the other token types.
@@ -213,6 +342,7 @@ impl SierraCasmRunner {
                         casm_extend! {ctx,
                             [ap + 0] = initial_gas, ap++;
                         }
+                        ap_offset += 1;
                     } else {
                         return Err(RunnerError::NotEnoughGasToCall);
                     }
@@ -222,19 +352,35 @@ impl SierraCasmRunner {
             } else {
                 let arg_size = self.sierra_program_registry.get_type(ty)?.info().size;
                 expected_arguments_size += arg_size;
-                for _ in 0..arg_size {
-                    if let Some(value) = arg_iter.next() {
-                        casm_extend! {ctx,
-                            [ap + 0] = (value.clone()), ap++;
+                let param_end = actual_arguments_size + arg_size;
+                while actual_arguments_size < param_end {
+                    let Some((arg, array_ptr)) = arg_iter.next() else { break };
+                    actual_arguments_size += arg.size();
+                    match arg {
+                        Arg::Value(value) => {
+                            casm_extend! {ctx,
+                                [ap + 0] = (value.clone()), ap++;
+                            }
+                            ap_offset += 1;
+                        }
+                        Arg::Array(_) => {
+                            // Copying the start and end pointers of the array.
+                            let distance = (ap_offset - array_ptr.unwrap()) as i16;
+                            casm_extend! {ctx,
+                                [ap + 0] = [ap - distance], ap++;
+                                [ap + 0] = [ap - distance], ap++;
+                            }
+                            ap_offset += 2;
                         }
                     }
                 }
             }
         }
-        if expected_arguments_size != args.len() {
+        actual_arguments_size += arg_iter.map(|(arg, _)| arg.size()).sum::<usize>();
+        if expected_arguments_size != actual_arguments_size {
             return Err(RunnerError::ArgumentsSizeMismatch {
                 expected: expected_arguments_size,
-                actual: args.len(),
+                actual: actual_arguments_size,
             });
         }
         let before_final_call = ctx.current_code_offset;
@@ -250,6 +396,65 @@ impl SierraCasmRunner {
     }
 }
 
+/// Adds instructions writing the elements of an array into a new memory segment, followed by
+/// pushing the pointers to the start and end of the segment. Arrays nested in the elements are
+/// written first. Returns the extended context, and the offset, from the beginning of the entry
+/// code's pushed cells, of the cell holding the start pointer.
+fn write_array(
+    mut ctx: CasmContext,
+    elements: &[Arg],
+    ap_offset: &mut usize,
+) -> (CasmContext, usize) {
+    let mut inner_array_ptrs = vec![];
+    for element in elements {
+        inner_array_ptrs.push(match element {
+            Arg::Value(_) => None,
+            Arg::Array(inner) => {
+                let inner_array_ptr;
+                (ctx, inner_array_ptr) = write_array(ctx, inner, ap_offset);
+                Some(inner_array_ptr)
+            }
+        });
+    }
+    let segment_ptr = *ap_offset;
+    casm_extend! {ctx,
+        %{ memory[ap + 0] = segments.add() %}
+        ap += 1;
+    }
+    *ap_offset += 1;
+    let mut cell_index: i16 = 0;
+    for (element, inner_array_ptr) in zip_eq(elements, inner_array_ptrs) {
+        let segment = (*ap_offset - segment_ptr) as i16;
+        match element {
+            Arg::Value(value) => {
+                casm_extend! {ctx,
+                    [ap + 0] = (value.clone());
+                    [ap + 0] = [[ap - segment] + cell_index], ap++;
+                }
+                *ap_offset += 1;
+                cell_index += 1;
+            }
+            Arg::Array(_) => {
+                let inner_start = (*ap_offset - inner_array_ptr.unwrap()) as i16;
+                let inner_end = inner_start - 1;
+                casm_extend! {ctx,
+                    [ap - inner_start] = [[ap - segment] + cell_index];
+                    [ap - inner_end] = [[ap - segment] + (cell_index + 1)];
+                }
+                cell_index += 2;
+            }
+        }
+    }
+    let segment = (*ap_offset - segment_ptr) as i16;
+    let array_ptr = *ap_offset;
+    casm_extend! {ctx,
+        [ap + 0] = [ap - segment], ap++;
+        [ap + 0] = [ap - 1] + cell_index, ap++;
+    }
+    *ap_offset += 2;
+    (ctx, array_ptr)
+}
+
 /// Creates the metadata required for a Sierra program lowering to casm.
 fn create_metadata(
     sierra_program: &sierra::program::Program,
@@ -0,0 +1,184 @@
+//! Reconstruction of the call stack of a run in terms of the Cairo code, using the execution trace
+//! of the run and the debug information of the compilation.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use casm::instructions::{Instruction, InstructionBody};
+use casm::run::TraceEntry;
+use num_bigint::BigInt;
+use sierra::extensions::ConcreteType;
+use sierra::program::Function;
+
+use crate::{RunnerError, SierraCasmRunner};
+
+/// A frame of a [StackTrace].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StackFrame {
+    /// The name of the function of the frame.
+    pub function: String,
+    /// The location in the Cairo code of the frame, as `file:line:col`, if known.
+    pub location: Option<String>,
+}
+impl Display for StackFrame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "at {} ({location})", self.function),
+            None => write!(f, "at {}", self.function),
+        }
+    }
+}
+
+/// The call stack at some point of a run, starting from the innermost frame.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StackTrace(pub Vec<StackFrame>);
+impl Display for StackTrace {
+    /// Writes each frame on a line of its own, preceded by a line break, so the stack trace can be
+    /// appended to a message.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for frame in &self.0 {
+            write!(f, "\n    {frame}")?;
+        }
+        Ok(())
+    }
+}
+
+impl SierraCasmRunner {
+    /// Returns the stack trace of the panic of a run, taken at the return of the innermost function
+    /// returning a `PanicResult::Err`, or None if no such return occurred. `effects` are the
+    /// effects of the instructions of the run code, see [call_stack_effects], where the program
+    /// code starts at offset `code_start`, after the entry code.
+    pub(crate) fn panic_stack_trace(
+        &self,
+        effects: &HashMap<usize, CallStackEffect>,
+        code_start: usize,
+        trace: &[TraceEntry],
+        memory: &[Option<BigInt>],
+    ) -> Result<Option<StackTrace>, RunnerError> {
+        let mut call_stack = vec![];
+        for entry in trace {
+            match effects.get(&entry.pc) {
+                Some(CallStackEffect::Push) => call_stack.push(entry.pc),
+                Some(CallStackEffect::Pop) => {
+                    if entry.pc >= code_start && self.returns_panic(entry, code_start, memory)? {
+                        call_stack.push(entry.pc);
+                        return Ok(Some(self.stack_trace(&call_stack, code_start)));
+                    }
+                    call_stack.pop();
+                }
+                None => {}
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the stack trace of a run that failed at the instruction at offset `pc`, given the
+    /// offsets of the instructions executed before the failure. `effects` and `code_start` are as
+    /// in [SierraCasmRunner::panic_stack_trace].
+    pub(crate) fn failure_stack_trace(
+        &self,
+        effects: &HashMap<usize, CallStackEffect>,
+        code_start: usize,
+        pc_trace: &[usize],
+        pc: usize,
+    ) -> StackTrace {
+        let mut call_stack = vec![];
+        for executed_pc in pc_trace {
+            match effects.get(executed_pc) {
+                Some(CallStackEffect::Push) => call_stack.push(*executed_pc),
+                Some(CallStackEffect::Pop) => {
+                    call_stack.pop();
+                }
+                None => {}
+            }
+        }
+        call_stack.push(pc);
+        self.stack_trace(&call_stack, code_start)
+    }
+
+    /// Returns true if the `ret` instruction of `entry` returns from a function returning a
+    /// `PanicResult`, with the `Err` variant.
+    fn returns_panic(
+        &self,
+        entry: &TraceEntry,
+        code_start: usize,
+        memory: &[Option<BigInt>],
+    ) -> Result<bool, RunnerError> {
+        let func = self.statement_function(self.statement_at(entry.pc - code_start));
+        let Some(ty) = func.signature.ret_types.last() else { return Ok(false) };
+        if self.panic_result_inner_type(ty)?.is_none() {
+            return Ok(false);
+        }
+        // The returned `PanicResult` is the last returned value, starting with its selector.
+        let size = self.sierra_program_registry.get_type(ty)?.info().size;
+        Ok(memory[entry.ap - size] == Some(BigInt::from(1)))
+    }
+
+    /// Returns the stack trace of the given call stack of code offsets, given from the outermost
+    /// frame. Frames of the entry code are omitted.
+    fn stack_trace(&self, call_stack: &[usize], code_start: usize) -> StackTrace {
+        StackTrace(
+            call_stack
+                .iter()
+                .rev()
+                .filter(|pc| **pc >= code_start)
+                .map(|pc| {
+                    let statement_idx = self.statement_at(pc - code_start);
+                    let func = self.statement_function(statement_idx);
+                    StackFrame {
+                        function: func.id.to_string(),
+                        location: self.statements_locations.get(statement_idx).cloned().flatten(),
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the index of the Sierra statement compiled into the instruction at `code_offset`.
+    fn statement_at(&self, code_offset: usize) -> usize {
+        self.casm_program
+            .debug_info
+            .sierra_statement_info
+            .partition_point(|info| info.code_offset <= code_offset)
+            - 1
+    }
+
+    /// Returns the Sierra function containing the statement at `statement_idx`.
+    fn statement_function(&self, statement_idx: usize) -> &Function {
+        self.sierra_program
+            .funcs
+            .iter()
+            .filter(|func| func.entry_point.0 <= statement_idx)
+            .max_by_key(|func| func.entry_point.0)
+            .unwrap()
+    }
+}
+
+/// The effect of an instruction on the call stack.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum CallStackEffect {
+    /// A `call` instruction, pushing a frame.
+    Push,
+    /// A `ret` instruction, popping a frame.
+    Pop,
+}
+
+/// Returns the effects on the call stack of the instructions of a program, by their offsets in the
+/// code. Instructions not affecting the call stack are omitted.
+pub(crate) fn call_stack_effects(program: &[Instruction]) -> HashMap<usize, CallStackEffect> {
+    let mut offset = 0;
+    let mut effects = HashMap::new();
+    for instruction in program {
+        match instruction.body {
+            InstructionBody::Call(_) => {
+                effects.insert(offset, CallStackEffect::Push);
+            }
+            InstructionBody::Ret(_) => {
+                effects.insert(offset, CallStackEffect::Pop);
+            }
+            _ => {}
+        }
+        offset += instruction.body.op_size();
+    }
+    effects
+}
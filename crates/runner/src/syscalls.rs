@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use cairo_rs::vm::errors::vm_errors::VirtualMachineError;
+use casm::run::{SyscallHandler, SyscallMemory};
+use num_bigint::BigInt;
+
+/// A call to another contract, made by `call_contract`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ContractCall {
+    pub address: BigInt,
+    pub entry_point_selector: BigInt,
+    pub calldata: Vec<BigInt>,
+}
+
+/// An event, emitted by `emit_event`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Event {
+    pub keys: Vec<BigInt>,
+    pub data: Vec<BigInt>,
+}
+
+/// A message to L1, sent by `send_message_to_l1`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct L1Message {
+    pub to_address: BigInt,
+    pub payload: Vec<BigInt>,
+}
+
+/// A contract deployment, made by `deploy`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Deployment {
+    pub class_hash: BigInt,
+    pub contract_address_salt: BigInt,
+    pub calldata: Vec<BigInt>,
+}
+
+/// A handler of the StarkNet system calls of a run, keeping the state of the contract in memory.
+/// Enables testing contracts locally, without a StarkNet node.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MockSyscallHandler {
+    /// The storage of the contract, by storage address. Unset addresses are read as 0.
+    pub storage: HashMap<BigInt, BigInt>,
+    /// The address returned by `get_caller_address`.
+    pub caller_address: BigInt,
+    /// The address returned by `get_contract_address`.
+    pub contract_address: BigInt,
+    /// The number returned by `get_block_number`.
+    pub block_number: BigInt,
+    /// The timestamp returned by `get_block_timestamp`.
+    pub block_timestamp: BigInt,
+    /// The results of calls to other contracts, by the called contract address and entry point
+    /// selector. Calls without a result return an empty array.
+    pub call_results: HashMap<(BigInt, BigInt), Vec<BigInt>>,
+    /// The calls made to other contracts.
+    pub calls: Vec<ContractCall>,
+    /// The emitted events.
+    pub events: Vec<Event>,
+    /// The messages sent to L1.
+    pub l1_messages: Vec<L1Message>,
+    /// The deployed contracts. The address of a deployed contract is its contract address salt.
+    pub deployments: Vec<Deployment>,
+}
+impl SyscallHandler for MockSyscallHandler {
+    fn execute(&mut self, memory: &mut SyscallMemory<'_>) -> Result<(), VirtualMachineError> {
+        // The selector is the name of the system call, encoded in little endian.
+        let selector = memory.read(0)?;
+        let Ok(selector) = String::from_utf8(selector.to_bytes_le().1) else {
+            return Err(VirtualMachineError::UnknownHint(format!(
+                "Invalid system call selector `{selector}`."
+            )));
+        };
+        // The request arguments start at offset 1, and the response follows the request. Felts
+        // take a single cell, and arrays take two.
+        match selector.as_str() {
+            "storage_read" => {
+                let address = memory.read(1)?;
+                let value = self.storage.get(&address).cloned().unwrap_or_default();
+                memory.write(2, value)
+            }
+            "storage_write" => {
+                let address = memory.read(1)?;
+                self.storage.insert(address, memory.read(2)?);
+                Ok(())
+            }
+            "call_contract" => {
+                let call = ContractCall {
+                    address: memory.read(1)?,
+                    entry_point_selector: memory.read(2)?,
+                    calldata: memory.read_array(3)?,
+                };
+                let result = self
+                    .call_results
+                    .get(&(call.address.clone(), call.entry_point_selector.clone()))
+                    .cloned()
+                    .unwrap_or_default();
+                self.calls.push(call);
+                memory.write_array(5, result)
+            }
+            "deploy" => {
+                let deployment = Deployment {
+                    class_hash: memory.read(1)?,
+                    contract_address_salt: memory.read(2)?,
+                    calldata: memory.read_array(3)?,
+                };
+                let address = deployment.contract_address_salt.clone();
+                self.deployments.push(deployment);
+                memory.write(5, address)
+            }
+            "get_caller_address" => memory.write(1, self.caller_address.clone()),
+            "get_contract_address" => memory.write(1, self.contract_address.clone()),
+            "get_block_number" => memory.write(1, self.block_number.clone()),
+            "get_block_timestamp" => memory.write(1, self.block_timestamp.clone()),
+            "emit_event" => {
+                self.events
+                    .push(Event { keys: memory.read_array(1)?, data: memory.read_array(3)? });
+                Ok(())
+            }
+            "send_message_to_l1" => {
+                self.l1_messages.push(L1Message {
+                    to_address: memory.read(1)?,
+                    payload: memory.read_array(2)?,
+                });
+                Ok(())
+            }
+            _ => {
+                Err(VirtualMachineError::UnknownHint(format!("Unknown system call `{selector}`.")))
+            }
+        }
+    }
+}
@@ -0,0 +1,245 @@
+//! Conversion between JSON values and the memory representation of Sierra types, for passing typed
+//! arguments to a run function and reading back its return value.
+
+use itertools::{repeat_n, Itertools};
+use num_bigint::BigInt;
+use num_traits::{Num, Zero};
+use serde_json::{json, Value};
+use sierra::extensions::core::CoreTypeConcrete;
+use sierra::extensions::ConcreteType;
+use sierra::ids::ConcreteTypeId;
+use sierra::program::GenericArg;
+
+use crate::{Arg, RunnerError, SierraCasmRunner};
+
+/// The name of the user type of `uint256`, represented in JSON as a single number.
+const UINT256_TYPE_NAME: &str = "core::integer::uint256";
+
+impl SierraCasmRunner {
+    /// Serializes JSON values into the arguments of the first function ending with `name_suffix`,
+    /// according to the types of its parameters. Implicits are not given as arguments.
+    ///
+    /// Felts and integers (including `uint256`) are given as numbers, or as strings of decimal or
+    /// `0x`-prefixed hexadecimal numbers. Structs and tuples are given as arrays of their members,
+    /// arrays as arrays of their elements, and enums as objects of the form
+    /// `{"variant": <index>, "value": <value>}`.
+    pub fn serialize_args(
+        &self,
+        name_suffix: &str,
+        args: &[Value],
+    ) -> Result<Vec<Arg>, RunnerError> {
+        let func = self.find_function(name_suffix)?;
+        let param_types = func
+            .signature
+            .param_types
+            .iter()
+            .filter(|ty| {
+                **ty != "RangeCheck".into()
+                    && **ty != "GasBuiltin".into()
+                    && **ty != "SyscallPtr".into()
+            })
+            .collect_vec();
+        if param_types.len() != args.len() {
+            return Err(RunnerError::ArgumentsCountMismatch {
+                expected: param_types.len(),
+                actual: args.len(),
+            });
+        }
+        let mut serialized = vec![];
+        for (index, (ty, arg)) in param_types.into_iter().zip(args).enumerate() {
+            self.serialize_value(ty, arg, &mut serialized)
+                .map_err(|reason| RunnerError::InvalidArgument { index, reason })?;
+        }
+        Ok(serialized)
+    }
+
+    /// Serializes a JSON value of type `ty` into `serialized`. Returns the reason on failure.
+    fn serialize_value(
+        &self,
+        ty: &ConcreteTypeId,
+        value: &Value,
+        serialized: &mut Vec<Arg>,
+    ) -> Result<(), String> {
+        match self.sierra_program_registry.get_type(ty).map_err(|err| err.to_string())? {
+            CoreTypeConcrete::Felt(_) => serialized.push(Arg::Value(parse_number(value)?)),
+            CoreTypeConcrete::Uint128(_) => {
+                serialized.push(Arg::Value(parse_unsigned(value, 128)?));
+            }
+            CoreTypeConcrete::NonZero(info) => {
+                let start = serialized.len();
+                self.serialize_value(&info.ty, value, serialized)?;
+                if serialized[start..].iter().all(|arg| matches!(arg, Arg::Value(v) if v.is_zero()))
+                {
+                    return Err(format!("Expected a non-zero value, got `{value}`."));
+                }
+            }
+            CoreTypeConcrete::Struct(info) if is_uint256(&info.info.long_id.generic_args) => {
+                let value = parse_unsigned(value, 256)?;
+                let high = &value >> 128;
+                serialized.push(Arg::Value(value - (&high << 128)));
+                serialized.push(Arg::Value(high));
+            }
+            CoreTypeConcrete::Struct(info) => {
+                let members = value
+                    .as_array()
+                    .filter(|members| members.len() == info.members.len())
+                    .ok_or_else(|| {
+                        format!(
+                            "Expected an array of {} members, got `{value}`.",
+                            info.members.len()
+                        )
+                    })?;
+                for (member_ty, member) in info.members.iter().zip(members) {
+                    self.serialize_value(member_ty, member, serialized)?;
+                }
+            }
+            CoreTypeConcrete::Enum(info) => {
+                let variant_error =
+                    || format!("Expected an object with `variant` and `value`, got `{value}`.");
+                let object = value.as_object().ok_or_else(variant_error)?;
+                let index =
+                    object.get("variant").and_then(Value::as_u64).ok_or_else(variant_error)?
+                        as usize;
+                let variant_ty = info.variants.get(index).ok_or_else(|| {
+                    format!(
+                        "Variant index {index} is out of range, the enum has {} variants.",
+                        info.variants.len()
+                    )
+                })?;
+                // Matches the variant selector set by `enum_init`.
+                let selector = if info.variants.len() <= 2 { index } else { 2 * index + 1 };
+                serialized.push(Arg::Value(selector.into()));
+                self.serialize_value(
+                    variant_ty,
+                    object.get("value").unwrap_or(&json!([])),
+                    serialized,
+                )?;
+                let variant_size = self.type_size(variant_ty).map_err(|err| err.to_string())?;
+                serialized.extend(repeat_n(
+                    Arg::Value(BigInt::zero()),
+                    info.info.size - 1 - variant_size,
+                ));
+            }
+            CoreTypeConcrete::Array(info) => {
+                let elements =
+                    value.as_array().ok_or_else(|| format!("Expected an array, got `{value}`."))?;
+                let mut serialized_elements = vec![];
+                for element in elements {
+                    self.serialize_value(&info.ty, element, &mut serialized_elements)?;
+                }
+                serialized.push(Arg::Array(serialized_elements));
+            }
+            _ => return Err(format!("Arguments of type `{ty}` are not supported.")),
+        }
+        Ok(())
+    }
+
+    /// Deserializes the cells of a value of type `ty` into JSON, in the format of
+    /// [SierraCasmRunner::serialize_args]. Values of unsupported types are given as arrays of their
+    /// raw cells.
+    pub(crate) fn deserialize_value(
+        &self,
+        ty: &ConcreteTypeId,
+        cells: &[BigInt],
+        memory: &[Option<BigInt>],
+    ) -> Result<Value, RunnerError> {
+        Ok(match self.sierra_program_registry.get_type(ty)? {
+            CoreTypeConcrete::Felt(_) | CoreTypeConcrete::Uint128(_) => number_to_json(&cells[0]),
+            CoreTypeConcrete::NonZero(info) => self.deserialize_value(&info.ty, cells, memory)?,
+            CoreTypeConcrete::Box(info) => {
+                let start = usize::try_from(&cells[0]).unwrap();
+                let inner_cells = read_memory(memory, start, start + self.type_size(&info.ty)?);
+                self.deserialize_value(&info.ty, &inner_cells, memory)?
+            }
+            CoreTypeConcrete::Struct(info) if is_uint256(&info.info.long_id.generic_args) => {
+                number_to_json(&(&cells[0] + (&cells[1] << 128)))
+            }
+            CoreTypeConcrete::Struct(info) => {
+                let mut offset = 0;
+                let mut members = vec![];
+                for member_ty in &info.members {
+                    let size = self.type_size(member_ty)?;
+                    members.push(self.deserialize_value(
+                        member_ty,
+                        &cells[offset..offset + size],
+                        memory,
+                    )?);
+                    offset += size;
+                }
+                Value::Array(members)
+            }
+            CoreTypeConcrete::Enum(info) => {
+                let selector = usize::try_from(&cells[0]).unwrap();
+                let index = if info.variants.len() <= 2 { selector } else { (selector - 1) / 2 };
+                let variant_ty = &info.variants[index];
+                let variant_size = self.type_size(variant_ty)?;
+                let value =
+                    self.deserialize_value(variant_ty, &cells[1..1 + variant_size], memory)?;
+                json!({ "variant": index, "value": value })
+            }
+            CoreTypeConcrete::Array(info) => {
+                let start = usize::try_from(&cells[0]).unwrap();
+                let end = usize::try_from(&cells[1]).unwrap();
+                let element_size = self.type_size(&info.ty)?;
+                let mut elements = vec![];
+                if element_size > 0 {
+                    for element_cells in read_memory(memory, start, end).chunks(element_size) {
+                        elements.push(self.deserialize_value(&info.ty, element_cells, memory)?);
+                    }
+                }
+                Value::Array(elements)
+            }
+            _ => Value::Array(cells.iter().map(number_to_json).collect()),
+        })
+    }
+
+    /// Returns the size of a type.
+    fn type_size(
+        &self,
+        ty: &ConcreteTypeId,
+    ) -> Result<usize, Box<sierra::program_registry::ProgramRegistryError>> {
+        Ok(self.sierra_program_registry.get_type(ty)?.info().size)
+    }
+}
+
+/// Returns true if the generic args of a struct type are these of `uint256`.
+fn is_uint256(generic_args: &[GenericArg]) -> bool {
+    matches!(generic_args.first(), Some(GenericArg::UserType(ut)) if ut.debug_name.as_deref() == Some(UINT256_TYPE_NAME))
+}
+
+/// Parses a JSON number, or a string of a decimal or `0x`-prefixed hexadecimal number.
+fn parse_number(value: &Value) -> Result<BigInt, String> {
+    match value {
+        Value::Number(number) => number
+            .as_i64()
+            .map(BigInt::from)
+            .or_else(|| number.as_u64().map(BigInt::from))
+            .ok_or_else(|| format!("Expected an integer, got `{number}`.")),
+        Value::String(text) => match text.strip_prefix("0x") {
+            Some(hex) => BigInt::from_str_radix(hex, 16),
+            None => text.parse::<BigInt>(),
+        }
+        .map_err(|_| format!("Invalid number `{text}`.")),
+        _ => Err(format!("Expected a number, got `{value}`.")),
+    }
+}
+
+/// Parses a number as in [parse_number], and checks it is in the range of an unsigned integer of
+/// `bits` bits.
+fn parse_unsigned(value: &Value, bits: usize) -> Result<BigInt, String> {
+    let number = parse_number(value)?;
+    if number < BigInt::zero() || number >= (BigInt::from(1) << bits) {
+        return Err(format!("Expected a {bits} bit unsigned integer, got `{number}`."));
+    }
+    Ok(number)
+}
+
+/// Converts a number to JSON, as a string if it is too large for a JSON number.
+fn number_to_json(value: &BigInt) -> Value {
+    u64::try_from(value).map(Value::from).unwrap_or_else(|_| Value::String(value.to_string()))
+}
+
+/// Returns the values of the memory cells in the range `[start, end)`.
+fn read_memory(memory: &[Option<BigInt>], start: usize, end: usize) -> Vec<BigInt> {
+    memory[start..end].iter().cloned().map(|cell| cell.unwrap()).collect()
+}
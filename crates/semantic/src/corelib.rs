@@ -8,6 +8,7 @@ use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind;
 use crate::expr::compute::ComputationContext;
 use crate::items::enm::SemanticEnumEx;
+use crate::items::imp::{find_impls_at_context, ConcreteImplLongId, ImplLookupContext};
 use crate::items::trt::ConcreteTraitId;
 use crate::resolve_path::ResolvedGenericItem;
 use crate::types::ConcreteEnumLongId;
@@ -29,6 +30,10 @@ pub fn core_nonzero_ty(db: &dyn SemanticGroup, inner_type: TypeId) -> TypeId {
     get_core_ty_by_name(db, "NonZero".into(), vec![GenericArgumentId::Type(inner_type)])
 }
 
+pub fn core_byte_array_ty(db: &dyn SemanticGroup) -> TypeId {
+    get_core_ty_by_name(db, "ByteArray".into(), vec![])
+}
+
 pub fn try_get_core_ty_by_name(
     db: &dyn SemanticGroup,
     name: SmolStr,
@@ -234,92 +239,131 @@ pub fn unit_expr(ctx: &mut ComputationContext<'_>, stable_ptr: ast::ExprPtr) ->
     }))
 }
 
+/// Returns the function implementing a unary operator for an operand of type `ty`. This is the
+/// function of the corresponding corelib trait (e.g. `Neg::neg` for `-`), in the impl of that
+/// trait for `ty`.
 pub fn core_unary_operator(
     db: &dyn SemanticGroup,
+    impl_lookup_context: &ImplLookupContext,
     unary_op: &UnaryOperator,
     ty: TypeId,
 ) -> Result<FunctionId, SemanticDiagnosticKind> {
-    let felt_ty = core_felt_ty(db);
-    let bool_ty = core_bool_ty(db);
-    let unsupported_operator =
-        |op: &str| Err(SemanticDiagnosticKind::UnsupportedUnaryOperator { op: op.into(), ty });
-
-    let function_name = match unary_op {
-        UnaryOperator::Minus(_) if ty == felt_ty => "felt_neg",
-        UnaryOperator::Minus(_) => return unsupported_operator("-"),
-
-        UnaryOperator::Not(_) if ty == bool_ty => "bool_not",
-        UnaryOperator::Not(_) => return unsupported_operator("!"),
+    let (trait_name, function_name, op) = match unary_op {
+        UnaryOperator::Minus(_) => ("Neg", "neg", "-"),
+        UnaryOperator::Not(_) => ("Not", "not", "!"),
     };
-    Ok(get_core_function_id(db, function_name.into(), vec![]))
+    get_core_trait_impl_function(db, impl_lookup_context, trait_name, function_name, ty)
+        .ok_or_else(|| SemanticDiagnosticKind::UnsupportedUnaryOperator { op: op.into(), ty })
 }
 
+/// Returns the function implementing a binary operator for operands of types `type1` and `type2`.
+/// This is the function of the corresponding corelib trait (e.g. `Add::add` for `+`), in the impl
+/// of that trait for the type of the operands. Both operands must be of the same type.
 pub fn core_binary_operator(
     db: &dyn SemanticGroup,
+    impl_lookup_context: &ImplLookupContext,
     binary_op: &BinaryOperator,
     type1: TypeId,
     type2: TypeId,
 ) -> Result<FunctionId, SemanticDiagnosticKind> {
-    // TODO(lior): Replace current hard-coded implementation with an implementation that is based on
-    //   traits.
-    let felt_ty = core_felt_ty(db);
-    let uint128_ty = get_core_ty_by_name(db, "uint128".into(), vec![]);
-    let uint256_ty = get_core_ty_by_name(db, "uint256".into(), vec![]);
-    let bool_ty = core_bool_ty(db);
-    let unsupported_operator = |op: &str| {
-        Err(SemanticDiagnosticKind::UnsupportedBinaryOperator { op: op.into(), type1, type2 })
-    };
-    let function_name = match binary_op {
-        BinaryOperator::Plus(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_add",
-        BinaryOperator::Plus(_) if [type1, type2] == [uint128_ty, uint128_ty] => "uint128_add",
-        BinaryOperator::Plus(_) if [type1, type2] == [uint256_ty, uint256_ty] => "uint256_add",
-        BinaryOperator::Plus(_) => return unsupported_operator("+"),
-        BinaryOperator::Minus(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_sub",
-        BinaryOperator::Minus(_) if [type1, type2] == [uint128_ty, uint128_ty] => "uint128_sub",
-        BinaryOperator::Minus(_) if [type1, type2] == [uint256_ty, uint256_ty] => "uint256_sub",
-        BinaryOperator::Minus(_) => return unsupported_operator("-"),
-        BinaryOperator::Mul(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_mul",
-        BinaryOperator::Mul(_) if [type1, type2] == [uint128_ty, uint128_ty] => "uint128_mul",
-        BinaryOperator::Mul(_) if [type1, type2] == [uint256_ty, uint256_ty] => "uint256_mul",
-        BinaryOperator::Mul(_) => return unsupported_operator("*"),
-        BinaryOperator::Div(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_div",
-        BinaryOperator::Div(_) if [type1, type2] == [uint128_ty, uint128_ty] => "uint128_div",
-        BinaryOperator::Div(_) => return unsupported_operator("/"),
-        BinaryOperator::Mod(_) if [type1, type2] == [uint128_ty, uint128_ty] => "uint128_mod",
-        BinaryOperator::Mod(_) => return unsupported_operator("%"),
-        BinaryOperator::EqEq(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_eq",
-        BinaryOperator::EqEq(_) if [type1, type2] == [bool_ty, bool_ty] => "bool_eq",
-        BinaryOperator::EqEq(_) if [type1, type2] == [uint128_ty, uint128_ty] => "uint128_eq",
-        BinaryOperator::EqEq(_) if [type1, type2] == [uint256_ty, uint256_ty] => "uint256_eq",
-        BinaryOperator::EqEq(_) => return unsupported_operator("=="),
-        BinaryOperator::Neq(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_ne",
-        BinaryOperator::Neq(_) if [type1, type2] == [bool_ty, bool_ty] => "bool_ne",
-        BinaryOperator::Neq(_) if [type1, type2] == [uint128_ty, uint128_ty] => "uint128_ne",
-        BinaryOperator::Neq(_) if [type1, type2] == [uint256_ty, uint256_ty] => "uint256_ne",
-        BinaryOperator::Neq(_) => return unsupported_operator("!="),
-        BinaryOperator::And(_) if [type1, type2] == [bool_ty, bool_ty] => "bool_and",
-        BinaryOperator::And(_) => return unsupported_operator("&"),
-        BinaryOperator::Or(_) if [type1, type2] == [bool_ty, bool_ty] => "bool_or",
-        BinaryOperator::Or(_) => return unsupported_operator("|"),
-        BinaryOperator::LE(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_le",
-        BinaryOperator::LE(_) if [type1, type2] == [uint128_ty, uint128_ty] => "uint128_le",
-        BinaryOperator::LE(_) => return unsupported_operator("<="),
-        BinaryOperator::GE(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_ge",
-        BinaryOperator::GE(_) if [type1, type2] == [uint128_ty, uint128_ty] => "uint128_ge",
-        BinaryOperator::GE(_) => return unsupported_operator(">="),
-        BinaryOperator::LT(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_lt",
-        BinaryOperator::LT(_) if [type1, type2] == [uint128_ty, uint128_ty] => "uint128_lt",
-        BinaryOperator::LT(_) => return unsupported_operator("<"),
-        BinaryOperator::GT(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_gt",
-        BinaryOperator::GT(_) if [type1, type2] == [uint128_ty, uint128_ty] => "uint128_gt",
-        BinaryOperator::GT(_) => return unsupported_operator(">"),
+    let (trait_name, function_name, op) = match binary_op {
+        BinaryOperator::Plus(_) => ("Add", "add", "+"),
+        BinaryOperator::Minus(_) => ("Sub", "sub", "-"),
+        BinaryOperator::Mul(_) => ("Mul", "mul", "*"),
+        BinaryOperator::Div(_) => ("Div", "div", "/"),
+        BinaryOperator::Mod(_) => ("Rem", "rem", "%"),
+        BinaryOperator::EqEq(_) => ("PartialEq", "eq", "=="),
+        BinaryOperator::Neq(_) => ("PartialEq", "ne", "!="),
+        BinaryOperator::And(_) => ("BitAnd", "bitand", "&"),
+        BinaryOperator::Or(_) => ("BitOr", "bitor", "|"),
+        BinaryOperator::LE(_) => ("PartialOrd", "le", "<="),
+        BinaryOperator::GE(_) => ("PartialOrd", "ge", ">="),
+        BinaryOperator::LT(_) => ("PartialOrd", "lt", "<"),
+        BinaryOperator::GT(_) => ("PartialOrd", "gt", ">"),
         _ => return Err(SemanticDiagnosticKind::UnknownBinaryOperator),
     };
-    Ok(get_core_function_id(db, function_name.into(), vec![]))
+    let unsupported_operator =
+        || SemanticDiagnosticKind::UnsupportedBinaryOperator { op: op.into(), type1, type2 };
+    if type1 != type2 {
+        return Err(unsupported_operator());
+    }
+    get_core_trait_impl_function(db, impl_lookup_context, trait_name, function_name, type1)
+        .ok_or_else(unsupported_operator)
+}
+
+/// Returns the function `function_name` of the impl of the corelib trait `trait_name` for `ty`,
+/// found in the given lookup context. If `ty` is a generic param bounded by the trait, returns the
+/// trait function itself. Returns None if there is no such impl, or if it is ambiguous.
+fn get_core_trait_impl_function(
+    db: &dyn SemanticGroup,
+    impl_lookup_context: &ImplLookupContext,
+    trait_name: &str,
+    function_name: &str,
+    ty: TypeId,
+) -> Option<FunctionId> {
+    let concrete_trait_id =
+        get_core_concrete_trait(db, trait_name.into(), vec![GenericArgumentId::Type(ty)]);
+    let concrete_impl_id =
+        match find_impls_at_context(db, impl_lookup_context, concrete_trait_id)?[..] {
+            [concrete_impl_id] => concrete_impl_id,
+            [] => {
+                return get_bounded_trait_function(
+                    db,
+                    impl_lookup_context,
+                    concrete_trait_id,
+                    function_name,
+                );
+            }
+            _ => return None,
+        };
+    let ConcreteImplLongId { impl_id, generic_args } =
+        db.lookup_intern_concrete_impl(concrete_impl_id);
+    let impl_function_id = db
+        .impl_functions(impl_id)?
+        .into_iter()
+        .find(|impl_function_id| impl_function_id.name(db.upcast()) == function_name)?;
+    Some(db.intern_function(FunctionLongId {
+        function: ConcreteFunction {
+            generic_function: GenericFunctionId::ImplFunction(impl_function_id),
+            generic_args,
+        },
+    }))
+}
+
+/// Returns the function `function_name` of a concrete trait that bounds one of the generic params
+/// of the lookup context, if there is one.
+fn get_bounded_trait_function(
+    db: &dyn SemanticGroup,
+    impl_lookup_context: &ImplLookupContext,
+    concrete_trait_id: ConcreteTraitId,
+    function_name: &str,
+) -> Option<FunctionId> {
+    let is_bound = impl_lookup_context.generic_params.iter().any(|generic_param| {
+        db.generic_param_bounds(*generic_param).unwrap_or_default().contains(&concrete_trait_id)
+    });
+    if !is_bound {
+        return None;
+    }
+    let semantic::ConcreteTraitLongId { trait_id, generic_args } =
+        db.lookup_intern_concrete_trait(concrete_trait_id);
+    let trait_function_id = *db.trait_functions(trait_id)?.get(function_name)?;
+    Some(db.intern_function(FunctionLongId {
+        function: ConcreteFunction {
+            generic_function: GenericFunctionId::TraitFunction(trait_function_id),
+            generic_args,
+        },
+    }))
 }
 
+/// Returns the function implementing `==` for felts.
 pub fn felt_eq(db: &dyn SemanticGroup) -> FunctionId {
-    get_core_function_id(db, "felt_eq".into(), vec![])
+    let impl_lookup_context = ImplLookupContext {
+        module_id: db.core_module(),
+        extra_modules: vec![],
+        generic_params: vec![],
+    };
+    get_core_trait_impl_function(db, &impl_lookup_context, "PartialEq", "eq", core_felt_ty(db))
+        .expect("PartialEq for felt was not found in core lib.")
 }
 
 pub fn felt_sub(db: &dyn SemanticGroup) -> FunctionId {
@@ -1,16 +1,17 @@
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use db_utils::Upcast;
 use defs::db::DefsGroup;
 use defs::diagnostic_utils::StableLocation;
 use defs::ids::{
-    EnumId, ExternFunctionId, ExternTypeId, FreeFunctionId, GenericFunctionId, GenericParamId,
-    GenericTypeId, ImplFunctionId, ImplId, LanguageElementId, LookupItemId, ModuleId, ModuleItemId,
-    StructId, TraitFunctionId, TraitId, UseId, VariantId,
+    ConstantId, EnumId, ExternFunctionId, ExternTypeId, FreeFunctionId, FunctionWithBodyId,
+    GenericFunctionId, GenericParamId, GenericTypeId, ImplFunctionId, ImplId, LanguageElementId,
+    LookupItemId, ModuleId, ModuleItemId, StructId, TraitFunctionId, TraitId, UseId, VariantId,
 };
 use diagnostics::{Diagnostics, DiagnosticsBuilder};
 use filesystem::db::{AsFilesGroupMut, FilesGroup};
-use filesystem::ids::FileId;
+use filesystem::ids::{CrateId, FileId};
 use parser::db::ParserGroup;
 use smol_str::SmolStr;
 use syntax::node::ast;
@@ -22,7 +23,7 @@ use crate::items::imp::{ConcreteImplId, ImplLookupContext};
 use crate::items::trt::ConcreteTraitId;
 use crate::resolve_path::{ResolvedConcreteItem, ResolvedGenericItem, ResolvedLookback};
 use crate::{
-    corelib, items, literals, semantic, types, FreeFunctionDefinition, FunctionId, Parameter,
+    corelib, items, lints, literals, semantic, types, FunctionDefinition, FunctionId, Parameter,
     SemanticDiagnostic, TypeId,
 };
 
@@ -77,6 +78,43 @@ pub trait SemanticGroup:
     #[salsa::invoke(items::us::use_resolved_lookback)]
     fn use_resolved_lookback(&self, use_id: UseId) -> Option<Arc<ResolvedLookback>>;
 
+    // Constant.
+    // =========
+    /// Private query to compute data about a constant.
+    #[salsa::invoke(items::constant::priv_constant_semantic_data)]
+    #[salsa::cycle(items::constant::priv_constant_semantic_data_cycle)]
+    fn priv_constant_semantic_data(
+        &self,
+        constant_id: ConstantId,
+    ) -> Option<items::constant::ConstantData>;
+    /// Returns the semantic diagnostics of a constant.
+    #[salsa::invoke(items::constant::constant_semantic_diagnostics)]
+    fn constant_semantic_diagnostics(
+        &self,
+        constant_id: ConstantId,
+    ) -> Diagnostics<SemanticDiagnostic>;
+    /// Returns the type and the compile-time value of a constant.
+    #[salsa::invoke(items::constant::constant_semantic_data)]
+    fn constant_semantic_data(&self, constant_id: ConstantId) -> Option<items::constant::Constant>;
+    /// Returns the resolution lookback of a constant.
+    #[salsa::invoke(items::constant::constant_resolved_lookback)]
+    fn constant_resolved_lookback(&self, constant_id: ConstantId) -> Option<Arc<ResolvedLookback>>;
+
+    // Generic param.
+    // ==============
+    /// Private query to compute data about a generic param.
+    #[salsa::invoke(items::generics::priv_generic_param_data)]
+    fn priv_generic_param_data(
+        &self,
+        generic_param_id: GenericParamId,
+    ) -> Option<items::generics::GenericParamData>;
+    /// Returns the trait bounds of a generic param.
+    #[salsa::invoke(items::generics::generic_param_bounds)]
+    fn generic_param_bounds(
+        &self,
+        generic_param_id: GenericParamId,
+    ) -> Option<Vec<ConcreteTraitId>>;
+
     // Struct.
     // =======
     /// Private query to compute data about a struct.
@@ -228,12 +266,16 @@ pub trait SemanticGroup:
         &self,
         impl_function_id: ImplFunctionId,
     ) -> Option<semantic::Signature>;
-    /// Returns the generic params of a impl function.
+    /// Returns the generic params of a impl function, starting with the generic params of its
+    /// impl.
     #[salsa::invoke(items::imp::impl_function_generic_params)]
     fn impl_function_generic_params(
         &self,
         impl_function_id: ImplFunctionId,
     ) -> Option<Vec<GenericParamId>>;
+    /// Returns the attributes of an impl function.
+    #[salsa::invoke(items::imp::impl_function_attributes)]
+    fn impl_function_attributes(&self, impl_function_id: ImplFunctionId) -> Option<Vec<Attribute>>;
     /// Returns the semantic diagnostics of a impl function declaration -
     /// its signature excluding its body.
     #[salsa::invoke(items::imp::impl_function_declaration_diagnostics)]
@@ -253,6 +295,24 @@ pub trait SemanticGroup:
         &self,
         impl_function_id: ImplFunctionId,
     ) -> Option<items::imp::ImplFunctionDeclarationData>;
+    /// Returns the semantic diagnostics of an impl function definition - its body.
+    #[salsa::invoke(items::imp::impl_function_definition_diagnostics)]
+    fn impl_function_definition_diagnostics(
+        &self,
+        impl_function_id: ImplFunctionId,
+    ) -> Diagnostics<SemanticDiagnostic>;
+    /// Returns the resolution lookback of an impl function definition.
+    #[salsa::invoke(items::imp::impl_function_definition_resolved_lookback)]
+    fn impl_function_definition_resolved_lookback(
+        &self,
+        impl_function_id: ImplFunctionId,
+    ) -> Option<Arc<ResolvedLookback>>;
+    /// Private query to compute data about an impl function definition - its body.
+    #[salsa::invoke(items::imp::priv_impl_function_definition_data)]
+    fn priv_impl_function_definition_data(
+        &self,
+        impl_function_id: ImplFunctionId,
+    ) -> Option<items::function_with_body::FunctionDefinitionData>;
 
     // Free function.
     // ==============
@@ -306,7 +366,7 @@ pub trait SemanticGroup:
     fn priv_free_function_definition_data(
         &self,
         free_function_id: FreeFunctionId,
-    ) -> Option<items::free_function::FreeFunctionDefinitionData>;
+    ) -> Option<items::function_with_body::FunctionDefinitionData>;
     /// Returns the semantic diagnostics of a function definition - its body.
     #[salsa::invoke(items::free_function::free_function_definition_diagnostics)]
     fn free_function_definition_diagnostics(
@@ -319,26 +379,6 @@ pub trait SemanticGroup:
         &self,
         free_function_id: FreeFunctionId,
     ) -> Option<semantic::ExprId>;
-    /// Returns the direct callees of a free function definition. The items in the vector are
-    /// unique.
-    #[salsa::invoke(items::free_function::free_function_definition_direct_callees)]
-    fn free_function_definition_direct_callees(
-        &self,
-        free_function_id: FreeFunctionId,
-    ) -> Option<Vec<FunctionId>>;
-    /// Returns the free function direct callees of a free function definition (i.e. excluding
-    /// libfunc callees). The items in the vector are unique.
-    #[salsa::invoke(items::free_function::free_function_definition_direct_free_function_callees)]
-    fn free_function_definition_direct_free_function_callees(
-        &self,
-        free_function_id: FreeFunctionId,
-    ) -> Option<Vec<FreeFunctionId>>;
-    /// Returns the definition of a free function.
-    #[salsa::invoke(items::free_function::free_function_definition)]
-    fn free_function_definition(
-        &self,
-        free_function_id: FreeFunctionId,
-    ) -> Option<Arc<FreeFunctionDefinition>>;
     /// Returns the resolution lookback of a free function.
     #[salsa::invoke(items::free_function::free_function_definition_resolved_lookback)]
     fn free_function_definition_resolved_lookback(
@@ -346,6 +386,42 @@ pub trait SemanticGroup:
         free_function_id: FreeFunctionId,
     ) -> Option<Arc<ResolvedLookback>>;
 
+    // Function with body.
+    // ==================
+    /// Returns the signature of a function with a body.
+    #[salsa::invoke(items::function_with_body::function_with_body_signature)]
+    fn function_with_body_signature(
+        &self,
+        function_id: FunctionWithBodyId,
+    ) -> Option<semantic::Signature>;
+    /// Returns the generic params of a function with a body.
+    #[salsa::invoke(items::function_with_body::function_with_body_generic_params)]
+    fn function_with_body_generic_params(
+        &self,
+        function_id: FunctionWithBodyId,
+    ) -> Option<Vec<GenericParamId>>;
+    /// Returns the definition of a function with a body.
+    #[salsa::invoke(items::function_with_body::function_with_body_definition)]
+    fn function_with_body_definition(
+        &self,
+        function_id: FunctionWithBodyId,
+    ) -> Option<Arc<FunctionDefinition>>;
+    /// Returns the direct callees of a function with a body. The items in the vector are unique.
+    #[salsa::invoke(items::function_with_body::function_with_body_direct_callees)]
+    fn function_with_body_direct_callees(
+        &self,
+        function_id: FunctionWithBodyId,
+    ) -> Option<Vec<FunctionId>>;
+    /// Returns the direct callees of a function with a body that have a body as well (i.e.
+    /// excluding libfunc callees). The items in the vector are unique.
+    #[salsa::invoke(
+        items::function_with_body::function_with_body_direct_function_with_body_callees
+    )]
+    fn function_with_body_direct_function_with_body_callees(
+        &self,
+        function_id: FunctionWithBodyId,
+    ) -> Option<Vec<FunctionWithBodyId>>;
+
     // Extern function.
     // ================
     /// Private query to compute data about an extern function declaration. An extern function has
@@ -464,16 +540,16 @@ pub trait SemanticGroup:
 
     // Expression.
     // ===========
-    #[salsa::invoke(items::free_function::expr_semantic)]
+    #[salsa::invoke(items::function_with_body::expr_semantic)]
     fn expr_semantic(
         &self,
-        free_function_id: FreeFunctionId,
+        function_id: FunctionWithBodyId,
         id: semantic::ExprId,
     ) -> semantic::Expr;
-    #[salsa::invoke(items::free_function::statement_semantic)]
+    #[salsa::invoke(items::function_with_body::statement_semantic)]
     fn statement_semantic(
         &self,
-        free_function_id: FreeFunctionId,
+        function_id: FunctionWithBodyId,
         id: semantic::StatementId,
     ) -> semantic::Statement;
 
@@ -502,6 +578,22 @@ pub trait SemanticGroup:
     fn file_semantic_diagnostics(&self, file_id: FileId)
     -> Option<Diagnostics<SemanticDiagnostic>>;
 
+    // Lints.
+    // ======
+    /// Returns the lint warnings of a module. See [crate::lints].
+    #[salsa::invoke(lints::module_lint_diagnostics)]
+    fn module_lint_diagnostics(
+        &self,
+        module_id: ModuleId,
+    ) -> Option<Diagnostics<SemanticDiagnostic>>;
+    /// Aggregates file level lint warnings.
+    #[salsa::invoke(lints::file_lint_diagnostics)]
+    fn file_lint_diagnostics(&self, file_id: FileId) -> Option<Diagnostics<SemanticDiagnostic>>;
+    /// Returns the free functions called by the functions of a crate, excluding recursive calls of
+    /// a function to itself. Used by the dead code lint.
+    #[salsa::invoke(lints::crate_called_functions)]
+    fn crate_called_functions(&self, crate_id: CrateId) -> Arc<HashSet<FreeFunctionId>>;
+
     // Corelib.
     // ========
     #[salsa::invoke(corelib::core_module)]
@@ -531,6 +623,9 @@ fn module_semantic_diagnostics(
                 diagnostics.extend(db.free_function_declaration_diagnostics(*free_function));
                 diagnostics.extend(db.free_function_definition_diagnostics(*free_function));
             }
+            ModuleItemId::Constant(constant_id) => {
+                diagnostics.extend(db.constant_semantic_diagnostics(*constant_id));
+            }
             ModuleItemId::Struct(struct_id) => {
                 diagnostics.extend(db.struct_semantic_diagnostics(*struct_id));
             }
@@ -600,7 +695,10 @@ pub fn lookup_resolved_concrete_item_by_ptr(
         .find_map(|resolver_lookback| resolver_lookback.concrete.get(&ptr).cloned())
 }
 
-fn get_resolver_lookbacks(id: LookupItemId, db: &dyn SemanticGroup) -> Vec<Arc<ResolvedLookback>> {
+pub(crate) fn get_resolver_lookbacks(
+    id: LookupItemId,
+    db: &dyn SemanticGroup,
+) -> Vec<Arc<ResolvedLookback>> {
     match id {
         LookupItemId::ModuleItem(module_item) => match module_item {
             ModuleItemId::Submodule(_) => vec![],
@@ -609,6 +707,7 @@ fn get_resolver_lookbacks(id: LookupItemId, db: &dyn SemanticGroup) -> Vec<Arc<R
                 db.free_function_declaration_resolved_lookback(id),
                 db.free_function_definition_resolved_lookback(id),
             ],
+            ModuleItemId::Constant(id) => vec![db.constant_resolved_lookback(id)],
             ModuleItemId::Struct(id) => vec![db.struct_resolved_lookback(id)],
             ModuleItemId::Enum(id) => vec![db.enum_resolved_lookback(id)],
             ModuleItemId::Trait(_) => vec![],
@@ -618,7 +717,10 @@ fn get_resolver_lookbacks(id: LookupItemId, db: &dyn SemanticGroup) -> Vec<Arc<R
                 vec![db.extern_function_declaration_resolved_lookback(id)]
             }
         },
-        LookupItemId::ImplFunction(_) => vec![],
+        LookupItemId::ImplFunction(id) => vec![
+            db.impl_function_resolved_lookback(id),
+            db.impl_function_definition_resolved_lookback(id),
+        ],
     }
     .into_iter()
     .flatten()
@@ -5,16 +5,20 @@ mod test;
 use defs::db::PluginDiagnostic;
 use defs::diagnostic_utils::StableLocation;
 use defs::ids::{
-    EnumId, GenericFunctionId, ImplFunctionId, ImplId, ModuleFileId, StructId,
+    EnumId, GenericFunctionId, ImplFunctionId, ImplId, LanguageElementId, ModuleFileId, StructId,
     TopLevelLanguageElementId, TraitFunctionId, TraitId,
 };
-use diagnostics::{DiagnosticEntry, DiagnosticLocation, Diagnostics, DiagnosticsBuilder};
+use diagnostics::{
+    DiagnosticEntry, DiagnosticLocation, Diagnostics, DiagnosticsBuilder, RelatedLocation, Severity,
+};
+use itertools::Itertools;
 use smol_str::SmolStr;
 use syntax::node::ids::SyntaxStablePtrId;
 use syntax::node::TypedSyntaxNode;
 
 use crate::db::SemanticGroup;
-use crate::semantic;
+use crate::items::trt::ConcreteTraitId;
+use crate::{lints, semantic};
 
 pub struct SemanticDiagnostics {
     pub diagnostics: DiagnosticsBuilder<SemanticDiagnostic>,
@@ -54,6 +58,15 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::FileNotFound => "File not found.".into(),
             SemanticDiagnosticKind::Unsupported => "Unsupported feature.".into(),
             SemanticDiagnosticKind::UnknownLiteral => "Unknown literal.".into(),
+            SemanticDiagnosticKind::IllegalStringEscaping => {
+                "Invalid escape sequence in string literal.".into()
+            }
+            SemanticDiagnosticKind::ShortStringMustBeAscii => {
+                "Short string literals can only contain ASCII characters.".into()
+            }
+            SemanticDiagnosticKind::ShortStringTooLong => {
+                "Short string literal does not fit in a felt (at most 31 characters).".into()
+            }
             SemanticDiagnosticKind::UnsupportedUnaryOperator { op, ty } => {
                 format!("Unary operator '{op}' is not supported for type '{}'.", ty.format(db),)
             }
@@ -267,12 +280,43 @@ impl DiagnosticEntry for SemanticDiagnostic {
                     struct_id.full_path(db.upcast())
                 )
             }
+            SemanticDiagnosticKind::NoSuchMethod { ty, method_name } => {
+                format!(r#"Method "{method_name}" not found on type "{}"."#, ty.format(db))
+            }
+            SemanticDiagnosticKind::AmbiguousMethod { ty, method_name, impl_functions } => {
+                format!(
+                    r#"Ambiguous method "{method_name}" on type "{}". Candidates: {}."#,
+                    ty.format(db),
+                    impl_functions
+                        .iter()
+                        .map(|impl_function_id| GenericFunctionId::ImplFunction(*impl_function_id)
+                            .format(db.upcast()))
+                        .join(", ")
+                )
+            }
+            SemanticDiagnosticKind::NoImplForTrait { concrete_trait_id } => {
+                format!(r#"No impl of trait "{}" found."#, concrete_trait_id.format(db))
+            }
+            SemanticDiagnosticKind::AmbiguousImpls { concrete_trait_id, impls } => {
+                format!(
+                    r#"Multiple impls of trait "{}" found. Candidates: {}."#,
+                    concrete_trait_id.format(db),
+                    impls.iter().map(|impl_id| impl_id.full_path(db.upcast())).join(", ")
+                )
+            }
             SemanticDiagnosticKind::NoSuchVariant { enum_id, variant_name } => {
                 format!(
                     r#"Enum "{}" has no variant "{variant_name}""#,
                     enum_id.full_path(db.upcast())
                 )
             }
+            SemanticDiagnosticKind::MissingMatchArms { enum_id, missing_variants } => {
+                format!(
+                    r#"Match on enum "{}" is missing arms for the variants: {}"#,
+                    enum_id.full_path(db.upcast()),
+                    missing_variants.iter().map(|variant| format!(r#""{variant}""#)).join(", ")
+                )
+            }
             SemanticDiagnosticKind::IncompatibleErrorPropagateType { return_ty, err_ty } => {
                 format!(
                     r#"Return type "{}" does not wrap error "{}""#,
@@ -335,6 +379,41 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::InvalidDropTraitImpl => {
                 "Invalid drop trait implementation.".into()
             }
+            SemanticDiagnosticKind::OverlappingImpls { impl_id, other_impl_id } => {
+                let defs_db = db.upcast();
+                format!(
+                    "Impl `{}` overlaps with impl `{}` for the same trait.",
+                    impl_id.full_path(defs_db),
+                    other_impl_id.full_path(defs_db)
+                )
+            }
+            SemanticDiagnosticKind::ConstantCycle => {
+                "Cycle detected while evaluating the value of a constant.".into()
+            }
+            SemanticDiagnosticKind::NotAConstant => "Expected a constant.".into(),
+            SemanticDiagnosticKind::UnsupportedConstantType { ty } => {
+                format!(
+                    "Constants of type '{}' are not supported. Only 'felt' and 'uint128' \
+                     constants are supported.",
+                    ty.format(db)
+                )
+            }
+            SemanticDiagnosticKind::UnsupportedConstantExpression => {
+                "Only literals, constants and arithmetic operators are supported in the value of a \
+                 constant."
+                    .into()
+            }
+            SemanticDiagnosticKind::WrongConstantType { expected_ty, actual_ty } => {
+                format!(
+                    r#"Unexpected constant type. Expected: "{}", found: "{}"."#,
+                    expected_ty.format(db),
+                    actual_ty.format(db)
+                )
+            }
+            SemanticDiagnosticKind::ConstantOverflow { ty } => {
+                format!("The value of the constant is out of range for type '{}'.", ty.format(db))
+            }
+            SemanticDiagnosticKind::DivisionByZero => "Division by zero.".into(),
             SemanticDiagnosticKind::InvalidImplItem { item_kw } => {
                 format!("`{}` is not allowed inside impl.", item_kw)
             }
@@ -352,22 +431,165 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::PanicableExternFunction => {
                 "An extern function must be marked as nopanic.".into()
             }
+            SemanticDiagnosticKind::LoopInNoPanicFunction => {
+                "Loops are not allowed in nopanic functions.".into()
+            }
+            SemanticDiagnosticKind::BreakOutsideLoop => {
+                "`break` is only allowed inside a loop.".into()
+            }
+            SemanticDiagnosticKind::ContinueOutsideLoop => {
+                "`continue` is only allowed inside a loop.".into()
+            }
+            SemanticDiagnosticKind::BreakWithValueInWhile => {
+                "`break` with a value is only allowed inside `loop`.".into()
+            }
+            SemanticDiagnosticKind::IncompatibleLoopBreakTypes { current_ty, break_ty } => {
+                format!(
+                    r#"Loop has incompatible break types: "{}" and "{}""#,
+                    current_ty.format(db),
+                    break_ty.format(db),
+                )
+            }
+            SemanticDiagnosticKind::ReturnInsideLoop => {
+                "`return` is not supported inside a loop.".into()
+            }
+            SemanticDiagnosticKind::ErrorPropagateInsideLoop => {
+                "The `?` operator is not supported inside a loop.".into()
+            }
             SemanticDiagnosticKind::PluginDiagnostic(diagnostic) => {
                 format!("Plugin diagnostic: {}", diagnostic.message)
             }
+            SemanticDiagnosticKind::UnusedVariable { name } => {
+                format!(r#"Unused variable "{name}"."#)
+            }
+            SemanticDiagnosticKind::UnusedParameter { name } => {
+                format!(r#"Unused parameter "{name}"."#)
+            }
+            SemanticDiagnosticKind::UnusedUse { name } => format!(r#"Unused use "{name}"."#),
+            SemanticDiagnosticKind::UnreachableMatchArm => "Unreachable match arm.".into(),
+            SemanticDiagnosticKind::UnusedFunction { name } => {
+                format!(r#"Function "{name}" is never used."#)
+            }
         }
     }
 
     fn location(&self, db: &Self::DbType) -> DiagnosticLocation {
         self.stable_location.diagnostic_location(db.upcast())
     }
+
+    fn code(&self) -> &'static str {
+        self.kind.code()
+    }
+
+    fn severity(&self) -> Severity {
+        if self.kind.lint().is_some() { Severity::Warning } else { Severity::Error }
+    }
+
+    fn related(&self, db: &Self::DbType) -> Vec<RelatedLocation> {
+        match &self.kind {
+            SemanticDiagnosticKind::FunctionNotMemberOfTrait { trait_id, .. } => {
+                vec![RelatedLocation {
+                    location: element_location(db, trait_id),
+                    label: "The trait is defined here.".into(),
+                }]
+            }
+            SemanticDiagnosticKind::WrongNumberOfParameters {
+                impl_function_id, trait_id, ..
+            }
+            | SemanticDiagnosticKind::WrongParameterType { impl_function_id, trait_id, .. }
+            | SemanticDiagnosticKind::ParamaterShouldBeReference {
+                impl_function_id,
+                trait_id,
+                ..
+            }
+            | SemanticDiagnosticKind::ParamaterShouldNotBeReference {
+                impl_function_id,
+                trait_id,
+                ..
+            }
+            | SemanticDiagnosticKind::WrongReturnTypeForImpl {
+                impl_function_id, trait_id, ..
+            }
+            | SemanticDiagnosticKind::PassPanicAsNonpanic { impl_function_id, trait_id } => {
+                let name = impl_function_id.name(db.upcast());
+                let trait_functions = db.trait_functions(*trait_id).unwrap_or_default();
+                let location = match trait_functions.get(&name) {
+                    Some(trait_function_id) => element_location(db, trait_function_id),
+                    None => element_location(db, trait_id),
+                };
+                vec![RelatedLocation {
+                    location,
+                    label: "The trait function is declared here.".into(),
+                }]
+            }
+            SemanticDiagnosticKind::AmbiguousMethod { impl_functions, .. } => impl_functions
+                .iter()
+                .map(|impl_function_id| RelatedLocation {
+                    location: element_location(db, impl_function_id),
+                    label: "A candidate is defined here.".into(),
+                })
+                .collect(),
+            SemanticDiagnosticKind::AmbiguousImpls { impls, .. } => impls
+                .iter()
+                .map(|impl_id| RelatedLocation {
+                    location: element_location(db, impl_id),
+                    label: "A candidate is defined here.".into(),
+                })
+                .collect(),
+            SemanticDiagnosticKind::OverlappingImpls { other_impl_id, .. } => {
+                vec![RelatedLocation {
+                    location: element_location(db, other_impl_id),
+                    label: "The other impl is defined here.".into(),
+                }]
+            }
+            _ => vec![],
+        }
+    }
+
+    fn help(&self, _db: &Self::DbType) -> Option<String> {
+        match &self.kind {
+            SemanticDiagnosticKind::RefArgNotMutable
+            | SemanticDiagnosticKind::AssignmentToImmutableVar => {
+                Some("Declare the variable as mutable, e.g. `let mut x = ...`.".into())
+            }
+            SemanticDiagnosticKind::MissingMatchArms { .. } => {
+                Some("Add an arm for each missing variant, or a wildcard arm `_ => ...`.".into())
+            }
+            SemanticDiagnosticKind::ShortStringTooLong => {
+                Some("Split the string into several short strings.".into())
+            }
+            SemanticDiagnosticKind::PanicableFromNonPanicable => {
+                Some("Remove the `nopanic` annotation, or call only nopanic functions.".into())
+            }
+            SemanticDiagnosticKind::UnusedVariable { .. }
+            | SemanticDiagnosticKind::UnusedParameter { .. } => {
+                Some("Prefix the name with an underscore if it is intentionally unused.".into())
+            }
+            _ => None,
+        }
+    }
 }
 
+/// Returns the location of the definition of a language element.
+fn element_location(
+    db: &dyn SemanticGroup,
+    element_id: &impl LanguageElementId,
+) -> DiagnosticLocation {
+    let defs_db = db.upcast();
+    StableLocation::new(element_id.module_file(defs_db), element_id.untyped_stable_ptr(defs_db))
+        .diagnostic_location(defs_db)
+}
+
+/// The kind of a semantic diagnostic. Each kind has a stable code, see
+/// [SemanticDiagnosticKind::code].
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum SemanticDiagnosticKind {
     FileNotFound,
     Unsupported,
     UnknownLiteral,
+    IllegalStringEscaping,
+    ShortStringMustBeAscii,
+    ShortStringTooLong,
     UnsupportedUnaryOperator {
         op: SmolStr,
         ty: semantic::TypeId,
@@ -484,10 +706,30 @@ pub enum SemanticDiagnosticKind {
         struct_id: StructId,
         member_name: SmolStr,
     },
+    NoSuchMethod {
+        ty: semantic::TypeId,
+        method_name: SmolStr,
+    },
+    AmbiguousMethod {
+        ty: semantic::TypeId,
+        method_name: SmolStr,
+        impl_functions: Vec<ImplFunctionId>,
+    },
+    NoImplForTrait {
+        concrete_trait_id: ConcreteTraitId,
+    },
+    AmbiguousImpls {
+        concrete_trait_id: ConcreteTraitId,
+        impls: Vec<ImplId>,
+    },
     NoSuchVariant {
         enum_id: EnumId,
         variant_name: SmolStr,
     },
+    MissingMatchArms {
+        enum_id: EnumId,
+        missing_variants: Vec<SmolStr>,
+    },
     IncompatibleErrorPropagateType {
         return_ty: semantic::TypeId,
         err_ty: semantic::TypeId,
@@ -525,6 +767,24 @@ pub enum SemanticDiagnosticKind {
     },
     InvalidCopyTraitImpl,
     InvalidDropTraitImpl,
+    OverlappingImpls {
+        impl_id: ImplId,
+        other_impl_id: ImplId,
+    },
+    ConstantCycle,
+    NotAConstant,
+    UnsupportedConstantType {
+        ty: semantic::TypeId,
+    },
+    UnsupportedConstantExpression,
+    WrongConstantType {
+        expected_ty: semantic::TypeId,
+        actual_ty: semantic::TypeId,
+    },
+    ConstantOverflow {
+        ty: semantic::TypeId,
+    },
+    DivisionByZero,
     InvalidImplItem {
         item_kw: SmolStr,
     },
@@ -534,5 +794,142 @@ pub enum SemanticDiagnosticKind {
     },
     PanicableFromNonPanicable,
     PanicableExternFunction,
+    LoopInNoPanicFunction,
+    BreakOutsideLoop,
+    ContinueOutsideLoop,
+    BreakWithValueInWhile,
+    IncompatibleLoopBreakTypes {
+        current_ty: semantic::TypeId,
+        break_ty: semantic::TypeId,
+    },
+    ReturnInsideLoop,
+    ErrorPropagateInsideLoop,
     PluginDiagnostic(PluginDiagnostic),
+    UnusedVariable {
+        name: SmolStr,
+    },
+    UnusedParameter {
+        name: SmolStr,
+    },
+    UnusedUse {
+        name: SmolStr,
+    },
+    UnreachableMatchArm,
+    UnusedFunction {
+        name: SmolStr,
+    },
+}
+impl SemanticDiagnosticKind {
+    /// Returns the stable code of the kind. Codes of removed kinds must not be reused.
+    pub fn code(&self) -> &'static str {
+        match self {
+            SemanticDiagnosticKind::FileNotFound => "S0001",
+            SemanticDiagnosticKind::Unsupported => "S0002",
+            SemanticDiagnosticKind::UnknownLiteral => "S0003",
+            SemanticDiagnosticKind::IllegalStringEscaping => "S0004",
+            SemanticDiagnosticKind::ShortStringMustBeAscii => "S0005",
+            SemanticDiagnosticKind::ShortStringTooLong => "S0006",
+            SemanticDiagnosticKind::UnsupportedUnaryOperator { .. } => "S0007",
+            SemanticDiagnosticKind::UnknownBinaryOperator => "S0008",
+            SemanticDiagnosticKind::UnsupportedBinaryOperator { .. } => "S0009",
+            SemanticDiagnosticKind::UnknownFunction => "S0010",
+            SemanticDiagnosticKind::UnknownTrait => "S0011",
+            SemanticDiagnosticKind::UnknownImpl => "S0012",
+            SemanticDiagnosticKind::NotAFunction => "S0013",
+            SemanticDiagnosticKind::UnknownType => "S0014",
+            SemanticDiagnosticKind::UnknownStruct => "S0015",
+            SemanticDiagnosticKind::UnknownEnum => "S0016",
+            SemanticDiagnosticKind::NotAVariant => "S0017",
+            SemanticDiagnosticKind::NotAStruct => "S0018",
+            SemanticDiagnosticKind::NotAType => "S0019",
+            SemanticDiagnosticKind::NotATrait => "S0020",
+            SemanticDiagnosticKind::FunctionNotMemberOfTrait { .. } => "S0021",
+            SemanticDiagnosticKind::UnexpectedGenericArgs => "S0022",
+            SemanticDiagnosticKind::UnknownMember => "S0023",
+            SemanticDiagnosticKind::MemberSpecifiedMoreThanOnce => "S0024",
+            SemanticDiagnosticKind::UseCycle => "S0025",
+            SemanticDiagnosticKind::ExpectedConcreteVariant => "S0026",
+            SemanticDiagnosticKind::MissingMember { .. } => "S0027",
+            SemanticDiagnosticKind::WrongNumberOfParameters { .. } => "S0028",
+            SemanticDiagnosticKind::WrongNumberOfArguments { .. } => "S0029",
+            SemanticDiagnosticKind::WrongNumberOfGenericArguments { .. } => "S0030",
+            SemanticDiagnosticKind::WrongParameterType { .. } => "S0031",
+            SemanticDiagnosticKind::TraitParamMutable { .. } => "S0032",
+            SemanticDiagnosticKind::ParamaterShouldBeReference { .. } => "S0033",
+            SemanticDiagnosticKind::ParamaterShouldNotBeReference { .. } => "S0034",
+            SemanticDiagnosticKind::WrongArgumentType { .. } => "S0035",
+            SemanticDiagnosticKind::WrongReturnType { .. } => "S0036",
+            SemanticDiagnosticKind::WrongReturnTypeForImpl { .. } => "S0037",
+            SemanticDiagnosticKind::VariableNotFound { .. } => "S0038",
+            SemanticDiagnosticKind::StructMemberRedefinition { .. } => "S0039",
+            SemanticDiagnosticKind::EnumVariantRedefinition { .. } => "S0040",
+            SemanticDiagnosticKind::ParamNameRedefinition { .. } => "S0041",
+            SemanticDiagnosticKind::IncompatibleMatchArms { .. } => "S0042",
+            SemanticDiagnosticKind::IncompatibleIfBlockTypes { .. } => "S0043",
+            SemanticDiagnosticKind::TypeHasNoMembers { .. } => "S0044",
+            SemanticDiagnosticKind::NoSuchMember { .. } => "S0045",
+            SemanticDiagnosticKind::NoSuchMethod { .. } => "S0046",
+            SemanticDiagnosticKind::AmbiguousMethod { .. } => "S0047",
+            SemanticDiagnosticKind::NoSuchVariant { .. } => "S0048",
+            SemanticDiagnosticKind::MissingMatchArms { .. } => "S0049",
+            SemanticDiagnosticKind::IncompatibleErrorPropagateType { .. } => "S0050",
+            SemanticDiagnosticKind::ErrorPropagateOnNonErrorType { .. } => "S0051",
+            SemanticDiagnosticKind::RefArgNotAVariable => "S0052",
+            SemanticDiagnosticKind::RefArgNotMutable => "S0053",
+            SemanticDiagnosticKind::AssignmentToImmutableVar => "S0054",
+            SemanticDiagnosticKind::InvalidLhsForAssignment => "S0055",
+            SemanticDiagnosticKind::InvalidMemberExpression => "S0056",
+            SemanticDiagnosticKind::InvalidPath => "S0057",
+            SemanticDiagnosticKind::PathNotFound => "S0058",
+            SemanticDiagnosticKind::RedundantModifier { .. } => "S0059",
+            SemanticDiagnosticKind::ReferenceLocalVariable => "S0060",
+            SemanticDiagnosticKind::UnexpectedLiteralPattern { .. } => "S0061",
+            SemanticDiagnosticKind::UnexpectedEnumPattern { .. } => "S0062",
+            SemanticDiagnosticKind::UnexpectedStructPattern { .. } => "S0063",
+            SemanticDiagnosticKind::UnexpectedTuplePattern { .. } => "S0064",
+            SemanticDiagnosticKind::WrongEnum { .. } => "S0065",
+            SemanticDiagnosticKind::InvalidCopyTraitImpl => "S0066",
+            SemanticDiagnosticKind::InvalidDropTraitImpl => "S0067",
+            SemanticDiagnosticKind::OverlappingImpls { .. } => "S0068",
+            SemanticDiagnosticKind::ConstantCycle => "S0069",
+            SemanticDiagnosticKind::NotAConstant => "S0070",
+            SemanticDiagnosticKind::UnsupportedConstantType { .. } => "S0071",
+            SemanticDiagnosticKind::UnsupportedConstantExpression => "S0072",
+            SemanticDiagnosticKind::WrongConstantType { .. } => "S0073",
+            SemanticDiagnosticKind::ConstantOverflow { .. } => "S0074",
+            SemanticDiagnosticKind::DivisionByZero => "S0075",
+            SemanticDiagnosticKind::InvalidImplItem { .. } => "S0076",
+            SemanticDiagnosticKind::PassPanicAsNonpanic { .. } => "S0077",
+            SemanticDiagnosticKind::PanicableFromNonPanicable => "S0078",
+            SemanticDiagnosticKind::PanicableExternFunction => "S0079",
+            SemanticDiagnosticKind::LoopInNoPanicFunction => "S0080",
+            SemanticDiagnosticKind::BreakOutsideLoop => "S0081",
+            SemanticDiagnosticKind::ContinueOutsideLoop => "S0082",
+            SemanticDiagnosticKind::BreakWithValueInWhile => "S0083",
+            SemanticDiagnosticKind::IncompatibleLoopBreakTypes { .. } => "S0084",
+            SemanticDiagnosticKind::ReturnInsideLoop => "S0085",
+            SemanticDiagnosticKind::ErrorPropagateInsideLoop => "S0086",
+            SemanticDiagnosticKind::PluginDiagnostic(_) => "S0087",
+            SemanticDiagnosticKind::UnusedVariable { .. } => "S0088",
+            SemanticDiagnosticKind::UnusedParameter { .. } => "S0089",
+            SemanticDiagnosticKind::UnusedUse { .. } => "S0090",
+            SemanticDiagnosticKind::UnreachableMatchArm => "S0091",
+            SemanticDiagnosticKind::UnusedFunction { .. } => "S0092",
+            SemanticDiagnosticKind::NoImplForTrait { .. } => "S0093",
+            SemanticDiagnosticKind::AmbiguousImpls { .. } => "S0094",
+        }
+    }
+
+    /// Returns the name of the lint reporting this kind, if it is a lint warning. See
+    /// [crate::lints].
+    pub fn lint(&self) -> Option<&'static str> {
+        match self {
+            SemanticDiagnosticKind::UnusedVariable { .. } => Some(lints::UNUSED_VARIABLES),
+            SemanticDiagnosticKind::UnusedParameter { .. } => Some(lints::UNUSED_PARAMETERS),
+            SemanticDiagnosticKind::UnusedUse { .. } => Some(lints::UNUSED_IMPORTS),
+            SemanticDiagnosticKind::UnreachableMatchArm => Some(lints::UNREACHABLE_PATTERNS),
+            SemanticDiagnosticKind::UnusedFunction { .. } => Some(lints::DEAD_CODE),
+            _ => None,
+        }
+    }
 }
@@ -5,15 +5,19 @@
 use std::collections::HashMap;
 
 use ast::{BinaryOperator, PathSegment};
-use defs::ids::{GenericFunctionId, LocalVarLongId, MemberId};
+use defs::ids::{
+    ConstantId, GenericFunctionId, ImplFunctionId, LocalVarLongId, MemberId, ModuleId,
+    ModuleItemId, TraitId,
+};
 use id_arena::Arena;
-use itertools::zip_eq;
+use itertools::{chain, zip_eq, Itertools};
 use smol_str::SmolStr;
 use syntax::node::ast::{BlockOrIf, PatternStructParam};
 use syntax::node::db::SyntaxGroup;
 use syntax::node::helpers::{GetIdentifier, PathSegmentEx};
 use syntax::node::{ast, Terminal, TypedSyntaxNode};
 use utils::ordered_hash_map::OrderedHashMap;
+use utils::ordered_hash_set::OrderedHashSet;
 use utils::unordered_hash_map::UnorderedHashMap;
 use utils::unordered_hash_set::UnorderedHashSet;
 use utils::{try_extract_matches, OptionHelper};
@@ -23,20 +27,24 @@ use super::pattern::{
     Pattern, PatternEnumVariant, PatternLiteral, PatternOtherwise, PatternTuple, PatternVariable,
 };
 use crate::corelib::{
-    core_binary_operator, core_felt_ty, core_unary_operator, false_literal_expr, never_ty,
-    true_literal_expr, try_get_core_ty_by_name, unit_ty, unwrap_error_propagation_type,
+    core_binary_operator, core_byte_array_ty, core_felt_ty, core_module, core_unary_operator,
+    false_literal_expr, never_ty, true_literal_expr, try_get_core_ty_by_name, unit_ty,
+    unwrap_error_propagation_type,
 };
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
 use crate::diagnostic::SemanticDiagnostics;
 use crate::items::enm::SemanticEnumEx;
+use crate::items::imp::{find_impls_at_context, ConcreteImplId, ImplLookupContext};
 use crate::items::modifiers::compute_mutability;
 use crate::items::strct::SemanticStructEx;
-use crate::literals::LiteralLongId;
-use crate::resolve_path::{ResolvedConcreteItem, ResolvedGenericItem, Resolver};
+use crate::literals::{short_string_value, unescape_quoted_literal, LiteralLongId};
+use crate::resolve_path::{
+    specialize_function, ResolvedConcreteItem, ResolvedGenericItem, Resolver,
+};
 use crate::semantic::{self, FunctionId, LocalVariable, TypeId, TypeLongId, Variable};
-use crate::types::{resolve_type, ConcreteTypeId};
-use crate::{Mutability, Parameter, PatternStruct, Signature};
+use crate::types::{infer_generic_args, resolve_type, ConcreteTypeId};
+use crate::{ConcreteTraitLongId, Mutability, Parameter, PatternStruct, Signature};
 
 /// Context for computing the semantic model of expression trees.
 pub struct ComputationContext<'ctx> {
@@ -49,6 +57,8 @@ pub struct ComputationContext<'ctx> {
     pub statements: Arena<semantic::Statement>,
     /// Definitions of semantic variables.
     pub semantic_defs: UnorderedHashMap<semantic::VarId, semantic::Variable>,
+    /// Context of the innermost loop whose body is currently computed, if any.
+    loop_ctx: Option<LoopContext>,
 }
 impl<'ctx> ComputationContext<'ctx> {
     pub fn new(
@@ -74,6 +84,7 @@ impl<'ctx> ComputationContext<'ctx> {
             exprs: Arena::default(),
             statements: Arena::default(),
             semantic_defs,
+            loop_ctx: None,
         }
     }
 
@@ -97,6 +108,30 @@ impl<'ctx> ComputationContext<'ctx> {
         self.environment = parent.unwrap();
         res
     }
+
+    /// Runs a function with a modified context, with a new loop context.
+    /// Used for the condition and body of loop expressions.
+    /// Returns the result of the function and the final loop context.
+    fn run_in_loop<T, F>(&mut self, is_while: bool, f: F) -> (T, LoopContext)
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let new_loop_ctx = LoopContext { is_while, break_types: FlowMergeTypeHelper::new(self.db) };
+        let old_loop_ctx = self.loop_ctx.replace(new_loop_ctx);
+
+        let res = f(self);
+
+        let loop_ctx = std::mem::replace(&mut self.loop_ctx, old_loop_ctx);
+        (res, loop_ctx.unwrap())
+    }
+}
+
+/// Context for computing the body of a loop.
+struct LoopContext {
+    /// Whether the loop is a `while` loop, in which `break` may not have a value.
+    is_while: bool,
+    /// Merges the types of the values the loop breaks with.
+    break_types: FlowMergeTypeHelper,
 }
 
 // TODO(ilya): Change value to VarId.
@@ -166,6 +201,12 @@ pub fn maybe_compute_expr_semantic(
         ast::Expr::Literal(literal_syntax) => {
             Some(Expr::Literal(literal_to_semantic(ctx, literal_syntax)?))
         }
+        ast::Expr::ShortString(short_string_syntax) => {
+            Some(Expr::Literal(short_string_to_semantic(ctx, short_string_syntax)?))
+        }
+        ast::Expr::String(string_syntax) => {
+            Some(Expr::StringLiteral(string_to_semantic(ctx, string_syntax)?))
+        }
         ast::Expr::False(syntax) => Some(false_literal_expr(ctx, syntax.stable_ptr().into())),
         ast::Expr::True(syntax) => Some(true_literal_expr(ctx, syntax.stable_ptr().into())),
         ast::Expr::Parenthesized(paren_syntax) => {
@@ -181,6 +222,8 @@ pub fn maybe_compute_expr_semantic(
         ast::Expr::Block(block_syntax) => compute_expr_block_semantic(ctx, block_syntax),
         ast::Expr::Match(expr_match) => compute_expr_match_semantic(ctx, expr_match),
         ast::Expr::If(expr_if) => compute_expr_if_semantic(ctx, expr_if),
+        ast::Expr::Loop(expr_loop) => compute_expr_loop_semantic(ctx, expr_loop),
+        ast::Expr::While(expr_while) => compute_expr_while_semantic(ctx, expr_while),
         ast::Expr::ErrorPropagate(expr) => compute_expr_error_propagate_semantic(ctx, expr),
         ast::Expr::Missing(_) => {
             ctx.diagnostics.report(syntax, Unsupported);
@@ -197,7 +240,8 @@ fn compute_expr_unary_semantic(
     let unary_op = syntax.op(syntax_db);
     let expr = compute_expr_semantic(ctx, &syntax.expr(syntax_db));
 
-    let function = match core_unary_operator(ctx.db, &unary_op, expr.ty()) {
+    let impl_lookup_context = ctx.resolver.impl_lookup_context();
+    let function = match core_unary_operator(ctx.db, &impl_lookup_context, &unary_op, expr.ty()) {
         Err(err_kind) => {
             ctx.diagnostics.report(&unary_op, err_kind);
             return None;
@@ -220,6 +264,9 @@ fn compute_expr_binary_semantic(
     let lexpr = compute_expr_semantic(ctx, lhs_syntax);
     let rhs_syntax = syntax.rhs(syntax_db);
     if matches!(binary_op, BinaryOperator::Dot(_)) {
+        if let ast::Expr::FunctionCall(call_syntax) = rhs_syntax {
+            return method_call_expr(ctx, lexpr, call_syntax, stable_ptr);
+        }
         return member_access_expr(ctx, lexpr, rhs_syntax, stable_ptr);
     }
     let rexpr = compute_expr_semantic(ctx, &rhs_syntax);
@@ -245,13 +292,15 @@ fn compute_expr_binary_semantic(
             }
         };
     }
-    let function = match core_binary_operator(db, &binary_op, lexpr.ty(), rexpr.ty()) {
-        Err(err_kind) => {
-            ctx.diagnostics.report(&binary_op, err_kind);
-            return None;
-        }
-        Ok(function) => function,
-    };
+    let impl_lookup_context = ctx.resolver.impl_lookup_context();
+    let function =
+        match core_binary_operator(db, &impl_lookup_context, &binary_op, lexpr.ty(), rexpr.ty()) {
+            Err(err_kind) => {
+                ctx.diagnostics.report(&binary_op, err_kind);
+                return None;
+            }
+            Ok(function) => function,
+        };
     expr_function_call(ctx, function, vec![lexpr, rexpr], stable_ptr)
 }
 
@@ -355,10 +404,11 @@ pub fn compute_expr_block_semantic(
         let ty = if let Some(t) = &tail_semantic_expr {
             t.ty()
         } else if let Some(statement) = statements_semantic.last() {
-            if let Statement::Return(_) = &new_ctx.statements[*statement] {
-                never_ty(new_ctx.db)
-            } else {
-                unit_ty(db)
+            match &new_ctx.statements[*statement] {
+                Statement::Return(_) | Statement::Break(_) | Statement::Continue(_) => {
+                    never_ty(new_ctx.db)
+                }
+                Statement::Expr(_) | Statement::Let(_) => unit_ty(db),
             }
         } else {
             unit_ty(db)
@@ -407,7 +457,6 @@ fn compute_expr_match_semantic(
     ctx: &mut ComputationContext<'_>,
     syntax: &ast::ExprMatch,
 ) -> Option<Expr> {
-    // TODO(yuval): verify exhaustiveness.
     let db = ctx.db;
     let syntax_db = db.upcast();
 
@@ -451,6 +500,33 @@ fn compute_expr_match_semantic(
     }
     // Compute semantic representation of the match arms.
     let pattern_and_exprs: Vec<_> = pattern_and_expr_options.into_iter().collect::<Option<_>>()?;
+    // Verify that a match on an enum has an arm for each of its variants.
+    if let TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) =
+        db.lookup_intern_type(expr.ty())
+    {
+        let enum_id = concrete_enum_id.enum_id(db);
+        let patterns: Vec<_> = pattern_and_exprs.iter().map(|(pattern, _)| pattern).collect();
+        if !patterns.iter().any(|pattern| matches!(pattern, Pattern::Otherwise(_))) {
+            let missing_variants: Vec<_> = db
+                .enum_variants(enum_id)?
+                .into_iter()
+                .filter(|(_, variant_id)| {
+                    !patterns.iter().any(|pattern| {
+                        matches!(pattern, Pattern::EnumVariant(enum_pattern)
+                            if enum_pattern.variant.id == *variant_id)
+                    })
+                })
+                .map(|(variant_name, _)| variant_name)
+                .collect();
+            if !missing_variants.is_empty() {
+                ctx.diagnostics.report(
+                    &syntax.expr(syntax_db),
+                    MissingMatchArms { enum_id, missing_variants },
+                );
+                return None;
+            }
+        }
+    }
     let semantic_arms = pattern_and_exprs
         .into_iter()
         .map(|(pattern, arm_expr)| MatchArm { pattern, expression: ctx.exprs.alloc(arm_expr) })
@@ -505,12 +581,62 @@ fn compute_expr_if_semantic(
     }))
 }
 
+/// Computes the semantic model of an expression of type [ast::ExprLoop].
+fn compute_expr_loop_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprLoop,
+) -> Option<Expr> {
+    let syntax_db = ctx.db.upcast();
+
+    // Loops consume gas on each iteration, and thus may panic.
+    if !ctx.signature.panicable {
+        ctx.diagnostics.report(syntax, LoopInNoPanicFunction);
+    }
+    let (body, loop_ctx) = ctx.run_in_loop(false, |new_ctx| {
+        compute_expr_block_semantic(new_ctx, &syntax.body(syntax_db))
+    });
+    Some(Expr::Loop(ExprLoop {
+        body: ctx.exprs.alloc(body?),
+        // A loop without a `break` never ends.
+        ty: loop_ctx.break_types.get_final_type(),
+        stable_ptr: syntax.stable_ptr().into(),
+    }))
+}
+
+/// Computes the semantic model of an expression of type [ast::ExprWhile].
+fn compute_expr_while_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprWhile,
+) -> Option<Expr> {
+    let syntax_db = ctx.db.upcast();
+
+    // Loops consume gas on each iteration, and thus may panic.
+    if !ctx.signature.panicable {
+        ctx.diagnostics.report(syntax, LoopInNoPanicFunction);
+    }
+    let ((condition, body), _) = ctx.run_in_loop(true, |new_ctx| {
+        let condition = compute_expr_semantic(new_ctx, &syntax.condition(syntax_db));
+        let body = compute_expr_block_semantic(new_ctx, &syntax.body(syntax_db));
+        (condition, body)
+    });
+    Some(Expr::While(ExprWhile {
+        condition: ctx.exprs.alloc(condition),
+        body: ctx.exprs.alloc(body?),
+        ty: unit_ty(ctx.db),
+        stable_ptr: syntax.stable_ptr().into(),
+    }))
+}
+
 /// Computes the semantic model of an expression of type [ast::ExprErrorPropagate].
 fn compute_expr_error_propagate_semantic(
     ctx: &mut ComputationContext<'_>,
     syntax: &ast::ExprErrorPropagate,
 ) -> Option<Expr> {
     let syntax_db = ctx.db.upcast();
+    // TODO(spapini): Support early returns from loops.
+    if ctx.loop_ctx.is_some() {
+        ctx.diagnostics.report(syntax, ErrorPropagateInsideLoop);
+    }
     let inner = compute_expr_semantic(ctx, &syntax.expr(syntax_db));
     let (ok_variant, err_variant) =
         unwrap_error_propagation_type(ctx.db, inner.ty()).on_none(|| {
@@ -631,6 +757,14 @@ fn compute_pattern_semantic(
             ty,
         ),
         ast::Pattern::Struct(pattern_struct) => {
+            // The struct is taken from the type. The path is resolved anyway, so it is recorded in
+            // the lookback (e.g. for finding references in the language server).
+            resolve_type(
+                ctx.db,
+                ctx.diagnostics,
+                &mut ctx.resolver,
+                &ast::Expr::Path(pattern_struct.path(syntax_db)),
+            );
             // Check that type is an struct, and get the concrete struct from it.
             let concrete_struct =
                 try_extract_matches!(ctx.db.lookup_intern_type(ty), TypeLongId::Concrete)
@@ -858,6 +992,40 @@ fn literal_to_semantic(
     Some(ExprLiteral { value, ty, stable_ptr: literal_syntax.stable_ptr().into() })
 }
 
+/// Creates the semantic model of a short string literal (e.g. 'hello') from its AST. The string is
+/// encoded into a felt, whose big-endian bytes are the ASCII characters of the string.
+fn short_string_to_semantic(
+    ctx: &mut ComputationContext<'_>,
+    short_string_syntax: &ast::TerminalShortString,
+) -> Option<ExprLiteral> {
+    let db = ctx.db;
+    let text = short_string_syntax.text(db.upcast());
+    let value = short_string_value(&text)
+        .map_err(|err_kind| ctx.diagnostics.report(short_string_syntax, err_kind))
+        .ok()?;
+    Some(ExprLiteral {
+        value,
+        ty: db.core_felt_ty(),
+        stable_ptr: short_string_syntax.stable_ptr().into(),
+    })
+}
+
+/// Creates the semantic model of a string literal (e.g. "hello") from its AST.
+fn string_to_semantic(
+    ctx: &mut ComputationContext<'_>,
+    string_syntax: &ast::TerminalString,
+) -> Option<ExprStringLiteral> {
+    let db = ctx.db;
+    let text = string_syntax.text(db.upcast());
+    let value = unescape_quoted_literal(&text)
+        .on_none(|| ctx.diagnostics.report(string_syntax, IllegalStringEscaping))?;
+    Some(ExprStringLiteral {
+        value,
+        ty: core_byte_array_ty(db),
+        stable_ptr: string_syntax.stable_ptr().into(),
+    })
+}
+
 /// Given an expression syntax, if it's an identifier, returns it. Otherwise, returns the proper
 /// error.
 fn expr_as_identifier(
@@ -885,9 +1053,14 @@ fn member_access_expr(
 ) -> Option<Expr> {
     let syntax_db = ctx.db.upcast();
 
+    // The expression is kept even if the member is not found, so its type can be looked up (e.g.
+    // for completion of the member in the language server).
+    let lexpr_ty = lexpr.ty();
+    let lexpr_id = ctx.exprs.alloc(lexpr);
+
     // Find MemberId.
     let member_name = expr_as_identifier(ctx, &rhs_syntax, syntax_db)?;
-    match ctx.db.lookup_intern_type(lexpr.ty()) {
+    match ctx.db.lookup_intern_type(lexpr_ty) {
         TypeLongId::Concrete(concrete) => match concrete {
             ConcreteTypeId::Struct(concrete_struct_id) => {
                 let member = ctx
@@ -903,7 +1076,6 @@ fn member_access_expr(
                             },
                         )
                     })?;
-                let lexpr_id = ctx.exprs.alloc(lexpr);
                 return Some(Expr::MemberAccess(ExprMemberAccess {
                     expr: lexpr_id,
                     struct_id: concrete_struct_id.struct_id(ctx.db),
@@ -913,8 +1085,7 @@ fn member_access_expr(
                 }));
             }
             _ => {
-                ctx.diagnostics
-                    .report(&rhs_syntax, TypeHasNoMembers { ty: lexpr.ty(), member_name });
+                ctx.diagnostics.report(&rhs_syntax, TypeHasNoMembers { ty: lexpr_ty, member_name });
             }
         },
         TypeLongId::Tuple(_) => {
@@ -922,40 +1093,241 @@ fn member_access_expr(
             ctx.diagnostics.report(&rhs_syntax, Unsupported);
         }
         TypeLongId::GenericParameter(_) => {
-            ctx.diagnostics.report(&rhs_syntax, TypeHasNoMembers { ty: lexpr.ty(), member_name });
+            ctx.diagnostics.report(&rhs_syntax, TypeHasNoMembers { ty: lexpr_ty, member_name });
         }
         TypeLongId::Missing => {}
     }
     None
 }
 
-/// Resolves a variable given a context and a path expression.
-fn resolve_variable(ctx: &mut ComputationContext<'_>, path: &ast::ExprPath) -> Option<Expr> {
+/// Computes the semantic model of a method call expression (e.g. "expr.method(args)").
+/// The method is a function of a trait in scope whose first parameter is `self`, implemented for
+/// the type of `expr`. `expr` is passed as the first argument, by reference if `self` is a `ref`
+/// parameter.
+fn method_call_expr(
+    ctx: &mut ComputationContext<'_>,
+    lexpr: Expr,
+    call_syntax: ast::ExprFunctionCall,
+    stable_ptr: ast::ExprPtr,
+) -> Option<Expr> {
     let db = ctx.db;
     let syntax_db = db.upcast();
+
+    let path = call_syntax.path(syntax_db);
+    let arg_exprs: Vec<_> = call_syntax
+        .arguments(syntax_db)
+        .expressions(syntax_db)
+        .elements(syntax_db)
+        .into_iter()
+        .map(|arg_syntax| compute_expr_semantic(ctx, &arg_syntax))
+        .collect();
     let segments = path.elements(syntax_db);
     if segments.len() != 1 {
-        ctx.diagnostics.report(path, Unsupported);
+        ctx.diagnostics.report(&path, InvalidMemberExpression);
+        return None;
+    }
+    let generic_args = match &segments[0] {
+        PathSegment::Simple(_) => vec![],
+        PathSegment::WithGenericArgs(generic_args_segment) => ctx
+            .resolver
+            .resolve_generic_args(ctx.diagnostics, &generic_args_segment.generic_args(syntax_db))?,
+    };
+    let method_name = segments[0].identifier(syntax_db);
+    let ty = lexpr.ty();
+    if ty == TypeId::missing(db) {
+        // A diagnostic should have already been added.
         return None;
     }
 
-    match &segments[0] {
-        PathSegment::Simple(ident_segment) => {
-            resolve_variable_by_name(ctx, &ident_segment.ident(syntax_db), path)
+    let candidates =
+        find_method_candidates(db, &ctx.resolver.impl_lookup_context(), ty, Some(&method_name));
+    let (concrete_impl_id, impl_function_id) = match candidates[..] {
+        [candidate] => candidate,
+        [] => {
+            ctx.diagnostics.report(&path, NoSuchMethod { ty, method_name });
+            return None;
         }
-        PathSegment::WithGenericArgs(generic_args_segment) => {
-            // TODO(ilya, 10/10/2022): Generics are not supported yet.
-            ctx.diagnostics.report(generic_args_segment, Unsupported);
+        _ => {
+            let impl_functions =
+                candidates.into_iter().map(|(_, impl_function_id)| impl_function_id).collect();
+            ctx.diagnostics.report(&path, AmbiguousMethod { ty, method_name, impl_functions });
+            return None;
+        }
+    };
+    // The generic params of an impl function start with the generic params of its impl.
+    let impl_generic_args = db.lookup_intern_concrete_impl(concrete_impl_id).generic_args;
+    let function_id = specialize_function(
+        db,
+        ctx.diagnostics,
+        path.stable_ptr().untyped(),
+        GenericFunctionId::ImplFunction(impl_function_id),
+        chain!(impl_generic_args, generic_args).collect(),
+    )?;
+    // The method is not resolved as a path, so it is marked explicitly (e.g. for the language
+    // server).
+    ctx.resolver.lookback.mark_concrete(
+        db,
+        &segments[0],
+        ResolvedConcreteItem::Function(function_id),
+    );
+    expr_function_call(ctx, function_id, chain!([lexpr], arg_exprs).collect(), stable_ptr)
+}
+
+/// Finds the impl functions that may be called as methods on a value of type `ty`, in the given
+/// lookup context. These are the functions of the impls of the traits in scope, whose trait
+/// function takes `self` of a type that matches `ty` as the first parameter. If `method_name` is
+/// given, only the methods of that name are returned. Each candidate is returned with the concrete
+/// impl it belongs to.
+pub fn find_method_candidates(
+    db: &dyn SemanticGroup,
+    lookup_context: &ImplLookupContext,
+    ty: TypeId,
+    method_name: Option<&SmolStr>,
+) -> Vec<(ConcreteImplId, ImplFunctionId)> {
+    let mut candidates = vec![];
+    for trait_id in traits_in_scope(db, lookup_context.module_id) {
+        let Some(trait_functions) = db.trait_functions(trait_id) else {
+            continue;
+        };
+        for (name, trait_function_id) in trait_functions {
+            if method_name.map_or(false, |method_name| *method_name != name) {
+                continue;
+            }
+            let Some(self_param) = db
+                .trait_function_signature(trait_function_id)
+                .and_then(|signature| signature.params.into_iter().next()) else {
+                continue;
+            };
+            if self_param.id.name(db.upcast()) != "self" {
+                continue;
+            }
+            let Some(trait_generic_params) = db.trait_generic_params(trait_id) else {
+                continue;
+            };
+            // Infer the generic arguments of the trait from the type of `self`.
+            let mut substitution = HashMap::new();
+            if !infer_generic_args(db, &mut substitution, self_param.ty, ty) {
+                continue;
+            }
+            let Some(generic_args) = trait_generic_params
+                .iter()
+                .map(|generic_param| substitution.get(generic_param).copied())
+                .collect::<Option<Vec<_>>>() else {
+                continue;
+            };
+            let concrete_trait_id =
+                db.intern_concrete_trait(ConcreteTraitLongId { trait_id, generic_args });
+            let Some(concrete_impls) = find_impls_at_context(db, lookup_context, concrete_trait_id) else {
+                continue;
+            };
+            for concrete_impl_id in concrete_impls.into_iter().unique() {
+                let impl_id = db.lookup_intern_concrete_impl(concrete_impl_id).impl_id;
+                let impl_function_id = db.impl_functions(impl_id).and_then(|impl_functions| {
+                    impl_functions
+                        .into_iter()
+                        .find(|impl_function_id| impl_function_id.name(db.upcast()) == name)
+                });
+                if let Some(impl_function_id) = impl_function_id {
+                    candidates.push((concrete_impl_id, impl_function_id));
+                }
+            }
+        }
+    }
+    candidates
+}
+
+/// Returns the traits that are in scope in a module - the traits defined or used in the module, and
+/// the traits defined or used in the root of the core crate.
+fn traits_in_scope(db: &dyn SemanticGroup, module_id: ModuleId) -> Vec<TraitId> {
+    let mut traits = OrderedHashSet::default();
+    for module_id in [module_id, core_module(db)] {
+        let Some(module_items) = db.module_items(module_id) else {
+            continue;
+        };
+        for item in module_items.items.values() {
+            match item {
+                ModuleItemId::Trait(trait_id) => {
+                    traits.insert(*trait_id);
+                }
+                ModuleItemId::Use(use_id) => {
+                    if let Some(ResolvedGenericItem::Trait(trait_id)) =
+                        db.use_resolved_item(*use_id)
+                    {
+                        traits.insert(trait_id);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    traits.into_iter().collect()
+}
+
+/// Resolves a variable given a context and a path expression.
+fn resolve_variable(ctx: &mut ComputationContext<'_>, path: &ast::ExprPath) -> Option<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+    let segments = path.elements(syntax_db);
+    if let [PathSegment::Simple(ident_segment)] = &segments[..] {
+        let identifier = ident_segment.ident(syntax_db);
+        if let Some(expr) = try_resolve_variable_by_name(ctx, &identifier, path) {
+            return Some(expr);
+        }
+        // Not a variable. The only other item that may be used by a simple name is a constant.
+        let mut constant_diagnostics = SemanticDiagnostics::new(ctx.resolver.module_file_id);
+        let resolved_item = ctx.resolver.resolve_concrete_path(&mut constant_diagnostics, path);
+        if let Some(ResolvedConcreteItem::Constant(constant_id)) = resolved_item {
+            return constant_expr(ctx, constant_id, path);
+        }
+        ctx.diagnostics.report(&identifier, VariableNotFound { name: identifier.text(syntax_db) });
+        return None;
+    }
+
+    match ctx.resolver.resolve_concrete_path(ctx.diagnostics, path)? {
+        ResolvedConcreteItem::Constant(constant_id) => constant_expr(ctx, constant_id, path),
+        _ => {
+            ctx.diagnostics.report(path, Unsupported);
             None
         }
     }
 }
 
+/// Creates the semantic model of a path to a constant. Constants are evaluated at compile time, so
+/// this is a literal with the value of the constant.
+fn constant_expr(
+    ctx: &mut ComputationContext<'_>,
+    constant_id: ConstantId,
+    path: &ast::ExprPath,
+) -> Option<Expr> {
+    // Errors in the constant are reported on its definition.
+    let constant = ctx.db.constant_semantic_data(constant_id)?;
+    Some(Expr::Literal(ExprLiteral {
+        value: constant.value,
+        ty: constant.ty,
+        stable_ptr: path.stable_ptr().into(),
+    }))
+}
+
 /// Resolves a variable given a context and a simple name.
 pub fn resolve_variable_by_name(
     ctx: &mut ComputationContext<'_>,
     identifier: &ast::TerminalIdentifier,
     path: &ast::ExprPath,
+) -> Option<Expr> {
+    let expr = try_resolve_variable_by_name(ctx, identifier, path);
+    if expr.is_none() {
+        let variable_name = identifier.text(ctx.db.upcast());
+        ctx.diagnostics.report(identifier, VariableNotFound { name: variable_name });
+    }
+    expr
+}
+
+/// Resolves a variable given a context and a simple name. Returns None, without reporting a
+/// diagnostic, if there is no such variable.
+fn try_resolve_variable_by_name(
+    ctx: &mut ComputationContext<'_>,
+    identifier: &ast::TerminalIdentifier,
+    path: &ast::ExprPath,
 ) -> Option<Expr> {
     let variable_name = identifier.text(ctx.db.upcast());
     let mut maybe_env = Some(&*ctx.environment);
@@ -969,7 +1341,6 @@ pub fn resolve_variable_by_name(
         }
         maybe_env = env.parent.as_deref();
     }
-    ctx.diagnostics.report(identifier, VariableNotFound { name: variable_name });
     None
 }
 
@@ -1094,6 +1465,10 @@ pub fn compute_statement_semantic(
             })
         }
         ast::Statement::Return(return_syntax) => {
+            // TODO(spapini): Support early returns from loops.
+            if ctx.loop_ctx.is_some() {
+                ctx.diagnostics.report(return_syntax, ReturnInsideLoop);
+            }
             let expr_syntax = return_syntax.expr(syntax_db);
             let expr = compute_expr_semantic(ctx, &expr_syntax);
             if expr.ty() != ctx.signature.return_type {
@@ -1110,6 +1485,43 @@ pub fn compute_statement_semantic(
                 stable_ptr: syntax.stable_ptr(),
             })
         }
+        ast::Statement::Break(break_syntax) => {
+            let expr = match break_syntax.expr_clause(syntax_db) {
+                ast::OptionExprClause::Empty(_) => None,
+                ast::OptionExprClause::ExprClause(expr_clause) => {
+                    Some(compute_expr_semantic(ctx, &expr_clause.expr(syntax_db)))
+                }
+            };
+            match &mut ctx.loop_ctx {
+                None => ctx.diagnostics.report(break_syntax, BreakOutsideLoop),
+                Some(LoopContext { is_while: true, .. }) => {
+                    if expr.is_some() {
+                        ctx.diagnostics.report(break_syntax, BreakWithValueInWhile);
+                    }
+                }
+                Some(LoopContext { is_while: false, break_types }) => {
+                    let ty = expr.as_ref().map(|expr| expr.ty()).unwrap_or_else(|| unit_ty(db));
+                    if let Err((current_ty, break_ty)) = break_types.try_merge_types(ty) {
+                        ctx.diagnostics.report(
+                            break_syntax,
+                            IncompatibleLoopBreakTypes { current_ty, break_ty },
+                        );
+                    }
+                }
+            }
+            semantic::Statement::Break(semantic::StatementBreak {
+                expr: expr.map(|expr| ctx.exprs.alloc(expr)),
+                stable_ptr: syntax.stable_ptr(),
+            })
+        }
+        ast::Statement::Continue(continue_syntax) => {
+            if ctx.loop_ctx.is_none() {
+                ctx.diagnostics.report(continue_syntax, ContinueOutsideLoop);
+            }
+            semantic::Statement::Continue(semantic::StatementContinue {
+                stable_ptr: syntax.stable_ptr(),
+            })
+        }
         ast::Statement::Missing(_) => todo!(),
     };
     Some(ctx.statements.alloc(statement))
@@ -1,6 +1,6 @@
 use db_utils::Upcast;
 use defs::db::DefsGroup;
-use defs::ids::FreeFunctionId;
+use defs::ids::FunctionWithBodyId;
 
 use crate::db::SemanticGroup;
 
@@ -8,7 +8,7 @@ use crate::db::SemanticGroup;
 /// Acts like a "db" for DebugWithDb.
 pub struct ExprFormatter<'a> {
     pub db: &'a (dyn SemanticGroup + 'static),
-    pub free_function_id: FreeFunctionId,
+    pub function_id: FunctionWithBodyId,
 }
 
 impl<'a> Upcast<dyn SemanticGroup + 'static> for ExprFormatter<'a> {
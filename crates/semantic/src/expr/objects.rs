@@ -18,10 +18,7 @@ impl DebugWithDb<ExprFormatter<'_>> for ExprId {
         f: &mut std::fmt::Formatter<'_>,
         expr_formatter: &ExprFormatter<'_>,
     ) -> std::fmt::Result {
-        expr_formatter
-            .db
-            .expr_semantic(expr_formatter.free_function_id, *self)
-            .fmt(f, expr_formatter)
+        expr_formatter.db.expr_semantic(expr_formatter.function_id, *self).fmt(f, expr_formatter)
     }
 }
 impl DebugWithDb<ExprFormatter<'_>> for StatementId {
@@ -32,7 +29,7 @@ impl DebugWithDb<ExprFormatter<'_>> for StatementId {
     ) -> std::fmt::Result {
         expr_formatter
             .db
-            .statement_semantic(expr_formatter.free_function_id, *self)
+            .statement_semantic(expr_formatter.function_id, *self)
             .fmt(f, expr_formatter)
     }
 }
@@ -43,6 +40,8 @@ pub enum Statement {
     Expr(StatementExpr),
     Let(StatementLet),
     Return(StatementReturn),
+    Break(StatementBreak),
+    Continue(StatementContinue),
 }
 impl Statement {
     pub fn stable_ptr(&self) -> ast::StatementPtr {
@@ -50,6 +49,8 @@ impl Statement {
             Statement::Expr(stmt) => stmt.stable_ptr,
             Statement::Let(stmt) => stmt.stable_ptr,
             Statement::Return(stmt) => stmt.stable_ptr,
+            Statement::Break(stmt) => stmt.stable_ptr,
+            Statement::Continue(stmt) => stmt.stable_ptr,
         }
     }
 }
@@ -79,6 +80,22 @@ pub struct StatementReturn {
     pub stable_ptr: ast::StatementPtr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct StatementBreak {
+    /// The value the loop evaluates to. None if the break has no value.
+    pub expr: Option<ExprId>,
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::StatementPtr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct StatementContinue {
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::StatementPtr,
+}
+
 // Expressions.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
 #[debug_db(ExprFormatter<'a>)]
@@ -89,8 +106,11 @@ pub enum Expr {
     FunctionCall(ExprFunctionCall),
     Match(ExprMatch),
     If(ExprIf),
+    Loop(ExprLoop),
+    While(ExprWhile),
     Var(ExprVar),
     Literal(ExprLiteral),
+    StringLiteral(ExprStringLiteral),
     MemberAccess(ExprMemberAccess),
     StructCtor(ExprStructCtor),
     EnumVariantCtor(ExprEnumVariantCtor),
@@ -106,8 +126,11 @@ impl Expr {
             Expr::FunctionCall(expr) => expr.ty,
             Expr::Match(expr) => expr.ty,
             Expr::If(expr) => expr.ty,
+            Expr::Loop(expr) => expr.ty,
+            Expr::While(expr) => expr.ty,
             Expr::Var(expr) => expr.ty,
             Expr::Literal(expr) => expr.ty,
+            Expr::StringLiteral(expr) => expr.ty,
             Expr::MemberAccess(expr) => expr.ty,
             Expr::StructCtor(expr) => expr.ty,
             Expr::EnumVariantCtor(expr) => expr.ty,
@@ -123,8 +146,11 @@ impl Expr {
             Expr::FunctionCall(expr) => expr.stable_ptr,
             Expr::Match(expr) => expr.stable_ptr,
             Expr::If(expr) => expr.stable_ptr,
+            Expr::Loop(expr) => expr.stable_ptr,
+            Expr::While(expr) => expr.stable_ptr,
             Expr::Var(expr) => expr.stable_ptr,
             Expr::Literal(expr) => expr.stable_ptr,
+            Expr::StringLiteral(expr) => expr.stable_ptr,
             Expr::MemberAccess(expr) => expr.stable_ptr,
             Expr::StructCtor(expr) => expr.stable_ptr,
             Expr::EnumVariantCtor(expr) => expr.stable_ptr,
@@ -189,6 +215,26 @@ pub struct ExprIf {
     pub stable_ptr: ast::ExprPtr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprLoop {
+    pub body: ExprId,
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::ExprPtr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprWhile {
+    pub condition: ExprId,
+    pub body: ExprId,
+    // ExprWhile is always of unit type.
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::ExprPtr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
 #[debug_db(ExprFormatter<'a>)]
 pub struct MatchArm {
@@ -225,6 +271,16 @@ pub struct ExprLiteral {
     pub stable_ptr: ast::ExprPtr,
 }
 
+/// A string literal (e.g. "hello"), of type `ByteArray`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprStringLiteral {
+    pub value: String,
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::ExprPtr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
 #[debug_db(ExprFormatter<'a>)]
 pub struct ExprMemberAccess {
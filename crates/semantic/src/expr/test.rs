@@ -1,7 +1,7 @@
 use assert_matches::assert_matches;
 use debug::DebugWithDb;
 use defs::db::DefsGroup;
-use defs::ids::{ModuleItemId, VarId};
+use defs::ids::{FunctionWithBodyId, ModuleItemId, VarId};
 use indoc::indoc;
 use num_bigint::ToBigInt;
 use pretty_assertions::assert_eq;
@@ -26,10 +26,14 @@ semantic_test!(
         "src/expr/test_data/generics",
         "src/expr/test_data/if",
         "src/expr/test_data/let_statement",
+        "src/expr/test_data/loop",
         "src/expr/test_data/match",
+        "src/expr/test_data/method",
         "src/expr/test_data/operators",
         "src/expr/test_data/pattern",
         "src/expr/test_data/return",
+        "src/expr/test_data/string",
+        "src/expr/test_data/trait_function",
     ],
     test_function_diagnostics
 );
@@ -39,12 +43,16 @@ semantic_test!(
 #[test_case("12_felt", 12, "felt")]
 #[test_case("16_uint128", 16, "uint128")]
 #[test_case("0x16_uint128", 0x16, "uint128")]
+#[test_case("'a'", 0x61, "felt")]
+#[test_case("'hello'", 0x68656c6c6f, "felt")]
+#[test_case("'it\\'s\\n'", 0x697427730a, "felt")]
+#[test_case("''", 0, "felt")]
 fn test_expr_literal(expr: &str, value: i128, ty_name: &str) {
     let mut db_val = SemanticDatabaseForTesting::default();
     let test_expr = setup_test_expr(&mut db_val, expr, "", "").unwrap();
     let db = &db_val;
     let expr = db.expr_semantic(test_expr.function_id, test_expr.expr_id);
-    let expr_formatter = ExprFormatter { db, free_function_id: test_expr.function_id };
+    let expr_formatter = ExprFormatter { db, function_id: test_expr.function_id };
     // TODO(spapini): Currently, DebugWithDb can't "switch" dbs, and thus ExternTypeId is not
     // followed (it uses SyntaxGroup, and not SemanticGroup).
     // Fix this.
@@ -75,14 +83,14 @@ fn test_expr_assignment() {
     let test_expr = setup_test_expr(&mut db_val, "a = a * 3", "", "let mut a = 5;").unwrap();
     let db = &db_val;
     let expr = db.expr_semantic(test_expr.function_id, test_expr.expr_id);
-    let expr_formatter = ExprFormatter { db, free_function_id: test_expr.function_id };
+    let expr_formatter = ExprFormatter { db, function_id: test_expr.function_id };
 
     assert_eq!(
         format!("{:?}", expr.debug(&expr_formatter)),
         "Assignment(ExprAssignment { var: LocalVarId(test::a), rhs: FunctionCall(ExprFunctionCall \
-         { function: core::felt_mul, ref_args: [], args: [Var(ExprVar { var: LocalVarId(test::a), \
-         ty: core::felt }), Literal(ExprLiteral { value: 3, ty: core::felt })], ty: core::felt \
-         }), ty: () })"
+         { function: core::FeltMul::mul, ref_args: [], args: [Var(ExprVar { var: \
+         LocalVarId(test::a), ty: core::felt }), Literal(ExprLiteral { value: 3, ty: core::felt \
+         })], ty: core::felt }), ty: () })"
     );
 }
 
@@ -92,18 +100,18 @@ fn test_expr_operator() {
     let test_expr = setup_test_expr(&mut db_val, "!(-5 + 9 * 3 == 0)", "", "").unwrap();
     let db = &db_val;
     let expr = db.expr_semantic(test_expr.function_id, test_expr.expr_id);
-    let expr_formatter = ExprFormatter { db, free_function_id: test_expr.function_id };
+    let expr_formatter = ExprFormatter { db, function_id: test_expr.function_id };
 
     // TODO(spapini): Make transparent DebugWithDb attribute, to have better outputs.
     // TODO(spapini): Have better whitespaces here somehow.
     assert_eq!(
         format!("{:?}", expr.debug(&expr_formatter)),
-        "FunctionCall(ExprFunctionCall { function: core::bool_not, ref_args: [], args: \
-         [FunctionCall(ExprFunctionCall { function: core::felt_eq, ref_args: [], args: \
-         [FunctionCall(ExprFunctionCall { function: core::felt_add, ref_args: [], args: \
-         [FunctionCall(ExprFunctionCall { function: core::felt_neg, ref_args: [], args: \
+        "FunctionCall(ExprFunctionCall { function: core::BoolNot::not, ref_args: [], args: \
+         [FunctionCall(ExprFunctionCall { function: core::FeltPartialEq::eq, ref_args: [], args: \
+         [FunctionCall(ExprFunctionCall { function: core::FeltAdd::add, ref_args: [], args: \
+         [FunctionCall(ExprFunctionCall { function: core::FeltNeg::neg, ref_args: [], args: \
          [Literal(ExprLiteral { value: 5, ty: core::felt })], ty: core::felt }), \
-         FunctionCall(ExprFunctionCall { function: core::felt_mul, ref_args: [], args: \
+         FunctionCall(ExprFunctionCall { function: core::FeltMul::mul, ref_args: [], args: \
          [Literal(ExprLiteral { value: 9, ty: core::felt }), Literal(ExprLiteral { value: 3, ty: \
          core::felt })], ty: core::felt })], ty: core::felt }), Literal(ExprLiteral { value: 0, \
          ty: core::felt })], ty: core::bool })], ty: core::bool })"
@@ -134,13 +142,14 @@ fn test_member_access() {
     )
     .unwrap();
     let db = &db_val;
-    let foo_id = extract_matches!(
+    let free_function_id = extract_matches!(
         db.module_item_by_name(module_id, "foo".into()).unwrap(),
         ModuleItemId::FreeFunction
     );
-    let expr_formatter = ExprFormatter { db, free_function_id: foo_id };
+    let foo_id = FunctionWithBodyId::Free(free_function_id);
+    let expr_formatter = ExprFormatter { db, function_id: foo_id };
     let block = extract_matches!(
-        db.expr_semantic(foo_id, db.free_function_definition_body(foo_id).unwrap()),
+        db.expr_semantic(foo_id, db.free_function_definition_body(free_function_id).unwrap()),
         semantic::Expr::Block
     );
     let exprs: Vec<_> = block
@@ -309,7 +318,7 @@ fn test_let_statement() {
     .unwrap();
     let db = &db_val;
     let expr = db.expr_semantic(test_function.function_id, test_function.body);
-    let expr_formatter = ExprFormatter { db, free_function_id: test_function.function_id };
+    let expr_formatter = ExprFormatter { db, function_id: test_function.function_id };
 
     assert_eq!(
         format!("{:?}", expr.debug(&expr_formatter)),
@@ -393,10 +402,10 @@ fn test_expr_var_failures() {
     assert_eq!(
         diagnostics,
         indoc! {"
-            error: Unsupported feature.
+            error: Path not found.
              --> lib.cairo:2:5
                 a::b;
-                ^**^
+                ^
 
         "}
     )
@@ -425,7 +434,7 @@ fn test_expr_match() {
         crate::Expr::Block
     );
     let expr = db.expr_semantic(test_function.function_id, tail.unwrap());
-    let expr_formatter = ExprFormatter { db, free_function_id: test_function.function_id };
+    let expr_formatter = ExprFormatter { db, function_id: test_function.function_id };
     assert_eq!(
         format!("{:?}", expr.debug(&expr_formatter)),
         "Match(ExprMatch { matched_expr: Var(ExprVar { var: ParamId(test::a), ty: core::felt }), \
@@ -543,7 +552,7 @@ fn test_expr_call_failures() {
     // TODO(spapini): Add types.
     let (test_expr, diagnostics) = setup_test_expr(&mut db_val, "foo()", "", "").split();
     let db = &db_val;
-    let expr_formatter = ExprFormatter { db, free_function_id: test_expr.function_id };
+    let expr_formatter = ExprFormatter { db, function_id: test_expr.function_id };
 
     // Check expr.
     assert_eq!(
@@ -625,7 +634,7 @@ fn test_expr_struct_ctor() {
     .unwrap();
     let db = &db_val;
     let expr = db.expr_semantic(test_expr.function_id, test_expr.expr_id);
-    let expr_formatter = ExprFormatter { db, free_function_id: test_expr.function_id };
+    let expr_formatter = ExprFormatter { db, function_id: test_expr.function_id };
     assert_eq!(
         format!("{:?}", expr.debug(&expr_formatter)),
         "StructCtor(ExprStructCtor { struct_id: StructId(test::A), members: [(MemberId(test::a), \
@@ -640,10 +649,10 @@ fn test_expr_tuple() {
     let test_expr = setup_test_expr(&mut db_val, "(1 + 2, (2, 3))", "", "").unwrap();
     let db = &db_val;
     let expr = db.expr_semantic(test_expr.function_id, test_expr.expr_id);
-    let expr_formatter = ExprFormatter { db, free_function_id: test_expr.function_id };
+    let expr_formatter = ExprFormatter { db, function_id: test_expr.function_id };
     assert_eq!(
         format!("{:?}", expr.debug(&expr_formatter)),
-        "Tuple(ExprTuple { items: [FunctionCall(ExprFunctionCall { function: core::felt_add, \
+        "Tuple(ExprTuple { items: [FunctionCall(ExprFunctionCall { function: core::FeltAdd::add, \
          ref_args: [], args: [Literal(ExprLiteral { value: 1, ty: core::felt }), \
          Literal(ExprLiteral { value: 2, ty: core::felt })], ty: core::felt }), Tuple(ExprTuple { \
          items: [Literal(ExprLiteral { value: 2, ty: core::felt }), Literal(ExprLiteral { value: \
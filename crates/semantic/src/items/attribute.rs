@@ -10,7 +10,7 @@ use crate::db::SemanticGroup;
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Attribute {
     pub id: SmolStr,
-    pub args: Vec<ast::Expr>,
+    pub args: Vec<ast::AttributeArg>,
 }
 
 impl DebugWithDb<dyn SemanticGroup> for Attribute {
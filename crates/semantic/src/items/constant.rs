@@ -0,0 +1,264 @@
+use std::sync::Arc;
+
+use defs::ids::{ConstantId, LanguageElementId};
+use diagnostics::Diagnostics;
+use diagnostics_proc_macros::DebugWithDb;
+use num_bigint::BigInt;
+use num_traits::{One, Signed, Zero};
+use syntax::node::ast::{BinaryOperator, UnaryOperator};
+use syntax::node::{ast, Terminal, TypedSyntaxNode};
+use utils::OptionHelper;
+
+use crate::corelib::{core_felt_ty, get_core_ty_by_name, try_get_core_ty_by_name};
+use crate::db::SemanticGroup;
+use crate::diagnostic::SemanticDiagnosticKind::*;
+use crate::diagnostic::SemanticDiagnostics;
+use crate::literals::{short_string_value, LiteralLongId};
+use crate::resolve_path::{ResolvedConcreteItem, ResolvedLookback, Resolver};
+use crate::types::resolve_type;
+use crate::{semantic, SemanticDiagnostic, TypeId};
+
+#[cfg(test)]
+#[path = "constant_test.rs"]
+mod test;
+
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub struct ConstantData {
+    diagnostics: Diagnostics<SemanticDiagnostic>,
+    constant: Option<Constant>,
+    resolved_lookback: Arc<ResolvedLookback>,
+}
+
+/// The semantic model of a constant. The value is evaluated at compile time.
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub struct Constant {
+    pub ty: semantic::TypeId,
+    pub value: BigInt,
+}
+
+/// Query implementation of [crate::db::SemanticGroup::priv_constant_semantic_data].
+pub fn priv_constant_semantic_data(
+    db: &dyn SemanticGroup,
+    constant_id: ConstantId,
+) -> Option<ConstantData> {
+    let module_file_id = constant_id.module_file(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let module_data = db.module_data(module_file_id.0)?;
+    let constant_ast = module_data.constants.get(&constant_id)?;
+    let syntax_db = db.upcast();
+    let mut resolver = Resolver::new(db, module_file_id, &[]);
+
+    let ty_syntax = constant_ast.type_clause(syntax_db).ty(syntax_db);
+    let ty = resolve_type(db, &mut diagnostics, &mut resolver, &ty_syntax);
+    let constant = if ty == TypeId::missing(db) {
+        None
+    } else if ty != core_felt_ty(db) && ty != uint128_ty(db) {
+        diagnostics.report(&ty_syntax, UnsupportedConstantType { ty });
+        None
+    } else {
+        let mut ctx = ConstantContext { db, diagnostics: &mut diagnostics, resolver, ty };
+        let value = ctx.evaluate(&constant_ast.value(syntax_db));
+        resolver = ctx.resolver;
+        value.map(|value| Constant { ty, value })
+    };
+
+    let resolved_lookback = Arc::new(resolver.lookback);
+    Some(ConstantData { diagnostics: diagnostics.build(), constant, resolved_lookback })
+}
+
+/// Cycle handling for [crate::db::SemanticGroup::priv_constant_semantic_data].
+pub fn priv_constant_semantic_data_cycle(
+    db: &dyn SemanticGroup,
+    _cycle: &[String],
+    constant_id: &ConstantId,
+) -> Option<ConstantData> {
+    let module_file_id = constant_id.module_file(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let module_data = db.module_data(module_file_id.0)?;
+    let constant_ast = module_data.constants.get(constant_id)?;
+    let syntax_db = db.upcast();
+    diagnostics.report(&constant_ast.name(syntax_db), ConstantCycle);
+    Some(ConstantData {
+        diagnostics: diagnostics.build(),
+        constant: None,
+        resolved_lookback: Arc::new(ResolvedLookback::default()),
+    })
+}
+
+/// Query implementation of [crate::db::SemanticGroup::constant_semantic_diagnostics].
+pub fn constant_semantic_diagnostics(
+    db: &dyn SemanticGroup,
+    constant_id: ConstantId,
+) -> Diagnostics<SemanticDiagnostic> {
+    db.priv_constant_semantic_data(constant_id).map(|data| data.diagnostics).unwrap_or_default()
+}
+
+/// Query implementation of [crate::db::SemanticGroup::constant_semantic_data].
+pub fn constant_semantic_data(db: &dyn SemanticGroup, constant_id: ConstantId) -> Option<Constant> {
+    db.priv_constant_semantic_data(constant_id)?.constant
+}
+
+/// Query implementation of [crate::db::SemanticGroup::constant_resolved_lookback].
+pub fn constant_resolved_lookback(
+    db: &dyn SemanticGroup,
+    constant_id: ConstantId,
+) -> Option<Arc<ResolvedLookback>> {
+    Some(db.priv_constant_semantic_data(constant_id)?.resolved_lookback)
+}
+
+fn uint128_ty(db: &dyn SemanticGroup) -> TypeId {
+    get_core_ty_by_name(db, "uint128".into(), vec![])
+}
+
+/// Context for the compile-time evaluation of the value of a constant.
+/// All the operands in the value expression are of the type of the constant.
+struct ConstantContext<'a> {
+    db: &'a dyn SemanticGroup,
+    diagnostics: &'a mut SemanticDiagnostics,
+    resolver: Resolver<'a>,
+    ty: TypeId,
+}
+impl<'a> ConstantContext<'a> {
+    /// Evaluates a constant expression. Returns None and reports a diagnostic if the expression
+    /// can't be evaluated at compile time.
+    fn evaluate(&mut self, expr_syntax: &ast::Expr) -> Option<BigInt> {
+        let syntax_db = self.db.upcast();
+        let value = match expr_syntax {
+            ast::Expr::Literal(literal_syntax) => self.evaluate_literal(literal_syntax)?,
+            ast::Expr::ShortString(short_string_syntax) => {
+                self.check_ty(short_string_syntax, core_felt_ty(self.db))?;
+                short_string_value(&short_string_syntax.text(syntax_db))
+                    .map_err(|err_kind| self.diagnostics.report(short_string_syntax, err_kind))
+                    .ok()?
+            }
+            ast::Expr::Parenthesized(paren_syntax) => {
+                self.evaluate(&paren_syntax.expr(syntax_db))?
+            }
+            ast::Expr::Path(path_syntax) => {
+                let constant_id =
+                    match self.resolver.resolve_concrete_path(self.diagnostics, path_syntax)? {
+                        ResolvedConcreteItem::Constant(constant_id) => constant_id,
+                        _ => {
+                            self.diagnostics.report(path_syntax, NotAConstant);
+                            return None;
+                        }
+                    };
+                // Errors in the referenced constant are reported on its definition.
+                // The private query is called directly for cycle handling, as in the resolution of
+                // uses.
+                let constant = self.db.priv_constant_semantic_data(constant_id)?.constant?;
+                self.check_ty(path_syntax, constant.ty)?;
+                constant.value
+            }
+            ast::Expr::Unary(unary_syntax) => {
+                let op = unary_syntax.op(syntax_db);
+                let value = self.evaluate(&unary_syntax.expr(syntax_db))?;
+                match op {
+                    UnaryOperator::Minus(_) if self.ty == core_felt_ty(self.db) => -value,
+                    _ => {
+                        self.diagnostics.report(
+                            &op,
+                            UnsupportedUnaryOperator {
+                                op: op.as_syntax_node().get_text(syntax_db).trim().into(),
+                                ty: self.ty,
+                            },
+                        );
+                        return None;
+                    }
+                }
+            }
+            ast::Expr::Binary(binary_syntax) => {
+                let op = binary_syntax.op(syntax_db);
+                let lhs = self.evaluate(&binary_syntax.lhs(syntax_db))?;
+                let rhs = self.evaluate(&binary_syntax.rhs(syntax_db))?;
+                self.evaluate_binary_operator(&op, lhs, rhs)?
+            }
+            // A diagnostic was already reported by the parser.
+            ast::Expr::Missing(_) => return None,
+            _ => {
+                self.diagnostics.report(expr_syntax, UnsupportedConstantExpression);
+                return None;
+            }
+        };
+        self.check_range(expr_syntax, value)
+    }
+
+    /// Evaluates a numeric literal. If the literal has a type suffix (e.g. `5_uint128`), it must
+    /// match the type of the constant.
+    fn evaluate_literal(&mut self, literal_syntax: &ast::TerminalLiteralNumber) -> Option<BigInt> {
+        let text = literal_syntax.text(self.db.upcast());
+        let literal_text = if let Some((literal, ty_str)) = text.split_once('_') {
+            let ty = try_get_core_ty_by_name(self.db, ty_str.into(), vec![])
+                .map_err(|err_kind| self.diagnostics.report(literal_syntax, err_kind))
+                .ok()?;
+            self.check_ty(literal_syntax, ty)?;
+            literal.into()
+        } else {
+            text
+        };
+        Some(
+            LiteralLongId::try_from(literal_text)
+                .ok()
+                .on_none(|| self.diagnostics.report(literal_syntax, UnknownLiteral))?
+                .value,
+        )
+    }
+
+    /// Evaluates a binary operator over two already evaluated operands.
+    fn evaluate_binary_operator(
+        &mut self,
+        op: &BinaryOperator,
+        lhs: BigInt,
+        rhs: BigInt,
+    ) -> Option<BigInt> {
+        let is_uint128 = self.ty == uint128_ty(self.db);
+        Some(match op {
+            BinaryOperator::Plus(_) => lhs + rhs,
+            BinaryOperator::Minus(_) => lhs - rhs,
+            BinaryOperator::Mul(_) => lhs * rhs,
+            BinaryOperator::Div(_) | BinaryOperator::Mod(_) if is_uint128 => {
+                if rhs.is_zero() {
+                    self.diagnostics.report(op, DivisionByZero);
+                    return None;
+                }
+                if matches!(op, BinaryOperator::Div(_)) { lhs / rhs } else { lhs % rhs }
+            }
+            _ => {
+                let op_text = op.as_syntax_node().get_text(self.db.upcast());
+                self.diagnostics.report(
+                    op,
+                    UnsupportedBinaryOperator {
+                        op: op_text.trim().into(),
+                        type1: self.ty,
+                        type2: self.ty,
+                    },
+                );
+                return None;
+            }
+        })
+    }
+
+    /// Checks that an operand is of the type of the constant.
+    fn check_ty<T: TypedSyntaxNode>(&mut self, node: &T, actual_ty: TypeId) -> Option<()> {
+        if actual_ty != self.ty {
+            self.diagnostics.report(node, WrongConstantType { expected_ty: self.ty, actual_ty });
+            return None;
+        }
+        Some(())
+    }
+
+    /// Checks that an evaluated value fits in the type of the constant. Felt values are not
+    /// bounded, and are reduced by the backend. Values of integer types must fit in the type, at
+    /// every step of the evaluation.
+    fn check_range(&mut self, expr_syntax: &ast::Expr, value: BigInt) -> Option<BigInt> {
+        if self.ty == uint128_ty(self.db)
+            && (value.is_negative() || value >= (BigInt::one() << 128))
+        {
+            self.diagnostics.report(expr_syntax, ConstantOverflow { ty: self.ty });
+            return None;
+        }
+        Some(value)
+    }
+}
@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use debug::DebugWithDb;
+use defs::db::DefsGroup;
+use defs::ids::{FunctionWithBodyId, ModuleId, ModuleItemId};
+use filesystem::db::{AsFilesGroupMut, FilesGroup, FilesGroupEx};
+use filesystem::ids::{CrateLongId, Directory, FileLongId};
+use num_bigint::BigInt;
+use pretty_assertions::assert_eq;
+use test_log::test;
+use utils::extract_matches;
+
+use crate::corelib::{core_felt_ty, get_core_ty_by_name};
+use crate::db::SemanticGroup;
+use crate::expr::fmt::ExprFormatter;
+use crate::test_utils::{setup_test_module, SemanticDatabaseForTesting};
+
+#[test]
+fn test_constant() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let db = &mut db_val;
+    let test_module = setup_test_module(
+        db,
+        indoc::indoc! {"
+            const MAX_SUPPLY: felt = 1000;
+            const HALF_SUPPLY: uint128 = (1000_uint128 + 1) / 2;
+            const NEG: felt = -MAX_SUPPLY * 3 + 'a';
+        "},
+    )
+    .unwrap();
+    let module_id = test_module.module_id;
+
+    let constant = |name: &str| {
+        let constant_id = extract_matches!(
+            db.module_item_by_name(module_id, name.into()).unwrap(),
+            ModuleItemId::Constant
+        );
+        db.constant_semantic_data(constant_id).unwrap()
+    };
+    let max_supply = constant("MAX_SUPPLY");
+    assert_eq!(max_supply.ty, core_felt_ty(db));
+    assert_eq!(max_supply.value, BigInt::from(1000));
+    let half_supply = constant("HALF_SUPPLY");
+    assert_eq!(half_supply.ty, get_core_ty_by_name(db, "uint128".into(), vec![]));
+    assert_eq!(half_supply.value, BigInt::from(500));
+    assert_eq!(constant("NEG").value, BigInt::from(-3000 + 97));
+}
+
+fn set_file_content(db: &mut SemanticDatabaseForTesting, path: &str, content: &str) {
+    let file_id = db.intern_file(FileLongId::OnDisk(path.into()));
+    db.as_files_group_mut().override_file_content(file_id, Some(Arc::new(content.into())));
+}
+
+#[test]
+fn test_constant_from_other_module() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let db = &mut db_val;
+
+    let crate_id = db.intern_crate(CrateLongId("test".into()));
+    db.set_crate_root(crate_id, Some(Directory("src".into())));
+    set_file_content(
+        db,
+        "src/lib.cairo",
+        indoc::indoc! {"
+            mod consts;
+            use consts::MAX_SUPPLY;
+
+            func foo() -> felt {
+                MAX_SUPPLY + consts::OFFSET
+            }
+        "},
+    );
+    set_file_content(
+        db,
+        "src/consts.cairo",
+        "const MAX_SUPPLY: felt = 1000;\nconst OFFSET: felt = 7;",
+    );
+
+    let free_function_id = extract_matches!(
+        db.module_item_by_name(ModuleId::CrateRoot(crate_id), "foo".into()).unwrap(),
+        ModuleItemId::FreeFunction
+    );
+    assert_eq!(db.free_function_definition_diagnostics(free_function_id).format(db), "");
+    let expr_formatter =
+        ExprFormatter { db, function_id: FunctionWithBodyId::Free(free_function_id) };
+    let body = db.free_function_definition_body(free_function_id);
+    assert_eq!(
+        format!("{:?}", body.debug(&expr_formatter)),
+        "Some(Block(ExprBlock { statements: [], tail: Some(FunctionCall(ExprFunctionCall { \
+         function: core::FeltAdd::add, ref_args: [], args: [Literal(ExprLiteral { value: 1000, \
+         ty: core::felt }), Literal(ExprLiteral { value: 7, ty: core::felt })], ty: core::felt \
+         })), ty: core::felt }))"
+    );
+}
@@ -1,23 +1,17 @@
-use std::collections::HashSet;
 use std::sync::Arc;
 
-use db_utils::Upcast;
 use defs::ids::{FreeFunctionId, GenericFunctionId, GenericParamId, LanguageElementId};
 use diagnostics::Diagnostics;
 use diagnostics_proc_macros::DebugWithDb;
-use id_arena::Arena;
-use syntax::node::ast;
-use utils::try_extract_matches;
-use utils::unordered_hash_map::UnorderedHashMap;
 
 use super::attribute::{ast_attributes_to_semantic, Attribute};
+use super::function_with_body::{compute_function_definition_data, FunctionDefinitionData};
 use super::generics::semantic_generic_params;
-use crate::corelib::never_ty;
 use crate::db::SemanticGroup;
-use crate::diagnostic::{SemanticDiagnosticKind, SemanticDiagnostics};
-use crate::expr::compute::{compute_expr_block_semantic, ComputationContext, Environment};
+use crate::diagnostic::SemanticDiagnostics;
+use crate::expr::compute::Environment;
 use crate::resolve_path::{ResolvedLookback, Resolver};
-use crate::{semantic, Expr, ExprId, FunctionId, SemanticDiagnostic, TypeId};
+use crate::{semantic, SemanticDiagnostic, TypeId};
 
 #[cfg(test)]
 #[path = "free_function_test.rs"]
@@ -133,26 +127,6 @@ pub fn priv_free_function_declaration_data(
 
 // === Definition ===
 
-#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
-#[debug_db(dyn SemanticGroup + 'static)]
-pub struct FreeFunctionDefinitionData {
-    diagnostics: Diagnostics<SemanticDiagnostic>,
-    expr_lookup: UnorderedHashMap<ast::ExprPtr, ExprId>,
-    resolved_lookback: Arc<ResolvedLookback>,
-    definition: Arc<FreeFunctionDefinition>,
-}
-
-#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
-#[debug_db(dyn SemanticGroup + 'static)]
-pub struct FreeFunctionDefinition {
-    pub exprs: Arena<semantic::Expr>,
-    pub statements: Arena<semantic::Statement>,
-    pub body: semantic::ExprId,
-    /// The set of direct callees of the free function (user functions and libfuncs that are called
-    /// from this free function). The items in the vector are unique.
-    pub direct_callees: Vec<FunctionId>,
-}
-
 // --- Selectors ---
 
 /// Query implementation of [crate::db::SemanticGroup::free_function_definition_diagnostics].
@@ -173,41 +147,6 @@ pub fn free_function_definition_body(
     Some(db.priv_free_function_definition_data(free_function_id)?.definition.body)
 }
 
-/// Query implementation of [crate::db::SemanticGroup::free_function_definition_direct_callees].
-pub fn free_function_definition_direct_callees(
-    db: &dyn SemanticGroup,
-    free_function_id: FreeFunctionId,
-) -> Option<Vec<FunctionId>> {
-    Some(db.priv_free_function_definition_data(free_function_id)?.definition.direct_callees.clone())
-}
-
-/// Query implementation of
-/// [crate::db::SemanticGroup::free_function_definition_direct_free_function_callees].
-pub fn free_function_definition_direct_free_function_callees(
-    db: &dyn SemanticGroup,
-    free_function_id: FreeFunctionId,
-) -> Option<Vec<FreeFunctionId>> {
-    Some(
-        db.free_function_definition_direct_callees(free_function_id)?
-            .into_iter()
-            .filter_map(|function_id| {
-                match db.lookup_intern_function(function_id).function.generic_function {
-                    GenericFunctionId::Free(free_function) => Some(free_function),
-                    _ => None,
-                }
-            })
-            .collect(),
-    )
-}
-
-/// Query implementation of [crate::db::SemanticGroup::free_function_definition].
-pub fn free_function_definition(
-    db: &dyn SemanticGroup,
-    free_function_id: FreeFunctionId,
-) -> Option<Arc<FreeFunctionDefinition>> {
-    Some(db.priv_free_function_definition_data(free_function_id)?.definition)
-}
-
 /// Query implementation of [crate::db::SemanticGroup::free_function_definition_resolved_lookback].
 pub fn free_function_definition_resolved_lookback(
     db: &dyn SemanticGroup,
@@ -222,102 +161,21 @@ pub fn free_function_definition_resolved_lookback(
 pub fn priv_free_function_definition_data(
     db: &dyn SemanticGroup,
     free_function_id: FreeFunctionId,
-) -> Option<FreeFunctionDefinitionData> {
+) -> Option<FunctionDefinitionData> {
     let module_file_id = free_function_id.module_file(db.upcast());
-    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let diagnostics = SemanticDiagnostics::new(module_file_id);
     let module_data = db.module_data(module_file_id.0)?;
     let syntax = module_data.free_functions.get(&free_function_id)?.clone();
     // Compute signature semantic.
     let declaration = db.priv_free_function_declaration_data(free_function_id)?;
     let resolver = Resolver::new(db, module_file_id, &declaration.generic_params);
-    let environment = declaration.environment;
     // Compute body semantic expr.
-    let mut ctx = ComputationContext::new(
+    compute_function_definition_data(
         db,
-        &mut diagnostics,
+        diagnostics,
         resolver,
         &declaration.signature,
-        environment,
-    );
-    let expr = compute_expr_block_semantic(&mut ctx, &syntax.body(db.upcast()))?;
-    if expr.ty() != declaration.signature.return_type
-        && expr.ty() != semantic::TypeId::missing(db)
-        && expr.ty() != never_ty(db)
-    {
-        ctx.diagnostics.report(
-            &syntax.body(db.upcast()),
-            SemanticDiagnosticKind::WrongReturnType {
-                expected_ty: declaration.signature.return_type,
-                actual_ty: expr.ty(),
-            },
-        );
-    }
-    let body = ctx.exprs.alloc(expr);
-    let ComputationContext { exprs, statements, resolver, .. } = ctx;
-
-    let direct_callees: HashSet<FunctionId> = exprs
-        .iter()
-        .filter_map(|(_id, expr)| try_extract_matches!(expr, Expr::FunctionCall))
-        .map(|f| f.function)
-        .collect();
-
-    let expr_lookup: UnorderedHashMap<_, _> =
-        exprs.iter().map(|(expr_id, expr)| (expr.stable_ptr(), expr_id)).collect();
-    let resolved_lookback = Arc::new(resolver.lookback);
-    Some(FreeFunctionDefinitionData {
-        diagnostics: diagnostics.build(),
-        expr_lookup,
-        resolved_lookback,
-        definition: Arc::new(FreeFunctionDefinition {
-            exprs,
-            statements,
-            body,
-            direct_callees: direct_callees.into_iter().collect(),
-        }),
-    })
-}
-
-/// Query implementation of [crate::db::SemanticGroup::expr_semantic].
-/// Assumes function and expression are present.
-pub fn expr_semantic(
-    db: &dyn SemanticGroup,
-    free_function_id: FreeFunctionId,
-    id: semantic::ExprId,
-) -> semantic::Expr {
-    db.priv_free_function_definition_data(free_function_id)
-        .unwrap()
-        .definition
-        .exprs
-        .get(id)
-        .unwrap()
-        .clone()
-}
-
-/// Query implementation of [crate::db::SemanticGroup::statement_semantic].
-/// Assumes function and statement are valid.
-pub fn statement_semantic(
-    db: &dyn SemanticGroup,
-    free_function_id: FreeFunctionId,
-    id: semantic::StatementId,
-) -> semantic::Statement {
-    db.priv_free_function_definition_data(free_function_id)
-        .unwrap()
-        .definition
-        .statements
-        .get(id)
-        .unwrap()
-        .clone()
-}
-
-pub trait SemanticExprLookup<'a>: Upcast<dyn SemanticGroup + 'a> {
-    fn lookup_expr_by_ptr(
-        &self,
-        free_function_id: FreeFunctionId,
-        ptr: ast::ExprPtr,
-    ) -> Option<ExprId> {
-        let definition_data = self.upcast().priv_free_function_definition_data(free_function_id)?;
-        definition_data.expr_lookup.get(&ptr).copied()
-    }
+        declaration.environment,
+        &syntax.body(db.upcast()),
+    )
 }
-
-impl<'a, T: Upcast<dyn SemanticGroup + 'a> + ?Sized> SemanticExprLookup<'a> for T {}
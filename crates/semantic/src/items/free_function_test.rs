@@ -1,13 +1,13 @@
 use debug::DebugWithDb;
 use defs::db::DefsGroup;
-use defs::ids::ModuleItemId;
+use defs::ids::{FunctionWithBodyId, ModuleItemId};
 use pretty_assertions::assert_eq;
 use test_log::test;
 use utils::extract_matches;
 
 use crate::db::SemanticGroup;
 use crate::expr::fmt::ExprFormatter;
-use crate::items::free_function::SemanticExprLookup;
+use crate::items::function_with_body::SemanticExprLookup;
 use crate::test_utils::{setup_test_module, SemanticDatabaseForTesting};
 
 #[test]
@@ -35,11 +35,12 @@ fn test_expr_lookup() {
         db.module_item_by_name(module_id, "foo".into()).unwrap(),
         ModuleItemId::FreeFunction
     );
-    let expr_formatter = ExprFormatter { db, free_function_id };
+    let function_id = FunctionWithBodyId::Free(free_function_id);
+    let expr_formatter = ExprFormatter { db, function_id };
     let definition_data = db.priv_free_function_definition_data(free_function_id).unwrap();
     let mut expr_debugs = Vec::new();
     for (expr_id, expr) in &definition_data.definition.exprs {
-        assert_eq!(db.lookup_expr_by_ptr(free_function_id, expr.stable_ptr()), Some(expr_id));
+        assert_eq!(db.lookup_expr_by_ptr(function_id, expr.stable_ptr()), Some(expr_id));
         expr_debugs.push(format!("{:?}", expr.debug(&expr_formatter)));
     }
     expr_debugs.sort();
@@ -47,14 +48,14 @@ fn test_expr_lookup() {
         expr_debugs,
         [
             "Block(ExprBlock { statements: [Let(StatementLet { pattern: Variable(x), expr: \
-             FunctionCall(ExprFunctionCall { function: core::felt_add, ref_args: [], args: \
+             FunctionCall(ExprFunctionCall { function: core::FeltAdd::add, ref_args: [], args: \
              [Literal(ExprLiteral { value: 5, ty: core::felt }), Literal(ExprLiteral { value: 5, \
              ty: core::felt })], ty: core::felt }) })], tail: Some(Match(ExprMatch { \
-             matched_expr: FunctionCall(ExprFunctionCall { function: core::felt_mul, ref_args: \
-             [], args: [Literal(ExprLiteral { value: 1, ty: core::felt }), Literal(ExprLiteral { \
-             value: 1, ty: core::felt })], ty: core::felt }), arms: [MatchArm { pattern: \
-             Literal(PatternLiteral { literal: ExprLiteral { value: 0, ty: core::felt }, ty: \
-             core::felt }), expression: Block(ExprBlock { statements: [], tail: \
+             matched_expr: FunctionCall(ExprFunctionCall { function: core::FeltMul::mul, \
+             ref_args: [], args: [Literal(ExprLiteral { value: 1, ty: core::felt }), \
+             Literal(ExprLiteral { value: 1, ty: core::felt })], ty: core::felt }), arms: \
+             [MatchArm { pattern: Literal(PatternLiteral { literal: ExprLiteral { value: 0, ty: \
+             core::felt }, ty: core::felt }), expression: Block(ExprBlock { statements: [], tail: \
              Some(Literal(ExprLiteral { value: 5, ty: core::felt })), ty: core::felt }) }, \
              MatchArm { pattern: Otherwise(PatternOtherwise { ty: core::felt }), expression: \
              Block(ExprBlock { statements: [], tail: Some(Literal(ExprLiteral { value: 6, ty: \
@@ -63,10 +64,10 @@ fn test_expr_lookup() {
              core::felt })), ty: core::felt })",
             "Block(ExprBlock { statements: [], tail: Some(Literal(ExprLiteral { value: 6, ty: \
              core::felt })), ty: core::felt })",
-            "FunctionCall(ExprFunctionCall { function: core::felt_add, ref_args: [], args: \
+            "FunctionCall(ExprFunctionCall { function: core::FeltAdd::add, ref_args: [], args: \
              [Literal(ExprLiteral { value: 5, ty: core::felt }), Literal(ExprLiteral { value: 5, \
              ty: core::felt })], ty: core::felt })",
-            "FunctionCall(ExprFunctionCall { function: core::felt_mul, ref_args: [], args: \
+            "FunctionCall(ExprFunctionCall { function: core::FeltMul::mul, ref_args: [], args: \
              [Literal(ExprLiteral { value: 1, ty: core::felt }), Literal(ExprLiteral { value: 1, \
              ty: core::felt })], ty: core::felt })",
             "Literal(ExprLiteral { value: 1, ty: core::felt })",
@@ -76,14 +77,14 @@ fn test_expr_lookup() {
             "Literal(ExprLiteral { value: 5, ty: core::felt })",
             "Literal(ExprLiteral { value: 6, ty: core::felt })",
             "Match(ExprMatch { matched_expr: FunctionCall(ExprFunctionCall { function: \
-             core::felt_mul, ref_args: [], args: [Literal(ExprLiteral { value: 1, ty: core::felt \
-             }), Literal(ExprLiteral { value: 1, ty: core::felt })], ty: core::felt }), arms: \
-             [MatchArm { pattern: Literal(PatternLiteral { literal: ExprLiteral { value: 0, ty: \
-             core::felt }, ty: core::felt }), expression: Block(ExprBlock { statements: [], tail: \
-             Some(Literal(ExprLiteral { value: 5, ty: core::felt })), ty: core::felt }) }, \
-             MatchArm { pattern: Otherwise(PatternOtherwise { ty: core::felt }), expression: \
-             Block(ExprBlock { statements: [], tail: Some(Literal(ExprLiteral { value: 6, ty: \
-             core::felt })), ty: core::felt }) }], ty: core::felt })",
+             core::FeltMul::mul, ref_args: [], args: [Literal(ExprLiteral { value: 1, ty: \
+             core::felt }), Literal(ExprLiteral { value: 1, ty: core::felt })], ty: core::felt \
+             }), arms: [MatchArm { pattern: Literal(PatternLiteral { literal: ExprLiteral { \
+             value: 0, ty: core::felt }, ty: core::felt }), expression: Block(ExprBlock { \
+             statements: [], tail: Some(Literal(ExprLiteral { value: 5, ty: core::felt })), ty: \
+             core::felt }) }, MatchArm { pattern: Otherwise(PatternOtherwise { ty: core::felt }), \
+             expression: Block(ExprBlock { statements: [], tail: Some(Literal(ExprLiteral { \
+             value: 6, ty: core::felt })), ty: core::felt }) }], ty: core::felt })",
         ]
     );
 
@@ -0,0 +1,210 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use db_utils::Upcast;
+use defs::ids::{FunctionWithBodyId, GenericParamId};
+use diagnostics::Diagnostics;
+use diagnostics_proc_macros::DebugWithDb;
+use id_arena::Arena;
+use syntax::node::ast;
+use utils::try_extract_matches;
+use utils::unordered_hash_map::UnorderedHashMap;
+
+use crate::corelib::{get_core_function_id, never_ty};
+use crate::db::SemanticGroup;
+use crate::diagnostic::{SemanticDiagnosticKind, SemanticDiagnostics};
+use crate::expr::compute::{compute_expr_block_semantic, ComputationContext, Environment};
+use crate::resolve_path::{ResolvedLookback, Resolver};
+use crate::{semantic, Expr, ExprId, FunctionId, SemanticDiagnostic};
+
+// === Definition ===
+
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub struct FunctionDefinitionData {
+    pub diagnostics: Diagnostics<SemanticDiagnostic>,
+    pub expr_lookup: UnorderedHashMap<ast::ExprPtr, ExprId>,
+    pub resolved_lookback: Arc<ResolvedLookback>,
+    pub definition: Arc<FunctionDefinition>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub struct FunctionDefinition {
+    pub exprs: Arena<semantic::Expr>,
+    pub statements: Arena<semantic::Statement>,
+    pub body: semantic::ExprId,
+    /// The set of direct callees of the function (user functions and libfuncs that are called
+    /// from this function). The items in the vector are unique.
+    pub direct_callees: Vec<FunctionId>,
+}
+
+// --- Selectors ---
+
+/// Query implementation of [crate::db::SemanticGroup::function_with_body_signature].
+pub fn function_with_body_signature(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+) -> Option<semantic::Signature> {
+    match function_id {
+        FunctionWithBodyId::Free(free_function_id) => {
+            db.free_function_declaration_signature(free_function_id)
+        }
+        FunctionWithBodyId::Impl(impl_function_id) => db.impl_function_signature(impl_function_id),
+    }
+}
+
+/// Query implementation of [crate::db::SemanticGroup::function_with_body_generic_params].
+pub fn function_with_body_generic_params(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+) -> Option<Vec<GenericParamId>> {
+    match function_id {
+        FunctionWithBodyId::Free(free_function_id) => {
+            db.free_function_declaration_generic_params(free_function_id)
+        }
+        FunctionWithBodyId::Impl(impl_function_id) => {
+            db.impl_function_generic_params(impl_function_id)
+        }
+    }
+}
+
+/// Query implementation of [crate::db::SemanticGroup::function_with_body_definition].
+pub fn function_with_body_definition(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+) -> Option<Arc<FunctionDefinition>> {
+    Some(priv_function_with_body_definition_data(db, function_id)?.definition)
+}
+
+/// Query implementation of [crate::db::SemanticGroup::function_with_body_direct_callees].
+pub fn function_with_body_direct_callees(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+) -> Option<Vec<FunctionId>> {
+    Some(db.function_with_body_definition(function_id)?.direct_callees.clone())
+}
+
+/// Query implementation of
+/// [crate::db::SemanticGroup::function_with_body_direct_function_with_body_callees].
+pub fn function_with_body_direct_function_with_body_callees(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+) -> Option<Vec<FunctionWithBodyId>> {
+    Some(
+        db.function_with_body_direct_callees(function_id)?
+            .into_iter()
+            .filter_map(|function_id| {
+                db.lookup_intern_function(function_id)
+                    .function
+                    .generic_function
+                    .function_with_body()
+            })
+            .collect(),
+    )
+}
+
+/// Query implementation of [crate::db::SemanticGroup::expr_semantic].
+/// Assumes function and expression are present.
+pub fn expr_semantic(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+    id: semantic::ExprId,
+) -> semantic::Expr {
+    db.function_with_body_definition(function_id).unwrap().exprs.get(id).unwrap().clone()
+}
+
+/// Query implementation of [crate::db::SemanticGroup::statement_semantic].
+/// Assumes function and statement are valid.
+pub fn statement_semantic(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+    id: semantic::StatementId,
+) -> semantic::Statement {
+    db.function_with_body_definition(function_id).unwrap().statements.get(id).unwrap().clone()
+}
+
+/// Returns the definition data of a function with a body.
+fn priv_function_with_body_definition_data(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+) -> Option<FunctionDefinitionData> {
+    match function_id {
+        FunctionWithBodyId::Free(free_function_id) => {
+            db.priv_free_function_definition_data(free_function_id)
+        }
+        FunctionWithBodyId::Impl(impl_function_id) => {
+            db.priv_impl_function_definition_data(impl_function_id)
+        }
+    }
+}
+
+pub trait SemanticExprLookup<'a>: Upcast<dyn SemanticGroup + 'a> {
+    fn lookup_expr_by_ptr(
+        &self,
+        function_id: FunctionWithBodyId,
+        ptr: ast::ExprPtr,
+    ) -> Option<ExprId> {
+        let definition_data = priv_function_with_body_definition_data(self.upcast(), function_id)?;
+        definition_data.expr_lookup.get(&ptr).copied()
+    }
+}
+
+impl<'a, T: Upcast<dyn SemanticGroup + 'a> + ?Sized> SemanticExprLookup<'a> for T {}
+
+// --- Computation ---
+
+/// Computes the semantic model of the body of a function, given its signature and the environment
+/// of its parameters.
+pub fn compute_function_definition_data(
+    db: &dyn SemanticGroup,
+    mut diagnostics: SemanticDiagnostics,
+    resolver: Resolver<'_>,
+    signature: &semantic::Signature,
+    environment: Environment,
+    body_syntax: &ast::ExprBlock,
+) -> Option<FunctionDefinitionData> {
+    let mut ctx = ComputationContext::new(db, &mut diagnostics, resolver, signature, environment);
+    let expr = compute_expr_block_semantic(&mut ctx, body_syntax)?;
+    if expr.ty() != signature.return_type
+        && expr.ty() != semantic::TypeId::missing(db)
+        && expr.ty() != never_ty(db)
+    {
+        ctx.diagnostics.report(
+            body_syntax,
+            SemanticDiagnosticKind::WrongReturnType {
+                expected_ty: signature.return_type,
+                actual_ty: expr.ty(),
+            },
+        );
+    }
+    let body = ctx.exprs.alloc(expr);
+    let ComputationContext { exprs, statements, resolver, .. } = ctx;
+
+    let mut direct_callees: HashSet<FunctionId> = exprs
+        .iter()
+        .filter_map(|(_id, expr)| try_extract_matches!(expr, Expr::FunctionCall))
+        .map(|f| f.function)
+        .collect();
+    // Loops are lowered into functions that consume gas on each iteration, and panic when running
+    // out of gas.
+    if exprs.iter().any(|(_id, expr)| matches!(expr, Expr::Loop(_) | Expr::While(_))) {
+        direct_callees.insert(get_core_function_id(db, "get_gas".into(), vec![]));
+        direct_callees.insert(get_core_function_id(db, "panic".into(), vec![]));
+    }
+
+    let expr_lookup: UnorderedHashMap<_, _> =
+        exprs.iter().map(|(expr_id, expr)| (expr.stable_ptr(), expr_id)).collect();
+    let resolved_lookback = Arc::new(resolver.lookback);
+    Some(FunctionDefinitionData {
+        diagnostics: diagnostics.build(),
+        expr_lookup,
+        resolved_lookback,
+        definition: Arc::new(FunctionDefinition {
+            exprs,
+            statements,
+            body,
+            direct_callees: direct_callees.into_iter().collect(),
+        }),
+    })
+}
@@ -2,6 +2,7 @@ use db_utils::define_short_id;
 use debug::DebugWithDb;
 use defs::ids::{ExternFunctionId, GenericFunctionId, GenericParamId, ParamLongId};
 use diagnostics_proc_macros::DebugWithDb;
+use itertools::chain;
 use smol_str::SmolStr;
 use syntax::node::{ast, Terminal, TypedSyntaxNode};
 use utils::try_extract_matches;
@@ -190,6 +191,8 @@ pub fn generic_function_signature(
             db.trait_function_signature(trait_function)
         }
         GenericFunctionId::ImplFunction(impl_function) => db.impl_function_signature(impl_function),
+        // The signature of a loop function is only known after lowering its parent function.
+        GenericFunctionId::Loop(_) => None,
     }
 }
 /// Query implementation of [crate::db::SemanticGroup::generic_function_generic_params].
@@ -204,12 +207,18 @@ pub fn generic_function_generic_params(
         GenericFunctionId::Extern(extern_function) => {
             db.extern_function_declaration_generic_params(extern_function)
         }
-        GenericFunctionId::TraitFunction(trait_function) => {
-            db.trait_function_generic_params(trait_function)
-        }
+        // Like the generic params of an impl function, these start with the ones of the trait.
+        GenericFunctionId::TraitFunction(trait_function) => Some(
+            chain!(
+                db.trait_generic_params(trait_function.trait_id(db.upcast()))?,
+                db.trait_function_generic_params(trait_function)?
+            )
+            .collect(),
+        ),
         GenericFunctionId::ImplFunction(impl_function) => {
             db.impl_function_generic_params(impl_function)
         }
+        GenericFunctionId::Loop(_) => Some(vec![]),
     }
 }
 
@@ -1,13 +1,93 @@
 use defs::ids::{GenericParamId, GenericParamLongId, ModuleFileId};
+use diagnostics::Diagnostics;
+use diagnostics_proc_macros::DebugWithDb;
 use syntax::node::{ast, TypedSyntaxNode};
+use utils::{try_extract_matches, OptionHelper};
 
 use crate::db::SemanticGroup;
+use crate::diagnostic::SemanticDiagnosticKind::*;
 use crate::diagnostic::SemanticDiagnostics;
+use crate::resolve_path::{ResolvedGenericItem, Resolver};
+use crate::{
+    ConcreteTraitId, ConcreteTraitLongId, GenericArgumentId, SemanticDiagnostic, TypeId, TypeLongId,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub struct GenericParamData {
+    diagnostics: Diagnostics<SemanticDiagnostic>,
+    /// The concrete traits the generic param is required to implement. E.g. `Copy::<T>` for
+    /// `T: Copy`.
+    bounds: Vec<ConcreteTraitId>,
+}
+
+/// Query implementation of [crate::db::SemanticGroup::generic_param_bounds].
+pub fn generic_param_bounds(
+    db: &dyn SemanticGroup,
+    generic_param_id: GenericParamId,
+) -> Option<Vec<ConcreteTraitId>> {
+    Some(db.priv_generic_param_data(generic_param_id)?.bounds)
+}
+
+/// Query implementation of [crate::db::SemanticGroup::priv_generic_param_data].
+pub fn priv_generic_param_data(
+    db: &dyn SemanticGroup,
+    generic_param_id: GenericParamId,
+) -> Option<GenericParamData> {
+    let syntax_db = db.upcast();
+    let GenericParamLongId(module_file_id, ptr) = db.lookup_intern_generic_param(generic_param_id);
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let file_syntax = db.file_syntax(db.module_file(module_file_id)?)?;
+    let param_syntax = ast::GenericParam::from_ptr(syntax_db, &file_syntax, ptr);
+
+    // The bounds are resolved in the scope of all the generic params of the same item.
+    let param_list_syntax =
+        ast::GenericParamList::from_syntax_node(syntax_db, param_syntax.as_syntax_node().parent()?);
+    let generic_params = intern_generic_params(db, module_file_id, &param_list_syntax);
+    let mut resolver = Resolver::new(db, module_file_id, &generic_params);
+
+    let ty = db.intern_type(TypeLongId::GenericParameter(generic_param_id));
+    let bounds = match param_syntax.bounds(syntax_db) {
+        ast::OptionGenericParamBounds::Empty(_) => vec![],
+        ast::OptionGenericParamBounds::GenericParamBounds(bounds_syntax) => bounds_syntax
+            .traits(syntax_db)
+            .elements(syntax_db)
+            .iter()
+            .filter_map(|path| resolve_bound(db, &mut diagnostics, &mut resolver, ty, path))
+            .collect(),
+    };
+
+    Some(GenericParamData { diagnostics: diagnostics.build(), bounds })
+}
+
+/// Resolves a single trait bound of a generic param, given as a path to a trait with a single
+/// generic param. Returns the trait, concretized with the type of the generic param.
+fn resolve_bound(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    resolver: &mut Resolver<'_>,
+    ty: TypeId,
+    path: &ast::ExprPath,
+) -> Option<ConcreteTraitId> {
+    let trait_id = resolver.resolve_generic_path(diagnostics, path).and_then(|item| {
+        try_extract_matches!(item, ResolvedGenericItem::Trait)
+            .on_none(|| diagnostics.report(path, NotATrait))
+    })?;
+    let expected = db.trait_generic_params(trait_id)?.len();
+    if expected != 1 {
+        diagnostics.report(path, WrongNumberOfGenericArguments { expected, actual: 1 });
+        return None;
+    }
+    Some(db.intern_concrete_trait(ConcreteTraitLongId {
+        trait_id,
+        generic_args: vec![GenericArgumentId::Type(ty)],
+    }))
+}
 
 /// Returns the parameters of the given function signature's AST.
 pub fn semantic_generic_params(
     db: &dyn SemanticGroup,
-    _diagnostics: &mut SemanticDiagnostics,
+    diagnostics: &mut SemanticDiagnostics,
     module_file_id: ModuleFileId,
     generic_args: &ast::OptionWrappedGenericParamList,
 ) -> Vec<GenericParamId> {
@@ -15,16 +95,31 @@ pub fn semantic_generic_params(
 
     match generic_args {
         syntax::node::ast::OptionWrappedGenericParamList::Empty(_) => vec![],
-        syntax::node::ast::OptionWrappedGenericParamList::WrappedGenericParamList(syntax) => syntax
-            .generic_params(syntax_db)
-            .elements(syntax_db)
-            .iter()
-            .map(|param_syntax| {
-                db.intern_generic_param(GenericParamLongId(
-                    module_file_id,
-                    param_syntax.stable_ptr(),
-                ))
-            })
-            .collect(),
+        syntax::node::ast::OptionWrappedGenericParamList::WrappedGenericParamList(syntax) => {
+            let generic_params =
+                intern_generic_params(db, module_file_id, &syntax.generic_params(syntax_db));
+            for generic_param in &generic_params {
+                if let Some(data) = db.priv_generic_param_data(*generic_param) {
+                    diagnostics.diagnostics.extend(data.diagnostics);
+                }
+            }
+            generic_params
+        }
     }
 }
+
+/// Interns the generic params in the given list.
+fn intern_generic_params(
+    db: &dyn SemanticGroup,
+    module_file_id: ModuleFileId,
+    param_list_syntax: &ast::GenericParamList,
+) -> Vec<GenericParamId> {
+    let syntax_db = db.upcast();
+    param_list_syntax
+        .elements(syntax_db)
+        .iter()
+        .map(|param_syntax| {
+            db.intern_generic_param(GenericParamLongId(module_file_id, param_syntax.stable_ptr()))
+        })
+        .collect()
+}
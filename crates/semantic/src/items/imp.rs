@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::vec;
 
@@ -8,16 +9,18 @@ use defs::ids::{
 };
 use diagnostics::{Diagnostics, DiagnosticsBuilder};
 use diagnostics_proc_macros::DebugWithDb;
-use itertools::izip;
+use itertools::{chain, izip, zip_eq};
 use syntax::node::ast::{self, Item, MaybeImplBody, OptionReturnTypeClause};
 use syntax::node::db::SyntaxGroup;
 use syntax::node::ids::SyntaxStablePtrId;
 use syntax::node::TypedSyntaxNode;
 use utils::ordered_hash_map::OrderedHashMap;
+use utils::ordered_hash_set::OrderedHashSet;
 use utils::{extract_matches, try_extract_matches, OptionHelper};
 
 use super::attribute::{ast_attributes_to_semantic, Attribute};
 use super::enm::SemanticEnumEx;
+use super::function_with_body::{compute_function_definition_data, FunctionDefinitionData};
 use super::generics::semantic_generic_params;
 use super::strct::SemanticStructEx;
 use crate::corelib::{copy_trait, drop_trait};
@@ -26,6 +29,7 @@ use crate::diagnostic::SemanticDiagnosticKind::*;
 use crate::diagnostic::SemanticDiagnostics;
 use crate::expr::compute::Environment;
 use crate::resolve_path::{ResolvedConcreteItem, ResolvedGenericItem, ResolvedLookback, Resolver};
+use crate::types::{infer_generic_args_from_list, substitute_generics};
 use crate::{
     semantic, ConcreteTraitId, ConcreteTraitLongId, GenericArgumentId, Mutability,
     SemanticDiagnostic, TypeId, TypeLongId,
@@ -142,6 +146,7 @@ pub fn impl_semantic_definition_diagnostics(
     diagnostics.extend(data.diagnostics);
     for impl_function_id in data.function_asts.keys() {
         diagnostics.extend(db.impl_function_declaration_diagnostics(*impl_function_id));
+        diagnostics.extend(db.impl_function_definition_diagnostics(*impl_function_id));
     }
 
     diagnostics.build()
@@ -186,6 +191,13 @@ pub fn priv_impl_definition_data(
         concrete_trait,
         impl_ast.stable_ptr().untyped(),
     );
+    check_impl_overlaps(
+        db,
+        &mut diagnostics,
+        impl_id,
+        concrete_trait,
+        impl_ast.name(syntax_db).stable_ptr().untyped(),
+    );
 
     let mut function_asts = OrderedHashMap::default();
 
@@ -199,6 +211,11 @@ pub fn priv_impl_definition_data(
                 Item::Use(use_item) => {
                     report_invalid_in_impl(syntax_db, &mut diagnostics, use_item.use_kw(syntax_db))
                 }
+                Item::Constant(constant) => report_invalid_in_impl(
+                    syntax_db,
+                    &mut diagnostics,
+                    constant.const_kw(syntax_db),
+                ),
                 Item::ExternFunction(extern_func) => report_invalid_in_impl(
                     syntax_db,
                     &mut diagnostics,
@@ -309,6 +326,8 @@ fn get_inner_types(db: &dyn SemanticGroup, ty: TypeId) -> Option<Vec<TypeId>> {
 }
 
 /// Query implementation of [crate::db::SemanticGroup::find_impls_at_module].
+/// The generic arguments of generic impls are inferred from the concrete trait. The trait bounds
+/// of their generic params are not checked here, as they depend on the lookup context.
 pub fn find_impls_at_module(
     db: &dyn SemanticGroup,
     module_id: ModuleId,
@@ -316,24 +335,41 @@ pub fn find_impls_at_module(
 ) -> Option<Vec<ConcreteImplId>> {
     let mut res = Vec::new();
     let impls = db.module_data(module_id)?.impls;
+    let ConcreteTraitLongId { trait_id, generic_args } =
+        db.lookup_intern_concrete_trait(concrete_trait_id);
     // TODO(spapini): Index better.
     for impl_id in impls.keys().copied() {
-        let Some(imp_data)= db.priv_impl_declaration_data(impl_id) else {continue};
-        if !imp_data.generic_params.is_empty() {
-            // TODO(spapini): Infer generics and substitute.
+        let Some(imp_data) = db.priv_impl_declaration_data(impl_id) else { continue };
+        let Some(impl_concrete_trait) = imp_data.concrete_trait else { continue };
+        let impl_concrete_trait = db.lookup_intern_concrete_trait(impl_concrete_trait);
+        if impl_concrete_trait.trait_id != trait_id {
             continue;
         }
-
-        if imp_data.concrete_trait == Some(concrete_trait_id) {
-            let concrete_impl_id =
-                db.intern_concrete_impl(ConcreteImplLongId { impl_id, generic_args: vec![] });
-            res.push(concrete_impl_id);
+        let mut substitution = HashMap::new();
+        if !infer_generic_args_from_list(
+            db,
+            &mut substitution,
+            &impl_concrete_trait.generic_args,
+            &generic_args,
+        ) {
+            continue;
         }
+        // Generic params that do not appear in the concrete trait can not be inferred.
+        let Some(impl_generic_args) = imp_data
+            .generic_params
+            .iter()
+            .map(|generic_param| substitution.get(generic_param).copied())
+            .collect::<Option<Vec<_>>>() else { continue };
+        res.push(
+            db.intern_concrete_impl(ConcreteImplLongId {
+                impl_id,
+                generic_args: impl_generic_args,
+            }),
+        );
     }
     Some(res)
 }
 
-#[allow(dead_code)]
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ImplLookupContext {
     pub module_id: ModuleId,
@@ -341,29 +377,310 @@ pub struct ImplLookupContext {
     pub generic_params: Vec<GenericParamId>,
 }
 
+/// The maximal depth of nested impl lookups caused by trait bounds of generic impls.
+const MAX_IMPL_LOOKUP_DEPTH: usize = 64;
+
 /// Finds all the implementations for a concrete trait, in a specific lookup context.
+/// Besides the modules of the context, impls are also looked up in the module of the trait and in
+/// the modules of the types the trait is applied to.
 pub fn find_impls_at_context(
     db: &dyn SemanticGroup,
     lookup_context: &ImplLookupContext,
     concrete_trait_id: ConcreteTraitId,
 ) -> Option<Vec<ConcreteImplId>> {
+    find_impls_at_context_ex(db, lookup_context, concrete_trait_id, &mut vec![])
+}
+
+/// Returns whether a concrete trait is implemented in a specific lookup context, either by an
+/// impl or by a trait bound of one of the generic params of the context.
+pub fn has_impl_at_context(
+    db: &dyn SemanticGroup,
+    lookup_context: &ImplLookupContext,
+    concrete_trait_id: ConcreteTraitId,
+) -> Option<bool> {
+    has_impl_at_context_ex(db, lookup_context, concrete_trait_id, &mut vec![])
+}
+
+/// Implementation of [find_impls_at_context]. `stack` holds the concrete traits whose lookup is
+/// in progress.
+fn find_impls_at_context_ex(
+    db: &dyn SemanticGroup,
+    lookup_context: &ImplLookupContext,
+    concrete_trait_id: ConcreteTraitId,
+    stack: &mut Vec<ConcreteTraitId>,
+) -> Option<Vec<ConcreteImplId>> {
+    let module_data = db.module_data(lookup_context.module_id)?;
+    let used_modules = module_data.uses.keys().filter_map(|use_id| {
+        try_extract_matches!(db.use_resolved_item(*use_id)?, ResolvedGenericItem::Module)
+    });
+    let modules: OrderedHashSet<ModuleId> = chain!(
+        [lookup_context.module_id],
+        lookup_context.extra_modules.iter().copied(),
+        concrete_trait_modules(db, concrete_trait_id),
+        db.module_submodules(lookup_context.module_id)?,
+        used_modules,
+    )
+    .collect();
+
     let mut res = Vec::new();
-    // TODO(spapini): Lookup in generic_params once impl generic params are supported.
-    res.extend(find_impls_at_module(db, lookup_context.module_id, concrete_trait_id)?);
-    for module_id in &lookup_context.extra_modules {
-        if let Some(imps) = find_impls_at_module(db, *module_id, concrete_trait_id) {
-            res.extend(imps);
+    for module_id in modules {
+        for concrete_impl_id in
+            db.find_impls_at_module(module_id, concrete_trait_id).unwrap_or_default()
+        {
+            if impl_bounds_satisfied(db, lookup_context, concrete_impl_id, stack)? {
+                res.push(concrete_impl_id);
+            }
+        }
+    }
+    Some(res)
+}
+
+/// Implementation of [has_impl_at_context]. `stack` holds the concrete traits whose lookup is in
+/// progress.
+fn has_impl_at_context_ex(
+    db: &dyn SemanticGroup,
+    lookup_context: &ImplLookupContext,
+    concrete_trait_id: ConcreteTraitId,
+    stack: &mut Vec<ConcreteTraitId>,
+) -> Option<bool> {
+    // An impl whose bounds (transitively) require the trait it implements proves nothing.
+    if stack.contains(&concrete_trait_id) || stack.len() >= MAX_IMPL_LOOKUP_DEPTH {
+        return Some(false);
+    }
+    stack.push(concrete_trait_id);
+    let res = has_impl_at_context_inner(db, lookup_context, concrete_trait_id, stack);
+    stack.pop();
+    res
+}
+
+fn has_impl_at_context_inner(
+    db: &dyn SemanticGroup,
+    lookup_context: &ImplLookupContext,
+    concrete_trait_id: ConcreteTraitId,
+    stack: &mut Vec<ConcreteTraitId>,
+) -> Option<bool> {
+    for generic_param in &lookup_context.generic_params {
+        if db.generic_param_bounds(*generic_param).unwrap_or_default().contains(&concrete_trait_id)
+        {
+            return Some(true);
+        }
+    }
+
+    // Tuples are Copy and Drop if all their members are.
+    let ConcreteTraitLongId { trait_id, generic_args } =
+        db.lookup_intern_concrete_trait(concrete_trait_id);
+    if trait_id == copy_trait(db) || trait_id == drop_trait(db) {
+        if let [GenericArgumentId::Type(ty)] = generic_args[..] {
+            if let TypeLongId::Tuple(tys) = db.lookup_intern_type(ty) {
+                for ty in tys {
+                    let member_trait_id = db.intern_concrete_trait(ConcreteTraitLongId {
+                        trait_id,
+                        generic_args: vec![GenericArgumentId::Type(ty)],
+                    });
+                    if !has_impl_at_context_ex(db, lookup_context, member_trait_id, stack)? {
+                        return Some(false);
+                    }
+                }
+                return Some(true);
+            }
         }
     }
-    for submodule in db.module_submodules(lookup_context.module_id)? {
-        res.extend(find_impls_at_module(db, submodule, concrete_trait_id)?);
+
+    Some(!find_impls_at_context_ex(db, lookup_context, concrete_trait_id, stack)?.is_empty())
+}
+
+/// Returns whether the generic arguments of a concrete impl satisfy the trait bounds of the
+/// generic params of the impl, in a specific lookup context.
+fn impl_bounds_satisfied(
+    db: &dyn SemanticGroup,
+    lookup_context: &ImplLookupContext,
+    concrete_impl_id: ConcreteImplId,
+    stack: &mut Vec<ConcreteTraitId>,
+) -> Option<bool> {
+    let ConcreteImplLongId { impl_id, generic_args } =
+        db.lookup_intern_concrete_impl(concrete_impl_id);
+    let generic_params = db.impl_generic_params(impl_id)?;
+    let substitution: HashMap<_, _> =
+        zip_eq(generic_params.iter().copied(), generic_args).collect();
+    for generic_param in generic_params {
+        for bound in db.generic_param_bounds(generic_param)? {
+            let ConcreteTraitLongId { trait_id, generic_args } =
+                db.lookup_intern_concrete_trait(bound);
+            let generic_args = generic_args
+                .into_iter()
+                .map(|generic_arg| match generic_arg {
+                    GenericArgumentId::Type(ty) => {
+                        GenericArgumentId::Type(substitute_generics(db, &substitution, ty))
+                    }
+                    GenericArgumentId::Literal(_) => generic_arg,
+                })
+                .collect();
+            let bound = db.intern_concrete_trait(ConcreteTraitLongId { trait_id, generic_args });
+            if !has_impl_at_context_ex(db, lookup_context, bound, stack)? {
+                return Some(false);
+            }
+        }
     }
-    for use_id in db.module_data(lookup_context.module_id)?.uses.keys() {
-        if let Some(ResolvedGenericItem::Module(submodule)) = db.use_resolved_item(*use_id) {
-            res.extend(find_impls_at_module(db, submodule, concrete_trait_id)?);
+    Some(true)
+}
+
+/// Returns the modules, other than the ones of the lookup context, in which impls of a concrete
+/// trait are looked up: the module of the trait and the modules of the types it is applied to.
+fn concrete_trait_modules(
+    db: &dyn SemanticGroup,
+    concrete_trait_id: ConcreteTraitId,
+) -> Vec<ModuleId> {
+    let ConcreteTraitLongId { trait_id, generic_args } =
+        db.lookup_intern_concrete_trait(concrete_trait_id);
+    let mut modules = vec![trait_id.module_file(db.upcast()).0];
+    for generic_arg in generic_args {
+        let GenericArgumentId::Type(ty) = generic_arg else { continue };
+        if let TypeLongId::Concrete(concrete_type_id) = db.lookup_intern_type(ty) {
+            modules.push(concrete_type_id.generic_type(db).module(db.upcast()));
         }
     }
-    Some(res)
+    modules
+}
+
+/// Reports an impl that overlaps with another impl of the same trait, i.e. both apply to some
+/// concrete trait. The other impl is looked up in the modules in which impls of the trait are
+/// looked up. Each overlapping pair is reported once, by the impl that comes later in the
+/// ordering of [impl_order_key], unless the other impl does not look up impls in its module.
+fn check_impl_overlaps(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    impl_id: ImplId,
+    concrete_trait_id: ConcreteTraitId,
+    stable_ptr: SyntaxStablePtrId,
+) -> Option<()> {
+    let module_id = impl_id.module_file(db.upcast()).0;
+    let order_key = impl_order_key(db, impl_id)?;
+    let generic_params = db.impl_generic_params(impl_id)?;
+    let ConcreteTraitLongId { trait_id, generic_args } =
+        db.lookup_intern_concrete_trait(concrete_trait_id);
+    let modules: OrderedHashSet<ModuleId> =
+        chain!([module_id], concrete_trait_modules(db, concrete_trait_id)).collect();
+    for other_module_id in modules {
+        for (index, other_impl_id) in
+            db.module_data(other_module_id)?.impls.keys().copied().enumerate()
+        {
+            if other_impl_id == impl_id {
+                continue;
+            }
+            let Some(other_data) = db.priv_impl_declaration_data(other_impl_id) else { continue };
+            let Some(other_concrete_trait_id) = other_data.concrete_trait else { continue };
+            let other_concrete_trait = db.lookup_intern_concrete_trait(other_concrete_trait_id);
+            if other_concrete_trait.trait_id != trait_id {
+                continue;
+            }
+            let generic_params: Vec<_> =
+                chain!(generic_params.iter(), other_data.generic_params.iter()).copied().collect();
+            if !unify_generic_args(
+                db,
+                &mut HashMap::new(),
+                &generic_params,
+                &generic_args,
+                &other_concrete_trait.generic_args,
+            ) {
+                continue;
+            }
+            // The other impl reports the overlap itself if it comes later and finds this impl.
+            let other_finds_this = other_module_id == module_id
+                || concrete_trait_modules(db, other_concrete_trait_id).contains(&module_id);
+            if other_finds_this && order_key < (other_module_id.full_path(db.upcast()), index) {
+                continue;
+            }
+            diagnostics.report_by_ptr(stable_ptr, OverlappingImpls { impl_id, other_impl_id });
+            return Some(());
+        }
+    }
+    Some(())
+}
+
+/// Returns a key by which impls are ordered, independently of the order in which they are looked
+/// up: the path of the module of the impl, and the index of the impl in that module.
+fn impl_order_key(db: &dyn SemanticGroup, impl_id: ImplId) -> Option<(String, usize)> {
+    let module_id = impl_id.module_file(db.upcast()).0;
+    let index = db.module_data(module_id)?.impls.keys().position(|id| *id == impl_id)?;
+    Some((module_id.full_path(db.upcast()), index))
+}
+
+/// Returns whether two lists of generic arguments can be made equal, by substituting the given
+/// generic params in both of them. Records the substitution in `substitution`.
+fn unify_generic_args(
+    db: &dyn SemanticGroup,
+    substitution: &mut HashMap<GenericParamId, TypeId>,
+    generic_params: &[GenericParamId],
+    generic_args0: &[GenericArgumentId],
+    generic_args1: &[GenericArgumentId],
+) -> bool {
+    generic_args0.len() == generic_args1.len()
+        && zip_eq(generic_args0, generic_args1).all(|(arg0, arg1)| match (arg0, arg1) {
+            (GenericArgumentId::Type(ty0), GenericArgumentId::Type(ty1)) => {
+                unify_types(db, substitution, generic_params, *ty0, *ty1)
+            }
+            _ => arg0 == arg1,
+        })
+}
+
+/// Returns whether two types can be made equal, by substituting the given generic params in both
+/// of them. Records the substitution in `substitution`.
+fn unify_types(
+    db: &dyn SemanticGroup,
+    substitution: &mut HashMap<GenericParamId, TypeId>,
+    generic_params: &[GenericParamId],
+    ty0: TypeId,
+    ty1: TypeId,
+) -> bool {
+    let ty0 = substituted_type(db, substitution, ty0);
+    let ty1 = substituted_type(db, substitution, ty1);
+    if ty0 == ty1 {
+        return true;
+    }
+    match (db.lookup_intern_type(ty0), db.lookup_intern_type(ty1)) {
+        (TypeLongId::GenericParameter(generic_param), _)
+            if generic_params.contains(&generic_param) =>
+        {
+            substitution.insert(generic_param, ty1);
+            true
+        }
+        (_, TypeLongId::GenericParameter(generic_param))
+            if generic_params.contains(&generic_param) =>
+        {
+            substitution.insert(generic_param, ty0);
+            true
+        }
+        (TypeLongId::Concrete(concrete0), TypeLongId::Concrete(concrete1)) => {
+            concrete0.generic_type(db) == concrete1.generic_type(db)
+                && unify_generic_args(
+                    db,
+                    substitution,
+                    generic_params,
+                    &concrete0.generic_args(db),
+                    &concrete1.generic_args(db),
+                )
+        }
+        (TypeLongId::Tuple(tys0), TypeLongId::Tuple(tys1)) => {
+            tys0.len() == tys1.len()
+                && zip_eq(tys0, tys1)
+                    .all(|(ty0, ty1)| unify_types(db, substitution, generic_params, ty0, ty1))
+        }
+        _ => false,
+    }
+}
+
+/// Follows the substitution of a generic param type, until reaching a type that is not a
+/// substituted generic param.
+fn substituted_type(
+    db: &dyn SemanticGroup,
+    substitution: &HashMap<GenericParamId, TypeId>,
+    mut ty: TypeId,
+) -> TypeId {
+    while let TypeLongId::GenericParameter(generic_param) = db.lookup_intern_type(ty) {
+        let Some(substituted) = substitution.get(&generic_param) else { break };
+        ty = *substituted;
+    }
+    ty
 }
 
 // Declaration.
@@ -373,7 +690,7 @@ pub struct ImplFunctionDeclarationData {
     diagnostics: Diagnostics<SemanticDiagnostic>,
     signature: semantic::Signature,
     generic_params: Vec<GenericParamId>,
-    // TODO(ilya): Do we need Environment like in a free function?
+    environment: Environment,
     attributes: Vec<Attribute>,
     resolved_lookback: Arc<ResolvedLookback>,
 }
@@ -394,6 +711,14 @@ pub fn impl_function_generic_params(
     Some(db.priv_impl_function_declaration_data(impl_function_id)?.generic_params)
 }
 
+/// Query implementation of [crate::db::SemanticGroup::impl_function_attributes].
+pub fn impl_function_attributes(
+    db: &dyn SemanticGroup,
+    impl_function_id: ImplFunctionId,
+) -> Option<Vec<Attribute>> {
+    Some(db.priv_impl_function_declaration_data(impl_function_id)?.attributes)
+}
+
 /// Query implementation of [crate::db::SemanticGroup::impl_function_declaration_diagnostics].
 pub fn impl_function_declaration_diagnostics(
     db: &dyn SemanticGroup,
@@ -422,12 +747,15 @@ pub fn priv_impl_function_declaration_data(
     let impl_id = impl_function_id.impl_id(db.upcast());
     let data = db.priv_impl_definition_data(impl_id)?;
     let function_syntax = &data.function_asts[impl_function_id];
-    let generic_params = semantic_generic_params(
+    let function_generic_params = semantic_generic_params(
         db,
         &mut diagnostics,
         module_file_id,
         &function_syntax.generic_params(db.upcast()),
     );
+    // The generic params of the impl are in scope of its functions.
+    let generic_params: Vec<_> =
+        chain!(db.impl_generic_params(impl_id)?, function_generic_params).collect();
     let mut resolver = Resolver::new(db, module_file_id, &generic_params);
     let syntax_db = db.upcast();
     let signature_syntax = function_syntax.signature(syntax_db);
@@ -459,6 +787,7 @@ pub fn priv_impl_function_declaration_data(
         diagnostics: diagnostics.build(),
         signature,
         generic_params,
+        environment,
         attributes,
         resolved_lookback,
     })
@@ -494,6 +823,12 @@ fn validate_impl_function_signature(
     let Some(trait_signature) = db.trait_function_signature(*trait_function_id) else {
         return;
     };
+    let Some(trait_generic_params) = db.trait_generic_params(trait_id) else {
+        return;
+    };
+    // The signature of the trait function is given in terms of the generic params of the trait.
+    let substitution: HashMap<_, _> =
+        zip_eq(trait_generic_params, concrete_trait_long_id.generic_args).collect();
     if signature.params.len() != trait_signature.params.len() {
         diagnostics.report(
             &signature_syntax.parameters(syntax_db),
@@ -509,7 +844,7 @@ fn validate_impl_function_signature(
     for (idx, (param, trait_param)) in
         izip!(signature.params.iter(), trait_signature.params.iter()).enumerate()
     {
-        let expected_ty = trait_param.ty;
+        let expected_ty = substitute_generics(db, &substitution, trait_param.ty);
         let actual_ty = param.ty;
 
         if expected_ty != actual_ty {
@@ -544,7 +879,7 @@ fn validate_impl_function_signature(
         diagnostics.report(signature_syntax, PassPanicAsNonpanic { impl_function_id, trait_id });
     }
 
-    let expected_ty = trait_signature.return_type;
+    let expected_ty = substitute_generics(db, &substitution, trait_signature.return_type);
     let actual_ty = signature.return_type;
     if expected_ty != actual_ty {
         let location_ptr = match signature_syntax.ret_ty(syntax_db) {
@@ -562,3 +897,46 @@ fn validate_impl_function_signature(
         );
     }
 }
+
+// Definition.
+/// Query implementation of [crate::db::SemanticGroup::impl_function_definition_diagnostics].
+pub fn impl_function_definition_diagnostics(
+    db: &dyn SemanticGroup,
+    impl_function_id: ImplFunctionId,
+) -> Diagnostics<SemanticDiagnostic> {
+    db.priv_impl_function_definition_data(impl_function_id)
+        .map(|data| data.diagnostics)
+        .unwrap_or_default()
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_function_definition_resolved_lookback].
+pub fn impl_function_definition_resolved_lookback(
+    db: &dyn SemanticGroup,
+    impl_function_id: ImplFunctionId,
+) -> Option<Arc<ResolvedLookback>> {
+    Some(db.priv_impl_function_definition_data(impl_function_id)?.resolved_lookback)
+}
+
+/// Query implementation of [crate::db::SemanticGroup::priv_impl_function_definition_data].
+pub fn priv_impl_function_definition_data(
+    db: &dyn SemanticGroup,
+    impl_function_id: ImplFunctionId,
+) -> Option<FunctionDefinitionData> {
+    let module_file_id = impl_function_id.module_file(db.upcast());
+    let diagnostics = SemanticDiagnostics::new(module_file_id);
+    let impl_id = impl_function_id.impl_id(db.upcast());
+    let data = db.priv_impl_definition_data(impl_id)?;
+    let function_syntax = &data.function_asts[impl_function_id];
+    // Compute signature semantic.
+    let declaration = db.priv_impl_function_declaration_data(impl_function_id)?;
+    let resolver = Resolver::new(db, module_file_id, &declaration.generic_params);
+    // Compute body semantic expr.
+    compute_function_definition_data(
+        db,
+        diagnostics,
+        resolver,
+        &declaration.signature,
+        declaration.environment,
+        &function_syntax.body(db.upcast()),
+    )
+}
@@ -1,11 +1,16 @@
+use std::sync::Arc;
+
 use defs::db::DefsGroup;
 use defs::ids::ModuleItemId;
+use diagnostics::DiagnosticsBuilder;
+use filesystem::db::{AsFilesGroupMut, FilesGroup, FilesGroupEx};
+use filesystem::ids::FileLongId;
 use pretty_assertions::assert_eq;
 use test_log::test;
 use utils::extract_matches;
 
 use crate::db::SemanticGroup;
-use crate::test_utils::{setup_test_module, SemanticDatabaseForTesting};
+use crate::test_utils::{setup_test_crate, setup_test_module, SemanticDatabaseForTesting};
 
 #[test]
 fn test_impl() {
@@ -23,7 +28,7 @@ fn test_impl() {
             #[Contract]
             impl Contract of IContract {
                 func foo(a: felt) {
-                    return 1;
+                    return ();
                 }
             }
         "},
@@ -48,3 +53,44 @@ fn test_impl() {
 
     assert_eq!(format!("{:?}", db.impl_trait(impl_id).unwrap()), "ConcreteTraitId(0)");
 }
+
+#[test]
+fn test_overlapping_impls_in_different_modules() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let db = &mut db_val;
+    // Each impl looks up impls in the module of the other one.
+    let crate_id = setup_test_crate(
+        db,
+        indoc::indoc! {"
+            mod inner;
+            trait MyTrait<T> {}
+            impl OuterImpl of MyTrait::<inner::MyStruct>;
+        "},
+    );
+    let inner_file_id = db.intern_file(FileLongId::OnDisk("src/inner.cairo".into()));
+    db.as_files_group_mut().override_file_content(
+        inner_file_id,
+        Some(Arc::new(
+            "struct MyStruct {}\nimpl InnerImpl of super::MyTrait::<MyStruct>;\n".into(),
+        )),
+    );
+
+    let mut diagnostics = DiagnosticsBuilder::default();
+    for module_id in db.crate_modules(crate_id).iter() {
+        diagnostics.extend(db.module_semantic_diagnostics(*module_id).unwrap());
+    }
+    assert_eq!(
+        diagnostics.build().format(db),
+        indoc::indoc! {"
+            error: Impl `test::inner::InnerImpl` overlaps with impl `test::OuterImpl` for the same trait.
+             --> inner.cairo:2:6
+            impl InnerImpl of super::MyTrait::<MyStruct>;
+                 ^*******^
+            note: The other impl is defined here.
+             --> lib.cairo:3:1
+            impl OuterImpl of MyTrait::<inner::MyStruct>;
+            ^*******************************************^
+
+        "}
+    );
+}
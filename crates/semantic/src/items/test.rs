@@ -3,6 +3,7 @@ use pretty_assertions::assert_eq;
 use crate::semantic_test;
 use crate::test_utils::{test_function_diagnostics, SemanticDatabaseForTesting};
 
+semantic_test!(constant_diagnostics_tests, ["src/items/tests/constant"], test_function_diagnostics);
 semantic_test!(enum_diagnostics_tests, ["src/items/tests/enum"], test_function_diagnostics);
 semantic_test!(
     extern_func_diagnostics_tests,
@@ -14,6 +15,7 @@ semantic_test!(
     ["src/items/tests/free_function"],
     test_function_diagnostics
 );
+semantic_test!(impl_diagnostics_tests, ["src/items/tests/impl"], test_function_diagnostics);
 semantic_test!(
     panicable_diagnostics_tests,
     ["src/items/tests/panicable"],
@@ -2,11 +2,12 @@ use std::sync::Arc;
 
 use db_utils::define_short_id;
 use defs::ids::{
-    GenericFunctionId, GenericParamId, LanguageElementId, TraitFunctionId, TraitFunctionLongId,
-    TraitId,
+    GenericFunctionId, GenericParamId, LanguageElementId, TopLevelLanguageElementId,
+    TraitFunctionId, TraitFunctionLongId, TraitId,
 };
 use diagnostics::{Diagnostics, DiagnosticsBuilder};
 use diagnostics_proc_macros::DebugWithDb;
+use itertools::{chain, Itertools};
 use smol_str::SmolStr;
 use syntax::node::{ast, TypedSyntaxNode};
 use utils::ordered_hash_map::OrderedHashMap;
@@ -30,6 +31,27 @@ pub struct ConcreteTraitLongId {
     pub generic_args: Vec<GenericArgumentId>,
 }
 define_short_id!(ConcreteTraitId, ConcreteTraitLongId, SemanticGroup, lookup_intern_concrete_trait);
+impl ConcreteTraitId {
+    pub fn format(&self, db: &dyn SemanticGroup) -> String {
+        let ConcreteTraitLongId { trait_id, generic_args } = db.lookup_intern_concrete_trait(*self);
+        let trait_format = trait_id.full_path(db.upcast());
+        if generic_args.is_empty() {
+            trait_format
+        } else {
+            format!(
+                "{}::<{}>",
+                trait_format,
+                generic_args
+                    .iter()
+                    .map(|arg| match arg {
+                        GenericArgumentId::Type(ty) => ty.format(db),
+                        GenericArgumentId::Literal(literal_id) => literal_id.format(db),
+                    })
+                    .join(", ")
+            )
+        }
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
 #[debug_db(dyn SemanticGroup + 'static)]
@@ -187,7 +209,12 @@ pub fn priv_trait_function_data(
         module_file_id,
         &function_syntax.generic_params(db.upcast()),
     );
-    let mut resolver = Resolver::new(db, module_file_id, &generic_params);
+    // The generic params of the trait are accessible in the signatures of its functions.
+    let mut resolver = Resolver::new(
+        db,
+        module_file_id,
+        &chain!(data.generic_params.iter().copied(), generic_params.iter().copied()).collect_vec(),
+    );
     let syntax_db = db.upcast();
     let signature_syntax = function_syntax.signature(syntax_db);
     let mut environment = Environment::default();
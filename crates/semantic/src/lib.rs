@@ -7,6 +7,7 @@ pub mod db;
 pub mod diagnostic;
 pub mod expr;
 pub mod items;
+pub mod lints;
 pub mod literals;
 pub mod resolve_path;
 pub mod types;
@@ -0,0 +1,411 @@
+//! Lints over the semantic model, warning about code that compiles but is likely a mistake, e.g.
+//! unused variables. The lints of an item are suppressed with the `#[allow(<lint>)]` attribute,
+//! e.g. `#[allow(unused_variables)]` on a function.
+
+#[cfg(test)]
+#[path = "lints_test.rs"]
+mod test;
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use defs::diagnostic_utils::StableLocation;
+use defs::ids::{
+    FreeFunctionId, FunctionWithBodyId, GenericFunctionId, LanguageElementId, LookupItemId,
+    ModuleFileId, ModuleId, ModuleItemId, VarId,
+};
+use diagnostics::{Diagnostics, DiagnosticsBuilder};
+use filesystem::ids::{CrateId, FileId, FileLongId, VirtualFile};
+use syntax::node::helpers::GetIdentifier;
+use syntax::node::ids::SyntaxStablePtrId;
+use syntax::node::kind::SyntaxKind;
+use syntax::node::{ast, TypedSyntaxNode};
+
+use crate::db::{get_resolver_lookbacks, SemanticGroup};
+use crate::diagnostic::SemanticDiagnosticKind;
+use crate::items::attribute::{ast_attributes_to_semantic, Attribute};
+use crate::resolve_path::ResolvedGenericItem;
+use crate::{Expr, MatchArm, Mutability, Pattern, SemanticDiagnostic, Statement};
+
+/// Variables that are defined but never read.
+pub const UNUSED_VARIABLES: &str = "unused_variables";
+/// Function parameters that are never read. Parameters named `self` and `ref` parameters, which are
+/// returned to the caller, are never reported.
+pub const UNUSED_PARAMETERS: &str = "unused_parameters";
+/// `use` items whose name is never referred to. Uses of traits and impls are never reported, as
+/// they may be required for method calls.
+pub const UNUSED_IMPORTS: &str = "unused_imports";
+/// Match arms that can't be reached, as a previous arm already matches all their values.
+pub const UNREACHABLE_PATTERNS: &str = "unreachable_patterns";
+/// Free functions that are never called. As there is no item visibility, the functions of the
+/// modules forming the interface of the crate are never reported (see [is_crate_interface]).
+/// Neither are functions named `main` and functions with attributes (e.g. `#[test]`).
+pub const DEAD_CODE: &str = "dead_code";
+
+/// Query implementation of [crate::db::SemanticGroup::module_lint_diagnostics].
+pub fn module_lint_diagnostics(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+) -> Option<Diagnostics<SemanticDiagnostic>> {
+    let defs_db = db.upcast();
+    let module_data = db.module_data(module_id)?;
+    let mut diagnostics = DiagnosticsBuilder::default();
+
+    let mut functions = vec![];
+    for free_function_id in module_data.free_functions.keys() {
+        let attributes = db.free_function_declaration_attributes(*free_function_id)?;
+        functions.push((FunctionWithBodyId::Free(*free_function_id), attributes));
+    }
+    for impl_id in module_data.impls.keys() {
+        for impl_function_id in db.impl_functions(*impl_id)? {
+            let attributes = db.impl_function_attributes(impl_function_id)?;
+            functions.push((FunctionWithBodyId::Impl(impl_function_id), attributes));
+        }
+    }
+    for (function_id, attributes) in &functions {
+        if !is_generated(db, function_id.module_file(defs_db)) {
+            function_lints(db, &mut diagnostics, *function_id, attributes);
+        }
+    }
+
+    if !is_crate_interface(db, module_id) {
+        let crates = db.crates();
+        for (function_id, attributes) in &functions {
+            let FunctionWithBodyId::Free(free_function_id) = function_id else {
+                continue;
+            };
+            let name = free_function_id.name(defs_db);
+            if name == "main"
+                || !attributes.is_empty()
+                || is_generated(db, free_function_id.module_file(defs_db))
+            {
+                continue;
+            }
+            if !crates
+                .iter()
+                .any(|crate_id| db.crate_called_functions(*crate_id).contains(free_function_id))
+            {
+                add_lint(
+                    &mut diagnostics,
+                    free_function_id.module_file(defs_db),
+                    free_function_id.untyped_stable_ptr(defs_db),
+                    SemanticDiagnosticKind::UnusedFunction { name },
+                );
+            }
+        }
+    }
+
+    let syntax_db = db.upcast();
+    let mut referred_items = None;
+    for (use_id, use_ast) in module_data.uses.iter() {
+        let module_file_id = use_id.module_file(defs_db);
+        let attributes = ast_attributes_to_semantic(syntax_db, use_ast.attributes(syntax_db));
+        if is_generated(db, module_file_id) || is_allowed(db, &attributes, UNUSED_IMPORTS) {
+            continue;
+        }
+        let Some(resolved_item) = db.use_resolved_item(*use_id) else {
+            continue;
+        };
+        if matches!(resolved_item, ResolvedGenericItem::Trait(_) | ResolvedGenericItem::Impl(_)) {
+            continue;
+        }
+        let referred_items =
+            referred_items.get_or_insert_with(|| module_referred_items(db, module_id));
+        let item_id = ModuleItemId::Use(*use_id);
+        if !referred_items
+            .iter()
+            .any(|(referrer, item)| *referrer != item_id && *item == resolved_item)
+        {
+            add_lint(
+                &mut diagnostics,
+                module_file_id,
+                use_id.untyped_stable_ptr(defs_db),
+                SemanticDiagnosticKind::UnusedUse {
+                    name: use_ast.name(syntax_db).identifier(syntax_db),
+                },
+            );
+        }
+    }
+
+    Some(diagnostics.build())
+}
+
+/// Query implementation of [crate::db::SemanticGroup::file_lint_diagnostics].
+pub fn file_lint_diagnostics(
+    db: &dyn SemanticGroup,
+    file_id: FileId,
+) -> Option<Diagnostics<SemanticDiagnostic>> {
+    let mut diagnostics = DiagnosticsBuilder::default();
+    for module_id in db.file_modules(file_id)? {
+        if let Some(module_diagnostics) = db.module_lint_diagnostics(module_id) {
+            diagnostics.extend(module_diagnostics)
+        }
+    }
+    Some(diagnostics.build())
+}
+
+/// Adds the lints of the body and the parameters of a function.
+fn function_lints(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut DiagnosticsBuilder<SemanticDiagnostic>,
+    function_id: FunctionWithBodyId,
+    attributes: &[Attribute],
+) -> Option<()> {
+    let defs_db = db.upcast();
+    let module_file_id = function_id.module_file(defs_db);
+    let definition = db.function_with_body_definition(function_id)?;
+    let signature = db.function_with_body_signature(function_id)?;
+
+    // Collect the read variables, and the variables defined in the body.
+    let mut read_vars = HashSet::new();
+    let mut defined_vars = vec![];
+    let mut match_arms = vec![];
+    for (_, expr) in definition.exprs.iter() {
+        match expr {
+            Expr::Var(expr_var) => {
+                read_vars.insert(expr_var.var);
+            }
+            Expr::FunctionCall(expr_function_call) => {
+                read_vars.extend(expr_function_call.ref_args.iter().copied());
+            }
+            Expr::Match(expr_match) => {
+                for arm in &expr_match.arms {
+                    defined_vars.extend(arm.pattern.variables().into_iter().cloned());
+                }
+                match_arms.push(&expr_match.arms);
+            }
+            _ => {}
+        }
+    }
+    for (_, statement) in definition.statements.iter() {
+        if let Statement::Let(statement_let) = statement {
+            defined_vars.extend(statement_let.pattern.variables().into_iter().cloned());
+        }
+    }
+
+    if !is_allowed(db, attributes, UNUSED_VARIABLES) {
+        for pattern_variable in defined_vars {
+            let var_id = pattern_variable.var.id;
+            if !pattern_variable.name.starts_with('_') && !read_vars.contains(&VarId::Local(var_id))
+            {
+                add_lint(
+                    diagnostics,
+                    var_id.module_file(defs_db),
+                    var_id.untyped_stable_ptr(defs_db),
+                    SemanticDiagnosticKind::UnusedVariable { name: pattern_variable.name },
+                );
+            }
+        }
+    }
+
+    if !is_allowed(db, attributes, UNUSED_PARAMETERS) {
+        for param in signature.params {
+            let name = param.id.name(defs_db);
+            if !name.starts_with('_')
+                && name != "self"
+                && param.mutability != Mutability::Reference
+                && !read_vars.contains(&VarId::Param(param.id))
+            {
+                add_lint(
+                    diagnostics,
+                    module_file_id,
+                    param.id.untyped_stable_ptr(defs_db),
+                    SemanticDiagnosticKind::UnusedParameter { name },
+                );
+            }
+        }
+    }
+
+    if !is_allowed(db, attributes, UNREACHABLE_PATTERNS) {
+        let syntax_db = db.upcast();
+        let root = db.file_syntax(db.module_file(module_file_id)?)?.as_syntax_node();
+        for arms in match_arms {
+            for arm_index in unreachable_arms(arms) {
+                // Report on the pattern of the arm, found through the syntax of its expression.
+                let expr_ptr = definition.exprs[arms[arm_index].expression].stable_ptr().untyped();
+                let arm_node = root.lookup_ptr(syntax_db, expr_ptr).parent()?;
+                let pattern =
+                    ast::MatchArm::from_syntax_node(syntax_db, arm_node).pattern(syntax_db);
+                add_lint(
+                    diagnostics,
+                    module_file_id,
+                    pattern.stable_ptr().untyped(),
+                    SemanticDiagnosticKind::UnreachableMatchArm,
+                );
+            }
+        }
+    }
+    Some(())
+}
+
+/// Returns the indices of the arms that can't be reached, as a previous arm already matches all
+/// their values.
+fn unreachable_arms(arms: &[MatchArm]) -> Vec<usize> {
+    let mut unreachable = vec![];
+    let mut matches_all = false;
+    let mut matched_variants = vec![];
+    let mut matched_literals = vec![];
+    for (index, arm) in arms.iter().enumerate() {
+        let is_unreachable = matches_all
+            || match &arm.pattern {
+                Pattern::EnumVariant(pattern) => matched_variants.contains(&pattern.variant),
+                Pattern::Literal(pattern) => matched_literals.contains(&pattern.literal.value),
+                _ => false,
+            };
+        if is_unreachable {
+            unreachable.push(index);
+            continue;
+        }
+        match &arm.pattern {
+            Pattern::EnumVariant(pattern) if is_irrefutable(&pattern.inner_pattern) => {
+                matched_variants.push(pattern.variant.clone());
+            }
+            Pattern::Literal(pattern) => matched_literals.push(pattern.literal.value.clone()),
+            pattern => matches_all = is_irrefutable(pattern),
+        }
+    }
+    unreachable
+}
+
+/// Returns true if the pattern matches any value of its type.
+fn is_irrefutable(pattern: &Pattern) -> bool {
+    match pattern {
+        Pattern::Variable(_) | Pattern::Otherwise(_) => true,
+        Pattern::Struct(pattern) => {
+            pattern.field_patterns.iter().all(|(_, pattern)| is_irrefutable(pattern))
+        }
+        Pattern::Tuple(pattern) => {
+            pattern.field_patterns.iter().all(|pattern| is_irrefutable(pattern))
+        }
+        Pattern::Literal(_) | Pattern::EnumVariant(_) => false,
+    }
+}
+
+/// Query implementation of [crate::db::SemanticGroup::crate_called_functions].
+pub fn crate_called_functions(
+    db: &dyn SemanticGroup,
+    crate_id: CrateId,
+) -> Arc<HashSet<FreeFunctionId>> {
+    let mut called_functions = HashSet::new();
+    for module_id in db.crate_modules(crate_id).iter() {
+        let Some(module_data) = db.module_data(*module_id) else {
+            continue;
+        };
+        let impl_functions = module_data
+            .impls
+            .keys()
+            .flat_map(|impl_id| db.impl_functions(*impl_id).unwrap_or_default())
+            .map(FunctionWithBodyId::Impl);
+        let functions = module_data
+            .free_functions
+            .keys()
+            .map(|free_function_id| FunctionWithBodyId::Free(*free_function_id))
+            .chain(impl_functions);
+        for function_id in functions {
+            for callee in db.function_with_body_direct_callees(function_id).unwrap_or_default() {
+                let GenericFunctionId::Free(free_function_id) =
+                    db.lookup_intern_function(callee).function.generic_function else {
+                    continue;
+                };
+                if function_id != FunctionWithBodyId::Free(free_function_id) {
+                    called_functions.insert(free_function_id);
+                }
+            }
+        }
+    }
+    Arc::new(called_functions)
+}
+
+/// Returns the generic items referred to by the paths of the module, with the module item in
+/// which each path appears.
+fn module_referred_items(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+) -> Vec<(ModuleItemId, ResolvedGenericItem)> {
+    let syntax_db = db.upcast();
+    let mut lookbacks = vec![];
+    for module_item_id in db.module_items(module_id).unwrap_or_default().items.values() {
+        lookbacks.push((
+            *module_item_id,
+            get_resolver_lookbacks(LookupItemId::ModuleItem(*module_item_id), db),
+        ));
+        if let ModuleItemId::Impl(impl_id) = module_item_id {
+            for impl_function_id in db.impl_functions(*impl_id).unwrap_or_default() {
+                let lookup_item_id = LookupItemId::ImplFunction(impl_function_id);
+                lookbacks.push((*module_item_id, get_resolver_lookbacks(lookup_item_id, db)));
+            }
+        }
+    }
+
+    let mut referred_items = vec![];
+    for file_id in db.module_files(module_id).unwrap_or_default() {
+        let Some(syntax_file) = db.file_syntax(file_id) else {
+            continue;
+        };
+        let mut nodes = vec![syntax_file.as_syntax_node()];
+        while let Some(node) = nodes.pop() {
+            if node.kind(syntax_db) != SyntaxKind::TerminalIdentifier {
+                nodes.extend(node.children(syntax_db));
+                continue;
+            }
+            let identifier_ptr =
+                ast::TerminalIdentifier::from_syntax_node(syntax_db, node).stable_ptr();
+            for (module_item_id, item_lookbacks) in &lookbacks {
+                for lookback in item_lookbacks.iter() {
+                    if let Some(item) = lookback.generic.get(&identifier_ptr) {
+                        referred_items.push((*module_item_id, item.clone()));
+                    }
+                }
+            }
+        }
+    }
+    referred_items
+}
+
+/// Returns true if the lint is suppressed by an `#[allow(<lint>)]` attribute.
+fn is_allowed(db: &dyn SemanticGroup, attributes: &[Attribute], lint: &str) -> bool {
+    let syntax_db = db.upcast();
+    attributes.iter().filter(|attribute| attribute.id == "allow").any(|attribute| {
+        attribute.args.iter().any(|arg| arg.as_syntax_node().get_text(syntax_db).trim() == lint)
+    })
+}
+
+/// Returns true if the functions of the module form the interface of its crate. These are the
+/// crate root module, and the module named as the crate, which is the main module of single file
+/// projects.
+fn is_crate_interface(db: &dyn SemanticGroup, module_id: ModuleId) -> bool {
+    match module_id {
+        ModuleId::CrateRoot(_) => true,
+        ModuleId::Submodule(submodule_id) => {
+            let defs_db = db.upcast();
+            let ModuleId::CrateRoot(crate_id) = submodule_id.module_file(defs_db).0 else {
+                return false;
+            };
+            db.lookup_intern_crate(crate_id).0 == submodule_id.name(defs_db)
+        }
+        ModuleId::VirtualSubmodule(_) => false,
+    }
+}
+
+/// Returns true if the module file was generated by a plugin, in which case it is not linted.
+fn is_generated(db: &dyn SemanticGroup, module_file_id: ModuleFileId) -> bool {
+    let Some(file_id) = db.module_file(module_file_id) else {
+        return false;
+    };
+    matches!(
+        db.lookup_intern_file(file_id),
+        FileLongId::Virtual(VirtualFile { parent: Some(_), .. })
+    )
+}
+
+fn add_lint(
+    diagnostics: &mut DiagnosticsBuilder<SemanticDiagnostic>,
+    module_file_id: ModuleFileId,
+    stable_ptr: SyntaxStablePtrId,
+    kind: SemanticDiagnosticKind,
+) {
+    diagnostics.add(SemanticDiagnostic {
+        stable_location: StableLocation::new(module_file_id, stable_ptr),
+        kind,
+    });
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use diagnostics::DiagnosticsBuilder;
+use filesystem::db::FilesGroupEx;
+use filesystem::ids::FileLongId;
+use utils::ordered_hash_map::OrderedHashMap;
+
+use crate::db::SemanticGroup;
+use crate::semantic_test;
+use crate::test_utils::{setup_test_crate, SemanticDatabaseForTesting};
+
+semantic_test!(lints_tests, ["src/lints_test_data/lints"], test_lint_diagnostics);
+
+/// Sets up a crate with a root module and a submodule, and returns the semantic diagnostics and
+/// the lints of both.
+fn test_lint_diagnostics(
+    db: &mut (dyn SemanticGroup + 'static),
+    inputs: &OrderedHashMap<String, String>,
+) -> OrderedHashMap<String, String> {
+    let crate_id = setup_test_crate(db, &format!("mod submodule;\n{}", inputs["crate_code"]));
+    let submodule_file_id = db.intern_file(FileLongId::OnDisk("src/submodule.cairo".into()));
+    db.as_files_group_mut()
+        .override_file_content(submodule_file_id, Some(Arc::new(inputs["submodule_code"].clone())));
+
+    let mut diagnostics = DiagnosticsBuilder::default();
+    for module_id in db.crate_modules(crate_id).iter() {
+        diagnostics.extend(db.module_semantic_diagnostics(*module_id).unwrap());
+        diagnostics.extend(db.module_lint_diagnostics(*module_id).unwrap());
+    }
+    OrderedHashMap::from([("expected_diagnostics".into(), diagnostics.build().format(db))])
+}
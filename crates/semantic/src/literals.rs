@@ -1,4 +1,4 @@
-use num_bigint::BigInt;
+use num_bigint::{BigInt, Sign};
 use num_traits::Num;
 use smol_str::SmolStr;
 
@@ -21,9 +21,58 @@ impl TryFrom<SmolStr> for LiteralLongId {
     }
 }
 
+/// The maximal number of bytes in a short string literal (e.g. 'hello'), so that it fits in a felt.
+pub const SHORT_STRING_MAX_LEN: usize = 31;
+
+/// Returns the felt value of a short string literal (e.g. 'hello'), whose big-endian bytes are the
+/// ASCII characters of the string.
+pub fn short_string_value(text: &str) -> Result<BigInt, SemanticDiagnosticKind> {
+    let value =
+        unescape_quoted_literal(text).ok_or(SemanticDiagnosticKind::IllegalStringEscaping)?;
+    if !value.is_ascii() {
+        return Err(SemanticDiagnosticKind::ShortStringMustBeAscii);
+    }
+    if value.len() > SHORT_STRING_MAX_LEN {
+        return Err(SemanticDiagnosticKind::ShortStringTooLong);
+    }
+    Ok(BigInt::from_bytes_be(Sign::Plus, value.as_bytes()))
+}
+
+/// Returns the content of a quoted literal (e.g. 'hello' or "hello"), without the quotes and with
+/// the escape sequences interpreted. Returns None if the literal contains an invalid escape
+/// sequence.
+pub fn unescape_quoted_literal(text: &str) -> Option<String> {
+    let mut chars = text[1..text.len() - 1].chars();
+    let mut res = String::new();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            res.push(c);
+            continue;
+        }
+        res.push(match chars.next()? {
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '0' => '\0',
+            '\\' => '\\',
+            '\'' => '\'',
+            '"' => '"',
+            'x' => {
+                let high = chars.next()?.to_digit(16)?;
+                let low = chars.next()?.to_digit(16)?;
+                // Only ASCII characters may be escaped, to keep the string valid UTF-8.
+                char::from_u32(high * 16 + low).filter(char::is_ascii)?
+            }
+            _ => return None,
+        });
+    }
+    Some(res)
+}
+
 use db_utils::define_short_id;
 
 use crate::db::SemanticGroup;
+use crate::diagnostic::SemanticDiagnosticKind;
 
 define_short_id!(LiteralId, LiteralLongId, SemanticGroup, lookup_intern_literal);
 impl LiteralId {
@@ -5,16 +5,17 @@ mod test;
 use std::iter::Peekable;
 
 use defs::ids::{
-    GenericFunctionId, GenericParamId, GenericTypeId, ImplId, ModuleFileId, ModuleId, ModuleItemId,
-    TraitId,
+    ConstantId, GenericFunctionId, GenericParamId, GenericTypeId, ImplId, ModuleFileId, ModuleId,
+    ModuleItemId, TraitId,
 };
 use diagnostics_proc_macros::DebugWithDb;
 use filesystem::ids::CrateLongId;
-use itertools::Itertools;
+use itertools::{chain, Itertools};
 use smol_str::SmolStr;
 use syntax::node::helpers::PathSegmentEx;
 use syntax::node::ids::SyntaxStablePtrId;
 use syntax::node::{ast, Terminal, TypedSyntaxNode};
+use utils::ordered_hash_map::OrderedHashMap;
 use utils::unordered_hash_map::UnorderedHashMap;
 use utils::OptionHelper;
 
@@ -23,7 +24,9 @@ use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
 use crate::diagnostic::SemanticDiagnostics;
 use crate::items::enm::{ConcreteVariant, SemanticEnumEx};
-use crate::items::imp::{ConcreteImplId, ConcreteImplLongId};
+use crate::items::imp::{
+    find_impls_at_context, ConcreteImplId, ConcreteImplLongId, ImplLookupContext,
+};
 use crate::items::trt::{ConcreteTraitId, ConcreteTraitLongId};
 use crate::literals::LiteralLongId;
 use crate::types::resolve_type;
@@ -39,6 +42,7 @@ use crate::{
 #[debug_db(dyn SemanticGroup + 'static)]
 pub enum ResolvedConcreteItem {
     Module(ModuleId),
+    Constant(ConstantId),
     Function(FunctionId),
     Type(TypeId),
     Variant(ConcreteVariant),
@@ -49,6 +53,7 @@ pub enum ResolvedConcreteItem {
 #[debug_db(dyn SemanticGroup + 'static)]
 pub enum ResolvedGenericItem {
     Module(ModuleId),
+    Constant(ConstantId),
     GenericFunction(GenericFunctionId),
     GenericType(GenericTypeId),
     Variant(Variant),
@@ -59,6 +64,7 @@ impl ResolvedConcreteItem {
     pub fn generic(&self, db: &dyn SemanticGroup) -> Option<ResolvedGenericItem> {
         Some(match self {
             ResolvedConcreteItem::Module(item) => ResolvedGenericItem::Module(*item),
+            ResolvedConcreteItem::Constant(id) => ResolvedGenericItem::Constant(*id),
             ResolvedConcreteItem::Function(function) => ResolvedGenericItem::GenericFunction(
                 db.lookup_intern_function(*function).function.generic_function,
             ),
@@ -132,7 +138,7 @@ pub struct Resolver<'db> {
     // Current module in which to resolve the path.
     pub module_file_id: ModuleFileId,
     // Generic parameters accessible to the resolver.
-    generic_params: UnorderedHashMap<SmolStr, GenericParamId>,
+    generic_params: OrderedHashMap<SmolStr, GenericParamId>,
     // Lookback map for resolved identifiers in path. Used in "Go to definition".
     pub lookback: ResolvedLookback,
 }
@@ -153,6 +159,15 @@ impl<'db> Resolver<'db> {
         }
     }
 
+    /// Returns the context in which impls are looked up from the resolver's scope.
+    pub fn impl_lookup_context(&self) -> ImplLookupContext {
+        ImplLookupContext {
+            module_id: self.module_file_id.0,
+            extra_modules: vec![],
+            generic_params: self.generic_params.values().copied().collect(),
+        }
+    }
+
     /// Resolves a concrete item, given a path.
     /// Guaranteed to result in at most one diagnostic.
     pub fn resolve_concrete_path(
@@ -170,32 +185,10 @@ impl<'db> Resolver<'db> {
         // Follow modules.
         for segment in segments {
             let (identifier, generic_args) = match segment {
-                syntax::node::ast::PathSegment::WithGenericArgs(segment) => {
-                    let mut generic_args = vec![];
-                    for generic_arg_syntax in
-                        segment.generic_args(syntax_db).generic_args(syntax_db).elements(syntax_db)
-                    {
-                        match generic_arg_syntax {
-                            ast::Expr::Literal(literal_syntax) => {
-                                let literal =
-                                    LiteralLongId::try_from(literal_syntax.text(syntax_db))
-                                        .ok()
-                                        .on_none(|| {
-                                            diagnostics.report(&literal_syntax, UnknownLiteral)
-                                        })?;
-                                generic_args.push(GenericArgumentId::Literal(
-                                    self.db.intern_literal(literal),
-                                ));
-                            }
-                            _ => {
-                                let ty =
-                                    resolve_type(self.db, diagnostics, self, &generic_arg_syntax);
-                                generic_args.push(GenericArgumentId::Type(ty));
-                            }
-                        }
-                    }
-                    (segment.ident(syntax_db), Some(generic_args))
-                }
+                syntax::node::ast::PathSegment::WithGenericArgs(segment) => (
+                    segment.ident(syntax_db),
+                    Some(self.resolve_generic_args(diagnostics, &segment.generic_args(syntax_db))?),
+                ),
                 syntax::node::ast::PathSegment::Simple(segment) => (segment.ident(syntax_db), None),
             };
             item = self.resolve_next_concrete(diagnostics, &item, &identifier, generic_args)?;
@@ -204,6 +197,50 @@ impl<'db> Resolver<'db> {
         Some(item)
     }
 
+    /// Resolves the generic arguments of a path segment.
+    pub fn resolve_generic_args(
+        &mut self,
+        diagnostics: &mut SemanticDiagnostics,
+        generic_args_syntax: &ast::GenericArgs,
+    ) -> Option<Vec<GenericArgumentId>> {
+        let syntax_db = self.db.upcast();
+        let mut generic_args = vec![];
+        for generic_arg_syntax in generic_args_syntax.generic_args(syntax_db).elements(syntax_db) {
+            match generic_arg_syntax {
+                ast::Expr::Literal(literal_syntax) => {
+                    let literal = LiteralLongId::try_from(literal_syntax.text(syntax_db))
+                        .ok()
+                        .on_none(|| diagnostics.report(&literal_syntax, UnknownLiteral))?;
+                    generic_args.push(GenericArgumentId::Literal(self.db.intern_literal(literal)));
+                }
+                ast::Expr::Path(path) => {
+                    let generic_arg = match self.resolve_concrete_path(diagnostics, &path) {
+                        Some(ResolvedConcreteItem::Type(ty)) => GenericArgumentId::Type(ty),
+                        // A constant is passed as a literal, with its compile-time value.
+                        Some(ResolvedConcreteItem::Constant(constant_id)) => {
+                            let value = self.db.constant_semantic_data(constant_id)?.value;
+                            GenericArgumentId::Literal(
+                                self.db.intern_literal(LiteralLongId { value }),
+                            )
+                        }
+                        resolved_item => {
+                            if resolved_item.is_some() {
+                                diagnostics.report(&path, NotAType);
+                            }
+                            GenericArgumentId::Type(TypeId::missing(self.db))
+                        }
+                    };
+                    generic_args.push(generic_arg);
+                }
+                _ => {
+                    let ty = resolve_type(self.db, diagnostics, self, &generic_arg_syntax);
+                    generic_args.push(GenericArgumentId::Type(ty));
+                }
+            }
+        }
+        Some(generic_args)
+    }
+
     /// Resolves the first segment of a concrete path.
     fn resolve_concrete_path_first_segment(
         &mut self,
@@ -395,6 +432,49 @@ impl<'db> Resolver<'db> {
                     None
                 }
             }
+            ResolvedConcreteItem::Trait(concrete_trait_id) => {
+                // A trait function is resolved to the function of the single impl of the trait.
+                let trait_id = self.db.lookup_intern_concrete_trait(*concrete_trait_id).trait_id;
+                self.db
+                    .trait_functions(trait_id)
+                    .and_then(|functions| functions.get(&ident).copied())
+                    .on_none(|| diagnostics.report(identifier, PathNotFound))?;
+                let concrete_impls: Vec<_> = find_impls_at_context(
+                    self.db,
+                    &self.impl_lookup_context(),
+                    *concrete_trait_id,
+                )?
+                .into_iter()
+                .unique()
+                .collect();
+                let concrete_impl_id = match concrete_impls[..] {
+                    [concrete_impl_id] => concrete_impl_id,
+                    [] => {
+                        diagnostics.report(
+                            identifier,
+                            NoImplForTrait { concrete_trait_id: *concrete_trait_id },
+                        );
+                        return None;
+                    }
+                    _ => {
+                        let impls = concrete_impls
+                            .into_iter()
+                            .map(|concrete_impl_id| {
+                                self.db.lookup_intern_concrete_impl(concrete_impl_id).impl_id
+                            })
+                            .collect();
+                        diagnostics.report(
+                            identifier,
+                            AmbiguousImpls { concrete_trait_id: *concrete_trait_id, impls },
+                        );
+                        return None;
+                    }
+                };
+                self.resolve_impl_function(diagnostics, identifier, concrete_impl_id, generic_args)
+            }
+            ResolvedConcreteItem::Impl(concrete_impl_id) => {
+                self.resolve_impl_function(diagnostics, identifier, *concrete_impl_id, generic_args)
+            }
             _ => {
                 diagnostics.report(identifier, InvalidPath);
                 None
@@ -402,6 +482,37 @@ impl<'db> Resolver<'db> {
         }
     }
 
+    /// Resolves the function named by `identifier` in a concrete impl.
+    fn resolve_impl_function(
+        &mut self,
+        diagnostics: &mut SemanticDiagnostics,
+        identifier: &ast::TerminalIdentifier,
+        concrete_impl_id: ConcreteImplId,
+        generic_args: Option<Vec<GenericArgumentId>>,
+    ) -> Option<ResolvedConcreteItem> {
+        let syntax_db = self.db.upcast();
+        let ident = identifier.text(syntax_db);
+        let ConcreteImplLongId { impl_id, generic_args: impl_generic_args } =
+            self.db.lookup_intern_concrete_impl(concrete_impl_id);
+        let impl_function_id = self
+            .db
+            .impl_functions(impl_id)
+            .and_then(|impl_functions| {
+                impl_functions
+                    .into_iter()
+                    .find(|impl_function_id| impl_function_id.name(self.db.upcast()) == ident)
+            })
+            .on_none(|| diagnostics.report(identifier, PathNotFound))?;
+        // The generic params of an impl function start with the generic params of its impl.
+        Some(ResolvedConcreteItem::Function(specialize_function(
+            self.db,
+            diagnostics,
+            identifier.stable_ptr().untyped(),
+            GenericFunctionId::ImplFunction(impl_function_id),
+            chain!(impl_generic_args, generic_args.unwrap_or_default()).collect(),
+        )?))
+    }
+
     /// Specializes a ResolvedGenericItem that came from a ModuleItem.
     fn specialize_generic_module_item(
         &mut self,
@@ -418,6 +529,13 @@ impl<'db> Resolver<'db> {
                 }
                 ResolvedConcreteItem::Module(module_id)
             }
+            ResolvedGenericItem::Constant(id) => {
+                if generic_args.is_some() {
+                    diagnostics.report(identifier, UnexpectedGenericArgs);
+                    return None;
+                }
+                ResolvedConcreteItem::Constant(id)
+            }
             ResolvedGenericItem::GenericFunction(generic_function) => {
                 ResolvedConcreteItem::Function(specialize_function(
                     self.db,
@@ -512,6 +630,7 @@ impl<'db> Resolver<'db> {
             ModuleItemId::FreeFunction(id) => {
                 ResolvedGenericItem::GenericFunction(GenericFunctionId::Free(id))
             }
+            ModuleItemId::Constant(id) => ResolvedGenericItem::Constant(id),
             ModuleItemId::ExternFunction(id) => {
                 ResolvedGenericItem::GenericFunction(GenericFunctionId::Extern(id))
             }
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use debug::DebugWithDb;
 use defs::db::DefsGroup;
-use defs::ids::{ModuleId, ModuleItemId};
+use defs::ids::{FunctionWithBodyId, ModuleId, ModuleItemId};
 use filesystem::db::{AsFilesGroupMut, FilesGroup, FilesGroupEx};
 use filesystem::ids::{CrateLongId, Directory, FileLongId};
 use indoc::indoc;
@@ -38,7 +38,8 @@ fn test_resolve_path() {
         db.module_item_by_name(module_id, "foo".into()).unwrap(),
         ModuleItemId::FreeFunction
     );
-    let expr_formatter = ExprFormatter { db, free_function_id };
+    let expr_formatter =
+        ExprFormatter { db, function_id: FunctionWithBodyId::Free(free_function_id) };
     let body = db.free_function_definition_body(free_function_id);
     assert_eq!(
         format!("{:?}", body.debug(&expr_formatter)),
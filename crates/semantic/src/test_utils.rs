@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use db_utils::Upcast;
 use defs::db::{init_defs_group, DefsDatabase, DefsGroup};
-use defs::ids::{FreeFunctionId, GenericFunctionId, ModuleId};
+use defs::ids::{FunctionWithBodyId, GenericFunctionId, ModuleId};
 use filesystem::db::{init_files_group, AsFilesGroupMut, FilesDatabase, FilesGroup, FilesGroupEx};
 use filesystem::ids::{CrateId, CrateLongId, Directory};
 use parser::db::ParserDatabase;
@@ -113,7 +113,7 @@ pub fn setup_test_module(
 /// Helper struct for the return value of [setup_test_function].
 pub struct TestFunction {
     pub module_id: ModuleId,
-    pub function_id: FreeFunctionId,
+    pub function_id: FunctionWithBodyId,
     pub signature: semantic::Signature,
     pub body: semantic::ExprId,
 }
@@ -137,13 +137,13 @@ pub fn setup_test_function(
         .module_item_by_name(test_module.module_id, function_name.into())
         .and_then(GenericFunctionId::option_from)
         .unwrap_or_else(|| panic!("Function {function_name} was not found."));
-    let function_id = extract_matches!(generic_function_id, GenericFunctionId::Free);
+    let free_function_id = extract_matches!(generic_function_id, GenericFunctionId::Free);
     WithStringDiagnostics {
         value: TestFunction {
             module_id: test_module.module_id,
-            function_id,
-            signature: db.free_function_declaration_signature(function_id).unwrap(),
-            body: db.free_function_definition_body(function_id).unwrap(),
+            function_id: FunctionWithBodyId::Free(free_function_id),
+            signature: db.free_function_declaration_signature(free_function_id).unwrap(),
+            body: db.free_function_definition_body(free_function_id).unwrap(),
         },
         diagnostics,
     }
@@ -152,7 +152,7 @@ pub fn setup_test_function(
 /// Helper struct for the return value of [setup_test_expr] and [setup_test_block].
 pub struct TestExpr {
     pub module_id: ModuleId,
-    pub function_id: FreeFunctionId,
+    pub function_id: FunctionWithBodyId,
     pub signature: semantic::Signature,
     pub body: semantic::ExprId,
     pub expr_id: semantic::ExprId,
@@ -2,8 +2,8 @@ use std::collections::HashMap;
 
 use db_utils::define_short_id;
 use debug::DebugWithDb;
-use defs::ids::{EnumId, ExternTypeId, GenericParamId, GenericTypeId, LanguageElementId, StructId};
-use itertools::Itertools;
+use defs::ids::{EnumId, ExternTypeId, GenericParamId, GenericTypeId, StructId};
+use itertools::{zip_eq, Itertools};
 use syntax::node::ast;
 use utils::OptionFrom;
 
@@ -11,7 +11,7 @@ use crate::corelib::{concrete_copy_trait, concrete_drop_trait};
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
 use crate::diagnostic::SemanticDiagnostics;
-use crate::items::imp::{find_impls_at_context, ImplLookupContext};
+use crate::items::imp::{has_impl_at_context, ImplLookupContext};
 use crate::resolve_path::{ResolvedConcreteItem, Resolver};
 use crate::{semantic, GenericArgumentId};
 
@@ -296,6 +296,55 @@ pub fn substitute_generics(
     }
 }
 
+/// Matches a type that may contain generic parameters against a given type, and records in
+/// `substitution` the generic arguments that make them equal.
+/// Returns false if the types can not be matched.
+pub fn infer_generic_args(
+    db: &dyn SemanticGroup,
+    substitution: &mut HashMap<GenericParamId, GenericArgumentId>,
+    generic_ty: TypeId,
+    ty: TypeId,
+) -> bool {
+    match (db.lookup_intern_type(generic_ty), db.lookup_intern_type(ty)) {
+        (TypeLongId::GenericParameter(generic_param), _) => {
+            *substitution.entry(generic_param).or_insert(GenericArgumentId::Type(ty))
+                == GenericArgumentId::Type(ty)
+        }
+        (TypeLongId::Concrete(generic_concrete), TypeLongId::Concrete(concrete)) => {
+            generic_concrete.generic_type(db) == concrete.generic_type(db)
+                && infer_generic_args_from_list(
+                    db,
+                    substitution,
+                    &generic_concrete.generic_args(db),
+                    &concrete.generic_args(db),
+                )
+        }
+        (TypeLongId::Tuple(generic_tys), TypeLongId::Tuple(tys)) => {
+            generic_tys.len() == tys.len()
+                && zip_eq(generic_tys, tys)
+                    .all(|(generic_ty, ty)| infer_generic_args(db, substitution, generic_ty, ty))
+        }
+        _ => generic_ty == ty,
+    }
+}
+
+/// Like [infer_generic_args], for lists of generic arguments. Literal arguments are matched by
+/// equality.
+pub fn infer_generic_args_from_list(
+    db: &dyn SemanticGroup,
+    substitution: &mut HashMap<GenericParamId, GenericArgumentId>,
+    generic_args: &[GenericArgumentId],
+    args: &[GenericArgumentId],
+) -> bool {
+    generic_args.len() == args.len()
+        && zip_eq(generic_args, args).all(|(generic_arg, arg)| match (generic_arg, arg) {
+            (GenericArgumentId::Type(generic_ty), GenericArgumentId::Type(ty)) => {
+                infer_generic_args(db, substitution, *generic_ty, *ty)
+            }
+            _ => generic_arg == arg,
+        })
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
 pub struct TypeInfo {
     /// Can the type be (trivially) dropped.
@@ -307,23 +356,15 @@ pub struct TypeInfo {
 /// Query implementation of [crate::db::SemanticGroup::type_info].
 pub fn type_info(
     db: &dyn SemanticGroup,
-    mut lookup_context: ImplLookupContext,
+    lookup_context: ImplLookupContext,
     ty: TypeId,
 ) -> Option<TypeInfo> {
     // TODO(spapini): Validate Copy and Drop for structs and enums.
     Some(match db.lookup_intern_type(ty) {
-        TypeLongId::Concrete(concrete_type_id) => {
-            let module = concrete_type_id.generic_type(db).module(db.upcast());
-            // Look for Copy and Drop trait also in the defining module.
-            if !lookup_context.extra_modules.contains(&module) {
-                lookup_context.extra_modules.push(module);
-            }
-            let droppable =
-                !find_impls_at_context(db, &lookup_context, concrete_drop_trait(db, ty))?
-                    .is_empty();
+        TypeLongId::Concrete(_) | TypeLongId::GenericParameter(_) => {
+            let droppable = has_impl_at_context(db, &lookup_context, concrete_drop_trait(db, ty))?;
             let duplicatable =
-                !find_impls_at_context(db, &lookup_context, concrete_copy_trait(db, ty))?
-                    .is_empty();
+                has_impl_at_context(db, &lookup_context, concrete_copy_trait(db, ty))?;
             TypeInfo { droppable, duplicatable }
         }
         TypeLongId::Tuple(tys) => {
@@ -335,7 +376,6 @@ pub fn type_info(
             let duplicatable = infos.iter().all(|info| info.duplicatable);
             TypeInfo { droppable, duplicatable }
         }
-        TypeLongId::GenericParameter(_) => todo!(),
         TypeLongId::Missing => {
             return None;
         }
@@ -1,10 +1,17 @@
 use crate::{define_libfunc_hierarchy, define_type_hierarchy};
 
 pub mod storage;
-use storage::{StorageAddressConstLibFunc, StorageAddressType, StorageReadLibFunc};
+use storage::{
+    StorageAddressConstLibFunc, StorageAddressOffsetLibFunc, StorageAddressType,
+    StorageReadLibFunc, StorageWriteLibFunc,
+};
 
-mod syscalls;
-use syscalls::SyscallPtrType;
+pub mod syscalls;
+use syscalls::{
+    CallContractLibFunc, DeployLibFunc, EmitEventLibFunc, GetBlockNumberLibFunc,
+    GetBlockTimestampLibFunc, GetCallerAddressLibFunc, GetContractAddressLibFunc,
+    SendMessageToL1LibFunc, SyscallPtrType,
+};
 
 define_type_hierarchy! {
     pub enum StarkNetType {
@@ -16,6 +23,16 @@ define_type_hierarchy! {
 define_libfunc_hierarchy! {
     pub enum StarkNetLibFunc {
          StorageRead(StorageReadLibFunc),
+         StorageWrite(StorageWriteLibFunc),
          StorageAddressConst(StorageAddressConstLibFunc),
+         StorageAddressOffset(StorageAddressOffsetLibFunc),
+         CallContract(CallContractLibFunc),
+         EmitEvent(EmitEventLibFunc),
+         GetCallerAddress(GetCallerAddressLibFunc),
+         GetContractAddress(GetContractAddressLibFunc),
+         GetBlockNumber(GetBlockNumberLibFunc),
+         GetBlockTimestamp(GetBlockTimestampLibFunc),
+         SendMessageToL1(SendMessageToL1LibFunc),
+         Deploy(DeployLibFunc),
     }, StarkNetConcreteLibFunc
 }
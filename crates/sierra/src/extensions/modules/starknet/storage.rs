@@ -1,17 +1,18 @@
 use num_bigint::BigInt;
+use num_traits::Signed;
 
-use super::syscalls::SyscallPtrType;
+use super::syscalls::SyscallGenericLibFunc;
 use crate::extensions::felt::FeltType;
 use crate::extensions::lib_func::{
-    DeferredOutputKind, LibFuncSignature, OutputVarInfo, ParamSignature, SierraApChange,
+    DeferredOutputKind, LibFuncSignature, OutputVarInfo, SierraApChange,
     SignatureSpecializationContext, SpecializationContext,
 };
 use crate::extensions::types::{InfoOnlyConcreteType, TypeInfo};
 use crate::extensions::{
-    NamedLibFunc, NamedType, NoGenericArgsGenericLibFunc, NoGenericArgsGenericType,
-    OutputVarReferenceInfo, SignatureBasedConcreteLibFunc, SpecializationError,
+    NamedLibFunc, NamedType, NoGenericArgsGenericType, OutputVarReferenceInfo,
+    SignatureBasedConcreteLibFunc, SpecializationError,
 };
-use crate::ids::{GenericLibFuncId, GenericTypeId};
+use crate::ids::{ConcreteTypeId, GenericLibFuncId, GenericTypeId};
 use crate::program::GenericArg;
 
 /// Type for StarkNet storage address, a value in the range [0, 2 ** 251 - 256).
@@ -85,42 +86,99 @@ impl SignatureBasedConcreteLibFunc for StorageAddressConstConcreteLibFunc {
     }
 }
 
-/// LibFunc for a storage read system call.
+/// LibFunc for offsetting a storage address by a constant, e.g. for the addresses of the cells of
+/// a value stored in several cells. The offset must be in the range [0, 256), so that the result
+/// is a valid storage address.
 #[derive(Default)]
-pub struct StorageReadLibFunc {}
-impl NoGenericArgsGenericLibFunc for StorageReadLibFunc {
-    const ID: GenericLibFuncId = GenericLibFuncId::new_inline("storage_read_syscall");
+pub struct StorageAddressOffsetLibFunc {}
+impl NamedLibFunc for StorageAddressOffsetLibFunc {
+    type Concrete = StorageAddressOffsetConcreteLibFunc;
+    const ID: GenericLibFuncId = GenericLibFuncId::new_inline("storage_address_offset");
 
     fn specialize_signature(
         &self,
         context: &dyn SignatureSpecializationContext,
+        _args: &[GenericArg],
     ) -> Result<LibFuncSignature, SpecializationError> {
-        let syscall_ptr_ty = context.get_concrete_type(SyscallPtrType::id(), &[])?;
-        let addr_ty = context.get_concrete_type(StorageAddressType::id(), &[])?;
-        let felt_ty = context.get_concrete_type(FeltType::id(), &[])?;
-        Ok(LibFuncSignature::new_non_branch_ex(
-            vec![
-                ParamSignature {
-                    ty: syscall_ptr_ty.clone(),
-                    allow_deferred: false,
-                    allow_add_const: true,
-                    allow_const: false,
-                },
-                ParamSignature::new(addr_ty),
-            ],
-            vec![
-                OutputVarInfo {
-                    ty: syscall_ptr_ty,
-                    ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
-                        param_idx: 0,
-                    }),
-                },
-                OutputVarInfo {
-                    ty: felt_ty,
-                    ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
-                },
-            ],
-            SierraApChange::Known { new_vars_only: false },
+        let address_ty = context.get_concrete_type(StorageAddressType::id(), &[])?;
+        Ok(LibFuncSignature::new_non_branch(
+            vec![address_ty.clone()],
+            vec![OutputVarInfo {
+                ty: address_ty,
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+            }],
+            SierraApChange::Known { new_vars_only: true },
         ))
     }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        match args {
+            [GenericArg::Value(offset)] if !offset.is_negative() && *offset < BigInt::from(256) => {
+                Ok(StorageAddressOffsetConcreteLibFunc {
+                    offset: offset.clone(),
+                    signature: <Self as NamedLibFunc>::specialize_signature(
+                        self,
+                        context.upcast(),
+                        args,
+                    )?,
+                })
+            }
+            _ => Err(SpecializationError::UnsupportedGenericArg),
+        }
+    }
+}
+
+pub struct StorageAddressOffsetConcreteLibFunc {
+    pub offset: BigInt,
+    pub signature: LibFuncSignature,
+}
+impl SignatureBasedConcreteLibFunc for StorageAddressOffsetConcreteLibFunc {
+    fn signature(&self) -> &LibFuncSignature {
+        &self.signature
+    }
+}
+
+/// LibFunc for a storage read system call.
+#[derive(Default)]
+pub struct StorageReadLibFunc {}
+impl SyscallGenericLibFunc for StorageReadLibFunc {
+    const STR_ID: &'static str = "storage_read";
+
+    fn input_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![context.get_concrete_type(StorageAddressType::id(), &[])?])
+    }
+
+    fn output_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![context.get_concrete_type(FeltType::id(), &[])?])
+    }
+}
+
+/// LibFunc for a storage write system call.
+#[derive(Default)]
+pub struct StorageWriteLibFunc {}
+impl SyscallGenericLibFunc for StorageWriteLibFunc {
+    const STR_ID: &'static str = "storage_write";
+
+    fn input_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![
+            context.get_concrete_type(StorageAddressType::id(), &[])?,
+            context.get_concrete_type(FeltType::id(), &[])?,
+        ])
+    }
+
+    fn output_tys(
+        _context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![])
+    }
 }
@@ -1,6 +1,17 @@
+use itertools::chain;
+
+use crate::extensions::array::ArrayType;
+use crate::extensions::felt::FeltType;
+use crate::extensions::lib_func::{
+    DeferredOutputKind, LibFuncSignature, OutputVarInfo, ParamSignature, SierraApChange,
+    SignatureSpecializationContext,
+};
 use crate::extensions::types::{InfoOnlyConcreteType, TypeInfo};
-use crate::extensions::{NamedType, NoGenericArgsGenericType};
-use crate::ids::GenericTypeId;
+use crate::extensions::{
+    NamedType, NoGenericArgsGenericLibFunc, NoGenericArgsGenericType, OutputVarReferenceInfo,
+    SpecializationError,
+};
+use crate::ids::{ConcreteTypeId, GenericLibFuncId, GenericTypeId};
 
 /// Type for StarkNet system call pointer.
 /// Used to make system calls.
@@ -22,3 +33,223 @@ impl NoGenericArgsGenericType for SyscallPtrType {
         }
     }
 }
+
+/// Trait for implementing a library function for a StarkNet system call.
+/// The library function takes the system call pointer, followed by the request arguments, and
+/// returns the advanced system call pointer, followed by the response values.
+pub trait SyscallGenericLibFunc: Default {
+    /// The library function id.
+    const STR_ID: &'static str;
+
+    /// The types of the request arguments of the system call.
+    fn input_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError>;
+
+    /// The types of the response values of the system call.
+    fn output_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError>;
+}
+impl<T: SyscallGenericLibFunc> NoGenericArgsGenericLibFunc for T {
+    const ID: GenericLibFuncId = GenericLibFuncId::new_inline(Self::STR_ID);
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibFuncSignature, SpecializationError> {
+        let syscall_ptr_ty = context.get_concrete_type(SyscallPtrType::id(), &[])?;
+        Ok(LibFuncSignature::new_non_branch_ex(
+            chain!(
+                [ParamSignature {
+                    ty: syscall_ptr_ty.clone(),
+                    allow_deferred: false,
+                    allow_add_const: true,
+                    allow_const: false,
+                }],
+                T::input_tys(context)?.into_iter().map(ParamSignature::new)
+            )
+            .collect(),
+            chain!(
+                [OutputVarInfo {
+                    ty: syscall_ptr_ty,
+                    ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
+                        param_idx: 0,
+                    }),
+                }],
+                T::output_tys(context)?.into_iter().map(|ty| OutputVarInfo {
+                    ty,
+                    ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+                })
+            )
+            .collect(),
+            SierraApChange::Known { new_vars_only: false },
+        ))
+    }
+}
+
+/// Returns the type of an array of felts.
+fn felt_array_ty(
+    context: &dyn SignatureSpecializationContext,
+) -> Result<ConcreteTypeId, SpecializationError> {
+    let felt_ty = context.get_concrete_type(FeltType::id(), &[])?;
+    context.get_wrapped_concrete_type(ArrayType::id(), felt_ty)
+}
+
+/// LibFunc for a system call calling another contract.
+#[derive(Default)]
+pub struct CallContractLibFunc {}
+impl SyscallGenericLibFunc for CallContractLibFunc {
+    const STR_ID: &'static str = "call_contract";
+
+    fn input_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        let felt_ty = context.get_concrete_type(FeltType::id(), &[])?;
+        // Contract address, entry point selector and calldata.
+        Ok(vec![felt_ty.clone(), felt_ty, felt_array_ty(context)?])
+    }
+
+    fn output_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![felt_array_ty(context)?])
+    }
+}
+
+/// LibFunc for a system call emitting an event.
+#[derive(Default)]
+pub struct EmitEventLibFunc {}
+impl SyscallGenericLibFunc for EmitEventLibFunc {
+    const STR_ID: &'static str = "emit_event";
+
+    fn input_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        // Keys and data.
+        Ok(vec![felt_array_ty(context)?, felt_array_ty(context)?])
+    }
+
+    fn output_tys(
+        _context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![])
+    }
+}
+
+/// LibFunc for a system call getting the address of the calling contract.
+#[derive(Default)]
+pub struct GetCallerAddressLibFunc {}
+impl SyscallGenericLibFunc for GetCallerAddressLibFunc {
+    const STR_ID: &'static str = "get_caller_address";
+
+    fn input_tys(
+        _context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![])
+    }
+
+    fn output_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![context.get_concrete_type(FeltType::id(), &[])?])
+    }
+}
+
+/// LibFunc for a system call getting the address of the current contract.
+#[derive(Default)]
+pub struct GetContractAddressLibFunc {}
+impl SyscallGenericLibFunc for GetContractAddressLibFunc {
+    const STR_ID: &'static str = "get_contract_address";
+
+    fn input_tys(
+        _context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![])
+    }
+
+    fn output_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![context.get_concrete_type(FeltType::id(), &[])?])
+    }
+}
+
+/// LibFunc for a system call getting the number of the current block.
+#[derive(Default)]
+pub struct GetBlockNumberLibFunc {}
+impl SyscallGenericLibFunc for GetBlockNumberLibFunc {
+    const STR_ID: &'static str = "get_block_number";
+
+    fn input_tys(
+        _context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![])
+    }
+
+    fn output_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![context.get_concrete_type(FeltType::id(), &[])?])
+    }
+}
+
+/// LibFunc for a system call getting the timestamp of the current block.
+#[derive(Default)]
+pub struct GetBlockTimestampLibFunc {}
+impl SyscallGenericLibFunc for GetBlockTimestampLibFunc {
+    const STR_ID: &'static str = "get_block_timestamp";
+
+    fn input_tys(
+        _context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![])
+    }
+
+    fn output_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![context.get_concrete_type(FeltType::id(), &[])?])
+    }
+}
+
+/// LibFunc for a system call sending a message to L1.
+#[derive(Default)]
+pub struct SendMessageToL1LibFunc {}
+impl SyscallGenericLibFunc for SendMessageToL1LibFunc {
+    const STR_ID: &'static str = "send_message_to_l1";
+
+    fn input_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        // The L1 address and the payload.
+        Ok(vec![context.get_concrete_type(FeltType::id(), &[])?, felt_array_ty(context)?])
+    }
+
+    fn output_tys(
+        _context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![])
+    }
+}
+
+/// LibFunc for a system call deploying a contract. Returns the address of the deployed contract.
+#[derive(Default)]
+pub struct DeployLibFunc {}
+impl SyscallGenericLibFunc for DeployLibFunc {
+    const STR_ID: &'static str = "deploy";
+
+    fn input_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        let felt_ty = context.get_concrete_type(FeltType::id(), &[])?;
+        // Class hash, contract address salt and constructor calldata.
+        Ok(vec![felt_ty.clone(), felt_ty, felt_array_ty(context)?])
+    }
+
+    fn output_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![context.get_concrete_type(FeltType::id(), &[])?])
+    }
+}
@@ -222,6 +222,10 @@ fn find_type_specialization(
 #[test_case("uint128_const", vec![] => Err(UnsupportedGenericArg); "uint128_const")]
 #[test_case("storage_address_const", vec![value_arg(8)] => Ok(()); "storage_address_const<8>")]
 #[test_case("storage_address_const", vec![] => Err(UnsupportedGenericArg); "storage_address_const")]
+#[test_case("storage_address_offset", vec![value_arg(1)] => Ok(()); "storage_address_offset<1>")]
+#[test_case("storage_address_offset", vec![value_arg(256)] => Err(UnsupportedGenericArg);
+"storage_address_offset<256>")]
+#[test_case("storage_address_offset", vec![] => Err(UnsupportedGenericArg); "storage_address_offset")]
 #[test_case("drop", vec![type_arg("uint128")] => Ok(()); "drop<uint128>")]
 #[test_case("drop", vec![] => Err(WrongNumberOfGenericArgs); "drop<>")]
 #[test_case("drop", vec![type_arg("GasBuiltin")] => Err(UnsupportedGenericArg);
@@ -283,7 +287,16 @@ Ok(());"enum_init<Option,1>")]
             "struct_deconstruct<Uint128AndFelt>")]
 #[test_case("struct_deconstruct", vec![value_arg(4)] => Err(UnsupportedGenericArg);
             "struct_deconstruct<4>")]
-#[test_case("storage_read_syscall", vec![] => Ok(()); "storage_read_syscall")]
+#[test_case("storage_read", vec![] => Ok(()); "storage_read")]
+#[test_case("storage_write", vec![] => Ok(()); "storage_write")]
+#[test_case("call_contract", vec![] => Ok(()); "call_contract")]
+#[test_case("emit_event", vec![] => Ok(()); "emit_event")]
+#[test_case("get_caller_address", vec![] => Ok(()); "get_caller_address")]
+#[test_case("get_contract_address", vec![] => Ok(()); "get_contract_address")]
+#[test_case("get_block_number", vec![] => Ok(()); "get_block_number")]
+#[test_case("get_block_timestamp", vec![] => Ok(()); "get_block_timestamp")]
+#[test_case("send_message_to_l1", vec![] => Ok(()); "send_message_to_l1")]
+#[test_case("deploy", vec![] => Ok(()); "deploy")]
 fn find_libfunc_specialization(
     id: &str,
     generic_args: Vec<GenericArg>,
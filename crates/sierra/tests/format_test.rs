@@ -14,6 +14,8 @@ fn format_test() {
                 type  ConcreteTypeId = TypeId<arg1, 4>;
                 type [123] = TypeId<[12],  4>;
                 type [4]= Enum<ut@core::option ::Option:: <core::felt>, [3],[2]>;
+                type [5] = Enum<ut@core::PanicResult::<( )>, [3], [2]>;
+                type [6] = Struct<ut@(core::felt, (core::felt ,)), [0]>;
                 libfunc CalleeId = LibFuncId ;
                 // Additional comment.
                 libfunc OtherCalleeId = LibFuncId <arg, 4>;
@@ -42,6 +44,8 @@ fn format_test() {
             type ConcreteTypeId = TypeId<arg1, 4>;
             type [123] = TypeId<[12], 4>;
             type [4] = Enum<ut@core::option::Option::<core::felt>, [3], [2]>;
+            type [5] = Enum<ut@core::PanicResult::<()>, [3], [2]>;
+            type [6] = Struct<ut@(core::felt, (core::felt,)), [0]>;
 
             libfunc CalleeId = LibFuncId;
             libfunc OtherCalleeId = LibFuncId<arg, 4>;
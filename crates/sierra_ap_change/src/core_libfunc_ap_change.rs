@@ -92,8 +92,21 @@ pub fn core_libfunc_ap_change(libfunc: &CoreConcreteLibFunc) -> Vec<ApChange> {
         },
         CoreConcreteLibFunc::Pedersen(_) => vec![ApChange::Known(0)],
         CoreConcreteLibFunc::StarkNet(libfunc) => match libfunc {
-            StarkNetConcreteLibFunc::StorageRead(_) => vec![ApChange::Known(1)],
-            StarkNetConcreteLibFunc::StorageAddressConst(_) => vec![ApChange::Known(0)],
+            StarkNetConcreteLibFunc::StorageAddressConst(_)
+            | StarkNetConcreteLibFunc::StorageAddressOffset(_) => {
+                vec![ApChange::Known(0)]
+            }
+            // All the system calls push their selector.
+            StarkNetConcreteLibFunc::StorageRead(_)
+            | StarkNetConcreteLibFunc::StorageWrite(_)
+            | StarkNetConcreteLibFunc::CallContract(_)
+            | StarkNetConcreteLibFunc::EmitEvent(_)
+            | StarkNetConcreteLibFunc::GetCallerAddress(_)
+            | StarkNetConcreteLibFunc::GetContractAddress(_)
+            | StarkNetConcreteLibFunc::GetBlockNumber(_)
+            | StarkNetConcreteLibFunc::GetBlockTimestamp(_)
+            | StarkNetConcreteLibFunc::SendMessageToL1(_)
+            | StarkNetConcreteLibFunc::Deploy(_) => vec![ApChange::Known(1)],
         },
     }
 }
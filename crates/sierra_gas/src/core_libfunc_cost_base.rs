@@ -2,7 +2,7 @@ use sierra::extensions::array::ArrayConcreteLibFunc;
 use sierra::extensions::builtin_cost::{BuiltinCostConcreteLibFunc, CostTokenType};
 use sierra::extensions::core::CoreConcreteLibFunc::{
     self, ApTracking, Array, Box, BranchAlign, BuiltinCost, DictFeltTo, Drop, Dup, Enum, Felt,
-    FunctionCall, Gas, Mem, Pedersen, Struct, Uint128, UnconditionalJump, UnwrapNonZero,
+    FunctionCall, Gas, Mem, Pedersen, StarkNet, Struct, Uint128, UnconditionalJump, UnwrapNonZero,
 };
 use sierra::extensions::dict_felt_to::DictFeltToConcreteLibFunc;
 use sierra::extensions::enm::EnumConcreteLibFunc;
@@ -16,6 +16,7 @@ use sierra::extensions::integer::{
 use sierra::extensions::mem::MemConcreteLibFunc::{
     AlignTemps, AllocLocal, FinalizeLocals, Rename, StoreLocal, StoreTemp,
 };
+use sierra::extensions::starknet::StarkNetConcreteLibFunc;
 use sierra::extensions::strct::StructConcreteLibFunc;
 use sierra::program::Function;
 
@@ -109,7 +110,29 @@ pub fn core_libfunc_cost_base<Ops: CostOperations>(
                 ops.const_cost(compute_requested_cost_steps + 5),
             ]
         }
-        &CoreConcreteLibFunc::StarkNet(_) => todo!(),
+        StarkNet(libfunc) => starknet_libfunc_cost(ops, libfunc),
+    }
+}
+
+/// Returns costs for StarkNet libfuncs.
+fn starknet_libfunc_cost<Ops: CostOperations>(
+    ops: &Ops,
+    libfunc: &StarkNetConcreteLibFunc,
+) -> Vec<Ops::CostType> {
+    match libfunc {
+        StarkNetConcreteLibFunc::StorageAddressConst(_)
+        | StarkNetConcreteLibFunc::StorageAddressOffset(_) => vec![ops.const_cost(0)],
+        // A system call costs 3 steps, in addition to a step per cell of its request.
+        StarkNetConcreteLibFunc::GetCallerAddress(_)
+        | StarkNetConcreteLibFunc::GetContractAddress(_)
+        | StarkNetConcreteLibFunc::GetBlockNumber(_)
+        | StarkNetConcreteLibFunc::GetBlockTimestamp(_) => vec![ops.const_cost(3)],
+        StarkNetConcreteLibFunc::StorageRead(_) => vec![ops.const_cost(4)],
+        StarkNetConcreteLibFunc::StorageWrite(_) => vec![ops.const_cost(5)],
+        StarkNetConcreteLibFunc::SendMessageToL1(_) => vec![ops.const_cost(6)],
+        StarkNetConcreteLibFunc::CallContract(_)
+        | StarkNetConcreteLibFunc::EmitEvent(_)
+        | StarkNetConcreteLibFunc::Deploy(_) => vec![ops.const_cost(7)],
     }
 }
 
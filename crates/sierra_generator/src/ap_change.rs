@@ -2,7 +2,7 @@
 #[path = "ap_change_test.rs"]
 mod test;
 
-use defs::ids::FreeFunctionId;
+use defs::ids::{FunctionWithBodyId, GenericFunctionId};
 use sierra::extensions::lib_func::SierraApChange;
 use sierra::program::GenStatement;
 
@@ -11,23 +11,29 @@ use crate::pre_sierra;
 use crate::utils::get_libfunc_signature;
 
 /// Query implementation of [SierraGenGroup::contains_cycle].
-pub fn contains_cycle(db: &dyn SierraGenGroup, function_id: FreeFunctionId) -> Option<bool> {
-    let lowered_function = &*db.free_function_lowered(function_id)?;
+pub fn contains_cycle(db: &dyn SierraGenGroup, function_id: FunctionWithBodyId) -> Option<bool> {
+    let lowered_function = &*db.function_with_body_lowered(function_id)?;
     for (_, block) in &lowered_function.blocks {
         for statement in &block.statements {
             if let lowering::Statement::Call(statement_call) = statement {
                 let concrete = db.lookup_intern_function(statement_call.function).function;
                 match concrete.generic_function {
-                    defs::ids::GenericFunctionId::Free(free_function_id) => {
-                        if db.contains_cycle(free_function_id)? {
+                    GenericFunctionId::Free(free_function_id) => {
+                        if db.contains_cycle(FunctionWithBodyId::Free(free_function_id))? {
                             return Some(true);
                         }
                     }
-                    defs::ids::GenericFunctionId::Extern(_) => {}
-                    defs::ids::GenericFunctionId::TraitFunction(_) => {
+                    GenericFunctionId::ImplFunction(impl_function_id) => {
+                        if db.contains_cycle(FunctionWithBodyId::Impl(impl_function_id))? {
+                            return Some(true);
+                        }
+                    }
+                    GenericFunctionId::Extern(_) => {}
+                    // Loops are lowered into recursive functions.
+                    GenericFunctionId::Loop(_) => return Some(true),
+                    GenericFunctionId::TraitFunction(_) => {
                         panic!("Trait function should be replaced with concrete functions.")
                     }
-                    defs::ids::GenericFunctionId::ImplFunction(_) => todo!(),
                 }
             }
         }
@@ -40,7 +46,7 @@ pub fn contains_cycle(db: &dyn SierraGenGroup, function_id: FreeFunctionId) -> O
 pub fn contains_cycle_handle_cycle(
     _db: &dyn SierraGenGroup,
     _cycle: &[String],
-    _function_id: &FreeFunctionId,
+    _function_id: &FunctionWithBodyId,
 ) -> Option<bool> {
     Some(true)
 }
@@ -48,7 +54,7 @@ pub fn contains_cycle_handle_cycle(
 /// Query implementation of [SierraGenGroup::get_ap_change].
 pub fn get_ap_change(
     db: &dyn SierraGenGroup,
-    function_id: FreeFunctionId,
+    function_id: FunctionWithBodyId,
 ) -> Option<SierraApChange> {
     // The implementation of get_ap_change() may call this function recursively. To guarantee no
     // salsa query cycles are created, we first verify that there are no cycles.
@@ -56,7 +62,7 @@ pub fn get_ap_change(
         return Some(SierraApChange::Unknown);
     }
 
-    let function = &*db.free_function_sierra(function_id)?;
+    let function = &*db.function_with_body_sierra(function_id)?;
     for statement in &function.body {
         if let pre_sierra::Statement::Sierra(GenStatement::Invocation(invocation)) = statement {
             let signature = get_libfunc_signature(db, invocation.libfunc_id.clone());
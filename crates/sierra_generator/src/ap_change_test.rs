@@ -1,4 +1,5 @@
 use defs::db::DefsGroup;
+use defs::ids::FunctionWithBodyId;
 use itertools::Itertools;
 use lowering::db::LoweringGroup;
 use pretty_assertions::assert_eq;
@@ -36,8 +37,8 @@ fn contains_cycles_test(
             format!(
                 "{}: ap_change={:?}, has_cycles={:?}",
                 name,
-                db.get_ap_change(*function_id),
-                db.contains_cycle(*function_id),
+                db.get_ap_change(FunctionWithBodyId::Free(*function_id)),
+                db.contains_cycle(FunctionWithBodyId::Free(*function_id)),
             )
         })
         .join("\n");
@@ -9,9 +9,9 @@ use sierra::program;
 use crate::expr_generator_context::ExprGeneratorContext;
 use crate::pre_sierra;
 use crate::utils::{
-    branch_align_libfunc_id, enum_init_libfunc_id, felt_const_libfunc_id, get_concrete_libfunc_id,
-    jump_libfunc_id, jump_statement, match_enum_libfunc_id, return_statement, simple_statement,
-    struct_construct_libfunc_id, struct_deconstruct_libfunc_id,
+    branch_align_libfunc_id, const_libfunc_id_by_type, enum_init_libfunc_id,
+    get_concrete_libfunc_id, jump_libfunc_id, jump_statement, match_enum_libfunc_id,
+    return_statement, simple_statement, struct_construct_libfunc_id, struct_deconstruct_libfunc_id,
 };
 
 /// Generates Sierra code that computes a given [lowering::Block].
@@ -98,11 +98,16 @@ pub fn generate_return_code(
 }
 
 /// Generates Sierra code for [lowering::Statement].
+///
+/// If the location of the statement is known, the code is preceded by a
+/// [pre_sierra::Statement::Location] marker.
 pub fn generate_statement_code(
     context: &mut ExprGeneratorContext<'_>,
     statement: &lowering::Statement,
 ) -> Option<Vec<pre_sierra::Statement>> {
-    match statement {
+    let location_marker =
+        statement.location().map(|location| pre_sierra::Statement::Location(location.clone()));
+    let statements = match statement {
         lowering::Statement::Literal(statement_literal) => {
             generate_statement_literal_code(context, statement_literal)
         }
@@ -127,7 +132,8 @@ pub fn generate_statement_code(
         lowering::Statement::StructDestructure(statement) => {
             generate_statement_struct_destructure_code(context, statement)
         }
-    }
+    }?;
+    Some(chain!(location_marker, statements).collect())
 }
 
 /// Generates Sierra code for [lowering::StatementLiteral].
@@ -137,7 +143,11 @@ fn generate_statement_literal_code(
 ) -> Option<Vec<pre_sierra::Statement>> {
     let output_var = context.get_sierra_variable(statement.output);
     Some(vec![simple_statement(
-        felt_const_libfunc_id(context.get_db(), statement.value.clone()),
+        const_libfunc_id_by_type(
+            context.get_db(),
+            context.get_variable_semantic_type(statement.output),
+            statement.value.clone(),
+        ),
         &[],
         &[output_var],
     )])
@@ -157,7 +167,9 @@ fn generate_statement_call_code(
         get_concrete_libfunc_id(context.get_db(), statement.function);
 
     match function_long_id.generic_function {
-        GenericFunctionId::Free(_) => {
+        GenericFunctionId::Free(_)
+        | GenericFunctionId::ImplFunction(_)
+        | GenericFunctionId::Loop(_) => {
             // Create [pre_sierra::PushValue] instances for the arguments.
             let mut args_on_stack: Vec<sierra::ids::VarId> = vec![];
             let mut push_values_vec: Vec<pre_sierra::PushValue> = vec![];
@@ -185,7 +197,6 @@ fn generate_statement_call_code(
         GenericFunctionId::TraitFunction(_) => {
             panic!("Trait function should be replaced with concrete functions.")
         }
-        GenericFunctionId::ImplFunction(_) => todo!(),
     }
 }
 
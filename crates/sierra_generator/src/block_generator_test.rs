@@ -7,7 +7,7 @@ use super::generate_block_code;
 use crate::expr_generator_context::ExprGeneratorContext;
 use crate::replace_ids::replace_sierra_ids;
 use crate::test_utils::SierraGenDatabaseForTesting;
-use crate::SierraGeneratorDiagnostic;
+use crate::{pre_sierra, SierraGeneratorDiagnostic};
 
 test_utils::test_file_test!(
     lowering_test,
@@ -51,11 +51,12 @@ fn block_generator_test(
     // Generate (pre-)Sierra statements.
     let mut diagnostics = DiagnosticsBuilder::<SierraGeneratorDiagnostic>::default();
     let mut expr_generator_context =
-        ExprGeneratorContext::new(db, &lowered, test_function.function_id, &mut diagnostics);
+        ExprGeneratorContext::new(db, &lowered, test_function.function_id.into(), &mut diagnostics);
     let statements_opt = generate_block_code(&mut expr_generator_context, block);
     let expected_sierra_code = statements_opt.map_or("None".into(), |statements| {
         statements
             .iter()
+            .filter(|x| !matches!(x, pre_sierra::Statement::Location(_)))
             .map(|x| replace_sierra_ids(db, x).to_string())
             .collect::<Vec<String>>()
             .join("\n")
@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
 use db_utils::Upcast;
-use defs::ids::{FreeFunctionId, ModuleId};
+use defs::ids::{FreeFunctionId, FunctionWithBodyId, GenericFunctionId, LoopFunctionId, ModuleId};
 use diagnostics::Diagnostics;
 use filesystem::ids::CrateId;
+use itertools::chain;
 use lowering::db::LoweringGroup;
 use semantic::corelib::get_core_ty_by_name;
 use semantic::{GenericArgumentId, Mutability};
@@ -57,23 +58,36 @@ pub trait SierraGenGroup: LoweringGroup + Upcast<dyn LoweringGroup> {
         concrete_type_id: sierra::ids::ConcreteTypeId,
     ) -> Option<Arc<sierra::extensions::types::TypeInfo>>;
 
-    /// Private query to compute Sierra data about a free function.
-    #[salsa::invoke(function_generator::priv_free_function_sierra_data)]
-    fn priv_free_function_sierra_data(
+    /// Private query to compute Sierra data about a function with a body.
+    #[salsa::invoke(function_generator::priv_function_with_body_sierra_data)]
+    fn priv_function_with_body_sierra_data(
         &self,
-        function_id: FreeFunctionId,
+        function_id: FunctionWithBodyId,
     ) -> function_generator::SierraFreeFunctionData;
-    /// Returns the Sierra diagnostics of a free function.
-    #[salsa::invoke(function_generator::free_function_sierra_diagnostics)]
-    fn free_function_sierra_diagnostics(
+    /// Returns the Sierra diagnostics of a function with a body.
+    #[salsa::invoke(function_generator::function_with_body_sierra_diagnostics)]
+    fn function_with_body_sierra_diagnostics(
         &self,
-        function_id: FreeFunctionId,
+        function_id: FunctionWithBodyId,
     ) -> Diagnostics<SierraGeneratorDiagnostic>;
-    /// Returns the Sierra code (as [pre_sierra::Function]) for a given free function.
-    #[salsa::invoke(function_generator::free_function_sierra)]
-    fn free_function_sierra(
+    /// Returns the Sierra code (as [pre_sierra::Function]) for a given function with a body.
+    #[salsa::invoke(function_generator::function_with_body_sierra)]
+    fn function_with_body_sierra(
         &self,
-        function_id: FreeFunctionId,
+        function_id: FunctionWithBodyId,
+    ) -> Option<Arc<pre_sierra::Function>>;
+
+    /// Private query to compute Sierra data about a loop function.
+    #[salsa::invoke(function_generator::priv_loop_function_sierra_data)]
+    fn priv_loop_function_sierra_data(
+        &self,
+        function_id: LoopFunctionId,
+    ) -> function_generator::SierraFreeFunctionData;
+    /// Returns the Sierra code (as [pre_sierra::Function]) for a given loop function.
+    #[salsa::invoke(function_generator::loop_function_sierra)]
+    fn loop_function_sierra(
+        &self,
+        function_id: LoopFunctionId,
     ) -> Option<Arc<pre_sierra::Function>>;
 
     /// Returns the Sierra diagnostics of a module.
@@ -88,12 +102,20 @@ pub trait SierraGenGroup: LoweringGroup + Upcast<dyn LoweringGroup> {
     /// calls f2, then [Self::contains_cycle] will return `true` for all of these functions.
     #[salsa::invoke(ap_change::contains_cycle)]
     #[salsa::cycle(ap_change::contains_cycle_handle_cycle)]
-    fn contains_cycle(&self, function_id: FreeFunctionId) -> Option<bool>;
+    fn contains_cycle(&self, function_id: FunctionWithBodyId) -> Option<bool>;
 
     /// Returns the ap change of a given function if it is known at compile time or
     /// [SierraApChange::Unknown] otherwise.
     #[salsa::invoke(ap_change::get_ap_change)]
-    fn get_ap_change(&self, function_id: FreeFunctionId) -> Option<SierraApChange>;
+    fn get_ap_change(&self, function_id: FunctionWithBodyId) -> Option<SierraApChange>;
+
+    /// Returns the [sierra::program::Program] object of the requested functions, together with its
+    /// debug information.
+    #[salsa::invoke(program_generator::get_sierra_program_with_debug_for_functions)]
+    fn get_sierra_program_with_debug_for_functions(
+        &self,
+        requested_function_ids: Vec<FreeFunctionId>,
+    ) -> Option<Arc<program_generator::SierraProgramWithDebug>>;
 
     /// Returns the [sierra::program::Program] object of the requested functions.
     #[salsa::invoke(program_generator::get_sierra_program_for_functions)]
@@ -102,6 +124,14 @@ pub trait SierraGenGroup: LoweringGroup + Upcast<dyn LoweringGroup> {
         requested_function_ids: Vec<FreeFunctionId>,
     ) -> Option<Arc<sierra::program::Program>>;
 
+    /// Returns the [sierra::program::Program] object of the requested crates, together with its
+    /// debug information.
+    #[salsa::invoke(program_generator::get_sierra_program_with_debug)]
+    fn get_sierra_program_with_debug(
+        &self,
+        requested_crate_ids: Vec<CrateId>,
+    ) -> Option<Arc<program_generator::SierraProgramWithDebug>>;
+
     /// Returns the [sierra::program::Program] object of the requested crates.
     #[salsa::invoke(program_generator::get_sierra_program)]
     fn get_sierra_program(
@@ -120,6 +150,11 @@ fn get_function_signature(
     // it in the end of program_generator::get_sierra_program instead of calling this function from
     // there.
     let semantic_function_id = db.lookup_intern_sierra_function(function_id);
+    if let GenericFunctionId::Loop(loop_function_id) =
+        db.lookup_intern_function(semantic_function_id).function.generic_function
+    {
+        return get_loop_function_signature(db, semantic_function_id, loop_function_id);
+    }
     let signature = db.concrete_function_signature(semantic_function_id)?;
     let may_panic = db.function_may_panic(semantic_function_id)?;
 
@@ -155,6 +190,32 @@ fn get_function_signature(
     Some(Arc::new(sierra::program::FunctionSignature { param_types: all_params, ret_types }))
 }
 
+/// Returns the [sierra::program::FunctionSignature] object of a loop function. A loop function
+/// gets the implicits, the captured variables passed by reference and the rest of the captured
+/// variables. It returns the implicits, the reference variables and a `PanicResult` of the loop
+/// type.
+fn get_loop_function_signature(
+    db: &dyn SierraGenGroup,
+    semantic_function_id: semantic::FunctionId,
+    loop_function_id: LoopFunctionId,
+) -> Option<Arc<sierra::program::FunctionSignature>> {
+    let lowered_loop = db.loop_function_lowered(loop_function_id)?;
+    let implicits = db.function_all_implicits(semantic_function_id)?;
+    let ref_param_tys = chain!(implicits, lowered_loop.ref_param_tys.iter().cloned());
+    let return_type = get_core_ty_by_name(
+        db.upcast(),
+        "PanicResult".into(),
+        vec![GenericArgumentId::Type(lowered_loop.return_type)],
+    );
+    let param_types = chain!(ref_param_tys.clone(), lowered_loop.param_tys.iter().cloned())
+        .map(|ty| db.get_concrete_type_id(ty))
+        .collect::<Option<Vec<_>>>()?;
+    let ret_types = chain!(ref_param_tys, [return_type])
+        .map(|ty| db.get_concrete_type_id(ty))
+        .collect::<Option<Vec<_>>>()?;
+    Some(Arc::new(sierra::program::FunctionSignature { param_types, ret_types }))
+}
+
 fn get_type_info(
     db: &dyn SierraGenGroup,
     concrete_type_id: sierra::ids::ConcreteTypeId,
@@ -18,7 +18,19 @@ impl DiagnosticEntry for SierraGeneratorDiagnostic {
     fn location(&self, db: &Self::DbType) -> DiagnosticLocation {
         self.stable_location.diagnostic_location(db.upcast())
     }
+
+    fn code(&self) -> &'static str {
+        self.kind.code()
+    }
 }
 
+/// The kind of a sierra generator diagnostic. Each kind has a stable code, prefixed by `G`, see
+/// [SierraGeneratorDiagnosticKind::code].
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum SierraGeneratorDiagnosticKind {}
+impl SierraGeneratorDiagnosticKind {
+    /// Returns the stable code of the kind. Codes of removed kinds must not be reused.
+    pub fn code(&self) -> &'static str {
+        match *self {}
+    }
+}
@@ -76,7 +76,7 @@ pub fn calculate_statement_dups_and_drops(
                             .collect(),
                     }
                 }
-                Statement::Label(_) => {
+                Statement::Label(_) | Statement::Location(_) => {
                     // Label is a no-op - so we do no changes to it.
                     VarsDupsAndDrops {
                         dups: OrderedHashSet::<VarId>::default(),
@@ -131,7 +131,7 @@ fn get_existing_vars_per_statement(
                 }
             }
             Statement::Sierra(sierra::program::GenStatement::Return(_)) => {}
-            Statement::Label(_) => {
+            Statement::Label(_) | Statement::Location(_) => {
                 // Label is a no-op - so we do no changes to it.
                 statement_existing_vars[i + 1] = statement_existing_vars[i].clone();
             }
@@ -225,7 +225,7 @@ fn calculate_required_vars_for_statement(
                 });
             }
             Statement::Sierra(sierra::program::GenStatement::Return(_)) => {}
-            Statement::Label(_) => {
+            Statement::Label(_) | Statement::Location(_) => {
                 push_if_relevant(&mut handle_stack, &visited, index + 1);
             }
             Statement::PushValues(_) => {
@@ -270,7 +270,7 @@ fn calculate_required_vars_for_statement(
                     future_vars: OrderedHashSet::default(),
                 })
             }
-            Statement::Label(_) => {
+            Statement::Label(_) | Statement::Location(_) => {
                 // Labels are no-ops - so we just use the same as next line.
                 required_vars[index] = Some(RequiredVarsSets {
                     curr_vars: OrderedHashSet::default(),
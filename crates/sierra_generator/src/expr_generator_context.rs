@@ -1,5 +1,5 @@
 use defs::diagnostic_utils::StableLocation;
-use defs::ids::{FreeFunctionId, LanguageElementId, ModuleFileId};
+use defs::ids::{GenericFunctionId, LanguageElementId, ModuleFileId};
 use diagnostics::DiagnosticsBuilder;
 use syntax::node::ids::SyntaxStablePtrId;
 use utils::unordered_hash_map::UnorderedHashMap;
@@ -13,7 +13,7 @@ use crate::{pre_sierra, SierraGeneratorDiagnostic};
 pub struct ExprGeneratorContext<'a> {
     db: &'a dyn SierraGenGroup,
     lowered: &'a lowering::lower::Lowered,
-    function_id: FreeFunctionId,
+    function_id: GenericFunctionId,
     module_file_id: ModuleFileId,
     diagnostics: &'a mut DiagnosticsBuilder<SierraGeneratorDiagnostic>,
     var_id_allocator: IdAllocator,
@@ -25,7 +25,7 @@ impl<'a> ExprGeneratorContext<'a> {
     pub fn new(
         db: &'a dyn SierraGenGroup,
         lowered: &'a lowering::lower::Lowered,
-        function_id: FreeFunctionId,
+        function_id: GenericFunctionId,
         diagnostics: &'a mut DiagnosticsBuilder<SierraGeneratorDiagnostic>,
     ) -> Self {
         ExprGeneratorContext {
@@ -101,6 +101,11 @@ impl<'a> ExprGeneratorContext<'a> {
         self.db.get_concrete_type_id(self.lowered.variables[var].ty)
     }
 
+    /// Returns the semantic type of a [lowering::VariableId].
+    pub fn get_variable_semantic_type(&self, var: lowering::VariableId) -> semantic::TypeId {
+        self.lowered.variables[var].ty
+    }
+
     /// Returns the block ([lowering::Block]) associated with [lowering::BlockId].
     pub fn get_lowered_block(&self, block_id: lowering::BlockId) -> &'a lowering::Block {
         &self.lowered.blocks[block_id]
@@ -5,7 +5,8 @@ mod test;
 use std::collections::hash_map::Entry;
 use std::sync::Arc;
 
-use defs::ids::{FreeFunctionId, GenericFunctionId};
+use defs::diagnostic_utils::StableLocation;
+use defs::ids::{FunctionWithBodyId, GenericFunctionId, LoopFunctionId};
 use diagnostics::{Diagnostics, DiagnosticsBuilder};
 use itertools::zip_eq;
 use sierra::extensions::core::CoreLibFunc;
@@ -37,39 +38,84 @@ pub struct SierraFreeFunctionData {
     pub function: Option<Arc<pre_sierra::Function>>,
 }
 
-/// Query implementation of [SierraGenGroup::priv_free_function_sierra_data].
-pub fn priv_free_function_sierra_data(
+/// Query implementation of [SierraGenGroup::priv_function_with_body_sierra_data].
+pub fn priv_function_with_body_sierra_data(
     db: &dyn SierraGenGroup,
-    function_id: FreeFunctionId,
+    function_id: FunctionWithBodyId,
 ) -> SierraFreeFunctionData {
     let mut diagnostics = DiagnosticsBuilder::new();
-    let function = get_function_code(&mut diagnostics, db, function_id);
+    let function = db.function_with_body_signature(function_id).and_then(|signature| {
+        get_function_code(
+            &mut diagnostics,
+            db,
+            function_id.into(),
+            &*db.function_with_body_lowered(function_id)?,
+            signature.return_type,
+        )
+    });
     SierraFreeFunctionData { diagnostics: diagnostics.build(), function }
 }
 
-/// Query implementation of [SierraGenGroup::free_function_sierra_diagnostics].
-pub fn free_function_sierra_diagnostics(
+/// Query implementation of [SierraGenGroup::function_with_body_sierra_diagnostics].
+pub fn function_with_body_sierra_diagnostics(
     db: &dyn SierraGenGroup,
-    function_id: FreeFunctionId,
+    function_id: FunctionWithBodyId,
 ) -> Diagnostics<SierraGeneratorDiagnostic> {
-    db.priv_free_function_sierra_data(function_id).diagnostics
+    let mut diagnostics = DiagnosticsBuilder::new();
+    diagnostics.extend(db.priv_function_with_body_sierra_data(function_id).diagnostics);
+    // Add the diagnostics of the loops in the function.
+    if let Some(lowered_function) = db.function_with_body_lowered(function_id) {
+        for loop_function_id in lowered_function.loops.keys() {
+            diagnostics.extend(db.priv_loop_function_sierra_data(*loop_function_id).diagnostics);
+        }
+    }
+    diagnostics.build()
 }
 
-/// Query implementation of [SierraGenGroup::free_function_sierra].
-pub fn free_function_sierra(
+/// Query implementation of [SierraGenGroup::function_with_body_sierra].
+pub fn function_with_body_sierra(
     db: &dyn SierraGenGroup,
-    function_id: FreeFunctionId,
+    function_id: FunctionWithBodyId,
 ) -> Option<Arc<pre_sierra::Function>> {
-    db.priv_free_function_sierra_data(function_id).function
+    db.priv_function_with_body_sierra_data(function_id).function
+}
+
+/// Query implementation of [SierraGenGroup::priv_loop_function_sierra_data].
+pub fn priv_loop_function_sierra_data(
+    db: &dyn SierraGenGroup,
+    function_id: LoopFunctionId,
+) -> SierraFreeFunctionData {
+    let mut diagnostics = DiagnosticsBuilder::new();
+    let function = db.loop_function_lowered(function_id).and_then(|lowered_loop| {
+        get_function_code(
+            &mut diagnostics,
+            db,
+            GenericFunctionId::Loop(function_id),
+            &lowered_loop.lowered,
+            lowered_loop.return_type,
+        )
+    });
+    SierraFreeFunctionData { diagnostics: diagnostics.build(), function }
 }
 
+/// Query implementation of [SierraGenGroup::loop_function_sierra].
+pub fn loop_function_sierra(
+    db: &dyn SierraGenGroup,
+    function_id: LoopFunctionId,
+) -> Option<Arc<pre_sierra::Function>> {
+    db.priv_loop_function_sierra_data(function_id).function
+}
+
+/// Generates the Sierra code of a user function (a free function, an impl function or a loop
+/// function),
+/// given its lowered representation.
 fn get_function_code(
     diagnostics: &mut DiagnosticsBuilder<SierraGeneratorDiagnostic>,
     db: &dyn SierraGenGroup,
-    function_id: FreeFunctionId,
+    function_id: GenericFunctionId,
+    lowered_function: &lowering::lower::Lowered,
+    return_type: semantic::TypeId,
 ) -> Option<Arc<pre_sierra::Function>> {
-    let signature = db.free_function_declaration_signature(function_id)?;
-    let lowered_function = &*db.free_function_lowered(function_id)?;
     let block = &lowered_function.blocks[lowered_function.root?];
 
     // Find the local variables.
@@ -91,7 +137,7 @@ fn get_function_code(
         })
     }
 
-    let ret_types = vec![db.get_concrete_type_id(signature.return_type)?];
+    let ret_types = vec![db.get_concrete_type_id(return_type)?];
 
     let mut statements: Vec<pre_sierra::Statement> = vec![label];
 
@@ -108,10 +154,11 @@ fn get_function_code(
 
     // Generate the return statement if necessary.
     match &block.end {
-        lowering::BlockEnd::Callsite(returned_variables) => {
+        lowering::BlockEnd::Callsite(returned_variables)
+        | lowering::BlockEnd::Return(returned_variables) => {
             statements.extend(generate_return_code(&mut context, returned_variables)?);
         }
-        lowering::BlockEnd::Return(_) | lowering::BlockEnd::Unreachable => {}
+        lowering::BlockEnd::Unreachable => {}
     };
 
     let statements = add_store_statements(
@@ -124,6 +171,7 @@ fn get_function_code(
     );
 
     let statements = add_dups_and_drops(&mut context, &parameters, statements);
+    let (statements, statements_locations) = extract_locations(statements);
 
     // TODO(spapini): Don't intern objects for the semantic model outside the crate. These should
     // be regarded as private.
@@ -131,12 +179,13 @@ fn get_function_code(
         pre_sierra::Function {
             id: db.intern_sierra_function(db.intern_function(semantic::FunctionLongId {
                 function: semantic::ConcreteFunction {
-                    generic_function: GenericFunctionId::Free(function_id),
+                    generic_function: function_id,
                     // TODO(lior): Add generic arguments.
                     generic_args: vec![],
                 },
             })),
             body: statements,
+            statements_locations,
             entry_point: label_id,
             parameters,
             ret_types,
@@ -145,6 +194,28 @@ fn get_function_code(
     )
 }
 
+/// Removes the [Statement::Location] markers from the given statements.
+///
+/// Returns the remaining statements, and the location of each of them - the location of the last
+/// marker preceding it, if any.
+fn extract_locations(statements: Vec<Statement>) -> (Vec<Statement>, Vec<Option<StableLocation>>) {
+    let mut current_location = None;
+    let mut locations = vec![];
+    let statements = statements
+        .into_iter()
+        .filter(|statement| {
+            if let Statement::Location(location) = statement {
+                current_location = Some(location.clone());
+                false
+            } else {
+                locations.push(current_location.clone());
+                true
+            }
+        })
+        .collect();
+    (statements, locations)
+}
+
 /// Allocates space for the local variables.
 /// Returns:
 /// * A map from a Sierra variable that should be stored as local variable to its allocated space
@@ -265,7 +336,9 @@ fn get_var_types(
                     }
                 }
             }
-            Statement::Sierra(sierra::program::GenStatement::Return(_)) | Statement::Label(_) => {}
+            Statement::Sierra(sierra::program::GenStatement::Return(_))
+            | Statement::Label(_)
+            | Statement::Location(_) => {}
             Statement::PushValues(_) => {
                 panic!("Unexpected pre_sierra::Statement::PushValues in get_var_types.")
             }
@@ -1,5 +1,5 @@
 use defs::db::DefsGroup;
-use defs::ids::ModuleItemId;
+use defs::ids::{FunctionWithBodyId, ModuleItemId};
 use indoc::indoc;
 use lowering::db::LoweringGroup;
 use pretty_assertions::assert_eq;
@@ -29,15 +29,15 @@ fn test_function_generator() {
     )
     .unwrap()
     .module_id;
-    let foo = extract_matches!(
+    let foo = FunctionWithBodyId::Free(extract_matches!(
         db.module_items(module_id).unwrap().items["foo"],
         ModuleItemId::FreeFunction,
         "Unexpected item type."
-    );
+    ));
 
     db.module_lowering_diagnostics(module_id).expect("");
-    db.free_function_sierra_diagnostics(foo).expect("");
-    let function = db.free_function_sierra(foo).unwrap();
+    db.function_with_body_sierra_diagnostics(foo).expect("");
+    let function = db.function_with_body_sierra(foo).unwrap();
     assert_eq!(
         function
             .body
@@ -71,7 +71,7 @@ fn test_function_generator_local_vars() {
         &mut db,
         indoc! {"
             func foo(a: felt) -> felt {
-                let b = a + a + a;
+                let b = felt_add(felt_add(a, a), a);
                 revoke_ap();
                 b
             }
@@ -84,15 +84,15 @@ fn test_function_generator_local_vars() {
     )
     .unwrap()
     .module_id;
-    let foo = extract_matches!(
+    let foo = FunctionWithBodyId::Free(extract_matches!(
         db.module_items(module_id).unwrap().items["foo"],
         ModuleItemId::FreeFunction,
         "Unexpected item type."
-    );
+    ));
 
     db.module_lowering_diagnostics(module_id).expect("");
-    db.free_function_sierra_diagnostics(foo).expect("");
-    let function = db.free_function_sierra(foo).unwrap();
+    db.function_with_body_sierra_diagnostics(foo).expect("");
+    let function = db.function_with_body_sierra(foo).unwrap();
     assert_eq!(
         function
             .body
@@ -22,10 +22,11 @@ pub fn test_function_generator(
 
     // Verify that there are no diagnostics.
     let lowering_diagnostics = db.module_lowering_diagnostics(test_function.module_id);
-    let sierra_gen_diagnostics = db.free_function_sierra_diagnostics(test_function.function_id);
+    let sierra_gen_diagnostics =
+        db.function_with_body_sierra_diagnostics(test_function.function_id);
 
     // Compile the function.
-    let function = db.free_function_sierra(test_function.function_id);
+    let function = db.function_with_body_sierra(test_function.function_id);
     let sierra_code: String = function.map_or("None".into(), |func| {
         func.body
             .iter()
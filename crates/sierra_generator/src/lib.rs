@@ -25,3 +25,4 @@ mod types;
 mod utils;
 
 pub use diagnostic::SierraGeneratorDiagnostic;
+pub use program_generator::{SierraProgramDebugInfo, SierraProgramWithDebug};
@@ -40,7 +40,7 @@ fn check_find_local_variables(
         .unwrap()
         .expect_with_db(db, "Unexpected diagnostics.");
 
-    let lowered_function = &*db.free_function_lowered(test_function.function_id).unwrap();
+    let lowered_function = &*db.function_with_body_lowered(test_function.function_id).unwrap();
 
     let lowered_formatter = lowering::fmt::LoweredFormatter { db, lowered: lowered_function };
     let lowered_str = format!("{:?}", lowered_function.debug(&lowered_formatter));
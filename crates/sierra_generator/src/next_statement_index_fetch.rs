@@ -11,7 +11,7 @@ pub struct NextStatementIndexFetch {
 impl NextStatementIndexFetch {
     /// Creates the mapping to fetch statement indices.
     ///
-    /// If `include_label_indices` is `true`, indices will include label statements.
+    /// If `include_label_indices` is `true`, indices will include label and location statements.
     /// Otherwise, those statements will be skipped.
     pub fn new(statements: &[Statement], include_label_indices: bool) -> Self {
         let mut index = 0;
@@ -29,6 +29,11 @@ impl NextStatementIndexFetch {
                         index += 1;
                     }
                 }
+                Statement::Location(_) => {
+                    if include_label_indices {
+                        index += 1;
+                    }
+                }
                 Statement::PushValues(_) => panic!(
                     "Unexpected pre_sierra::Statement::PushValues in \
                      NextStatementIndexFetch::new()."
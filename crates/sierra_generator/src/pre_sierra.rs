@@ -1,5 +1,6 @@
 use db_utils::define_short_id;
-use defs::ids::FreeFunctionId;
+use defs::diagnostic_utils::StableLocation;
+use defs::ids::GenericFunctionId;
 use sierra::ids::ConcreteTypeId;
 use sierra::program;
 use utils::write_comma_separated;
@@ -11,7 +12,7 @@ use crate::db::SierraGenGroup;
 // TODO(lior): Make sure this struct can only be constructed by expr_generator_context.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub struct LabelLongId {
-    pub parent: FreeFunctionId,
+    pub parent: GenericFunctionId,
     // A unique identifier inside the function
     pub id: usize,
 }
@@ -30,6 +31,8 @@ pub struct Function {
     pub id: sierra::ids::FunctionId,
     /// The body of the function.
     pub body: Vec<Statement>,
+    /// The location in the source code of each statement of the body, if known.
+    pub statements_locations: Vec<Option<StableLocation>>,
     /// A label pointing to the first instruction of the function.
     pub entry_point: LabelId,
     /// The parameters for the function.
@@ -51,6 +54,9 @@ pub enum Statement {
     /// Note that push values does not guarantee that new copies of the values will be pushed.
     /// If a prefix of the values is already on the stack, they will not be re-pushed.
     PushValues(Vec<PushValue>),
+    /// A marker of the location in the source code of the following statements. Such markers are
+    /// removed from the body of a [Function] and moved to [Function::statements_locations].
+    Location(StableLocation),
 }
 impl std::fmt::Display for Statement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -70,6 +76,7 @@ impl std::fmt::Display for Statement {
                 )?;
                 write!(f, ")")
             }
+            Statement::Location(_) => write!(f, "Location"),
         }
     }
 }
@@ -1,10 +1,11 @@
 use std::collections::{HashSet, VecDeque};
 use std::sync::Arc;
 
-use defs::ids::{FreeFunctionId, ModuleId};
+use defs::diagnostic_utils::StableLocation;
+use defs::ids::{FreeFunctionId, FunctionWithBodyId, GenericFunctionId, ModuleId};
 use diagnostics::{Diagnostics, DiagnosticsBuilder};
 use filesystem::ids::CrateId;
-use itertools::chain;
+use itertools::{chain, zip_eq};
 use sierra::extensions::core::CoreLibFunc;
 use sierra::extensions::GenericLibFuncEx;
 use sierra::ids::{ConcreteLibFuncId, ConcreteTypeId};
@@ -29,8 +30,22 @@ pub fn module_sierra_diagnostics(
     module_id: ModuleId,
 ) -> Diagnostics<SierraGeneratorDiagnostic> {
     let mut diagnostics = DiagnosticsBuilder::new();
-    for free_function_id in db.module_data(module_id).unwrap_or_default().free_functions.keys() {
-        diagnostics.extend(db.free_function_sierra_diagnostics(*free_function_id))
+    let module_data = db.module_data(module_id).unwrap_or_default();
+    let impl_functions = module_data
+        .impls
+        .keys()
+        .flat_map(|impl_id| db.impl_functions(*impl_id).unwrap_or_default())
+        .map(FunctionWithBodyId::Impl);
+    for function_id in chain!(
+        module_data.free_functions.keys().copied().map(FunctionWithBodyId::Free),
+        impl_functions
+    ) {
+        // Generic functions can't be compiled to Sierra without specializing them. Calls to them,
+        // and calls to trait functions in their bodies, are reported as lowering diagnostics.
+        if !db.function_with_body_generic_params(function_id).unwrap_or_default().is_empty() {
+            continue;
+        }
+        diagnostics.extend(db.function_with_body_sierra_diagnostics(function_id))
     }
     diagnostics.build()
 }
@@ -65,6 +80,9 @@ fn collect_used_libfuncs(
             pre_sierra::Statement::PushValues(_) => {
                 panic!("Unexpected pre_sierra::Statement::PushValues in collect_used_libfuncs().")
             }
+            pre_sierra::Statement::Location(_) => {
+                panic!("Unexpected pre_sierra::Statement::Location in collect_used_libfuncs().")
+            }
         })
         .collect()
 }
@@ -131,24 +149,64 @@ fn collect_used_types(
         .collect()
 }
 
-pub fn get_sierra_program_for_functions(
+/// The debug information of a Sierra program generated from Cairo code.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SierraProgramDebugInfo {
+    /// The location in the Cairo code of each statement of the program, if known.
+    pub statements_locations: Vec<Option<StableLocation>>,
+}
+impl SierraProgramDebugInfo {
+    /// Returns the location in the Cairo code of each statement of the program, formatted as
+    /// `file:line:col`.
+    pub fn statements_source_locations(&self, db: &dyn SierraGenGroup) -> Vec<Option<String>> {
+        self.statements_locations
+            .iter()
+            .map(|location| {
+                location.as_ref().map(|location| {
+                    location.diagnostic_location(db.upcast()).fmt_location(db.upcast())
+                })
+            })
+            .collect()
+    }
+}
+
+/// A Sierra program, together with its debug information.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SierraProgramWithDebug {
+    pub program: Arc<program::Program>,
+    pub debug_info: SierraProgramDebugInfo,
+}
+
+pub fn get_sierra_program_with_debug_for_functions(
     db: &dyn SierraGenGroup,
     requested_function_ids: Vec<FreeFunctionId>,
-) -> Option<Arc<sierra::program::Program>> {
+) -> Option<Arc<SierraProgramWithDebug>> {
     let mut functions: Vec<Arc<pre_sierra::Function>> = vec![];
     let mut statements: Vec<pre_sierra::Statement> = vec![];
-    let mut processed_function_ids = UnorderedHashSet::<FreeFunctionId>::default();
-    let mut function_id_queue: VecDeque<FreeFunctionId> =
-        requested_function_ids.into_iter().collect();
+    let mut statements_locations = vec![];
+    let mut processed_function_ids = UnorderedHashSet::<GenericFunctionId>::default();
+    let mut function_id_queue: VecDeque<GenericFunctionId> =
+        requested_function_ids.into_iter().map(GenericFunctionId::Free).collect();
     while let Some(function_id) = function_id_queue.pop_front() {
         if !processed_function_ids.insert(function_id) {
             continue;
         }
-        let function: Arc<pre_sierra::Function> = db.free_function_sierra(function_id)?;
+        let function: Arc<pre_sierra::Function> = match function_id {
+            GenericFunctionId::Loop(loop_function_id) => db.loop_function_sierra(loop_function_id),
+            _ => db.function_with_body_sierra(
+                function_id.function_with_body().expect("Only user functions are queued."),
+            ),
+        }?;
         functions.push(function.clone());
         statements.extend_from_slice(function.body.as_slice());
+        for (statement, location) in zip_eq(&function.body, &function.statements_locations) {
+            // Labels are removed on label resolution.
+            if !matches!(statement, pre_sierra::Statement::Label(_)) {
+                statements_locations.push(location.clone());
+            }
+        }
         for statement in &function.body {
-            if let Some(related_function_id) = try_get_free_function_id(db, statement) {
+            if let Some(related_function_id) = try_get_user_function_id(db, statement) {
                 function_id_queue.push_back(related_function_id);
             }
         }
@@ -162,7 +220,7 @@ pub fn get_sierra_program_for_functions(
     let label_replacer = LabelReplacer::from_statements(&statements);
     let resolved_statements = resolve_labels(statements, &label_replacer);
 
-    Some(Arc::new(program::Program {
+    let program = program::Program {
         type_declarations,
         libfunc_declarations,
         statements: resolved_statements,
@@ -178,14 +236,26 @@ pub fn get_sierra_program_for_functions(
                 )
             })
             .collect(),
+    };
+    Some(Arc::new(SierraProgramWithDebug {
+        program: Arc::new(program),
+        debug_info: SierraProgramDebugInfo { statements_locations },
     }))
 }
 
-/// Tries extracting a free function id from a pre-Sierra statement.
-fn try_get_free_function_id(
+pub fn get_sierra_program_for_functions(
+    db: &dyn SierraGenGroup,
+    requested_function_ids: Vec<FreeFunctionId>,
+) -> Option<Arc<sierra::program::Program>> {
+    Some(db.get_sierra_program_with_debug_for_functions(requested_function_ids)?.program.clone())
+}
+
+/// Tries extracting the id of a user function (a free function, an impl function or a loop
+/// function) from a pre-Sierra statement.
+fn try_get_user_function_id(
     db: &dyn SierraGenGroup,
     statement: &pre_sierra::Statement,
-) -> Option<FreeFunctionId> {
+) -> Option<GenericFunctionId> {
     let invc = try_extract_matches!(
         try_extract_matches!(statement, pre_sierra::Statement::Sierra)?,
         program::GenStatement::Invocation
@@ -206,20 +276,36 @@ fn try_get_free_function_id(
         )
         .function;
     assert!(function.generic_args.is_empty(), "Generic args are not yet supported");
-    try_extract_matches!(function.generic_function, defs::ids::GenericFunctionId::Free)
+    match function.generic_function {
+        GenericFunctionId::Free(_)
+        | GenericFunctionId::ImplFunction(_)
+        | GenericFunctionId::Loop(_) => Some(function.generic_function),
+        _ => None,
+    }
 }
 
-pub fn get_sierra_program(
+pub fn get_sierra_program_with_debug(
     db: &dyn SierraGenGroup,
     requested_crate_ids: Vec<CrateId>,
-) -> Option<Arc<sierra::program::Program>> {
+) -> Option<Arc<SierraProgramWithDebug>> {
     let mut requested_function_ids = vec![];
     for crate_id in requested_crate_ids {
         for module_id in db.crate_modules(crate_id).iter() {
             for (free_func_id, _) in db.module_data(*module_id)?.free_functions {
-                requested_function_ids.push(free_func_id)
+                // Generic functions can't be compiled to Sierra without specializing them, and are
+                // only reachable through calls that report a lowering diagnostic.
+                if db.free_function_declaration_generic_params(free_func_id)?.is_empty() {
+                    requested_function_ids.push(free_func_id)
+                }
             }
         }
     }
-    db.get_sierra_program_for_functions(requested_function_ids)
+    db.get_sierra_program_with_debug_for_functions(requested_function_ids)
+}
+
+pub fn get_sierra_program(
+    db: &dyn SierraGenGroup,
+    requested_crate_ids: Vec<CrateId>,
+) -> Option<Arc<sierra::program::Program>> {
+    Some(db.get_sierra_program_with_debug(requested_crate_ids)?.program.clone())
 }
@@ -143,3 +143,47 @@ fn test_only_include_dependecies(func_name: &str, sierra_used_funcs: &[&str]) {
         sierra_used_funcs
     );
 }
+
+#[test]
+fn test_statements_locations() {
+    let (db, crate_id) = setup_db_and_get_crate_id(indoc! {"
+        func foo(a: felt) -> felt {
+            felt_add(1, bar(a))
+        }
+
+        func bar(a: felt) -> felt {
+            felt_mul(a, 2)
+        }
+    "});
+    let program_with_debug = db.get_sierra_program_with_debug(vec![crate_id]).unwrap();
+    let program = replace_sierra_ids_in_program(&db, &program_with_debug.program);
+    assert_eq!(
+        program
+            .statements
+            .iter()
+            .zip(program_with_debug.debug_info.statements_source_locations(&db))
+            .map(|(statement, location)| match location {
+                Some(location) => format!("{statement} // {location}"),
+                None => statement.to_string(),
+            })
+            .join("\n"),
+        indoc! {"
+            alloc_local<felt>() -> ([2])
+            finalize_locals() -> ()
+            revoke_ap_tracking() -> ()
+            felt_const<1>() -> ([1])
+            store_temp<felt>([0]) -> ([4]) // lib.cairo:2:17
+            store_local<felt>([2], [1]) -> ([1]) // lib.cairo:2:17
+            function_call<user@test::bar>([4]) -> ([3]) // lib.cairo:2:17
+            felt_add([1], [3]) -> ([5]) // lib.cairo:2:5
+            store_temp<felt>([5]) -> ([5]) // lib.cairo:2:5
+            rename<felt>([5]) -> ([6]) // lib.cairo:2:5
+            return([6]) // lib.cairo:2:5
+            revoke_ap_tracking() -> ()
+            felt_const<2>() -> ([1])
+            felt_mul([0], [1]) -> ([2]) // lib.cairo:6:5
+            store_temp<felt>([2]) -> ([2]) // lib.cairo:6:5
+            rename<felt>([2]) -> ([3]) // lib.cairo:6:5
+            return([3]) // lib.cairo:6:5"}
+    );
+}
@@ -22,6 +22,9 @@ pub fn resolve_labels(
             pre_sierra::Statement::PushValues(_) => {
                 panic!("Unexpected pre_sierra::Statement::PushValues in resolve_labels().")
             }
+            pre_sierra::Statement::Location(_) => {
+                panic!("Unexpected pre_sierra::Statement::Location in resolve_labels().")
+            }
         })
         .collect()
 }
@@ -1,4 +1,4 @@
-use defs::ids::GenericFunctionId;
+use defs::ids::{FunctionWithBodyId, GenericFunctionId};
 use sierra::extensions::lib_func::{SierraApChange, SignatureSpecializationContext};
 use sierra::extensions::type_specialization_context::TypeSpecializationContext;
 use sierra::program::ConcreteTypeLongId;
@@ -51,12 +51,18 @@ impl SignatureSpecializationContext for SierraSignatureSpecializationContext<'_>
             .lookup_intern_function(self.0.lookup_intern_sierra_function(function_id.clone()))
             .function;
         match concrete_function.generic_function {
-            GenericFunctionId::Free(free_function_id) => self.0.get_ap_change(free_function_id),
+            GenericFunctionId::Free(free_function_id) => {
+                self.0.get_ap_change(FunctionWithBodyId::Free(free_function_id))
+            }
+            GenericFunctionId::ImplFunction(impl_function_id) => {
+                self.0.get_ap_change(FunctionWithBodyId::Impl(impl_function_id))
+            }
+            // Loop functions are recursive, so their ap change is unknown.
+            GenericFunctionId::Loop(_) => Some(SierraApChange::Unknown),
             GenericFunctionId::Extern(_) | GenericFunctionId::TraitFunction(_) => panic!(
                 "Internal compiler error: get_function_ap_change() should only be used for user \
                  defined functions."
             ),
-            GenericFunctionId::ImplFunction(_) => todo!(),
         }
     }
 }
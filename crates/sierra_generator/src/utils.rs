@@ -1,5 +1,6 @@
 use defs::ids::GenericFunctionId;
 use num_bigint::BigInt;
+use semantic::corelib::get_core_ty_by_name;
 use sierra::extensions::core::CoreLibFunc;
 use sierra::extensions::lib_func::LibFuncSignature;
 use sierra::extensions::GenericLibFuncEx;
@@ -120,12 +121,20 @@ fn get_libfunc_id_without_generics(
     })
 }
 
-pub fn felt_const_libfunc_id(
+/// Returns the libfunc that creates a constant of the given type: `uint128_const` for `uint128`,
+/// and `felt_const` otherwise.
+pub fn const_libfunc_id_by_type(
     db: &dyn SierraGenGroup,
+    ty: semantic::TypeId,
     value: BigInt,
 ) -> sierra::ids::ConcreteLibFuncId {
+    let name = if ty == get_core_ty_by_name(db.upcast(), "uint128".into(), vec![]) {
+        "uint128_const"
+    } else {
+        "felt_const"
+    };
     db.intern_concrete_lib_func(sierra::program::ConcreteLibFuncLongId {
-        generic_id: sierra::ids::GenericLibFuncId::from_string("felt_const"),
+        generic_id: sierra::ids::GenericLibFuncId::from_string(name),
         generic_args: vec![sierra::program::GenericArg::Value(value)],
     })
 }
@@ -220,7 +229,9 @@ pub fn get_concrete_libfunc_id(
     // Check if this is a user-defined function or a libfunc.
     let concrete_function = db.lookup_intern_function(function).function;
     match concrete_function.generic_function {
-        GenericFunctionId::Free(_) => (concrete_function, function_call_libfunc_id(db, function)),
+        GenericFunctionId::Free(_)
+        | GenericFunctionId::ImplFunction(_)
+        | GenericFunctionId::Loop(_) => (concrete_function, function_call_libfunc_id(db, function)),
         GenericFunctionId::Extern(extern_id) => {
             let mut generic_args = vec![];
             for generic_arg in &concrete_function.generic_args {
@@ -242,6 +253,5 @@ pub fn get_concrete_libfunc_id(
         GenericFunctionId::TraitFunction(_) => {
             panic!("Trait function should be replaced with concrete functions.")
         }
-        GenericFunctionId::ImplFunction(_) => todo!(),
     }
 }
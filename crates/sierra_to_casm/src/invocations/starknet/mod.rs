@@ -1,24 +1,46 @@
+use casm::operand::DerefOrImmediate;
 use num_bigint::BigInt;
-use sierra::extensions::starknet::storage::StorageAddressConstConcreteLibFunc;
+use sierra::extensions::felt::FeltBinaryOperator;
+use sierra::extensions::starknet::storage::{
+    StorageAddressConstConcreteLibFunc, StorageAddressOffsetConcreteLibFunc,
+};
 use sierra::extensions::starknet::StarkNetConcreteLibFunc;
 
 use super::{CompiledInvocation, CompiledInvocationBuilder};
 use crate::invocations::InvocationError;
-use crate::references::{CellExpression, ReferenceExpression};
+use crate::references::{BinOpExpression, CellExpression, ReferenceExpression, ReferenceValue};
 
-mod storage;
-use storage::build_storage_read;
+mod syscalls;
+use syscalls::build_syscall;
 
-/// Builds instructions for Sierra array operations.
+/// Builds instructions for Sierra StarkNet operations.
 pub fn build(
     libfunc: &StarkNetConcreteLibFunc,
     builder: CompiledInvocationBuilder<'_>,
 ) -> Result<CompiledInvocation, InvocationError> {
     match libfunc {
-        StarkNetConcreteLibFunc::StorageRead(_) => build_storage_read(builder),
         StarkNetConcreteLibFunc::StorageAddressConst(libfunc) => {
             build_storage_address_const(builder, libfunc)
         }
+        StarkNetConcreteLibFunc::StorageAddressOffset(libfunc) => {
+            build_storage_address_offset(builder, libfunc)
+        }
+        StarkNetConcreteLibFunc::StorageRead(_) => build_syscall(builder, "storage_read"),
+        StarkNetConcreteLibFunc::StorageWrite(_) => build_syscall(builder, "storage_write"),
+        StarkNetConcreteLibFunc::CallContract(_) => build_syscall(builder, "call_contract"),
+        StarkNetConcreteLibFunc::EmitEvent(_) => build_syscall(builder, "emit_event"),
+        StarkNetConcreteLibFunc::GetCallerAddress(_) => {
+            build_syscall(builder, "get_caller_address")
+        }
+        StarkNetConcreteLibFunc::GetContractAddress(_) => {
+            build_syscall(builder, "get_contract_address")
+        }
+        StarkNetConcreteLibFunc::GetBlockNumber(_) => build_syscall(builder, "get_block_number"),
+        StarkNetConcreteLibFunc::GetBlockTimestamp(_) => {
+            build_syscall(builder, "get_block_timestamp")
+        }
+        StarkNetConcreteLibFunc::SendMessageToL1(_) => build_syscall(builder, "send_message_to_l1"),
+        StarkNetConcreteLibFunc::Deploy(_) => build_syscall(builder, "deploy"),
     }
 }
 
@@ -36,3 +58,32 @@ fn build_storage_address_const(
         [ReferenceExpression::from_cell(CellExpression::Immediate(libfunc.c.clone()))].into_iter(),
     ))
 }
+
+/// Handles the storage_address_offset libfunc.
+fn build_storage_address_offset(
+    builder: CompiledInvocationBuilder<'_>,
+    libfunc: &StorageAddressOffsetConcreteLibFunc,
+) -> Result<CompiledInvocation, InvocationError> {
+    let base = match builder.refs {
+        [ReferenceValue { expression, .. }] => expression
+            .try_unpack_single()
+            .map_err(|_| InvocationError::InvalidReferenceExpressionForArgument)?,
+        refs => {
+            return Err(InvocationError::WrongNumberOfArguments {
+                expected: 1,
+                actual: refs.len(),
+            });
+        }
+    };
+    let CellExpression::Deref(base) = base else {
+        return Err(InvocationError::InvalidReferenceExpressionForArgument);
+    };
+    Ok(builder.build_only_reference_changes(
+        [ReferenceExpression::from_cell(CellExpression::BinOp(BinOpExpression {
+            op: FeltBinaryOperator::Add,
+            a: base,
+            b: DerefOrImmediate::Immediate(libfunc.offset.clone()),
+        }))]
+        .into_iter(),
+    ))
+}
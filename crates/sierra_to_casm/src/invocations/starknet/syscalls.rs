@@ -0,0 +1,95 @@
+use casm::ap_change::ApplyApChange;
+use casm::hints::Hint;
+use casm::operand::{BinOpOperand, DerefOrImmediate, ResOperand};
+use casm::{casm, casm_extend};
+use num_bigint::BigInt;
+use num_traits::FromPrimitive;
+use sierra::extensions::felt::FeltBinaryOperator;
+use sierra::extensions::ConcreteLibFunc;
+use utils::try_extract_matches;
+
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::references::{
+    try_unpack_deref_with_offset, BinOpExpression, CellExpression, ReferenceExpression,
+};
+
+/// Builds instructions for a StarkNet system call.
+///
+/// The request, made of the selector followed by the cells of the arguments, is written into the
+/// system call segment, followed by the system call hint. The hint writes the response right after
+/// the request, and the outputs of the libfunc refer to it.
+pub fn build_syscall(
+    builder: CompiledInvocationBuilder<'_>,
+    selector: &str,
+) -> Result<CompiledInvocation, InvocationError> {
+    let selector = BigInt::from_bytes_le(num_bigint::Sign::Plus, selector.as_bytes());
+
+    let (expr_syscall_ptr, request_exprs) =
+        builder.refs.split_first().ok_or(InvocationError::WrongNumberOfArguments {
+            expected: builder.libfunc.param_signatures().len(),
+            actual: 0,
+        })?;
+    let (syscall_base, syscall_offset) =
+        try_unpack_deref_with_offset(&expr_syscall_ptr.expression)?;
+    // All the cells are used after the selector is pushed.
+    let syscall_base = syscall_base.unchecked_apply_known_ap_change(1);
+    let request_cells = request_exprs
+        .iter()
+        .flat_map(|reference| reference.expression.cells.iter())
+        .map(|cell| {
+            try_extract_matches!(cell, CellExpression::Deref)
+                .map(|cell| cell.unchecked_apply_known_ap_change(1))
+                .ok_or(InvocationError::InvalidReferenceExpressionForArgument)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let output_sizes: Vec<usize> = builder.libfunc.output_types()[0]
+        .iter()
+        .skip(1)
+        .map(|ty| builder.program_info.type_sizes[ty])
+        .collect();
+    let syscall_size = 1 + request_cells.len() + output_sizes.iter().sum::<usize>();
+    if syscall_offset as usize + syscall_size > i16::MAX as usize {
+        return Err(InvocationError::InvalidReferenceExpressionForArgument);
+    }
+
+    let mut ctx = casm! {
+        [ap] = selector, ap++;
+        [ap + -1] = [[syscall_base] + syscall_offset];
+    };
+    let mut offset = syscall_offset;
+    for cell in request_cells {
+        offset += 1;
+        casm_extend!(ctx, cell = [[syscall_base] + offset];);
+    }
+    // The hint is executed before the instruction it is attached to, so it is attached to an
+    // instruction following the complete request.
+    casm_extend!(ctx, ap += 0;);
+    ctx.instructions.last_mut().unwrap().hints = vec![Hint::SystemCall {
+        syscall_ptr: ResOperand::BinOp(BinOpOperand {
+            op: casm::operand::Operation::Add,
+            a: syscall_base,
+            b: DerefOrImmediate::Immediate(BigInt::from_i16(syscall_offset).unwrap()),
+        }),
+    }];
+
+    let mut output_expressions = vec![ReferenceExpression {
+        cells: vec![CellExpression::BinOp(BinOpExpression {
+            op: FeltBinaryOperator::Add,
+            a: syscall_base,
+            b: DerefOrImmediate::Immediate(
+                BigInt::from_i16(syscall_offset).unwrap() + syscall_size,
+            ),
+        })],
+    }];
+    for size in output_sizes {
+        output_expressions.push(ReferenceExpression {
+            cells: (0..size)
+                .map(|_| {
+                    offset += 1;
+                    CellExpression::DoubleDeref(syscall_base, offset)
+                })
+                .collect(),
+        });
+    }
+    Ok(builder.build(ctx.instructions, vec![], [output_expressions.into_iter()].into_iter()))
+}
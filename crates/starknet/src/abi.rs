@@ -1,8 +1,13 @@
-use defs::ids::{TraitFunctionId, TraitId};
+use defs::ids::{
+    ImplFunctionId, ImplId, ModuleId, ModuleItemId, StructId, TraitFunctionId, TraitId,
+};
 use semantic::db::SemanticGroup;
 use serde::{Deserialize, Serialize};
+use syntax::node::TypedSyntaxNode;
 use thiserror::Error;
 
+use crate::plugin::{CONSTRUCTOR_ATTR, EVENT_ATTR, L1_HANDLER_ATTR};
+
 #[cfg(test)]
 #[path = "abi_test.rs"]
 mod test;
@@ -11,36 +16,105 @@ mod test;
 #[derive(Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct Contract {
-    // TODO(spapini): Add storage variables.
     pub items: Vec<Item>,
 }
 impl Contract {
-    /// Creates a Starknet contract ABI from a TraitId.
-    pub fn from_trait(db: &dyn SemanticGroup, trait_id: TraitId) -> Result<Self, ABIError> {
+    /// Creates a Starknet contract ABI from a TraitId and the ImplId of the contract implementing
+    /// it.
+    pub fn from_trait(
+        db: &dyn SemanticGroup,
+        trait_id: TraitId,
+        impl_id: ImplId,
+    ) -> Result<Self, ABIError> {
         if !db.trait_generic_params(trait_id).ok_or(ABIError::CompilationError)?.is_empty() {
             return Err(ABIError::GenericTraitsUnsupported);
         }
 
         let mut contract = Self::default();
 
-        for trait_function_id in db.trait_functions(trait_id).unwrap_or_default().values() {
-            contract.add_function(db, *trait_function_id)?;
+        let impl_functions = db.impl_functions(impl_id).ok_or(ABIError::CompilationError)?;
+        for (name, trait_function_id) in db.trait_functions(trait_id).unwrap_or_default() {
+            let impl_function_id = impl_functions
+                .iter()
+                .find(|impl_function_id| impl_function_id.name(db.upcast()) == name)
+                .ok_or(ABIError::CompilationError)?;
+            contract.add_function(db, trait_function_id, *impl_function_id)?;
         }
 
         Ok(contract)
     }
 
-    /// Adds a function to the ABI from a TraitFunctionId.
+    /// Adds the storage variables of a contract to the ABI, from the members of its storage struct.
+    pub fn add_storage_vars(
+        &mut self,
+        db: &dyn SemanticGroup,
+        struct_id: StructId,
+    ) -> Result<(), ABIError> {
+        for (name, member) in db.struct_members(struct_id).ok_or(ABIError::CompilationError)? {
+            self.items.push(Item::Storage(Storage { name: name.into(), ty: member.ty.format(db) }));
+        }
+        Ok(())
+    }
+
+    /// Adds the events declared in a module to the ABI, from its functions annotated with
+    /// EVENT_ATTR. The keys of an event are the parameters named in the attribute arguments, and
+    /// its data is the rest of the parameters, excluding the system call pointer.
+    pub fn add_events(
+        &mut self,
+        db: &dyn SemanticGroup,
+        module_id: ModuleId,
+    ) -> Result<(), ABIError> {
+        let defs_db = db.upcast();
+        let syntax_db = db.upcast();
+        let module_items = db.module_items(module_id).ok_or(ABIError::CompilationError)?;
+        for item in module_items.items.values() {
+            let ModuleItemId::FreeFunction(free_function_id) = item else {
+                continue;
+            };
+            let attrs = db
+                .free_function_declaration_attributes(*free_function_id)
+                .ok_or(ABIError::CompilationError)?;
+            let Some(event_attr) = attrs.into_iter().find(|attr| attr.id == EVENT_ATTR) else {
+                continue;
+            };
+            let key_names: Vec<String> = event_attr
+                .args
+                .iter()
+                .map(|arg| arg.as_syntax_node().get_text_without_trivia(syntax_db))
+                .collect();
+            let signature = db
+                .free_function_declaration_signature(*free_function_id)
+                .ok_or(ABIError::CompilationError)?;
+            let mut event =
+                Event { name: free_function_id.name(defs_db).into(), keys: vec![], data: vec![] };
+            for param in signature.params.into_iter().skip(1) {
+                let input = Input { name: param.id.name(defs_db).into(), ty: param.ty.format(db) };
+                if key_names.contains(&input.name) {
+                    event.keys.push(input);
+                } else {
+                    event.data.push(input);
+                }
+            }
+            self.items.push(Item::Event(event));
+        }
+        Ok(())
+    }
+
+    /// Adds a function to the ABI from a TraitFunctionId. The type of the function is determined by
+    /// the attributes of its implementation, as for the entry points of the contract.
     fn add_function(
         &mut self,
         db: &dyn SemanticGroup,
         trait_function_id: TraitFunctionId,
+        impl_function_id: ImplFunctionId,
     ) -> Result<(), ABIError> {
         let defs_db = db.upcast();
         let name = trait_function_id.name(defs_db).into();
         let signature =
             db.trait_function_signature(trait_function_id).ok_or(ABIError::CompilationError)?;
-        self.items.push(Item::Function(Function {
+        let attrs =
+            db.impl_function_attributes(impl_function_id).ok_or(ABIError::CompilationError)?;
+        let function = Function {
             name,
             inputs: signature
                 .params
@@ -52,7 +126,14 @@ impl Contract {
                 .collect(),
             // TODO(spapini): output refs?
             output_ty: signature.return_type.format(db),
-        }));
+        };
+        self.items.push(if attrs.iter().any(|attr| attr.id == CONSTRUCTOR_ATTR) {
+            Item::Constructor(function)
+        } else if attrs.iter().any(|attr| attr.id == L1_HANDLER_ATTR) {
+            Item::L1Handler(function)
+        } else {
+            Item::Function(function)
+        });
 
         Ok(())
     }
@@ -76,6 +157,14 @@ pub enum ABIError {
 pub enum Item {
     #[serde(rename = "function")]
     Function(Function),
+    #[serde(rename = "constructor")]
+    Constructor(Function),
+    #[serde(rename = "l1_handler")]
+    L1Handler(Function),
+    #[serde(rename = "storage")]
+    Storage(Storage),
+    #[serde(rename = "event")]
+    Event(Event),
 }
 
 /// Contract function ABI.
@@ -92,3 +181,18 @@ pub struct Input {
     pub name: String,
     pub ty: String,
 }
+
+/// Contract storage variable ABI.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Storage {
+    pub name: String,
+    pub ty: String,
+}
+
+/// Contract event ABI.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Event {
+    pub name: String,
+    pub keys: Vec<Input>,
+    pub data: Vec<Input>,
+}
@@ -15,6 +15,17 @@ fn test_abi() {
         indoc! {"
             trait MyAbi {
                 func foo(a: felt, b: uint128) -> Option::<()>;
+                func init(ref syscall_ptr: SyscallPtr, a: felt);
+                func handle(ref syscall_ptr: SyscallPtr, from_address: felt);
+            }
+            impl MyImpl of MyAbi {
+                func foo(a: felt, b: uint128) -> Option::<()> {
+                    Option::<()>::Some(())
+                }
+                #[constructor]
+                func init(ref syscall_ptr: SyscallPtr, a: felt) {}
+                #[l1_handler]
+                func handle(ref syscall_ptr: SyscallPtr, from_address: felt) {}
             }
         "},
     )
@@ -26,7 +37,11 @@ fn test_abi() {
         db.module_item_by_name(module_id, "MyAbi".into()).unwrap(),
         ModuleItemId::Trait
     );
-    let abi = Contract::from_trait(db, trait_id).unwrap();
+    let impl_id = extract_matches!(
+        db.module_item_by_name(module_id, "MyImpl".into()).unwrap(),
+        ModuleItemId::Impl
+    );
+    let abi = Contract::from_trait(db, trait_id, impl_id).unwrap();
     let actual_serialization = serde_json::to_string_pretty(&abi).unwrap();
     assert_eq!(
         actual_serialization,
@@ -46,6 +61,169 @@ fn test_abi() {
                 }
               ],
               "output_ty": "core::option::Option::<()>"
+            },
+            {
+              "type": "constructor",
+              "name": "init",
+              "inputs": [
+                {
+                  "name": "syscall_ptr",
+                  "ty": "core::SyscallPtr"
+                },
+                {
+                  "name": "a",
+                  "ty": "core::felt"
+                }
+              ],
+              "output_ty": "()"
+            },
+            {
+              "type": "l1_handler",
+              "name": "handle",
+              "inputs": [
+                {
+                  "name": "syscall_ptr",
+                  "ty": "core::SyscallPtr"
+                },
+                {
+                  "name": "from_address",
+                  "ty": "core::felt"
+                }
+              ],
+              "output_ty": "()"
+            }
+          ]"#}
+    );
+}
+
+#[test]
+fn test_abi_storage() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let module_id = setup_test_module(
+        &mut db_val,
+        indoc! {"
+            trait MyAbi {
+                func foo(a: felt);
+            }
+            impl MyImpl of MyAbi {
+                func foo(a: felt) {}
+            }
+            struct MyStorage {
+                balance: felt,
+                owner: felt,
+            }
+        "},
+    )
+    .unwrap()
+    .module_id;
+
+    let db = &db_val;
+    let trait_id = extract_matches!(
+        db.module_item_by_name(module_id, "MyAbi".into()).unwrap(),
+        ModuleItemId::Trait
+    );
+    let struct_id = extract_matches!(
+        db.module_item_by_name(module_id, "MyStorage".into()).unwrap(),
+        ModuleItemId::Struct
+    );
+    let impl_id = extract_matches!(
+        db.module_item_by_name(module_id, "MyImpl".into()).unwrap(),
+        ModuleItemId::Impl
+    );
+    let mut abi = Contract::from_trait(db, trait_id, impl_id).unwrap();
+    abi.add_storage_vars(db, struct_id).unwrap();
+    let actual_serialization = serde_json::to_string_pretty(&abi).unwrap();
+    assert_eq!(
+        actual_serialization,
+        indoc! {
+        r#"[
+            {
+              "type": "function",
+              "name": "foo",
+              "inputs": [
+                {
+                  "name": "a",
+                  "ty": "core::felt"
+                }
+              ],
+              "output_ty": "()"
+            },
+            {
+              "type": "storage",
+              "name": "balance",
+              "ty": "core::felt"
+            },
+            {
+              "type": "storage",
+              "name": "owner",
+              "ty": "core::felt"
+            }
+          ]"#}
+    );
+}
+
+#[test]
+fn test_abi_events() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let module_id = setup_test_module(
+        &mut db_val,
+        indoc! {"
+            trait MyAbi {
+                func foo(a: felt);
+            }
+            impl MyImpl of MyAbi {
+                func foo(a: felt) {}
+            }
+            #[event(from)]
+            func Transfer(ref syscall_ptr: SyscallPtr, from: felt, amount: uint256) {}
+            func not_an_event(ref syscall_ptr: SyscallPtr, a: felt) {}
+        "},
+    )
+    .unwrap()
+    .module_id;
+
+    let db = &db_val;
+    let trait_id = extract_matches!(
+        db.module_item_by_name(module_id, "MyAbi".into()).unwrap(),
+        ModuleItemId::Trait
+    );
+    let impl_id = extract_matches!(
+        db.module_item_by_name(module_id, "MyImpl".into()).unwrap(),
+        ModuleItemId::Impl
+    );
+    let mut abi = Contract::from_trait(db, trait_id, impl_id).unwrap();
+    abi.add_events(db, module_id).unwrap();
+    let actual_serialization = serde_json::to_string_pretty(&abi).unwrap();
+    assert_eq!(
+        actual_serialization,
+        indoc! {
+        r#"[
+            {
+              "type": "function",
+              "name": "foo",
+              "inputs": [
+                {
+                  "name": "a",
+                  "ty": "core::felt"
+                }
+              ],
+              "output_ty": "()"
+            },
+            {
+              "type": "event",
+              "name": "Transfer",
+              "keys": [
+                {
+                  "name": "from",
+                  "ty": "core::felt"
+                }
+              ],
+              "data": [
+                {
+                  "name": "amount",
+                  "ty": "core::integer::uint256"
+                }
+              ]
             }
           ]"#}
     );
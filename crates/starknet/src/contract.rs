@@ -49,7 +49,11 @@ pub fn find_contract_structs(db: &dyn SemanticGroup) -> Vec<ContractDeclaration>
                             if attr.id == "contract" {
                                 contracts.push(ContractDeclaration {
                                     struct_id: *struct_id,
-                                    impls: attr.args.clone(),
+                                    impls: attr
+                                        .args
+                                        .iter()
+                                        .map(|arg| arg.value(db.upcast()))
+                                        .collect(),
                                 });
                             }
                         };
@@ -6,7 +6,7 @@ use compiler::db::RootDatabase;
 use compiler::diagnostics::check_and_eprint_diagnostics;
 use compiler::project::setup_project;
 use defs::db::DefsGroup;
-use defs::ids::{GenericFunctionId, LanguageElementId, ModuleId, ModuleItemId, TraitId};
+use defs::ids::{GenericFunctionId, ImplId, LanguageElementId, ModuleItemId, TraitId};
 use itertools::join;
 use num_bigint::BigUint;
 use plugins::get_default_plugins;
@@ -23,7 +23,7 @@ use utils::try_extract_matches;
 use crate::abi;
 use crate::casm_contract_class::{deserialize_big_uint, serialize_big_uint};
 use crate::contract::{find_contract_structs, resolve_contract_impls, starknet_keccak};
-use crate::plugin::{StarkNetPlugin, WRAPPER_PREFIX};
+use crate::plugin::{StarkNetPlugin, CONSTRUCTOR_ATTR, L1_HANDLER_ATTR, WRAPPER_PREFIX};
 
 #[cfg(test)]
 #[path = "contract_class_test.rs"]
@@ -115,26 +115,47 @@ pub fn compile_path(path: &Path, replace_ids: bool) -> anyhow::Result<ContractCl
         replacer.apply(&sierra_program)
     };
 
-    let entry_points_by_type = get_entry_points(db, impl_id.module(db), trait_id, &replacer)?;
+    let entry_points_by_type = get_entry_points(db, impl_id, trait_id, &replacer)?;
 
-    Ok(ContractClass {
-        sierra_program,
-        entry_points_by_type,
-        abi: abi::Contract::from_trait(db, trait_id)
-            .with_context(|| "Failed to extract contract ABI.")?,
-    })
+    let mut abi = abi::Contract::from_trait(db, trait_id, impl_id)
+        .with_context(|| "Failed to extract contract ABI.")?;
+    abi.add_storage_vars(db, contract.struct_id)
+        .with_context(|| "Failed to extract contract storage ABI.")?;
+    abi.add_events(db, contract.struct_id.module(db))
+        .with_context(|| "Failed to extract contract events ABI.")?;
+
+    Ok(ContractClass { sierra_program, entry_points_by_type, abi })
 }
 
-/// Return the entry points given a trait and a module_id where they are implemented.
+/// Return the entry points given a trait and the impl where they are implemented.
+/// The type of each entry point is determined by the attributes of its impl function.
 fn get_entry_points(
     db: &mut RootDatabase,
-    impl_module_id: ModuleId,
+    impl_id: ImplId,
     trait_id: TraitId,
     replacer: &CanonicalReplacer,
 ) -> Result<ContractEntryPoints, anyhow::Error> {
+    let impl_module_id = impl_id.module(db);
+    let impl_functions =
+        db.impl_functions(impl_id).with_context(|| "Failed to get impl functions.")?;
     let trait_functions = db.trait_functions(trait_id).unwrap();
     let mut entry_points_by_type = ContractEntryPoints::default();
     for function_name in trait_functions.keys() {
+        let impl_function_id = impl_functions
+            .iter()
+            .find(|impl_function_id| impl_function_id.name(db) == *function_name)
+            .with_context(|| format!("The `{}` function is not implemented.", function_name))?;
+        let attrs = db
+            .impl_function_attributes(*impl_function_id)
+            .with_context(|| format!("Failed to get the attributes of `{}`.", function_name))?;
+        let entry_points = if attrs.iter().any(|attr| attr.id == CONSTRUCTOR_ATTR) {
+            &mut entry_points_by_type.constructor
+        } else if attrs.iter().any(|attr| attr.id == L1_HANDLER_ATTR) {
+            &mut entry_points_by_type.l1_handler
+        } else {
+            &mut entry_points_by_type.external
+        };
+
         let item = db
             .module_item_by_name(
                 impl_module_id,
@@ -154,7 +175,7 @@ fn get_entry_points(
 
         let sierra_id = db.intern_sierra_function(func_id);
 
-        entry_points_by_type.external.push(ContractEntryPoint {
+        entry_points.push(ContractEntryPoint {
             selector: starknet_keccak(function_name.as_bytes()),
             function_idx: replacer.replace_function_id(&sierra_id).id as usize,
         });
@@ -1,4 +1,4 @@
-use defs::db::{MacroPlugin, PluginResult};
+use defs::db::{MacroPlugin, PluginDiagnostic, PluginResult};
 use genco::prelude::*;
 use itertools::join;
 use syntax::node::ast::{ItemFreeFunction, MaybeImplBody, Modifier, Param};
@@ -6,7 +6,15 @@ use syntax::node::db::SyntaxGroup;
 use syntax::node::helpers::GetIdentifier;
 use syntax::node::{ast, Terminal, TypedSyntaxNode};
 
+use crate::contract::starknet_keccak;
+
 static CONTRACT_IMPL_ATTR: &str = "ContractImpl";
+static CONTRACT_ATTR: &str = "contract";
+pub static CONSTRUCTOR_ATTR: &str = "constructor";
+pub static L1_HANDLER_ATTR: &str = "l1_handler";
+pub static EVENT_ATTR: &str = "event";
+/// Marks the function generated for an event, which keeps EVENT_ATTR for the ABI.
+static GENERATED_EVENT_ATTR: &str = "generated_event";
 pub static WRAPPER_PREFIX: &str = "__wrapper_";
 
 #[cfg(test)]
@@ -18,102 +26,315 @@ pub struct StarkNetPlugin {}
 
 impl MacroPlugin for StarkNetPlugin {
     fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
-        let impl_ast = match item_ast {
-            ast::Item::Impl(impl_ast) => impl_ast,
+        match item_ast {
+            ast::Item::Impl(impl_ast) => handle_contract_impl(db, impl_ast),
+            ast::Item::Struct(struct_ast) => handle_contract_storage(db, struct_ast),
+            ast::Item::FreeFunction(function_ast) => handle_event(db, function_ast),
             // TODO(yuval): diagnostic
-            _ => return PluginResult::default(),
-        };
-
-        let attrs = impl_ast.attributes(db).elements(db);
-        if !attrs.iter().any(|attr| attr.attr(db).text(db) == CONTRACT_IMPL_ATTR) {
-            // TODO(yuval): diagnostic
-            return PluginResult::default();
+            _ => PluginResult::default(),
         }
+    }
+}
 
-        let body = match impl_ast.body(db) {
-            MaybeImplBody::Some(body) => body,
-            // TODO(yuval): diagnostic
-            MaybeImplBody::None(_) => return PluginResult::default(),
+/// Returns the attribute with the given name, if it is in the list.
+fn find_attr(
+    db: &dyn SyntaxGroup,
+    attrs: &ast::AttributeList,
+    name: &str,
+) -> Option<ast::Attribute> {
+    attrs.elements(db).into_iter().find(|attr| attr.attr(db).text(db) == name)
+}
+
+/// If the impl is annotated with CONTRACT_IMPL_ATTR, generates the entry point wrappers of its
+/// functions. Functions annotated with CONSTRUCTOR_ATTR or L1_HANDLER_ATTR are validated according
+/// to their entry point type.
+fn handle_contract_impl(db: &dyn SyntaxGroup, impl_ast: ast::ItemImpl) -> PluginResult {
+    let attrs = impl_ast.attributes(db).elements(db);
+    if !attrs.iter().any(|attr| attr.attr(db).text(db) == CONTRACT_IMPL_ATTR) {
+        // TODO(yuval): diagnostic
+        return PluginResult::default();
+    }
+
+    let body = match impl_ast.body(db) {
+        MaybeImplBody::Some(body) => body,
+        // TODO(yuval): diagnostic
+        MaybeImplBody::None(_) => return PluginResult::default(),
+    };
+
+    let impl_items = body.items(db);
+    let mut functions_tokens = rust::Tokens::new();
+    functions_tokens.append(impl_items.as_syntax_node().get_text(db).as_str());
+
+    let mut diagnostics = vec![];
+    let mut has_constructor = false;
+    for impl_item in impl_items.elements(db) {
+        let item_function = match impl_item {
+            ast::Item::FreeFunction(f) => f,
+            // Impl should only have free functions.
+            _ => unreachable!(),
         };
+        let function_attrs = item_function.attributes(db);
+        if find_attr(db, &function_attrs, CONSTRUCTOR_ATTR).is_some() {
+            if has_constructor {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: item_function.name(db).stable_ptr().untyped(),
+                    message: "A contract may have at most one constructor.".into(),
+                });
+            }
+            has_constructor = true;
+        }
+        if find_attr(db, &function_attrs, L1_HANDLER_ATTR).is_some() {
+            diagnostics.extend(validate_l1_handler(db, &item_function));
+        }
+        match generate_entry_point_wrapper(db, &item_function) {
+            Ok(generated_function) => functions_tokens.append(generated_function),
+            Err(entry_point_diagnostics) => diagnostics.extend(entry_point_diagnostics),
+        }
+    }
 
-        let impl_items = body.items(db);
-        let mut functions_tokens = rust::Tokens::new();
-        functions_tokens.append(impl_items.as_syntax_node().get_text(db).as_str());
-
-        for impl_item in impl_items.elements(db) {
-            let item_function = match impl_item {
-                ast::Item::FreeFunction(f) => f,
-                // Impl should only have free functions.
-                _ => unreachable!(),
-            };
-            if let Some(generated_function) = generate_entry_point_wrapper(db, &item_function) {
-                functions_tokens.append(generated_function);
+    PluginResult {
+        code: Some(("entry_points".into(), functions_tokens.to_string().unwrap())),
+        diagnostics,
+        ..Default::default()
+    }
+}
+
+/// If the struct is annotated with CONTRACT_ATTR, generates the storage accessors of its members:
+/// `<member>_read` and `<member>_write`, using the `StorageAccess` trait of the corelib. The
+/// storage address of a member is the `starknet_keccak` of its name.
+fn handle_contract_storage(db: &dyn SyntaxGroup, struct_ast: ast::ItemStruct) -> PluginResult {
+    let attrs = struct_ast.attributes(db).elements(db);
+    if !attrs.iter().any(|attr| attr.attr(db).text(db) == CONTRACT_ATTR) {
+        return PluginResult::default();
+    }
+
+    let mut storage_tokens = rust::Tokens::new();
+    for member in struct_ast.members(db).elements(db) {
+        let ty = member.type_clause(db).ty(db).as_syntax_node().get_text_without_trivia(db);
+        let name = member.name(db).text(db);
+        let address = format!("0x{:x}", starknet_keccak(name.as_bytes()));
+        let read_name = format!("{name}_read");
+        let write_name = format!("{name}_write");
+        storage_tokens.append(quote! {
+            func $read_name(ref syscall_ptr: SyscallPtr) -> $(ty.clone()) {
+                starknet::StorageAccess::<$(ty.clone())>::read(
+                    syscall_ptr, starknet::storage_address_const::<$(address.clone())>()
+                )
             }
+            func $write_name(ref syscall_ptr: SyscallPtr, value: $(ty.clone())) {
+                starknet::StorageAccess::<$ty>::write(
+                    syscall_ptr, starknet::storage_address_const::<$address>(), value
+                )
+            }
+        });
+    }
+
+    PluginResult {
+        code: if storage_tokens.is_empty() {
+            None
+        } else {
+            Some(("storage".into(), storage_tokens.to_string().unwrap()))
+        },
+        diagnostics: vec![],
+        ..Default::default()
+    }
+}
+
+/// Returns a diagnostic if the second parameter of the L1 handler, following the system call
+/// pointer, is not `from_address: felt`.
+fn validate_l1_handler(
+    db: &dyn SyntaxGroup,
+    function: &ItemFreeFunction,
+) -> Option<PluginDiagnostic> {
+    let sig = function.signature(db);
+    let params = sig.parameters(db).elements(db);
+    match params.get(1) {
+        Some(param)
+            if param.name(db).identifier(db) == "from_address"
+                && param.type_clause(db).ty(db).as_syntax_node().get_text_without_trivia(db)
+                    == "felt"
+                && !is_ref_param(db, param) =>
+        {
+            None
         }
+        param => Some(PluginDiagnostic {
+            stable_ptr: match param {
+                Some(param) => param.stable_ptr().untyped(),
+                None => sig.stable_ptr().untyped(),
+            },
+            message: "The second parameter of an L1 handler must be `from_address: felt`.".into(),
+        }),
+    }
+}
 
-        PluginResult {
-            code: Some(("entry_points".into(), functions_tokens.to_string().unwrap())),
-            diagnostics: vec![],
+/// If the free function is annotated with EVENT_ATTR, replaces it with a function of the same
+/// signature that emits the event, annotated with GENERATED_EVENT_ATTR as well. The keys of the
+/// event are the `starknet_keccak` of its name, followed by the parameters named in the attribute
+/// arguments, and its data is the rest of the parameters. All are serialized using the `Serde`
+/// trait.
+fn handle_event(db: &dyn SyntaxGroup, function_ast: ast::ItemFreeFunction) -> PluginResult {
+    let attrs = function_ast.attributes(db);
+    let Some(event_attr) = find_attr(db, &attrs, EVENT_ATTR) else {
+        return PluginResult::default();
+    };
+    if find_attr(db, &attrs, GENERATED_EVENT_ATTR).is_some() {
+        return PluginResult::default();
+    }
+
+    let sig = function_ast.signature(db);
+    let mut diagnostics = vec![];
+    let body = function_ast.body(db);
+    if !body.statements(db).elements(db).is_empty() {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: body.stable_ptr().untyped(),
+            message: "An event must have an empty body.".into(),
+        });
+    }
+    if let ast::OptionReturnTypeClause::ReturnTypeClause(ret_ty) = sig.ret_ty(db) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: ret_ty.stable_ptr().untyped(),
+            message: "An event must not have a return type.".into(),
+        });
+    }
+    let params = sig.parameters(db).elements(db);
+    let mut params_iter = params.iter();
+    diagnostics.extend(validate_syscall_ptr_param(db, &sig, params_iter.next()));
+
+    let mut key_names = vec![];
+    if let ast::OptionAttributeArgs::AttributeArgs(args) = event_attr.args(db) {
+        for arg in args.arg_list(db).elements(db) {
+            key_names.push((arg.as_syntax_node().get_text_without_trivia(db), arg.stable_ptr()));
+        }
+    }
+
+    let mut keys_appends = quote! {};
+    let mut data_appends = quote! {};
+    for param in params_iter {
+        if is_ref_param(db, param) {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: param.stable_ptr().untyped(),
+                message: "The parameters of an event must not be `ref`.".into(),
+            });
+        }
+        let param_name = param.name(db).identifier(db).to_string();
+        let param_type = param.type_clause(db).ty(db).as_syntax_node().get_text_without_trivia(db);
+        let append = quote! {serde::Serde::<$param_type>::serialize};
+        if key_names.iter().any(|(key_name, _)| key_name == &param_name) {
+            keys_appends.append(quote! {$append(keys, $param_name);});
+        } else {
+            data_appends.append(quote! {$append(data, $param_name);});
         }
     }
+    for (key_name, stable_ptr) in key_names {
+        if !params.iter().any(|param| param.name(db).identifier(db) == key_name) {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: stable_ptr.untyped(),
+                message: format!("`{key_name}` is not a parameter of the event."),
+            });
+        }
+    }
+    if !diagnostics.is_empty() {
+        return PluginResult { code: None, diagnostics, ..Default::default() };
+    }
+
+    let attr_text = event_attr.as_syntax_node().get_text_without_trivia(db);
+    let generated_attr_text = format!("#[{GENERATED_EVENT_ATTR}]");
+    let name = function_ast.name(db).text(db).to_string();
+    let selector = format!("0x{:x}", starknet_keccak(name.as_bytes()));
+    let params_text = sig.parameters(db).as_syntax_node().get_text_without_trivia(db);
+    let event_tokens: rust::Tokens = quote! {
+        $attr_text
+        $generated_attr_text
+        func $name($params_text) {
+            let mut keys = array::array_new::<felt>();
+            array::array_append::<felt>(keys, $selector);
+            $keys_appends
+            let mut data = array::array_new::<felt>();
+            $data_appends
+            starknet::emit_event(syscall_ptr, keys, data);
+        }
+    };
+    PluginResult {
+        code: Some(("event".into(), event_tokens.to_string().unwrap())),
+        diagnostics,
+        remove_original_item: true,
+    }
+}
+
+/// Returns a diagnostic if the given first parameter of a function is not
+/// `ref syscall_ptr: SyscallPtr`.
+fn validate_syscall_ptr_param(
+    db: &dyn SyntaxGroup,
+    sig: &ast::FunctionSignature,
+    first_param: Option<&Param>,
+) -> Option<PluginDiagnostic> {
+    let stable_ptr = match first_param {
+        Some(first_param) => {
+            let first_param_type =
+                first_param.type_clause(db).ty(db).as_syntax_node().get_text_without_trivia(db);
+            if first_param_type == "SyscallPtr" && is_ref_param(db, first_param) {
+                return None;
+            }
+            first_param.stable_ptr().untyped()
+        }
+        None => sig.stable_ptr().untyped(),
+    };
+    Some(PluginDiagnostic {
+        stable_ptr,
+        message: "The first parameter of a contract function must be `ref syscall_ptr: \
+                  SyscallPtr`."
+            .into(),
+    })
 }
 
 /// Generates Cairo code for an entry point wrapper (expanded from attribute CONTRACT_IMPL_ATTR).
+/// The wrapper deserializes the calldata into the parameters of the function, using the `Serde`
+/// trait of the corelib, calls the function, and serializes the final values of its `ref`
+/// parameters, followed by its return value.
 fn generate_entry_point_wrapper(
     db: &dyn SyntaxGroup,
     function: &ItemFreeFunction,
-) -> Option<rust::Tokens> {
-    let mut successful_expansion = true;
-
+) -> Result<rust::Tokens, Vec<PluginDiagnostic>> {
     let sig = function.signature(db);
     let params = sig.parameters(db).elements(db);
-    // TODO(yuval): support types of size >1. `params_len` should be sum of the params lengths.
-    let params_len = params.len();
     let mut params_iter = params.into_iter();
 
-    // Assert the first parameter is SyscallPtr.
-    // TODO(yuval): get_text includes trivia... This wouldn't always work.
-    match params_iter.next() {
-        Some(first_param) => {
-            let first_param_type = first_param.type_clause(db).ty(db).as_syntax_node().get_text(db);
-            if first_param_type != "SyscallPtr" {
-                // TODO(yuval): diagnostic
-                successful_expansion = false;
-            }
-            if !is_ref_param(db, &first_param) {
-                // TODO(yuval): diagnostic
-                successful_expansion = false;
-            }
-        }
-        None => {
-            // TODO(yuval): diagnostic
-            successful_expansion = false;
-        }
-    }
-
-    if !successful_expansion {
-        return None;
+    // Assert the first parameter is `ref syscall_ptr: SyscallPtr`.
+    if let Some(diagnostic) = validate_syscall_ptr_param(db, &sig, params_iter.next().as_ref()) {
+        return Err(vec![diagnostic]);
     }
 
-    let mut arg_names = Vec::new();
+    let mut arg_names = vec!["syscall_ptr".to_string()];
     let mut arg_definitions = quote! {};
     let mut ref_appends = quote! {};
     for (idx, param) in params_iter.enumerate() {
         let arg_name = format!("__arg_{}", param.name(db).identifier(db));
+        let arg_type = param.type_clause(db).ty(db).as_syntax_node().get_text_without_trivia(db);
         let is_ref = is_ref_param(db, &param);
 
         arg_names.push(arg_name.clone());
         let mut_modifier = if is_ref { "mut " } else { "" };
-        // TODO(yuval): use panicable version of `array_at` once panic_with supports generic
+        // The error is followed by the index of the param, as a felt short string is limited to
+        // 31 characters.
+        let err_msg = "'Failed to deserialize param'";
+        let param_index = (idx + 1).to_string();
+        // TODO(yuval): use panicable version of `deserialize` once panic_with supports generic
         // params.
-        arg_definitions.append(quote! {let $mut_modifier$(arg_name.clone()): felt = match array::array_at::<felt>(data, $idx) {
-                    Some(x) => x,
-                    None(()) => panic(array::array_new::<felt>()),
-                };});
+        arg_definitions.append(quote! {
+            let $mut_modifier$(arg_name.clone()) =
+                match serde::Serde::<$(arg_type.clone())>::deserialize(data, offset) {
+                    Option::Some(x) => x,
+                    Option::None(()) => {
+                        let mut err_data = array::array_new::<felt>();
+                        array::array_append::<felt>(err_data, $err_msg);
+                        array::array_append::<felt>(err_data, $param_index);
+                        panic(err_data)
+                    },
+                };
+        });
 
         if is_ref {
-            // TODO(yuval): support types != felt.
-            ref_appends.append(quote! {array::array_append::<felt>(arr, $arg_name);});
+            ref_appends.append(quote! {serde::Serde::<$arg_type>::serialize(arr, $arg_name);});
         }
     }
     let param_names_tokens = join(arg_names.into_iter(), ", ");
@@ -121,18 +342,34 @@ fn generate_entry_point_wrapper(
     let wrapped_name = function.name(db).text(db).to_string();
     let wrapper_name = format!("{}{}", WRAPPER_PREFIX, wrapped_name);
 
+    let (call, res_append) = match sig.ret_ty(db) {
+        ast::OptionReturnTypeClause::Empty(_) => {
+            (quote! {$wrapped_name($param_names_tokens);}, quote! {})
+        }
+        ast::OptionReturnTypeClause::ReturnTypeClause(ret_ty) => {
+            let ret_type = ret_ty.ty(db).as_syntax_node().get_text_without_trivia(db);
+            (
+                quote! {let res = $wrapped_name($param_names_tokens);},
+                quote! {serde::Serde::<$ret_type>::serialize(arr, res);},
+            )
+        }
+    };
+
+    let input_too_long_msg = "'Input too long for params.'";
     // TODO(yuval): change to uint128 literal once it's supported.
-    Some(quote! {
+    Ok(quote! {
         func $wrapper_name(ref syscall_ptr: SyscallPtr, mut data: Array::<felt>) -> Array::<felt> {
-            if array::array_len::<felt>(data) != integer::uint128_from_felt($params_len) {
-                // TODO(yuval): add error message.
-                panic(array::array_new::<felt>());
-            }
+            let mut offset = integer::uint128_from_felt(0);
             $arg_definitions
-            let res = $wrapped_name(syscall_ptr, $param_names_tokens);
+            if array::array_len::<felt>(data) != offset {
+                let mut err_data = array::array_new::<felt>();
+                array::array_append::<felt>(err_data, $input_too_long_msg);
+                panic(err_data);
+            }
+            $call
             let mut arr = array::array_new::<felt>();
             $ref_appends
-            array::array_append::<felt>(arr, res);
+            $res_append
             arr
         }
     })
@@ -2,6 +2,7 @@ use defs::db::{MacroPlugin, PluginResult};
 use parser::parser_test;
 use parser::test_utils::create_virtual_file;
 use parser::utils::{get_syntax_file_and_diagnostics, SimpleParserDatabase};
+use syntax::node::TypedSyntaxNode;
 use utils::ordered_hash_map::OrderedHashMap;
 
 use crate::plugin::StarkNetPlugin;
@@ -17,9 +18,17 @@ pub fn test_expand_contract(
 
     let plugin = StarkNetPlugin {};
     let mut generated_items: Vec<String> = Vec::new();
+    let mut diagnostic_items: Vec<String> = Vec::new();
     for item in syntax_file.items(db).elements(db).into_iter() {
-        let PluginResult { code, diagnostics } = plugin.generate_code(db, item);
-        assert_eq!(diagnostics.len(), 0);
+        let PluginResult { code, diagnostics, .. } = plugin.generate_code(db, item);
+        for diagnostic in diagnostics {
+            let node = syntax_file.as_syntax_node().lookup_ptr(db, diagnostic.stable_ptr);
+            diagnostic_items.push(format!(
+                "{}: {}\n",
+                node.get_text(db).trim(),
+                diagnostic.message
+            ));
+        }
         let content = match code {
             Some((_path, content)) => content,
             None => continue,
@@ -27,7 +36,10 @@ pub fn test_expand_contract(
         generated_items.push(formatter::format_string(db, content));
     }
 
-    OrderedHashMap::from([("generated_cairo_code".into(), generated_items.join("\n"))])
+    OrderedHashMap::from([
+        ("generated_cairo_code".into(), generated_items.join("\n")),
+        ("expected_diagnostics".into(), diagnostic_items.join("")),
+    ])
 }
 
 parser_test!(test1, ["src/plugin_test_data/starknet",], test_expand_contract);
@@ -1,10 +1,37 @@
-trait ITestContract { func test(ref syscall_ptr: SyscallPtr) -> felt; }
+#[event(from_address)]
+func L1Deposit(ref syscall_ptr: SyscallPtr, from_address: felt, amount: felt) {}
+
+trait ITestContract {
+    func constructor(ref syscall_ptr: SyscallPtr, initial_balance: felt);
+    func test(ref syscall_ptr: SyscallPtr) -> felt;
+    func deposit(ref syscall_ptr: SyscallPtr, ref receipts: Array::<felt>, amount: uint256) -> felt;
+    func deposit_from_l1(ref syscall_ptr: SyscallPtr, from_address: felt, amount: felt);
+}
 
 #[ContractImpl]
-impl TestContractImpl of ITestContract { func test(ref syscall_ptr: SyscallPtr) -> felt {
-    1
+impl TestContractImpl of ITestContract { #[constructor]
+func constructor(ref syscall_ptr: SyscallPtr, initial_balance: felt) {
+    balance_write(syscall_ptr, initial_balance);
+}
+func test(ref syscall_ptr: SyscallPtr) -> felt {
+    let balance = balance_read(syscall_ptr) + 1;
+    balance_write(syscall_ptr, balance);
+    balance
+}
+func deposit(ref syscall_ptr: SyscallPtr, ref receipts: Array::<felt>, amount: uint256) -> felt {
+    total_write(syscall_ptr, total_read(syscall_ptr) + amount);
+    let amount = uint128_to_felt(amount.low);
+    let balance = balance_read(syscall_ptr) + amount;
+    balance_write(syscall_ptr, balance);
+    receipts.append(amount);
+    balance
+}
+#[l1_handler]
+func deposit_from_l1(ref syscall_ptr: SyscallPtr, from_address: felt, amount: felt) {
+    balance_write(syscall_ptr, balance_read(syscall_ptr) + amount);
+    L1Deposit(syscall_ptr, from_address, amount);
 }
 }
 
 #[contract(TestContractImpl)]
-struct TestContract { }
+struct TestContract { balance: felt, total: uint256 }
@@ -163,6 +163,8 @@ impl TypedSyntaxNode for Trivium {
 pub enum Expr {
     Path(ExprPath),
     Literal(TerminalLiteralNumber),
+    ShortString(TerminalShortString),
+    String(TerminalString),
     False(TerminalFalse),
     True(TerminalTrue),
     Parenthesized(ExprParenthesized),
@@ -174,6 +176,8 @@ pub enum Expr {
     Block(ExprBlock),
     Match(ExprMatch),
     If(ExprIf),
+    Loop(ExprLoop),
+    While(ExprWhile),
     ErrorPropagate(ExprErrorPropagate),
     Missing(ExprMissing),
 }
@@ -194,6 +198,16 @@ impl From<TerminalLiteralNumberPtr> for ExprPtr {
         Self(value.0)
     }
 }
+impl From<TerminalShortStringPtr> for ExprPtr {
+    fn from(value: TerminalShortStringPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalStringPtr> for ExprPtr {
+    fn from(value: TerminalStringPtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<TerminalFalsePtr> for ExprPtr {
     fn from(value: TerminalFalsePtr) -> Self {
         Self(value.0)
@@ -249,6 +263,16 @@ impl From<ExprIfPtr> for ExprPtr {
         Self(value.0)
     }
 }
+impl From<ExprLoopPtr> for ExprPtr {
+    fn from(value: ExprLoopPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ExprWhilePtr> for ExprPtr {
+    fn from(value: ExprWhilePtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ExprErrorPropagatePtr> for ExprPtr {
     fn from(value: ExprErrorPropagatePtr) -> Self {
         Self(value.0)
@@ -269,6 +293,16 @@ impl From<TerminalLiteralNumberGreen> for ExprGreen {
         Self(value.0)
     }
 }
+impl From<TerminalShortStringGreen> for ExprGreen {
+    fn from(value: TerminalShortStringGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalStringGreen> for ExprGreen {
+    fn from(value: TerminalStringGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<TerminalFalseGreen> for ExprGreen {
     fn from(value: TerminalFalseGreen) -> Self {
         Self(value.0)
@@ -324,6 +358,16 @@ impl From<ExprIfGreen> for ExprGreen {
         Self(value.0)
     }
 }
+impl From<ExprLoopGreen> for ExprGreen {
+    fn from(value: ExprLoopGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ExprWhileGreen> for ExprGreen {
+    fn from(value: ExprWhileGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ExprErrorPropagateGreen> for ExprGreen {
     fn from(value: ExprErrorPropagateGreen) -> Self {
         Self(value.0)
@@ -350,6 +394,10 @@ impl TypedSyntaxNode for Expr {
             SyntaxKind::TerminalLiteralNumber => {
                 Expr::Literal(TerminalLiteralNumber::from_syntax_node(db, node))
             }
+            SyntaxKind::TerminalShortString => {
+                Expr::ShortString(TerminalShortString::from_syntax_node(db, node))
+            }
+            SyntaxKind::TerminalString => Expr::String(TerminalString::from_syntax_node(db, node)),
             SyntaxKind::TerminalFalse => Expr::False(TerminalFalse::from_syntax_node(db, node)),
             SyntaxKind::TerminalTrue => Expr::True(TerminalTrue::from_syntax_node(db, node)),
             SyntaxKind::ExprParenthesized => {
@@ -367,6 +415,8 @@ impl TypedSyntaxNode for Expr {
             SyntaxKind::ExprBlock => Expr::Block(ExprBlock::from_syntax_node(db, node)),
             SyntaxKind::ExprMatch => Expr::Match(ExprMatch::from_syntax_node(db, node)),
             SyntaxKind::ExprIf => Expr::If(ExprIf::from_syntax_node(db, node)),
+            SyntaxKind::ExprLoop => Expr::Loop(ExprLoop::from_syntax_node(db, node)),
+            SyntaxKind::ExprWhile => Expr::While(ExprWhile::from_syntax_node(db, node)),
             SyntaxKind::ExprErrorPropagate => {
                 Expr::ErrorPropagate(ExprErrorPropagate::from_syntax_node(db, node))
             }
@@ -378,6 +428,8 @@ impl TypedSyntaxNode for Expr {
         match self {
             Expr::Path(x) => x.as_syntax_node(),
             Expr::Literal(x) => x.as_syntax_node(),
+            Expr::ShortString(x) => x.as_syntax_node(),
+            Expr::String(x) => x.as_syntax_node(),
             Expr::False(x) => x.as_syntax_node(),
             Expr::True(x) => x.as_syntax_node(),
             Expr::Parenthesized(x) => x.as_syntax_node(),
@@ -389,6 +441,8 @@ impl TypedSyntaxNode for Expr {
             Expr::Block(x) => x.as_syntax_node(),
             Expr::Match(x) => x.as_syntax_node(),
             Expr::If(x) => x.as_syntax_node(),
+            Expr::Loop(x) => x.as_syntax_node(),
+            Expr::While(x) => x.as_syntax_node(),
             Expr::ErrorPropagate(x) => x.as_syntax_node(),
             Expr::Missing(x) => x.as_syntax_node(),
         }
@@ -2339,6 +2393,156 @@ impl TypedSyntaxNode for OptionElseClauseEmpty {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ExprLoop {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ExprLoop {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        loop_kw: TerminalLoopGreen,
+        body: ExprBlockGreen,
+    ) -> ExprLoopGreen {
+        let children: Vec<GreenId> = vec![loop_kw.0, body.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ExprLoopGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprLoop,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ExprLoop {
+    pub fn loop_kw(&self, db: &dyn SyntaxGroup) -> TerminalLoop {
+        TerminalLoop::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn body(&self, db: &dyn SyntaxGroup) -> ExprBlock {
+        ExprBlock::from_syntax_node(db, self.children[1].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprLoopPtr(pub SyntaxStablePtrId);
+impl ExprLoopPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprLoopGreen(pub GreenId);
+impl TypedSyntaxNode for ExprLoop {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ExprLoop);
+    type StablePtr = ExprLoopPtr;
+    type Green = ExprLoopGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ExprLoopGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprLoop,
+            details: GreenNodeDetails::Node {
+                children: vec![TerminalLoop::missing(db).0, ExprBlock::missing(db).0],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ExprLoop,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ExprLoop
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ExprLoopPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ExprWhile {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ExprWhile {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        while_kw: TerminalWhileGreen,
+        condition: ExprGreen,
+        body: ExprBlockGreen,
+    ) -> ExprWhileGreen {
+        let children: Vec<GreenId> = vec![while_kw.0, condition.0, body.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ExprWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprWhile,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ExprWhile {
+    pub fn while_kw(&self, db: &dyn SyntaxGroup) -> TerminalWhile {
+        TerminalWhile::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn condition(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn body(&self, db: &dyn SyntaxGroup) -> ExprBlock {
+        ExprBlock::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprWhilePtr(pub SyntaxStablePtrId);
+impl ExprWhilePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprWhileGreen(pub GreenId);
+impl TypedSyntaxNode for ExprWhile {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ExprWhile);
+    type StablePtr = ExprWhilePtr;
+    type Green = ExprWhileGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ExprWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprWhile,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalWhile::missing(db).0,
+                    Expr::missing(db).0,
+                    ExprBlock::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ExprWhile,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ExprWhile
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ExprWhilePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct ExprErrorPropagate {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
@@ -4159,6 +4363,8 @@ pub enum Statement {
     Let(StatementLet),
     Expr(StatementExpr),
     Return(StatementReturn),
+    Break(StatementBreak),
+    Continue(StatementContinue),
     Missing(StatementMissing),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -4183,6 +4389,16 @@ impl From<StatementReturnPtr> for StatementPtr {
         Self(value.0)
     }
 }
+impl From<StatementBreakPtr> for StatementPtr {
+    fn from(value: StatementBreakPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<StatementContinuePtr> for StatementPtr {
+    fn from(value: StatementContinuePtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<StatementMissingPtr> for StatementPtr {
     fn from(value: StatementMissingPtr) -> Self {
         Self(value.0)
@@ -4203,6 +4419,16 @@ impl From<StatementReturnGreen> for StatementGreen {
         Self(value.0)
     }
 }
+impl From<StatementBreakGreen> for StatementGreen {
+    fn from(value: StatementBreakGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<StatementContinueGreen> for StatementGreen {
+    fn from(value: StatementContinueGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<StatementMissingGreen> for StatementGreen {
     fn from(value: StatementMissingGreen) -> Self {
         Self(value.0)
@@ -4225,6 +4451,12 @@ impl TypedSyntaxNode for Statement {
             SyntaxKind::StatementReturn => {
                 Statement::Return(StatementReturn::from_syntax_node(db, node))
             }
+            SyntaxKind::StatementBreak => {
+                Statement::Break(StatementBreak::from_syntax_node(db, node))
+            }
+            SyntaxKind::StatementContinue => {
+                Statement::Continue(StatementContinue::from_syntax_node(db, node))
+            }
             SyntaxKind::StatementMissing => {
                 Statement::Missing(StatementMissing::from_syntax_node(db, node))
             }
@@ -4236,6 +4468,8 @@ impl TypedSyntaxNode for Statement {
             Statement::Let(x) => x.as_syntax_node(),
             Statement::Expr(x) => x.as_syntax_node(),
             Statement::Return(x) => x.as_syntax_node(),
+            Statement::Break(x) => x.as_syntax_node(),
+            Statement::Continue(x) => x.as_syntax_node(),
             Statement::Missing(x) => x.as_syntax_node(),
         }
     }
@@ -4735,131 +4969,468 @@ impl TypedSyntaxNode for StatementReturn {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum ParamName {
-    Underscore(TerminalUnderscore),
-    Name(TerminalIdentifier),
+pub struct ExprClause {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ExprClause {
+    pub fn new_green(db: &dyn SyntaxGroup, expr: ExprGreen) -> ExprClauseGreen {
+        let children: Vec<GreenId> = vec![expr.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ExprClauseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprClause,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ExprClause {
+    pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[0].clone())
+    }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ParamNamePtr(pub SyntaxStablePtrId);
-impl ParamNamePtr {
+pub struct ExprClausePtr(pub SyntaxStablePtrId);
+impl ExprClausePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-impl From<TerminalUnderscorePtr> for ParamNamePtr {
-    fn from(value: TerminalUnderscorePtr) -> Self {
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprClauseGreen(pub GreenId);
+impl TypedSyntaxNode for ExprClause {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ExprClause);
+    type StablePtr = ExprClausePtr;
+    type Green = ExprClauseGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ExprClauseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprClause,
+            details: GreenNodeDetails::Node { children: vec![Expr::missing(db).0], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ExprClause,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ExprClause
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ExprClausePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum OptionExprClause {
+    Empty(OptionExprClauseEmpty),
+    ExprClause(ExprClause),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionExprClausePtr(pub SyntaxStablePtrId);
+impl OptionExprClausePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<OptionExprClauseEmptyPtr> for OptionExprClausePtr {
+    fn from(value: OptionExprClauseEmptyPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalIdentifierPtr> for ParamNamePtr {
-    fn from(value: TerminalIdentifierPtr) -> Self {
+impl From<ExprClausePtr> for OptionExprClausePtr {
+    fn from(value: ExprClausePtr) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalUnderscoreGreen> for ParamNameGreen {
-    fn from(value: TerminalUnderscoreGreen) -> Self {
+impl From<OptionExprClauseEmptyGreen> for OptionExprClauseGreen {
+    fn from(value: OptionExprClauseEmptyGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalIdentifierGreen> for ParamNameGreen {
-    fn from(value: TerminalIdentifierGreen) -> Self {
+impl From<ExprClauseGreen> for OptionExprClauseGreen {
+    fn from(value: ExprClauseGreen) -> Self {
         Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ParamNameGreen(pub GreenId);
-impl TypedSyntaxNode for ParamName {
+pub struct OptionExprClauseGreen(pub GreenId);
+impl TypedSyntaxNode for OptionExprClause {
     const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = ParamNamePtr;
-    type Green = ParamNameGreen;
+    type StablePtr = OptionExprClausePtr;
+    type Green = OptionExprClauseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
         panic!("No missing variant.");
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         match kind {
-            SyntaxKind::TerminalUnderscore => {
-                ParamName::Underscore(TerminalUnderscore::from_syntax_node(db, node))
+            SyntaxKind::OptionExprClauseEmpty => {
+                OptionExprClause::Empty(OptionExprClauseEmpty::from_syntax_node(db, node))
             }
-            SyntaxKind::TerminalIdentifier => {
-                ParamName::Name(TerminalIdentifier::from_syntax_node(db, node))
+            SyntaxKind::ExprClause => {
+                OptionExprClause::ExprClause(ExprClause::from_syntax_node(db, node))
             }
-            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "ParamName"),
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "OptionExprClause"
+            ),
         }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         match self {
-            ParamName::Underscore(x) => x.as_syntax_node(),
-            ParamName::Name(x) => x.as_syntax_node(),
+            OptionExprClause::Empty(x) => x.as_syntax_node(),
+            OptionExprClause::ExprClause(x) => x.as_syntax_node(),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ParamNamePtr(self.as_syntax_node().0.stable_ptr)
+        OptionExprClausePtr(self.as_syntax_node().0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct Param {
+pub struct OptionExprClauseEmpty {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Param {
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        modifiers: ModifierListGreen,
-        name: ParamNameGreen,
-        type_clause: TypeClauseGreen,
-    ) -> ParamGreen {
-        let children: Vec<GreenId> = vec![modifiers.0, name.0, type_clause.0];
+impl OptionExprClauseEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionExprClauseEmptyGreen {
+        let children: Vec<GreenId> = vec![];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ParamGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::Param,
+        OptionExprClauseEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionExprClauseEmpty,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl Param {
-    pub fn modifiers(&self, db: &dyn SyntaxGroup) -> ModifierList {
-        ModifierList::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> ParamName {
-        ParamName::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> TypeClause {
-        TypeClause::from_syntax_node(db, self.children[2].clone())
-    }
-}
+impl OptionExprClauseEmpty {}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ParamPtr(pub SyntaxStablePtrId);
-impl ParamPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> ParamNameGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            ParamNameGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct OptionExprClauseEmptyPtr(pub SyntaxStablePtrId);
+impl OptionExprClauseEmptyPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ParamGreen(pub GreenId);
-impl TypedSyntaxNode for Param {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::Param);
-    type StablePtr = ParamPtr;
-    type Green = ParamGreen;
+pub struct OptionExprClauseEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionExprClauseEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionExprClauseEmpty);
+    type StablePtr = OptionExprClauseEmptyPtr;
+    type Green = OptionExprClauseEmptyGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ParamGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::Param,
-            details: GreenNodeDetails::Node {
-                children: vec![
-                    ModifierList::missing(db).0,
-                    ParamName::missing(db).0,
-                    TypeClause::missing(db).0,
+        OptionExprClauseEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionExprClauseEmpty,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::OptionExprClauseEmpty,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::OptionExprClauseEmpty
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionExprClauseEmptyPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StatementBreak {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl StatementBreak {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        break_kw: TerminalBreakGreen,
+        expr_clause: OptionExprClauseGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> StatementBreakGreen {
+        let children: Vec<GreenId> = vec![break_kw.0, expr_clause.0, semicolon.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        StatementBreakGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementBreak,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl StatementBreak {
+    pub fn break_kw(&self, db: &dyn SyntaxGroup) -> TerminalBreak {
+        TerminalBreak::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn expr_clause(&self, db: &dyn SyntaxGroup) -> OptionExprClause {
+        OptionExprClause::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StatementBreakPtr(pub SyntaxStablePtrId);
+impl StatementBreakPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StatementBreakGreen(pub GreenId);
+impl TypedSyntaxNode for StatementBreak {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StatementBreak);
+    type StablePtr = StatementBreakPtr;
+    type Green = StatementBreakGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        StatementBreakGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementBreak,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalBreak::missing(db).0,
+                    OptionExprClause::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::StatementBreak,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::StatementBreak
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        StatementBreakPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StatementContinue {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl StatementContinue {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        continue_kw: TerminalContinueGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> StatementContinueGreen {
+        let children: Vec<GreenId> = vec![continue_kw.0, semicolon.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        StatementContinueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementContinue,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl StatementContinue {
+    pub fn continue_kw(&self, db: &dyn SyntaxGroup) -> TerminalContinue {
+        TerminalContinue::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[1].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StatementContinuePtr(pub SyntaxStablePtrId);
+impl StatementContinuePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StatementContinueGreen(pub GreenId);
+impl TypedSyntaxNode for StatementContinue {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StatementContinue);
+    type StablePtr = StatementContinuePtr;
+    type Green = StatementContinueGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        StatementContinueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementContinue,
+            details: GreenNodeDetails::Node {
+                children: vec![TerminalContinue::missing(db).0, TerminalSemicolon::missing(db).0],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::StatementContinue,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::StatementContinue
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        StatementContinuePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ParamName {
+    Underscore(TerminalUnderscore),
+    Name(TerminalIdentifier),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ParamNamePtr(pub SyntaxStablePtrId);
+impl ParamNamePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<TerminalUnderscorePtr> for ParamNamePtr {
+    fn from(value: TerminalUnderscorePtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalIdentifierPtr> for ParamNamePtr {
+    fn from(value: TerminalIdentifierPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalUnderscoreGreen> for ParamNameGreen {
+    fn from(value: TerminalUnderscoreGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalIdentifierGreen> for ParamNameGreen {
+    fn from(value: TerminalIdentifierGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ParamNameGreen(pub GreenId);
+impl TypedSyntaxNode for ParamName {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = ParamNamePtr;
+    type Green = ParamNameGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::TerminalUnderscore => {
+                ParamName::Underscore(TerminalUnderscore::from_syntax_node(db, node))
+            }
+            SyntaxKind::TerminalIdentifier => {
+                ParamName::Name(TerminalIdentifier::from_syntax_node(db, node))
+            }
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "ParamName"),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            ParamName::Underscore(x) => x.as_syntax_node(),
+            ParamName::Name(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ParamNamePtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Param {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl Param {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        modifiers: ModifierListGreen,
+        name: ParamNameGreen,
+        type_clause: TypeClauseGreen,
+    ) -> ParamGreen {
+        let children: Vec<GreenId> = vec![modifiers.0, name.0, type_clause.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ParamGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::Param,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl Param {
+    pub fn modifiers(&self, db: &dyn SyntaxGroup) -> ModifierList {
+        ModifierList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> ParamName {
+        ParamName::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> TypeClause {
+        TypeClause::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ParamPtr(pub SyntaxStablePtrId);
+impl ParamPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> ParamNameGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            ParamNameGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ParamGreen(pub GreenId);
+impl TypedSyntaxNode for Param {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::Param);
+    type StablePtr = ParamPtr;
+    type Green = ParamGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ParamGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::Param,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    ModifierList::missing(db).0,
+                    ParamName::missing(db).0,
+                    TypeClause::missing(db).0,
                 ],
                 width: 0,
             },
@@ -5755,6 +6326,7 @@ impl TypedSyntaxNode for MemberList {
 pub enum Item {
     Module(ItemModule),
     Use(ItemUse),
+    Constant(ItemConstant),
     FreeFunction(ItemFreeFunction),
     ExternFunction(ItemExternFunction),
     ExternType(ItemExternType),
@@ -5780,6 +6352,11 @@ impl From<ItemUsePtr> for ItemPtr {
         Self(value.0)
     }
 }
+impl From<ItemConstantPtr> for ItemPtr {
+    fn from(value: ItemConstantPtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ItemFreeFunctionPtr> for ItemPtr {
     fn from(value: ItemFreeFunctionPtr) -> Self {
         Self(value.0)
@@ -5825,6 +6402,11 @@ impl From<ItemUseGreen> for ItemGreen {
         Self(value.0)
     }
 }
+impl From<ItemConstantGreen> for ItemGreen {
+    fn from(value: ItemConstantGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ItemFreeFunctionGreen> for ItemGreen {
     fn from(value: ItemFreeFunctionGreen) -> Self {
         Self(value.0)
@@ -5874,6 +6456,7 @@ impl TypedSyntaxNode for Item {
         match kind {
             SyntaxKind::ItemModule => Item::Module(ItemModule::from_syntax_node(db, node)),
             SyntaxKind::ItemUse => Item::Use(ItemUse::from_syntax_node(db, node)),
+            SyntaxKind::ItemConstant => Item::Constant(ItemConstant::from_syntax_node(db, node)),
             SyntaxKind::ItemFreeFunction => {
                 Item::FreeFunction(ItemFreeFunction::from_syntax_node(db, node))
             }
@@ -5894,6 +6477,7 @@ impl TypedSyntaxNode for Item {
         match self {
             Item::Module(x) => x.as_syntax_node(),
             Item::Use(x) => x.as_syntax_node(),
+            Item::Constant(x) => x.as_syntax_node(),
             Item::FreeFunction(x) => x.as_syntax_node(),
             Item::ExternFunction(x) => x.as_syntax_node(),
             Item::ExternType(x) => x.as_syntax_node(),
@@ -6401,9 +6985,9 @@ impl TypedSyntaxNode for AttributeArgs {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct AttributeArgList(ElementList<Expr, 2>);
+pub struct AttributeArgList(ElementList<AttributeArg, 2>);
 impl Deref for AttributeArgList {
-    type Target = ElementList<Expr, 2>;
+    type Target = ElementList<AttributeArg, 2>;
     fn deref(&self) -> &Self::Target {
         &self.0
     }
@@ -6433,15 +7017,15 @@ impl AttributeArgListPtr {
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub enum AttributeArgListElementOrSeparatorGreen {
     Separator(TerminalCommaGreen),
-    Element(ExprGreen),
+    Element(AttributeArgGreen),
 }
 impl From<TerminalCommaGreen> for AttributeArgListElementOrSeparatorGreen {
     fn from(value: TerminalCommaGreen) -> Self {
         AttributeArgListElementOrSeparatorGreen::Separator(value)
     }
 }
-impl From<ExprGreen> for AttributeArgListElementOrSeparatorGreen {
-    fn from(value: ExprGreen) -> Self {
+impl From<AttributeArgGreen> for AttributeArgListElementOrSeparatorGreen {
+    fn from(value: AttributeArgGreen) -> Self {
         AttributeArgListElementOrSeparatorGreen::Element(value)
     }
 }
@@ -6479,94 +7063,188 @@ impl TypedSyntaxNode for AttributeArgList {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemFreeFunction {
+pub struct AttributeArg {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemFreeFunction {
+impl AttributeArg {
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        function_kw: TerminalFunctionGreen,
-        name: TerminalIdentifierGreen,
-        generic_params: OptionWrappedGenericParamListGreen,
-        signature: FunctionSignatureGreen,
-        body: ExprBlockGreen,
-    ) -> ItemFreeFunctionGreen {
-        let children: Vec<GreenId> =
-            vec![attributes.0, function_kw.0, name.0, generic_params.0, signature.0, body.0];
+        name: OptionAttributeArgNameGreen,
+        value: ExprGreen,
+    ) -> AttributeArgGreen {
+        let children: Vec<GreenId> = vec![name.0, value.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemFreeFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemFreeFunction,
+        AttributeArgGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::AttributeArg,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ItemFreeFunction {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
+impl AttributeArg {
+    pub fn name(&self, db: &dyn SyntaxGroup) -> OptionAttributeArgName {
+        OptionAttributeArgName::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn function_kw(&self, db: &dyn SyntaxGroup) -> TerminalFunction {
-        TerminalFunction::from_syntax_node(db, self.children[1].clone())
+    pub fn value(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct AttributeArgPtr(pub SyntaxStablePtrId);
+impl AttributeArgPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
-    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
-        OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct AttributeArgGreen(pub GreenId);
+impl TypedSyntaxNode for AttributeArg {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::AttributeArg);
+    type StablePtr = AttributeArgPtr;
+    type Green = AttributeArgGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        AttributeArgGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::AttributeArg,
+            details: GreenNodeDetails::Node {
+                children: vec![OptionAttributeArgName::missing(db).0, Expr::missing(db).0],
+                width: 0,
+            },
+        }))
     }
-    pub fn signature(&self, db: &dyn SyntaxGroup) -> FunctionSignature {
-        FunctionSignature::from_syntax_node(db, self.children[4].clone())
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::AttributeArg,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::AttributeArg
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
     }
-    pub fn body(&self, db: &dyn SyntaxGroup) -> ExprBlock {
-        ExprBlock::from_syntax_node(db, self.children[5].clone())
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
     }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        AttributeArgPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum OptionAttributeArgName {
+    Empty(OptionAttributeArgNameEmpty),
+    AttributeArgName(AttributeArgName),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemFreeFunctionPtr(pub SyntaxStablePtrId);
-impl ItemFreeFunctionPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
+pub struct OptionAttributeArgNamePtr(pub SyntaxStablePtrId);
+impl OptionAttributeArgNamePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<OptionAttributeArgNameEmptyPtr> for OptionAttributeArgNamePtr {
+    fn from(value: OptionAttributeArgNameEmptyPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<AttributeArgNamePtr> for OptionAttributeArgNamePtr {
+    fn from(value: AttributeArgNamePtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<OptionAttributeArgNameEmptyGreen> for OptionAttributeArgNameGreen {
+    fn from(value: OptionAttributeArgNameEmptyGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<AttributeArgNameGreen> for OptionAttributeArgNameGreen {
+    fn from(value: AttributeArgNameGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionAttributeArgNameGreen(pub GreenId);
+impl TypedSyntaxNode for OptionAttributeArgName {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = OptionAttributeArgNamePtr;
+    type Green = OptionAttributeArgNameGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::OptionAttributeArgNameEmpty => OptionAttributeArgName::Empty(
+                OptionAttributeArgNameEmpty::from_syntax_node(db, node),
+            ),
+            SyntaxKind::AttributeArgName => OptionAttributeArgName::AttributeArgName(
+                AttributeArgName::from_syntax_node(db, node),
+            ),
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "OptionAttributeArgName"
+            ),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            OptionAttributeArgName::Empty(x) => x.as_syntax_node(),
+            OptionAttributeArgName::AttributeArgName(x) => x.as_syntax_node(),
         }
     }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionAttributeArgNamePtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OptionAttributeArgNameEmpty {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl OptionAttributeArgNameEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionAttributeArgNameEmptyGreen {
+        let children: Vec<GreenId> = vec![];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        OptionAttributeArgNameEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionAttributeArgNameEmpty,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl OptionAttributeArgNameEmpty {}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionAttributeArgNameEmptyPtr(pub SyntaxStablePtrId);
+impl OptionAttributeArgNameEmptyPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemFreeFunctionGreen(pub GreenId);
-impl TypedSyntaxNode for ItemFreeFunction {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemFreeFunction);
-    type StablePtr = ItemFreeFunctionPtr;
-    type Green = ItemFreeFunctionGreen;
+pub struct OptionAttributeArgNameEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionAttributeArgNameEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionAttributeArgNameEmpty);
+    type StablePtr = OptionAttributeArgNameEmptyPtr;
+    type Green = OptionAttributeArgNameEmptyGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemFreeFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemFreeFunction,
-            details: GreenNodeDetails::Node {
-                children: vec![
-                    AttributeList::missing(db).0,
-                    TerminalFunction::missing(db).0,
-                    TerminalIdentifier::missing(db).0,
-                    OptionWrappedGenericParamList::missing(db).0,
-                    FunctionSignature::missing(db).0,
-                    ExprBlock::missing(db).0,
-                ],
-                width: 0,
-            },
+        OptionAttributeArgNameEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionAttributeArgNameEmpty,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemFreeFunction,
+            SyntaxKind::OptionAttributeArgNameEmpty,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemFreeFunction
+            SyntaxKind::OptionAttributeArgNameEmpty
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -6578,98 +7256,54 @@ impl TypedSyntaxNode for ItemFreeFunction {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemFreeFunctionPtr(self.node.0.stable_ptr)
+        OptionAttributeArgNameEmptyPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemExternFunction {
+pub struct AttributeArgName {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemExternFunction {
+impl AttributeArgName {
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        extern_kw: TerminalExternGreen,
-        function_kw: TerminalFunctionGreen,
         name: TerminalIdentifierGreen,
-        generic_params: OptionWrappedGenericParamListGreen,
-        signature: FunctionSignatureGreen,
-        semicolon: TerminalSemicolonGreen,
-    ) -> ItemExternFunctionGreen {
-        let children: Vec<GreenId> = vec![
-            attributes.0,
-            extern_kw.0,
-            function_kw.0,
-            name.0,
-            generic_params.0,
-            signature.0,
-            semicolon.0,
-        ];
+        colon: TerminalColonGreen,
+    ) -> AttributeArgNameGreen {
+        let children: Vec<GreenId> = vec![name.0, colon.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemExternFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemExternFunction,
+        AttributeArgNameGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::AttributeArgName,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ItemExternFunction {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn extern_kw(&self, db: &dyn SyntaxGroup) -> TerminalExtern {
-        TerminalExtern::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn function_kw(&self, db: &dyn SyntaxGroup) -> TerminalFunction {
-        TerminalFunction::from_syntax_node(db, self.children[2].clone())
-    }
+impl AttributeArgName {
     pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[3].clone())
-    }
-    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
-        OptionWrappedGenericParamList::from_syntax_node(db, self.children[4].clone())
-    }
-    pub fn signature(&self, db: &dyn SyntaxGroup) -> FunctionSignature {
-        FunctionSignature::from_syntax_node(db, self.children[5].clone())
+        TerminalIdentifier::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
-        TerminalSemicolon::from_syntax_node(db, self.children[6].clone())
+    pub fn colon(&self, db: &dyn SyntaxGroup) -> TerminalColon {
+        TerminalColon::from_syntax_node(db, self.children[1].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemExternFunctionPtr(pub SyntaxStablePtrId);
-impl ItemExternFunctionPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct AttributeArgNamePtr(pub SyntaxStablePtrId);
+impl AttributeArgNamePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemExternFunctionGreen(pub GreenId);
-impl TypedSyntaxNode for ItemExternFunction {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemExternFunction);
-    type StablePtr = ItemExternFunctionPtr;
-    type Green = ItemExternFunctionGreen;
+pub struct AttributeArgNameGreen(pub GreenId);
+impl TypedSyntaxNode for AttributeArgName {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::AttributeArgName);
+    type StablePtr = AttributeArgNamePtr;
+    type Green = AttributeArgNameGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemExternFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemExternFunction,
+        AttributeArgNameGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::AttributeArgName,
             details: GreenNodeDetails::Node {
-                children: vec![
-                    AttributeList::missing(db).0,
-                    TerminalExtern::missing(db).0,
-                    TerminalFunction::missing(db).0,
-                    TerminalIdentifier::missing(db).0,
-                    OptionWrappedGenericParamList::missing(db).0,
-                    FunctionSignature::missing(db).0,
-                    TerminalSemicolon::missing(db).0,
-                ],
+                children: vec![TerminalIdentifier::missing(db).0, TerminalColon::missing(db).0],
                 width: 0,
             },
         }))
@@ -6678,10 +7312,10 @@ impl TypedSyntaxNode for ItemExternFunction {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemExternFunction,
+            SyntaxKind::AttributeArgName,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemExternFunction
+            SyntaxKind::AttributeArgName
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -6693,52 +7327,60 @@ impl TypedSyntaxNode for ItemExternFunction {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemExternFunctionPtr(self.node.0.stable_ptr)
+        AttributeArgNamePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemExternType {
+pub struct ItemConstant {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemExternType {
+impl ItemConstant {
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        extern_kw: TerminalExternGreen,
-        type_kw: TerminalTypeGreen,
+        attributes: AttributeListGreen,
+        const_kw: TerminalConstGreen,
         name: TerminalIdentifierGreen,
-        generic_params: OptionWrappedGenericParamListGreen,
+        type_clause: TypeClauseGreen,
+        eq: TerminalEqGreen,
+        value: ExprGreen,
         semicolon: TerminalSemicolonGreen,
-    ) -> ItemExternTypeGreen {
+    ) -> ItemConstantGreen {
         let children: Vec<GreenId> =
-            vec![extern_kw.0, type_kw.0, name.0, generic_params.0, semicolon.0];
+            vec![attributes.0, const_kw.0, name.0, type_clause.0, eq.0, value.0, semicolon.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemExternTypeGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemExternType,
+        ItemConstantGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemConstant,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ItemExternType {
-    pub fn extern_kw(&self, db: &dyn SyntaxGroup) -> TerminalExtern {
-        TerminalExtern::from_syntax_node(db, self.children[0].clone())
+impl ItemConstant {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn type_kw(&self, db: &dyn SyntaxGroup) -> TerminalType {
-        TerminalType::from_syntax_node(db, self.children[1].clone())
+    pub fn const_kw(&self, db: &dyn SyntaxGroup) -> TerminalConst {
+        TerminalConst::from_syntax_node(db, self.children[1].clone())
     }
     pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
         TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
     }
-    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
-        OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
+    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> TypeClause {
+        TypeClause::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn eq(&self, db: &dyn SyntaxGroup) -> TerminalEq {
+        TerminalEq::from_syntax_node(db, self.children[4].clone())
+    }
+    pub fn value(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[5].clone())
     }
     pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
-        TerminalSemicolon::from_syntax_node(db, self.children[4].clone())
+        TerminalSemicolon::from_syntax_node(db, self.children[6].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemExternTypePtr(pub SyntaxStablePtrId);
-impl ItemExternTypePtr {
+pub struct ItemConstantPtr(pub SyntaxStablePtrId);
+impl ItemConstantPtr {
     pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
         let ptr = db.lookup_intern_stable_ptr(self.0);
         if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
@@ -6752,20 +7394,22 @@ impl ItemExternTypePtr {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemExternTypeGreen(pub GreenId);
-impl TypedSyntaxNode for ItemExternType {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemExternType);
-    type StablePtr = ItemExternTypePtr;
-    type Green = ItemExternTypeGreen;
+pub struct ItemConstantGreen(pub GreenId);
+impl TypedSyntaxNode for ItemConstant {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemConstant);
+    type StablePtr = ItemConstantPtr;
+    type Green = ItemConstantGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemExternTypeGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemExternType,
+        ItemConstantGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemConstant,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    TerminalExtern::missing(db).0,
-                    TerminalType::missing(db).0,
+                    AttributeList::missing(db).0,
+                    TerminalConst::missing(db).0,
                     TerminalIdentifier::missing(db).0,
-                    OptionWrappedGenericParamList::missing(db).0,
+                    TypeClause::missing(db).0,
+                    TerminalEq::missing(db).0,
+                    Expr::missing(db).0,
                     TerminalSemicolon::missing(db).0,
                 ],
                 width: 0,
@@ -6776,10 +7420,10 @@ impl TypedSyntaxNode for ItemExternType {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemExternType,
+            SyntaxKind::ItemConstant,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemExternType
+            SyntaxKind::ItemConstant
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -6791,38 +7435,39 @@ impl TypedSyntaxNode for ItemExternType {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemExternTypePtr(self.node.0.stable_ptr)
+        ItemConstantPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemTrait {
+pub struct ItemFreeFunction {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemTrait {
+impl ItemFreeFunction {
     pub fn new_green(
         db: &dyn SyntaxGroup,
         attributes: AttributeListGreen,
-        trait_kw: TerminalTraitGreen,
+        function_kw: TerminalFunctionGreen,
         name: TerminalIdentifierGreen,
         generic_params: OptionWrappedGenericParamListGreen,
-        body: MaybeTraitBodyGreen,
-    ) -> ItemTraitGreen {
+        signature: FunctionSignatureGreen,
+        body: ExprBlockGreen,
+    ) -> ItemFreeFunctionGreen {
         let children: Vec<GreenId> =
-            vec![attributes.0, trait_kw.0, name.0, generic_params.0, body.0];
+            vec![attributes.0, function_kw.0, name.0, generic_params.0, signature.0, body.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemTraitGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemTrait,
+        ItemFreeFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemFreeFunction,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ItemTrait {
+impl ItemFreeFunction {
     pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
         AttributeList::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn trait_kw(&self, db: &dyn SyntaxGroup) -> TerminalTrait {
-        TerminalTrait::from_syntax_node(db, self.children[1].clone())
+    pub fn function_kw(&self, db: &dyn SyntaxGroup) -> TerminalFunction {
+        TerminalFunction::from_syntax_node(db, self.children[1].clone())
     }
     pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
         TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
@@ -6830,13 +7475,16 @@ impl ItemTrait {
     pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
         OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
     }
-    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeTraitBody {
-        MaybeTraitBody::from_syntax_node(db, self.children[4].clone())
+    pub fn signature(&self, db: &dyn SyntaxGroup) -> FunctionSignature {
+        FunctionSignature::from_syntax_node(db, self.children[4].clone())
+    }
+    pub fn body(&self, db: &dyn SyntaxGroup) -> ExprBlock {
+        ExprBlock::from_syntax_node(db, self.children[5].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemTraitPtr(pub SyntaxStablePtrId);
-impl ItemTraitPtr {
+pub struct ItemFreeFunctionPtr(pub SyntaxStablePtrId);
+impl ItemFreeFunctionPtr {
     pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
         let ptr = db.lookup_intern_stable_ptr(self.0);
         if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
@@ -6850,21 +7498,22 @@ impl ItemTraitPtr {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemTraitGreen(pub GreenId);
-impl TypedSyntaxNode for ItemTrait {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemTrait);
-    type StablePtr = ItemTraitPtr;
-    type Green = ItemTraitGreen;
+pub struct ItemFreeFunctionGreen(pub GreenId);
+impl TypedSyntaxNode for ItemFreeFunction {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemFreeFunction);
+    type StablePtr = ItemFreeFunctionPtr;
+    type Green = ItemFreeFunctionGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemTraitGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemTrait,
+        ItemFreeFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemFreeFunction,
             details: GreenNodeDetails::Node {
                 children: vec![
                     AttributeList::missing(db).0,
-                    TerminalTrait::missing(db).0,
+                    TerminalFunction::missing(db).0,
                     TerminalIdentifier::missing(db).0,
                     OptionWrappedGenericParamList::missing(db).0,
-                    MaybeTraitBody::missing(db).0,
+                    FunctionSignature::missing(db).0,
+                    ExprBlock::missing(db).0,
                 ],
                 width: 0,
             },
@@ -6874,10 +7523,10 @@ impl TypedSyntaxNode for ItemTrait {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemTrait,
+            SyntaxKind::ItemFreeFunction,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemTrait
+            SyntaxKind::ItemFreeFunction
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -6889,127 +7538,97 @@ impl TypedSyntaxNode for ItemTrait {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemTraitPtr(self.node.0.stable_ptr)
-    }
-}
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum MaybeTraitBody {
-    Some(TraitBody),
-    None(TerminalSemicolon),
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeTraitBodyPtr(pub SyntaxStablePtrId);
-impl MaybeTraitBodyPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
-    }
-}
-impl From<TraitBodyPtr> for MaybeTraitBodyPtr {
-    fn from(value: TraitBodyPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<TerminalSemicolonPtr> for MaybeTraitBodyPtr {
-    fn from(value: TerminalSemicolonPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<TraitBodyGreen> for MaybeTraitBodyGreen {
-    fn from(value: TraitBodyGreen) -> Self {
-        Self(value.0)
-    }
-}
-impl From<TerminalSemicolonGreen> for MaybeTraitBodyGreen {
-    fn from(value: TerminalSemicolonGreen) -> Self {
-        Self(value.0)
-    }
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeTraitBodyGreen(pub GreenId);
-impl TypedSyntaxNode for MaybeTraitBody {
-    const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = MaybeTraitBodyPtr;
-    type Green = MaybeTraitBodyGreen;
-    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        panic!("No missing variant.");
-    }
-    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        match kind {
-            SyntaxKind::TraitBody => MaybeTraitBody::Some(TraitBody::from_syntax_node(db, node)),
-            SyntaxKind::TerminalSemicolon => {
-                MaybeTraitBody::None(TerminalSemicolon::from_syntax_node(db, node))
-            }
-            _ => {
-                panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "MaybeTraitBody")
-            }
-        }
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        match self {
-            MaybeTraitBody::Some(x) => x.as_syntax_node(),
-            MaybeTraitBody::None(x) => x.as_syntax_node(),
-        }
-    }
-    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
-        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
-    }
-    fn stable_ptr(&self) -> Self::StablePtr {
-        MaybeTraitBodyPtr(self.as_syntax_node().0.stable_ptr)
+        ItemFreeFunctionPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TraitBody {
+pub struct ItemExternFunction {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl TraitBody {
+impl ItemExternFunction {
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        lbrace: TerminalLBraceGreen,
-        items: TraitItemListGreen,
-        rbrace: TerminalRBraceGreen,
-    ) -> TraitBodyGreen {
-        let children: Vec<GreenId> = vec![lbrace.0, items.0, rbrace.0];
+        attributes: AttributeListGreen,
+        extern_kw: TerminalExternGreen,
+        function_kw: TerminalFunctionGreen,
+        name: TerminalIdentifierGreen,
+        generic_params: OptionWrappedGenericParamListGreen,
+        signature: FunctionSignatureGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> ItemExternFunctionGreen {
+        let children: Vec<GreenId> = vec![
+            attributes.0,
+            extern_kw.0,
+            function_kw.0,
+            name.0,
+            generic_params.0,
+            signature.0,
+            semicolon.0,
+        ];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TraitBodyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitBody,
+        ItemExternFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemExternFunction,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl TraitBody {
-    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
-        TerminalLBrace::from_syntax_node(db, self.children[0].clone())
+impl ItemExternFunction {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn items(&self, db: &dyn SyntaxGroup) -> TraitItemList {
-        TraitItemList::from_syntax_node(db, self.children[1].clone())
+    pub fn extern_kw(&self, db: &dyn SyntaxGroup) -> TerminalExtern {
+        TerminalExtern::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
-        TerminalRBrace::from_syntax_node(db, self.children[2].clone())
+    pub fn function_kw(&self, db: &dyn SyntaxGroup) -> TerminalFunction {
+        TerminalFunction::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
+        OptionWrappedGenericParamList::from_syntax_node(db, self.children[4].clone())
+    }
+    pub fn signature(&self, db: &dyn SyntaxGroup) -> FunctionSignature {
+        FunctionSignature::from_syntax_node(db, self.children[5].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[6].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitBodyPtr(pub SyntaxStablePtrId);
-impl TraitBodyPtr {
+pub struct ItemExternFunctionPtr(pub SyntaxStablePtrId);
+impl ItemExternFunctionPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitBodyGreen(pub GreenId);
-impl TypedSyntaxNode for TraitBody {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitBody);
-    type StablePtr = TraitBodyPtr;
-    type Green = TraitBodyGreen;
+pub struct ItemExternFunctionGreen(pub GreenId);
+impl TypedSyntaxNode for ItemExternFunction {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemExternFunction);
+    type StablePtr = ItemExternFunctionPtr;
+    type Green = ItemExternFunctionGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TraitBodyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitBody,
+        ItemExternFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemExternFunction,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    TerminalLBrace::missing(db).0,
-                    TraitItemList::missing(db).0,
-                    TerminalRBrace::missing(db).0,
+                    AttributeList::missing(db).0,
+                    TerminalExtern::missing(db).0,
+                    TerminalFunction::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    OptionWrappedGenericParamList::missing(db).0,
+                    FunctionSignature::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
                 ],
                 width: 0,
             },
@@ -7019,10 +7638,10 @@ impl TypedSyntaxNode for TraitBody {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TraitBody,
+            SyntaxKind::ItemExternFunction,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TraitBody
+            SyntaxKind::ItemExternFunction
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -7034,142 +7653,38 @@ impl TypedSyntaxNode for TraitBody {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TraitBodyPtr(self.node.0.stable_ptr)
+        ItemExternFunctionPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TraitItemList(ElementList<TraitItem, 1>);
-impl Deref for TraitItemList {
-    type Target = ElementList<TraitItem, 1>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
+pub struct ItemExternType {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
 }
-impl TraitItemList {
-    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<TraitItemGreen>) -> TraitItemListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.0).width()).sum();
-        TraitItemListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitItemList,
-            details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.0).collect(),
-                width,
-            },
+impl ItemExternType {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        extern_kw: TerminalExternGreen,
+        type_kw: TerminalTypeGreen,
+        name: TerminalIdentifierGreen,
+        generic_params: OptionWrappedGenericParamListGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> ItemExternTypeGreen {
+        let children: Vec<GreenId> =
+            vec![extern_kw.0, type_kw.0, name.0, generic_params.0, semicolon.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ItemExternTypeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemExternType,
+            details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemListPtr(pub SyntaxStablePtrId);
-impl TraitItemListPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
-    }
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemListGreen(pub GreenId);
-impl TypedSyntaxNode for TraitItemList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitItemList);
-    type StablePtr = TraitItemListPtr;
-    type Green = TraitItemListGreen;
-    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TraitItemListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitItemList,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
-        }))
-    }
-    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        Self(ElementList::new(node))
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
-    }
-    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
-        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
-    }
-    fn stable_ptr(&self) -> Self::StablePtr {
-        TraitItemListPtr(self.node.0.stable_ptr)
-    }
-}
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum TraitItem {
-    Function(TraitItemFunction),
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemPtr(pub SyntaxStablePtrId);
-impl TraitItemPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
-    }
-}
-impl From<TraitItemFunctionPtr> for TraitItemPtr {
-    fn from(value: TraitItemFunctionPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<TraitItemFunctionGreen> for TraitItemGreen {
-    fn from(value: TraitItemFunctionGreen) -> Self {
-        Self(value.0)
-    }
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemGreen(pub GreenId);
-impl TypedSyntaxNode for TraitItem {
-    const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = TraitItemPtr;
-    type Green = TraitItemGreen;
-    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        panic!("No missing variant.");
-    }
-    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        match kind {
-            SyntaxKind::TraitItemFunction => {
-                TraitItem::Function(TraitItemFunction::from_syntax_node(db, node))
-            }
-            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "TraitItem"),
-        }
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        match self {
-            TraitItem::Function(x) => x.as_syntax_node(),
-        }
-    }
-    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
-        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
-    }
-    fn stable_ptr(&self) -> Self::StablePtr {
-        TraitItemPtr(self.as_syntax_node().0.stable_ptr)
-    }
-}
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TraitItemFunction {
-    node: SyntaxNode,
-    children: Vec<SyntaxNode>,
-}
-impl TraitItemFunction {
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        function_kw: TerminalFunctionGreen,
-        name: TerminalIdentifierGreen,
-        generic_params: OptionWrappedGenericParamListGreen,
-        signature: FunctionSignatureGreen,
-        semicolon: TerminalSemicolonGreen,
-    ) -> TraitItemFunctionGreen {
-        let children: Vec<GreenId> =
-            vec![attributes.0, function_kw.0, name.0, generic_params.0, signature.0, semicolon.0];
-        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TraitItemFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitItemFunction,
-            details: GreenNodeDetails::Node { children, width },
-        }))
-    }
-}
-impl TraitItemFunction {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
+impl ItemExternType {
+    pub fn extern_kw(&self, db: &dyn SyntaxGroup) -> TerminalExtern {
+        TerminalExtern::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn function_kw(&self, db: &dyn SyntaxGroup) -> TerminalFunction {
-        TerminalFunction::from_syntax_node(db, self.children[1].clone())
+    pub fn type_kw(&self, db: &dyn SyntaxGroup) -> TerminalType {
+        TerminalType::from_syntax_node(db, self.children[1].clone())
     }
     pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
         TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
@@ -7177,16 +7692,13 @@ impl TraitItemFunction {
     pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
         OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
     }
-    pub fn signature(&self, db: &dyn SyntaxGroup) -> FunctionSignature {
-        FunctionSignature::from_syntax_node(db, self.children[4].clone())
-    }
     pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
-        TerminalSemicolon::from_syntax_node(db, self.children[5].clone())
+        TerminalSemicolon::from_syntax_node(db, self.children[4].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemFunctionPtr(pub SyntaxStablePtrId);
-impl TraitItemFunctionPtr {
+pub struct ItemExternTypePtr(pub SyntaxStablePtrId);
+impl ItemExternTypePtr {
     pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
         let ptr = db.lookup_intern_stable_ptr(self.0);
         if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
@@ -7200,21 +7712,20 @@ impl TraitItemFunctionPtr {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemFunctionGreen(pub GreenId);
-impl TypedSyntaxNode for TraitItemFunction {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitItemFunction);
-    type StablePtr = TraitItemFunctionPtr;
-    type Green = TraitItemFunctionGreen;
+pub struct ItemExternTypeGreen(pub GreenId);
+impl TypedSyntaxNode for ItemExternType {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemExternType);
+    type StablePtr = ItemExternTypePtr;
+    type Green = ItemExternTypeGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TraitItemFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitItemFunction,
+        ItemExternTypeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemExternType,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    AttributeList::missing(db).0,
-                    TerminalFunction::missing(db).0,
+                    TerminalExtern::missing(db).0,
+                    TerminalType::missing(db).0,
                     TerminalIdentifier::missing(db).0,
                     OptionWrappedGenericParamList::missing(db).0,
-                    FunctionSignature::missing(db).0,
                     TerminalSemicolon::missing(db).0,
                 ],
                 width: 0,
@@ -7225,10 +7736,10 @@ impl TypedSyntaxNode for TraitItemFunction {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TraitItemFunction,
+            SyntaxKind::ItemExternType,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TraitItemFunction
+            SyntaxKind::ItemExternType
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -7240,40 +7751,38 @@ impl TypedSyntaxNode for TraitItemFunction {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TraitItemFunctionPtr(self.node.0.stable_ptr)
+        ItemExternTypePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemImpl {
+pub struct ItemTrait {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemImpl {
+impl ItemTrait {
     pub fn new_green(
         db: &dyn SyntaxGroup,
         attributes: AttributeListGreen,
-        impl_kw: TerminalImplGreen,
+        trait_kw: TerminalTraitGreen,
         name: TerminalIdentifierGreen,
         generic_params: OptionWrappedGenericParamListGreen,
-        of_kw: TerminalOfGreen,
-        trait_path: ExprPathGreen,
-        body: MaybeImplBodyGreen,
-    ) -> ItemImplGreen {
+        body: MaybeTraitBodyGreen,
+    ) -> ItemTraitGreen {
         let children: Vec<GreenId> =
-            vec![attributes.0, impl_kw.0, name.0, generic_params.0, of_kw.0, trait_path.0, body.0];
+            vec![attributes.0, trait_kw.0, name.0, generic_params.0, body.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemImplGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemImpl,
+        ItemTraitGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemTrait,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ItemImpl {
+impl ItemTrait {
     pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
         AttributeList::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn impl_kw(&self, db: &dyn SyntaxGroup) -> TerminalImpl {
-        TerminalImpl::from_syntax_node(db, self.children[1].clone())
+    pub fn trait_kw(&self, db: &dyn SyntaxGroup) -> TerminalTrait {
+        TerminalTrait::from_syntax_node(db, self.children[1].clone())
     }
     pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
         TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
@@ -7281,19 +7790,13 @@ impl ItemImpl {
     pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
         OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
     }
-    pub fn of_kw(&self, db: &dyn SyntaxGroup) -> TerminalOf {
-        TerminalOf::from_syntax_node(db, self.children[4].clone())
-    }
-    pub fn trait_path(&self, db: &dyn SyntaxGroup) -> ExprPath {
-        ExprPath::from_syntax_node(db, self.children[5].clone())
-    }
-    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeImplBody {
-        MaybeImplBody::from_syntax_node(db, self.children[6].clone())
+    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeTraitBody {
+        MaybeTraitBody::from_syntax_node(db, self.children[4].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemImplPtr(pub SyntaxStablePtrId);
-impl ItemImplPtr {
+pub struct ItemTraitPtr(pub SyntaxStablePtrId);
+impl ItemTraitPtr {
     pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
         let ptr = db.lookup_intern_stable_ptr(self.0);
         if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
@@ -7307,23 +7810,21 @@ impl ItemImplPtr {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemImplGreen(pub GreenId);
-impl TypedSyntaxNode for ItemImpl {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemImpl);
-    type StablePtr = ItemImplPtr;
-    type Green = ItemImplGreen;
+pub struct ItemTraitGreen(pub GreenId);
+impl TypedSyntaxNode for ItemTrait {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemTrait);
+    type StablePtr = ItemTraitPtr;
+    type Green = ItemTraitGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemImplGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemImpl,
+        ItemTraitGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemTrait,
             details: GreenNodeDetails::Node {
                 children: vec![
                     AttributeList::missing(db).0,
-                    TerminalImpl::missing(db).0,
+                    TerminalTrait::missing(db).0,
                     TerminalIdentifier::missing(db).0,
                     OptionWrappedGenericParamList::missing(db).0,
-                    TerminalOf::missing(db).0,
-                    ExprPath::missing(db).0,
-                    MaybeImplBody::missing(db).0,
+                    MaybeTraitBody::missing(db).0,
                 ],
                 width: 0,
             },
@@ -7333,10 +7834,10 @@ impl TypedSyntaxNode for ItemImpl {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemImpl,
+            SyntaxKind::ItemTrait,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemImpl
+            SyntaxKind::ItemTrait
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -7348,124 +7849,126 @@ impl TypedSyntaxNode for ItemImpl {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemImplPtr(self.node.0.stable_ptr)
+        ItemTraitPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum MaybeImplBody {
-    Some(ImplBody),
+pub enum MaybeTraitBody {
+    Some(TraitBody),
     None(TerminalSemicolon),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeImplBodyPtr(pub SyntaxStablePtrId);
-impl MaybeImplBodyPtr {
+pub struct MaybeTraitBodyPtr(pub SyntaxStablePtrId);
+impl MaybeTraitBodyPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-impl From<ImplBodyPtr> for MaybeImplBodyPtr {
-    fn from(value: ImplBodyPtr) -> Self {
+impl From<TraitBodyPtr> for MaybeTraitBodyPtr {
+    fn from(value: TraitBodyPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalSemicolonPtr> for MaybeImplBodyPtr {
+impl From<TerminalSemicolonPtr> for MaybeTraitBodyPtr {
     fn from(value: TerminalSemicolonPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<ImplBodyGreen> for MaybeImplBodyGreen {
-    fn from(value: ImplBodyGreen) -> Self {
+impl From<TraitBodyGreen> for MaybeTraitBodyGreen {
+    fn from(value: TraitBodyGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalSemicolonGreen> for MaybeImplBodyGreen {
+impl From<TerminalSemicolonGreen> for MaybeTraitBodyGreen {
     fn from(value: TerminalSemicolonGreen) -> Self {
         Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeImplBodyGreen(pub GreenId);
-impl TypedSyntaxNode for MaybeImplBody {
+pub struct MaybeTraitBodyGreen(pub GreenId);
+impl TypedSyntaxNode for MaybeTraitBody {
     const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = MaybeImplBodyPtr;
-    type Green = MaybeImplBodyGreen;
+    type StablePtr = MaybeTraitBodyPtr;
+    type Green = MaybeTraitBodyGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
         panic!("No missing variant.");
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         match kind {
-            SyntaxKind::ImplBody => MaybeImplBody::Some(ImplBody::from_syntax_node(db, node)),
+            SyntaxKind::TraitBody => MaybeTraitBody::Some(TraitBody::from_syntax_node(db, node)),
             SyntaxKind::TerminalSemicolon => {
-                MaybeImplBody::None(TerminalSemicolon::from_syntax_node(db, node))
+                MaybeTraitBody::None(TerminalSemicolon::from_syntax_node(db, node))
+            }
+            _ => {
+                panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "MaybeTraitBody")
             }
-            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "MaybeImplBody"),
         }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         match self {
-            MaybeImplBody::Some(x) => x.as_syntax_node(),
-            MaybeImplBody::None(x) => x.as_syntax_node(),
+            MaybeTraitBody::Some(x) => x.as_syntax_node(),
+            MaybeTraitBody::None(x) => x.as_syntax_node(),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        MaybeImplBodyPtr(self.as_syntax_node().0.stable_ptr)
+        MaybeTraitBodyPtr(self.as_syntax_node().0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ImplBody {
+pub struct TraitBody {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ImplBody {
+impl TraitBody {
     pub fn new_green(
         db: &dyn SyntaxGroup,
         lbrace: TerminalLBraceGreen,
-        items: ItemListGreen,
+        items: TraitItemListGreen,
         rbrace: TerminalRBraceGreen,
-    ) -> ImplBodyGreen {
+    ) -> TraitBodyGreen {
         let children: Vec<GreenId> = vec![lbrace.0, items.0, rbrace.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ImplBodyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ImplBody,
+        TraitBodyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitBody,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ImplBody {
+impl TraitBody {
     pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
         TerminalLBrace::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn items(&self, db: &dyn SyntaxGroup) -> ItemList {
-        ItemList::from_syntax_node(db, self.children[1].clone())
+    pub fn items(&self, db: &dyn SyntaxGroup) -> TraitItemList {
+        TraitItemList::from_syntax_node(db, self.children[1].clone())
     }
     pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
         TerminalRBrace::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ImplBodyPtr(pub SyntaxStablePtrId);
-impl ImplBodyPtr {
+pub struct TraitBodyPtr(pub SyntaxStablePtrId);
+impl TraitBodyPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ImplBodyGreen(pub GreenId);
-impl TypedSyntaxNode for ImplBody {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ImplBody);
-    type StablePtr = ImplBodyPtr;
-    type Green = ImplBodyGreen;
+pub struct TraitBodyGreen(pub GreenId);
+impl TypedSyntaxNode for TraitBody {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitBody);
+    type StablePtr = TraitBodyPtr;
+    type Green = TraitBodyGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ImplBodyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ImplBody,
+        TraitBodyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitBody,
             details: GreenNodeDetails::Node {
                 children: vec![
                     TerminalLBrace::missing(db).0,
-                    ItemList::missing(db).0,
+                    TraitItemList::missing(db).0,
                     TerminalRBrace::missing(db).0,
                 ],
                 width: 0,
@@ -7476,10 +7979,10 @@ impl TypedSyntaxNode for ImplBody {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ImplBody,
+            SyntaxKind::TraitBody,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ImplBody
+            SyntaxKind::TraitBody
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -7491,47 +7994,142 @@ impl TypedSyntaxNode for ImplBody {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ImplBodyPtr(self.node.0.stable_ptr)
+        TraitBodyPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemStruct {
+pub struct TraitItemList(ElementList<TraitItem, 1>);
+impl Deref for TraitItemList {
+    type Target = ElementList<TraitItem, 1>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl TraitItemList {
+    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<TraitItemGreen>) -> TraitItemListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.0).width()).sum();
+        TraitItemListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitItemList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.0).collect(),
+                width,
+            },
+        }))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TraitItemListPtr(pub SyntaxStablePtrId);
+impl TraitItemListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TraitItemListGreen(pub GreenId);
+impl TypedSyntaxNode for TraitItemList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitItemList);
+    type StablePtr = TraitItemListPtr;
+    type Green = TraitItemListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TraitItemListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitItemList,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TraitItemListPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum TraitItem {
+    Function(TraitItemFunction),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TraitItemPtr(pub SyntaxStablePtrId);
+impl TraitItemPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<TraitItemFunctionPtr> for TraitItemPtr {
+    fn from(value: TraitItemFunctionPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TraitItemFunctionGreen> for TraitItemGreen {
+    fn from(value: TraitItemFunctionGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TraitItemGreen(pub GreenId);
+impl TypedSyntaxNode for TraitItem {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = TraitItemPtr;
+    type Green = TraitItemGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::TraitItemFunction => {
+                TraitItem::Function(TraitItemFunction::from_syntax_node(db, node))
+            }
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "TraitItem"),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            TraitItem::Function(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TraitItemPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TraitItemFunction {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemStruct {
+impl TraitItemFunction {
     pub fn new_green(
         db: &dyn SyntaxGroup,
         attributes: AttributeListGreen,
-        struct_kw: TerminalStructGreen,
+        function_kw: TerminalFunctionGreen,
         name: TerminalIdentifierGreen,
         generic_params: OptionWrappedGenericParamListGreen,
-        lbrace: TerminalLBraceGreen,
-        members: MemberListGreen,
-        rbrace: TerminalRBraceGreen,
-    ) -> ItemStructGreen {
-        let children: Vec<GreenId> = vec![
-            attributes.0,
-            struct_kw.0,
-            name.0,
-            generic_params.0,
-            lbrace.0,
-            members.0,
-            rbrace.0,
-        ];
+        signature: FunctionSignatureGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> TraitItemFunctionGreen {
+        let children: Vec<GreenId> =
+            vec![attributes.0, function_kw.0, name.0, generic_params.0, signature.0, semicolon.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemStructGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemStruct,
+        TraitItemFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitItemFunction,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ItemStruct {
+impl TraitItemFunction {
     pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
         AttributeList::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn struct_kw(&self, db: &dyn SyntaxGroup) -> TerminalStruct {
-        TerminalStruct::from_syntax_node(db, self.children[1].clone())
+    pub fn function_kw(&self, db: &dyn SyntaxGroup) -> TerminalFunction {
+        TerminalFunction::from_syntax_node(db, self.children[1].clone())
     }
     pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
         TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
@@ -7539,19 +8137,16 @@ impl ItemStruct {
     pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
         OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
     }
-    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
-        TerminalLBrace::from_syntax_node(db, self.children[4].clone())
-    }
-    pub fn members(&self, db: &dyn SyntaxGroup) -> MemberList {
-        MemberList::from_syntax_node(db, self.children[5].clone())
+    pub fn signature(&self, db: &dyn SyntaxGroup) -> FunctionSignature {
+        FunctionSignature::from_syntax_node(db, self.children[4].clone())
     }
-    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
-        TerminalRBrace::from_syntax_node(db, self.children[6].clone())
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[5].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemStructPtr(pub SyntaxStablePtrId);
-impl ItemStructPtr {
+pub struct TraitItemFunctionPtr(pub SyntaxStablePtrId);
+impl TraitItemFunctionPtr {
     pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
         let ptr = db.lookup_intern_stable_ptr(self.0);
         if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
@@ -7565,23 +8160,22 @@ impl ItemStructPtr {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemStructGreen(pub GreenId);
-impl TypedSyntaxNode for ItemStruct {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemStruct);
-    type StablePtr = ItemStructPtr;
-    type Green = ItemStructGreen;
+pub struct TraitItemFunctionGreen(pub GreenId);
+impl TypedSyntaxNode for TraitItemFunction {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitItemFunction);
+    type StablePtr = TraitItemFunctionPtr;
+    type Green = TraitItemFunctionGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemStructGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemStruct,
+        TraitItemFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitItemFunction,
             details: GreenNodeDetails::Node {
                 children: vec![
                     AttributeList::missing(db).0,
-                    TerminalStruct::missing(db).0,
+                    TerminalFunction::missing(db).0,
                     TerminalIdentifier::missing(db).0,
                     OptionWrappedGenericParamList::missing(db).0,
-                    TerminalLBrace::missing(db).0,
-                    MemberList::missing(db).0,
-                    TerminalRBrace::missing(db).0,
+                    FunctionSignature::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
                 ],
                 width: 0,
             },
@@ -7591,10 +8185,10 @@ impl TypedSyntaxNode for ItemStruct {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemStruct,
+            SyntaxKind::TraitItemFunction,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemStruct
+            SyntaxKind::TraitItemFunction
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -7606,40 +8200,40 @@ impl TypedSyntaxNode for ItemStruct {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemStructPtr(self.node.0.stable_ptr)
+        TraitItemFunctionPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemEnum {
+pub struct ItemImpl {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemEnum {
+impl ItemImpl {
     pub fn new_green(
         db: &dyn SyntaxGroup,
         attributes: AttributeListGreen,
-        enum_kw: TerminalEnumGreen,
+        impl_kw: TerminalImplGreen,
         name: TerminalIdentifierGreen,
         generic_params: OptionWrappedGenericParamListGreen,
-        lbrace: TerminalLBraceGreen,
-        variants: MemberListGreen,
-        rbrace: TerminalRBraceGreen,
-    ) -> ItemEnumGreen {
+        of_kw: TerminalOfGreen,
+        trait_path: ExprPathGreen,
+        body: MaybeImplBodyGreen,
+    ) -> ItemImplGreen {
         let children: Vec<GreenId> =
-            vec![attributes.0, enum_kw.0, name.0, generic_params.0, lbrace.0, variants.0, rbrace.0];
+            vec![attributes.0, impl_kw.0, name.0, generic_params.0, of_kw.0, trait_path.0, body.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemEnumGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemEnum,
+        ItemImplGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemImpl,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ItemEnum {
+impl ItemImpl {
     pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
         AttributeList::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn enum_kw(&self, db: &dyn SyntaxGroup) -> TerminalEnum {
-        TerminalEnum::from_syntax_node(db, self.children[1].clone())
+    pub fn impl_kw(&self, db: &dyn SyntaxGroup) -> TerminalImpl {
+        TerminalImpl::from_syntax_node(db, self.children[1].clone())
     }
     pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
         TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
@@ -7647,19 +8241,19 @@ impl ItemEnum {
     pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
         OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
     }
-    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
-        TerminalLBrace::from_syntax_node(db, self.children[4].clone())
+    pub fn of_kw(&self, db: &dyn SyntaxGroup) -> TerminalOf {
+        TerminalOf::from_syntax_node(db, self.children[4].clone())
     }
-    pub fn variants(&self, db: &dyn SyntaxGroup) -> MemberList {
-        MemberList::from_syntax_node(db, self.children[5].clone())
+    pub fn trait_path(&self, db: &dyn SyntaxGroup) -> ExprPath {
+        ExprPath::from_syntax_node(db, self.children[5].clone())
     }
-    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
-        TerminalRBrace::from_syntax_node(db, self.children[6].clone())
+    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeImplBody {
+        MaybeImplBody::from_syntax_node(db, self.children[6].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemEnumPtr(pub SyntaxStablePtrId);
-impl ItemEnumPtr {
+pub struct ItemImplPtr(pub SyntaxStablePtrId);
+impl ItemImplPtr {
     pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
         let ptr = db.lookup_intern_stable_ptr(self.0);
         if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
@@ -7673,23 +8267,23 @@ impl ItemEnumPtr {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemEnumGreen(pub GreenId);
-impl TypedSyntaxNode for ItemEnum {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemEnum);
-    type StablePtr = ItemEnumPtr;
-    type Green = ItemEnumGreen;
+pub struct ItemImplGreen(pub GreenId);
+impl TypedSyntaxNode for ItemImpl {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemImpl);
+    type StablePtr = ItemImplPtr;
+    type Green = ItemImplGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemEnumGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemEnum,
+        ItemImplGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemImpl,
             details: GreenNodeDetails::Node {
                 children: vec![
                     AttributeList::missing(db).0,
-                    TerminalEnum::missing(db).0,
+                    TerminalImpl::missing(db).0,
                     TerminalIdentifier::missing(db).0,
                     OptionWrappedGenericParamList::missing(db).0,
-                    TerminalLBrace::missing(db).0,
-                    MemberList::missing(db).0,
-                    TerminalRBrace::missing(db).0,
+                    TerminalOf::missing(db).0,
+                    ExprPath::missing(db).0,
+                    MaybeImplBody::missing(db).0,
                 ],
                 width: 0,
             },
@@ -7699,10 +8293,10 @@ impl TypedSyntaxNode for ItemEnum {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemEnum,
+            SyntaxKind::ItemImpl,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemEnum
+            SyntaxKind::ItemImpl
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -7714,153 +8308,125 @@ impl TypedSyntaxNode for ItemEnum {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemEnumPtr(self.node.0.stable_ptr)
+        ItemImplPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemUse {
-    node: SyntaxNode,
-    children: Vec<SyntaxNode>,
+pub enum MaybeImplBody {
+    Some(ImplBody),
+    None(TerminalSemicolon),
 }
-impl ItemUse {
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        use_kw: TerminalUseGreen,
-        name: ExprPathGreen,
-        semicolon: TerminalSemicolonGreen,
-    ) -> ItemUseGreen {
-        let children: Vec<GreenId> = vec![attributes.0, use_kw.0, name.0, semicolon.0];
-        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemUseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemUse,
-            details: GreenNodeDetails::Node { children, width },
-        }))
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MaybeImplBodyPtr(pub SyntaxStablePtrId);
+impl MaybeImplBodyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
 }
-impl ItemUse {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn use_kw(&self, db: &dyn SyntaxGroup) -> TerminalUse {
-        TerminalUse::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> ExprPath {
-        ExprPath::from_syntax_node(db, self.children[2].clone())
+impl From<ImplBodyPtr> for MaybeImplBodyPtr {
+    fn from(value: ImplBodyPtr) -> Self {
+        Self(value.0)
     }
-    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
-        TerminalSemicolon::from_syntax_node(db, self.children[3].clone())
+}
+impl From<TerminalSemicolonPtr> for MaybeImplBodyPtr {
+    fn from(value: TerminalSemicolonPtr) -> Self {
+        Self(value.0)
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemUsePtr(pub SyntaxStablePtrId);
-impl ItemUsePtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> ExprPathGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            ExprPathGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
+impl From<ImplBodyGreen> for MaybeImplBodyGreen {
+    fn from(value: ImplBodyGreen) -> Self {
+        Self(value.0)
     }
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
+}
+impl From<TerminalSemicolonGreen> for MaybeImplBodyGreen {
+    fn from(value: TerminalSemicolonGreen) -> Self {
+        Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemUseGreen(pub GreenId);
-impl TypedSyntaxNode for ItemUse {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemUse);
-    type StablePtr = ItemUsePtr;
-    type Green = ItemUseGreen;
+pub struct MaybeImplBodyGreen(pub GreenId);
+impl TypedSyntaxNode for MaybeImplBody {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = MaybeImplBodyPtr;
+    type Green = MaybeImplBodyGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemUseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemUse,
-            details: GreenNodeDetails::Node {
-                children: vec![
-                    AttributeList::missing(db).0,
-                    TerminalUse::missing(db).0,
-                    ExprPath::missing(db).0,
-                    TerminalSemicolon::missing(db).0,
-                ],
-                width: 0,
-            },
-        }))
+        panic!("No missing variant.");
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
-        assert_eq!(
-            kind,
-            SyntaxKind::ItemUse,
-            "Unexpected SyntaxKind {:?}. Expected {:?}.",
-            kind,
-            SyntaxKind::ItemUse
-        );
-        let children = node.children(db).collect();
-        Self { node, children }
+        match kind {
+            SyntaxKind::ImplBody => MaybeImplBody::Some(ImplBody::from_syntax_node(db, node)),
+            SyntaxKind::TerminalSemicolon => {
+                MaybeImplBody::None(TerminalSemicolon::from_syntax_node(db, node))
+            }
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "MaybeImplBody"),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            MaybeImplBody::Some(x) => x.as_syntax_node(),
+            MaybeImplBody::None(x) => x.as_syntax_node(),
+        }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
-    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemUsePtr(self.node.0.stable_ptr)
+        MaybeImplBodyPtr(self.as_syntax_node().0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct GenericArgs {
+pub struct ImplBody {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl GenericArgs {
+impl ImplBody {
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        langle: TerminalLTGreen,
-        generic_args: GenericArgListGreen,
-        rangle: TerminalGTGreen,
-    ) -> GenericArgsGreen {
-        let children: Vec<GreenId> = vec![langle.0, generic_args.0, rangle.0];
+        lbrace: TerminalLBraceGreen,
+        items: ItemListGreen,
+        rbrace: TerminalRBraceGreen,
+    ) -> ImplBodyGreen {
+        let children: Vec<GreenId> = vec![lbrace.0, items.0, rbrace.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        GenericArgsGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericArgs,
+        ImplBodyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ImplBody,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl GenericArgs {
-    pub fn langle(&self, db: &dyn SyntaxGroup) -> TerminalLT {
-        TerminalLT::from_syntax_node(db, self.children[0].clone())
+impl ImplBody {
+    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
+        TerminalLBrace::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn generic_args(&self, db: &dyn SyntaxGroup) -> GenericArgList {
-        GenericArgList::from_syntax_node(db, self.children[1].clone())
+    pub fn items(&self, db: &dyn SyntaxGroup) -> ItemList {
+        ItemList::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn rangle(&self, db: &dyn SyntaxGroup) -> TerminalGT {
-        TerminalGT::from_syntax_node(db, self.children[2].clone())
+    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
+        TerminalRBrace::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericArgsPtr(pub SyntaxStablePtrId);
-impl GenericArgsPtr {
+pub struct ImplBodyPtr(pub SyntaxStablePtrId);
+impl ImplBodyPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericArgsGreen(pub GreenId);
-impl TypedSyntaxNode for GenericArgs {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericArgs);
-    type StablePtr = GenericArgsPtr;
-    type Green = GenericArgsGreen;
+pub struct ImplBodyGreen(pub GreenId);
+impl TypedSyntaxNode for ImplBody {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ImplBody);
+    type StablePtr = ImplBodyPtr;
+    type Green = ImplBodyGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        GenericArgsGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericArgs,
+        ImplBodyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ImplBody,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    TerminalLT::missing(db).0,
-                    GenericArgList::missing(db).0,
-                    TerminalGT::missing(db).0,
+                    TerminalLBrace::missing(db).0,
+                    ItemList::missing(db).0,
+                    TerminalRBrace::missing(db).0,
                 ],
                 width: 0,
             },
@@ -7870,10 +8436,10 @@ impl TypedSyntaxNode for GenericArgs {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::GenericArgs,
+            SyntaxKind::ImplBody,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::GenericArgs
+            SyntaxKind::ImplBody
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -7885,204 +8451,1875 @@ impl TypedSyntaxNode for GenericArgs {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        GenericArgsPtr(self.node.0.stable_ptr)
+        ImplBodyPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct GenericArgList(ElementList<Expr, 2>);
-impl Deref for GenericArgList {
-    type Target = ElementList<Expr, 2>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
+pub struct ItemStruct {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
 }
-impl GenericArgList {
+impl ItemStruct {
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        children: Vec<GenericArgListElementOrSeparatorGreen>,
-    ) -> GenericArgListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
-        GenericArgListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericArgList,
-            details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.id()).collect(),
-                width,
-            },
+        attributes: AttributeListGreen,
+        struct_kw: TerminalStructGreen,
+        name: TerminalIdentifierGreen,
+        generic_params: OptionWrappedGenericParamListGreen,
+        lbrace: TerminalLBraceGreen,
+        members: MemberListGreen,
+        rbrace: TerminalRBraceGreen,
+    ) -> ItemStructGreen {
+        let children: Vec<GreenId> = vec![
+            attributes.0,
+            struct_kw.0,
+            name.0,
+            generic_params.0,
+            lbrace.0,
+            members.0,
+            rbrace.0,
+        ];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ItemStructGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemStruct,
+            details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericArgListPtr(pub SyntaxStablePtrId);
-impl GenericArgListPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
+impl ItemStruct {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn struct_kw(&self, db: &dyn SyntaxGroup) -> TerminalStruct {
+        TerminalStruct::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
+        OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
+        TerminalLBrace::from_syntax_node(db, self.children[4].clone())
+    }
+    pub fn members(&self, db: &dyn SyntaxGroup) -> MemberList {
+        MemberList::from_syntax_node(db, self.children[5].clone())
+    }
+    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
+        TerminalRBrace::from_syntax_node(db, self.children[6].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemStructPtr(pub SyntaxStablePtrId);
+impl ItemStructPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemStructGreen(pub GreenId);
+impl TypedSyntaxNode for ItemStruct {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemStruct);
+    type StablePtr = ItemStructPtr;
+    type Green = ItemStructGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ItemStructGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemStruct,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    TerminalStruct::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    OptionWrappedGenericParamList::missing(db).0,
+                    TerminalLBrace::missing(db).0,
+                    MemberList::missing(db).0,
+                    TerminalRBrace::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ItemStruct,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ItemStruct
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ItemStructPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ItemEnum {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ItemEnum {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        enum_kw: TerminalEnumGreen,
+        name: TerminalIdentifierGreen,
+        generic_params: OptionWrappedGenericParamListGreen,
+        lbrace: TerminalLBraceGreen,
+        variants: MemberListGreen,
+        rbrace: TerminalRBraceGreen,
+    ) -> ItemEnumGreen {
+        let children: Vec<GreenId> =
+            vec![attributes.0, enum_kw.0, name.0, generic_params.0, lbrace.0, variants.0, rbrace.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ItemEnumGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemEnum,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ItemEnum {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn enum_kw(&self, db: &dyn SyntaxGroup) -> TerminalEnum {
+        TerminalEnum::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
+        OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
+        TerminalLBrace::from_syntax_node(db, self.children[4].clone())
+    }
+    pub fn variants(&self, db: &dyn SyntaxGroup) -> MemberList {
+        MemberList::from_syntax_node(db, self.children[5].clone())
+    }
+    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
+        TerminalRBrace::from_syntax_node(db, self.children[6].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemEnumPtr(pub SyntaxStablePtrId);
+impl ItemEnumPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemEnumGreen(pub GreenId);
+impl TypedSyntaxNode for ItemEnum {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemEnum);
+    type StablePtr = ItemEnumPtr;
+    type Green = ItemEnumGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ItemEnumGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemEnum,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    TerminalEnum::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    OptionWrappedGenericParamList::missing(db).0,
+                    TerminalLBrace::missing(db).0,
+                    MemberList::missing(db).0,
+                    TerminalRBrace::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ItemEnum,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ItemEnum
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ItemEnumPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ItemUse {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ItemUse {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        use_kw: TerminalUseGreen,
+        name: ExprPathGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> ItemUseGreen {
+        let children: Vec<GreenId> = vec![attributes.0, use_kw.0, name.0, semicolon.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ItemUseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemUse,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ItemUse {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn use_kw(&self, db: &dyn SyntaxGroup) -> TerminalUse {
+        TerminalUse::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> ExprPath {
+        ExprPath::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[3].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemUsePtr(pub SyntaxStablePtrId);
+impl ItemUsePtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> ExprPathGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            ExprPathGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemUseGreen(pub GreenId);
+impl TypedSyntaxNode for ItemUse {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemUse);
+    type StablePtr = ItemUsePtr;
+    type Green = ItemUseGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ItemUseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemUse,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    TerminalUse::missing(db).0,
+                    ExprPath::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ItemUse,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ItemUse
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ItemUsePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct GenericArgs {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl GenericArgs {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        langle: TerminalLTGreen,
+        generic_args: GenericArgListGreen,
+        rangle: TerminalGTGreen,
+    ) -> GenericArgsGreen {
+        let children: Vec<GreenId> = vec![langle.0, generic_args.0, rangle.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        GenericArgsGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericArgs,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl GenericArgs {
+    pub fn langle(&self, db: &dyn SyntaxGroup) -> TerminalLT {
+        TerminalLT::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn generic_args(&self, db: &dyn SyntaxGroup) -> GenericArgList {
+        GenericArgList::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn rangle(&self, db: &dyn SyntaxGroup) -> TerminalGT {
+        TerminalGT::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericArgsPtr(pub SyntaxStablePtrId);
+impl GenericArgsPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericArgsGreen(pub GreenId);
+impl TypedSyntaxNode for GenericArgs {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericArgs);
+    type StablePtr = GenericArgsPtr;
+    type Green = GenericArgsGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        GenericArgsGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericArgs,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalLT::missing(db).0,
+                    GenericArgList::missing(db).0,
+                    TerminalGT::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::GenericArgs,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::GenericArgs
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        GenericArgsPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct GenericArgList(ElementList<Expr, 2>);
+impl Deref for GenericArgList {
+    type Target = ElementList<Expr, 2>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl GenericArgList {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        children: Vec<GenericArgListElementOrSeparatorGreen>,
+    ) -> GenericArgListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
+        GenericArgListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericArgList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.id()).collect(),
+                width,
+            },
+        }))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericArgListPtr(pub SyntaxStablePtrId);
+impl GenericArgListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum GenericArgListElementOrSeparatorGreen {
+    Separator(TerminalCommaGreen),
+    Element(ExprGreen),
+}
+impl From<TerminalCommaGreen> for GenericArgListElementOrSeparatorGreen {
+    fn from(value: TerminalCommaGreen) -> Self {
+        GenericArgListElementOrSeparatorGreen::Separator(value)
+    }
+}
+impl From<ExprGreen> for GenericArgListElementOrSeparatorGreen {
+    fn from(value: ExprGreen) -> Self {
+        GenericArgListElementOrSeparatorGreen::Element(value)
+    }
+}
+impl GenericArgListElementOrSeparatorGreen {
+    fn id(&self) -> GreenId {
+        match self {
+            GenericArgListElementOrSeparatorGreen::Separator(green) => green.0,
+            GenericArgListElementOrSeparatorGreen::Element(green) => green.0,
+        }
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericArgListGreen(pub GreenId);
+impl TypedSyntaxNode for GenericArgList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericArgList);
+    type StablePtr = GenericArgListPtr;
+    type Green = GenericArgListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        GenericArgListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericArgList,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        GenericArgListPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum OptionWrappedGenericParamList {
+    Empty(OptionWrappedGenericParamListEmpty),
+    WrappedGenericParamList(WrappedGenericParamList),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionWrappedGenericParamListPtr(pub SyntaxStablePtrId);
+impl OptionWrappedGenericParamListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<OptionWrappedGenericParamListEmptyPtr> for OptionWrappedGenericParamListPtr {
+    fn from(value: OptionWrappedGenericParamListEmptyPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<WrappedGenericParamListPtr> for OptionWrappedGenericParamListPtr {
+    fn from(value: WrappedGenericParamListPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<OptionWrappedGenericParamListEmptyGreen> for OptionWrappedGenericParamListGreen {
+    fn from(value: OptionWrappedGenericParamListEmptyGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<WrappedGenericParamListGreen> for OptionWrappedGenericParamListGreen {
+    fn from(value: WrappedGenericParamListGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionWrappedGenericParamListGreen(pub GreenId);
+impl TypedSyntaxNode for OptionWrappedGenericParamList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = OptionWrappedGenericParamListPtr;
+    type Green = OptionWrappedGenericParamListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::OptionWrappedGenericParamListEmpty => OptionWrappedGenericParamList::Empty(
+                OptionWrappedGenericParamListEmpty::from_syntax_node(db, node),
+            ),
+            SyntaxKind::WrappedGenericParamList => {
+                OptionWrappedGenericParamList::WrappedGenericParamList(
+                    WrappedGenericParamList::from_syntax_node(db, node),
+                )
+            }
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "OptionWrappedGenericParamList"
+            ),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            OptionWrappedGenericParamList::Empty(x) => x.as_syntax_node(),
+            OptionWrappedGenericParamList::WrappedGenericParamList(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionWrappedGenericParamListPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OptionWrappedGenericParamListEmpty {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl OptionWrappedGenericParamListEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionWrappedGenericParamListEmptyGreen {
+        let children: Vec<GreenId> = vec![];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        OptionWrappedGenericParamListEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionWrappedGenericParamListEmpty,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl OptionWrappedGenericParamListEmpty {}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionWrappedGenericParamListEmptyPtr(pub SyntaxStablePtrId);
+impl OptionWrappedGenericParamListEmptyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionWrappedGenericParamListEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionWrappedGenericParamListEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionWrappedGenericParamListEmpty);
+    type StablePtr = OptionWrappedGenericParamListEmptyPtr;
+    type Green = OptionWrappedGenericParamListEmptyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        OptionWrappedGenericParamListEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionWrappedGenericParamListEmpty,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::OptionWrappedGenericParamListEmpty,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::OptionWrappedGenericParamListEmpty
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionWrappedGenericParamListEmptyPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct WrappedGenericParamList {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl WrappedGenericParamList {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        langle: TerminalLTGreen,
+        generic_params: GenericParamListGreen,
+        rangle: TerminalGTGreen,
+    ) -> WrappedGenericParamListGreen {
+        let children: Vec<GreenId> = vec![langle.0, generic_params.0, rangle.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        WrappedGenericParamListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::WrappedGenericParamList,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl WrappedGenericParamList {
+    pub fn langle(&self, db: &dyn SyntaxGroup) -> TerminalLT {
+        TerminalLT::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> GenericParamList {
+        GenericParamList::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn rangle(&self, db: &dyn SyntaxGroup) -> TerminalGT {
+        TerminalGT::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct WrappedGenericParamListPtr(pub SyntaxStablePtrId);
+impl WrappedGenericParamListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct WrappedGenericParamListGreen(pub GreenId);
+impl TypedSyntaxNode for WrappedGenericParamList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::WrappedGenericParamList);
+    type StablePtr = WrappedGenericParamListPtr;
+    type Green = WrappedGenericParamListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        WrappedGenericParamListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::WrappedGenericParamList,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalLT::missing(db).0,
+                    GenericParamList::missing(db).0,
+                    TerminalGT::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::WrappedGenericParamList,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::WrappedGenericParamList
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        WrappedGenericParamListPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct GenericParamList(ElementList<GenericParam, 2>);
+impl Deref for GenericParamList {
+    type Target = ElementList<GenericParam, 2>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl GenericParamList {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        children: Vec<GenericParamListElementOrSeparatorGreen>,
+    ) -> GenericParamListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
+        GenericParamListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParamList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.id()).collect(),
+                width,
+            },
+        }))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericParamListPtr(pub SyntaxStablePtrId);
+impl GenericParamListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum GenericParamListElementOrSeparatorGreen {
+    Separator(TerminalCommaGreen),
+    Element(GenericParamGreen),
+}
+impl From<TerminalCommaGreen> for GenericParamListElementOrSeparatorGreen {
+    fn from(value: TerminalCommaGreen) -> Self {
+        GenericParamListElementOrSeparatorGreen::Separator(value)
+    }
+}
+impl From<GenericParamGreen> for GenericParamListElementOrSeparatorGreen {
+    fn from(value: GenericParamGreen) -> Self {
+        GenericParamListElementOrSeparatorGreen::Element(value)
+    }
+}
+impl GenericParamListElementOrSeparatorGreen {
+    fn id(&self) -> GreenId {
+        match self {
+            GenericParamListElementOrSeparatorGreen::Separator(green) => green.0,
+            GenericParamListElementOrSeparatorGreen::Element(green) => green.0,
+        }
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericParamListGreen(pub GreenId);
+impl TypedSyntaxNode for GenericParamList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericParamList);
+    type StablePtr = GenericParamListPtr;
+    type Green = GenericParamListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        GenericParamListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParamList,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        GenericParamListPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct GenericParam {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl GenericParam {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        name: TerminalIdentifierGreen,
+        bounds: OptionGenericParamBoundsGreen,
+    ) -> GenericParamGreen {
+        let children: Vec<GreenId> = vec![name.0, bounds.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        GenericParamGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParam,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl GenericParam {
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn bounds(&self, db: &dyn SyntaxGroup) -> OptionGenericParamBounds {
+        OptionGenericParamBounds::from_syntax_node(db, self.children[1].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericParamPtr(pub SyntaxStablePtrId);
+impl GenericParamPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericParamGreen(pub GreenId);
+impl TypedSyntaxNode for GenericParam {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericParam);
+    type StablePtr = GenericParamPtr;
+    type Green = GenericParamGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        GenericParamGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParam,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalIdentifier::missing(db).0,
+                    OptionGenericParamBounds::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::GenericParam,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::GenericParam
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        GenericParamPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum OptionGenericParamBounds {
+    Empty(OptionGenericParamBoundsEmpty),
+    GenericParamBounds(GenericParamBounds),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionGenericParamBoundsPtr(pub SyntaxStablePtrId);
+impl OptionGenericParamBoundsPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<OptionGenericParamBoundsEmptyPtr> for OptionGenericParamBoundsPtr {
+    fn from(value: OptionGenericParamBoundsEmptyPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<GenericParamBoundsPtr> for OptionGenericParamBoundsPtr {
+    fn from(value: GenericParamBoundsPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<OptionGenericParamBoundsEmptyGreen> for OptionGenericParamBoundsGreen {
+    fn from(value: OptionGenericParamBoundsEmptyGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<GenericParamBoundsGreen> for OptionGenericParamBoundsGreen {
+    fn from(value: GenericParamBoundsGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionGenericParamBoundsGreen(pub GreenId);
+impl TypedSyntaxNode for OptionGenericParamBounds {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = OptionGenericParamBoundsPtr;
+    type Green = OptionGenericParamBoundsGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::OptionGenericParamBoundsEmpty => OptionGenericParamBounds::Empty(
+                OptionGenericParamBoundsEmpty::from_syntax_node(db, node),
+            ),
+            SyntaxKind::GenericParamBounds => OptionGenericParamBounds::GenericParamBounds(
+                GenericParamBounds::from_syntax_node(db, node),
+            ),
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "OptionGenericParamBounds"
+            ),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            OptionGenericParamBounds::Empty(x) => x.as_syntax_node(),
+            OptionGenericParamBounds::GenericParamBounds(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionGenericParamBoundsPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OptionGenericParamBoundsEmpty {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl OptionGenericParamBoundsEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionGenericParamBoundsEmptyGreen {
+        let children: Vec<GreenId> = vec![];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        OptionGenericParamBoundsEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionGenericParamBoundsEmpty,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl OptionGenericParamBoundsEmpty {}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionGenericParamBoundsEmptyPtr(pub SyntaxStablePtrId);
+impl OptionGenericParamBoundsEmptyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionGenericParamBoundsEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionGenericParamBoundsEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionGenericParamBoundsEmpty);
+    type StablePtr = OptionGenericParamBoundsEmptyPtr;
+    type Green = OptionGenericParamBoundsEmptyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        OptionGenericParamBoundsEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionGenericParamBoundsEmpty,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::OptionGenericParamBoundsEmpty,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::OptionGenericParamBoundsEmpty
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionGenericParamBoundsEmptyPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct GenericParamBounds {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl GenericParamBounds {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        colon: TerminalColonGreen,
+        traits: GenericParamBoundListGreen,
+    ) -> GenericParamBoundsGreen {
+        let children: Vec<GreenId> = vec![colon.0, traits.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        GenericParamBoundsGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParamBounds,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl GenericParamBounds {
+    pub fn colon(&self, db: &dyn SyntaxGroup) -> TerminalColon {
+        TerminalColon::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn traits(&self, db: &dyn SyntaxGroup) -> GenericParamBoundList {
+        GenericParamBoundList::from_syntax_node(db, self.children[1].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericParamBoundsPtr(pub SyntaxStablePtrId);
+impl GenericParamBoundsPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericParamBoundsGreen(pub GreenId);
+impl TypedSyntaxNode for GenericParamBounds {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericParamBounds);
+    type StablePtr = GenericParamBoundsPtr;
+    type Green = GenericParamBoundsGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        GenericParamBoundsGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParamBounds,
+            details: GreenNodeDetails::Node {
+                children: vec![TerminalColon::missing(db).0, GenericParamBoundList::missing(db).0],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::GenericParamBounds,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::GenericParamBounds
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        GenericParamBoundsPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct GenericParamBoundList(ElementList<ExprPath, 2>);
+impl Deref for GenericParamBoundList {
+    type Target = ElementList<ExprPath, 2>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl GenericParamBoundList {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        children: Vec<GenericParamBoundListElementOrSeparatorGreen>,
+    ) -> GenericParamBoundListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
+        GenericParamBoundListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParamBoundList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.id()).collect(),
+                width,
+            },
+        }))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericParamBoundListPtr(pub SyntaxStablePtrId);
+impl GenericParamBoundListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum GenericParamBoundListElementOrSeparatorGreen {
+    Separator(TerminalPlusGreen),
+    Element(ExprPathGreen),
+}
+impl From<TerminalPlusGreen> for GenericParamBoundListElementOrSeparatorGreen {
+    fn from(value: TerminalPlusGreen) -> Self {
+        GenericParamBoundListElementOrSeparatorGreen::Separator(value)
+    }
+}
+impl From<ExprPathGreen> for GenericParamBoundListElementOrSeparatorGreen {
+    fn from(value: ExprPathGreen) -> Self {
+        GenericParamBoundListElementOrSeparatorGreen::Element(value)
+    }
+}
+impl GenericParamBoundListElementOrSeparatorGreen {
+    fn id(&self) -> GreenId {
+        match self {
+            GenericParamBoundListElementOrSeparatorGreen::Separator(green) => green.0,
+            GenericParamBoundListElementOrSeparatorGreen::Element(green) => green.0,
+        }
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericParamBoundListGreen(pub GreenId);
+impl TypedSyntaxNode for GenericParamBoundList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericParamBoundList);
+    type StablePtr = GenericParamBoundListPtr;
+    type Green = GenericParamBoundListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        GenericParamBoundListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParamBoundList,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        GenericParamBoundListPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TokenIdentifier {
+    node: SyntaxNode,
+}
+impl Token for TokenIdentifier {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenIdentifierGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenIdentifier,
+            details: GreenNodeDetails::Token(text),
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenIdentifierPtr(pub SyntaxStablePtrId);
+impl TokenIdentifierPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenIdentifierGreen(pub GreenId);
+impl TokenIdentifierGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenIdentifier {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenIdentifier);
+    type StablePtr = TokenIdentifierPtr;
+    type Green = TokenIdentifierGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TokenIdentifierGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenIdentifier)
+            }
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TokenIdentifierPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TerminalIdentifier {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl Terminal for TerminalIdentifier {
+    const KIND: SyntaxKind = SyntaxKind::TerminalIdentifier;
+    type TokenType = TokenIdentifier;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalIdentifier as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TerminalIdentifierGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalIdentifier,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
+}
+impl TerminalIdentifier {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenIdentifier {
+        TokenIdentifier::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalIdentifierPtr(pub SyntaxStablePtrId);
+impl TerminalIdentifierPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalIdentifierGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalIdentifier {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalIdentifier);
+    type StablePtr = TerminalIdentifierPtr;
+    type Green = TerminalIdentifierGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TerminalIdentifierGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalIdentifier,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenIdentifier::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TerminalIdentifier,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TerminalIdentifier
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TerminalIdentifierPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TokenLiteralNumber {
+    node: SyntaxNode,
+}
+impl Token for TokenLiteralNumber {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenLiteralNumberGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenLiteralNumber,
+            details: GreenNodeDetails::Token(text),
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenLiteralNumberPtr(pub SyntaxStablePtrId);
+impl TokenLiteralNumberPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenLiteralNumberGreen(pub GreenId);
+impl TokenLiteralNumberGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenLiteralNumber {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenLiteralNumber);
+    type StablePtr = TokenLiteralNumberPtr;
+    type Green = TokenLiteralNumberGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TokenLiteralNumberGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => panic!(
+                "Expected a token {:?}, not an internal node",
+                SyntaxKind::TokenLiteralNumber
+            ),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TokenLiteralNumberPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TerminalLiteralNumber {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl Terminal for TerminalLiteralNumber {
+    const KIND: SyntaxKind = SyntaxKind::TerminalLiteralNumber;
+    type TokenType = TokenLiteralNumber;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalLiteralNumber as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TerminalLiteralNumberGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLiteralNumber,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
+}
+impl TerminalLiteralNumber {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenLiteralNumber {
+        TokenLiteralNumber::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalLiteralNumberPtr(pub SyntaxStablePtrId);
+impl TerminalLiteralNumberPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalLiteralNumberGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalLiteralNumber {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalLiteralNumber);
+    type StablePtr = TerminalLiteralNumberPtr;
+    type Green = TerminalLiteralNumberGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TerminalLiteralNumberGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLiteralNumber,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenLiteralNumber::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TerminalLiteralNumber,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TerminalLiteralNumber
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TerminalLiteralNumberPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TokenShortString {
+    node: SyntaxNode,
+}
+impl Token for TokenShortString {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenShortStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenShortString,
+            details: GreenNodeDetails::Token(text),
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenShortStringPtr(pub SyntaxStablePtrId);
+impl TokenShortStringPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenShortStringGreen(pub GreenId);
+impl TokenShortStringGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenShortString {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenShortString);
+    type StablePtr = TokenShortStringPtr;
+    type Green = TokenShortStringGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TokenShortStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenShortString)
+            }
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TokenShortStringPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TerminalShortString {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl Terminal for TerminalShortString {
+    const KIND: SyntaxKind = SyntaxKind::TerminalShortString;
+    type TokenType = TokenShortString;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalShortString as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TerminalShortStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalShortString,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
+}
+impl TerminalShortString {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenShortString {
+        TokenShortString::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub enum GenericArgListElementOrSeparatorGreen {
-    Separator(TerminalCommaGreen),
-    Element(ExprGreen),
+pub struct TerminalShortStringPtr(pub SyntaxStablePtrId);
+impl TerminalShortStringPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
 }
-impl From<TerminalCommaGreen> for GenericArgListElementOrSeparatorGreen {
-    fn from(value: TerminalCommaGreen) -> Self {
-        GenericArgListElementOrSeparatorGreen::Separator(value)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalShortStringGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalShortString {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalShortString);
+    type StablePtr = TerminalShortStringPtr;
+    type Green = TerminalShortStringGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TerminalShortStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalShortString,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenShortString::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TerminalShortString,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TerminalShortString
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TerminalShortStringPtr(self.node.0.stable_ptr)
     }
 }
-impl From<ExprGreen> for GenericArgListElementOrSeparatorGreen {
-    fn from(value: ExprGreen) -> Self {
-        GenericArgListElementOrSeparatorGreen::Element(value)
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TokenString {
+    node: SyntaxNode,
+}
+impl Token for TokenString {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenString,
+            details: GreenNodeDetails::Token(text),
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
     }
 }
-impl GenericArgListElementOrSeparatorGreen {
-    fn id(&self) -> GreenId {
-        match self {
-            GenericArgListElementOrSeparatorGreen::Separator(green) => green.0,
-            GenericArgListElementOrSeparatorGreen::Element(green) => green.0,
-        }
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenStringPtr(pub SyntaxStablePtrId);
+impl TokenStringPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericArgListGreen(pub GreenId);
-impl TypedSyntaxNode for GenericArgList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericArgList);
-    type StablePtr = GenericArgListPtr;
-    type Green = GenericArgListGreen;
+pub struct TokenStringGreen(pub GreenId);
+impl TokenStringGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenString {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenString);
+    type StablePtr = TokenStringPtr;
+    type Green = TokenStringGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        GenericArgListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericArgList,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        TokenStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        Self(ElementList::new(node))
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenString)
+            }
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         self.node.clone()
     }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TokenStringPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TerminalString {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl Terminal for TerminalString {
+    const KIND: SyntaxKind = SyntaxKind::TerminalString;
+    type TokenType = TokenString;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalString as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TerminalStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalString,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
+}
+impl TerminalString {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenString {
+        TokenString::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalStringPtr(pub SyntaxStablePtrId);
+impl TerminalStringPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalStringGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalString {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalString);
+    type StablePtr = TerminalStringPtr;
+    type Green = TerminalStringGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TerminalStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalString,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenString::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TerminalString,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TerminalString
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        GenericArgListPtr(self.node.0.stable_ptr)
+        TerminalStringPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum OptionWrappedGenericParamList {
-    Empty(OptionWrappedGenericParamListEmpty),
-    WrappedGenericParamList(WrappedGenericParamList),
+pub struct TokenFalse {
+    node: SyntaxNode,
+}
+impl Token for TokenFalse {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenFalseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenFalse,
+            details: GreenNodeDetails::Token(text),
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionWrappedGenericParamListPtr(pub SyntaxStablePtrId);
-impl OptionWrappedGenericParamListPtr {
+pub struct TokenFalsePtr(pub SyntaxStablePtrId);
+impl TokenFalsePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-impl From<OptionWrappedGenericParamListEmptyPtr> for OptionWrappedGenericParamListPtr {
-    fn from(value: OptionWrappedGenericParamListEmptyPtr) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenFalseGreen(pub GreenId);
+impl TokenFalseGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl From<WrappedGenericParamListPtr> for OptionWrappedGenericParamListPtr {
-    fn from(value: WrappedGenericParamListPtr) -> Self {
-        Self(value.0)
+impl TypedSyntaxNode for TokenFalse {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenFalse);
+    type StablePtr = TokenFalsePtr;
+    type Green = TokenFalseGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TokenFalseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenFalse)
+            }
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TokenFalsePtr(self.node.0.stable_ptr)
     }
 }
-impl From<OptionWrappedGenericParamListEmptyGreen> for OptionWrappedGenericParamListGreen {
-    fn from(value: OptionWrappedGenericParamListEmptyGreen) -> Self {
-        Self(value.0)
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TerminalFalse {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl Terminal for TerminalFalse {
+    const KIND: SyntaxKind = SyntaxKind::TerminalFalse;
+    type TokenType = TokenFalse;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalFalse as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TerminalFalseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalFalse,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
     }
 }
-impl From<WrappedGenericParamListGreen> for OptionWrappedGenericParamListGreen {
-    fn from(value: WrappedGenericParamListGreen) -> Self {
-        Self(value.0)
+impl TerminalFalse {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenFalse {
+        TokenFalse::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionWrappedGenericParamListGreen(pub GreenId);
-impl TypedSyntaxNode for OptionWrappedGenericParamList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = OptionWrappedGenericParamListPtr;
-    type Green = OptionWrappedGenericParamListGreen;
+pub struct TerminalFalsePtr(pub SyntaxStablePtrId);
+impl TerminalFalsePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalFalseGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalFalse {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalFalse);
+    type StablePtr = TerminalFalsePtr;
+    type Green = TerminalFalseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        panic!("No missing variant.");
+        TerminalFalseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalFalse,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenFalse::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
-        match kind {
-            SyntaxKind::OptionWrappedGenericParamListEmpty => OptionWrappedGenericParamList::Empty(
-                OptionWrappedGenericParamListEmpty::from_syntax_node(db, node),
-            ),
-            SyntaxKind::WrappedGenericParamList => {
-                OptionWrappedGenericParamList::WrappedGenericParamList(
-                    WrappedGenericParamList::from_syntax_node(db, node),
-                )
-            }
-            _ => panic!(
-                "Unexpected syntax kind {:?} when constructing {}.",
-                kind, "OptionWrappedGenericParamList"
-            ),
-        }
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        match self {
-            OptionWrappedGenericParamList::Empty(x) => x.as_syntax_node(),
-            OptionWrappedGenericParamList::WrappedGenericParamList(x) => x.as_syntax_node(),
-        }
+        assert_eq!(
+            kind,
+            SyntaxKind::TerminalFalse,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TerminalFalse
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionWrappedGenericParamListPtr(self.as_syntax_node().0.stable_ptr)
+        TerminalFalsePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct OptionWrappedGenericParamListEmpty {
+pub struct TokenTrue {
     node: SyntaxNode,
-    children: Vec<SyntaxNode>,
 }
-impl OptionWrappedGenericParamListEmpty {
-    pub fn new_green(db: &dyn SyntaxGroup) -> OptionWrappedGenericParamListEmptyGreen {
-        let children: Vec<GreenId> = vec![];
-        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        OptionWrappedGenericParamListEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionWrappedGenericParamListEmpty,
-            details: GreenNodeDetails::Node { children, width },
+impl Token for TokenTrue {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenTrueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenTrue,
+            details: GreenNodeDetails::Token(text),
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
 }
-impl OptionWrappedGenericParamListEmpty {}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionWrappedGenericParamListEmptyPtr(pub SyntaxStablePtrId);
-impl OptionWrappedGenericParamListEmptyPtr {
+pub struct TokenTruePtr(pub SyntaxStablePtrId);
+impl TokenTruePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionWrappedGenericParamListEmptyGreen(pub GreenId);
-impl TypedSyntaxNode for OptionWrappedGenericParamListEmpty {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionWrappedGenericParamListEmpty);
-    type StablePtr = OptionWrappedGenericParamListEmptyPtr;
-    type Green = OptionWrappedGenericParamListEmptyGreen;
+pub struct TokenTrueGreen(pub GreenId);
+impl TokenTrueGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenTrue {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenTrue);
+    type StablePtr = TokenTruePtr;
+    type Green = TokenTrueGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        OptionWrappedGenericParamListEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionWrappedGenericParamListEmpty,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        TokenTrueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        assert_eq!(
-            kind,
-            SyntaxKind::OptionWrappedGenericParamListEmpty,
-            "Unexpected SyntaxKind {:?}. Expected {:?}.",
-            kind,
-            SyntaxKind::OptionWrappedGenericParamListEmpty
-        );
-        let children = node.children(db).collect();
-        Self { node, children }
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenTrue)
+            }
+        }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
@@ -8091,61 +10328,66 @@ impl TypedSyntaxNode for OptionWrappedGenericParamListEmpty {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionWrappedGenericParamListEmptyPtr(self.node.0.stable_ptr)
+        TokenTruePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct WrappedGenericParamList {
+pub struct TerminalTrue {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl WrappedGenericParamList {
-    pub fn new_green(
+impl Terminal for TerminalTrue {
+    const KIND: SyntaxKind = SyntaxKind::TerminalTrue;
+    type TokenType = TokenTrue;
+    fn new_green(
         db: &dyn SyntaxGroup,
-        langle: TerminalLTGreen,
-        generic_params: GenericParamListGreen,
-        rangle: TerminalGTGreen,
-    ) -> WrappedGenericParamListGreen {
-        let children: Vec<GreenId> = vec![langle.0, generic_params.0, rangle.0];
+        leading_trivia: TriviaGreen,
+        token: <<TerminalTrue as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        WrappedGenericParamListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::WrappedGenericParamList,
+        TerminalTrueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalTrue,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
 }
-impl WrappedGenericParamList {
-    pub fn langle(&self, db: &dyn SyntaxGroup) -> TerminalLT {
-        TerminalLT::from_syntax_node(db, self.children[0].clone())
+impl TerminalTrue {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> GenericParamList {
-        GenericParamList::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenTrue {
+        TokenTrue::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn rangle(&self, db: &dyn SyntaxGroup) -> TerminalGT {
-        TerminalGT::from_syntax_node(db, self.children[2].clone())
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct WrappedGenericParamListPtr(pub SyntaxStablePtrId);
-impl WrappedGenericParamListPtr {
+pub struct TerminalTruePtr(pub SyntaxStablePtrId);
+impl TerminalTruePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct WrappedGenericParamListGreen(pub GreenId);
-impl TypedSyntaxNode for WrappedGenericParamList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::WrappedGenericParamList);
-    type StablePtr = WrappedGenericParamListPtr;
-    type Green = WrappedGenericParamListGreen;
+pub struct TerminalTrueGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalTrue {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalTrue);
+    type StablePtr = TerminalTruePtr;
+    type Green = TerminalTrueGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        WrappedGenericParamListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::WrappedGenericParamList,
+        TerminalTrueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalTrue,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    TerminalLT::missing(db).0,
-                    GenericParamList::missing(db).0,
-                    TerminalGT::missing(db).0,
+                    Trivia::missing(db).0,
+                    TokenTrue::missing(db).0,
+                    Trivia::missing(db).0,
                 ],
                 width: 0,
             },
@@ -8155,10 +10397,10 @@ impl TypedSyntaxNode for WrappedGenericParamList {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::WrappedGenericParamList,
+            SyntaxKind::TerminalTrue,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::WrappedGenericParamList
+            SyntaxKind::TerminalTrue
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -8170,133 +10412,124 @@ impl TypedSyntaxNode for WrappedGenericParamList {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        WrappedGenericParamListPtr(self.node.0.stable_ptr)
+        TerminalTruePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct GenericParamList(ElementList<GenericParam, 2>);
-impl Deref for GenericParamList {
-    type Target = ElementList<GenericParam, 2>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
+pub struct TokenExtern {
+    node: SyntaxNode,
 }
-impl GenericParamList {
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        children: Vec<GenericParamListElementOrSeparatorGreen>,
-    ) -> GenericParamListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
-        GenericParamListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericParamList,
-            details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.id()).collect(),
-                width,
-            },
+impl Token for TokenExtern {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenExternGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenExtern,
+            details: GreenNodeDetails::Token(text),
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericParamListPtr(pub SyntaxStablePtrId);
-impl GenericParamListPtr {
+pub struct TokenExternPtr(pub SyntaxStablePtrId);
+impl TokenExternPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub enum GenericParamListElementOrSeparatorGreen {
-    Separator(TerminalCommaGreen),
-    Element(GenericParamGreen),
-}
-impl From<TerminalCommaGreen> for GenericParamListElementOrSeparatorGreen {
-    fn from(value: TerminalCommaGreen) -> Self {
-        GenericParamListElementOrSeparatorGreen::Separator(value)
-    }
-}
-impl From<GenericParamGreen> for GenericParamListElementOrSeparatorGreen {
-    fn from(value: GenericParamGreen) -> Self {
-        GenericParamListElementOrSeparatorGreen::Element(value)
-    }
-}
-impl GenericParamListElementOrSeparatorGreen {
-    fn id(&self) -> GreenId {
-        match self {
-            GenericParamListElementOrSeparatorGreen::Separator(green) => green.0,
-            GenericParamListElementOrSeparatorGreen::Element(green) => green.0,
-        }
+pub struct TokenExternGreen(pub GreenId);
+impl TokenExternGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericParamListGreen(pub GreenId);
-impl TypedSyntaxNode for GenericParamList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericParamList);
-    type StablePtr = GenericParamListPtr;
-    type Green = GenericParamListGreen;
+impl TypedSyntaxNode for TokenExtern {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenExtern);
+    type StablePtr = TokenExternPtr;
+    type Green = TokenExternGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        GenericParamListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericParamList,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        TokenExternGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        Self(ElementList::new(node))
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenExtern)
+            }
+        }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        GenericParamListPtr(self.node.0.stable_ptr)
+        TokenExternPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct GenericParam {
+pub struct TerminalExtern {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl GenericParam {
-    pub fn new_green(db: &dyn SyntaxGroup, name: TerminalIdentifierGreen) -> GenericParamGreen {
-        let children: Vec<GreenId> = vec![name.0];
+impl Terminal for TerminalExtern {
+    const KIND: SyntaxKind = SyntaxKind::TerminalExtern;
+    type TokenType = TokenExtern;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalExtern as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        GenericParamGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericParam,
+        TerminalExternGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalExtern,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
 }
-impl GenericParam {
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[0].clone())
+impl TerminalExtern {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenExtern {
+        TokenExtern::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericParamPtr(pub SyntaxStablePtrId);
-impl GenericParamPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct TerminalExternPtr(pub SyntaxStablePtrId);
+impl TerminalExternPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericParamGreen(pub GreenId);
-impl TypedSyntaxNode for GenericParam {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericParam);
-    type StablePtr = GenericParamPtr;
-    type Green = GenericParamGreen;
+pub struct TerminalExternGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalExtern {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalExtern);
+    type StablePtr = TerminalExternPtr;
+    type Green = TerminalExternGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        GenericParamGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericParam,
+        TerminalExternGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalExtern,
             details: GreenNodeDetails::Node {
-                children: vec![TerminalIdentifier::missing(db).0],
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenExtern::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
                 width: 0,
             },
         }))
@@ -8305,10 +10538,10 @@ impl TypedSyntaxNode for GenericParam {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::GenericParam,
+            SyntaxKind::TerminalExtern,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::GenericParam
+            SyntaxKind::TerminalExtern
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -8320,17 +10553,17 @@ impl TypedSyntaxNode for GenericParam {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        GenericParamPtr(self.node.0.stable_ptr)
+        TerminalExternPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenIdentifier {
+pub struct TokenType {
     node: SyntaxNode,
 }
-impl Token for TokenIdentifier {
+impl Token for TokenType {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenIdentifierGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenIdentifier,
+        TokenTypeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenType,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -8339,25 +10572,25 @@ impl Token for TokenIdentifier {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenIdentifierPtr(pub SyntaxStablePtrId);
-impl TokenIdentifierPtr {
+pub struct TokenTypePtr(pub SyntaxStablePtrId);
+impl TokenTypePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenIdentifierGreen(pub GreenId);
-impl TokenIdentifierGreen {
+pub struct TokenTypeGreen(pub GreenId);
+impl TokenTypeGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenIdentifier {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenIdentifier);
-    type StablePtr = TokenIdentifierPtr;
-    type Green = TokenIdentifierGreen;
+impl TypedSyntaxNode for TokenType {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenType);
+    type StablePtr = TokenTypePtr;
+    type Green = TokenTypeGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenIdentifierGreen(db.intern_green(GreenNode {
+        TokenTypeGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -8366,7 +10599,7 @@ impl TypedSyntaxNode for TokenIdentifier {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenIdentifier)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenType)
             }
         }
     }
@@ -8377,27 +10610,27 @@ impl TypedSyntaxNode for TokenIdentifier {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenIdentifierPtr(self.node.0.stable_ptr)
+        TokenTypePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalIdentifier {
+pub struct TerminalType {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalIdentifier {
-    const KIND: SyntaxKind = SyntaxKind::TerminalIdentifier;
-    type TokenType = TokenIdentifier;
+impl Terminal for TerminalType {
+    const KIND: SyntaxKind = SyntaxKind::TerminalType;
+    type TokenType = TokenType;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalIdentifier as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalType as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalIdentifierGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalIdentifier,
+        TerminalTypeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalType,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -8405,37 +10638,37 @@ impl Terminal for TerminalIdentifier {
         self.token(db).text(db)
     }
 }
-impl TerminalIdentifier {
+impl TerminalType {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenIdentifier {
-        TokenIdentifier::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenType {
+        TokenType::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalIdentifierPtr(pub SyntaxStablePtrId);
-impl TerminalIdentifierPtr {
+pub struct TerminalTypePtr(pub SyntaxStablePtrId);
+impl TerminalTypePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalIdentifierGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalIdentifier {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalIdentifier);
-    type StablePtr = TerminalIdentifierPtr;
-    type Green = TerminalIdentifierGreen;
+pub struct TerminalTypeGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalType {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalType);
+    type StablePtr = TerminalTypePtr;
+    type Green = TerminalTypeGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalIdentifierGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalIdentifier,
+        TerminalTypeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalType,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenIdentifier::missing(db).0,
+                    TokenType::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -8446,10 +10679,10 @@ impl TypedSyntaxNode for TerminalIdentifier {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalIdentifier,
+            SyntaxKind::TerminalType,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalIdentifier
+            SyntaxKind::TerminalType
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -8461,17 +10694,17 @@ impl TypedSyntaxNode for TerminalIdentifier {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalIdentifierPtr(self.node.0.stable_ptr)
+        TerminalTypePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenLiteralNumber {
+pub struct TokenFunction {
     node: SyntaxNode,
 }
-impl Token for TokenLiteralNumber {
+impl Token for TokenFunction {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenLiteralNumberGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenLiteralNumber,
+        TokenFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenFunction,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -8480,25 +10713,25 @@ impl Token for TokenLiteralNumber {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenLiteralNumberPtr(pub SyntaxStablePtrId);
-impl TokenLiteralNumberPtr {
+pub struct TokenFunctionPtr(pub SyntaxStablePtrId);
+impl TokenFunctionPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenLiteralNumberGreen(pub GreenId);
-impl TokenLiteralNumberGreen {
+pub struct TokenFunctionGreen(pub GreenId);
+impl TokenFunctionGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenLiteralNumber {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenLiteralNumber);
-    type StablePtr = TokenLiteralNumberPtr;
-    type Green = TokenLiteralNumberGreen;
+impl TypedSyntaxNode for TokenFunction {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenFunction);
+    type StablePtr = TokenFunctionPtr;
+    type Green = TokenFunctionGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenLiteralNumberGreen(db.intern_green(GreenNode {
+        TokenFunctionGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -8506,10 +10739,9 @@ impl TypedSyntaxNode for TokenLiteralNumber {
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
-            GreenNodeDetails::Node { .. } => panic!(
-                "Expected a token {:?}, not an internal node",
-                SyntaxKind::TokenLiteralNumber
-            ),
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenFunction)
+            }
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
@@ -8519,27 +10751,27 @@ impl TypedSyntaxNode for TokenLiteralNumber {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenLiteralNumberPtr(self.node.0.stable_ptr)
+        TokenFunctionPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalLiteralNumber {
+pub struct TerminalFunction {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalLiteralNumber {
-    const KIND: SyntaxKind = SyntaxKind::TerminalLiteralNumber;
-    type TokenType = TokenLiteralNumber;
+impl Terminal for TerminalFunction {
+    const KIND: SyntaxKind = SyntaxKind::TerminalFunction;
+    type TokenType = TokenFunction;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalLiteralNumber as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalFunction as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalLiteralNumberGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalLiteralNumber,
+        TerminalFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalFunction,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -8547,37 +10779,37 @@ impl Terminal for TerminalLiteralNumber {
         self.token(db).text(db)
     }
 }
-impl TerminalLiteralNumber {
+impl TerminalFunction {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenLiteralNumber {
-        TokenLiteralNumber::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenFunction {
+        TokenFunction::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalLiteralNumberPtr(pub SyntaxStablePtrId);
-impl TerminalLiteralNumberPtr {
+pub struct TerminalFunctionPtr(pub SyntaxStablePtrId);
+impl TerminalFunctionPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalLiteralNumberGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalLiteralNumber {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalLiteralNumber);
-    type StablePtr = TerminalLiteralNumberPtr;
-    type Green = TerminalLiteralNumberGreen;
+pub struct TerminalFunctionGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalFunction {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalFunction);
+    type StablePtr = TerminalFunctionPtr;
+    type Green = TerminalFunctionGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalLiteralNumberGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalLiteralNumber,
+        TerminalFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalFunction,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenLiteralNumber::missing(db).0,
+                    TokenFunction::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -8588,10 +10820,10 @@ impl TypedSyntaxNode for TerminalLiteralNumber {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalLiteralNumber,
+            SyntaxKind::TerminalFunction,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalLiteralNumber
+            SyntaxKind::TerminalFunction
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -8603,17 +10835,17 @@ impl TypedSyntaxNode for TerminalLiteralNumber {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalLiteralNumberPtr(self.node.0.stable_ptr)
+        TerminalFunctionPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenFalse {
+pub struct TokenModule {
     node: SyntaxNode,
 }
-impl Token for TokenFalse {
+impl Token for TokenModule {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenFalseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenFalse,
+        TokenModuleGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenModule,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -8622,25 +10854,25 @@ impl Token for TokenFalse {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenFalsePtr(pub SyntaxStablePtrId);
-impl TokenFalsePtr {
+pub struct TokenModulePtr(pub SyntaxStablePtrId);
+impl TokenModulePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenFalseGreen(pub GreenId);
-impl TokenFalseGreen {
+pub struct TokenModuleGreen(pub GreenId);
+impl TokenModuleGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenFalse {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenFalse);
-    type StablePtr = TokenFalsePtr;
-    type Green = TokenFalseGreen;
+impl TypedSyntaxNode for TokenModule {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenModule);
+    type StablePtr = TokenModulePtr;
+    type Green = TokenModuleGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenFalseGreen(db.intern_green(GreenNode {
+        TokenModuleGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -8649,7 +10881,7 @@ impl TypedSyntaxNode for TokenFalse {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenFalse)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenModule)
             }
         }
     }
@@ -8660,27 +10892,27 @@ impl TypedSyntaxNode for TokenFalse {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenFalsePtr(self.node.0.stable_ptr)
+        TokenModulePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalFalse {
+pub struct TerminalModule {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalFalse {
-    const KIND: SyntaxKind = SyntaxKind::TerminalFalse;
-    type TokenType = TokenFalse;
+impl Terminal for TerminalModule {
+    const KIND: SyntaxKind = SyntaxKind::TerminalModule;
+    type TokenType = TokenModule;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalFalse as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalModule as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalFalseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalFalse,
+        TerminalModuleGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalModule,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -8688,37 +10920,37 @@ impl Terminal for TerminalFalse {
         self.token(db).text(db)
     }
 }
-impl TerminalFalse {
+impl TerminalModule {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenFalse {
-        TokenFalse::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenModule {
+        TokenModule::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalFalsePtr(pub SyntaxStablePtrId);
-impl TerminalFalsePtr {
+pub struct TerminalModulePtr(pub SyntaxStablePtrId);
+impl TerminalModulePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalFalseGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalFalse {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalFalse);
-    type StablePtr = TerminalFalsePtr;
-    type Green = TerminalFalseGreen;
+pub struct TerminalModuleGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalModule {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalModule);
+    type StablePtr = TerminalModulePtr;
+    type Green = TerminalModuleGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalFalseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalFalse,
+        TerminalModuleGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalModule,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenFalse::missing(db).0,
+                    TokenModule::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -8729,10 +10961,10 @@ impl TypedSyntaxNode for TerminalFalse {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalFalse,
+            SyntaxKind::TerminalModule,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalFalse
+            SyntaxKind::TerminalModule
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -8744,17 +10976,17 @@ impl TypedSyntaxNode for TerminalFalse {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalFalsePtr(self.node.0.stable_ptr)
+        TerminalModulePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenTrue {
+pub struct TokenEnum {
     node: SyntaxNode,
 }
-impl Token for TokenTrue {
+impl Token for TokenEnum {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenTrueGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenTrue,
+        TokenEnumGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenEnum,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -8763,25 +10995,25 @@ impl Token for TokenTrue {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenTruePtr(pub SyntaxStablePtrId);
-impl TokenTruePtr {
+pub struct TokenEnumPtr(pub SyntaxStablePtrId);
+impl TokenEnumPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenTrueGreen(pub GreenId);
-impl TokenTrueGreen {
+pub struct TokenEnumGreen(pub GreenId);
+impl TokenEnumGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenTrue {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenTrue);
-    type StablePtr = TokenTruePtr;
-    type Green = TokenTrueGreen;
+impl TypedSyntaxNode for TokenEnum {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenEnum);
+    type StablePtr = TokenEnumPtr;
+    type Green = TokenEnumGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenTrueGreen(db.intern_green(GreenNode {
+        TokenEnumGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -8790,7 +11022,7 @@ impl TypedSyntaxNode for TokenTrue {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenTrue)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenEnum)
             }
         }
     }
@@ -8801,27 +11033,27 @@ impl TypedSyntaxNode for TokenTrue {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenTruePtr(self.node.0.stable_ptr)
+        TokenEnumPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalTrue {
+pub struct TerminalEnum {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalTrue {
-    const KIND: SyntaxKind = SyntaxKind::TerminalTrue;
-    type TokenType = TokenTrue;
+impl Terminal for TerminalEnum {
+    const KIND: SyntaxKind = SyntaxKind::TerminalEnum;
+    type TokenType = TokenEnum;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalTrue as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalEnum as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalTrueGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalTrue,
+        TerminalEnumGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalEnum,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -8829,37 +11061,37 @@ impl Terminal for TerminalTrue {
         self.token(db).text(db)
     }
 }
-impl TerminalTrue {
+impl TerminalEnum {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenTrue {
-        TokenTrue::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenEnum {
+        TokenEnum::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalTruePtr(pub SyntaxStablePtrId);
-impl TerminalTruePtr {
+pub struct TerminalEnumPtr(pub SyntaxStablePtrId);
+impl TerminalEnumPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalTrueGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalTrue {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalTrue);
-    type StablePtr = TerminalTruePtr;
-    type Green = TerminalTrueGreen;
+pub struct TerminalEnumGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalEnum {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalEnum);
+    type StablePtr = TerminalEnumPtr;
+    type Green = TerminalEnumGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalTrueGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalTrue,
+        TerminalEnumGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalEnum,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenTrue::missing(db).0,
+                    TokenEnum::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -8870,10 +11102,10 @@ impl TypedSyntaxNode for TerminalTrue {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalTrue,
+            SyntaxKind::TerminalEnum,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalTrue
+            SyntaxKind::TerminalEnum
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -8885,17 +11117,17 @@ impl TypedSyntaxNode for TerminalTrue {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalTruePtr(self.node.0.stable_ptr)
+        TerminalEnumPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenExtern {
+pub struct TokenStruct {
     node: SyntaxNode,
 }
-impl Token for TokenExtern {
+impl Token for TokenStruct {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenExternGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenExtern,
+        TokenStructGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenStruct,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -8904,25 +11136,25 @@ impl Token for TokenExtern {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenExternPtr(pub SyntaxStablePtrId);
-impl TokenExternPtr {
+pub struct TokenStructPtr(pub SyntaxStablePtrId);
+impl TokenStructPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenExternGreen(pub GreenId);
-impl TokenExternGreen {
+pub struct TokenStructGreen(pub GreenId);
+impl TokenStructGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenExtern {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenExtern);
-    type StablePtr = TokenExternPtr;
-    type Green = TokenExternGreen;
+impl TypedSyntaxNode for TokenStruct {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenStruct);
+    type StablePtr = TokenStructPtr;
+    type Green = TokenStructGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenExternGreen(db.intern_green(GreenNode {
+        TokenStructGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -8931,7 +11163,7 @@ impl TypedSyntaxNode for TokenExtern {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenExtern)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenStruct)
             }
         }
     }
@@ -8942,27 +11174,27 @@ impl TypedSyntaxNode for TokenExtern {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenExternPtr(self.node.0.stable_ptr)
+        TokenStructPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalExtern {
+pub struct TerminalStruct {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalExtern {
-    const KIND: SyntaxKind = SyntaxKind::TerminalExtern;
-    type TokenType = TokenExtern;
+impl Terminal for TerminalStruct {
+    const KIND: SyntaxKind = SyntaxKind::TerminalStruct;
+    type TokenType = TokenStruct;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalExtern as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalStruct as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalExternGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalExtern,
+        TerminalStructGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalStruct,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -8970,37 +11202,37 @@ impl Terminal for TerminalExtern {
         self.token(db).text(db)
     }
 }
-impl TerminalExtern {
+impl TerminalStruct {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenExtern {
-        TokenExtern::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenStruct {
+        TokenStruct::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalExternPtr(pub SyntaxStablePtrId);
-impl TerminalExternPtr {
+pub struct TerminalStructPtr(pub SyntaxStablePtrId);
+impl TerminalStructPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalExternGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalExtern {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalExtern);
-    type StablePtr = TerminalExternPtr;
-    type Green = TerminalExternGreen;
+pub struct TerminalStructGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalStruct {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalStruct);
+    type StablePtr = TerminalStructPtr;
+    type Green = TerminalStructGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalExternGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalExtern,
+        TerminalStructGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalStruct,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenExtern::missing(db).0,
+                    TokenStruct::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -9011,10 +11243,10 @@ impl TypedSyntaxNode for TerminalExtern {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalExtern,
+            SyntaxKind::TerminalStruct,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalExtern
+            SyntaxKind::TerminalStruct
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9026,17 +11258,17 @@ impl TypedSyntaxNode for TerminalExtern {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalExternPtr(self.node.0.stable_ptr)
+        TerminalStructPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenType {
+pub struct TokenTrait {
     node: SyntaxNode,
 }
-impl Token for TokenType {
+impl Token for TokenTrait {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenTypeGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenType,
+        TokenTraitGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenTrait,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -9045,25 +11277,25 @@ impl Token for TokenType {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenTypePtr(pub SyntaxStablePtrId);
-impl TokenTypePtr {
+pub struct TokenTraitPtr(pub SyntaxStablePtrId);
+impl TokenTraitPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenTypeGreen(pub GreenId);
-impl TokenTypeGreen {
+pub struct TokenTraitGreen(pub GreenId);
+impl TokenTraitGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenType {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenType);
-    type StablePtr = TokenTypePtr;
-    type Green = TokenTypeGreen;
+impl TypedSyntaxNode for TokenTrait {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenTrait);
+    type StablePtr = TokenTraitPtr;
+    type Green = TokenTraitGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenTypeGreen(db.intern_green(GreenNode {
+        TokenTraitGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -9072,7 +11304,7 @@ impl TypedSyntaxNode for TokenType {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenType)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenTrait)
             }
         }
     }
@@ -9083,27 +11315,27 @@ impl TypedSyntaxNode for TokenType {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenTypePtr(self.node.0.stable_ptr)
+        TokenTraitPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalType {
+pub struct TerminalTrait {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalType {
-    const KIND: SyntaxKind = SyntaxKind::TerminalType;
-    type TokenType = TokenType;
+impl Terminal for TerminalTrait {
+    const KIND: SyntaxKind = SyntaxKind::TerminalTrait;
+    type TokenType = TokenTrait;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalType as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalTrait as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalTypeGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalType,
+        TerminalTraitGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalTrait,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -9111,37 +11343,37 @@ impl Terminal for TerminalType {
         self.token(db).text(db)
     }
 }
-impl TerminalType {
+impl TerminalTrait {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenType {
-        TokenType::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenTrait {
+        TokenTrait::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalTypePtr(pub SyntaxStablePtrId);
-impl TerminalTypePtr {
+pub struct TerminalTraitPtr(pub SyntaxStablePtrId);
+impl TerminalTraitPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalTypeGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalType {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalType);
-    type StablePtr = TerminalTypePtr;
-    type Green = TerminalTypeGreen;
+pub struct TerminalTraitGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalTrait {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalTrait);
+    type StablePtr = TerminalTraitPtr;
+    type Green = TerminalTraitGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalTypeGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalType,
+        TerminalTraitGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalTrait,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenType::missing(db).0,
+                    TokenTrait::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -9152,10 +11384,10 @@ impl TypedSyntaxNode for TerminalType {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalType,
+            SyntaxKind::TerminalTrait,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalType
+            SyntaxKind::TerminalTrait
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9167,17 +11399,17 @@ impl TypedSyntaxNode for TerminalType {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalTypePtr(self.node.0.stable_ptr)
+        TerminalTraitPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenFunction {
+pub struct TokenImpl {
     node: SyntaxNode,
 }
-impl Token for TokenFunction {
+impl Token for TokenImpl {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenFunction,
+        TokenImplGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenImpl,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -9186,25 +11418,25 @@ impl Token for TokenFunction {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenFunctionPtr(pub SyntaxStablePtrId);
-impl TokenFunctionPtr {
+pub struct TokenImplPtr(pub SyntaxStablePtrId);
+impl TokenImplPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenFunctionGreen(pub GreenId);
-impl TokenFunctionGreen {
+pub struct TokenImplGreen(pub GreenId);
+impl TokenImplGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenFunction {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenFunction);
-    type StablePtr = TokenFunctionPtr;
-    type Green = TokenFunctionGreen;
+impl TypedSyntaxNode for TokenImpl {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenImpl);
+    type StablePtr = TokenImplPtr;
+    type Green = TokenImplGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenFunctionGreen(db.intern_green(GreenNode {
+        TokenImplGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -9213,7 +11445,7 @@ impl TypedSyntaxNode for TokenFunction {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenFunction)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenImpl)
             }
         }
     }
@@ -9224,27 +11456,27 @@ impl TypedSyntaxNode for TokenFunction {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenFunctionPtr(self.node.0.stable_ptr)
+        TokenImplPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalFunction {
+pub struct TerminalImpl {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalFunction {
-    const KIND: SyntaxKind = SyntaxKind::TerminalFunction;
-    type TokenType = TokenFunction;
+impl Terminal for TerminalImpl {
+    const KIND: SyntaxKind = SyntaxKind::TerminalImpl;
+    type TokenType = TokenImpl;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalFunction as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalImpl as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalFunction,
+        TerminalImplGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalImpl,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -9252,37 +11484,37 @@ impl Terminal for TerminalFunction {
         self.token(db).text(db)
     }
 }
-impl TerminalFunction {
+impl TerminalImpl {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenFunction {
-        TokenFunction::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenImpl {
+        TokenImpl::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalFunctionPtr(pub SyntaxStablePtrId);
-impl TerminalFunctionPtr {
+pub struct TerminalImplPtr(pub SyntaxStablePtrId);
+impl TerminalImplPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalFunctionGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalFunction {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalFunction);
-    type StablePtr = TerminalFunctionPtr;
-    type Green = TerminalFunctionGreen;
+pub struct TerminalImplGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalImpl {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalImpl);
+    type StablePtr = TerminalImplPtr;
+    type Green = TerminalImplGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalFunction,
+        TerminalImplGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalImpl,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenFunction::missing(db).0,
+                    TokenImpl::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -9293,10 +11525,10 @@ impl TypedSyntaxNode for TerminalFunction {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalFunction,
+            SyntaxKind::TerminalImpl,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalFunction
+            SyntaxKind::TerminalImpl
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9308,17 +11540,17 @@ impl TypedSyntaxNode for TerminalFunction {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalFunctionPtr(self.node.0.stable_ptr)
+        TerminalImplPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenModule {
+pub struct TokenOf {
     node: SyntaxNode,
 }
-impl Token for TokenModule {
+impl Token for TokenOf {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenModuleGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenModule,
+        TokenOfGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenOf,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -9327,25 +11559,25 @@ impl Token for TokenModule {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenModulePtr(pub SyntaxStablePtrId);
-impl TokenModulePtr {
+pub struct TokenOfPtr(pub SyntaxStablePtrId);
+impl TokenOfPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenModuleGreen(pub GreenId);
-impl TokenModuleGreen {
+pub struct TokenOfGreen(pub GreenId);
+impl TokenOfGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenModule {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenModule);
-    type StablePtr = TokenModulePtr;
-    type Green = TokenModuleGreen;
+impl TypedSyntaxNode for TokenOf {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenOf);
+    type StablePtr = TokenOfPtr;
+    type Green = TokenOfGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenModuleGreen(db.intern_green(GreenNode {
+        TokenOfGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -9354,7 +11586,7 @@ impl TypedSyntaxNode for TokenModule {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenModule)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenOf)
             }
         }
     }
@@ -9365,27 +11597,27 @@ impl TypedSyntaxNode for TokenModule {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenModulePtr(self.node.0.stable_ptr)
+        TokenOfPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalModule {
+pub struct TerminalOf {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalModule {
-    const KIND: SyntaxKind = SyntaxKind::TerminalModule;
-    type TokenType = TokenModule;
+impl Terminal for TerminalOf {
+    const KIND: SyntaxKind = SyntaxKind::TerminalOf;
+    type TokenType = TokenOf;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalModule as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalOf as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalModuleGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalModule,
+        TerminalOfGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalOf,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -9393,37 +11625,37 @@ impl Terminal for TerminalModule {
         self.token(db).text(db)
     }
 }
-impl TerminalModule {
+impl TerminalOf {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenModule {
-        TokenModule::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenOf {
+        TokenOf::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalModulePtr(pub SyntaxStablePtrId);
-impl TerminalModulePtr {
+pub struct TerminalOfPtr(pub SyntaxStablePtrId);
+impl TerminalOfPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalModuleGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalModule {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalModule);
-    type StablePtr = TerminalModulePtr;
-    type Green = TerminalModuleGreen;
+pub struct TerminalOfGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalOf {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalOf);
+    type StablePtr = TerminalOfPtr;
+    type Green = TerminalOfGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalModuleGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalModule,
+        TerminalOfGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalOf,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenModule::missing(db).0,
+                    TokenOf::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -9434,10 +11666,10 @@ impl TypedSyntaxNode for TerminalModule {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalModule,
+            SyntaxKind::TerminalOf,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalModule
+            SyntaxKind::TerminalOf
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9449,17 +11681,17 @@ impl TypedSyntaxNode for TerminalModule {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalModulePtr(self.node.0.stable_ptr)
+        TerminalOfPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenEnum {
+pub struct TokenLet {
     node: SyntaxNode,
 }
-impl Token for TokenEnum {
+impl Token for TokenLet {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenEnumGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenEnum,
+        TokenLetGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenLet,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -9468,25 +11700,25 @@ impl Token for TokenEnum {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenEnumPtr(pub SyntaxStablePtrId);
-impl TokenEnumPtr {
+pub struct TokenLetPtr(pub SyntaxStablePtrId);
+impl TokenLetPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenEnumGreen(pub GreenId);
-impl TokenEnumGreen {
+pub struct TokenLetGreen(pub GreenId);
+impl TokenLetGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenEnum {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenEnum);
-    type StablePtr = TokenEnumPtr;
-    type Green = TokenEnumGreen;
+impl TypedSyntaxNode for TokenLet {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenLet);
+    type StablePtr = TokenLetPtr;
+    type Green = TokenLetGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenEnumGreen(db.intern_green(GreenNode {
+        TokenLetGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -9495,7 +11727,7 @@ impl TypedSyntaxNode for TokenEnum {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenEnum)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenLet)
             }
         }
     }
@@ -9506,27 +11738,27 @@ impl TypedSyntaxNode for TokenEnum {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenEnumPtr(self.node.0.stable_ptr)
+        TokenLetPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalEnum {
+pub struct TerminalLet {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalEnum {
-    const KIND: SyntaxKind = SyntaxKind::TerminalEnum;
-    type TokenType = TokenEnum;
+impl Terminal for TerminalLet {
+    const KIND: SyntaxKind = SyntaxKind::TerminalLet;
+    type TokenType = TokenLet;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalEnum as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalLet as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalEnumGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalEnum,
+        TerminalLetGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLet,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -9534,37 +11766,37 @@ impl Terminal for TerminalEnum {
         self.token(db).text(db)
     }
 }
-impl TerminalEnum {
+impl TerminalLet {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenEnum {
-        TokenEnum::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenLet {
+        TokenLet::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalEnumPtr(pub SyntaxStablePtrId);
-impl TerminalEnumPtr {
+pub struct TerminalLetPtr(pub SyntaxStablePtrId);
+impl TerminalLetPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalEnumGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalEnum {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalEnum);
-    type StablePtr = TerminalEnumPtr;
-    type Green = TerminalEnumGreen;
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalLetGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalLet {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalLet);
+    type StablePtr = TerminalLetPtr;
+    type Green = TerminalLetGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalEnumGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalEnum,
+        TerminalLetGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLet,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenEnum::missing(db).0,
+                    TokenLet::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -9575,10 +11807,10 @@ impl TypedSyntaxNode for TerminalEnum {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalEnum,
+            SyntaxKind::TerminalLet,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalEnum
+            SyntaxKind::TerminalLet
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9590,17 +11822,17 @@ impl TypedSyntaxNode for TerminalEnum {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalEnumPtr(self.node.0.stable_ptr)
+        TerminalLetPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenStruct {
+pub struct TokenReturn {
     node: SyntaxNode,
 }
-impl Token for TokenStruct {
+impl Token for TokenReturn {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenStructGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenStruct,
+        TokenReturnGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenReturn,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -9609,25 +11841,25 @@ impl Token for TokenStruct {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenStructPtr(pub SyntaxStablePtrId);
-impl TokenStructPtr {
+pub struct TokenReturnPtr(pub SyntaxStablePtrId);
+impl TokenReturnPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenStructGreen(pub GreenId);
-impl TokenStructGreen {
+pub struct TokenReturnGreen(pub GreenId);
+impl TokenReturnGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenStruct {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenStruct);
-    type StablePtr = TokenStructPtr;
-    type Green = TokenStructGreen;
+impl TypedSyntaxNode for TokenReturn {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenReturn);
+    type StablePtr = TokenReturnPtr;
+    type Green = TokenReturnGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenStructGreen(db.intern_green(GreenNode {
+        TokenReturnGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -9636,7 +11868,7 @@ impl TypedSyntaxNode for TokenStruct {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenStruct)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenReturn)
             }
         }
     }
@@ -9647,27 +11879,27 @@ impl TypedSyntaxNode for TokenStruct {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenStructPtr(self.node.0.stable_ptr)
+        TokenReturnPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalStruct {
+pub struct TerminalReturn {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalStruct {
-    const KIND: SyntaxKind = SyntaxKind::TerminalStruct;
-    type TokenType = TokenStruct;
+impl Terminal for TerminalReturn {
+    const KIND: SyntaxKind = SyntaxKind::TerminalReturn;
+    type TokenType = TokenReturn;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalStruct as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalReturn as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalStructGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalStruct,
+        TerminalReturnGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalReturn,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -9675,37 +11907,37 @@ impl Terminal for TerminalStruct {
         self.token(db).text(db)
     }
 }
-impl TerminalStruct {
+impl TerminalReturn {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenStruct {
-        TokenStruct::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenReturn {
+        TokenReturn::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalStructPtr(pub SyntaxStablePtrId);
-impl TerminalStructPtr {
+pub struct TerminalReturnPtr(pub SyntaxStablePtrId);
+impl TerminalReturnPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalStructGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalStruct {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalStruct);
-    type StablePtr = TerminalStructPtr;
-    type Green = TerminalStructGreen;
+pub struct TerminalReturnGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalReturn {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalReturn);
+    type StablePtr = TerminalReturnPtr;
+    type Green = TerminalReturnGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalStructGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalStruct,
+        TerminalReturnGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalReturn,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenStruct::missing(db).0,
+                    TokenReturn::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -9716,10 +11948,10 @@ impl TypedSyntaxNode for TerminalStruct {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalStruct,
+            SyntaxKind::TerminalReturn,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalStruct
+            SyntaxKind::TerminalReturn
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9731,17 +11963,17 @@ impl TypedSyntaxNode for TerminalStruct {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalStructPtr(self.node.0.stable_ptr)
+        TerminalReturnPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenTrait {
+pub struct TokenMatch {
     node: SyntaxNode,
 }
-impl Token for TokenTrait {
+impl Token for TokenMatch {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenTraitGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenTrait,
+        TokenMatchGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMatch,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -9750,25 +11982,25 @@ impl Token for TokenTrait {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenTraitPtr(pub SyntaxStablePtrId);
-impl TokenTraitPtr {
+pub struct TokenMatchPtr(pub SyntaxStablePtrId);
+impl TokenMatchPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenTraitGreen(pub GreenId);
-impl TokenTraitGreen {
+pub struct TokenMatchGreen(pub GreenId);
+impl TokenMatchGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenTrait {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenTrait);
-    type StablePtr = TokenTraitPtr;
-    type Green = TokenTraitGreen;
+impl TypedSyntaxNode for TokenMatch {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenMatch);
+    type StablePtr = TokenMatchPtr;
+    type Green = TokenMatchGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenTraitGreen(db.intern_green(GreenNode {
+        TokenMatchGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -9777,7 +12009,7 @@ impl TypedSyntaxNode for TokenTrait {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenTrait)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenMatch)
             }
         }
     }
@@ -9788,27 +12020,27 @@ impl TypedSyntaxNode for TokenTrait {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenTraitPtr(self.node.0.stable_ptr)
+        TokenMatchPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalTrait {
+pub struct TerminalMatch {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalTrait {
-    const KIND: SyntaxKind = SyntaxKind::TerminalTrait;
-    type TokenType = TokenTrait;
+impl Terminal for TerminalMatch {
+    const KIND: SyntaxKind = SyntaxKind::TerminalMatch;
+    type TokenType = TokenMatch;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalTrait as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalMatch as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalTraitGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalTrait,
+        TerminalMatchGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalMatch,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -9816,37 +12048,37 @@ impl Terminal for TerminalTrait {
         self.token(db).text(db)
     }
 }
-impl TerminalTrait {
+impl TerminalMatch {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenTrait {
-        TokenTrait::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenMatch {
+        TokenMatch::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalTraitPtr(pub SyntaxStablePtrId);
-impl TerminalTraitPtr {
+pub struct TerminalMatchPtr(pub SyntaxStablePtrId);
+impl TerminalMatchPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalTraitGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalTrait {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalTrait);
-    type StablePtr = TerminalTraitPtr;
-    type Green = TerminalTraitGreen;
+pub struct TerminalMatchGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalMatch {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalMatch);
+    type StablePtr = TerminalMatchPtr;
+    type Green = TerminalMatchGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalTraitGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalTrait,
+        TerminalMatchGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalMatch,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenTrait::missing(db).0,
+                    TokenMatch::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -9857,10 +12089,10 @@ impl TypedSyntaxNode for TerminalTrait {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalTrait,
+            SyntaxKind::TerminalMatch,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalTrait
+            SyntaxKind::TerminalMatch
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9872,17 +12104,17 @@ impl TypedSyntaxNode for TerminalTrait {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalTraitPtr(self.node.0.stable_ptr)
+        TerminalMatchPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenImpl {
+pub struct TokenIf {
     node: SyntaxNode,
 }
-impl Token for TokenImpl {
+impl Token for TokenIf {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenImplGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenImpl,
+        TokenIfGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenIf,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -9891,25 +12123,25 @@ impl Token for TokenImpl {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenImplPtr(pub SyntaxStablePtrId);
-impl TokenImplPtr {
+pub struct TokenIfPtr(pub SyntaxStablePtrId);
+impl TokenIfPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenImplGreen(pub GreenId);
-impl TokenImplGreen {
+pub struct TokenIfGreen(pub GreenId);
+impl TokenIfGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenImpl {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenImpl);
-    type StablePtr = TokenImplPtr;
-    type Green = TokenImplGreen;
+impl TypedSyntaxNode for TokenIf {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenIf);
+    type StablePtr = TokenIfPtr;
+    type Green = TokenIfGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenImplGreen(db.intern_green(GreenNode {
+        TokenIfGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -9918,7 +12150,7 @@ impl TypedSyntaxNode for TokenImpl {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenImpl)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenIf)
             }
         }
     }
@@ -9929,27 +12161,27 @@ impl TypedSyntaxNode for TokenImpl {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenImplPtr(self.node.0.stable_ptr)
+        TokenIfPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalImpl {
+pub struct TerminalIf {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalImpl {
-    const KIND: SyntaxKind = SyntaxKind::TerminalImpl;
-    type TokenType = TokenImpl;
+impl Terminal for TerminalIf {
+    const KIND: SyntaxKind = SyntaxKind::TerminalIf;
+    type TokenType = TokenIf;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalImpl as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalIf as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalImplGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalImpl,
+        TerminalIfGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalIf,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -9957,37 +12189,37 @@ impl Terminal for TerminalImpl {
         self.token(db).text(db)
     }
 }
-impl TerminalImpl {
+impl TerminalIf {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenImpl {
-        TokenImpl::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenIf {
+        TokenIf::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalImplPtr(pub SyntaxStablePtrId);
-impl TerminalImplPtr {
+pub struct TerminalIfPtr(pub SyntaxStablePtrId);
+impl TerminalIfPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalImplGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalImpl {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalImpl);
-    type StablePtr = TerminalImplPtr;
-    type Green = TerminalImplGreen;
+pub struct TerminalIfGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalIf {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalIf);
+    type StablePtr = TerminalIfPtr;
+    type Green = TerminalIfGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalImplGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalImpl,
+        TerminalIfGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalIf,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenImpl::missing(db).0,
+                    TokenIf::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -9998,10 +12230,10 @@ impl TypedSyntaxNode for TerminalImpl {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalImpl,
+            SyntaxKind::TerminalIf,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalImpl
+            SyntaxKind::TerminalIf
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -10013,17 +12245,17 @@ impl TypedSyntaxNode for TerminalImpl {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalImplPtr(self.node.0.stable_ptr)
+        TerminalIfPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenOf {
+pub struct TokenElse {
     node: SyntaxNode,
 }
-impl Token for TokenOf {
+impl Token for TokenElse {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenOfGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenOf,
+        TokenElseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenElse,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -10032,25 +12264,25 @@ impl Token for TokenOf {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenOfPtr(pub SyntaxStablePtrId);
-impl TokenOfPtr {
+pub struct TokenElsePtr(pub SyntaxStablePtrId);
+impl TokenElsePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenOfGreen(pub GreenId);
-impl TokenOfGreen {
+pub struct TokenElseGreen(pub GreenId);
+impl TokenElseGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenOf {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenOf);
-    type StablePtr = TokenOfPtr;
-    type Green = TokenOfGreen;
+impl TypedSyntaxNode for TokenElse {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenElse);
+    type StablePtr = TokenElsePtr;
+    type Green = TokenElseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenOfGreen(db.intern_green(GreenNode {
+        TokenElseGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -10059,7 +12291,7 @@ impl TypedSyntaxNode for TokenOf {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenOf)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenElse)
             }
         }
     }
@@ -10070,27 +12302,27 @@ impl TypedSyntaxNode for TokenOf {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenOfPtr(self.node.0.stable_ptr)
+        TokenElsePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalOf {
+pub struct TerminalElse {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalOf {
-    const KIND: SyntaxKind = SyntaxKind::TerminalOf;
-    type TokenType = TokenOf;
+impl Terminal for TerminalElse {
+    const KIND: SyntaxKind = SyntaxKind::TerminalElse;
+    type TokenType = TokenElse;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalOf as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalElse as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalOfGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalOf,
+        TerminalElseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalElse,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -10098,37 +12330,37 @@ impl Terminal for TerminalOf {
         self.token(db).text(db)
     }
 }
-impl TerminalOf {
+impl TerminalElse {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenOf {
-        TokenOf::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenElse {
+        TokenElse::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalOfPtr(pub SyntaxStablePtrId);
-impl TerminalOfPtr {
+pub struct TerminalElsePtr(pub SyntaxStablePtrId);
+impl TerminalElsePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalOfGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalOf {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalOf);
-    type StablePtr = TerminalOfPtr;
-    type Green = TerminalOfGreen;
+pub struct TerminalElseGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalElse {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalElse);
+    type StablePtr = TerminalElsePtr;
+    type Green = TerminalElseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalOfGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalOf,
+        TerminalElseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalElse,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenOf::missing(db).0,
+                    TokenElse::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -10139,10 +12371,10 @@ impl TypedSyntaxNode for TerminalOf {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalOf,
+            SyntaxKind::TerminalElse,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalOf
+            SyntaxKind::TerminalElse
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -10154,17 +12386,17 @@ impl TypedSyntaxNode for TerminalOf {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalOfPtr(self.node.0.stable_ptr)
+        TerminalElsePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenLet {
+pub struct TokenLoop {
     node: SyntaxNode,
 }
-impl Token for TokenLet {
+impl Token for TokenLoop {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenLetGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenLet,
+        TokenLoopGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenLoop,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -10173,25 +12405,25 @@ impl Token for TokenLet {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenLetPtr(pub SyntaxStablePtrId);
-impl TokenLetPtr {
+pub struct TokenLoopPtr(pub SyntaxStablePtrId);
+impl TokenLoopPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenLetGreen(pub GreenId);
-impl TokenLetGreen {
+pub struct TokenLoopGreen(pub GreenId);
+impl TokenLoopGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenLet {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenLet);
-    type StablePtr = TokenLetPtr;
-    type Green = TokenLetGreen;
+impl TypedSyntaxNode for TokenLoop {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenLoop);
+    type StablePtr = TokenLoopPtr;
+    type Green = TokenLoopGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenLetGreen(db.intern_green(GreenNode {
+        TokenLoopGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -10200,7 +12432,7 @@ impl TypedSyntaxNode for TokenLet {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenLet)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenLoop)
             }
         }
     }
@@ -10211,27 +12443,27 @@ impl TypedSyntaxNode for TokenLet {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenLetPtr(self.node.0.stable_ptr)
+        TokenLoopPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalLet {
+pub struct TerminalLoop {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalLet {
-    const KIND: SyntaxKind = SyntaxKind::TerminalLet;
-    type TokenType = TokenLet;
+impl Terminal for TerminalLoop {
+    const KIND: SyntaxKind = SyntaxKind::TerminalLoop;
+    type TokenType = TokenLoop;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalLet as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalLoop as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalLetGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalLet,
+        TerminalLoopGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLoop,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -10239,37 +12471,37 @@ impl Terminal for TerminalLet {
         self.token(db).text(db)
     }
 }
-impl TerminalLet {
+impl TerminalLoop {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenLet {
-        TokenLet::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenLoop {
+        TokenLoop::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalLetPtr(pub SyntaxStablePtrId);
-impl TerminalLetPtr {
+pub struct TerminalLoopPtr(pub SyntaxStablePtrId);
+impl TerminalLoopPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalLetGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalLet {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalLet);
-    type StablePtr = TerminalLetPtr;
-    type Green = TerminalLetGreen;
+pub struct TerminalLoopGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalLoop {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalLoop);
+    type StablePtr = TerminalLoopPtr;
+    type Green = TerminalLoopGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalLetGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalLet,
+        TerminalLoopGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLoop,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenLet::missing(db).0,
+                    TokenLoop::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -10280,10 +12512,10 @@ impl TypedSyntaxNode for TerminalLet {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalLet,
+            SyntaxKind::TerminalLoop,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalLet
+            SyntaxKind::TerminalLoop
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -10295,17 +12527,17 @@ impl TypedSyntaxNode for TerminalLet {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalLetPtr(self.node.0.stable_ptr)
+        TerminalLoopPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenReturn {
+pub struct TokenWhile {
     node: SyntaxNode,
 }
-impl Token for TokenReturn {
+impl Token for TokenWhile {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenReturnGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenReturn,
+        TokenWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenWhile,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -10314,25 +12546,25 @@ impl Token for TokenReturn {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenReturnPtr(pub SyntaxStablePtrId);
-impl TokenReturnPtr {
+pub struct TokenWhilePtr(pub SyntaxStablePtrId);
+impl TokenWhilePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenReturnGreen(pub GreenId);
-impl TokenReturnGreen {
+pub struct TokenWhileGreen(pub GreenId);
+impl TokenWhileGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenReturn {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenReturn);
-    type StablePtr = TokenReturnPtr;
-    type Green = TokenReturnGreen;
+impl TypedSyntaxNode for TokenWhile {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenWhile);
+    type StablePtr = TokenWhilePtr;
+    type Green = TokenWhileGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenReturnGreen(db.intern_green(GreenNode {
+        TokenWhileGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -10341,7 +12573,7 @@ impl TypedSyntaxNode for TokenReturn {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenReturn)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenWhile)
             }
         }
     }
@@ -10352,27 +12584,27 @@ impl TypedSyntaxNode for TokenReturn {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenReturnPtr(self.node.0.stable_ptr)
+        TokenWhilePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalReturn {
+pub struct TerminalWhile {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalReturn {
-    const KIND: SyntaxKind = SyntaxKind::TerminalReturn;
-    type TokenType = TokenReturn;
+impl Terminal for TerminalWhile {
+    const KIND: SyntaxKind = SyntaxKind::TerminalWhile;
+    type TokenType = TokenWhile;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalReturn as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalWhile as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalReturnGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalReturn,
+        TerminalWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalWhile,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -10380,37 +12612,37 @@ impl Terminal for TerminalReturn {
         self.token(db).text(db)
     }
 }
-impl TerminalReturn {
+impl TerminalWhile {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenReturn {
-        TokenReturn::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenWhile {
+        TokenWhile::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalReturnPtr(pub SyntaxStablePtrId);
-impl TerminalReturnPtr {
+pub struct TerminalWhilePtr(pub SyntaxStablePtrId);
+impl TerminalWhilePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalReturnGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalReturn {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalReturn);
-    type StablePtr = TerminalReturnPtr;
-    type Green = TerminalReturnGreen;
+pub struct TerminalWhileGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalWhile {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalWhile);
+    type StablePtr = TerminalWhilePtr;
+    type Green = TerminalWhileGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalReturnGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalReturn,
+        TerminalWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalWhile,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenReturn::missing(db).0,
+                    TokenWhile::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -10421,10 +12653,10 @@ impl TypedSyntaxNode for TerminalReturn {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalReturn,
+            SyntaxKind::TerminalWhile,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalReturn
+            SyntaxKind::TerminalWhile
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -10436,17 +12668,17 @@ impl TypedSyntaxNode for TerminalReturn {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalReturnPtr(self.node.0.stable_ptr)
+        TerminalWhilePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenMatch {
+pub struct TokenBreak {
     node: SyntaxNode,
 }
-impl Token for TokenMatch {
+impl Token for TokenBreak {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenMatchGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenMatch,
+        TokenBreakGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenBreak,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -10455,25 +12687,25 @@ impl Token for TokenMatch {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenMatchPtr(pub SyntaxStablePtrId);
-impl TokenMatchPtr {
+pub struct TokenBreakPtr(pub SyntaxStablePtrId);
+impl TokenBreakPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenMatchGreen(pub GreenId);
-impl TokenMatchGreen {
+pub struct TokenBreakGreen(pub GreenId);
+impl TokenBreakGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenMatch {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenMatch);
-    type StablePtr = TokenMatchPtr;
-    type Green = TokenMatchGreen;
+impl TypedSyntaxNode for TokenBreak {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenBreak);
+    type StablePtr = TokenBreakPtr;
+    type Green = TokenBreakGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenMatchGreen(db.intern_green(GreenNode {
+        TokenBreakGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -10482,7 +12714,7 @@ impl TypedSyntaxNode for TokenMatch {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenMatch)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenBreak)
             }
         }
     }
@@ -10493,27 +12725,27 @@ impl TypedSyntaxNode for TokenMatch {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenMatchPtr(self.node.0.stable_ptr)
+        TokenBreakPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalMatch {
+pub struct TerminalBreak {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalMatch {
-    const KIND: SyntaxKind = SyntaxKind::TerminalMatch;
-    type TokenType = TokenMatch;
+impl Terminal for TerminalBreak {
+    const KIND: SyntaxKind = SyntaxKind::TerminalBreak;
+    type TokenType = TokenBreak;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalMatch as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalBreak as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalMatchGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalMatch,
+        TerminalBreakGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalBreak,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -10521,37 +12753,37 @@ impl Terminal for TerminalMatch {
         self.token(db).text(db)
     }
 }
-impl TerminalMatch {
+impl TerminalBreak {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenMatch {
-        TokenMatch::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenBreak {
+        TokenBreak::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalMatchPtr(pub SyntaxStablePtrId);
-impl TerminalMatchPtr {
+pub struct TerminalBreakPtr(pub SyntaxStablePtrId);
+impl TerminalBreakPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalMatchGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalMatch {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalMatch);
-    type StablePtr = TerminalMatchPtr;
-    type Green = TerminalMatchGreen;
+pub struct TerminalBreakGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalBreak {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalBreak);
+    type StablePtr = TerminalBreakPtr;
+    type Green = TerminalBreakGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalMatchGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalMatch,
+        TerminalBreakGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalBreak,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenMatch::missing(db).0,
+                    TokenBreak::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -10562,10 +12794,10 @@ impl TypedSyntaxNode for TerminalMatch {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalMatch,
+            SyntaxKind::TerminalBreak,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalMatch
+            SyntaxKind::TerminalBreak
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -10577,17 +12809,17 @@ impl TypedSyntaxNode for TerminalMatch {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalMatchPtr(self.node.0.stable_ptr)
+        TerminalBreakPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenIf {
+pub struct TokenContinue {
     node: SyntaxNode,
 }
-impl Token for TokenIf {
+impl Token for TokenContinue {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenIfGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenIf,
+        TokenContinueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenContinue,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -10596,25 +12828,25 @@ impl Token for TokenIf {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenIfPtr(pub SyntaxStablePtrId);
-impl TokenIfPtr {
+pub struct TokenContinuePtr(pub SyntaxStablePtrId);
+impl TokenContinuePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenIfGreen(pub GreenId);
-impl TokenIfGreen {
+pub struct TokenContinueGreen(pub GreenId);
+impl TokenContinueGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenIf {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenIf);
-    type StablePtr = TokenIfPtr;
-    type Green = TokenIfGreen;
+impl TypedSyntaxNode for TokenContinue {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenContinue);
+    type StablePtr = TokenContinuePtr;
+    type Green = TokenContinueGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenIfGreen(db.intern_green(GreenNode {
+        TokenContinueGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -10623,7 +12855,7 @@ impl TypedSyntaxNode for TokenIf {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenIf)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenContinue)
             }
         }
     }
@@ -10634,27 +12866,27 @@ impl TypedSyntaxNode for TokenIf {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenIfPtr(self.node.0.stable_ptr)
+        TokenContinuePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalIf {
+pub struct TerminalContinue {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalIf {
-    const KIND: SyntaxKind = SyntaxKind::TerminalIf;
-    type TokenType = TokenIf;
+impl Terminal for TerminalContinue {
+    const KIND: SyntaxKind = SyntaxKind::TerminalContinue;
+    type TokenType = TokenContinue;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalIf as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalContinue as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalIfGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalIf,
+        TerminalContinueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalContinue,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -10662,37 +12894,37 @@ impl Terminal for TerminalIf {
         self.token(db).text(db)
     }
 }
-impl TerminalIf {
+impl TerminalContinue {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenIf {
-        TokenIf::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenContinue {
+        TokenContinue::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalIfPtr(pub SyntaxStablePtrId);
-impl TerminalIfPtr {
+pub struct TerminalContinuePtr(pub SyntaxStablePtrId);
+impl TerminalContinuePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalIfGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalIf {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalIf);
-    type StablePtr = TerminalIfPtr;
-    type Green = TerminalIfGreen;
+pub struct TerminalContinueGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalContinue {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalContinue);
+    type StablePtr = TerminalContinuePtr;
+    type Green = TerminalContinueGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalIfGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalIf,
+        TerminalContinueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalContinue,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenIf::missing(db).0,
+                    TokenContinue::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -10703,10 +12935,10 @@ impl TypedSyntaxNode for TerminalIf {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalIf,
+            SyntaxKind::TerminalContinue,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalIf
+            SyntaxKind::TerminalContinue
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -10718,17 +12950,17 @@ impl TypedSyntaxNode for TerminalIf {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalIfPtr(self.node.0.stable_ptr)
+        TerminalContinuePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenElse {
+pub struct TokenConst {
     node: SyntaxNode,
 }
-impl Token for TokenElse {
+impl Token for TokenConst {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenElseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenElse,
+        TokenConstGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenConst,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -10737,25 +12969,25 @@ impl Token for TokenElse {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenElsePtr(pub SyntaxStablePtrId);
-impl TokenElsePtr {
+pub struct TokenConstPtr(pub SyntaxStablePtrId);
+impl TokenConstPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenElseGreen(pub GreenId);
-impl TokenElseGreen {
+pub struct TokenConstGreen(pub GreenId);
+impl TokenConstGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenElse {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenElse);
-    type StablePtr = TokenElsePtr;
-    type Green = TokenElseGreen;
+impl TypedSyntaxNode for TokenConst {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenConst);
+    type StablePtr = TokenConstPtr;
+    type Green = TokenConstGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenElseGreen(db.intern_green(GreenNode {
+        TokenConstGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -10764,7 +12996,7 @@ impl TypedSyntaxNode for TokenElse {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenElse)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenConst)
             }
         }
     }
@@ -10775,27 +13007,27 @@ impl TypedSyntaxNode for TokenElse {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenElsePtr(self.node.0.stable_ptr)
+        TokenConstPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalElse {
+pub struct TerminalConst {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalElse {
-    const KIND: SyntaxKind = SyntaxKind::TerminalElse;
-    type TokenType = TokenElse;
+impl Terminal for TerminalConst {
+    const KIND: SyntaxKind = SyntaxKind::TerminalConst;
+    type TokenType = TokenConst;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalElse as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalConst as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalElseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalElse,
+        TerminalConstGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalConst,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -10803,37 +13035,37 @@ impl Terminal for TerminalElse {
         self.token(db).text(db)
     }
 }
-impl TerminalElse {
+impl TerminalConst {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenElse {
-        TokenElse::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenConst {
+        TokenConst::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalElsePtr(pub SyntaxStablePtrId);
-impl TerminalElsePtr {
+pub struct TerminalConstPtr(pub SyntaxStablePtrId);
+impl TerminalConstPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalElseGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalElse {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalElse);
-    type StablePtr = TerminalElsePtr;
-    type Green = TerminalElseGreen;
+pub struct TerminalConstGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalConst {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalConst);
+    type StablePtr = TerminalConstPtr;
+    type Green = TerminalConstGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalElseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalElse,
+        TerminalConstGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalConst,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenElse::missing(db).0,
+                    TokenConst::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -10844,10 +13076,10 @@ impl TypedSyntaxNode for TerminalElse {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalElse,
+            SyntaxKind::TerminalConst,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalElse
+            SyntaxKind::TerminalConst
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -10859,7 +13091,7 @@ impl TypedSyntaxNode for TerminalElse {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalElsePtr(self.node.0.stable_ptr)
+        TerminalConstPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -26,6 +26,8 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::ExprIf => vec![],
         SyntaxKind::ElseClause => vec![],
         SyntaxKind::OptionElseClauseEmpty => vec![],
+        SyntaxKind::ExprLoop => vec![],
+        SyntaxKind::ExprWhile => vec![],
         SyntaxKind::ExprErrorPropagate => vec![],
         SyntaxKind::StructArgExpr => vec![],
         SyntaxKind::OptionStructArgExprEmpty => vec![],
@@ -50,6 +52,10 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::OptionTerminalSemicolonEmpty => vec![],
         SyntaxKind::StatementExpr => vec![],
         SyntaxKind::StatementReturn => vec![],
+        SyntaxKind::ExprClause => vec![],
+        SyntaxKind::OptionExprClauseEmpty => vec![],
+        SyntaxKind::StatementBreak => vec![],
+        SyntaxKind::StatementContinue => vec![],
         SyntaxKind::Param => vec![/* name */ children[1]],
         SyntaxKind::ModifierList => vec![],
         SyntaxKind::ParamList => vec![],
@@ -67,6 +73,10 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::OptionAttributeArgsEmpty => vec![],
         SyntaxKind::AttributeArgs => vec![],
         SyntaxKind::AttributeArgList => vec![],
+        SyntaxKind::AttributeArg => vec![],
+        SyntaxKind::OptionAttributeArgNameEmpty => vec![],
+        SyntaxKind::AttributeArgName => vec![],
+        SyntaxKind::ItemConstant => vec![/* name */ children[2]],
         SyntaxKind::ItemFreeFunction => vec![/* name */ children[2]],
         SyntaxKind::ItemExternFunction => vec![/* name */ children[3]],
         SyntaxKind::ItemExternType => vec![/* name */ children[2]],
@@ -85,10 +95,17 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::WrappedGenericParamList => vec![],
         SyntaxKind::GenericParamList => vec![],
         SyntaxKind::GenericParam => vec![/* name */ children[0]],
+        SyntaxKind::OptionGenericParamBoundsEmpty => vec![],
+        SyntaxKind::GenericParamBounds => vec![],
+        SyntaxKind::GenericParamBoundList => vec![],
         SyntaxKind::TokenIdentifier => vec![],
         SyntaxKind::TerminalIdentifier => vec![],
         SyntaxKind::TokenLiteralNumber => vec![],
         SyntaxKind::TerminalLiteralNumber => vec![],
+        SyntaxKind::TokenShortString => vec![],
+        SyntaxKind::TerminalShortString => vec![],
+        SyntaxKind::TokenString => vec![],
+        SyntaxKind::TerminalString => vec![],
         SyntaxKind::TokenFalse => vec![],
         SyntaxKind::TerminalFalse => vec![],
         SyntaxKind::TokenTrue => vec![],
@@ -121,6 +138,16 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::TerminalIf => vec![],
         SyntaxKind::TokenElse => vec![],
         SyntaxKind::TerminalElse => vec![],
+        SyntaxKind::TokenLoop => vec![],
+        SyntaxKind::TerminalLoop => vec![],
+        SyntaxKind::TokenWhile => vec![],
+        SyntaxKind::TerminalWhile => vec![],
+        SyntaxKind::TokenBreak => vec![],
+        SyntaxKind::TerminalBreak => vec![],
+        SyntaxKind::TokenContinue => vec![],
+        SyntaxKind::TerminalContinue => vec![],
+        SyntaxKind::TokenConst => vec![],
+        SyntaxKind::TerminalConst => vec![],
         SyntaxKind::TokenUse => vec![],
         SyntaxKind::TerminalUse => vec![],
         SyntaxKind::TokenImplicits => vec![],
@@ -22,6 +22,8 @@ pub enum SyntaxKind {
     ExprIf,
     ElseClause,
     OptionElseClauseEmpty,
+    ExprLoop,
+    ExprWhile,
     ExprErrorPropagate,
     StructArgExpr,
     OptionStructArgExprEmpty,
@@ -46,6 +48,10 @@ pub enum SyntaxKind {
     OptionTerminalSemicolonEmpty,
     StatementExpr,
     StatementReturn,
+    ExprClause,
+    OptionExprClauseEmpty,
+    StatementBreak,
+    StatementContinue,
     Param,
     ModifierList,
     ParamList,
@@ -63,6 +69,10 @@ pub enum SyntaxKind {
     OptionAttributeArgsEmpty,
     AttributeArgs,
     AttributeArgList,
+    AttributeArg,
+    OptionAttributeArgNameEmpty,
+    AttributeArgName,
+    ItemConstant,
     ItemFreeFunction,
     ItemExternFunction,
     ItemExternType,
@@ -81,10 +91,17 @@ pub enum SyntaxKind {
     WrappedGenericParamList,
     GenericParamList,
     GenericParam,
+    OptionGenericParamBoundsEmpty,
+    GenericParamBounds,
+    GenericParamBoundList,
     TokenIdentifier,
     TerminalIdentifier,
     TokenLiteralNumber,
     TerminalLiteralNumber,
+    TokenShortString,
+    TerminalShortString,
+    TokenString,
+    TerminalString,
     TokenFalse,
     TerminalFalse,
     TokenTrue,
@@ -117,6 +134,16 @@ pub enum SyntaxKind {
     TerminalIf,
     TokenElse,
     TerminalElse,
+    TokenLoop,
+    TerminalLoop,
+    TokenWhile,
+    TerminalWhile,
+    TokenBreak,
+    TerminalBreak,
+    TokenContinue,
+    TerminalContinue,
+    TokenConst,
+    TerminalConst,
     TokenUse,
     TerminalUse,
     TokenImplicits,
@@ -212,6 +239,8 @@ impl SyntaxKind {
             *self,
             SyntaxKind::TokenIdentifier
                 | SyntaxKind::TokenLiteralNumber
+                | SyntaxKind::TokenShortString
+                | SyntaxKind::TokenString
                 | SyntaxKind::TokenFalse
                 | SyntaxKind::TokenTrue
                 | SyntaxKind::TokenExtern
@@ -228,6 +257,11 @@ impl SyntaxKind {
                 | SyntaxKind::TokenMatch
                 | SyntaxKind::TokenIf
                 | SyntaxKind::TokenElse
+                | SyntaxKind::TokenLoop
+                | SyntaxKind::TokenWhile
+                | SyntaxKind::TokenBreak
+                | SyntaxKind::TokenContinue
+                | SyntaxKind::TokenConst
                 | SyntaxKind::TokenUse
                 | SyntaxKind::TokenImplicits
                 | SyntaxKind::TokenRef
@@ -281,6 +315,8 @@ impl SyntaxKind {
             *self,
             SyntaxKind::TerminalIdentifier
                 | SyntaxKind::TerminalLiteralNumber
+                | SyntaxKind::TerminalShortString
+                | SyntaxKind::TerminalString
                 | SyntaxKind::TerminalFalse
                 | SyntaxKind::TerminalTrue
                 | SyntaxKind::TerminalExtern
@@ -297,6 +333,11 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalMatch
                 | SyntaxKind::TerminalIf
                 | SyntaxKind::TerminalElse
+                | SyntaxKind::TerminalLoop
+                | SyntaxKind::TerminalWhile
+                | SyntaxKind::TerminalBreak
+                | SyntaxKind::TerminalContinue
+                | SyntaxKind::TerminalConst
                 | SyntaxKind::TerminalUse
                 | SyntaxKind::TerminalImplicits
                 | SyntaxKind::TerminalRef
@@ -359,6 +400,11 @@ impl SyntaxKind {
                 | SyntaxKind::TokenMatch
                 | SyntaxKind::TokenIf
                 | SyntaxKind::TokenElse
+                | SyntaxKind::TokenLoop
+                | SyntaxKind::TokenWhile
+                | SyntaxKind::TokenBreak
+                | SyntaxKind::TokenContinue
+                | SyntaxKind::TokenConst
                 | SyntaxKind::TokenUse
                 | SyntaxKind::TokenImplicits
                 | SyntaxKind::TokenRef
@@ -385,6 +431,11 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalMatch
                 | SyntaxKind::TerminalIf
                 | SyntaxKind::TerminalElse
+                | SyntaxKind::TerminalLoop
+                | SyntaxKind::TerminalWhile
+                | SyntaxKind::TerminalBreak
+                | SyntaxKind::TerminalContinue
+                | SyntaxKind::TerminalConst
                 | SyntaxKind::TerminalUse
                 | SyntaxKind::TerminalImplicits
                 | SyntaxKind::TerminalRef
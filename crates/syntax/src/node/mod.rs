@@ -178,6 +178,14 @@ impl SyntaxNode {
         buffer
     }
 
+    /// Returns all the text under the syntax node, without the leading trivia of its first token
+    /// and the trailing trivia of its last token.
+    pub fn get_text_without_trivia(self, db: &dyn SyntaxGroup) -> String {
+        let offset = self.offset().0;
+        let TextSpan { start, end } = self.span_without_trivia(db);
+        self.get_text(db)[start.0 - offset..end.0 - offset].to_string()
+    }
+
     /// Helper for `get_text` to allocate only a single string.
     fn append_text(self, db: &dyn SyntaxGroup, buffer: &mut String) {
         let node = self.green_node(db);
@@ -17,6 +17,8 @@ pub fn get_spec() -> Vec<Node> {
             .missing("Missing")
             .node("Path")
             .node_with_explicit_kind("Literal", "TerminalLiteralNumber")
+            .node_with_explicit_kind("ShortString", "TerminalShortString")
+            .node_with_explicit_kind("String", "TerminalString")
             .node_with_explicit_kind("False", "TerminalFalse")
             .node_with_explicit_kind("True", "TerminalTrue")
             .node("Parenthesized")
@@ -28,6 +30,8 @@ pub fn get_spec() -> Vec<Node> {
             .node("Block")
             .node("Match")
             .node("If")
+            .node("Loop")
+            .node("While")
             .node("ErrorPropagate"),
     )
     .add_separated_list("ExprList", "Expr", "TerminalComma")
@@ -126,6 +130,15 @@ pub fn get_spec() -> Vec<Node> {
         .node("else_block_or_if", "BlockOrIf")
         )
     .add_option("ElseClause")
+    .add_struct(StructBuilder::new("ExprLoop")
+        .node("loop_kw", "TerminalLoop")
+        .node("body", "ExprBlock")
+        )
+    .add_struct(StructBuilder::new("ExprWhile")
+        .node("while_kw", "TerminalWhile")
+        .node("condition", "Expr")
+        .node("body", "ExprBlock")
+        )
     .add_struct(StructBuilder::new("ExprErrorPropagate").node("expr", "Expr").node("op", "TerminalQuestionMark"))
     // --- Struct ctor ---
     .add_struct(StructBuilder::new("StructArgExpr")
@@ -212,6 +225,8 @@ pub fn get_spec() -> Vec<Node> {
         .node("Let")
         .node("Expr")
         .node("Return")
+        .node("Break")
+        .node("Continue")
         )
     .add_list("StatementList", "Statement")
     .add_struct(StructBuilder::new("StatementMissing"))
@@ -233,6 +248,17 @@ pub fn get_spec() -> Vec<Node> {
         .node("expr", "Expr")
         .node("semicolon", "TerminalSemicolon")
         )
+    .add_struct(StructBuilder::new("ExprClause").node("expr", "Expr"))
+    .add_option("ExprClause")
+    .add_struct(StructBuilder::new("StatementBreak")
+        .node("break_kw", "TerminalBreak")
+        .node("expr_clause", "OptionExprClause")
+        .node("semicolon", "TerminalSemicolon")
+        )
+    .add_struct(StructBuilder::new("StatementContinue")
+        .node("continue_kw", "TerminalContinue")
+        .node("semicolon", "TerminalSemicolon")
+        )
     // --- Functions ---
     .add_enum(EnumBuilder::new("ParamName")
         .node_with_explicit_kind("Underscore", "TerminalUnderscore")
@@ -279,6 +305,7 @@ pub fn get_spec() -> Vec<Node> {
     .add_enum(EnumBuilder::new("Item")
         .node("Module")
         .node("Use")
+        .node("Constant")
         .node("FreeFunction")
         .node("ExternFunction")
         .node("ExternType")
@@ -308,7 +335,25 @@ pub fn get_spec() -> Vec<Node> {
         .node("arg_list", "AttributeArgList")
         .node("rangle", "TerminalRParen")
         )
-    .add_separated_list("AttributeArgList", "Expr", "TerminalComma")
+    .add_separated_list("AttributeArgList", "AttributeArg", "TerminalComma")
+    .add_struct(StructBuilder::new("AttributeArg")
+        .node("name", "OptionAttributeArgName")
+        .node("value", "Expr")
+        )
+    .add_option("AttributeArgName")
+    .add_struct(StructBuilder::new("AttributeArgName")
+        .node("name", "TerminalIdentifier")
+        .node("colon", "TerminalColon")
+        )
+    .add_struct(StructBuilder::new("ItemConstant")
+        .node("attributes" ,"AttributeList")
+        .node("const_kw", "TerminalConst")
+        .key_node("name", "TerminalIdentifier")
+        .node("type_clause", "TypeClause")
+        .node("eq", "TerminalEq")
+        .node("value", "Expr")
+        .node("semicolon", "TerminalSemicolon")
+        )
     .add_struct(StructBuilder::new("ItemFreeFunction")
         .node("attributes" ,"AttributeList")
         .node("function_kw", "TerminalFunction")
@@ -419,11 +464,21 @@ pub fn get_spec() -> Vec<Node> {
         .node("rangle", "TerminalGT")
         )
     .add_separated_list("GenericParamList", "GenericParam", "TerminalComma")
-    // TODO(spapini): Remove this indirection.
-    .add_struct(StructBuilder::new("GenericParam").key_node("name", "TerminalIdentifier"))
+    .add_struct(StructBuilder::new("GenericParam")
+        .key_node("name", "TerminalIdentifier")
+        .node("bounds", "OptionGenericParamBounds")
+        )
+    .add_option("GenericParamBounds")
+    .add_struct(StructBuilder::new("GenericParamBounds")
+        .node("colon", "TerminalColon")
+        .node("traits", "GenericParamBoundList")
+        )
+    .add_separated_list("GenericParamBoundList", "ExprPath", "TerminalPlus")
     // --- Tokens + Terminals ---
     .add_token_and_terminal("Identifier")
     .add_token_and_terminal("LiteralNumber")
+    .add_token_and_terminal("ShortString")
+    .add_token_and_terminal("String")
     .add_keyword_token_and_terminal("False")
     .add_keyword_token_and_terminal("True")
     .add_keyword_token_and_terminal("Extern")
@@ -440,6 +495,11 @@ pub fn get_spec() -> Vec<Node> {
     .add_keyword_token_and_terminal("Match")
     .add_keyword_token_and_terminal("If")
     .add_keyword_token_and_terminal("Else")
+    .add_keyword_token_and_terminal("Loop")
+    .add_keyword_token_and_terminal("While")
+    .add_keyword_token_and_terminal("Break")
+    .add_keyword_token_and_terminal("Continue")
+    .add_keyword_token_and_terminal("Const")
     .add_keyword_token_and_terminal("Use")
     .add_keyword_token_and_terminal("Implicits")
     .add_keyword_token_and_terminal("Ref")
@@ -4,21 +4,26 @@ use std::path::Path;
 use std::sync::Mutex;
 
 use anyhow::{bail, Context};
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use colored::Colorize;
 use compiler::db::RootDatabase;
-use compiler::diagnostics::check_and_eprint_diagnostics;
+use compiler::diagnostics::{check_and_eprint_diagnostics_with_format, MessageFormat};
 use compiler::project::setup_project;
 use debug::DebugWithDb;
 use defs::ids::{FreeFunctionId, GenericFunctionId, ModuleItemId};
 use filesystem::ids::CrateId;
 use itertools::Itertools;
+use num_bigint::BigInt;
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
-use runner::SierraCasmRunner;
+use runner::{RunResultValue, SierraCasmRunner, StackTrace};
 use semantic::db::SemanticGroup;
+use semantic::items::attribute::Attribute;
+use semantic::literals::{short_string_value, LiteralLongId};
 use semantic::{ConcreteFunction, FunctionLongId};
+use serde::Serialize;
 use sierra_generator::db::SierraGenGroup;
 use sierra_generator::replace_ids::replace_sierra_ids_in_program;
+use syntax::node::{ast, Terminal};
 
 /// Command line args parser.
 /// Exits with 0/1 if the input is formatted correctly/incorrectly.
@@ -28,15 +33,90 @@ struct Args {
     /// The file to compile and run.
     #[arg(short, long)]
     path: String,
+    /// Runs only the tests whose name contains the filter.
+    #[arg(default_value_t = String::default())]
+    filter: String,
+    /// Runs the ignored tests as well.
+    #[arg(long, default_value_t = false, conflicts_with = "ignored")]
+    include_ignored: bool,
+    /// Runs only the ignored tests.
+    #[arg(long, default_value_t = false)]
+    ignored: bool,
+    /// The format of the output.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+    format: OutputFormat,
+    /// The format of the reported diagnostics.
+    #[arg(long, value_enum, default_value_t = MessageFormat::Human)]
+    message_format: MessageFormat,
+}
+
+/// The format of the output of the runner.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum OutputFormat {
+    /// Human readable lines.
+    Pretty,
+    /// A JSON object per line: one for each test, and one for the summary of the run.
+    Json,
 }
 
 /// The status of a ran test.
+#[derive(Clone, Copy, Serialize)]
 enum TestStatus {
+    #[serde(rename = "ok")]
     Success,
+    #[serde(rename = "failed")]
     Fail,
+    #[serde(rename = "ignored")]
     Ignore,
 }
 
+/// The result of a ran test.
+struct TestResult {
+    /// The status of the test.
+    status: TestStatus,
+    /// The data the test panicked with, if it panicked.
+    panic_data: Option<Vec<BigInt>>,
+    /// The data the test is expected to panic with, if specified.
+    expected_panic_data: Option<Vec<BigInt>>,
+    /// The gas remaining at the end of the test, if it was given gas.
+    remaining_gas: Option<BigInt>,
+    /// The stack trace at the origin of the panic, if the test panicked.
+    panic_stack_trace: Option<StackTrace>,
+    /// The error the test failed with, if it could not be run to completion.
+    run_error: Option<String>,
+}
+
+/// A JSON event emitted for a test in the json output format.
+#[derive(Serialize)]
+struct TestEvent<'a> {
+    #[serde(rename = "type")]
+    ty: &'static str,
+    name: &'a str,
+    status: TestStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    panic_data: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected_panic_data: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remaining_gas: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    panic_stack_trace: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    run_error: Option<&'a str>,
+}
+
+/// A JSON event emitted for the summary of the run in the json output format.
+#[derive(Serialize)]
+struct SummaryEvent {
+    #[serde(rename = "type")]
+    ty: &'static str,
+    status: TestStatus,
+    passed: usize,
+    failed: usize,
+    ignored: usize,
+    filtered_out: usize,
+}
+
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
@@ -45,14 +125,18 @@ fn main() -> anyhow::Result<()> {
 
     let main_crate_ids = setup_project(db, Path::new(&args.path))?;
 
-    if check_and_eprint_diagnostics(db) {
+    if check_and_eprint_diagnostics_with_format(db, args.message_format) {
         anyhow::bail!("failed to compile: {}", args.path);
     }
-    let all_tests = find_all_tests(db, main_crate_ids);
+    let all_tests = find_all_tests(db, main_crate_ids)?;
     let sierra_program = db
-        .get_sierra_program_for_functions(all_tests.iter().map(|t| t.func_id).collect())
+        .get_sierra_program_with_debug_for_functions(all_tests.iter().map(|t| t.func_id).collect())
         .with_context(|| "Compilation failed without any diagnostics.")?;
-    let sierra_program = replace_sierra_ids_in_program(db, &sierra_program);
+    let statements_locations = sierra_program.debug_info.statements_source_locations(db);
+    let sierra_program = replace_sierra_ids_in_program(db, &sierra_program.program);
+    // The gas usage is calculated for the whole program, as it is shared by all the tests.
+    let calc_gas = all_tests.iter().any(|test| test.available_gas.is_some());
+    let total_tests_count = all_tests.len();
     let named_tests = all_tests
         .into_iter()
         .map(|test| {
@@ -70,29 +154,61 @@ fn main() -> anyhow::Result<()> {
                 test,
             )
         })
+        .filter(|(name, _)| name.contains(&args.filter))
+        // With `--ignored`, only the ignored tests are run, and not ignored anymore.
+        .filter_map(|(name, mut test)| {
+            if args.include_ignored {
+                test.ignored = false;
+            } else if args.ignored {
+                if !test.ignored {
+                    return None;
+                }
+                test.ignored = false;
+            }
+            Some((name, test))
+        })
         .collect_vec();
-    let TestsSummary { passed, failed, ignored } = run_tests(named_tests, sierra_program)?;
+    let filtered_out = total_tests_count - named_tests.len();
+    let TestsSummary { passed, failed, ignored } =
+        run_tests(named_tests, sierra_program, statements_locations, calc_gas, args.format)?;
+    if args.format == OutputFormat::Json {
+        let summary = SummaryEvent {
+            ty: "suite",
+            status: if failed.is_empty() { TestStatus::Success } else { TestStatus::Fail },
+            passed: passed.len(),
+            failed: failed.len(),
+            ignored: ignored.len(),
+            filtered_out,
+        };
+        println!("{}", serde_json::to_string(&summary)?);
+        if !failed.is_empty() {
+            bail!("{} tests failed", failed.len());
+        }
+        return Ok(());
+    }
     if failed.is_empty() {
         println!(
-            "test result: {}. {} passed; {} failed; {} ignored",
+            "test result: {}. {} passed; {} failed; {} ignored; {} filtered out",
             "ok".bright_green(),
             passed.len(),
             failed.len(),
-            ignored.len()
+            ignored.len(),
+            filtered_out
         );
         Ok(())
     } else {
         println!("failures:");
-        for failure in &failed {
-            println!("   {failure}");
+        for (name, reason) in &failed {
+            println!("   {name} - {reason}");
         }
         println!();
         bail!(
-            "test result: {}. {} passed; {} failed; {} ignored",
+            "test result: {}. {} passed; {} failed; {} ignored; {} filtered out",
             "FAILED".bright_red(),
             passed.len(),
             failed.len(),
-            ignored.len()
+            ignored.len(),
+            filtered_out
         );
     }
 }
@@ -100,7 +216,8 @@ fn main() -> anyhow::Result<()> {
 /// Summary data of the ran tests.
 struct TestsSummary {
     passed: Vec<String>,
-    failed: Vec<String>,
+    /// The names of the failed tests, with the reasons of their failures.
+    failed: Vec<(String, String)>,
     ignored: Vec<String>,
 }
 
@@ -108,32 +225,86 @@ struct TestsSummary {
 fn run_tests(
     named_tests: Vec<(String, TestConfig)>,
     sierra_program: sierra::program::Program,
+    statements_locations: Vec<Option<String>>,
+    calc_gas: bool,
+    format: OutputFormat,
 ) -> anyhow::Result<TestsSummary> {
-    println!("running {} tests", named_tests.len());
+    if format == OutputFormat::Pretty {
+        println!("running {} tests", named_tests.len());
+    }
     let wrapped_summary =
         Mutex::new(Ok(TestsSummary { passed: vec![], failed: vec![], ignored: vec![] }));
     named_tests
         .into_par_iter()
-        .map(|(name, test)| -> anyhow::Result<(String, TestStatus)> {
+        .map(|(name, test)| -> anyhow::Result<(String, TestResult)> {
             if test.ignored {
-                return Ok((name, TestStatus::Ignore));
+                return Ok((
+                    name,
+                    TestResult {
+                        status: TestStatus::Ignore,
+                        panic_data: None,
+                        expected_panic_data: None,
+                        remaining_gas: None,
+                        panic_stack_trace: None,
+                        run_error: None,
+                    },
+                ));
             }
-            let runner = SierraCasmRunner::new(sierra_program.clone(), false)
-                .with_context(|| "Failed setting up runner.")?;
-            let result = runner
-                .run_function(name.as_str(), &[], &None)
-                .with_context(|| "Failed to run the function.")?;
+            let runner = SierraCasmRunner::new(sierra_program.clone(), calc_gas)
+                .with_context(|| "Failed setting up runner.")?
+                .with_statements_locations(statements_locations.clone());
+            // A test that can't be run (e.g. a VM error, or missing gas) fails, and the other
+            // tests are still run.
+            let result = match runner.run_function(name.as_str(), &[], &test.available_gas) {
+                Ok(result) => result,
+                Err(err) => {
+                    return Ok((
+                        name,
+                        TestResult {
+                            status: TestStatus::Fail,
+                            panic_data: None,
+                            expected_panic_data: None,
+                            remaining_gas: None,
+                            panic_stack_trace: None,
+                            run_error: Some(err.to_string()),
+                        },
+                    ));
+                }
+            };
+            let expected_panic_data = match &test.expectation {
+                TestExpectation::Panics(PanicExpectation::Exact(expected)) => {
+                    Some(expected.clone())
+                }
+                _ => None,
+            };
+            let (status, panic_data) = match (result.value, test.expectation) {
+                (RunResultValue::Success(_), TestExpectation::Success) => {
+                    (TestStatus::Success, None)
+                }
+                (RunResultValue::Panic(panic_data), TestExpectation::Panics(expectation)) => {
+                    match expectation {
+                        PanicExpectation::Exact(expected) if expected != panic_data => {
+                            (TestStatus::Fail, Some(panic_data))
+                        }
+                        _ => (TestStatus::Success, Some(panic_data)),
+                    }
+                }
+                (RunResultValue::Success(_), TestExpectation::Panics(_)) => {
+                    (TestStatus::Fail, None)
+                }
+                (RunResultValue::Panic(panic_data), TestExpectation::Success) => {
+                    (TestStatus::Fail, Some(panic_data))
+                }
+            };
             Ok((
                 name,
-                match (result.value, test.expectation) {
-                    (runner::RunResultValue::Success(_), TestExpectation::Success)
-                    | (runner::RunResultValue::Panic(_), TestExpectation::Panics) => {
-                        TestStatus::Success
-                    }
-                    (runner::RunResultValue::Success(_), TestExpectation::Panics)
-                    | (runner::RunResultValue::Panic(_), TestExpectation::Success) => {
-                        TestStatus::Fail
-                    }
+                TestResult {
+                    status,
+                    panic_data,
+                    expected_panic_data,
+                    remaining_gas: result.gas_counter,
+                    panic_stack_trace: result.panic_stack_trace,
+                    run_error: None,
                 },
             ))
         })
@@ -142,37 +313,122 @@ fn run_tests(
             if wrapped_summary.is_err() {
                 return;
             }
-            let (name, status) = match r {
-                Ok((name, status)) => (name, status),
+            let (name, result) = match r {
+                Ok((name, result)) => (name, result),
                 Err(err) => {
                     *wrapped_summary = Err(err);
                     return;
                 }
             };
             let summary = wrapped_summary.as_mut().unwrap();
-            let (res_type, status_str) = match status {
-                TestStatus::Success => (&mut summary.passed, "ok".bright_green()),
-                TestStatus::Fail => (&mut summary.failed, "fail".bright_red()),
-                TestStatus::Ignore => (&mut summary.ignored, "ignored".bright_yellow()),
+            let status_str = match result.status {
+                TestStatus::Success => "ok".bright_green(),
+                TestStatus::Fail => "fail".bright_red(),
+                TestStatus::Ignore => "ignored".bright_yellow(),
             };
-            println!("test {name} ... {status_str}",);
-            res_type.push(name);
+            match format {
+                OutputFormat::Pretty => {
+                    let gas_str = match &result.remaining_gas {
+                        Some(remaining_gas) => format!(" (remaining gas: {remaining_gas})"),
+                        None => String::new(),
+                    };
+                    println!("test {name} ... {status_str}{gas_str}");
+                }
+                OutputFormat::Json => println!("{}", test_event_json(&name, &result)),
+            }
+            match result.status {
+                TestStatus::Success => summary.passed.push(name),
+                TestStatus::Fail => summary.failed.push((name, failure_reason(&result))),
+                TestStatus::Ignore => summary.ignored.push(name),
+            }
         });
     wrapped_summary.into_inner().unwrap()
 }
 
+/// Returns a readable explanation of the failure of a test.
+fn failure_reason(result: &TestResult) -> String {
+    if let Some(run_error) = &result.run_error {
+        return format!("Failed to run the test: {run_error}");
+    }
+    let reason = match (&result.panic_data, &result.expected_panic_data) {
+        (None, _) => return "Expected the test to panic, but it completed successfully.".into(),
+        (Some(actual), None) => format!("Panicked with {}.", format_panic_data(actual)),
+        (Some(actual), Some(expected)) => format!(
+            "Panicked with unexpected data.\n      expected: {}\n      actual:   {}",
+            format_panic_data(expected),
+            format_panic_data(actual)
+        ),
+    };
+    match &result.panic_stack_trace {
+        Some(StackTrace(frames)) => {
+            format!("{reason}{}", frames.iter().map(|frame| format!("\n      {frame}")).join(""))
+        }
+        None => reason,
+    }
+}
+
+/// Formats panic data for display. Felts that are short strings are shown with their text, e.g.
+/// `0x6572726f72 ('error')`.
+fn format_panic_data(panic_data: &[BigInt]) -> String {
+    let felts = panic_data.iter().map(|felt| {
+        let (_, bytes) = felt.to_bytes_be();
+        let is_short_string = felt > &BigInt::from(0)
+            && bytes.iter().all(|byte| byte.is_ascii_graphic() || *byte == b' ');
+        if is_short_string {
+            format!("{felt:#x} ('{}')", String::from_utf8_lossy(&bytes))
+        } else {
+            felt.to_string()
+        }
+    });
+    format!("({})", felts.format(", "))
+}
+
+/// Returns the JSON event line of a ran test.
+fn test_event_json(name: &str, result: &TestResult) -> String {
+    let event = TestEvent {
+        ty: "test",
+        name,
+        status: result.status,
+        panic_data: result
+            .panic_data
+            .as_ref()
+            .map(|panic_data| panic_data.iter().map(|felt| felt.to_string()).collect()),
+        expected_panic_data: result
+            .expected_panic_data
+            .as_ref()
+            .map(|panic_data| panic_data.iter().map(|felt| felt.to_string()).collect()),
+        remaining_gas: result.remaining_gas.as_ref().map(|gas| gas.to_string()),
+        panic_stack_trace: result
+            .panic_stack_trace
+            .as_ref()
+            .map(|StackTrace(frames)| frames.iter().map(|frame| frame.to_string()).collect()),
+        run_error: result.run_error.as_deref(),
+    };
+    serde_json::to_string(&event).expect("Test events are always serializable.")
+}
+
 /// Expectation for a result of a test.
 enum TestExpectation {
     /// Running the test should not panic.
     Success,
     /// Running the test should result in a panic.
-    Panics,
+    Panics(PanicExpectation),
+}
+
+/// Expectation for the data of a panic of a test.
+enum PanicExpectation {
+    /// Any panic data is accepted.
+    Any,
+    /// The panic data must be exactly this data.
+    Exact(Vec<BigInt>),
 }
 
 /// The configuration for running a single test.
 struct TestConfig {
     /// The function id of the test function.
     func_id: FreeFunctionId,
+    /// The amount of gas the test requires.
+    available_gas: Option<usize>,
     /// The expected result of the run.
     expectation: TestExpectation,
     /// Should the test be ignored.
@@ -180,7 +436,10 @@ struct TestConfig {
 }
 
 /// Finds the tests in the requested crates.
-fn find_all_tests(db: &dyn SemanticGroup, main_crates: Vec<CrateId>) -> Vec<TestConfig> {
+fn find_all_tests(
+    db: &dyn SemanticGroup,
+    main_crates: Vec<CrateId>,
+) -> anyhow::Result<Vec<TestConfig>> {
     let mut tests = vec![];
     for crate_id in main_crates {
         let modules = db.crate_modules(crate_id);
@@ -193,15 +452,38 @@ fn find_all_tests(db: &dyn SemanticGroup, main_crates: Vec<CrateId>) -> Vec<Test
                 if let ModuleItemId::FreeFunction(func_id) = item {
                     if let Some(attrs) = db.free_function_declaration_attributes(*func_id) {
                         let mut is_test = false;
+                        let mut available_gas = None;
                         let mut ignored = false;
-                        let mut should_panic = false;
+                        let mut expectation = TestExpectation::Success;
                         for attr in attrs {
                             match attr.id.as_str() {
                                 "test" => {
                                     is_test = true;
                                 }
+                                "available_gas" => {
+                                    available_gas = Some(
+                                        extract_available_gas(db, &attr).with_context(|| {
+                                            format!(
+                                                "Expected a single numeric argument for the \
+                                                 `available_gas` attribute of `{}`.",
+                                                func_id.name(db.upcast())
+                                            )
+                                        })?,
+                                    );
+                                }
                                 "should_panic" => {
-                                    should_panic = true;
+                                    expectation = TestExpectation::Panics(
+                                        extract_panic_expectation(db, &attr).with_context(
+                                            || {
+                                                format!(
+                                                    "Expected no arguments, or `expected: \
+                                                     (<felts>)` for the `should_panic` attribute \
+                                                     of `{}`.",
+                                                    func_id.name(db.upcast())
+                                                )
+                                            },
+                                        )?,
+                                    );
                                 }
                                 "ignore" => {
                                     ignored = true;
@@ -212,11 +494,8 @@ fn find_all_tests(db: &dyn SemanticGroup, main_crates: Vec<CrateId>) -> Vec<Test
                         if is_test {
                             tests.push(TestConfig {
                                 func_id: *func_id,
-                                expectation: if should_panic {
-                                    TestExpectation::Panics
-                                } else {
-                                    TestExpectation::Success
-                                },
+                                available_gas,
+                                expectation,
                                 ignored,
                             })
                         }
@@ -225,5 +504,52 @@ fn find_all_tests(db: &dyn SemanticGroup, main_crates: Vec<CrateId>) -> Vec<Test
             }
         }
     }
-    tests
+    Ok(tests)
+}
+
+/// Extracts the amount of gas from an `available_gas` attribute, e.g. `#[available_gas(10000)]`.
+fn extract_available_gas(db: &dyn SemanticGroup, attr: &Attribute) -> Option<usize> {
+    let syntax_db = db.upcast();
+    let [arg] = &attr.args[..] else {
+        return None;
+    };
+    match (arg.name(syntax_db), arg.value(syntax_db)) {
+        (ast::OptionAttributeArgName::Empty(_), ast::Expr::Literal(literal)) => {
+            literal.text(syntax_db).parse().ok()
+        }
+        _ => None,
+    }
+}
+
+/// Extracts the expected panic data from a `should_panic` attribute, e.g.
+/// `#[should_panic(expected: ('error_code', 42))]`. Without arguments, any panic is accepted.
+fn extract_panic_expectation(db: &dyn SemanticGroup, attr: &Attribute) -> Option<PanicExpectation> {
+    let syntax_db = db.upcast();
+    let [arg] = &attr.args[..] else {
+        return if attr.args.is_empty() { Some(PanicExpectation::Any) } else { None };
+    };
+    let ast::OptionAttributeArgName::AttributeArgName(name) = arg.name(syntax_db) else {
+        return None;
+    };
+    if name.name(syntax_db).text(syntax_db) != "expected" {
+        return None;
+    }
+    let felts = match arg.value(syntax_db) {
+        ast::Expr::Tuple(tuple) => tuple.expressions(syntax_db).elements(syntax_db),
+        ast::Expr::Parenthesized(parenthesized) => vec![parenthesized.expr(syntax_db)],
+        value => vec![value],
+    };
+    felts
+        .into_iter()
+        .map(|felt| match felt {
+            ast::Expr::Literal(literal) => {
+                LiteralLongId::try_from(literal.text(syntax_db)).ok().map(|literal| literal.value)
+            }
+            ast::Expr::ShortString(short_string) => {
+                short_string_value(&short_string.text(syntax_db)).ok()
+            }
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(PanicExpectation::Exact)
 }
@@ -0,0 +1,47 @@
+#[test]
+func test_success() {}
+
+#[test]
+#[ignore]
+func test_ignored() {}
+
+#[test]
+func test_panic() {
+    let mut data = array_new::<felt>();
+    array_append::<felt>(data, 'failure');
+    panic(data);
+}
+
+#[test]
+#[should_panic(expected: ('other failure'))]
+func test_panic_unexpected_data() {
+    let mut data = array_new::<felt>();
+    array_append::<felt>(data, 'failure');
+    panic(data);
+}
+
+#[test]
+#[available_gas(100000)]
+func test_with_gas() {
+    countdown(3);
+}
+
+// Fails to run, as it requires gas but none is available.
+#[test]
+func test_without_gas() {
+    countdown(3);
+}
+
+func countdown(n: felt) {
+    match get_gas() {
+        Option::Some(_) => {},
+        Option::None(_) => {
+            let mut data = array_new::<felt>();
+            array_append::<felt>(data, 'OOG');
+            panic(data);
+        },
+    }
+    if n != 0 {
+        countdown(n - 1);
+    }
+}
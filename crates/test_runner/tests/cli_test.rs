@@ -0,0 +1,133 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+use serde_json::Value;
+
+/// Runs `cairo-test` on the test data file with the given extra arguments. Returns whether the run
+/// succeeded and its output.
+fn run_cairo_test_raw(args: &[&str]) -> (bool, String) {
+    let path: PathBuf =
+        [env!("CARGO_MANIFEST_DIR"), "test_data", "cli_tests.cairo"].iter().collect();
+    let output = Command::new(env!("CARGO_BIN_EXE_cairo-test"))
+        .arg("--path")
+        .arg(path)
+        .args(args)
+        .output()
+        .expect("Failed to run cairo-test.");
+    (output.status.success(), String::from_utf8(output.stdout).unwrap())
+}
+
+/// Runs `cairo-test` on the test data file with the given extra arguments and the json output
+/// format. Returns whether the run succeeded, the events of the tests sorted by name, and the
+/// summary event.
+fn run_cairo_test(args: &[&str]) -> (bool, Vec<Value>, Value) {
+    let (success, stdout) = run_cairo_test_raw(&[&["--format", "json"][..], args].concat());
+    let mut events: Vec<Value> =
+        stdout.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    let summary = events.pop().expect("Missing summary event.");
+    events.sort_by_key(|event| event["name"].as_str().unwrap().to_string());
+    (success, events, summary)
+}
+
+/// Returns the names of the tests of the given events, without the module path, with their
+/// statuses.
+fn statuses(events: &[Value]) -> Vec<(String, String)> {
+    events
+        .iter()
+        .map(|event| {
+            let name = event["name"].as_str().unwrap();
+            (
+                name.rsplit("::").next().unwrap().to_string(),
+                event["status"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_all_tests_json() {
+    let (success, events, summary) = run_cairo_test(&[]);
+    assert!(!success);
+    assert_eq!(
+        statuses(&events),
+        [
+            ("test_ignored", "ignored"),
+            ("test_panic", "failed"),
+            ("test_panic_unexpected_data", "failed"),
+            ("test_success", "ok"),
+            ("test_with_gas", "ok"),
+            ("test_without_gas", "failed"),
+        ]
+        .map(|(name, status)| (name.to_string(), status.to_string()))
+    );
+    assert_eq!(events[1]["panic_data"], serde_json::json!(["28817553043583589"]));
+    assert_eq!(
+        events[1]["panic_stack_trace"],
+        serde_json::json!(["at cli_tests::cli_tests::test_panic (cli_tests.cairo:12:5)"])
+    );
+    assert!(events[4]["remaining_gas"].is_string());
+    // A test that can't be run fails, without stopping the other tests.
+    assert!(events[5]["run_error"].is_string());
+    assert_eq!(
+        summary,
+        serde_json::json!({
+            "type": "suite",
+            "status": "failed",
+            "passed": 2,
+            "failed": 3,
+            "ignored": 1,
+            "filtered_out": 0,
+        })
+    );
+}
+
+#[test]
+fn test_filter() {
+    let (success, events, summary) = run_cairo_test(&["success"]);
+    assert!(success);
+    assert_eq!(statuses(&events), [("test_success".to_string(), "ok".to_string())]);
+    assert_eq!(summary["passed"], 1);
+    assert_eq!(summary["filtered_out"], 5);
+}
+
+#[test]
+fn test_include_ignored() {
+    let (success, events, summary) = run_cairo_test(&["test_ignored", "--include-ignored"]);
+    assert!(success);
+    assert_eq!(statuses(&events), [("test_ignored".to_string(), "ok".to_string())]);
+    assert_eq!(summary["ignored"], 0);
+}
+
+#[test]
+fn test_ignored() {
+    let (success, events, summary) = run_cairo_test(&["--ignored"]);
+    assert!(success);
+    assert_eq!(statuses(&events), [("test_ignored".to_string(), "ok".to_string())]);
+    assert_eq!(summary["filtered_out"], 5);
+}
+
+#[test]
+fn test_unexpected_panic_data_json() {
+    let (success, events, _) = run_cairo_test(&["test_panic_unexpected_data"]);
+    assert!(!success);
+    assert_eq!(
+        statuses(&events),
+        [("test_panic_unexpected_data".to_string(), "failed".to_string())]
+    );
+    assert_eq!(events[0]["panic_data"], serde_json::json!(["28817553043583589"]));
+    assert_eq!(
+        events[0]["expected_panic_data"],
+        serde_json::json!(["8830352507572135677088935277157"])
+    );
+}
+
+#[test]
+fn test_unexpected_panic_data_pretty() {
+    let (success, stdout) = run_cairo_test_raw(&["test_panic_unexpected_data"]);
+    assert!(!success);
+    assert!(stdout.contains(
+        "test_panic_unexpected_data - Panicked with unexpected data.
+      expected: (0x6f74686572206661696c757265 ('other failure'))
+      actual:   (0x6661696c757265 ('failure'))"
+    ));
+}
@@ -0,0 +1,8 @@
+// Appends a value to an array, and returns it along with its new length and an array of arrays.
+func main(
+    mut arr: Array::<felt>, nested: Array::<Array::<felt>>
+) -> (Array::<felt>, uint128, Array::<Array::<felt>>) {
+    arr.append(3);
+    let len = arr.len();
+    (arr, len, nested)
+}
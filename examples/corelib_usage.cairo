@@ -1,5 +1,3 @@
-impl MyCopy of Copy::<Option::<(felt, felt)>>;
-
 func foo(x: Option::<(felt, felt)>) -> Option::<felt> {
     let y = x;
     match x {
@@ -1,6 +1,7 @@
 enum MyEnumShort { a: felt, b: felt }
 enum MyEnumLong { a: felt, b: felt, c: felt }
 enum MyEnumGeneric<S, T> { a: T, b: S, c: T }
+impl MyEnumGenericDrop of Drop::<MyEnumGeneric::<(), felt>>;
 func main() -> felt {
     let es0 = MyEnumShort::a(10);
     match_short(es0);
@@ -8,6 +8,6 @@ func fib_inner(a: felt, b: felt, remaining: felt, mut arr: Array::<felt>) -> Arr
         return arr;
     }
 
-    array_append::<felt>(arr, a);
+    arr.append(a);
     fib_inner(b, a + b, remaining - 1, arr)
 }
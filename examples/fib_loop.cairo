@@ -0,0 +1,12 @@
+// Calculates fib using a loop.
+func fib(mut a: felt, mut b: felt, mut n: felt) -> felt implicits(RangeCheck, GasBuiltin) {
+    loop {
+        if n == 0 {
+            break a;
+        }
+        let c = a + b;
+        a = b;
+        b = c;
+        n = n - 1;
+    }
+}
@@ -0,0 +1,21 @@
+// Uses the StarkNet system calls, and returns the result of calling the deployed contract.
+func deploy_and_call(ref syscall_ptr: SyscallPtr) -> Array::<felt> {
+    let caller = starknet::get_caller_address(syscall_ptr);
+    let block_number = starknet::get_block_number(syscall_ptr);
+    let mut keys = array_new::<felt>();
+    keys.append(caller);
+    let mut data = array_new::<felt>();
+    data.append(block_number);
+    starknet::emit_event(syscall_ptr, keys, data);
+
+    let block_timestamp = starknet::get_block_timestamp(syscall_ptr);
+    let mut payload = array_new::<felt>();
+    payload.append(block_timestamp);
+    starknet::send_message_to_l1(syscall_ptr, 5, payload);
+
+    let address = starknet::deploy(syscall_ptr, 100, 7, array_new::<felt>());
+    let contract_address = starknet::get_contract_address(syscall_ptr);
+    let mut calldata = array_new::<felt>();
+    calldata.append(contract_address);
+    starknet::call_contract(syscall_ptr, address, 11, calldata)
+}
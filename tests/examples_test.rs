@@ -6,7 +6,11 @@ use compiler::project::setup_project;
 use filesystem::ids::CrateId;
 use num_bigint::BigInt;
 use pretty_assertions::assert_eq;
-use runner::{RunResultValue, SierraCasmRunner};
+use runner::{
+    Arg, ContractCall, Deployment, Event, L1Message, MockSyscallHandler, RunResultValue,
+    RunnerError, SierraCasmRunner, StackTrace,
+};
+use serde_json::json;
 use sierra_generator::db::SierraGenGroup;
 use sierra_generator::replace_ids::replace_sierra_ids_in_program;
 use sierra_to_casm::test_utils::build_metadata;
@@ -49,12 +53,14 @@ fn checked_compile_to_sierra(name: &str) -> sierra::program::Program {
 #[test_case("fib")]
 #[test_case("fib_box")]
 #[test_case("fib_array")]
+#[test_case("array_args")]
 #[test_case("fib_counter")]
 #[test_case("fib_struct")]
 #[test_case("fib_uint128")]
 #[test_case("fib_uint128_checked")]
 #[test_case("fib_gas")]
 #[test_case("fib_local")]
+#[test_case("fib_loop")]
 #[test_case("fib_unary")]
 #[test_case("enum_flow")]
 #[test_case("corelib_usage")]
@@ -62,6 +68,7 @@ fn checked_compile_to_sierra(name: &str) -> sierra::program::Program {
 #[test_case("hash_chain_gas")]
 #[test_case("pedersen_test")]
 #[test_case("testing")]
+#[test_case("syscalls")]
 fn cairo_to_sierra(name: &str) {
     compare_contents_or_fix(name, "sierra", checked_compile_to_sierra(name).to_string());
 }
@@ -70,12 +77,14 @@ fn cairo_to_sierra(name: &str) {
 #[test_case("fib", false)]
 #[test_case("fib_box", false)]
 #[test_case("fib_array", false)]
+#[test_case("array_args", false)]
 #[test_case("fib_counter", false)]
 #[test_case("fib_struct", false)]
 #[test_case("fib_uint128", false)]
 #[test_case("fib_uint128_checked", false)]
 #[test_case("fib_gas", true)]
 #[test_case("fib_local", false)]
+#[test_case("fib_loop", true)]
 #[test_case("fib_unary", false)]
 #[test_case("enum_flow", false)]
 #[test_case("corelib_usage", false)]
@@ -83,6 +92,7 @@ fn cairo_to_sierra(name: &str) {
 #[test_case("hash_chain_gas", true)]
 #[test_case("pedersen_test", false)]
 #[test_case("testing", false)]
+#[test_case("syscalls", false)]
 fn cairo_to_casm(name: &str, enable_gas_checks: bool) {
     let program = checked_compile_to_sierra(name);
     compare_contents_or_fix(
@@ -184,8 +194,172 @@ fn lowering_test(name: &str) {
 fn run_function_test(name: &str, params: &[BigInt], expected: RunResultValue) {
     let runner = SierraCasmRunner::new(checked_compile_to_sierra(name), false)
         .expect("Failed setting up runner.");
+    let args = params.iter().cloned().map(Arg::Value).collect::<Vec<_>>();
     let result = runner
-        .run_function(/* find first */ "", params, &None)
+        .run_function(/* find first */ "", &args, &None)
         .expect("Failed running the function.");
     assert_eq!(result.value, expected);
 }
+
+#[test_case("fib_struct", json!([1, 1, 9]), json!([55, 9, []]); "fib_struct")]
+#[test_case(
+    "fib_uint128_checked",
+    json!([1, "0x1", "10"]),
+    json!({"variant": 0, "value": 89});
+    "fib_uint128_checked"
+)]
+#[test_case("fib_array", json!([5]), json!([1, 1, 2, 3, 5]); "fib_array")]
+#[test_case(
+    "array_args",
+    json!([[1, 2], [[4, 5], [], [6]]]),
+    json!([[1, 2, 3], 3, [[4, 5], [], [6]]]);
+    "array_args"
+)]
+fn run_function_typed_test(name: &str, args: serde_json::Value, expected: serde_json::Value) {
+    let runner = SierraCasmRunner::new(checked_compile_to_sierra(name), false)
+        .expect("Failed setting up runner.");
+    let args = runner
+        .serialize_args(/* find first */ "", args.as_array().unwrap())
+        .expect("Failed serializing the arguments.");
+    let result = runner
+        .run_function(/* find first */ "", &args, &None)
+        .expect("Failed running the function.");
+    assert_eq!(result.typed_value, Some(expected));
+}
+
+#[test_case(
+    "fib_uint128_checked",
+    json!([1, "0x1", "10"]),
+    Ok([1, 1, 10].map(|v| Arg::Value(BigInt::from(v))).to_vec());
+    "uint128"
+)]
+#[test_case(
+    "fib_uint128_checked",
+    json!([1, 1, "0x100000000000000000000000000000000"]),
+    Err("Invalid value for the argument at index 2: Expected a 128 bit unsigned integer, got \
+         `340282366920938463463374607431768211456`."
+        .into());
+    "uint128_overflow"
+)]
+#[test_case(
+    "fib",
+    json!([1, 1]),
+    Err("Function expects 3 arguments and received 2 instead.".into());
+    "wrong_count"
+)]
+#[test_case(
+    "fib_struct",
+    json!([1, [1], 9]),
+    Err("Invalid value for the argument at index 1: Expected a number, got `[1]`.".into());
+    "wrong_type"
+)]
+#[test_case(
+    "array_args",
+    json!([[1, 2], [[4], []]]),
+    Ok(vec![
+        Arg::Array([1, 2].map(|v| Arg::Value(BigInt::from(v))).to_vec()),
+        Arg::Array(vec![Arg::Array(vec![Arg::Value(BigInt::from(4))]), Arg::Array(vec![])]),
+    ]);
+    "nested_array"
+)]
+fn serialize_args_test(name: &str, args: serde_json::Value, expected: Result<Vec<Arg>, String>) {
+    let runner = SierraCasmRunner::new(checked_compile_to_sierra(name), false)
+        .expect("Failed setting up runner.");
+    assert_eq!(
+        runner
+            .serialize_args(/* find first */ "", args.as_array().unwrap())
+            .map_err(|err| err.to_string()),
+        expected
+    );
+}
+
+#[test]
+fn run_function_stack_trace_test() {
+    let (db, main_crate_ids) = setup("fib_uint128");
+    let sierra_program = db.get_sierra_program_with_debug(main_crate_ids).unwrap();
+    let runner =
+        SierraCasmRunner::new(replace_sierra_ids_in_program(&db, &sierra_program.program), false)
+            .expect("Failed setting up runner.")
+            .with_statements_locations(sierra_program.debug_info.statements_source_locations(&db));
+    let args = [1, 1, 200].map(|v| Arg::Value(BigInt::from(v)));
+    let result = runner
+        .run_function(/* find first */ "", &args, &None)
+        .expect("Failed running the function.");
+    let StackTrace(frames) = result.panic_stack_trace.expect("Expected a panic stack trace.");
+    // The overflowing addition is called from the innermost `fib` frame, which is called
+    // recursively from the rest.
+    let fib_locations = frames
+        .iter()
+        .filter(|frame| frame.function.ends_with("::fib"))
+        .map(|frame| frame.location.as_deref())
+        .collect::<Vec<_>>();
+    assert!(fib_locations.len() > 1);
+    assert_eq!(fib_locations[0], Some("fib_uint128.cairo:9:20"));
+    assert!(fib_locations[1..].iter().all(|location| *location == Some("fib_uint128.cairo:9:13")));
+}
+
+#[test]
+fn run_function_failure_stack_trace_test() {
+    let (db, main_crate_ids) = setup("array_args");
+    let sierra_program = db.get_sierra_program_with_debug(main_crate_ids).unwrap();
+    let runner =
+        SierraCasmRunner::new(replace_sierra_ids_in_program(&db, &sierra_program.program), false)
+            .expect("Failed setting up runner.")
+            .with_statements_locations(sierra_program.debug_info.statements_source_locations(&db));
+    // Values instead of arrays, so appending to the first array fails in the VM.
+    let args = [1, 1, 2, 2].map(|v| Arg::Value(BigInt::from(v)));
+    let Err(RunnerError::VirtualMachineError { stack_trace: StackTrace(frames), .. }) =
+        runner.run_function(/* find first */ "", &args, &None)
+    else {
+        panic!("Expected the VM to fail.");
+    };
+    assert_eq!(
+        frames.iter().map(|frame| frame.location.as_deref()).collect::<Vec<_>>(),
+        vec![Some("array_args.cairo:5:5")]
+    );
+}
+
+#[test]
+fn run_function_syscalls_test() {
+    let syscall_handler = MockSyscallHandler {
+        caller_address: BigInt::from(1),
+        contract_address: BigInt::from(2),
+        block_number: BigInt::from(3),
+        block_timestamp: BigInt::from(4),
+        call_results: [((BigInt::from(7), BigInt::from(11)), vec![BigInt::from(12)])]
+            .into_iter()
+            .collect(),
+        ..MockSyscallHandler::default()
+    };
+    let runner = SierraCasmRunner::new(checked_compile_to_sierra("syscalls"), false)
+        .expect("Failed setting up runner.")
+        .with_syscall_handler(syscall_handler);
+    let result =
+        runner.run_function(/* find first */ "", &[], &None).expect("Failed running the function.");
+    assert_eq!(result.typed_value, Some(json!([12])));
+    let syscall_handler = result.syscall_handler;
+    assert_eq!(
+        syscall_handler.events,
+        vec![Event { keys: vec![BigInt::from(1)], data: vec![BigInt::from(3)] }]
+    );
+    assert_eq!(
+        syscall_handler.l1_messages,
+        vec![L1Message { to_address: BigInt::from(5), payload: vec![BigInt::from(4)] }]
+    );
+    assert_eq!(
+        syscall_handler.deployments,
+        vec![Deployment {
+            class_hash: BigInt::from(100),
+            contract_address_salt: BigInt::from(7),
+            calldata: vec![],
+        }]
+    );
+    assert_eq!(
+        syscall_handler.calls,
+        vec![ContractCall {
+            address: BigInt::from(7),
+            entry_point_selector: BigInt::from(11),
+            calldata: vec![BigInt::from(2)],
+        }]
+    );
+}